@@ -0,0 +1,232 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Component, Path, PathBuf},
+};
+
+/// What an archive knows about one of its entries without reading it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    /// Path in the archive, directories separated with `\`
+    pub name: String,
+    /// Size once read
+    pub size: u64,
+    /// Size in the archive when the entry is compressed
+    pub packed_size: Option<u64>,
+}
+
+/// Archive of game files, e.g. a Fallout DAT or a KotOR ERF.
+pub trait ReadableArchive {
+    /// Entries in the order they're stored in.
+    fn entries(&self) -> Vec<Entry>;
+
+    /// Reader of the entry's contents, decompressed.
+    fn open(&self, name: &str) -> io::Result<Box<dyn Read + '_>>;
+
+    /// Entry with the given name, compared case insensitively like the games do.
+    fn stat(&self, name: &str) -> Option<Entry> {
+        self.entries()
+            .into_iter()
+            .find(|entry| entry.name.eq_ignore_ascii_case(name))
+    }
+
+    fn read(&self, name: &str) -> io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.open(name)?.read_to_end(&mut data)?;
+        Ok(data)
+    }
+}
+
+pub fn not_found(name: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("No entry named '{name}' in the archive"),
+    )
+}
+
+/// Case insensitive glob match, `*` matches any run of characters and `?` any single one.
+pub fn matches(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.to_ascii_lowercase().into_bytes();
+    let name = name.to_ascii_lowercase().into_bytes();
+
+    // Backtracks only to the latest star, enough for globs without character classes
+    let (mut p, mut n) = (0, 0);
+    let mut star = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == b'?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    p = star_p + 1;
+                    n = star_n + 1;
+                    star = Some((star_p, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == b'*')
+}
+
+/// Listing of the entries matching the pattern: size, packed size if any, and name.
+pub fn render(archive: &dyn ReadableArchive, pattern: &str) -> String {
+    archive
+        .entries()
+        .iter()
+        .filter(|entry| matches(pattern, &entry.name))
+        .map(|entry| {
+            let packed = entry
+                .packed_size
+                .map_or_else(|| "-".to_string(), |size| size.to_string());
+            format!("{:>10} {:>10}  {}\n", entry.size, packed, entry.name)
+        })
+        .collect()
+}
+
+/// Where an entry goes when extracted into the directory. Names that would end up outside of
+/// it, e.g. `..\save.dat`, have no path.
+pub fn entry_path(directory: &Path, name: &str) -> Option<PathBuf> {
+    let relative: PathBuf = name
+        .split(['\\', '/'])
+        .filter(|part| !part.is_empty())
+        .collect();
+    let inside = relative
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)));
+    (inside && relative.components().next().is_some()).then(|| directory.join(relative))
+}
+
+/// Writes the entries matching the pattern under the directory, keeping their directories.
+/// Returns the paths written.
+pub fn extract(
+    archive: &dyn ReadableArchive,
+    pattern: &str,
+    directory: &Path,
+) -> io::Result<Vec<PathBuf>> {
+    let mut written = Vec::new();
+    for entry in archive.entries() {
+        if !matches(pattern, &entry.name) {
+            continue;
+        }
+        let path = entry_path(directory, &entry.name).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "Entry '{}' would be extracted outside of the directory",
+                    entry.name
+                ),
+            )
+        })?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, archive.read(&entry.name)?)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// Names of the entries matching the pattern that contain the bytes, with the offset of the
+/// first occurrence.
+pub fn search(
+    archive: &dyn ReadableArchive,
+    pattern: &str,
+    needle: &[u8],
+) -> io::Result<Vec<(String, usize)>> {
+    let mut found = Vec::new();
+    if needle.is_empty() {
+        return Ok(found);
+    }
+    for entry in archive.entries() {
+        if !matches(pattern, &entry.name) {
+            continue;
+        }
+        let data = archive.read(&entry.name)?;
+        if let Some(offset) = data
+            .windows(needle.len())
+            .position(|window| window == needle)
+        {
+            found.push((entry.name, offset));
+        }
+    }
+    Ok(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Memory(Vec<(&'static str, &'static [u8])>);
+
+    impl ReadableArchive for Memory {
+        fn entries(&self) -> Vec<Entry> {
+            self.0
+                .iter()
+                .map(|(name, data)| Entry {
+                    name: name.to_string(),
+                    size: data.len() as u64,
+                    packed_size: None,
+                })
+                .collect()
+        }
+
+        fn open(&self, name: &str) -> io::Result<Box<dyn Read + '_>> {
+            self.0
+                .iter()
+                .find(|(entry, _)| entry.eq_ignore_ascii_case(name))
+                .map(|(_, data)| Box::new(*data) as Box<dyn Read>)
+                .ok_or_else(|| not_found(name))
+        }
+    }
+
+    #[test]
+    fn globs_match_case_insensitively() {
+        assert!(matches("*", "art\\critters\\hanpwrga.frm"));
+        assert!(matches("ART\\*\\*.FRM", "art\\critters\\hanpwrga.frm"));
+        assert!(matches("maps\\arc?ves.map", "maps\\arcaves.map"));
+        assert!(matches("*a*a*", "banana"));
+        assert!(!matches("*.frm", "art\\critters\\hanpwrga.fr"));
+        assert!(!matches("maps\\?.map", "maps\\ab.map"));
+    }
+
+    #[test]
+    fn entry_paths_stay_in_the_directory() {
+        let out = Path::new("out");
+        assert_eq!(
+            entry_path(out, "art\\critters\\hanpwrga.frm"),
+            Some(out.join("art").join("critters").join("hanpwrga.frm"))
+        );
+        assert_eq!(entry_path(out, "..\\save.dat"), None);
+        assert_eq!(
+            entry_path(out, "/etc/passwd"),
+            Some(out.join("etc").join("passwd"))
+        );
+        assert_eq!(entry_path(out, ""), None);
+    }
+
+    #[test]
+    fn lists_and_searches_through_the_trait() {
+        let archive = Memory(vec![
+            ("text\\english\\game\\misc.msg", b"{100}{}{Vault 13}"),
+            ("maps\\arcaves.map", b"vault"),
+        ]);
+
+        assert_eq!(
+            render(&archive, "maps\\*"),
+            "         5          -  maps\\arcaves.map\n"
+        );
+        assert_eq!(archive.stat("MAPS\\ARCAVES.MAP").unwrap().size, 5);
+        assert_eq!(
+            search(&archive, "*", b"Vault").unwrap(),
+            vec![("text\\english\\game\\misc.msg".to_string(), 8)]
+        );
+        assert!(archive.read("missing").is_err());
+    }
+}
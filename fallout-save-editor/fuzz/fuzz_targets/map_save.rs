@@ -0,0 +1,11 @@
+#![no_main]
+
+use fallout_save_editor::parser::{gunzip, try_map_save};
+use libfuzzer_sys::fuzz_target;
+
+// Saved maps are usually gzipped, the ones in saves/ work as seeds as they are
+fuzz_target!(|data: &[u8]| {
+    if let Ok(data) = gunzip(data.to_vec()) {
+        let _ = try_map_save(&data);
+    }
+});
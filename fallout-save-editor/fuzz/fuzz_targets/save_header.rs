@@ -0,0 +1,8 @@
+#![no_main]
+
+use fallout_save_editor::parser::header;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = header(data);
+});
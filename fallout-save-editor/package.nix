@@ -6,18 +6,14 @@
     pname = "fallout-save-editor";
     version = "0.1.1";
     cargoLock.lockFile = ./Cargo.lock;
-    src = pkgs.lib.cleanSource ./.;
-
-    checkPhase = ''
-      cargo test
-    '';
+    # save-backup, hexdump and archive are path dependencies, they have to be in the source too
+    src = pkgs.lib.cleanSource ./..;
+    cargoRoot = "fallout-save-editor";
+    buildAndTestSubdir = "fallout-save-editor";
   };
   devShell = pkgs.mkShell {
     buildInputs = with pkgs; [
       cargo
-
-      # Scripts under hacks use python
-      (python3.withPackages (p: [ p.requests ]))
     ];
   };
 }
@@ -0,0 +1,156 @@
+/// Labels for hexdumps of saves: the fields the parser knows about and where they are, the rest
+/// is left for figuring out.
+use hexdump::Hexdump;
+use nom::number::complete::be_i32;
+
+use crate::parser::{
+    map_header, read_script_block_junk, script, tile_size_in_bytes, SCRIPTS_IN_GROUP,
+    SCRIPT_GROUP_COUNT,
+};
+
+/// Fields of the SAVE.DAT header and their sizes, in the order `parser::header` reads them
+const SAVE_HEADER_FIELDS: [(&str, usize); 18] = [
+    ("magic", 18),
+    ("steam padding", 6),
+    ("version", 4),
+    ("release type", 1),
+    ("character name", 32),
+    ("save name", 30),
+    ("save day", 2),
+    ("save month", 2),
+    ("save year", 2),
+    ("in-game time", 4),
+    ("in-game month", 2),
+    ("in-game day", 2),
+    ("in-game year", 2),
+    ("in-game ticks", 4),
+    ("current map", 4),
+    ("map name", 16),
+    ("thumbnail", 29792),
+    ("unused", 128),
+];
+
+/// Fields of a map save header and their sizes, in the order `parser::map_header` reads them
+const MAP_HEADER_FIELDS: [(&str, usize); 13] = [
+    ("version", 4),
+    ("filename", 16),
+    ("player position", 4),
+    ("player elevation", 4),
+    ("player orientation", 4),
+    ("local variable count", 4),
+    ("script id", 4),
+    ("flags", 4),
+    ("darkness", 4),
+    ("global variable count", 4),
+    ("map id", 4),
+    ("ticks", 4),
+    ("unknown", 176),
+];
+
+/// Script block check counter and possible CRC after every 16 scripts
+const SCRIPT_BLOCK_CHECK_SIZE: usize = 8;
+
+/// Adds `fields` one after another from `offset`, returns where they end.
+fn add_fields(dump: &mut Hexdump, offset: usize, fields: &[(&str, usize)]) -> usize {
+    fields.iter().fold(offset, |offset, (label, size)| {
+        dump.add(offset..offset + size, *label);
+        offset + size
+    })
+}
+
+pub fn save_header(data: &[u8]) -> Hexdump<'_> {
+    let mut dump = Hexdump::new(data);
+    add_fields(&mut dump, 0, &SAVE_HEADER_FIELDS);
+    dump
+}
+
+/// Labels the script groups from `offset`, walking them the same way `parser::script_group`
+/// does. Stops at the first thing that doesn't parse.
+fn add_scripts(dump: &mut Hexdump, data: &[u8], mut offset: usize) -> Option<()> {
+    let consumed = |rest: &[u8]| data.len() - rest.len();
+
+    for group in 0..SCRIPT_GROUP_COUNT {
+        let (rest, count) = be_i32::<_, ()>(data.get(offset..)?).ok()?;
+        dump.add(
+            offset..consumed(rest),
+            format!("script group {group}: {count} scripts"),
+        );
+        offset = consumed(rest);
+
+        let mut remaining = usize::try_from(count).ok()?;
+        while remaining > 0 {
+            let in_block = remaining.min(SCRIPTS_IN_GROUP);
+            for _ in 0..in_block {
+                let (rest, script) = script(&data[offset..]).ok()?;
+                let label = format!("script {} ({:?})", script.id, script.script_type);
+                dump.add(offset..consumed(rest), label);
+                offset = consumed(rest);
+            }
+
+            let unused_start = offset;
+            for _ in in_block..SCRIPTS_IN_GROUP {
+                let (rest, _) = read_script_block_junk(&data[offset..]).ok()?;
+                offset = consumed(rest);
+            }
+            if offset > unused_start {
+                dump.add(unused_start..offset, "unused script slots");
+            }
+
+            dump.add(
+                offset..offset + SCRIPT_BLOCK_CHECK_SIZE,
+                "script block check",
+            );
+            offset += SCRIPT_BLOCK_CHECK_SIZE;
+            remaining -= in_block;
+        }
+    }
+    Some(())
+}
+
+/// Labels a decompressed map save as far as it parses.
+pub fn map_save(data: &[u8]) -> Hexdump<'_> {
+    let mut dump = Hexdump::new(data);
+    let offset = add_fields(&mut dump, 0, &MAP_HEADER_FIELDS);
+    let Ok((_, header)) = map_header(data) else {
+        return dump;
+    };
+
+    let mut offset = offset;
+    for (kind, count) in [
+        ("global", header.global_variable_count),
+        ("local", header.local_variable_count),
+    ] {
+        // Counts can be garbage in a broken save, there's no more variables than there's data
+        let count = (count.max(0) as usize).min(data.len().saturating_sub(offset) / 4);
+        for i in 0..count {
+            dump.add(offset..offset + 4, format!("{kind} variable {i}"));
+            offset += 4;
+        }
+    }
+
+    let tiles = tile_size_in_bytes(&header.flags) as usize;
+    if tiles > 0 {
+        dump.add(offset..offset + tiles, "tiles");
+    }
+    add_scripts(&mut dump, data, offset + tiles);
+    dump
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_fields_add_up_to_the_parsed_header() {
+        let size: usize = SAVE_HEADER_FIELDS.iter().map(|(_, size)| size).sum();
+        let data = vec![b'A'; size + 1];
+        let (rest, _) = crate::parser::header(&data).unwrap();
+        assert_eq!(rest.len(), 1);
+
+        let size: usize = MAP_HEADER_FIELDS.iter().map(|(_, size)| size).sum();
+        let mut data = vec![0; size];
+        data[3] = 20;
+        let (rest, _) = map_header(&data).unwrap();
+        assert!(rest.is_empty());
+    }
+}
@@ -0,0 +1,366 @@
+// DAT archives hold the game data, e.g. master.dat and critter.dat. Fallout 1 uses DAT1, big
+// endian with LZSS compressed entries listed per directory. Fallout 2 uses DAT2, little endian
+// with zlib compressed entries and the file list at the end of the archive.
+//
+// Only DAT2 archives can be written, Fallout 2 reads the files of a DAT2 archive over the ones in
+// master.dat when it's named patch000.dat.
+//
+// Documentation: https://falloutmods.fandom.com/wiki/DAT_file_format
+use std::io::{self, Cursor, Read, Write};
+
+use archive::{Entry, ReadableArchive};
+use flate2::{read::ZlibDecoder, write::ZlibEncoder, Compression};
+
+use crate::parser::DatFile;
+
+const DAT1_COMPRESSED: u32 = 0x40;
+const DAT1_HEADER_SIZE: usize = 16;
+const DAT1_DIRECTORY_HEADER_SIZE: usize = 16;
+const LZSS_DICTIONARY_SIZE: usize = 4096;
+const LZSS_MAX_MATCH: usize = 18;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DatVersion {
+    Dat1,
+    Dat2,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DatEntry {
+    /// Path in the archive as stored, e.g. `art\critters\hanpwrga.frm`
+    pub name: String,
+    pub compressed: bool,
+    pub size: u32,
+    /// Size in the archive, DAT1 leaves it 0 for stored entries
+    pub packed_size: u32,
+    pub offset: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dat {
+    pub version: DatVersion,
+    pub entries: Vec<DatEntry>,
+    data: Vec<u8>,
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn bytes(data: &[u8], offset: usize, length: usize) -> io::Result<&[u8]> {
+    offset
+        .checked_add(length)
+        .and_then(|end| data.get(offset..end))
+        .ok_or_else(|| invalid_data("DAT ended in the middle of a value"))
+}
+
+fn le_u32(data: &[u8], offset: usize) -> io::Result<u32> {
+    let b = bytes(data, offset, 4)?;
+    Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+fn be_u32(data: &[u8], offset: usize) -> io::Result<u32> {
+    let b = bytes(data, offset, 4)?;
+    Ok(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+/// Pascal string used for DAT1 names, returns the string and the offset after it.
+fn dat1_name(data: &[u8], offset: usize) -> io::Result<(String, usize)> {
+    let length = *bytes(data, offset, 1)?.first().unwrap_or(&0) as usize;
+    let name = bytes(data, offset + 1, length)?;
+    Ok((
+        String::from_utf8_lossy(name).into_owned(),
+        offset + 1 + length,
+    ))
+}
+
+/// Decompresses a DAT1 entry. It's a run of blocks, each starting with a big endian i16: a
+/// negative one is that many bytes stored as is, a positive one that many bytes of LZSS with a
+/// dictionary of its own.
+pub fn lzss_decompress(input: &[u8], size: usize) -> io::Result<Vec<u8>> {
+    let mut out = Vec::with_capacity(size);
+    let mut position = 0;
+    while out.len() < size && position + 2 <= input.len() {
+        let length = i16::from_be_bytes([input[position], input[position + 1]]);
+        position += 2;
+        if length == 0 {
+            break;
+        }
+        let end = position + length.unsigned_abs() as usize;
+        let block = input
+            .get(position..end)
+            .ok_or_else(|| invalid_data("LZSS block is longer than the entry"))?;
+        position = end;
+
+        if length < 0 {
+            out.extend_from_slice(block);
+            continue;
+        }
+
+        // The dictionary starts out as spaces and is written from just before its end
+        let mut dictionary = [b' '; LZSS_DICTIONARY_SIZE];
+        let mut dictionary_position = LZSS_DICTIONARY_SIZE - LZSS_MAX_MATCH;
+        let mut block = block.iter().copied();
+        'block: while let Some(mut flags) = block.next() {
+            for _ in 0..8 {
+                if flags & 1 == 1 {
+                    let Some(byte) = block.next() else {
+                        break 'block;
+                    };
+                    out.push(byte);
+                    dictionary[dictionary_position] = byte;
+                    dictionary_position = (dictionary_position + 1) % LZSS_DICTIONARY_SIZE;
+                } else {
+                    let (Some(low), Some(high)) = (block.next(), block.next()) else {
+                        break 'block;
+                    };
+                    let offset = low as usize | ((high as usize & 0xf0) << 4);
+                    let length = (high as usize & 0x0f) + 3;
+                    for i in 0..length {
+                        // Copies can overlap with what they write, so read one at a time
+                        let byte = dictionary[(offset + i) % LZSS_DICTIONARY_SIZE];
+                        out.push(byte);
+                        dictionary[dictionary_position] = byte;
+                        dictionary_position = (dictionary_position + 1) % LZSS_DICTIONARY_SIZE;
+                    }
+                }
+                flags >>= 1;
+            }
+        }
+    }
+    out.truncate(size);
+    Ok(out)
+}
+
+fn to_u32(value: usize) -> io::Result<u32> {
+    u32::try_from(value).map_err(|_| invalid_data("DAT2 archives can't be larger than 4 GiB"))
+}
+
+impl Dat {
+    /// Empty DAT2 archive.
+    pub fn new() -> Dat {
+        Dat {
+            version: DatVersion::Dat2,
+            entries: Vec::new(),
+            data: Vec::new(),
+        }
+    }
+
+    /// Parses the file list of a DAT1 or DAT2 archive. DAT2 archives end with their own size,
+    /// anything else is taken for DAT1.
+    pub fn parse(data: Vec<u8>) -> io::Result<Dat> {
+        let is_dat2 = data.len() >= 8
+            && le_u32(&data, data.len() - 4).is_ok_and(|size| size as usize == data.len());
+        let (version, entries) = if is_dat2 {
+            (DatVersion::Dat2, Dat::dat2_entries(&data)?)
+        } else {
+            (DatVersion::Dat1, Dat::dat1_entries(&data)?)
+        };
+        Ok(Dat {
+            version,
+            entries,
+            data,
+        })
+    }
+
+    fn dat2_entries(data: &[u8]) -> io::Result<Vec<DatEntry>> {
+        let footer = DatFile {
+            tree_size: le_u32(data, data.len() - 8)?,
+            data_size: le_u32(data, data.len() - 4)?,
+        };
+        let tree = (footer.data_size as usize)
+            .checked_sub(footer.tree_size as usize + 8)
+            .ok_or_else(|| invalid_data("DAT2 tree is larger than the archive"))?;
+
+        let file_count = le_u32(data, tree)? as usize;
+        let mut position = tree + 4;
+        // Each entry takes at least 17 bytes, a count larger than that is corrupt
+        let mut entries = Vec::with_capacity(file_count.min(data.len() / 17));
+        for _ in 0..file_count {
+            let name_length = le_u32(data, position)? as usize;
+            let name = bytes(data, position + 4, name_length)?;
+            position += 4 + name_length;
+            entries.push(DatEntry {
+                name: String::from_utf8_lossy(name).into_owned(),
+                compressed: bytes(data, position, 1)?[0] == 1,
+                size: le_u32(data, position + 1)?,
+                packed_size: le_u32(data, position + 5)?,
+                offset: le_u32(data, position + 9)?,
+            });
+            position += 13;
+        }
+        Ok(entries)
+    }
+
+    fn dat1_entries(data: &[u8]) -> io::Result<Vec<DatEntry>> {
+        let directory_count = be_u32(data, 0)? as usize;
+        let mut position = DAT1_HEADER_SIZE;
+        let mut directories = Vec::with_capacity(directory_count.min(data.len()));
+        for _ in 0..directory_count {
+            let (name, next) = dat1_name(data, position)?;
+            directories.push(name);
+            position = next;
+        }
+
+        let mut entries = Vec::new();
+        for directory in directories {
+            let file_count = be_u32(data, position)?;
+            position += DAT1_DIRECTORY_HEADER_SIZE;
+            for _ in 0..file_count {
+                let (name, next) = dat1_name(data, position)?;
+                position = next;
+                let name = match directory.as_str() {
+                    "." => name,
+                    _ => format!("{directory}\\{name}"),
+                };
+                entries.push(DatEntry {
+                    name,
+                    compressed: be_u32(data, position)? == DAT1_COMPRESSED,
+                    offset: be_u32(data, position + 4)?,
+                    size: be_u32(data, position + 8)?,
+                    packed_size: be_u32(data, position + 12)?,
+                });
+                position += 16;
+            }
+        }
+        Ok(entries)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&DatEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.name.eq_ignore_ascii_case(name))
+    }
+
+    fn ensure_dat2(&self) -> io::Result<()> {
+        match self.version {
+            DatVersion::Dat2 => Ok(()),
+            DatVersion::Dat1 => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "Only DAT2 archives can be changed",
+            )),
+        }
+    }
+
+    /// Adds an entry, or replaces the one with the same name. Compressed entries are zlib
+    /// compressed like the ones in master.dat.
+    pub fn insert(&mut self, name: &str, data: &[u8], compress: bool) -> io::Result<()> {
+        self.ensure_dat2()?;
+        let stored = if compress {
+            let mut encoder = ZlibEncoder::new(Vec::new(), Compression::best());
+            encoder.write_all(data)?;
+            encoder.finish()?
+        } else {
+            data.to_vec()
+        };
+
+        // The replaced entry's bytes stay around until the archive is written
+        let entry = DatEntry {
+            name: name.to_string(),
+            compressed: compress,
+            size: to_u32(data.len())?,
+            packed_size: to_u32(stored.len())?,
+            offset: to_u32(self.data.len())?,
+        };
+        self.data.extend_from_slice(&stored);
+
+        match self
+            .entries
+            .iter_mut()
+            .find(|existing| existing.name.eq_ignore_ascii_case(name))
+        {
+            Some(existing) => *existing = entry,
+            None => self.entries.push(entry),
+        }
+        Ok(())
+    }
+
+    /// Removes the entries matching the pattern, returns how many there were.
+    pub fn remove(&mut self, pattern: &str) -> io::Result<usize> {
+        self.ensure_dat2()?;
+        let before = self.entries.len();
+        self.entries
+            .retain(|entry| !archive::matches(pattern, &entry.name));
+        Ok(before - self.entries.len())
+    }
+
+    /// Serializes a DAT2 archive: the entries back to back, the tree listing them and the sizes
+    /// of the tree and of the whole archive.
+    ///
+    /// The game binary searches the tree for files, so it's sorted by name ignoring case the same
+    /// way master.dat is whatever order the entries were added in.
+    pub fn to_bytes(&self) -> io::Result<Vec<u8>> {
+        self.ensure_dat2()?;
+        let mut entries: Vec<&DatEntry> = self.entries.iter().collect();
+        entries.sort_by_key(|entry| entry.name.to_ascii_lowercase());
+
+        let mut data = Vec::new();
+        let mut tree = to_u32(entries.len())?.to_le_bytes().to_vec();
+        for entry in entries {
+            let offset = to_u32(data.len())?;
+            data.extend_from_slice(self.stored(entry)?);
+
+            tree.extend_from_slice(&to_u32(entry.name.len())?.to_le_bytes());
+            tree.extend_from_slice(entry.name.as_bytes());
+            tree.push(entry.compressed as u8);
+            let packed_size = if entry.compressed {
+                entry.packed_size
+            } else {
+                entry.size
+            };
+            for value in [entry.size, packed_size, offset] {
+                tree.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+
+        let footer = DatFile {
+            tree_size: to_u32(tree.len())?,
+            data_size: to_u32(data.len() + tree.len() + 8)?,
+        };
+        data.extend_from_slice(&tree);
+        data.extend_from_slice(&footer.tree_size.to_le_bytes());
+        data.extend_from_slice(&footer.data_size.to_le_bytes());
+        Ok(data)
+    }
+
+    /// Bytes of the entry as stored in the archive.
+    fn stored(&self, entry: &DatEntry) -> io::Result<&[u8]> {
+        let length = if entry.compressed {
+            entry.packed_size
+        } else {
+            entry.size
+        };
+        bytes(&self.data, entry.offset as usize, length as usize)
+    }
+}
+
+impl Default for Dat {
+    fn default() -> Self {
+        Dat::new()
+    }
+}
+
+impl ReadableArchive for Dat {
+    fn entries(&self) -> Vec<Entry> {
+        self.entries
+            .iter()
+            .map(|entry| Entry {
+                name: entry.name.clone(),
+                size: entry.size as u64,
+                packed_size: entry.compressed.then_some(entry.packed_size as u64),
+            })
+            .collect()
+    }
+
+    fn open(&self, name: &str) -> io::Result<Box<dyn Read + '_>> {
+        let entry = self.get(name).ok_or_else(|| archive::not_found(name))?;
+        let stored = self.stored(entry)?;
+        Ok(match (self.version, entry.compressed) {
+            (_, false) => Box::new(stored),
+            (DatVersion::Dat2, true) => Box::new(ZlibDecoder::new(stored).take(entry.size as u64)),
+            (DatVersion::Dat1, true) => {
+                Box::new(Cursor::new(lzss_decompress(stored, entry.size as usize)?))
+            }
+        })
+    }
+}
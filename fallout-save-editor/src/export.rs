@@ -0,0 +1,325 @@
+/// JSON exports of saves for other tools, and the JSON Schemas they follow.
+///
+/// Exports are types of their own instead of the parser's, the parser can change without breaking
+/// whatever reads them. The schemas are published under `schemas`, a test keeps them in sync.
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::{
+    parser::{MapFlags, MapHeader, MapVariables, SaveHeader, Script, ScriptTagType},
+    sfall::{self, SfallGlobals},
+    time::{self, GameTime},
+};
+
+const SCHEMA_DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Names of the script types in the export
+const SCRIPT_TYPES: [&str; 6] = [
+    "system", "spatial", "items", "scenery", "critters", "unknown",
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Date {
+    pub year: u16,
+    pub month: u16,
+    pub day: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SaveExport {
+    pub version: u32,
+    pub character_name: String,
+    pub save_name: String,
+    /// Real world date the game was saved on
+    pub saved: Date,
+    pub game_date: Date,
+    pub game_ticks: u32,
+    /// In-game date and time of the ticks, e.g. `2242-06-13 13:08`
+    pub game_time: String,
+    /// How long the game had gone on, e.g. `323 days, 13 hours and 8 minutes`
+    pub elapsed: String,
+    pub current_map: u32,
+    pub map_name: String,
+    /// Globals of the slot's `sfallgv.sav`, left out when it has none
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sfall_globals: Option<Vec<SfallGlobalExport>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SfallGlobalExport {
+    /// Name given to `set_sfall_global`, e.g. `KILLCNT`, or the number of one set by number
+    pub name: String,
+    pub value: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScriptExport {
+    pub id: i32,
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    /// Index of the script's first variable in the map's local variables
+    pub local_variable_offset: i32,
+    pub local_variable_count: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MapExport {
+    /// 19 for Fallout, 20 for Fallout 2. Others aren't known and are read as 20
+    pub version: u32,
+    pub filename: String,
+    pub player_position: i32,
+    pub player_elevation: i32,
+    pub player_orientation: i32,
+    pub script_id: i32,
+    /// Which of the three elevations the map has tiles for
+    pub elevations: [bool; 3],
+    pub darkness: i32,
+    pub map_id: i32,
+    pub ticks: u32,
+    /// In-game date and time the map was left, e.g. `2242-06-13 13:08`
+    pub game_time: String,
+    pub global_variables: Vec<i32>,
+    pub local_variables: Vec<i32>,
+    pub scripts: Vec<ScriptExport>,
+}
+
+fn script_type(script_type: ScriptTagType) -> &'static str {
+    match script_type {
+        ScriptTagType::System => SCRIPT_TYPES[0],
+        ScriptTagType::Spatial => SCRIPT_TYPES[1],
+        ScriptTagType::Items => SCRIPT_TYPES[2],
+        ScriptTagType::Scenery => SCRIPT_TYPES[3],
+        ScriptTagType::Critters => SCRIPT_TYPES[4],
+        ScriptTagType::Unknown => SCRIPT_TYPES[5],
+    }
+}
+
+impl From<&SaveHeader> for SaveExport {
+    fn from(header: &SaveHeader) -> SaveExport {
+        SaveExport {
+            version: header.version,
+            character_name: header.name.clone(),
+            save_name: header.save_name.clone(),
+            saved: Date {
+                year: header.save_year,
+                month: header.save_month,
+                day: header.save_day,
+            },
+            game_date: Date {
+                year: header.ingame_year,
+                month: header.ingame_month,
+                day: header.ingame_day,
+            },
+            game_ticks: header.ingame_ticks,
+            game_time: GameTime::from_ticks(header.ingame_ticks).to_string(),
+            elapsed: time::elapsed(header.ingame_ticks),
+            current_map: header.current_map,
+            map_name: header.map_name.clone(),
+            sfall_globals: None,
+        }
+    }
+}
+
+impl SaveExport {
+    /// The export with the globals of the slot's `sfallgv.sav`.
+    pub fn with_sfall_globals(mut self, globals: &SfallGlobals) -> SaveExport {
+        self.sfall_globals = Some(
+            globals
+                .globals
+                .iter()
+                .map(|&(id, value)| SfallGlobalExport {
+                    name: sfall::global_name(id),
+                    value,
+                })
+                .collect(),
+        );
+        self
+    }
+}
+
+impl MapExport {
+    pub fn new(header: &MapHeader, variables: &MapVariables, scripts: &[Script]) -> MapExport {
+        MapExport {
+            version: header.version.clone() as u32,
+            filename: header.filename.clone(),
+            player_position: header.default_player_position,
+            player_elevation: header.default_player_elevation,
+            player_orientation: header.default_player_orientation,
+            script_id: header.script_id,
+            elevations: [
+                MapFlags::HasElevationAtLevel0,
+                MapFlags::HasElevationAtLevel1,
+                MapFlags::HasElevationAtLevel2,
+            ]
+            .map(|elevation| header.flags.contains(elevation)),
+            darkness: header.darkness,
+            map_id: header.id,
+            ticks: header.ticks,
+            game_time: GameTime::from_ticks(header.ticks).to_string(),
+            global_variables: variables.global_variables.clone(),
+            local_variables: variables.local_variables.clone(),
+            scripts: scripts
+                .iter()
+                .map(|script| ScriptExport {
+                    id: script.id,
+                    kind: script_type(script.script_type),
+                    local_variable_offset: script.local_variable_offset,
+                    local_variable_count: script.local_variable_count,
+                })
+                .collect(),
+        }
+    }
+}
+
+fn date_schema(description: &str) -> Value {
+    json!({
+        "type": "object",
+        "description": description,
+        "properties": {
+            "year": { "type": "integer" },
+            "month": { "type": "integer" },
+            "day": { "type": "integer" },
+        },
+        "required": ["year", "month", "day"],
+        "additionalProperties": false,
+    })
+}
+
+/// JSON Schema of `SaveExport`, published as `schemas/save.schema.json`.
+pub fn save_schema() -> Value {
+    json!({
+        "$schema": SCHEMA_DRAFT,
+        "title": "Fallout save header",
+        "description": "SAVE.DAT header of a save slot, printed by fallout-save-editor export",
+        "type": "object",
+        "properties": {
+            "version": { "type": "integer" },
+            "character_name": { "type": "string" },
+            "save_name": { "type": "string" },
+            "saved": date_schema("Real world date the game was saved on"),
+            "game_date": date_schema("In-game date"),
+            "game_ticks": { "type": "integer", "minimum": 0 },
+            "game_time": {
+                "type": "string",
+                "description": "In-game date and time of game_ticks, e.g. 2242-06-13 13:08",
+            },
+            "elapsed": {
+                "type": "string",
+                "description": "How long the game had gone on, e.g. 323 days, 13 hours and 8 \
+                                minutes",
+            },
+            "current_map": { "type": "integer", "minimum": 0 },
+            "map_name": { "type": "string", "description": "Map save file, e.g. NCRENT.sav" },
+            "sfall_globals": {
+                "type": "array",
+                "description": "Globals of the slot's sfallgv.sav, left out when it has none",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "name": {
+                            "type": "string",
+                            "description": "Name given to set_sfall_global, e.g. KILLCNT, or \
+                                            the number of one set by number",
+                        },
+                        "value": { "type": "integer" },
+                    },
+                    "required": ["name", "value"],
+                    "additionalProperties": false,
+                },
+            },
+        },
+        "required": [
+            "version",
+            "character_name",
+            "save_name",
+            "saved",
+            "game_date",
+            "game_ticks",
+            "game_time",
+            "elapsed",
+            "current_map",
+            "map_name",
+        ],
+        "additionalProperties": false,
+    })
+}
+
+/// JSON Schema of `MapExport`, published as `schemas/map-save.schema.json`.
+pub fn map_schema() -> Value {
+    let integers = |description: &str| {
+        json!({
+            "type": "array",
+            "items": { "type": "integer" },
+            "description": description,
+        })
+    };
+    json!({
+        "$schema": SCHEMA_DRAFT,
+        "title": "Fallout map save",
+        "description": "Map save in a save slot, e.g. NCR1.SAV, printed by \
+                        fallout-save-editor export <map>",
+        "type": "object",
+        "properties": {
+            "version": {
+                "type": "integer",
+                "description": "19 for Fallout, 20 for Fallout 2. Others aren't known and are read as 20"
+            },
+            "filename": { "type": "string" },
+            "player_position": { "type": "integer" },
+            "player_elevation": { "type": "integer" },
+            "player_orientation": { "type": "integer" },
+            "script_id": { "type": "integer" },
+            "elevations": {
+                "type": "array",
+                "items": { "type": "boolean" },
+                "minItems": 3,
+                "maxItems": 3,
+                "description": "Which of the three elevations the map has tiles for",
+            },
+            "darkness": { "type": "integer" },
+            "map_id": { "type": "integer" },
+            "ticks": { "type": "integer", "minimum": 0 },
+            "game_time": {
+                "type": "string",
+                "description": "In-game date and time the map was left, e.g. 2242-06-13 13:08",
+            },
+            "global_variables": integers("Map variables"),
+            "local_variables": integers("Variables of the scripts, see local_variable_offset"),
+            "scripts": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "id": { "type": "integer" },
+                        "type": { "enum": SCRIPT_TYPES },
+                        "local_variable_offset": {
+                            "type": "integer",
+                            "description": "Index of the script's first variable in \
+                                            local_variables",
+                        },
+                        "local_variable_count": { "type": "integer" },
+                    },
+                    "required": ["id", "type", "local_variable_offset", "local_variable_count"],
+                    "additionalProperties": false,
+                },
+            },
+        },
+        "required": [
+            "version",
+            "filename",
+            "player_position",
+            "player_elevation",
+            "player_orientation",
+            "script_id",
+            "elevations",
+            "darkness",
+            "map_id",
+            "ticks",
+            "game_time",
+            "global_variables",
+            "local_variables",
+            "scripts",
+        ],
+        "additionalProperties": false,
+    })
+}
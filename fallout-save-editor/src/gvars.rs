@@ -0,0 +1,136 @@
+/// Global variables, GVARs, of a save compared against their defaults in `vault13.gam`. Most of
+/// the hundreds of globals stay at their defaults the whole game, the ones that don't tell what
+/// has happened in it.
+///
+/// `vault13.gam` is in master.dat as `data\vault13.gam`, a text file of `NAME :=value;` lines:
+///
+/// ```text
+/// GAME_GLOBAL_VARS:
+/// GVAR_PLAYER_REPUTATION          :=0;    //  (0)
+/// GVAR_CHILDKILLER_REPUTATION     :=0;    //  (1)
+/// ```
+///
+/// The save doesn't have the number of globals, it's however many `vault13.gam` has. Right after
+/// them is the list of map saves in the slot, a count and names like `ARBRIDGE.SAV`.
+use std::io;
+
+/// Save header of 30051 bytes and the player's 4 byte combat id
+pub const GLOBAL_VARIABLE_START: usize = 0x7567;
+
+/// More globals than any game or mod has, for giving up on finding the map list
+const MAX_GLOBALS: usize = 10_000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlobalVariable {
+    pub name: String,
+    pub default: i32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Deviation {
+    pub index: usize,
+    pub name: String,
+    pub default: i32,
+    pub value: i32,
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Globals of a `.gam` file in order, their index is their number in scripts.
+pub fn parse_gam(text: &str) -> io::Result<Vec<GlobalVariable>> {
+    let mut globals = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        let line = line.split("//").next().unwrap_or_default().trim();
+        let Some((name, value)) = line.split_once(":=") else {
+            continue;
+        };
+        let value = value.trim().trim_end_matches(';').trim();
+        let default = value.parse().map_err(|_| {
+            invalid_data(format!(
+                "Line {}: {value:?} isn't a number for {}",
+                number + 1,
+                name.trim()
+            ))
+        })?;
+        globals.push(GlobalVariable {
+            name: name.trim().to_string(),
+            default,
+        });
+    }
+    Ok(globals)
+}
+
+fn read_i32(data: &[u8], offset: usize) -> Option<i32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Whether the list of map saves starts at `offset`: a count and the first name ending in `.SAV`.
+fn is_map_list(save: &[u8], offset: usize) -> bool {
+    let Some(count) = read_i32(save, offset) else {
+        return false;
+    };
+    let name = save
+        .get(offset + 4..)
+        .and_then(|rest| rest.split(|&byte| byte == 0).next())
+        .unwrap_or_default();
+    count > 0 && name.len() <= 16 && name.to_ascii_uppercase().ends_with(b".SAV")
+}
+
+/// How many globals the save has, found by looking for the map list after them.
+pub fn count_in_save(save: &[u8]) -> Option<usize> {
+    (0..MAX_GLOBALS).find(|&count| is_map_list(save, GLOBAL_VARIABLE_START + count * 4))
+}
+
+/// Values of the first `count` globals in SAVE.DAT. Fails when the map list doesn't follow them,
+/// the `.gam` file is then for a different game or version of a mod.
+pub fn global_variables(save: &[u8], count: usize) -> io::Result<Vec<i32>> {
+    if !is_map_list(save, GLOBAL_VARIABLE_START + count * 4) {
+        let found = match count_in_save(save) {
+            Some(found) => format!("the save has {found}"),
+            None => "the save's globals couldn't be found".to_string(),
+        };
+        return Err(invalid_data(format!(
+            "The .gam file has {count} global variables but {found}"
+        )));
+    }
+    Ok((0..count)
+        .filter_map(|i| read_i32(save, GLOBAL_VARIABLE_START + i * 4))
+        .collect())
+}
+
+/// Globals with a value other than their default, in order.
+pub fn deviations(globals: &[GlobalVariable], values: &[i32]) -> Vec<Deviation> {
+    globals
+        .iter()
+        .zip(values)
+        .enumerate()
+        .filter(|(_, (global, value))| global.default != **value)
+        .map(|(index, (global, value))| Deviation {
+            index,
+            name: global.name.clone(),
+            default: global.default,
+            value: *value,
+        })
+        .collect()
+}
+
+/// One line per global, e.g. `  15  GVAR_ENEMY_ARROYO  0 -> 1`.
+pub fn render(deviations: &[Deviation]) -> String {
+    let width = deviations
+        .iter()
+        .map(|deviation| deviation.name.len())
+        .max()
+        .unwrap_or(0);
+    deviations
+        .iter()
+        .map(|deviation| {
+            format!(
+                "{:>4}  {:<width$}  {} -> {}\n",
+                deviation.index, deviation.name, deviation.default, deviation.value
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,144 @@
+/// Index of the slots in a save directory, so listing a large collection of saves only parses the
+/// headers that changed since the last run. Kept next to the slots as JSON with the size and
+/// modification time of each `SAVE.DAT` and the parts of its header the listings show.
+///
+/// Only the header is read from a changed save, the rest of `SAVE.DAT` isn't needed for listing.
+use std::{
+    collections::BTreeMap,
+    fs::{self, File},
+    io::{self, Read},
+    path::Path,
+    time::SystemTime,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{header, SaveHeader};
+
+pub const INDEX_FILE: &str = ".fallout-save-editor-index.json";
+
+/// Bytes of `SAVE.DAT` the header takes
+const HEADER_SIZE: u64 = 30051;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SlotSummary {
+    /// SAVE.DAT version, for warning about ones the parser doesn't know
+    pub version: u32,
+    pub save_name: String,
+    pub save_year: u16,
+    pub save_month: u16,
+    pub save_day: u16,
+    pub ingame_ticks: u32,
+    pub map_name: String,
+}
+
+impl From<&SaveHeader> for SlotSummary {
+    fn from(header: &SaveHeader) -> SlotSummary {
+        SlotSummary {
+            version: header.version,
+            save_name: header.save_name.clone(),
+            save_year: header.save_year,
+            save_month: header.save_month,
+            save_day: header.save_day,
+            ingame_ticks: header.ingame_ticks,
+            map_name: header.map_name.clone(),
+        }
+    }
+}
+
+/// What tells a `SAVE.DAT` apart from the one indexed, the game rewrites the whole file on save
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stamp {
+    pub size: u64,
+    pub modified: SystemTime,
+}
+
+impl Stamp {
+    pub fn of(path: &Path) -> io::Result<Stamp> {
+        let metadata = fs::metadata(path)?;
+        Ok(Stamp {
+            size: metadata.len(),
+            modified: metadata.modified()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct IndexedSlot {
+    stamp: Stamp,
+    summary: SlotSummary,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SlotIndex {
+    /// By slot directory name, e.g. `SLOT01`
+    slots: BTreeMap<String, IndexedSlot>,
+    #[serde(skip)]
+    changed: bool,
+}
+
+impl SlotIndex {
+    /// The index at the path, or an empty one when there's none yet. An index that can't be read
+    /// is only a cache and is started over.
+    pub fn load(path: &Path) -> io::Result<SlotIndex> {
+        match fs::read(path) {
+            Ok(data) => Ok(serde_json::from_slice(&data).unwrap_or_default()),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(SlotIndex::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Writes the index if a slot was added, changed or forgotten since it was loaded.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if !self.changed {
+            return Ok(());
+        }
+        let json = serde_json::to_vec_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, json)
+    }
+
+    /// Summary of the slot's `SAVE.DAT` if the index has one for a file with the same stamp.
+    pub fn cached(&self, slot: &str, stamp: &Stamp) -> Option<&SlotSummary> {
+        self.slots
+            .get(slot)
+            .filter(|indexed| indexed.stamp == *stamp)
+            .map(|indexed| &indexed.summary)
+    }
+
+    /// Summary of the slot's `SAVE.DAT`, reading and parsing its header only when the file changed
+    /// since it was indexed. Saves that don't parse are left out of the index and tried again the
+    /// next time.
+    pub fn summary(&mut self, slot: &str, save: &Path) -> io::Result<Option<SlotSummary>> {
+        let stamp = Stamp::of(save)?;
+        if let Some(summary) = self.cached(slot, &stamp) {
+            return Ok(Some(summary.clone()));
+        }
+
+        let mut data = Vec::new();
+        File::open(save)?.take(HEADER_SIZE).read_to_end(&mut data)?;
+        let Ok((_, header)) = header(&data) else {
+            self.forget(slot);
+            return Ok(None);
+        };
+        let summary = SlotSummary::from(&header);
+        self.slots.insert(
+            slot.to_string(),
+            IndexedSlot {
+                stamp,
+                summary: summary.clone(),
+            },
+        );
+        self.changed = true;
+        Ok(Some(summary))
+    }
+
+    /// Drops the slot, e.g. one that was deleted.
+    pub fn forget(&mut self, slot: &str) {
+        self.changed |= self.slots.remove(slot).is_some();
+    }
+
+    /// Slots in the index, sorted by name.
+    pub fn slots(&self) -> impl Iterator<Item = &str> {
+        self.slots.keys().map(String::as_str)
+    }
+}
@@ -1,2 +1,16 @@
+pub mod annotate;
+pub mod dat;
+pub mod export;
+pub mod gvars;
+pub mod index;
 pub mod parser;
+pub mod patch;
+pub mod preset;
+pub mod serve;
+pub mod session;
+pub mod sfall;
+pub mod slot;
+pub mod time;
 pub mod ui;
+pub mod version;
+pub mod xref;
@@ -1,6 +1,8 @@
+use std::process::ExitCode;
+
 use fallout_save_editor::ui::run_terminal_ui;
 
-fn main() {
+fn main() -> ExitCode {
     // run_ui();
-    run_terminal_ui();
+    run_terminal_ui()
 }
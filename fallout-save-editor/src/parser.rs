@@ -9,9 +9,9 @@ use flate2::read::GzDecoder;
 // TODO(tatu): implement wrapper type that understands binary offsets/spans per field.
 // TODO(tatu): implement wrapper type that preserves original binary and provides better view
 use nom::{
-    bytes::streaming::{take, take_until},
-    combinator::{flat_map, map},
-    error::ErrorKind,
+    bytes::streaming::take,
+    combinator::{flat_map, map, map_res},
+    error::{Error, ErrorKind},
     multi::{count, fold_many_m_n},
     number::streaming::{be_i32, be_u16, be_u32, be_u8},
     sequence::tuple,
@@ -21,11 +21,11 @@ use nom::{
 use bitflags::bitflags;
 
 use core::fmt;
-use std::io::Read;
+use std::io::{self, Read};
 use std::str;
 
-const SCRIPT_GROUP_COUNT: usize = 5;
-const SCRIPTS_IN_GROUP: usize = 16;
+pub const SCRIPT_GROUP_COUNT: usize = 5;
+pub const SCRIPTS_IN_GROUP: usize = 16;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum MapVersion {
@@ -76,19 +76,21 @@ pub struct DatFile {
 // length of an ASCII string is 32, it can contain 31 characters with 0x0 at the end.
 //
 // Parser will try to consume the requested size and the resulting string will only contain data up
-// to the first null terminator.
+// to the first null terminator, or the whole field if there's none.
 pub fn ascii_string(size: usize) -> impl Fn(&[u8]) -> IResult<&[u8], String> {
     move |input| {
-        flat_map(take_until("\0"), |string_bytes: &[u8]| {
-            map(take(size - string_bytes.len()), |_| {
-                str::from_utf8(string_bytes)
-                    .expect("expected a valid fallout ascii string")
-                    .to_string()
-            })
+        map_res(take(size), |field: &[u8]| {
+            let end = field.iter().position(|&b| b == 0).unwrap_or(field.len());
+            str::from_utf8(&field[..end]).map(str::to_string)
         })(input)
     }
 }
 
+/// Error at `input` for values nom itself has no complaints about, like negative counts.
+fn invalid<T>(input: &[u8]) -> IResult<&[u8], T> {
+    Err(nom::Err::Error(Error::new(input, ErrorKind::Verify)))
+}
+
 pub fn save_name(input: &[u8]) -> IResult<&[u8], String> {
     ascii_string(30)(input)
 }
@@ -99,7 +101,7 @@ pub fn map_name(input: &[u8]) -> IResult<&[u8], String> {
 
 // On Steam Windows there's some extra 6 bytes of crap after the 18 byte header.
 pub fn header(input: &[u8]) -> IResult<&[u8], SaveHeader> {
-    map(
+    map_res(
         tuple((
             take(18u32),
             take(6u32),
@@ -140,10 +142,9 @@ pub fn header(input: &[u8]) -> IResult<&[u8], SaveHeader> {
             bitmap,
             void,
         )| {
-            SaveHeader {
-                magic: str::from_utf8(magic)
-                    .expect("'FALLOUT SAVE FILE ' header magic ascii text expected")
-                    .to_string(),
+            // 'FALLOUT SAVE FILE ' header magic ascii text expected
+            str::from_utf8(magic).map(|magic| SaveHeader {
+                magic: magic.to_string(),
                 version,
                 release_type,
                 name,
@@ -160,7 +161,7 @@ pub fn header(input: &[u8]) -> IResult<&[u8], SaveHeader> {
                 map_name,
                 bitmap: bitmap.to_vec(),
                 void: void.to_vec(),
-            }
+            })
         },
     )(input)
 }
@@ -248,7 +249,7 @@ pub struct Script {
 
 // maps are laid out on 100x100 grid for both the floor and the roof. Each tile is 2 bytes. Floor
 // and roof tiles alternate in the sequence.
-fn tile_size_in_bytes(map_flags: &MapFlags) -> u32 {
+pub fn tile_size_in_bytes(map_flags: &MapFlags) -> u32 {
     let mut bytes = 0;
 
     // FIXME(tatu): I probably have a bug somewhere else but for some reason it seems like these
@@ -272,15 +273,10 @@ fn tile_size_in_bytes(map_flags: &MapFlags) -> u32 {
 }
 
 fn map_flags(input: &[u8]) -> IResult<&[u8], MapFlags> {
-    map(be_u32, |raw_flags| {
+    map_res(be_u32, |raw_flags| {
         // Having 0 flags is troublesome for bitflags. This is probably overthinking. We need to
         // flip all the other bits but LSB. This breaks binary compatibility.
-        MapFlags::from_bits(
-            (raw_flags ^ 0xE)
-                .try_into()
-                .expect("flags should not overflow"),
-        )
-        .expect("should have parsed map flags")
+        i32::try_from(raw_flags ^ 0xE).map(MapFlags::from_bits_retain)
     })(input)
 }
 
@@ -303,19 +299,16 @@ pub fn map_variable_values(
 }
 
 fn map_version(input: &[u8]) -> IResult<&[u8], MapVersion> {
-    map(be_u32, |version| {
-        // Having 0 flags is troublesome for bitflags. This is probably overthinking. We need to
-        // flip all the other bits but LSB. This breaks binary compatibility.
-
-        // FIXME(tatu): this man loves unwrapping
-        MapVersion::try_from(version).expect("should have parsed map version")
-    })(input)
+    map_res(be_u32, MapVersion::try_from)(input)
 }
 
+/// Same as `try_map_save`, panicking on saves it can't parse.
 pub fn map_save(input: &[u8]) -> (MapHeader, MapVariables, Vec<Script>) {
-    let start = input.len();
-    println!("starting from {start}");
-    let header = map(
+    try_map_save(input).expect("should have parsed map save").1
+}
+
+pub fn map_header(input: &[u8]) -> IResult<&[u8], MapHeader> {
+    map(
         tuple((
             map_version,
             map_name,
@@ -345,73 +338,65 @@ pub fn map_save(input: &[u8]) -> (MapHeader, MapVariables, Vec<Script>) {
             id,
             ticks,
             mystery_bytes,
-        )| {
-            println!("flags: {:#032b}", &flags);
-            println!("gvars: {global_variable_count}");
-            println!("lvars: {local_variable_count}");
-            MapHeader {
-                version,
-                filename,
-                default_player_position,
-                default_player_elevation,
-                default_player_orientation,
-                local_variable_count,
-                script_id,
-                flags,
-                darkness,
-                global_variable_count,
-                id,
-                ticks,
-                mystery_bytes: mystery_bytes.to_vec(),
-            }
+        )| MapHeader {
+            version,
+            filename,
+            default_player_position,
+            default_player_elevation,
+            default_player_orientation,
+            local_variable_count,
+            script_id,
+            flags,
+            darkness,
+            global_variable_count,
+            id,
+            ticks,
+            mystery_bytes: mystery_bytes.to_vec(),
         },
-    )(input);
-
-    let (input, header) = header.unwrap();
+    )(input)
+}
 
-    let global_variable_count: usize = header.global_variable_count.try_into().unwrap();
-    let local_variable_count: usize = header.local_variable_count.try_into().unwrap();
+pub fn try_map_save(input: &[u8]) -> IResult<&[u8], (MapHeader, MapVariables, Vec<Script>)> {
+    let (input, header) = map_header(input)?;
 
-    let map_variables = map_variable_values(global_variable_count, local_variable_count)(input);
-    let (input, map_variables) = map_variables.expect("should have parsed map variable values");
+    let (Ok(global_variable_count), Ok(local_variable_count)) = (
+        usize::try_from(header.global_variable_count),
+        usize::try_from(header.local_variable_count),
+    ) else {
+        return invalid(input);
+    };
 
-    println!("at variable offset {}", start - input.len());
+    let (input, map_variables) =
+        map_variable_values(global_variable_count, local_variable_count)(input)?;
 
     // Consume tiles
     // FIXME: Actually parse the tiles rather than discarding them
-    let (input, _) =
-        take::<_, _, (_, ErrorKind)>(tile_size_in_bytes(&header.flags))(input).unwrap();
+    let (input, _) = take(tile_size_in_bytes(&header.flags))(input)?;
 
     let scripts = fold_many_m_n(
         SCRIPT_GROUP_COUNT,
         SCRIPT_GROUP_COUNT,
         script_group,
         || Vec::new(),
-        |acc, scripts| {
-            let size = scripts.len();
-            let had = acc.len();
-            println!("got {size} new scripts had {had}");
-            [acc, scripts].concat()
-        },
-    )(input);
+        |acc, scripts| [acc, scripts].concat(),
+    )(input)?;
 
-    (header, map_variables, scripts.unwrap().1)
+    Ok((scripts.0, (header, map_variables, scripts.1)))
 }
 
 pub fn script_group(input: &[u8]) -> IResult<&[u8], Vec<Script>> {
     let (mut input, script_count) = be_i32(input)?;
 
-    println!("trying to parse {script_count} scripts");
     // FIXME: make a parser for script counts rather than asserting here and return a parse
     // error, rather than panic
     // assert!(
     //     script_count <= SCRIPTS_IN_GROUP,
     //     "script sections should not have more than {SCRIPTS_IN_GROUP} scripts"
     // );
-    println!("found {script_count} scripts");
 
-    // FIXME(tatu): this man loves unwraps
-    let mut script_count: usize = script_count.try_into().unwrap();
+    let Ok(mut script_count) = usize::try_from(script_count) else {
+        return invalid(input);
+    };
     let mut scripts = Vec::new();
 
     while script_count > SCRIPTS_IN_GROUP {
@@ -429,16 +414,12 @@ pub fn script_group(input: &[u8]) -> IResult<&[u8], Vec<Script>> {
         input = remaining_input;
     }
 
-    println!("{script_count} scripts left after parsing");
-
     let (input, mut new_scripts) = map(count(script, script_count), |scripts| scripts)(input)?;
     scripts.append(&mut new_scripts);
 
     let input = if script_count > 0 {
         let remaining_block = SCRIPTS_IN_GROUP - script_count;
 
-        println!("{remaining_block} junk left");
-
         let (input, _) = tuple((
             count(read_script_block_junk, remaining_block),
             take(8u32), // script check counter and possible crc check
@@ -448,18 +429,11 @@ pub fn script_group(input: &[u8]) -> IResult<&[u8], Vec<Script>> {
         input
     };
 
-    let curr = input.len();
-
-    println!("at offset {curr}");
-
     Ok((input, scripts))
 }
 
 pub fn read_script_block_junk(input: &[u8]) -> IResult<&[u8], &[u8]> {
     flat_map(script_type_tag, |script_type_tag| {
-        let junk_size = script_type_tag.junk_size();
-        println!("reading junk rec {:?}", script_type_tag);
-        println!("reading junk {junk_size}");
         // FIXME(tatu): record sizes include the size and we've consumed it already, so substract 4
         // bytes. This is confusing as fuck. Make something better once everything works.
         take(script_type_tag.junk_size() - 4)
@@ -548,13 +522,11 @@ impl fmt::Display for UnknownScriptSizeType {
 
 pub fn script_type_tag(input: &[u8]) -> IResult<&[u8], ScriptTagType> {
     map(be_u32, |script_tag_raw| {
-        // FIXME(tatu): Don't unwrap, map the error to nom error
         // FIXME(tatu): Find out what type this is, seems like a PID
         //
         // This type is not really defined well anywhere. It seems like PID but PID values are
         // different.
-        println!("got {}", (script_tag_raw as i32) >> 24);
-        ScriptTagType::try_from(script_tag_raw >> 24).unwrap()
+        ScriptTagType::try_from(script_tag_raw >> 24).unwrap_or(ScriptTagType::Unknown)
     })(input)
 }
 
@@ -562,15 +534,17 @@ pub fn script(input: &[u8]) -> IResult<&[u8], Script> {
     // FIXME(tatu): We should peek script tag type and then parse the whole record as its own
     // buffer. All the offset calculations are now super confusing as we've consumed part of the
     // record and then carry that in all calculations.
+    let (_, tag) = script_type_tag(input)?;
+    if tag.byte_offset().is_err() {
+        return invalid(input);
+    }
+
     flat_map(script_type_tag, |script_type_tag| {
-        let offset = input.len();
-        println!("at scripts offset {:?}", offset);
-        let record_size = script_type_tag.byte_offset().unwrap();
+        let record_size = script_type_tag.byte_offset().unwrap_or_default();
         // TODO(tatu): Kinda bad as we need to keep this 20 bytes in sync with what we've read. I
         // think a better option is to slice the input at record size, parse that while discarding
         // the rest and then manually advance the input buffer.
         let junk_size = record_size - (record_size - 0x38 + 20u32 + 4u32);
-        println!("script suffix junk size {:?}", junk_size);
         map(
             tuple((
                 // Another mystery byte skip from F12SE
@@ -607,19 +581,20 @@ pub fn script(input: &[u8]) -> IResult<&[u8], Script> {
     })(input)
 }
 
-pub fn try_gunzip_buffer(input: Vec<u8>) -> Vec<u8> {
-    // decompress if needed
-    if &input[..2] == &[0x1f, 0x8b] {
+/// Decompresses `input` if it's gzipped, the way saved maps usually are.
+pub fn gunzip(input: Vec<u8>) -> io::Result<Vec<u8>> {
+    if input.starts_with(&[0x1f, 0x8b]) {
         let mut decompressed: Vec<u8> = Vec::new();
         let mut decoder = GzDecoder::new(&input[..]);
-        decoder
-            .read_to_end(&mut decompressed)
-            .expect("should have decompressed dat2");
-
-        return decompressed;
+        decoder.read_to_end(&mut decompressed)?;
+        return Ok(decompressed);
     }
 
-    input
+    Ok(input)
+}
+
+pub fn try_gunzip_buffer(input: Vec<u8>) -> Vec<u8> {
+    gunzip(input).expect("should have decompressed dat2")
 }
 
 #[cfg(test)]
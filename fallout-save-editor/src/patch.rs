@@ -0,0 +1,147 @@
+/// Raw patches for when there's no command for the edit: bytes written at an offset, checked
+/// against the fields `annotate` knows about first so a typo doesn't wreck the layout of the rest
+/// of the file.
+use std::{io, ops::Range};
+
+use hexdump::Span;
+
+/// Fields the rest of the file is read by, writing over them moves everything after
+const STRUCTURAL: [&str; 7] = [
+    "magic",
+    "version",
+    "flags",
+    "local variable count",
+    "global variable count",
+    "script group",
+    "script block check",
+];
+
+/// Strings and runs of bytes, writing only some of their bytes is fine
+const FREEFORM: [&str; 9] = [
+    "character name",
+    "save name",
+    "map name",
+    "filename",
+    "thumbnail",
+    "steam padding",
+    "unused",
+    "unknown",
+    "tiles",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Problem {
+    /// The patch goes past the end of the file
+    PastEnd { end: usize, len: usize },
+    /// Only some of the field's bytes would be written, e.g. half of a variable
+    Partial(String),
+    /// A count, version or such the rest of the file is laid out by
+    Structural(String),
+}
+
+impl std::fmt::Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Problem::PastEnd { end, len } => {
+                write!(
+                    f,
+                    "the patch ends at {end:#x}, past the end of the file at {len:#x}"
+                )
+            }
+            Problem::Partial(field) => write!(f, "only part of {field} would be written"),
+            Problem::Structural(field) => {
+                write!(f, "{field} is what the rest of the file is read by")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Check {
+    /// Known fields the patch writes over, in order
+    pub fields: Vec<String>,
+    /// Bytes the patch writes that no known field covers
+    pub unknown: Vec<Range<usize>>,
+    pub problems: Vec<Problem>,
+}
+
+impl Check {
+    /// Only forcing the patch can't get past going over the end of the file.
+    pub fn can_force(&self) -> bool {
+        !self
+            .problems
+            .iter()
+            .any(|problem| matches!(problem, Problem::PastEnd { .. }))
+    }
+}
+
+/// Checks writing `range` of a file `len` bytes long against the spans of its hexdump.
+pub fn check(spans: &[Span], len: usize, range: Range<usize>) -> Check {
+    let mut check = Check::default();
+    if range.end > len {
+        check.problems.push(Problem::PastEnd {
+            end: range.end,
+            len,
+        });
+    }
+
+    for span in spans {
+        if span.range.start >= range.end || span.range.end <= range.start {
+            continue;
+        }
+        check.fields.push(span.label.clone());
+        let partial = span.range.start < range.start || span.range.end > range.end;
+        if partial && !FREEFORM.iter().any(|field| span.label.starts_with(field)) {
+            check.problems.push(Problem::Partial(span.label.clone()));
+        }
+        if STRUCTURAL.iter().any(|field| span.label.starts_with(field)) {
+            check.problems.push(Problem::Structural(span.label.clone()));
+        }
+    }
+
+    for offset in range {
+        if spans.iter().any(|span| span.range.contains(&offset)) {
+            continue;
+        }
+        match check.unknown.last_mut() {
+            Some(unknown) if unknown.end == offset => unknown.end += 1,
+            _ => check.unknown.push(offset..offset + 1),
+        }
+    }
+    check
+}
+
+/// Offset in decimal or hex, e.g. `420` or `0x1A4`.
+pub fn parse_offset(offset: &str) -> Result<usize, String> {
+    let parsed = match offset
+        .strip_prefix("0x")
+        .or_else(|| offset.strip_prefix("0X"))
+    {
+        Some(hex) => usize::from_str_radix(hex, 16),
+        None => offset.parse(),
+    };
+    parsed.map_err(|_| format!("{offset} isn't an offset, e.g. 420 or 0x1A4"))
+}
+
+/// Hex bytes, e.g. `01 00 00 02` or `01000002`.
+pub fn parse_bytes(bytes: &str) -> io::Result<Vec<u8>> {
+    let invalid = || {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{bytes:?} isn't hex bytes, e.g. \"01 00 00 02\""),
+        )
+    };
+    let mut parsed = Vec::new();
+    for group in bytes.split_whitespace() {
+        if group.len() % 2 != 0 || !group.is_ascii() {
+            return Err(invalid());
+        }
+        for i in (0..group.len()).step_by(2) {
+            parsed.push(u8::from_str_radix(&group[i..i + 2], 16).map_err(|_| invalid())?);
+        }
+    }
+    if parsed.is_empty() {
+        return Err(invalid());
+    }
+    Ok(parsed)
+}
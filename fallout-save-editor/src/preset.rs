@@ -0,0 +1,193 @@
+/// Edit presets: named lists of variable edits kept in a TOML file, for sharing fixes like the
+/// one for the NCR cops without writing code.
+///
+/// Only map variables and local variables of scripts can be set. Stats, e.g. healing the party,
+/// would need the critters of the map saves, which aren't parsed.
+///
+/// ```toml
+/// name = "Friendly NCR cops"
+/// description = "Calms down the cops in NCR downtown after Sulik drew his weapon"
+///
+/// # Local variable 5 of every NCR cop script instance, only the ones at 2 (hostile)
+/// [[edit]]
+/// map = "NCR1"
+/// script = 447
+/// local_variable = 5
+/// value = 0
+/// only_if = 2
+///
+/// # Map variable 3 of Arroyo Bridge
+/// [[edit]]
+/// map = "ARBRIDGE"
+/// map_variable = 3
+/// value = 1
+/// ```
+use std::io;
+
+use serde::Deserialize;
+
+use crate::parser::try_map_save;
+
+/// Where the map variables start in a decompressed map save, right after the header
+pub const MAP_VARIABLE_START: usize = 0x00EC;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Preset {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    #[serde(default, rename = "edit")]
+    pub edits: Vec<Edit>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Variable {
+    /// Map variable by index
+    Map(usize),
+    /// Local variable by index of every instance of the script in the map
+    Local { script: i32, index: usize },
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(try_from = "RawEdit")]
+pub struct Edit {
+    /// Map save in the slot, e.g. `NCR1`
+    pub map: String,
+    pub variable: Variable,
+    pub value: i32,
+    /// Only variables with this value are changed
+    pub only_if: Option<i32>,
+}
+
+/// An edit as written in the file, the variable is either `map_variable` or `script` and
+/// `local_variable`
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawEdit {
+    map: String,
+    map_variable: Option<usize>,
+    script: Option<i32>,
+    local_variable: Option<usize>,
+    value: i32,
+    only_if: Option<i32>,
+}
+
+impl TryFrom<RawEdit> for Edit {
+    type Error = String;
+
+    fn try_from(raw: RawEdit) -> Result<Edit, String> {
+        let variable = match (raw.map_variable, raw.script, raw.local_variable) {
+            (Some(index), None, None) => Variable::Map(index),
+            (None, Some(script), Some(index)) => Variable::Local { script, index },
+            _ => {
+                return Err(format!(
+                    "edit of {} needs either map_variable, or script and local_variable",
+                    raw.map
+                ))
+            }
+        };
+        Ok(Edit {
+            map: raw.map,
+            variable,
+            value: raw.value,
+            only_if: raw.only_if,
+        })
+    }
+}
+
+/// A variable a preset changed, `offset` is in the decompressed map save.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Change {
+    pub offset: usize,
+    pub old: i32,
+    pub new: i32,
+}
+
+impl Preset {
+    pub fn parse(text: &str) -> io::Result<Preset> {
+        toml::from_str(text).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+}
+
+fn invalid_input(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+impl Edit {
+    /// Offsets of the variables the edit targets in the decompressed map save.
+    pub fn offsets(&self, data: &[u8]) -> io::Result<Vec<usize>> {
+        let (_, (header, variables, scripts)) = try_map_save(data).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Could not parse {}", self.map),
+            )
+        })?;
+        let local_start = MAP_VARIABLE_START + variables.global_variables.len() * 4;
+
+        match self.variable {
+            Variable::Map(index) if index < variables.global_variables.len() => {
+                Ok(vec![MAP_VARIABLE_START + index * 4])
+            }
+            Variable::Map(index) => Err(invalid_input(format!(
+                "{} has {} map variables, there's no {index}",
+                header.filename,
+                variables.global_variables.len()
+            ))),
+            Variable::Local { script, index } => {
+                let instances: Vec<_> = scripts.iter().filter(|s| s.id == script).collect();
+                if instances.is_empty() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::NotFound,
+                        format!("Script {script} isn't used in {}", header.filename),
+                    ));
+                }
+                instances
+                    .into_iter()
+                    .map(|instance| {
+                        let count = usize::try_from(instance.local_variable_count).unwrap_or(0);
+                        let offset = usize::try_from(instance.local_variable_offset)
+                            .ok()
+                            .filter(|_| index < count)
+                            .ok_or_else(|| {
+                                invalid_input(format!(
+                                    "Script {script} has {count} local variables, there's no {index}"
+                                ))
+                            })?;
+                        Ok(local_start + (offset + index) * 4)
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Variables the edit would change in the decompressed map save, leaving out the ones that
+    /// already have the value or don't match `only_if`.
+    pub fn changes(&self, data: &[u8]) -> io::Result<Vec<Change>> {
+        let mut changes = Vec::new();
+        for offset in self.offsets(data)? {
+            let bytes = data
+                .get(offset..offset + 4)
+                .ok_or_else(|| invalid_input(format!("{} ends before {offset:#x}", self.map)))?;
+            let old = i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+            if old != self.value && self.only_if.is_none_or(|only_if| only_if == old) {
+                changes.push(Change {
+                    offset,
+                    old,
+                    new: self.value,
+                });
+            }
+        }
+        Ok(changes)
+    }
+}
+
+impl std::fmt::Display for Variable {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Variable::Map(index) => write!(f, "map variable {index}"),
+            Variable::Local { script, index } => {
+                write!(f, "script {script} local variable {index}")
+            }
+        }
+    }
+}
@@ -0,0 +1,401 @@
+/// Read-only web viewer of the slots in a save directory, for browsing saves from a phone or
+/// another machine on the LAN. Pages are plain HTML rendered from the JSON exports, the exports
+/// themselves are served next to them:
+///
+/// - `/` the slots
+/// - `/SLOT01` the save header, global variables and map saves of a slot
+/// - `/SLOT01/save.json` the save header export
+/// - `/SLOT01/NCR1` a map save's variables and scripts, `/SLOT01/NCR1.json` its export
+///
+/// There's no character sheet: the save name and the character's name come from the header, but
+/// the stats, skills and perks come after the player object in SAVE.DAT. How long the object is
+/// depends on the inventory, and how long each item is depends on its type in the item protos
+/// in master.dat, so it can't be skipped from the save alone.
+///
+/// Nothing is ever written. Requests are handled one at a time, it's for one person looking at
+/// their saves. A client gets a deadline for its whole connection and a limit on how much it can
+/// send, so one that trickles bytes or never ends its headers can't hold up the others.
+use std::{
+    fs,
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use serde_json::Value;
+
+use crate::{
+    export::{MapExport, SaveExport},
+    gvars,
+    parser::{gunzip, header},
+    sfall, slot,
+    version::{self, Support},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+/// How long a client gets for sending its request and reading the response
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Longest request line and headers read, there's no body to a GET
+const MAX_REQUEST: u64 = 8 * 1024;
+
+const HTML: &str = "text/html; charset=utf-8";
+const JSON: &str = "application/json";
+
+impl Response {
+    fn html(title: &str, body: &str) -> Response {
+        Response {
+            status: 200,
+            content_type: HTML,
+            body: format!(
+                "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+                 <meta name=\"viewport\" content=\"width=device-width\">\n\
+                 <title>{title}</title>\n</head>\n<body>\n{body}</body>\n</html>\n",
+                title = escape(title)
+            ),
+        }
+    }
+
+    fn json(value: &impl serde::Serialize) -> Response {
+        Response {
+            status: 200,
+            content_type: JSON,
+            body: serde_json::to_string_pretty(value).unwrap_or_default(),
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Response {
+        Response {
+            status,
+            content_type: "text/plain; charset=utf-8",
+            body: format!("{message}\n"),
+        }
+    }
+
+    fn not_found() -> Response {
+        Response::error(404, "Not found")
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            431 => "Request Header Fields Too Large",
+            _ => "Internal Server Error",
+        }
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Slot and map names are only ever letters, digits and such, never paths.
+fn is_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Paragraph explaining an unknown version, empty for known ones.
+fn warning(support: &Support, file: &str) -> String {
+    support
+        .warning(file)
+        .map(|warning| format!("<p><strong>{}</strong></p>\n", escape(&warning)))
+        .unwrap_or_default()
+}
+
+/// Scalars as is, arrays of scalars on one line, objects and arrays of objects as tables.
+fn render_value(value: &Value) -> String {
+    match value {
+        Value::Object(fields) => {
+            let rows: String = fields
+                .iter()
+                .map(|(key, value)| {
+                    format!(
+                        "<tr><th>{}</th><td>{}</td></tr>\n",
+                        escape(key),
+                        render_value(value)
+                    )
+                })
+                .collect();
+            format!("<table>\n{rows}</table>")
+        }
+        Value::Array(items) if items.iter().all(Value::is_object) && !items.is_empty() => {
+            let columns: Vec<&String> = items[0]
+                .as_object()
+                .map_or(Vec::new(), |fields| fields.keys().collect());
+            let head: String = columns
+                .iter()
+                .map(|column| format!("<th>{}</th>", escape(column)))
+                .collect();
+            let rows: String = items
+                .iter()
+                .map(|item| {
+                    let cells: String = columns
+                        .iter()
+                        .map(|column| format!("<td>{}</td>", render_value(&item[column.as_str()])))
+                        .collect();
+                    format!("<tr>{cells}</tr>\n")
+                })
+                .collect();
+            format!("<table>\n<tr>{head}</tr>\n{rows}</table>")
+        }
+        Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(render_value).collect();
+            items.join(", ")
+        }
+        Value::String(text) => escape(text),
+        other => other.to_string(),
+    }
+}
+
+fn slots(saves: &Path) -> io::Result<Vec<String>> {
+    let mut slots: Vec<String> = fs::read_dir(saves)?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().join("SAVE.DAT").is_file())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .filter(|name| is_name(name))
+        .collect();
+    slots.sort();
+    Ok(slots)
+}
+
+fn index(saves: &Path) -> io::Result<Response> {
+    let mut rows = String::new();
+    for name in slots(saves)? {
+        let data = fs::read(saves.join(&name).join("SAVE.DAT"))?;
+        let description = match header(&data) {
+            Ok((_, header)) => {
+                let save = SaveExport::from(&header);
+                format!(
+                    "{}, {} in {}",
+                    escape(&save.save_name),
+                    save.game_time,
+                    escape(&save.map_name)
+                )
+            }
+            Err(_) => "could not be read".to_string(),
+        };
+        rows.push_str(&format!(
+            "<li><a href=\"/{name}\">{name}</a> {description}</li>\n"
+        ));
+    }
+    Ok(Response::html(
+        "Saves",
+        &format!("<h1>Saves</h1>\n<ul>\n{rows}</ul>\n"),
+    ))
+}
+
+fn slot_page(saves: &Path, name: &str, json: bool) -> io::Result<Response> {
+    let slot = saves.join(name);
+    let data = fs::read(slot.join("SAVE.DAT"))?;
+    let Ok((_, header)) = header(&data) else {
+        return Ok(Response::error(
+            500,
+            &format!("Could not parse {name}/SAVE.DAT"),
+        ));
+    };
+    let save = match sfall::globals(&slot)? {
+        Some(globals) => SaveExport::from(&header).with_sfall_globals(&globals),
+        None => SaveExport::from(&header),
+    };
+    if json {
+        return Ok(Response::json(&save));
+    }
+
+    let support = version::save(&header);
+    let globals = match gvars::count_in_save(&data) {
+        _ if support.unavailable.contains(&version::GLOBAL_VARIABLES) => {
+            "<p>Not available for the save's version</p>\n".to_string()
+        }
+        Some(count) => {
+            let values = gvars::global_variables(&data, count)?;
+            let values: Vec<String> = values.iter().map(i32::to_string).collect();
+            format!("<p>{}</p>\n", values.join(", "))
+        }
+        None => "<p>Could not be found</p>\n".to_string(),
+    };
+    let maps: String = slot::map_saves(&slot)?
+        .iter()
+        .map(|path| {
+            let map = slot::map_name(path);
+            format!("<li><a href=\"/{name}/{map}\">{map}</a></li>\n")
+        })
+        .collect();
+
+    let body = format!(
+        "<p><a href=\"/\">Saves</a></p>\n<h1>{name}: {title}</h1>\n\
+         {warning}<p><a href=\"/{name}/save.json\">JSON</a></p>\n{header}\n\
+         <h2>Global variables</h2>\n{globals}<h2>Maps</h2>\n<ul>\n{maps}</ul>\n",
+        title = escape(&save.save_name),
+        warning = warning(&support, &format!("{name}/SAVE.DAT")),
+        header = render_value(&serde_json::to_value(&save)?),
+    );
+    Ok(Response::html(
+        &format!("{name}: {}", save.save_name),
+        &body,
+    ))
+}
+
+fn map_page(saves: &Path, name: &str, map: &str, json: bool) -> io::Result<Response> {
+    let path = match slot::map_save_path(&saves.join(name), map) {
+        Ok(path) => path,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Response::not_found()),
+        Err(error) => return Err(error),
+    };
+    let data = gunzip(fs::read(&path)?)?;
+    let (support, (header, variables, scripts)) = match version::try_map_save(&data) {
+        Ok(parsed) => parsed,
+        Err(error) => return Ok(Response::error(500, &format!("{name}/{map}: {error}"))),
+    };
+    let mut export = MapExport::new(&header, &variables, &scripts);
+    export.version = support.version;
+    if json {
+        return Ok(Response::json(&export));
+    }
+
+    let map = map.to_uppercase();
+    let body = format!(
+        "<p><a href=\"/\">Saves</a> / <a href=\"/{name}\">{name}</a></p>\n<h1>{map}</h1>\n\
+         {}<p><a href=\"/{name}/{map}.json\">JSON</a></p>\n{}\n",
+        warning(&support, &format!("{name}/{map}")),
+        render_value(&serde_json::to_value(&export)?)
+    );
+    Ok(Response::html(&format!("{name}: {map}"), &body))
+}
+
+/// Response to a GET of the path, e.g. `/SLOT01/NCR1`, with `saves` the directory of slots.
+pub fn route(saves: &Path, path: &str) -> Response {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let parts: Vec<&str> = path.split('/').filter(|part| !part.is_empty()).collect();
+
+    let is_slot = |slot: &str| is_name(slot) && saves.join(slot).join("SAVE.DAT").is_file();
+    let response = match parts.as_slice() {
+        [] => index(saves),
+        [slot] if is_slot(slot) => slot_page(saves, slot, false),
+        [slot, "save.json"] if is_slot(slot) => slot_page(saves, slot, true),
+        [slot, map] if is_slot(slot) => match map.strip_suffix(".json") {
+            Some(map) if is_name(map) => map_page(saves, slot, map, true),
+            None if is_name(map) => map_page(saves, slot, map, false),
+            _ => Ok(Response::not_found()),
+        },
+        _ => Ok(Response::not_found()),
+    };
+    response.unwrap_or_else(|error| Response::error(500, &error.to_string()))
+}
+
+/// A connection whose reads and writes all time out at the same deadline. A timeout per read or
+/// write alone would let a client sending or reading a byte at a time keep it open for good.
+struct Connection<'a> {
+    stream: &'a TcpStream,
+    deadline: Instant,
+}
+
+impl Connection<'_> {
+    fn remaining(&self) -> io::Result<Duration> {
+        self.deadline
+            .checked_duration_since(Instant::now())
+            .filter(|remaining| !remaining.is_zero())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::TimedOut, "Client took too long"))
+    }
+}
+
+impl Read for Connection<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.stream.set_read_timeout(Some(self.remaining()?))?;
+        self.stream.read(buf)
+    }
+}
+
+impl Write for Connection<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.stream.set_write_timeout(Some(self.remaining()?))?;
+        self.stream.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stream.flush()
+    }
+}
+
+/// Reads the request line and the headers, up to `MAX_REQUEST` bytes of them.
+fn read_request(connection: &mut Connection) -> io::Result<Result<String, Response>> {
+    let mut reader = BufReader::new(connection.take(MAX_REQUEST));
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // The headers aren't needed, they're read so the client isn't cut off mid-request
+    let mut line = String::new();
+    while reader.read_line(&mut line)? > 2 {
+        line.clear();
+    }
+
+    if line.ends_with('\n') {
+        Ok(Ok(request_line))
+    } else if reader.get_ref().limit() == 0 {
+        Ok(Err(Response::error(431, "The request is too long")))
+    } else {
+        Ok(Err(Response::error(
+            400,
+            "The request ended before its headers",
+        )))
+    }
+}
+
+fn handle(saves: &Path, stream: TcpStream) -> io::Result<()> {
+    let mut connection = Connection {
+        stream: &stream,
+        deadline: Instant::now() + TIMEOUT,
+    };
+    let response = match read_request(&mut connection)? {
+        Ok(request_line) => {
+            let mut request = request_line.split_whitespace();
+            match (request.next(), request.next()) {
+                (Some("GET"), Some(path)) => route(saves, path),
+                _ => Response::error(405, "Only GET, this is a viewer"),
+            }
+        }
+        Err(response) => response,
+    };
+
+    write!(
+        connection,
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        response.status,
+        response.reason(),
+        response.content_type,
+        response.body.len()
+    )?;
+    connection.write_all(response.body.as_bytes())?;
+    connection.flush()
+}
+
+/// Serves the slots in `saves` on the address, e.g. `127.0.0.1:8080`, until killed.
+pub fn serve(saves: &Path, address: &str) -> io::Result<()> {
+    let listener = TcpListener::bind(address)?;
+    println!(
+        "Serving {} on http://{}",
+        saves.display(),
+        listener.local_addr()?
+    );
+    for stream in listener.incoming() {
+        let result = stream.and_then(|stream| handle(saves, stream));
+        if let Err(error) = result {
+            eprintln!("{error}");
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,282 @@
+/// Editing session over the map saves of a slot. Edits are made in memory and recorded so they
+/// can be undone and redone, nothing is written until the session is committed.
+///
+/// Edits replace bytes of the map saves as they were read, nothing is serialized again from what
+/// the parser made of them. An uncompressed map save differs from the original only in the bytes
+/// the operations replaced, a gzipped one is compressed again.
+///
+/// Committing writes every changed map save next to the original first and only then renames
+/// them over the originals, so a failed write leaves the slot as it was. The renames are one per
+/// map save, a commit isn't atomic across them: when one fails the map saves renamed before it
+/// stay changed and the rest don't. Back up the slot first, like the commands do.
+use std::{
+    collections::BTreeMap,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use flate2::{write::GzEncoder, Compression};
+
+use crate::{
+    parser::gunzip,
+    preset::{Change, Edit},
+    slot, version,
+};
+
+/// Bytes an operation replaced in a decompressed map save
+#[derive(Debug, Clone, PartialEq)]
+pub struct Replacement {
+    pub offset: usize,
+    pub old: Vec<u8>,
+    pub new: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Operation {
+    /// Map save the operation is in, e.g. `NCR1`
+    pub map: String,
+    /// What was done, e.g. `script 447 local variable 5 = 0`
+    pub description: String,
+    pub replacements: Vec<Replacement>,
+}
+
+#[derive(Debug)]
+struct MapSave {
+    path: PathBuf,
+    /// As read from the disk, for telling whether someone else changed it in the meantime
+    original: Vec<u8>,
+    gzipped: bool,
+    /// Decompressed, as read
+    loaded: Vec<u8>,
+    /// Decompressed, with the session's edits
+    data: Vec<u8>,
+}
+
+#[derive(Debug)]
+pub struct Session {
+    slot: PathBuf,
+    maps: BTreeMap<String, MapSave>,
+    done: Vec<Operation>,
+    undone: Vec<Operation>,
+}
+
+impl Session {
+    pub fn new(slot: &Path) -> Session {
+        Session {
+            slot: slot.to_path_buf(),
+            maps: BTreeMap::new(),
+            done: Vec::new(),
+            undone: Vec::new(),
+        }
+    }
+
+    fn map_save(&mut self, map: &str) -> io::Result<&mut MapSave> {
+        let map = map.to_uppercase();
+        if !self.maps.contains_key(&map) {
+            let path = slot::map_save_path(&self.slot, &map)?;
+            let original = fs::read(&path)?;
+            let gzipped = original.starts_with(&[0x1f, 0x8b]);
+            let loaded = gunzip(original.clone())?;
+            version::map_save(&loaded).require(version::EDITING, &path.display().to_string())?;
+            self.maps.insert(
+                map.clone(),
+                MapSave {
+                    path,
+                    original,
+                    gzipped,
+                    data: loaded.clone(),
+                    loaded,
+                },
+            );
+        }
+        Ok(self.maps.get_mut(&map).expect("map save was just loaded"))
+    }
+
+    /// The decompressed map save with the session's edits, loaded from the slot the first time.
+    pub fn data(&mut self, map: &str) -> io::Result<&[u8]> {
+        Ok(&self.map_save(map)?.data)
+    }
+
+    /// Replaces bytes of the decompressed map save and records it as one operation. Anything
+    /// undone is forgotten.
+    pub fn replace(
+        &mut self,
+        map: &str,
+        description: &str,
+        replacements: &[(usize, &[u8])],
+    ) -> io::Result<()> {
+        let map_save = self.map_save(map)?;
+        if let Some(end) = replacements
+            .iter()
+            .map(|(offset, new)| offset + new.len())
+            .find(|&end| end > map_save.data.len())
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{map} ends before {end:#x}"),
+            ));
+        }
+
+        // Made one at a time so overlapping replacements undo in reverse
+        let mut recorded = Vec::new();
+        for &(offset, new) in replacements {
+            let range = offset..offset + new.len();
+            recorded.push(Replacement {
+                offset,
+                old: map_save.data[range.clone()].to_vec(),
+                new: new.to_vec(),
+            });
+            map_save.data[range].copy_from_slice(new);
+        }
+        self.done.push(Operation {
+            map: map.to_uppercase(),
+            description: description.to_string(),
+            replacements: recorded,
+        });
+        self.undone.clear();
+        Ok(())
+    }
+
+    /// Makes a preset edit as one operation, returns what it changed. Edits that change nothing
+    /// aren't recorded.
+    pub fn apply(&mut self, edit: &Edit) -> io::Result<Vec<Change>> {
+        let changes = edit.changes(self.data(&edit.map)?)?;
+        if !changes.is_empty() {
+            let value = edit.value.to_be_bytes();
+            let replacements: Vec<(usize, &[u8])> = changes
+                .iter()
+                .map(|change| (change.offset, &value[..]))
+                .collect();
+            let description = format!("{} = {}", edit.variable, edit.value);
+            self.replace(&edit.map, &description, &replacements)?;
+        }
+        Ok(changes)
+    }
+
+    fn replay(&mut self, operation: &Operation, undo: bool) {
+        let data = &mut self
+            .maps
+            .get_mut(&operation.map)
+            .expect("operations are only recorded for loaded maps")
+            .data;
+        let replacements: Box<dyn Iterator<Item = &Replacement>> = if undo {
+            Box::new(operation.replacements.iter().rev())
+        } else {
+            Box::new(operation.replacements.iter())
+        };
+        for replacement in replacements {
+            let bytes = if undo {
+                &replacement.old
+            } else {
+                &replacement.new
+            };
+            data[replacement.offset..replacement.offset + bytes.len()].copy_from_slice(bytes);
+        }
+    }
+
+    /// Reverts the latest operation, returns it or `None` when there's nothing to undo.
+    pub fn undo(&mut self) -> Option<&Operation> {
+        let operation = self.done.pop()?;
+        self.replay(&operation, true);
+        self.undone.push(operation);
+        self.undone.last()
+    }
+
+    /// Makes the latest undone operation again, returns it or `None` when there's nothing to redo.
+    pub fn redo(&mut self) -> Option<&Operation> {
+        let operation = self.undone.pop()?;
+        self.replay(&operation, false);
+        self.done.push(operation);
+        self.done.last()
+    }
+
+    /// Operations made, oldest first
+    pub fn history(&self) -> &[Operation] {
+        &self.done
+    }
+
+    /// Map saves that differ from the slot, e.g. `NCR1`. Undoing everything leaves none.
+    pub fn modified(&self) -> Vec<&str> {
+        self.maps
+            .iter()
+            .filter(|(_, map_save)| map_save.data != map_save.loaded)
+            .map(|(map, _)| map.as_str())
+            .collect()
+    }
+
+    /// Writes the modified map saves to the slot, gzipped again when they were. Fails without
+    /// touching the slot when a map save was changed on disk since it was read or a write fails.
+    /// When renaming one over its original fails, the ones before it are already written and the
+    /// error lists them. No `.tmp` files are left behind either way. Returns the files written.
+    pub fn commit(self) -> io::Result<Vec<PathBuf>> {
+        let modified: Vec<&MapSave> = self
+            .modified()
+            .into_iter()
+            .map(|map| &self.maps[map])
+            .collect();
+        for map_save in &modified {
+            if fs::read(&map_save.path)? != map_save.original {
+                return Err(io::Error::other(format!(
+                    "{} was changed while editing it",
+                    map_save.path.display()
+                )));
+            }
+        }
+
+        let mut staged = Vec::new();
+        for map_save in &modified {
+            let temporary = temporary_path(&map_save.path);
+            if let Err(error) = write_map_save(&temporary, &map_save.data, map_save.gzipped) {
+                for path in staged.iter().chain([&temporary]) {
+                    let _ = fs::remove_file(path);
+                }
+                return Err(error);
+            }
+            staged.push(temporary);
+        }
+
+        let mut written: Vec<PathBuf> = Vec::new();
+        for (i, (temporary, map_save)) in staged.iter().zip(&modified).enumerate() {
+            if let Err(error) = fs::rename(temporary, &map_save.path) {
+                for path in &staged[i..] {
+                    let _ = fs::remove_file(path);
+                }
+                let already = match written.as_slice() {
+                    [] => "nothing was written".to_string(),
+                    written => {
+                        let paths: Vec<String> = written
+                            .iter()
+                            .map(|path| path.display().to_string())
+                            .collect();
+                        format!("{} were already written", paths.join(", "))
+                    }
+                };
+                return Err(io::Error::new(
+                    error.kind(),
+                    format!(
+                        "Could not replace {}: {error}, {already}",
+                        map_save.path.display()
+                    ),
+                ));
+            }
+            written.push(map_save.path.clone());
+        }
+        Ok(written)
+    }
+}
+
+fn temporary_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".tmp");
+    path.with_file_name(name)
+}
+
+fn write_map_save(path: &Path, data: &[u8], gzipped: bool) -> io::Result<()> {
+    if !gzipped {
+        return fs::write(path, data);
+    }
+    let mut encoder = GzEncoder::new(BufWriter::new(File::create(path)?), Compression::default());
+    encoder.write_all(data)?;
+    encoder.finish()?.flush()
+}
@@ -0,0 +1,187 @@
+/// sfall keeps what it adds to the game in files of its own next to SAVE.DAT: its global
+/// variables in `sfallgv.sav`, fake perks and traits in `sfallfs.sav` and drug effects in
+/// `sfalldb.sav`. A vanilla install doesn't know them, sfall reads them back when loading.
+///
+/// `inspect` and `export` show the globals of `sfallgv.sav` when the slot has one.
+///
+/// Converting a save to vanilla moves the files aside with a `.bak` suffix, converting it back
+/// puts them back or writes an empty `sfallgv.sav` when the save never had one.
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+pub const GLOBALS_FILE: &str = "sfallgv.sav";
+pub const FILES: [&str; 3] = [GLOBALS_FILE, "sfallfs.sav", "sfalldb.sav"];
+
+/// First id sfall hands out to objects it has to tell apart, what an empty save starts from
+pub const UNIQUE_ID_START: u32 = 0x0fff_ffff;
+
+/// 8 byte id, 4 byte value and 4 unused bytes
+const GLOBAL_SIZE: usize = 16;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SfallGlobals {
+    /// Ids are the names given to `set_sfall_global`, up to 8 characters packed in a u64
+    pub globals: Vec<(u64, i32)>,
+    /// Missing from saves of sfall versions older than the unique object ids
+    pub unique_id: Option<u32>,
+    /// Whatever newer sfall versions write after the unique id, kept as is
+    rest: Vec<u8>,
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let b = data.get(offset..offset + 4)?;
+    Some(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+impl SfallGlobals {
+    /// What sfall writes for a game that never set a global.
+    pub fn new() -> SfallGlobals {
+        SfallGlobals {
+            globals: Vec::new(),
+            unique_id: Some(UNIQUE_ID_START),
+            rest: Vec::new(),
+        }
+    }
+
+    pub fn parse(data: &[u8]) -> io::Result<SfallGlobals> {
+        let count =
+            read_u32(data, 0).ok_or_else(|| invalid_data("sfallgv.sav has no global count"))?;
+        let end = (count as usize)
+            .checked_mul(GLOBAL_SIZE)
+            .and_then(|size| size.checked_add(4))
+            .filter(|&end| end <= data.len())
+            .ok_or_else(|| invalid_data("sfallgv.sav ended in the middle of the globals"))?;
+
+        let globals = data[4..end]
+            .chunks_exact(GLOBAL_SIZE)
+            .map(|global| {
+                let mut id = [0; 8];
+                id.copy_from_slice(&global[..8]);
+                let value = i32::from_le_bytes([global[8], global[9], global[10], global[11]]);
+                (u64::from_le_bytes(id), value)
+            })
+            .collect();
+
+        Ok(SfallGlobals {
+            globals,
+            unique_id: read_u32(data, end),
+            rest: data.get(end + 4..).unwrap_or_default().to_vec(),
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = (self.globals.len() as u32).to_le_bytes().to_vec();
+        for (id, value) in &self.globals {
+            data.extend_from_slice(&id.to_le_bytes());
+            data.extend_from_slice(&value.to_le_bytes());
+            data.extend_from_slice(&[0; 4]);
+        }
+        if let Some(unique_id) = self.unique_id {
+            data.extend_from_slice(&unique_id.to_le_bytes());
+            data.extend_from_slice(&self.rest);
+        }
+        data
+    }
+}
+
+/// Name of a global as given to `set_sfall_global`, e.g. `KILLCNT`. Globals set by number have
+/// ids that aren't text, those are shown as the number.
+pub fn global_name(id: u64) -> String {
+    let bytes = id.to_le_bytes();
+    let name = match bytes.iter().position(|&byte| byte == 0) {
+        Some(end) => &bytes[..end],
+        None => &bytes[..],
+    };
+    let is_text = !name.is_empty()
+        && bytes[name.len()..].iter().all(|&byte| byte == 0)
+        && name.iter().all(u8::is_ascii_graphic);
+    if is_text {
+        String::from_utf8_lossy(name).into_owned()
+    } else {
+        id.to_string()
+    }
+}
+
+impl Default for SfallGlobals {
+    fn default() -> Self {
+        SfallGlobals::new()
+    }
+}
+
+fn backup_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".bak");
+    path.with_file_name(name)
+}
+
+/// sfall's files in the slot, matched case insensitively like the game does on Windows.
+pub fn files(slot: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut found = Vec::new();
+    for file in fs::read_dir(slot)? {
+        let path = file?.path();
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        if FILES.iter().any(|file| name.eq_ignore_ascii_case(file)) && path.is_file() {
+            found.push(path);
+        }
+    }
+    found.sort();
+    Ok(found)
+}
+
+/// Globals of the slot's `sfallgv.sav`, `None` when it has none, e.g. a vanilla save.
+pub fn globals(slot: &Path) -> io::Result<Option<SfallGlobals>> {
+    let path = files(slot)?.into_iter().find(|path| {
+        path.file_name()
+            .is_some_and(|name| name.eq_ignore_ascii_case(GLOBALS_FILE))
+    });
+    path.map(|path| SfallGlobals::parse(&fs::read(path)?))
+        .transpose()
+}
+
+/// Moves sfall's files in the slot aside, returns where they went. Backups left by an earlier
+/// conversion are overwritten.
+pub fn to_vanilla(slot: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut moved = Vec::new();
+    for path in files(slot)? {
+        let backup = backup_path(&path);
+        fs::rename(&path, &backup)?;
+        moved.push(backup);
+    }
+    Ok(moved)
+}
+
+/// Puts back sfall's files moved aside by `to_vanilla`, and writes an empty `sfallgv.sav` when
+/// there's none. Returns the files written.
+pub fn to_sfall(slot: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut written = Vec::new();
+    for file in fs::read_dir(slot)? {
+        let backup = file?.path();
+        let name = backup.file_name().unwrap_or_default().to_string_lossy();
+        let Some(original) = name.strip_suffix(".bak") else {
+            continue;
+        };
+        let path = backup.with_file_name(original);
+        let is_sfall = FILES.iter().any(|file| original.eq_ignore_ascii_case(file));
+        if is_sfall && backup.is_file() && !path.exists() {
+            fs::rename(&backup, &path)?;
+            written.push(path);
+        }
+    }
+
+    let has_globals = files(slot)?.iter().any(|path| {
+        path.file_name()
+            .is_some_and(|name| name.eq_ignore_ascii_case(GLOBALS_FILE))
+    });
+    if !has_globals {
+        let path = slot.join(GLOBALS_FILE);
+        fs::write(&path, SfallGlobals::new().to_bytes())?;
+        written.push(path);
+    }
+    Ok(written)
+}
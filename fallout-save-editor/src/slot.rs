@@ -0,0 +1,93 @@
+/// Files in a save slot directory, e.g. `SLOT01`. Next to `SAVE.DAT` there's a map save for every
+/// map visited, e.g. `NCR1.SAV`. Deleting one resets the map to how it's in master.dat the next
+/// time it's entered.
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::sfall;
+
+/// Map saves in the slot, e.g. `NCR1.SAV`, sorted by name. The automap and sfall's files share
+/// the extension but aren't maps.
+pub fn map_saves(slot: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut maps = Vec::new();
+    for file in fs::read_dir(slot)? {
+        let path = file?.path();
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let is_map = path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("SAV"))
+            && !name.eq_ignore_ascii_case("AUTOMAP.SAV")
+            && !sfall::FILES
+                .iter()
+                .any(|file| name.eq_ignore_ascii_case(file));
+        if is_map && path.is_file() {
+            maps.push(path);
+        }
+    }
+    maps.sort();
+    Ok(maps)
+}
+
+/// Where the map's save is in the slot, e.g. `SLOT01/NCR1.SAV` or `SLOT01/ncr1.sav` for `ncr1`.
+/// The game doesn't care about the case, so neither does the lookup.
+pub fn map_save_path(slot: &Path, map: &str) -> io::Result<PathBuf> {
+    map_saves(slot)?
+        .into_iter()
+        .find(|path| map_name(path).eq_ignore_ascii_case(map))
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("No map save for {map} in {}", slot.display()),
+            )
+        })
+}
+
+/// Name of the map a map save is for, e.g. `NCR1` for `SLOT01/ncr1.sav`.
+pub fn map_name(path: &Path) -> String {
+    path.file_stem()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_uppercase()
+}
+
+/// Map saves matching any of the patterns, e.g. `NCR1`, `NCR1.SAV` or `NCR*`. The current map,
+/// the one in the save header as e.g. `NCRENT.sav`, is left out: the game has it loaded from the
+/// save and doesn't recreate it. Naming it is an error.
+pub fn prunable(
+    maps: &[PathBuf],
+    patterns: &[String],
+    current_map: &str,
+) -> io::Result<Vec<PathBuf>> {
+    let current = map_name(Path::new(current_map));
+    let patterns: Vec<String> = patterns
+        .iter()
+        .map(|pattern| map_name(Path::new(pattern)))
+        .collect();
+    if patterns.contains(&current) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{current} is the map the save is in, it can't be pruned"),
+        ));
+    }
+
+    let selected: Vec<PathBuf> = maps
+        .iter()
+        .filter(|path| {
+            let map = map_name(path);
+            map != current
+                && patterns
+                    .iter()
+                    .any(|pattern| archive::matches(pattern, &map))
+        })
+        .cloned()
+        .collect();
+    if selected.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "No map saves in the slot match, nothing to prune",
+        ));
+    }
+    Ok(selected)
+}
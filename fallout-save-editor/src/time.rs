@@ -0,0 +1,93 @@
+/// Dates and times of saves the way people read them, for everything that prints them.
+///
+/// Game time is counted in ticks, tenths of a second, from midnight of the day before the game
+/// starts on 25 July 2241. Fallout has no leap years.
+use core::fmt;
+
+pub const TICKS_PER_SECOND: u32 = 10;
+pub const TICKS_PER_MINUTE: u32 = 60 * TICKS_PER_SECOND;
+pub const TICKS_PER_HOUR: u32 = 60 * TICKS_PER_MINUTE;
+pub const TICKS_PER_DAY: u32 = 24 * TICKS_PER_HOUR;
+
+const START_YEAR: u32 = 2241;
+/// Zero based, July
+const START_MONTH: usize = 6;
+/// Days of the year gone before tick 0, the game counts from 25 July
+const START_DAY: u32 = 24;
+const DAYS_PER_MONTH: [u32; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+/// Real world date, e.g. the day a save was made, as `2024-06-02`.
+pub fn date(year: u16, month: u16, day: u16) -> String {
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GameTime {
+    pub year: u32,
+    /// 1 to 12
+    pub month: u32,
+    /// 1 to 31
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+}
+
+impl GameTime {
+    /// In-game date and time of the tick, counted the same way the game does.
+    pub fn from_ticks(ticks: u32) -> GameTime {
+        let days = ticks / TICKS_PER_DAY + START_DAY;
+        let mut year = START_YEAR + days / 365;
+        let mut day = days % 365;
+        let mut month = START_MONTH;
+        while day >= DAYS_PER_MONTH[month] {
+            day -= DAYS_PER_MONTH[month];
+            month += 1;
+            if month == DAYS_PER_MONTH.len() {
+                month = 0;
+                year += 1;
+            }
+        }
+
+        GameTime {
+            year,
+            month: month as u32 + 1,
+            day: day + 1,
+            hour: ticks / TICKS_PER_HOUR % 24,
+            minute: ticks / TICKS_PER_MINUTE % 60,
+        }
+    }
+}
+
+/// `2242-06-13 13:08`
+impl fmt::Display for GameTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute
+        )
+    }
+}
+
+/// How long the game has gone on by the tick, e.g. `323 days, 13 hours and 8 minutes`. Parts
+/// that are zero are left out.
+pub fn elapsed(ticks: u32) -> String {
+    let parts: Vec<String> = [
+        (ticks / TICKS_PER_DAY, "day"),
+        (ticks / TICKS_PER_HOUR % 24, "hour"),
+        (ticks / TICKS_PER_MINUTE % 60, "minute"),
+    ]
+    .into_iter()
+    .filter(|(count, _)| *count > 0)
+    .map(|(count, unit)| match count {
+        1 => format!("1 {unit}"),
+        _ => format!("{count} {unit}s"),
+    })
+    .collect();
+
+    match parts.as_slice() {
+        [] => "less than a minute".to_string(),
+        [only] => only.clone(),
+        [rest @ .., last] => format!("{} and {last}", rest.join(", ")),
+    }
+}
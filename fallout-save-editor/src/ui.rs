@@ -1,24 +1,173 @@
 use std::{
+    ffi::OsStr,
     fs::{self, File, OpenOptions},
-    io::{BufWriter, Seek, SeekFrom, Write},
+    io::{self, BufWriter, IsTerminal, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
 };
 
 use clap::{Parser, Subcommand};
 use flate2::{write::GzEncoder, Compression};
+use save_backup::Retention;
 
-use crate::parser::{map_save, try_gunzip_buffer, Script};
+use crate::{
+    annotate,
+    dat::Dat,
+    export::{self, MapExport, SaveExport},
+    gvars,
+    index::{self, SlotIndex},
+    parser::{gunzip, header, try_gunzip_buffer, Script},
+    patch,
+    preset::Preset,
+    serve,
+    session::Session,
+    sfall, slot,
+    time::{self, GameTime},
+    version::{self, Support},
+    xref,
+};
 
 #[derive(Subcommand)]
 enum Commands {
+    /// Shows who the save is for, when it was saved and where, and sfall's globals when the
+    /// slot has them
+    Inspect,
+    /// Lists the slots next to the save's slot with their names and dates, or the ones with a
+    /// name or map matching the pattern, e.g. "*ncr*". Headers are cached in an index next to
+    /// the slots and only parsed again when the save changes
+    ListSlots { pattern: Option<String> },
     /// Sets all NCR cops to friendly, fuck you sulik!
     FixNCRCopAggro,
+    /// Lists the backups of the save's slot, e.g. ones taken by save-backup
+    Backups {
+        /// Directory with a directory of backups per slot
+        backups: PathBuf,
+    },
+    /// Replaces the save's slot with its latest backup, or the one taken at the given timestamp.
+    /// The slot is backed up first
+    Restore {
+        /// Directory with a directory of backups per slot
+        backups: PathBuf,
+        taken: Option<u128>,
+    },
+    /// Prints the save header, or a map save in the slot, e.g. NCR1, as JSON. The formats are
+    /// described by the JSON Schemas in schemas
+    Export { map: Option<String> },
+    /// Prints the JSON Schema of the save header export, or of the map save export
+    Schema {
+        #[arg(long)]
+        map: bool,
+    },
+    /// Hexdump of the save header with the known fields labeled, or of a map save in the slot,
+    /// e.g. NCR1
+    Hexdump {
+        map: Option<String>,
+        /// Writes an HTML page instead, hovering a byte shows its field
+        #[arg(long)]
+        html: bool,
+    },
+    /// Moves the files sfall keeps in the slot aside so the save loads in a vanilla game
+    ToVanilla,
+    /// Puts back the files moved aside by to-vanilla, or writes empty ones, for loading the
+    /// save with sfall
+    ToSfall,
+    /// Deletes map saves from the slot, e.g. NCR1 or "NCR*", so the maps reset when entered
+    /// next. The slot is backed up first and the map the save is in can't be pruned
+    Prune {
+        /// Directory with a directory of backups per slot
+        backups: PathBuf,
+        #[arg(required = true)]
+        maps: Vec<String>,
+    },
+    /// Makes the variable edits of a preset file, e.g. presets/friendly-ncr-cops.toml, in the
+    /// slot's map saves. Every edit is checked before anything is written and the slot is backed
+    /// up first
+    ApplyPreset {
+        preset: PathBuf,
+        /// Directory with a directory of backups per slot
+        backups: PathBuf,
+        /// Only lists what would change
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Writes bytes at an offset of the save header, or of a decompressed map save in the slot,
+    /// e.g. NCR1. Writing over part of a field or over a count or version the rest of the file
+    /// is read by is refused unless forced. The slot is backed up first
+    Patch {
+        /// Directory with a directory of backups per slot
+        backups: PathBuf,
+        map: Option<String>,
+        /// Where to write, e.g. 420 or 0x1A4
+        #[arg(long, value_parser = patch::parse_offset)]
+        offset: usize,
+        /// Hex bytes to write, e.g. "01 00 00 02"
+        #[arg(long)]
+        bytes: String,
+        /// Writes over fields it would otherwise refuse to
+        #[arg(long)]
+        force: bool,
+        /// Only checks the patch
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Lists the save's global variables that differ from their defaults in vault13.gam,
+    /// extracted from master.dat with e.g. extract master.dat "data\vault13.gam"
+    Gvars { gam: PathBuf },
+    /// Serves a read-only viewer of the slots next to the save's slot, as HTML pages and JSON
+    /// exports. Use e.g. 0.0.0.0:8080 to browse them from other devices on the LAN
+    Serve {
+        #[arg(long, default_value = "127.0.0.1:8080")]
+        address: String,
+    },
+    /// Lists which map saves in the slot use each script, with the script's instances and local
+    /// variables in each, or only the given script id
+    Xref { script: Option<i32> },
+    /// Lists the entries of a DAT archive, e.g. master.dat, only those matching the pattern when
+    /// one is given, e.g. "maps\*.map"
+    Entries {
+        archive: PathBuf,
+        pattern: Option<String>,
+    },
+    /// Extracts the entries of a DAT archive matching the pattern, keeping their directories
+    Extract {
+        archive: PathBuf,
+        pattern: String,
+        /// Directory to extract into
+        #[arg(short, long, default_value = ".")]
+        out: PathBuf,
+    },
+    /// Lists the entries of a DAT archive containing the text, and where it's first found
+    Search {
+        archive: PathBuf,
+        text: String,
+        /// Only searches the entries matching the pattern
+        #[arg(short, long, default_value = "*")]
+        pattern: String,
+    },
+    /// Writes a copy of a DAT2 archive with entries added, replaced or removed, e.g. a
+    /// patch000.dat for a mod. Removals are done first
+    Repack {
+        /// Archive to start from, an empty one when it doesn't exist
+        archive: PathBuf,
+        /// Where to write the new archive
+        out: PathBuf,
+        /// Adds an entry from a file or replaces it, e.g. "scripts\ncrcop.int=build/ncrcop.int"
+        #[arg(long, value_name = "ENTRY=FILE")]
+        put: Vec<String>,
+        /// Removes the entries matching the pattern
+        #[arg(long, value_name = "PATTERN")]
+        remove: Vec<String>,
+        /// Stores the added entries as is instead of compressing them
+        #[arg(long)]
+        store: bool,
+    },
 }
 
 /// Program to manipulate Fallout 2 saves
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 #[command(propagate_version = true)]
-struct Cli {
+pub struct Cli {
     #[command(subcommand)]
     command: Commands,
 
@@ -32,11 +181,13 @@ const GLOBAL_VARIABLE_START: usize = 0x00EC;
 
 // FIXME(tatu): Holy fuck this code is horrible :D
 //              I just wanted to get the NCR aggro reset working as quickly as possible.
-fn ncr_cop_aggro_fix(save_file_path: String) {
-    let content = fs::read(save_file_path).expect("could not read save file");
+fn ncr_cop_aggro_fix(save_file_path: &str) -> io::Result<()> {
+    let content = fs::read(save_file_path)?;
 
     let decompressed = try_gunzip_buffer(content);
-    let (_, map_variables, scripts) = map_save(&decompressed);
+    let (support, (_, map_variables, scripts)) = version::try_map_save(&decompressed)
+        .map_err(|error| io::Error::new(error.kind(), format!("{save_file_path}: {error}")))?;
+    support.require(version::EDITING, save_file_path)?;
 
     {
         let mut file = OpenOptions::new()
@@ -91,12 +242,541 @@ fn ncr_cop_aggro_fix(save_file_path: String) {
     let mut encoder = GzEncoder::new(writer, Compression::default());
     encoder.write_all(&bytes).unwrap();
     encoder.finish().unwrap();
+    Ok(())
+}
+
+/// Slot directory of the save, e.g. `SLOT01` for `SLOT01/SAVE.DAT`.
+fn slot(save_file_path: &str) -> io::Result<&Path> {
+    Path::new(save_file_path)
+        .parent()
+        .filter(|slot| slot.file_name().is_some())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Save is not in a slot"))
+}
+
+fn slot_name(slot: &Path) -> &OsStr {
+    slot.file_name().unwrap_or_default()
+}
+
+/// Warns about a file of a version the parser doesn't know.
+fn warn_unknown(support: &Support, path: &Path) {
+    if let Some(warning) = support.warning(&path.display().to_string()) {
+        eprintln!("Warning: {warning}");
+    }
+}
+
+fn inspect(save_file_path: &str) -> io::Result<()> {
+    let data = fs::read(save_file_path)?;
+    let (_, header) = header(&data).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Could not parse {save_file_path}"),
+        )
+    })?;
+    warn_unknown(&version::save(&header), Path::new(save_file_path));
+
+    println!("{} by {}", header.save_name, header.name);
+    println!(
+        "Saved on {}",
+        time::date(header.save_year, header.save_month, header.save_day)
+    );
+    println!(
+        "In game {}, {} in",
+        GameTime::from_ticks(header.ingame_ticks),
+        time::elapsed(header.ingame_ticks)
+    );
+    println!("In {}", header.map_name);
+
+    if let Some(globals) = sfall::globals(slot(save_file_path)?)? {
+        println!("{} sfall globals", globals.globals.len());
+        for (id, value) in &globals.globals {
+            println!("  {} = {value}", sfall::global_name(*id));
+        }
+    }
+    Ok(())
+}
+
+fn list_slots(save_file_path: &str, pattern: Option<&str>) -> io::Result<()> {
+    let slot = slot(save_file_path)?;
+    let saves = slot.parent().unwrap_or(Path::new("."));
+    let mut slots: Vec<PathBuf> = fs::read_dir(saves)?
+        .map(|entry| entry.map(|entry| entry.path().join("SAVE.DAT")))
+        .collect::<io::Result<_>>()?;
+    slots.retain(|save| save.is_file());
+    slots.sort();
+
+    let index_path = saves.join(index::INDEX_FILE);
+    let mut index = SlotIndex::load(&index_path)?;
+    let names: Vec<String> = slots
+        .iter()
+        .map(|save| {
+            slot_name(save.parent().unwrap_or(Path::new("")))
+                .to_string_lossy()
+                .into_owned()
+        })
+        .collect();
+    let gone: Vec<String> = index
+        .slots()
+        .filter(|slot| !names.iter().any(|name| name == slot))
+        .map(str::to_string)
+        .collect();
+    for slot in gone {
+        index.forget(&slot);
+    }
+
+    for (save, name) in slots.iter().zip(&names) {
+        let Some(summary) = index.summary(name, save)? else {
+            if pattern.is_none() {
+                println!("{name}  could not be read");
+            }
+            continue;
+        };
+        let matches = pattern.is_none_or(|pattern| {
+            archive::matches(pattern, &summary.save_name)
+                || archive::matches(pattern, &summary.map_name)
+        });
+        if matches {
+            warn_unknown(&version::save_version(summary.version), save);
+            println!(
+                "{name}  {:<30}  {}  {}  {}",
+                summary.save_name,
+                time::date(summary.save_year, summary.save_month, summary.save_day),
+                GameTime::from_ticks(summary.ingame_ticks),
+                summary.map_name
+            );
+        }
+    }
+    index.save(&index_path)
+}
+
+fn list_backups(save_file_path: &str, backups: &Path) -> io::Result<()> {
+    let slot = slot(save_file_path)?;
+    for backup in save_backup::backups(backups, slot_name(slot))? {
+        println!("{}", backup.taken);
+    }
+    Ok(())
+}
+
+fn restore(save_file_path: &str, backups: &Path, taken: Option<u128>) -> io::Result<()> {
+    let slot = slot(save_file_path)?;
+    let found = save_backup::backups(backups, slot_name(slot))?;
+    let backup = match taken {
+        Some(taken) => found.into_iter().find(|backup| backup.taken == taken),
+        None => found.into_iter().last(),
+    }
+    .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "No such backup"))?;
+
+    // Rotating is left to whatever took the backups
+    let current = save_backup::backup(slot, backups, &Retention::keep_all())?;
+    save_backup::restore(&backup.path, slot)?;
+    println!(
+        "Restored {} from {}, it was backed up to {}",
+        slot.display(),
+        backup.path.display(),
+        current.display()
+    );
+    Ok(())
+}
+
+/// Reads the save header, or the map save in the slot decompressed.
+fn read(save_file_path: &str, map: Option<&str>) -> io::Result<(PathBuf, Vec<u8>)> {
+    match map {
+        Some(map) => {
+            let path = slot::map_save_path(slot(save_file_path)?, map)?;
+            let data = gunzip(fs::read(&path)?)?;
+            Ok((path, data))
+        }
+        None => Ok((PathBuf::from(save_file_path), fs::read(save_file_path)?)),
+    }
+}
+
+fn export(save_file_path: &str, map: Option<&str>) -> io::Result<()> {
+    let (path, data) = read(save_file_path, map)?;
+    let unparsable = || {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Could not parse {}", path.display()),
+        )
+    };
+    let json = match map {
+        Some(_) => {
+            let (support, (header, variables, scripts)) =
+                version::try_map_save(&data).map_err(|error| {
+                    io::Error::new(error.kind(), format!("{}: {error}", path.display()))
+                })?;
+            warn_unknown(&support, &path);
+            let mut export = MapExport::new(&header, &variables, &scripts);
+            export.version = support.version;
+            serde_json::to_string_pretty(&export)?
+        }
+        None => {
+            let (_, header) = header(&data).map_err(|_| unparsable())?;
+            warn_unknown(&version::save(&header), &path);
+            let export = SaveExport::from(&header);
+            let export = match sfall::globals(slot(save_file_path)?)? {
+                Some(globals) => export.with_sfall_globals(&globals),
+                None => export,
+            };
+            serde_json::to_string_pretty(&export)?
+        }
+    };
+    println!("{json}");
+    Ok(())
+}
+
+fn schema(map: bool) -> io::Result<()> {
+    let schema = if map {
+        export::map_schema()
+    } else {
+        export::save_schema()
+    };
+    println!("{}", serde_json::to_string_pretty(&schema)?);
+    Ok(())
+}
+
+fn hexdump(save_file_path: &str, map: Option<&str>, html: bool) -> io::Result<()> {
+    let (path, data) = read(save_file_path, map)?;
+    let dump = match map {
+        Some(_) => annotate::map_save(&data),
+        None => annotate::save_header(&data),
+    };
+
+    let out = if html {
+        dump.html(&path.display().to_string())
+    } else {
+        dump.terminal(io::stdout().is_terminal())
+    };
+    io::stdout().write_all(out.as_bytes())
+}
+
+fn global_variables(save_file_path: &str, gam: &Path) -> io::Result<()> {
+    let globals = gvars::parse_gam(&fs::read_to_string(gam)?)?;
+    let data = fs::read(save_file_path)?;
+    let (_, header) = header(&data).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Could not parse {save_file_path}"),
+        )
+    })?;
+    version::save(&header).require(version::GLOBAL_VARIABLES, save_file_path)?;
+    let values = gvars::global_variables(&data, globals.len())?;
+    let deviations = gvars::deviations(&globals, &values);
+
+    print!("{}", gvars::render(&deviations));
+    println!(
+        "{} of {} global variables differ from {}",
+        deviations.len(),
+        globals.len(),
+        gam.display()
+    );
+    Ok(())
+}
+
+fn cross_reference(save_file_path: &str, script: Option<i32>) -> io::Result<()> {
+    let mut maps = Vec::new();
+    for path in slot::map_saves(slot(save_file_path)?)? {
+        let data = gunzip(fs::read(&path)?)?;
+        let Ok((support, (_, _, scripts))) = version::try_map_save(&data) else {
+            eprintln!("Skipping {}, could not parse it", path.display());
+            continue;
+        };
+        warn_unknown(&support, &path);
+        maps.push((slot::map_name(&path), scripts));
+    }
+
+    let mut references = xref::cross_reference(&maps);
+    if let Some(script) = script {
+        references.retain(|id, _| *id == script);
+    }
+    print!("{}", xref::render(&references));
+    Ok(())
+}
+
+fn prune(save_file_path: &str, backups: &Path, maps: &[String]) -> io::Result<()> {
+    let slot = slot(save_file_path)?;
+    let data = fs::read(save_file_path)?;
+    let (_, header) = header(&data).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Could not parse {save_file_path}"),
+        )
+    })?;
+    let pruned = slot::prunable(&slot::map_saves(slot)?, maps, &header.map_name)?;
+
+    let backup = save_backup::backup(slot, backups, &Retention::keep_all())?;
+    println!("Backed up {} to {}", slot.display(), backup.display());
+
+    for path in pruned {
+        fs::remove_file(&path)?;
+        println!("Pruned {}", slot::map_name(&path));
+    }
+    Ok(())
+}
+
+fn apply_preset(
+    save_file_path: &str,
+    preset: &Path,
+    backups: &Path,
+    dry_run: bool,
+) -> io::Result<()> {
+    let preset = Preset::parse(&fs::read_to_string(preset)?)?;
+    if let Some(name) = &preset.name {
+        println!("{name}");
+    }
+
+    let slot = slot(save_file_path)?;
+    let mut session = Session::new(slot);
+    for edit in &preset.edits {
+        for change in session.apply(edit)? {
+            println!(
+                "{} {} at {:#x}: {} -> {}",
+                edit.map.to_uppercase(),
+                edit.variable,
+                change.offset,
+                change.old,
+                change.new
+            );
+        }
+    }
+
+    if session.modified().is_empty() {
+        println!("Nothing to change");
+        return Ok(());
+    }
+    if dry_run {
+        return Ok(());
+    }
+
+    let backup = save_backup::backup(slot, backups, &Retention::keep_all())?;
+    println!("Backed up {} to {}", slot.display(), backup.display());
+    session.commit()?;
+    Ok(())
 }
 
-pub fn run_terminal_ui() {
-    let cli = Cli::parse();
+fn patch(
+    save_file_path: &str,
+    backups: &Path,
+    map: Option<&str>,
+    offset: usize,
+    bytes: &str,
+    force: bool,
+    dry_run: bool,
+) -> io::Result<()> {
+    let bytes = patch::parse_bytes(bytes)?;
+    let (path, data) = read(save_file_path, map)?;
+    // A header too broken to parse may be what's being fixed, its version isn't checked
+    let support = match map {
+        Some(_) => Some(version::map_save(&data)),
+        None => header(&data).ok().map(|(_, header)| version::save(&header)),
+    };
+    if let Some(support) = support {
+        support.require(version::EDITING, &path.display().to_string())?;
+    }
+    let dump = match map {
+        Some(_) => annotate::map_save(&data),
+        None => annotate::save_header(&data),
+    };
+    let check = patch::check(
+        dump.spans(),
+        data.len(),
+        offset..offset.saturating_add(bytes.len()),
+    );
 
-    match &cli.command {
-        Commands::FixNCRCopAggro => ncr_cop_aggro_fix(cli.save_file_path),
+    for field in &check.fields {
+        println!("Writes over {field}");
+    }
+    for unknown in &check.unknown {
+        eprintln!(
+            "Warning: {:#x}..{:#x} isn't a known field",
+            unknown.start, unknown.end
+        );
+    }
+    let forced = force && check.can_force();
+    for problem in &check.problems {
+        if forced {
+            eprintln!("Warning: {problem}");
+        } else {
+            eprintln!("Refusing to patch {}: {problem}", path.display());
+        }
+    }
+    let refused = !check.problems.is_empty() && !forced;
+    if refused {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Patch refused, see above",
+        ));
+    }
+    if dry_run {
+        return Ok(());
+    }
+
+    let slot = slot(save_file_path)?;
+    let backup = save_backup::backup(slot, backups, &Retention::keep_all())?;
+    println!("Backed up {} to {}", slot.display(), backup.display());
+
+    let description = format!("{} bytes at {offset:#x}", bytes.len());
+    match map {
+        Some(map) => {
+            let mut session = Session::new(slot);
+            session.replace(map, &description, &[(offset, &bytes)])?;
+            session.commit()?;
+        }
+        None => {
+            let mut data = data;
+            data[offset..offset + bytes.len()].copy_from_slice(&bytes);
+            fs::write(&path, data)?;
+        }
+    }
+    println!("Wrote {description} of {}", path.display());
+    Ok(())
+}
+
+fn to_vanilla(save_file_path: &str) -> io::Result<()> {
+    let moved = sfall::to_vanilla(slot(save_file_path)?)?;
+    if moved.is_empty() {
+        println!("No sfall files in the slot, it's already vanilla");
+    }
+    for path in moved {
+        println!("Moved aside to {}", path.display());
     }
+    Ok(())
+}
+
+fn to_sfall(save_file_path: &str) -> io::Result<()> {
+    let written = sfall::to_sfall(slot(save_file_path)?)?;
+    if written.is_empty() {
+        println!("The slot already has its sfall files");
+    }
+    for path in written {
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn entries(path: &Path, pattern: &str) -> io::Result<()> {
+    let dat = Dat::parse(fs::read(path)?)?;
+    print!("{}", archive::render(&dat, pattern));
+    Ok(())
+}
+
+fn extract(path: &Path, pattern: &str, out: &Path) -> io::Result<()> {
+    let dat = Dat::parse(fs::read(path)?)?;
+    let written = archive::extract(&dat, pattern, out)?;
+    println!("Extracted {} entries to {}", written.len(), out.display());
+    Ok(())
+}
+
+fn search(path: &Path, text: &str, pattern: &str) -> io::Result<()> {
+    let dat = Dat::parse(fs::read(path)?)?;
+    for (name, offset) in archive::search(&dat, pattern, text.as_bytes())? {
+        println!("{name} at {offset:#x}");
+    }
+    Ok(())
+}
+
+fn repack(
+    path: &Path,
+    out: &Path,
+    puts: &[String],
+    removes: &[String],
+    store: bool,
+) -> io::Result<()> {
+    let mut dat = match fs::read(path) {
+        Ok(data) => Dat::parse(data)?,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Dat::new(),
+        Err(e) => return Err(e),
+    };
+
+    for pattern in removes {
+        let removed = dat.remove(pattern)?;
+        println!("Removed {removed} entries matching {pattern}");
+    }
+    for put in puts {
+        let (name, file) = put.split_once('=').ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("'{put}' is not ENTRY=FILE"),
+            )
+        })?;
+        dat.insert(name, &fs::read(file)?, !store)?;
+        println!("Put {file} as {name}");
+    }
+
+    fs::write(out, dat.to_bytes()?)?;
+    println!("Wrote {} entries to {}", dat.entries.len(), out.display());
+    Ok(())
+}
+
+pub fn run(cli: Cli) -> ExitCode {
+    let result = match &cli.command {
+        Commands::Inspect => inspect(&cli.save_file_path),
+        Commands::ListSlots { pattern } => list_slots(&cli.save_file_path, pattern.as_deref()),
+        Commands::FixNCRCopAggro => ncr_cop_aggro_fix(&cli.save_file_path),
+        Commands::Backups { backups } => list_backups(&cli.save_file_path, backups),
+        Commands::Restore { backups, taken } => restore(&cli.save_file_path, backups, *taken),
+        Commands::Export { map } => export(&cli.save_file_path, map.as_deref()),
+        Commands::Schema { map } => schema(*map),
+        Commands::Hexdump { map, html } => hexdump(&cli.save_file_path, map.as_deref(), *html),
+        Commands::Prune { backups, maps } => prune(&cli.save_file_path, backups, maps),
+        Commands::ToVanilla => to_vanilla(&cli.save_file_path),
+        Commands::ToSfall => to_sfall(&cli.save_file_path),
+        Commands::ApplyPreset {
+            preset,
+            backups,
+            dry_run,
+        } => apply_preset(&cli.save_file_path, preset, backups, *dry_run),
+        Commands::Patch {
+            backups,
+            map,
+            offset,
+            bytes,
+            force,
+            dry_run,
+        } => patch(
+            &cli.save_file_path,
+            backups,
+            map.as_deref(),
+            *offset,
+            bytes,
+            *force,
+            *dry_run,
+        ),
+        Commands::Gvars { gam } => global_variables(&cli.save_file_path, gam),
+        Commands::Serve { address } => {
+            let slot = slot(&cli.save_file_path);
+            slot.and_then(|slot| serve::serve(slot.parent().unwrap_or(Path::new(".")), address))
+        }
+        Commands::Xref { script } => cross_reference(&cli.save_file_path, *script),
+        Commands::Entries { archive, pattern } => {
+            entries(archive, pattern.as_deref().unwrap_or("*"))
+        }
+        Commands::Extract {
+            archive,
+            pattern,
+            out,
+        } => extract(archive, pattern, out),
+        Commands::Search {
+            archive,
+            text,
+            pattern,
+        } => search(archive, text, pattern),
+        Commands::Repack {
+            archive,
+            out,
+            put,
+            remove,
+            store,
+        } => repack(archive, out, put, remove, *store),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+pub fn run_terminal_ui() -> ExitCode {
+    run(Cli::parse())
 }
@@ -0,0 +1,130 @@
+/// Versions of saves the parser was written against, and what still works with ones it wasn't.
+///
+/// SAVE.DAT of Fallout 2 is version 1.2, `0x00010002` or 65538, and its map saves are version 20.
+/// A save from a different version of the game or an engine remake can move things around. Rather
+/// than reading garbage at the expected offsets, such saves are read as the known version with a
+/// warning, and the features depending on offsets past what was checked are turned off.
+use std::io;
+
+use nom::number::complete::be_u32;
+
+use crate::parser::{self, MapHeader, MapVariables, MapVersion, SaveHeader, Script};
+
+/// Version 1.2 of SAVE.DAT, 65538, what Fallout 2 writes
+pub const SAVE_VERSION: u32 = 0x0001_0002;
+
+/// Global variables come from offsets after the header that differ between versions
+pub const GLOBAL_VARIABLES: &str = "global variables";
+/// Writing into a file needs the offsets to be right
+pub const EDITING: &str = "editing";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Support {
+    /// Version in the file
+    pub version: u32,
+    pub known: bool,
+    /// Features turned off for the version, e.g. `EDITING`
+    pub unavailable: Vec<&'static str>,
+}
+
+impl Support {
+    fn known(version: u32) -> Support {
+        Support {
+            version,
+            known: true,
+            unavailable: Vec::new(),
+        }
+    }
+
+    fn unknown(version: u32, unavailable: &[&'static str]) -> Support {
+        Support {
+            version,
+            known: false,
+            unavailable: unavailable.to_vec(),
+        }
+    }
+
+    /// Explains what an unknown version means for `file`, `None` for known versions.
+    pub fn warning(&self, file: &str) -> Option<String> {
+        if self.known {
+            return None;
+        }
+        Some(format!(
+            "{file} is version {}, which isn't known. It was read best-effort, {} not available",
+            self.version,
+            match self.unavailable.as_slice() {
+                [] => "everything is".to_string(),
+                [feature] => format!("{feature} is"),
+                features => format!("{} are", features.join(" and ")),
+            }
+        ))
+    }
+
+    /// Fails when `feature` is turned off for the version.
+    pub fn require(&self, feature: &str, file: &str) -> io::Result<()> {
+        if !self.unavailable.contains(&feature) {
+            return Ok(());
+        }
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!(
+                "Can't use {feature} with {file}, its version {} isn't known",
+                self.version
+            ),
+        ))
+    }
+}
+
+/// Support for the SAVE.DAT the header is from. The header is the same in every known version,
+/// what comes after it isn't.
+pub fn save(header: &SaveHeader) -> Support {
+    save_version(header.version)
+}
+
+/// Support for a SAVE.DAT of the version, e.g. one kept in the slot index.
+pub fn save_version(version: u32) -> Support {
+    match version {
+        SAVE_VERSION => Support::known(version),
+        version => Support::unknown(version, &[GLOBAL_VARIABLES, EDITING]),
+    }
+}
+
+/// Support for a decompressed map save, from the version in its first 4 bytes.
+pub fn map_save(data: &[u8]) -> Support {
+    let version = be_u32::<_, ()>(data).map_or(0, |(_, version)| version);
+    match MapVersion::try_from(version) {
+        Ok(_) => Support::known(version),
+        Err(_) => Support::unknown(version, &[EDITING]),
+    }
+}
+
+/// A map save as `parser::try_map_save` returns it
+pub type ParsedMapSave = (MapHeader, MapVariables, Vec<Script>);
+
+/// Parses a decompressed map save, one of an unknown version as if it was a Fallout 2 map save.
+/// The version in the parsed header is then Fallout 2's, the real one is in the support.
+pub fn try_map_save(data: &[u8]) -> io::Result<(Support, ParsedMapSave)> {
+    let support = map_save(data);
+    let parsed = if support.known || data.len() < 4 {
+        parser::try_map_save(data).map(|(_, parsed)| parsed).ok()
+    } else {
+        let mut known = data.to_vec();
+        known[..4].copy_from_slice(&(MapVersion::Fallout2 as u32).to_be_bytes());
+        parser::try_map_save(&known).map(|(_, parsed)| parsed).ok()
+    };
+
+    match parsed {
+        Some(parsed) => Ok((support, parsed)),
+        None if !support.known => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "Map save version {} isn't known and it couldn't be read as Fallout 2's",
+                support.version
+            ),
+        )),
+        None => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "Could not parse the map save",
+        )),
+    }
+}
@@ -0,0 +1,59 @@
+/// Cross-reference of the scripts in the map saves of a slot, for tracing where a script is used
+/// and how much of a map's local variables it holds.
+use std::collections::BTreeMap;
+
+use crate::parser::Script;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptReference {
+    /// Map save the script is in, e.g. `NCR1`
+    pub map: String,
+    /// Objects, spatials and so on in the map running the script
+    pub instances: usize,
+    /// Local variables of all the instances together
+    pub local_variables: usize,
+}
+
+/// References of every script id in the maps, in the order the maps are given. Scripts without
+/// local variables have an offset of -1 and a count of 0, negative counts are taken as 0 too.
+pub fn cross_reference(maps: &[(String, Vec<Script>)]) -> BTreeMap<i32, Vec<ScriptReference>> {
+    let mut references: BTreeMap<i32, Vec<ScriptReference>> = BTreeMap::new();
+    for (map, scripts) in maps {
+        for script in scripts {
+            let variables = usize::try_from(script.local_variable_count).unwrap_or(0);
+            let map_references = references.entry(script.id).or_default();
+            match map_references.last_mut() {
+                Some(reference) if reference.map == *map => {
+                    reference.instances += 1;
+                    reference.local_variables += variables;
+                }
+                _ => map_references.push(ScriptReference {
+                    map: map.clone(),
+                    instances: 1,
+                    local_variables: variables,
+                }),
+            }
+        }
+    }
+    references
+}
+
+/// One line per script: the id and the maps referencing it with their instance and variable
+/// counts, e.g. `560  MODINN x4 (32 variables)`.
+pub fn render(references: &BTreeMap<i32, Vec<ScriptReference>>) -> String {
+    references
+        .iter()
+        .map(|(id, maps)| {
+            let maps: Vec<String> = maps
+                .iter()
+                .map(|reference| {
+                    format!(
+                        "{} x{} ({} variables)",
+                        reference.map, reference.instances, reference.local_variables
+                    )
+                })
+                .collect();
+            format!("{id:>5}  {}\n", maps.join(", "))
+        })
+        .collect()
+}
@@ -0,0 +1,184 @@
+use std::io::Write;
+
+use archive::ReadableArchive;
+use fallout_save_editor::dat::{lzss_decompress, Dat, DatVersion};
+use flate2::{write::ZlibEncoder, Compression};
+
+/// LZSS block of "abc" followed by a 6 byte copy of it, then "xyz" stored as is.
+const LZSS: &[u8] = &[
+    0x00, 0x06, 0x07, b'a', b'b', b'c', 0xee, 0xf3, 0xff, 0xfd, b'x', b'y', b'z',
+];
+
+/// DAT2 with `maps\arcaves.map` stored and `text\misc.msg` zlib compressed.
+fn dat2() -> Vec<u8> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(b"{100}{}{Vault 13}").unwrap();
+    let compressed = encoder.finish().unwrap();
+
+    let mut data = b"ARCAVES".to_vec();
+    data.extend_from_slice(&compressed);
+
+    let mut tree = 2u32.to_le_bytes().to_vec();
+    for (name, flag, size, packed, offset) in [
+        ("maps\\arcaves.map", 0u8, 7u32, 7u32, 0u32),
+        ("text\\misc.msg", 1, 17, compressed.len() as u32, 7),
+    ] {
+        tree.extend_from_slice(&(name.len() as u32).to_le_bytes());
+        tree.extend_from_slice(name.as_bytes());
+        tree.push(flag);
+        for value in [size, packed, offset] {
+            tree.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+    data.extend_from_slice(&tree);
+    data.extend_from_slice(&(tree.len() as u32).to_le_bytes());
+    let size = data.len() as u32 + 4;
+    data.extend_from_slice(&size.to_le_bytes());
+    data
+}
+
+/// DAT1 with `readme.txt` stored in the root and `maps\v13ent.map` LZSS compressed.
+fn dat1() -> Vec<u8> {
+    let mut header = 2u32.to_be_bytes().to_vec();
+    header.extend_from_slice(&[0; 12]);
+    for directory in [".", "maps"] {
+        header.push(directory.len() as u8);
+        header.extend_from_slice(directory.as_bytes());
+    }
+
+    let entries = [
+        ("readme.txt", 0x20u32, 4u32, 0u32),
+        ("v13ent.map", 0x40, 12, 13),
+    ];
+    let tree_size: usize = entries
+        .iter()
+        .map(|(name, ..)| 16 + 1 + name.len() + 16)
+        .sum();
+    let data_offset = (header.len() + tree_size) as u32;
+
+    let mut data = header;
+    for (i, (name, attributes, size, packed)) in entries.into_iter().enumerate() {
+        data.extend_from_slice(&1u32.to_be_bytes());
+        data.extend_from_slice(&[0; 12]);
+        data.push(name.len() as u8);
+        data.extend_from_slice(name.as_bytes());
+        let offset = data_offset + i as u32 * 4;
+        for value in [attributes, offset, size, packed] {
+            data.extend_from_slice(&value.to_be_bytes());
+        }
+    }
+    data.extend_from_slice(b"Hi!\n");
+    data.extend_from_slice(LZSS);
+    data
+}
+
+#[test]
+fn lzss_copies_from_the_dictionary_and_stored_blocks() {
+    assert_eq!(lzss_decompress(LZSS, 12).unwrap(), b"abcabcabcxyz");
+    assert_eq!(lzss_decompress(LZSS, 4).unwrap(), b"abca");
+    assert!(lzss_decompress(&[0x00, 0x10, 0x01], 16).is_err());
+}
+
+#[test]
+fn reads_dat2_entries() {
+    let dat = Dat::parse(dat2()).unwrap();
+    assert_eq!(dat.version, DatVersion::Dat2);
+
+    let entries = dat.entries();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].packed_size, None);
+    assert_eq!(dat.stat("TEXT\\MISC.MSG").unwrap().size, 17);
+
+    assert_eq!(dat.read("maps\\arcaves.map").unwrap(), b"ARCAVES");
+    assert_eq!(dat.read("text\\misc.msg").unwrap(), b"{100}{}{Vault 13}");
+    assert_eq!(
+        archive::search(&dat, "*", b"Vault").unwrap(),
+        vec![("text\\misc.msg".to_string(), 8)]
+    );
+}
+
+#[test]
+fn reads_dat1_entries() {
+    let dat = Dat::parse(dat1()).unwrap();
+    assert_eq!(dat.version, DatVersion::Dat1);
+
+    let names: Vec<String> = dat.entries().into_iter().map(|entry| entry.name).collect();
+    assert_eq!(names, vec!["readme.txt", "maps\\v13ent.map"]);
+    assert_eq!(dat.read("readme.txt").unwrap(), b"Hi!\n");
+    assert_eq!(dat.read("maps\\v13ent.map").unwrap(), b"abcabcabcxyz");
+}
+
+#[test]
+fn extracts_into_directories() {
+    let out = std::env::temp_dir().join("fallout-save-editor-dat-extract");
+    let _ = std::fs::remove_dir_all(&out);
+
+    let dat = Dat::parse(dat2()).unwrap();
+    let written = archive::extract(&dat, "maps\\*", &out).unwrap();
+    assert_eq!(written, vec![out.join("maps").join("arcaves.map")]);
+    assert_eq!(std::fs::read(&written[0]).unwrap(), b"ARCAVES");
+}
+
+#[test]
+fn repacks_dat2_with_changed_entries() {
+    let mut dat = Dat::parse(dat2()).unwrap();
+    dat.insert("MAPS\\ARCAVES.MAP", b"REPLACED", false).unwrap();
+    dat.insert("scripts\\ncrcop.int", &[7; 1000], true).unwrap();
+    assert_eq!(dat.remove("text\\*").unwrap(), 1);
+
+    let repacked = Dat::parse(dat.to_bytes().unwrap()).unwrap();
+    assert_eq!(repacked.version, DatVersion::Dat2);
+    let names: Vec<String> = repacked
+        .entries()
+        .into_iter()
+        .map(|entry| entry.name)
+        .collect();
+    assert_eq!(names, vec!["MAPS\\ARCAVES.MAP", "scripts\\ncrcop.int"]);
+    assert_eq!(repacked.read("maps\\arcaves.map").unwrap(), b"REPLACED");
+    assert_eq!(repacked.read("scripts\\ncrcop.int").unwrap(), vec![7; 1000]);
+    assert!(repacked.get("scripts\\ncrcop.int").unwrap().packed_size < 1000);
+
+    // Writing it again without changes gives the same bytes
+    assert_eq!(repacked.to_bytes().unwrap(), dat.to_bytes().unwrap());
+    assert_eq!(Dat::new().to_bytes().unwrap().len(), 12);
+}
+
+#[test]
+fn writes_the_tree_sorted_ignoring_case() {
+    let mut dat = Dat::parse(dat2()).unwrap();
+    dat.insert("PROTO\\ITEMS\\00000001.PRO", b"PRO", false)
+        .unwrap();
+    dat.insert("art\\inven\\1stpal.frm", b"FRM", false).unwrap();
+
+    let repacked = Dat::parse(dat.to_bytes().unwrap()).unwrap();
+    let names: Vec<String> = repacked
+        .entries()
+        .into_iter()
+        .map(|entry| entry.name)
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            "art\\inven\\1stpal.frm",
+            "maps\\arcaves.map",
+            "PROTO\\ITEMS\\00000001.PRO",
+            "text\\misc.msg",
+        ]
+    );
+    assert_eq!(repacked.read("proto\\items\\00000001.pro").unwrap(), b"PRO");
+}
+
+#[test]
+fn dat1_archives_are_read_only() {
+    let mut dat = Dat::parse(dat1()).unwrap();
+    assert!(dat.insert("readme.txt", b"", false).is_err());
+    assert!(dat.remove("*").is_err());
+    assert!(dat.to_bytes().is_err());
+}
+
+#[test]
+fn rejects_truncated_archives() {
+    let mut data = dat2();
+    data.truncate(data.len() - 8);
+    assert!(Dat::parse(data).is_err());
+}
@@ -0,0 +1,72 @@
+use fallout_save_editor::{
+    export::{map_schema, save_schema, MapExport, SaveExport},
+    parser::{header, map_save, try_gunzip_buffer},
+    sfall::SfallGlobals,
+};
+use serde_json::Value;
+
+const SLOT01_SAVE: &[u8] = include_bytes!("../saves/SLOT01/SAVE.DAT");
+const NCR1_SAVE: &[u8] = include_bytes!("../saves/SLOT01/NCR1.SAV");
+
+/// Fails on keys of `value` the schema doesn't list and on required ones missing, nested objects
+/// and arrays of them included.
+fn assert_follows(value: &Value, schema: &Value) {
+    match value {
+        Value::Object(fields) => {
+            let properties = schema["properties"].as_object().unwrap();
+            for (key, field) in fields {
+                let property = properties
+                    .get(key)
+                    .unwrap_or_else(|| panic!("{key} is not in the schema"));
+                assert_follows(field, property);
+            }
+            for required in schema["required"].as_array().unwrap() {
+                assert!(fields.contains_key(required.as_str().unwrap()));
+            }
+        }
+        Value::Array(items) if schema["items"].is_object() => {
+            for item in items {
+                assert_follows(item, &schema["items"]);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[test]
+fn exports_follow_their_schemas() {
+    let (_, save) = header(SLOT01_SAVE).unwrap();
+    let export = serde_json::to_value(SaveExport::from(&save)).unwrap();
+    assert_follows(&export, &save_schema());
+    assert_eq!(export["map_name"], "NCRENT.sav");
+    assert!(export.get("sfall_globals").is_none());
+
+    let mut globals = SfallGlobals::new();
+    globals.globals = vec![(u64::from_le_bytes(*b"KILLCNT\0"), 42)];
+    let export =
+        serde_json::to_value(SaveExport::from(&save).with_sfall_globals(&globals)).unwrap();
+    assert_follows(&export, &save_schema());
+    assert_eq!(export["sfall_globals"][0]["name"], "KILLCNT");
+
+    let (map, variables, scripts) = map_save(&try_gunzip_buffer(NCR1_SAVE.to_vec()));
+    let export = serde_json::to_value(MapExport::new(&map, &variables, &scripts)).unwrap();
+    assert_follows(&export, &map_schema());
+    assert_eq!(export["scripts"][0]["type"], "scenery");
+}
+
+#[test]
+fn published_schemas_are_up_to_date() {
+    for (published, schema) in [
+        (include_str!("../schemas/save.schema.json"), save_schema()),
+        (
+            include_str!("../schemas/map-save.schema.json"),
+            map_schema(),
+        ),
+    ] {
+        let published: Value = serde_json::from_str(published).unwrap();
+        assert_eq!(
+            published, schema,
+            "Schemas in schemas differ from export.rs, regenerate them with the schema command"
+        );
+    }
+}
@@ -0,0 +1,138 @@
+//! Parses every save in `saves` and compares the result, as the JSON the export command prints,
+//! against the snapshots committed under `tests/golden`.
+//!
+//! After a parser change that's meant to change the results, rewrite the snapshots and review
+//! their diff:
+//!
+//! ```bash
+//! UPDATE_GOLDEN=1 cargo test --test golden
+//! ```
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use fallout_save_editor::{
+    export::{MapExport, SaveExport},
+    parser::{gunzip, header, try_map_save},
+};
+
+/// Lines shown around the first difference
+const DIFF_CONTEXT: usize = 3;
+
+fn saves() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("saves")
+}
+
+fn goldens() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+}
+
+/// Save headers and map saves in the slots under `saves`, sorted so failures come out in the
+/// same order. The automap and the protos in the slots have no parser yet.
+fn fixtures() -> Vec<PathBuf> {
+    let mut fixtures = Vec::new();
+    for slot in fs::read_dir(saves()).unwrap() {
+        let slot = slot.unwrap().path();
+        for file in fs::read_dir(&slot).unwrap() {
+            let file = file.unwrap().path();
+            let name = file.file_name().unwrap().to_string_lossy().to_uppercase();
+            if file.is_file() && name != "AUTOMAP.SAV" {
+                fixtures.push(file);
+            }
+        }
+    }
+    fixtures.sort();
+    fixtures
+}
+
+/// Canonical JSON of a parsed save header or map save, fields in declaration order.
+fn snapshot(path: &Path) -> Result<String, String> {
+    let data = fs::read(path).map_err(|e| e.to_string())?;
+    let is_header = path
+        .file_name()
+        .is_some_and(|name| name.eq_ignore_ascii_case("SAVE.DAT"));
+
+    let json = if is_header {
+        let (_, header) = header(&data).map_err(|e| e.to_string())?;
+        serde_json::to_string_pretty(&SaveExport::from(&header))
+    } else {
+        let data = gunzip(data).map_err(|e| e.to_string())?;
+        let (_, (header, variables, scripts)) = try_map_save(&data).map_err(|e| e.to_string())?;
+        serde_json::to_string_pretty(&MapExport::new(&header, &variables, &scripts))
+    };
+    json.map(|json| json + "\n").map_err(|e| e.to_string())
+}
+
+/// The first differing line with a few lines around it, `-` for the golden and `+` for now.
+fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let first = expected
+        .iter()
+        .zip(&actual)
+        .position(|(expected, actual)| expected != actual)
+        .unwrap_or(expected.len().min(actual.len()));
+
+    let start = first.saturating_sub(DIFF_CONTEXT);
+    let mut out = format!("first difference on line {}\n", first + 1);
+    for line in &expected[start..first] {
+        out += &format!("  {line}\n");
+    }
+    for line in expected.iter().skip(first).take(DIFF_CONTEXT) {
+        out += &format!("- {line}\n");
+    }
+    for line in actual.iter().skip(first).take(DIFF_CONTEXT) {
+        out += &format!("+ {line}\n");
+    }
+    out
+}
+
+#[test]
+fn parses_fixtures_like_the_goldens() {
+    let update = env::var_os("UPDATE_GOLDEN").is_some();
+    let mut failures = Vec::new();
+
+    for fixture in fixtures() {
+        let relative = fixture.strip_prefix(saves()).unwrap();
+        let golden = goldens().join(relative).with_extension(format!(
+            "{}.json",
+            fixture.extension().unwrap().to_string_lossy()
+        ));
+
+        let actual = match snapshot(&fixture) {
+            Ok(actual) => actual,
+            Err(e) => {
+                failures.push(format!("{}: {e}", relative.display()));
+                continue;
+            }
+        };
+
+        if update {
+            fs::create_dir_all(golden.parent().unwrap()).unwrap();
+            fs::write(&golden, actual).unwrap();
+            continue;
+        }
+        match fs::read_to_string(&golden) {
+            Ok(expected) if expected == actual => {}
+            Ok(expected) => failures.push(format!(
+                "{} differs from its golden, {}",
+                relative.display(),
+                diff(&expected, &actual)
+            )),
+            Err(_) => failures.push(format!(
+                "{} has no golden at {}",
+                relative.display(),
+                golden.display()
+            )),
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{}\n\nRun with UPDATE_GOLDEN=1 to accept the changes",
+        failures.join("\n")
+    );
+}
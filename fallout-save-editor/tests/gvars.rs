@@ -0,0 +1,77 @@
+use fallout_save_editor::gvars::{
+    count_in_save, deviations, global_variables, parse_gam, render, Deviation, GlobalVariable,
+};
+
+const SLOT01_SAVE: &[u8] = include_bytes!("../saves/SLOT01/SAVE.DAT");
+
+const SLOT01_GLOBALS: usize = 696;
+
+fn gam(count: usize) -> String {
+    let mut gam = "// Global variables\nGAME_GLOBAL_VARS:\n".to_string();
+    for i in 0..count {
+        gam.push_str(&format!("GVAR_{i:<24}:=0;    //  ({i})\n"));
+    }
+    gam
+}
+
+#[test]
+fn parses_gam_files() {
+    let globals = parse_gam(
+        "GAME_GLOBAL_VARS:\n\
+         GVAR_PLAYER_REPUTATION          :=0;    //  (0)\n\
+         \n\
+         GVAR_ENEMY_ARROYO               :=-1;   //  (1) := in a comment\n",
+    )
+    .unwrap();
+
+    assert_eq!(
+        globals,
+        [
+            GlobalVariable {
+                name: "GVAR_PLAYER_REPUTATION".to_string(),
+                default: 0
+            },
+            GlobalVariable {
+                name: "GVAR_ENEMY_ARROYO".to_string(),
+                default: -1
+            }
+        ]
+    );
+    assert!(parse_gam("GVAR_BROKEN :=lots;").is_err());
+}
+
+#[test]
+fn lists_globals_differing_from_defaults() {
+    let globals = parse_gam(&gam(SLOT01_GLOBALS)).unwrap();
+    let values = global_variables(SLOT01_SAVE, globals.len()).unwrap();
+    assert_eq!(values.len(), SLOT01_GLOBALS);
+
+    let deviations = deviations(&globals, &values);
+    assert_eq!(
+        deviations[0],
+        Deviation {
+            index: 0,
+            name: "GVAR_0".to_string(),
+            default: 0,
+            value: 290
+        }
+    );
+    assert!(deviations.iter().all(|deviation| deviation.value != 0));
+    assert_eq!(
+        deviations.len(),
+        values.iter().filter(|&&value| value != 0).count()
+    );
+    assert!(render(&deviations).starts_with("   0  GVAR_0    0 -> 290\n"));
+}
+
+#[test]
+fn gam_files_for_other_games_are_errors() {
+    assert_eq!(count_in_save(SLOT01_SAVE), Some(SLOT01_GLOBALS));
+
+    let error = global_variables(SLOT01_SAVE, 100).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "The .gam file has 100 global variables but the save has 696"
+    );
+    assert!(global_variables(b"FALLOUT SAVE FILE", 1).is_err());
+}
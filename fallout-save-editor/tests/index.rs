@@ -0,0 +1,80 @@
+use std::{
+    fs::{self, File},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use fallout_save_editor::index::{SlotIndex, Stamp, INDEX_FILE};
+
+const SLOT01_SAVE: &[u8] = include_bytes!("../saves/SLOT01/SAVE.DAT");
+
+fn saves(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("fallout-save-editor-index-{test}"));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write_save(saves: &Path, slot: &str, data: &[u8]) -> PathBuf {
+    let path = saves.join(slot).join("SAVE.DAT");
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, data).unwrap();
+    path
+}
+
+#[test]
+fn summaries_are_kept_between_runs() {
+    let dir = saves("kept");
+    let path = dir.join(INDEX_FILE);
+    let save = write_save(&dir, "SLOT01", SLOT01_SAVE);
+
+    let mut index = SlotIndex::load(&path).unwrap();
+    let stamp = Stamp::of(&save).unwrap();
+    assert_eq!(index.cached("SLOT01", &stamp), None);
+    let summary = index.summary("SLOT01", &save).unwrap().unwrap();
+    assert_eq!(summary.save_name, "start");
+    assert_eq!(summary.map_name, "NCRENT.sav");
+    assert_eq!(summary.ingame_ticks, 279545357);
+    index.save(&path).unwrap();
+
+    let index = SlotIndex::load(&path).unwrap();
+    assert_eq!(index.cached("SLOT01", &stamp), Some(&summary));
+    assert_eq!(index.slots().collect::<Vec<_>>(), ["SLOT01"]);
+
+    // Saving again in the game rewrites the file
+    let file = File::options().write(true).open(&save).unwrap();
+    file.set_modified(SystemTime::now() + Duration::from_secs(60))
+        .unwrap();
+    assert_eq!(index.cached("SLOT01", &Stamp::of(&save).unwrap()), None);
+}
+
+#[test]
+fn only_the_header_is_read() {
+    let dir = saves("header");
+    let mut data = SLOT01_SAVE.to_vec();
+    // Whatever is after the header doesn't matter for the summary
+    data.truncate(30051);
+    let save = write_save(&dir, "SLOT01", &data);
+
+    let mut index = SlotIndex::load(&dir.join(INDEX_FILE)).unwrap();
+    let summary = index.summary("SLOT01", &save).unwrap().unwrap();
+    assert_eq!(summary.save_name, "start");
+}
+
+#[test]
+fn unreadable_saves_and_indexes_are_not_cached() {
+    let dir = saves("unreadable");
+    let path = dir.join(INDEX_FILE);
+    fs::write(&path, "not json").unwrap();
+
+    let mut index = SlotIndex::load(&path).unwrap();
+    assert_eq!(index.slots().count(), 0);
+    let broken = write_save(&dir, "SLOT02", b"FALLOUT SAVE FILE");
+    assert_eq!(index.summary("SLOT02", &broken).unwrap(), None);
+    assert_eq!(index.slots().count(), 0);
+
+    let save = write_save(&dir, "SLOT01", SLOT01_SAVE);
+    index.summary("SLOT01", &save).unwrap().unwrap();
+    index.forget("SLOT01");
+    assert_eq!(index.slots().count(), 0);
+}
@@ -0,0 +1,77 @@
+use fallout_save_editor::{
+    annotate,
+    parser::try_gunzip_buffer,
+    patch::{check, parse_bytes, parse_offset, Problem},
+};
+
+const SLOT01_SAVE: &[u8] = include_bytes!("../saves/SLOT01/SAVE.DAT");
+const NCR1_SAVE: &[u8] = include_bytes!("../saves/SLOT01/NCR1.SAV");
+
+#[test]
+fn parses_offsets_and_bytes() {
+    assert_eq!(parse_offset("0x1A4"), Ok(0x1a4));
+    assert_eq!(parse_offset("420"), Ok(420));
+    assert!(parse_offset("0xZZ").is_err());
+
+    assert_eq!(parse_bytes("01 00 00 02").unwrap(), [1, 0, 0, 2]);
+    assert_eq!(parse_bytes("0100ff02").unwrap(), [1, 0, 0xff, 2]);
+    for invalid in ["", "01 0", "0 1", "zz", "ä1"] {
+        assert!(parse_bytes(invalid).is_err(), "{invalid:?}");
+    }
+}
+
+#[test]
+fn whole_variables_can_be_patched() {
+    let data = try_gunzip_buffer(NCR1_SAVE.to_vec());
+    let dump = annotate::map_save(&data);
+
+    let variables = check(dump.spans(), data.len(), 0xec..0xf4);
+    assert_eq!(variables.fields, ["global variable 0", "global variable 1"]);
+    assert!(variables.problems.is_empty());
+    assert!(variables.unknown.is_empty());
+
+    let half = check(dump.spans(), data.len(), 0xee..0xf2);
+    assert_eq!(
+        half.problems,
+        [
+            Problem::Partial("global variable 0".to_string()),
+            Problem::Partial("global variable 1".to_string())
+        ]
+    );
+    assert!(half.can_force());
+}
+
+#[test]
+fn structure_and_the_end_of_the_file_are_guarded() {
+    let data = try_gunzip_buffer(NCR1_SAVE.to_vec());
+    let dump = annotate::map_save(&data);
+
+    let version = check(dump.spans(), data.len(), 0..4);
+    assert_eq!(
+        version.problems,
+        [Problem::Structural("version".to_string())]
+    );
+    assert!(version.can_force());
+
+    let past_end = check(dump.spans(), data.len(), data.len() - 2..data.len() + 2);
+    assert!(past_end.problems.contains(&Problem::PastEnd {
+        end: data.len() + 2,
+        len: data.len()
+    }));
+    assert!(!past_end.can_force());
+}
+
+#[test]
+fn freeform_fields_and_unknown_bytes_are_allowed() {
+    let dump = annotate::save_header(SLOT01_SAVE);
+
+    let thumbnail = check(dump.spans(), SLOT01_SAVE.len(), 0x1a4..0x1a8);
+    assert_eq!(thumbnail.fields, ["thumbnail"]);
+    assert!(thumbnail.problems.is_empty());
+
+    let end = dump.spans().last().unwrap().range.end;
+    let unknown = check(dump.spans(), SLOT01_SAVE.len(), end - 2..end + 2);
+    assert_eq!(unknown.unknown.len(), 1);
+    assert_eq!(unknown.unknown[0], end..end + 2);
+    assert!(unknown.problems.is_empty());
+}
@@ -0,0 +1,90 @@
+use std::fs;
+
+use fallout_save_editor::{
+    parser::{try_gunzip_buffer, try_map_save},
+    preset::{Change, Preset, Variable},
+    session::Session,
+};
+
+const NCR1_SAVE: &[u8] = include_bytes!("../saves/SLOT01/NCR1.SAV");
+const FRIENDLY_NCR_COPS: &str = include_str!("../presets/friendly-ncr-cops.toml");
+
+#[test]
+fn parses_presets() {
+    let preset = Preset::parse(FRIENDLY_NCR_COPS).unwrap();
+
+    assert_eq!(preset.name.as_deref(), Some("Friendly NCR cops"));
+    assert_eq!(preset.edits.len(), 1);
+    assert_eq!(preset.edits[0].map, "NCR1");
+    assert_eq!(
+        preset.edits[0].variable,
+        Variable::Local {
+            script: 447,
+            index: 5
+        }
+    );
+    assert_eq!(preset.edits[0].only_if, Some(2));
+}
+
+#[test]
+fn edits_need_one_variable() {
+    for edit in [
+        "map = \"NCR1\"\nvalue = 1",
+        "map = \"NCR1\"\nmap_variable = 1\nscript = 447\nlocal_variable = 5\nvalue = 1",
+        "map = \"NCR1\"\nscript = 447\nvalue = 1",
+    ] {
+        assert!(
+            Preset::parse(&format!("[[edit]]\n{edit}")).is_err(),
+            "{edit}"
+        );
+    }
+    assert!(Preset::parse("[[edits]]\nmap = \"NCR1\"").is_err());
+}
+
+#[test]
+fn applies_presets_to_map_saves() {
+    let slot = std::env::temp_dir().join("fallout-save-editor-preset-apply");
+    let _ = fs::remove_dir_all(&slot);
+    fs::create_dir_all(&slot).unwrap();
+    fs::write(slot.join("NCR1.SAV"), NCR1_SAVE).unwrap();
+
+    let preset = Preset::parse(FRIENDLY_NCR_COPS).unwrap();
+    let mut session = Session::new(&slot);
+    let changes = session.apply(&preset.edits[0]).unwrap();
+    assert_eq!(
+        changes,
+        [
+            Change {
+                offset: 0x440,
+                old: 2,
+                new: 0
+            },
+            Change {
+                offset: 0x904,
+                old: 2,
+                new: 0
+            }
+        ]
+    );
+    assert_eq!(session.apply(&preset.edits[0]).unwrap(), []);
+
+    let (_, (_, variables, scripts)) = try_map_save(session.data("NCR1").unwrap()).unwrap();
+    for cop in scripts.iter().filter(|script| script.id == 447) {
+        let offset = cop.local_variable_offset as usize;
+        assert_eq!(variables.local_variables[offset + 5], 0);
+    }
+}
+
+#[test]
+fn missing_variables_are_errors() {
+    let data = try_gunzip_buffer(NCR1_SAVE.to_vec());
+    for edit in [
+        "script = 1\nlocal_variable = 0",
+        "script = 447\nlocal_variable = 1000",
+        "map_variable = 1000",
+    ] {
+        let preset =
+            Preset::parse(&format!("[[edit]]\nmap = \"NCR1\"\nvalue = 1\n{edit}")).unwrap();
+        assert!(preset.edits[0].changes(&data).is_err(), "{edit}");
+    }
+}
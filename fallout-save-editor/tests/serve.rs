@@ -0,0 +1,54 @@
+use std::path::Path;
+
+use fallout_save_editor::serve::route;
+
+fn get(path: &str) -> (u16, &'static str, String) {
+    let response = route(Path::new("saves"), path);
+    (response.status, response.content_type, response.body)
+}
+
+#[test]
+fn pages_link_slots_and_maps() {
+    let (status, content_type, index) = get("/");
+    assert_eq!((status, content_type), (200, "text/html; charset=utf-8"));
+    assert!(index.contains("<a href=\"/SLOT01\">SLOT01</a> start, 2242-06-13 13:08 in NCRENT.sav"));
+
+    let (status, _, slot) = get("/SLOT01");
+    assert_eq!(status, 200);
+    assert!(slot.contains("<tr><th>character_name</th><td>diglet</td></tr>"));
+    assert!(slot.contains("<p>290, 0, 0, 0, 3, 2,"));
+    assert!(slot.contains("<a href=\"/SLOT01/NCR1\">NCR1</a>"));
+    assert!(!slot.contains("AUTOMAP"));
+
+    let (status, _, map) = get("/SLOT01/NCR1?tab=scripts");
+    assert_eq!(status, 200);
+    assert!(map.contains("<tr><th>filename</th><td>NCR1.SAV</td></tr>"));
+    assert!(map.contains("<tr><th>id</th><th>local_variable_count</th>"));
+}
+
+#[test]
+fn exports_are_served_as_json() {
+    let (status, content_type, save) = get("/SLOT01/save.json");
+    assert_eq!((status, content_type), (200, "application/json"));
+    let save: serde_json::Value = serde_json::from_str(&save).unwrap();
+    assert_eq!(save["map_name"], "NCRENT.sav");
+
+    let (status, _, map) = get("/SLOT01/ncr1.json");
+    assert_eq!(status, 200);
+    let map: serde_json::Value = serde_json::from_str(&map).unwrap();
+    assert_eq!(map["filename"], "NCR1.SAV");
+}
+
+#[test]
+fn only_slots_and_maps_are_served() {
+    for path in [
+        "/SLOT99",
+        "/SLOT01/NOSUCHMAP",
+        "/SLOT01/..",
+        "/../saves/SLOT01",
+        "/SLOT01/SAVE.DAT",
+        "/SLOT01/NCR1/extra",
+    ] {
+        assert_eq!(get(path).0, 404, "{path}");
+    }
+}
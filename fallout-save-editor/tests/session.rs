@@ -0,0 +1,132 @@
+use std::{fs, path::PathBuf};
+
+use fallout_save_editor::{
+    parser::{gunzip, try_map_save},
+    preset::Preset,
+    session::Session,
+};
+
+const NCR1_SAVE: &[u8] = include_bytes!("../saves/SLOT01/NCR1.SAV");
+const FRIENDLY_NCR_COPS: &str = include_str!("../presets/friendly-ncr-cops.toml");
+
+fn slot(test: &str) -> PathBuf {
+    let slot = std::env::temp_dir().join(format!("fallout-save-editor-session-{test}"));
+    let _ = fs::remove_dir_all(&slot);
+    fs::create_dir_all(&slot).unwrap();
+    fs::write(slot.join("NCR1.SAV"), NCR1_SAVE).unwrap();
+    slot
+}
+
+fn map_variable(data: &[u8], index: usize) -> i32 {
+    let (_, (_, variables, _)) = try_map_save(data).unwrap();
+    variables.global_variables[index]
+}
+
+#[test]
+fn undoes_and_redoes_operations() {
+    let slot = slot("undo");
+    let mut session = Session::new(&slot);
+
+    session
+        .replace("NCR1", "first", &[(0xec, &[0, 0, 0, 7])])
+        .unwrap();
+    session
+        .replace("ncr1", "second", &[(0xec, &[0, 0, 0, 9])])
+        .unwrap();
+    assert_eq!(map_variable(session.data("NCR1").unwrap(), 0), 9);
+    assert_eq!(session.modified(), ["NCR1"]);
+
+    assert_eq!(session.undo().unwrap().description, "second");
+    assert_eq!(map_variable(session.data("NCR1").unwrap(), 0), 7);
+    assert_eq!(session.undo().unwrap().description, "first");
+    assert_eq!(map_variable(session.data("NCR1").unwrap(), 0), 0);
+    assert!(session.undo().is_none());
+    assert!(session.modified().is_empty());
+
+    assert_eq!(session.redo().unwrap().description, "first");
+    assert_eq!(map_variable(session.data("NCR1").unwrap(), 0), 7);
+    assert_eq!(session.history().len(), 1);
+
+    // A new operation forgets what was undone
+    session
+        .replace("NCR1", "third", &[(0xf0, &[0, 0, 0, 3])])
+        .unwrap();
+    assert!(session.redo().is_none());
+    assert!(session
+        .replace("NCR1", "past the end", &[(usize::MAX / 2, &[0])])
+        .is_err());
+}
+
+#[test]
+fn commits_presets() {
+    let slot = slot("commit");
+    let preset = Preset::parse(FRIENDLY_NCR_COPS).unwrap();
+    let mut session = Session::new(&slot);
+
+    assert_eq!(session.apply(&preset.edits[0]).unwrap().len(), 2);
+    assert_eq!(
+        session.history()[0].description,
+        "script 447 local variable 5 = 0"
+    );
+    assert_eq!(session.apply(&preset.edits[0]).unwrap(), []);
+    assert_eq!(session.history().len(), 1);
+    let edited = session.data("NCR1").unwrap().to_vec();
+
+    // Nothing is written before committing
+    assert_eq!(fs::read(slot.join("NCR1.SAV")).unwrap(), NCR1_SAVE);
+    assert_eq!(session.commit().unwrap(), [slot.join("NCR1.SAV")]);
+
+    let written = fs::read(slot.join("NCR1.SAV")).unwrap();
+    assert!(written.starts_with(&[0x1f, 0x8b]));
+    assert_eq!(gunzip(written).unwrap(), edited);
+    assert!(!slot.join("NCR1.SAV.tmp").exists());
+}
+
+#[test]
+fn does_not_commit_over_changed_files() {
+    let slot = slot("changed");
+    let mut session = Session::new(&slot);
+    session
+        .replace("NCR1", "edit", &[(0xec, &[0, 0, 0, 7])])
+        .unwrap();
+
+    fs::write(slot.join("NCR1.SAV"), b"changed").unwrap();
+    assert!(session.commit().is_err());
+    assert_eq!(fs::read(slot.join("NCR1.SAV")).unwrap(), b"changed");
+}
+
+#[test]
+fn uncompressed_saves_only_change_in_the_replaced_bytes() {
+    let slot = slot("uncompressed");
+    let original = gunzip(NCR1_SAVE.to_vec()).unwrap();
+    fs::write(slot.join("NCR1.SAV"), &original).unwrap();
+
+    let mut session = Session::new(&slot);
+    session
+        .replace("NCR1", "edit", &[(0xec, &[0, 0, 0, 7])])
+        .unwrap();
+    session.commit().unwrap();
+
+    let written = fs::read(slot.join("NCR1.SAV")).unwrap();
+    assert_eq!(written.len(), original.len());
+    let differing: Vec<usize> = (0..written.len())
+        .filter(|&i| written[i] != original[i])
+        .collect();
+    assert_eq!(differing, [0xef]);
+}
+
+#[test]
+fn commits_to_lowercase_map_saves() {
+    let slot = slot("lowercase");
+    fs::rename(slot.join("NCR1.SAV"), slot.join("ncr1.sav")).unwrap();
+
+    let mut session = Session::new(&slot);
+    session
+        .replace("NCR1", "edit", &[(0xec, &[0, 0, 0, 7])])
+        .unwrap();
+    assert_eq!(session.commit().unwrap(), [slot.join("ncr1.sav")]);
+
+    let written = gunzip(fs::read(slot.join("ncr1.sav")).unwrap()).unwrap();
+    assert_eq!(map_variable(&written, 0), 7);
+    assert!(!slot.join("NCR1.SAV").exists());
+}
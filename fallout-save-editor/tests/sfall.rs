@@ -0,0 +1,87 @@
+use std::{fs, path::PathBuf};
+
+use fallout_save_editor::sfall::{self, SfallGlobals, UNIQUE_ID_START};
+
+fn slot(test: &str) -> PathBuf {
+    let slot = std::env::temp_dir().join(format!("fallout-save-editor-sfall-{test}"));
+    let _ = fs::remove_dir_all(&slot);
+    fs::create_dir_all(&slot).unwrap();
+    fs::write(slot.join("SAVE.DAT"), b"FALLOUT SAVE FILE").unwrap();
+    slot
+}
+
+#[test]
+fn globals_round_trip() {
+    let mut data = 1u32.to_le_bytes().to_vec();
+    data.extend_from_slice(b"KILLCNT\0");
+    data.extend_from_slice(&42i32.to_le_bytes());
+    data.extend_from_slice(&[0; 4]);
+    data.extend_from_slice(&0x1000_0005u32.to_le_bytes());
+    data.extend_from_slice(b"newer data");
+
+    let globals = SfallGlobals::parse(&data).unwrap();
+    assert_eq!(
+        globals.globals,
+        vec![(u64::from_le_bytes(*b"KILLCNT\0"), 42)]
+    );
+    assert_eq!(globals.unique_id, Some(0x1000_0005));
+    assert_eq!(globals.to_bytes(), data);
+
+    assert!(SfallGlobals::parse(&data[..10]).is_err());
+    assert_eq!(SfallGlobals::parse(&data[..20]).unwrap().unique_id, None);
+}
+
+#[test]
+fn converts_between_vanilla_and_sfall() {
+    let slot = slot("convert");
+    fs::write(slot.join("SFALLGV.SAV"), SfallGlobals::new().to_bytes()).unwrap();
+    fs::write(slot.join("sfallfs.sav"), [0; 12]).unwrap();
+
+    let moved = sfall::to_vanilla(&slot).unwrap();
+    assert_eq!(
+        moved,
+        vec![slot.join("SFALLGV.SAV.bak"), slot.join("sfallfs.sav.bak")]
+    );
+    assert!(sfall::files(&slot).unwrap().is_empty());
+    assert!(slot.join("SAVE.DAT").exists());
+
+    let mut written = sfall::to_sfall(&slot).unwrap();
+    written.sort();
+    assert_eq!(
+        written,
+        vec![slot.join("SFALLGV.SAV"), slot.join("sfallfs.sav")]
+    );
+    assert!(sfall::to_sfall(&slot).unwrap().is_empty());
+}
+
+#[test]
+fn synthesizes_globals_for_vanilla_saves() {
+    let slot = slot("synthesize");
+
+    assert_eq!(
+        sfall::to_sfall(&slot).unwrap(),
+        vec![slot.join("sfallgv.sav")]
+    );
+    let globals = SfallGlobals::parse(&fs::read(slot.join("sfallgv.sav")).unwrap()).unwrap();
+    assert!(globals.globals.is_empty());
+    assert_eq!(globals.unique_id, Some(UNIQUE_ID_START));
+}
+
+#[test]
+fn reads_the_globals_of_a_slot() {
+    let slot = slot("read");
+    assert_eq!(sfall::globals(&slot).unwrap(), None);
+
+    let mut globals = SfallGlobals::new();
+    globals.globals = vec![(u64::from_le_bytes(*b"KILLCNT\0"), 42), (1234, -1)];
+    fs::write(slot.join("SFALLGV.SAV"), globals.to_bytes()).unwrap();
+    let read = sfall::globals(&slot).unwrap().unwrap();
+    assert_eq!(read, globals);
+
+    let names: Vec<String> = read
+        .globals
+        .iter()
+        .map(|(id, _)| sfall::global_name(*id))
+        .collect();
+    assert_eq!(names, vec!["KILLCNT", "1234"]);
+}
@@ -0,0 +1,66 @@
+use std::{fs, path::PathBuf};
+
+use fallout_save_editor::slot::{map_save_path, map_saves, prunable};
+
+fn slot() -> PathBuf {
+    let slot = std::env::temp_dir().join("fallout-save-editor-slot");
+    let _ = fs::remove_dir_all(&slot);
+    fs::create_dir_all(&slot).unwrap();
+    for file in [
+        "SAVE.DAT",
+        "AUTOMAP.SAV",
+        "sfallgv.sav",
+        "NCR1.SAV",
+        "NCR2.SAV",
+        "ncrent.sav",
+        "ARBRIDGE.SAV",
+    ] {
+        fs::write(slot.join(file), b"").unwrap();
+    }
+    slot
+}
+
+fn patterns(patterns: &[&str]) -> Vec<String> {
+    patterns.iter().map(|pattern| pattern.to_string()).collect()
+}
+
+#[test]
+fn prunes_matching_maps_but_the_current_one() {
+    let slot = slot();
+    let maps = map_saves(&slot).unwrap();
+    assert_eq!(
+        maps,
+        ["ARBRIDGE.SAV", "NCR1.SAV", "NCR2.SAV", "ncrent.sav"].map(|file| slot.join(file))
+    );
+
+    assert_eq!(
+        prunable(&maps, &patterns(&["NCR*"]), "NCRENT.sav").unwrap(),
+        vec![slot.join("NCR1.SAV"), slot.join("NCR2.SAV")]
+    );
+    assert_eq!(
+        prunable(&maps, &patterns(&["arbridge.sav", "NCR2"]), "NCRENT.sav").unwrap(),
+        vec![slot.join("ARBRIDGE.SAV"), slot.join("NCR2.SAV")]
+    );
+}
+
+#[test]
+fn refuses_the_current_map_and_nothing() {
+    let maps = vec![PathBuf::from("SLOT01/NCRENT.SAV")];
+
+    assert!(prunable(&maps, &patterns(&["NCRENT"]), "NCRENT.sav").is_err());
+    assert!(prunable(&maps, &patterns(&["*"]), "NCRENT.sav").is_err());
+    assert!(prunable(&maps, &patterns(&["DEN*"]), "NCRENT.sav").is_err());
+}
+
+#[test]
+fn finds_map_saves_in_any_case() {
+    let slot = slot();
+
+    assert_eq!(map_save_path(&slot, "ncr1").unwrap(), slot.join("NCR1.SAV"));
+    assert_eq!(
+        map_save_path(&slot, "NCRENT").unwrap(),
+        slot.join("ncrent.sav")
+    );
+    assert!(map_save_path(&slot, "AUTOMAP").is_err());
+    assert!(map_save_path(&slot, "DENBUS1").is_err());
+}
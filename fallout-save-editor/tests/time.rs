@@ -0,0 +1,58 @@
+use fallout_save_editor::{
+    parser::header,
+    time::{date, elapsed, GameTime, TICKS_PER_DAY, TICKS_PER_HOUR, TICKS_PER_MINUTE},
+};
+
+const SLOT01_SAVE: &[u8] = include_bytes!("../saves/SLOT01/SAVE.DAT");
+
+#[test]
+fn ticks_count_from_the_start_of_the_game() {
+    assert_eq!(
+        GameTime::from_ticks(0),
+        GameTime {
+            year: 2241,
+            month: 7,
+            day: 25,
+            hour: 0,
+            minute: 0,
+        }
+    );
+    assert_eq!(
+        GameTime::from_ticks(160 * TICKS_PER_DAY + 23 * TICKS_PER_HOUR).to_string(),
+        "2242-01-01 23:00"
+    );
+}
+
+#[test]
+fn game_time_matches_the_save_header() {
+    let (_, save) = header(SLOT01_SAVE).unwrap();
+    let time = GameTime::from_ticks(save.ingame_ticks);
+
+    assert_eq!(
+        (time.year, time.month, time.day),
+        (
+            save.ingame_year as u32,
+            save.ingame_month as u32,
+            save.ingame_day as u32
+        )
+    );
+    assert_eq!(time.to_string(), "2242-06-13 13:08");
+    assert_eq!(
+        date(save.save_year, save.save_month, save.save_day),
+        "2024-06-02"
+    );
+}
+
+#[test]
+fn elapsed_time_leaves_out_zeroes() {
+    assert_eq!(elapsed(0), "less than a minute");
+    assert_eq!(elapsed(TICKS_PER_MINUTE), "1 minute");
+    assert_eq!(
+        elapsed(2 * TICKS_PER_DAY + 5 * TICKS_PER_MINUTE),
+        "2 days and 5 minutes"
+    );
+    assert_eq!(
+        elapsed(TICKS_PER_DAY + TICKS_PER_HOUR + 3 * TICKS_PER_MINUTE),
+        "1 day, 1 hour and 3 minutes"
+    );
+}
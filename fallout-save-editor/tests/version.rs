@@ -0,0 +1,105 @@
+use std::fs;
+
+use fallout_save_editor::{
+    index::{SlotIndex, INDEX_FILE},
+    parser::{header, try_gunzip_buffer},
+    session::Session,
+    version::{self, EDITING, GLOBAL_VARIABLES, SAVE_VERSION},
+};
+
+const SLOT01_SAVE: &[u8] = include_bytes!("../saves/SLOT01/SAVE.DAT");
+const NCR1_SAVE: &[u8] = include_bytes!("../saves/SLOT01/NCR1.SAV");
+
+/// Offset of the version in SAVE.DAT, after the magic and steam padding
+const SAVE_VERSION_OFFSET: usize = 24;
+
+fn with_version(data: &[u8], offset: usize, version: u32) -> Vec<u8> {
+    let mut data = data.to_vec();
+    data[offset..offset + 4].copy_from_slice(&version.to_be_bytes());
+    data
+}
+
+#[test]
+fn known_versions_have_everything() {
+    let (_, save) = header(SLOT01_SAVE).unwrap();
+    let support = version::save(&save);
+    assert_eq!(support.version, SAVE_VERSION);
+    assert!(support.known && support.unavailable.is_empty());
+    assert_eq!(support.warning("SAVE.DAT"), None);
+
+    let (support, (map, _, _)) =
+        version::try_map_save(&try_gunzip_buffer(NCR1_SAVE.to_vec())).unwrap();
+    assert!(support.known);
+    assert_eq!(map.filename, "NCR1.SAV");
+    assert!(support.require(EDITING, "NCR1.SAV").is_ok());
+}
+
+#[test]
+fn unknown_save_versions_turn_features_off() {
+    let data = with_version(SLOT01_SAVE, SAVE_VERSION_OFFSET, 0x0001_0003);
+    let (_, save) = header(&data).unwrap();
+    let support = version::save(&save);
+
+    assert!(!support.known);
+    assert_eq!(support.unavailable, [GLOBAL_VARIABLES, EDITING]);
+    assert_eq!(
+        support.warning("SAVE.DAT").unwrap(),
+        "SAVE.DAT is version 65539, which isn't known. It was read best-effort, global \
+         variables and editing are not available"
+    );
+    assert_eq!(version::save_version(save.version), support);
+    assert_eq!(
+        support
+            .require(GLOBAL_VARIABLES, "SAVE.DAT")
+            .unwrap_err()
+            .to_string(),
+        "Can't use global variables with SAVE.DAT, its version 65539 isn't known"
+    );
+}
+
+#[test]
+fn unknown_map_versions_are_read_as_fallout_2() {
+    let data = with_version(&try_gunzip_buffer(NCR1_SAVE.to_vec()), 0, 21);
+    let (support, (map, variables, scripts)) = version::try_map_save(&data).unwrap();
+
+    assert_eq!(support.version, 21);
+    assert_eq!(support.unavailable, [EDITING]);
+    assert_eq!(map.filename, "NCR1.SAV");
+    assert!(!variables.global_variables.is_empty());
+    assert!(scripts.iter().any(|script| script.id == 447));
+
+    let garbage = with_version(b"not a map save, not even close", 0, 21);
+    assert_eq!(
+        version::try_map_save(&garbage).unwrap_err().to_string(),
+        "Map save version 21 isn't known and it couldn't be read as Fallout 2's"
+    );
+    assert!(version::try_map_save(&[0, 0]).is_err());
+}
+
+#[test]
+fn indexed_slots_keep_their_version() {
+    let saves = std::env::temp_dir().join("fallout-save-editor-version-index");
+    let _ = fs::remove_dir_all(&saves);
+    fs::create_dir_all(saves.join("SLOT01")).unwrap();
+    let save = saves.join("SLOT01").join("SAVE.DAT");
+    let data = with_version(SLOT01_SAVE, SAVE_VERSION_OFFSET, 0x0001_0003);
+    fs::write(&save, data).unwrap();
+
+    let mut index = SlotIndex::load(&saves.join(INDEX_FILE)).unwrap();
+    let summary = index.summary("SLOT01", &save).unwrap().unwrap();
+    assert!(!version::save_version(summary.version).known);
+}
+
+#[test]
+fn unknown_map_versions_are_not_edited() {
+    let slot = std::env::temp_dir().join("fallout-save-editor-version");
+    let _ = fs::remove_dir_all(&slot);
+    fs::create_dir_all(&slot).unwrap();
+    let data = with_version(&try_gunzip_buffer(NCR1_SAVE.to_vec()), 0, 21);
+    fs::write(slot.join("NCR1.SAV"), data).unwrap();
+
+    let mut session = Session::new(&slot);
+    assert!(session
+        .replace("NCR1", "edit", &[(0xec, &[0, 0, 0, 7])])
+        .is_err());
+}
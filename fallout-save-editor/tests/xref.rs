@@ -0,0 +1,54 @@
+use fallout_save_editor::{
+    parser::{map_save, try_gunzip_buffer, Script, ScriptTagType},
+    xref::{cross_reference, render, ScriptReference},
+};
+
+const GSTCAV1_SAVE: &[u8] = include_bytes!("../saves/SLOT01/GSTCAV1.SAV");
+const MODINN_SAVE: &[u8] = include_bytes!("../saves/SLOT01/MODINN.SAV");
+
+fn scripts(save: &[u8]) -> Vec<Script> {
+    let (_, _, scripts) = map_save(&try_gunzip_buffer(save.to_vec()));
+    scripts
+}
+
+#[test]
+fn references_scripts_across_maps() {
+    let maps = vec![
+        ("GSTCAV1".to_string(), scripts(GSTCAV1_SAVE)),
+        ("MODINN".to_string(), scripts(MODINN_SAVE)),
+    ];
+    let references = cross_reference(&maps);
+
+    assert_eq!(
+        references[&560],
+        vec![
+            ScriptReference {
+                map: "GSTCAV1".to_string(),
+                instances: 1,
+                local_variables: 8,
+            },
+            ScriptReference {
+                map: "MODINN".to_string(),
+                instances: 11,
+                local_variables: 88,
+            },
+        ]
+    );
+    assert_eq!(references[&206][0].local_variables, 1);
+    assert!(render(&references)
+        .contains("  560  GSTCAV1 x1 (8 variables), MODINN x11 (88 variables)\n"));
+}
+
+#[test]
+fn scripts_without_variables_own_none() {
+    let script = Script {
+        _prefix_junk: Vec::new(),
+        id: 269,
+        script_type: ScriptTagType::Critters,
+        local_variable_offset: -1,
+        local_variable_count: -1,
+    };
+    let references = cross_reference(&[("NCR1".to_string(), vec![script])]);
+
+    assert_eq!(references[&269][0].local_variables, 0);
+}
@@ -0,0 +1,260 @@
+/// Annotated hexdumps: byte ranges of a file labeled and colored, printed to the terminal or as an
+/// HTML page. Made for reverse engineering save formats, where knowing which bytes are already
+/// accounted for is most of the work.
+use std::ops::Range;
+
+/// Bytes per row
+const WIDTH: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+/// Colors given to spans added without one, in turn
+const PALETTE: [Color; 6] = [
+    Color::Cyan,
+    Color::Yellow,
+    Color::Green,
+    Color::Magenta,
+    Color::Blue,
+    Color::Red,
+];
+
+impl Color {
+    /// SGR foreground color code
+    fn ansi(self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+        }
+    }
+
+    fn css(self) -> &'static str {
+        match self {
+            Color::Red => "#e06c75",
+            Color::Green => "#98c379",
+            Color::Yellow => "#e5c07b",
+            Color::Blue => "#61afef",
+            Color::Magenta => "#c678dd",
+            Color::Cyan => "#56b6c2",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span {
+    pub range: Range<usize>,
+    pub label: String,
+    pub color: Color,
+}
+
+pub struct Hexdump<'a> {
+    data: &'a [u8],
+    spans: Vec<Span>,
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl<'a> Hexdump<'a> {
+    pub fn new(data: &'a [u8]) -> Hexdump<'a> {
+        Hexdump {
+            data,
+            spans: Vec::new(),
+        }
+    }
+
+    /// Labels the bytes in `range`, colored with the next color of the palette.
+    pub fn add(&mut self, range: Range<usize>, label: impl Into<String>) -> &mut Self {
+        let color = PALETTE[self.spans.len() % PALETTE.len()];
+        self.add_colored(range, label, color)
+    }
+
+    pub fn add_colored(
+        &mut self,
+        range: Range<usize>,
+        label: impl Into<String>,
+        color: Color,
+    ) -> &mut Self {
+        self.spans.push(Span {
+            range,
+            label: label.into(),
+            color,
+        });
+        self
+    }
+
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+
+    /// Span coloring each byte, the shortest one covering it. Parts of spans past the end of the
+    /// data are ignored.
+    fn owners(&self) -> Vec<Option<usize>> {
+        let mut owners = vec![None; self.data.len()];
+        let mut longest_first: Vec<usize> = (0..self.spans.len()).collect();
+        longest_first.sort_by_key(|&i| std::cmp::Reverse(self.spans[i].range.len()));
+        for i in longest_first {
+            let range = &self.spans[i].range;
+            let end = range.end.min(self.data.len());
+            for owner in &mut owners[range.start.min(end)..end] {
+                *owner = Some(i);
+            }
+        }
+        owners
+    }
+
+    /// Lays out the rows, `paint` wraps a piece of text in the color of the span it belongs to.
+    fn render(&self, paint: impl Fn(&str, Option<&Span>) -> String) -> String {
+        let owners = self.owners();
+        let mut labels: Vec<Vec<usize>> = vec![Vec::new(); self.data.len().div_ceil(WIDTH)];
+        for (i, span) in self.spans.iter().enumerate() {
+            if let Some(row) = labels.get_mut(span.range.start / WIDTH) {
+                row.push(i);
+            }
+        }
+
+        let mut out = String::new();
+        let mut skipping = false;
+        for (row, chunk) in self.data.chunks(WIDTH).enumerate() {
+            let start = row * WIDTH;
+            let row_owners = &owners[start..start + chunk.len()];
+            let repeated = row > 0
+                && labels[row].is_empty()
+                && chunk == &self.data[start - WIDTH..start]
+                && row_owners == &owners[start - WIDTH..start];
+            if repeated {
+                if !skipping {
+                    out.push_str("*\n");
+                }
+                skipping = true;
+                continue;
+            }
+            skipping = false;
+
+            let span = |i: usize| row_owners[i].map(|owner| &self.spans[owner]);
+            out.push_str(&format!("{start:08x}  "));
+            for i in 0..WIDTH {
+                if i == WIDTH / 2 {
+                    out.push(' ');
+                }
+                match chunk.get(i) {
+                    Some(byte) => out.push_str(&paint(&format!("{byte:02x}"), span(i))),
+                    None => out.push_str("  "),
+                }
+                out.push(' ');
+            }
+
+            out.push_str(" |");
+            for (i, &byte) in chunk.iter().enumerate() {
+                let printable = if byte.is_ascii_graphic() || byte == b' ' {
+                    byte as char
+                } else {
+                    '.'
+                };
+                out.push_str(&paint(&printable.to_string(), span(i)));
+            }
+            out.push('|');
+
+            let row_labels: Vec<String> = labels[row]
+                .iter()
+                .map(|&i| paint(&self.spans[i].label, Some(&self.spans[i])))
+                .collect();
+            if !row_labels.is_empty() {
+                out.push_str("  ");
+                out.push_str(&row_labels.join(", "));
+            }
+            out.push('\n');
+        }
+        out.push_str(&format!("{:08x}\n", self.data.len()));
+        out
+    }
+
+    /// Rows for the terminal, colored with ANSI escapes when `colors` is set.
+    pub fn terminal(&self, colors: bool) -> String {
+        self.render(|text, span| match span {
+            Some(span) if colors => format!("\x1b[{}m{text}\x1b[0m", span.color.ansi()),
+            _ => text.to_string(),
+        })
+    }
+
+    /// Standalone HTML page of the rows, hovering a byte shows the label of its span.
+    pub fn html(&self, title: &str) -> String {
+        let rows = self.render(|text, span| match span {
+            Some(span) => format!(
+                "<span style=\"color: {}\" title=\"{}\">{}</span>",
+                span.color.css(),
+                escape_html(&span.label),
+                escape_html(text)
+            ),
+            None => escape_html(text),
+        });
+        format!(
+            "<!DOCTYPE html>\n\
+             <html>\n\
+             <head>\n\
+             <meta charset=\"utf-8\">\n\
+             <title>{}</title>\n\
+             <style>body {{ background: #1e1e1e; color: #abb2bf; }}</style>\n\
+             </head>\n\
+             <body>\n\
+             <pre>\n{rows}</pre>\n\
+             </body>\n\
+             </html>\n",
+            escape_html(title)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labels_rows_and_collapses_repeats() {
+        let mut data = b"FALLOUT SAVE FILE".to_vec();
+        data.resize(80, 0);
+        let mut dump = Hexdump::new(&data);
+        dump.add(0..18, "magic").add(18..22, "version");
+
+        assert_eq!(
+            dump.terminal(false),
+            "00000000  46 41 4c 4c 4f 55 54 20  53 41 56 45 20 46 49 4c  |FALLOUT SAVE FIL|  magic\n\
+             00000010  45 00 00 00 00 00 00 00  00 00 00 00 00 00 00 00  |E...............|  version\n\
+             00000020  00 00 00 00 00 00 00 00  00 00 00 00 00 00 00 00  |................|\n\
+             *\n\
+             00000050\n"
+        );
+    }
+
+    #[test]
+    fn innermost_span_colors_the_byte() {
+        let data = [1, 2, 3];
+        let mut dump = Hexdump::new(&data);
+        dump.add_colored(0..3, "record", Color::Red)
+            .add_colored(1..2, "id", Color::Blue)
+            .add(2..100, "past the end");
+
+        assert_eq!(dump.owners(), vec![Some(0), Some(1), Some(0)]);
+        assert!(dump
+            .terminal(true)
+            .starts_with("00000000  \x1b[31m01\x1b[0m \x1b[34m02\x1b[0m "));
+        let html = dump.html("<SAVE.DAT>");
+        assert!(html.contains("<title>&lt;SAVE.DAT&gt;</title>"));
+        assert!(html.contains("<span style=\"color: #61afef\" title=\"id\">02</span>"));
+    }
+}
@@ -0,0 +1,8 @@
+#![no_main]
+
+use kotor_save_editor::erf::Erf;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = Erf::parse(data);
+});
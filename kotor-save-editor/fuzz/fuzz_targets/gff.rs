@@ -0,0 +1,11 @@
+#![no_main]
+
+use kotor_save_editor::gff::Gff;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(gff) = Gff::parse(data) {
+        // Whatever parses has to write back
+        let _ = gff.to_bytes();
+    }
+});
@@ -0,0 +1,19 @@
+{
+  pkgs,
+}:
+{
+  package = pkgs.rustPlatform.buildRustPackage {
+    pname = "kotor-save-editor";
+    version = "0.1.0";
+    cargoLock.lockFile = ./Cargo.lock;
+    # hexdump and archive are path dependencies, they have to be in the source too
+    src = pkgs.lib.cleanSource ./..;
+    cargoRoot = "kotor-save-editor";
+    buildAndTestSubdir = "kotor-save-editor";
+  };
+  devShell = pkgs.mkShell {
+    buildInputs = with pkgs; [
+      cargo
+    ];
+  };
+}
@@ -0,0 +1,330 @@
+/// ERF (Encapsulated Resource File) reader and writer. Modules (`.mod`), hak paks and saves
+/// (`.sav`) are all ERF archives, only the file type in the header differs.
+///
+/// A save directory holds `SAVEGAME.sav`, an archive of the inventory, the companions and a
+/// nested `.sav` archive per visited module.
+use std::io::{self, Read};
+
+use archive::{Entry, ReadableArchive};
+use hexdump::Hexdump;
+
+const HEADER_SIZE: usize = 160;
+const KEY_SIZE: usize = 24;
+const RESOURCE_SIZE: usize = 8;
+const RESREF_SIZE: usize = 16;
+
+/// Header fields after the file type and version, the rest of the header is reserved
+const HEADER_FIELDS: [&str; 9] = [
+    "language count",
+    "localized strings size",
+    "entry count",
+    "localized strings offset",
+    "key offset",
+    "resource offset",
+    "build year",
+    "build day",
+    "description strref",
+];
+
+/// Generic GFF resources without an extension of their own, e.g. `INVENTORY.res`
+pub const RESOURCE_TYPE_RES: u16 = 0;
+/// Creature instances, the companions are stored as `AVAILNPC<n>.utc`
+pub const RESOURCE_TYPE_UTC: u16 = 2027;
+/// Module state archives nested in `SAVEGAME.sav`
+pub const RESOURCE_TYPE_SAV: u16 = 2057;
+
+/// File extensions and the resource type ids the game uses for them
+const EXTENSIONS: &[(&str, u16)] = &[
+    ("res", RESOURCE_TYPE_RES),
+    ("bmp", 1),
+    ("tga", 3),
+    ("wav", 4),
+    ("mdl", 2002),
+    ("nss", 2009),
+    ("ncs", 2010),
+    ("are", 2012),
+    ("ifo", 2014),
+    ("wok", 2016),
+    ("2da", 2017),
+    ("txi", 2022),
+    ("git", 2023),
+    ("uti", 2025),
+    ("utc", RESOURCE_TYPE_UTC),
+    ("dlg", 2029),
+    ("utt", 2032),
+    ("uts", 2035),
+    ("utd", 2042),
+    ("utp", 2044),
+    ("gui", 2047),
+    ("utm", 2051),
+    ("jrl", 2056),
+    ("sav", RESOURCE_TYPE_SAV),
+    ("utw", 2058),
+    ("ssf", 2060),
+    ("lyt", 3000),
+    ("vis", 3001),
+    ("lip", 3004),
+    ("tpc", 3007),
+    ("mdx", 3008),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Resource {
+    /// Resref, lower case
+    pub name: String,
+    pub resource_type: u16,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Erf {
+    /// Four character file type, e.g. `MOD `
+    pub file_type: String,
+    pub resources: Vec<Resource>,
+    /// Localized descriptions, kept as is since nothing here edits them
+    localized_strings: Vec<u8>,
+    language_count: u32,
+    build_year: u32,
+    build_day: u32,
+    description_strref: u32,
+}
+
+impl Resource {
+    /// Resref with the extension of the resource type, e.g. `availnpc0.utc`. Types without a
+    /// known extension get their id instead.
+    pub fn file_name(&self) -> String {
+        match EXTENSIONS
+            .iter()
+            .find(|(_, resource_type)| *resource_type == self.resource_type)
+        {
+            Some((extension, _)) => format!("{}.{extension}", self.name),
+            None => format!("{}.{}", self.name, self.resource_type),
+        }
+    }
+}
+
+/// Resources as archive entries, for the archives that keep all of them in memory.
+pub(crate) fn entries(resources: &[Resource]) -> Vec<Entry> {
+    resources
+        .iter()
+        .map(|resource| Entry {
+            name: resource.file_name(),
+            size: resource.data.len() as u64,
+            packed_size: None,
+        })
+        .collect()
+}
+
+pub(crate) fn open<'a>(resources: &'a [Resource], name: &str) -> io::Result<Box<dyn Read + 'a>> {
+    resources
+        .iter()
+        .find(|resource| resource.file_name().eq_ignore_ascii_case(name))
+        .map(|resource| Box::new(&resource.data[..]) as Box<dyn Read>)
+        .ok_or_else(|| archive::not_found(name))
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn bytes(data: &[u8], offset: usize, length: usize) -> io::Result<&[u8]> {
+    offset
+        .checked_add(length)
+        .and_then(|end| data.get(offset..end))
+        .ok_or_else(|| invalid_data("ERF ended in the middle of a value"))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> io::Result<u32> {
+    let b = bytes(data, offset, 4)?;
+    Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+impl Erf {
+    /// Empty archive of the given file type.
+    pub fn new(file_type: &str) -> Erf {
+        Erf {
+            file_type: file_type.to_string(),
+            resources: Vec::new(),
+            localized_strings: Vec::new(),
+            language_count: 0,
+            build_year: 0,
+            build_day: 0,
+            description_strref: u32::MAX,
+        }
+    }
+
+    pub fn parse(data: &[u8]) -> io::Result<Erf> {
+        if data.len() < HEADER_SIZE || &data[4..8] != b"V1.0" {
+            return Err(invalid_data("Not an ERF V1.0 file"));
+        }
+
+        let header = |index: usize| read_u32(data, 8 + index * 4);
+        let language_count = header(0)?;
+        let localized_size = header(1)? as usize;
+        let entry_count = header(2)? as usize;
+        let localized_offset = header(3)? as usize;
+        let key_offset = header(4)? as usize;
+        let resource_offset = header(5)? as usize;
+
+        // Each entry takes a key, so a count larger than the file can hold is corrupt
+        let mut resources = Vec::with_capacity(entry_count.min(data.len() / KEY_SIZE));
+        for i in 0..entry_count {
+            let key = bytes(data, key_offset + i * KEY_SIZE, KEY_SIZE)?;
+            let name_length = key[..RESREF_SIZE]
+                .iter()
+                .position(|&b| b == 0)
+                .unwrap_or(RESREF_SIZE);
+            let name = String::from_utf8_lossy(&key[..name_length]).to_ascii_lowercase();
+            let resource_type = u16::from_le_bytes([key[20], key[21]]);
+
+            let entry = resource_offset + i * RESOURCE_SIZE;
+            let offset = read_u32(data, entry)? as usize;
+            let size = read_u32(data, entry + 4)? as usize;
+
+            resources.push(Resource {
+                name,
+                resource_type,
+                data: bytes(data, offset, size)?.to_vec(),
+            });
+        }
+
+        Ok(Erf {
+            file_type: String::from_utf8_lossy(&data[0..4]).into_owned(),
+            resources,
+            localized_strings: bytes(data, localized_offset, localized_size)?.to_vec(),
+            language_count,
+            build_year: header(6)?,
+            build_day: header(7)?,
+            description_strref: header(8)?,
+        })
+    }
+
+    /// Hexdump of an ERF archive with the header, the descriptions and every key, resource entry
+    /// and resource labeled.
+    pub fn annotate(data: &[u8]) -> io::Result<Hexdump<'_>> {
+        if data.len() < HEADER_SIZE || &data[4..8] != b"V1.0" {
+            return Err(invalid_data("Not an ERF V1.0 file"));
+        }
+
+        let mut dump = Hexdump::new(data);
+        dump.add(0..4, "file type").add(4..8, "version");
+        for (i, label) in HEADER_FIELDS.iter().enumerate() {
+            dump.add(8 + i * 4..12 + i * 4, *label);
+        }
+        let reserved = 8 + HEADER_FIELDS.len() * 4;
+        dump.add(reserved..HEADER_SIZE, "reserved");
+
+        let header = |index: usize| read_u32(data, 8 + index * 4).map(|value| value as usize);
+        let localized_offset = header(3)?;
+        dump.add(
+            localized_offset..localized_offset + header(1)?,
+            "localized strings",
+        );
+
+        let (key_offset, resource_offset) = (header(4)?, header(5)?);
+        // The count can be anything in a broken file, there's no more keys than there's data
+        for i in 0..header(2)?.min(data.len() / KEY_SIZE) {
+            let key = key_offset + i * KEY_SIZE;
+            let Ok(key_bytes) = bytes(data, key, KEY_SIZE) else {
+                break;
+            };
+            let name_length = key_bytes[..RESREF_SIZE]
+                .iter()
+                .position(|&b| b == 0)
+                .unwrap_or(RESREF_SIZE);
+            let name = String::from_utf8_lossy(&key_bytes[..name_length]).to_ascii_lowercase();
+            let resource_type = u16::from_le_bytes([key_bytes[20], key_bytes[21]]);
+            dump.add(
+                key..key + KEY_SIZE,
+                format!("key {name}, type {resource_type}"),
+            );
+
+            let entry = resource_offset + i * RESOURCE_SIZE;
+            dump.add(
+                entry..entry + RESOURCE_SIZE,
+                format!("{name} offset and size"),
+            );
+            if let (Ok(offset), Ok(size)) = (read_u32(data, entry), read_u32(data, entry + 4)) {
+                let offset = offset as usize;
+                dump.add(offset..offset + size as usize, name);
+            }
+        }
+        Ok(dump)
+    }
+
+    pub fn get(&self, name: &str, resource_type: u16) -> Option<&Resource> {
+        self.resources.iter().find(|resource| {
+            resource.resource_type == resource_type && resource.name.eq_ignore_ascii_case(name)
+        })
+    }
+
+    pub fn get_mut(&mut self, name: &str, resource_type: u16) -> Option<&mut Resource> {
+        self.resources.iter_mut().find(|resource| {
+            resource.resource_type == resource_type && resource.name.eq_ignore_ascii_case(name)
+        })
+    }
+
+    /// Serializes the archive: header, descriptions, keys, resource list and then the data.
+    pub fn to_bytes(&self) -> io::Result<Vec<u8>> {
+        let localized_offset = HEADER_SIZE;
+        let key_offset = localized_offset + self.localized_strings.len();
+        let resource_offset = key_offset + self.resources.len() * KEY_SIZE;
+        let data_offset = resource_offset + self.resources.len() * RESOURCE_SIZE;
+
+        let mut file_type = self.file_type.as_bytes().to_vec();
+        file_type.resize(4, b' ');
+        let mut data = file_type[..4].to_vec();
+        data.extend_from_slice(b"V1.0");
+        for value in [
+            self.language_count,
+            self.localized_strings.len() as u32,
+            self.resources.len() as u32,
+            localized_offset as u32,
+            key_offset as u32,
+            resource_offset as u32,
+            self.build_year,
+            self.build_day,
+            self.description_strref,
+        ] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.resize(HEADER_SIZE, 0);
+        data.extend_from_slice(&self.localized_strings);
+
+        for (id, resource) in self.resources.iter().enumerate() {
+            if resource.name.len() > RESREF_SIZE {
+                return Err(invalid_data(
+                    "ERF resource name is longer than 16 characters",
+                ));
+            }
+            let mut name = [0u8; RESREF_SIZE];
+            name[..resource.name.len()].copy_from_slice(resource.name.as_bytes());
+
+            data.extend_from_slice(&name);
+            data.extend_from_slice(&(id as u32).to_le_bytes());
+            data.extend_from_slice(&resource.resource_type.to_le_bytes());
+            data.extend_from_slice(&[0, 0]);
+        }
+
+        let mut offset = data_offset;
+        for resource in &self.resources {
+            data.extend_from_slice(&(offset as u32).to_le_bytes());
+            data.extend_from_slice(&(resource.data.len() as u32).to_le_bytes());
+            offset += resource.data.len();
+        }
+        for resource in &self.resources {
+            data.extend_from_slice(&resource.data);
+        }
+        Ok(data)
+    }
+}
+
+impl ReadableArchive for Erf {
+    fn entries(&self) -> Vec<Entry> {
+        entries(&self.resources)
+    }
+
+    fn open(&self, name: &str) -> io::Result<Box<dyn Read + '_>> {
+        open(&self.resources, name)
+    }
+}
@@ -0,0 +1,633 @@
+/// GFF (Generic File Format) reader and writer. Most of the game's data is GFF: module info
+/// (`.ifo`), area instances (`.git`), templates (`.utc`, `.uti`, ...), dialogues and saves.
+///
+/// The whole file is read into a tree of structs, edited in place and written back out from
+/// scratch. Writing doesn't try to reproduce the original layout byte for byte, the game only
+/// cares about the tree.
+use std::io;
+
+use hexdump::Hexdump;
+
+const HEADER_SIZE: usize = 56;
+const STRUCT_SIZE: usize = 12;
+const FIELD_SIZE: usize = 12;
+const LABEL_SIZE: usize = 16;
+
+/// Header fields after the file type and version, offset and count of each section
+const HEADER_FIELDS: [&str; 12] = [
+    "struct offset",
+    "struct count",
+    "field offset",
+    "field count",
+    "label offset",
+    "label count",
+    "field data offset",
+    "field data size",
+    "field indices offset",
+    "field indices size",
+    "list indices offset",
+    "list indices size",
+];
+
+/// Names of the field types by their number
+const FIELD_TYPES: [&str; 18] = [
+    "byte",
+    "char",
+    "word",
+    "short",
+    "dword",
+    "int",
+    "dword64",
+    "int64",
+    "float",
+    "double",
+    "string",
+    "resref",
+    "locstring",
+    "void",
+    "struct",
+    "list",
+    "orientation",
+    "vector",
+];
+
+/// Localized string, a StrRef to `dialog.tlk` and optional strings embedded per language
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocString {
+    pub strref: u32,
+    pub strings: Vec<(u32, String)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GffValue {
+    Byte(u8),
+    Char(i8),
+    Word(u16),
+    Short(i16),
+    Dword(u32),
+    Int(i32),
+    Dword64(u64),
+    Int64(i64),
+    Float(f32),
+    Double(f64),
+    String(String),
+    ResRef(String),
+    LocString(LocString),
+    Void(Vec<u8>),
+    Struct(GffStruct),
+    List(Vec<GffStruct>),
+    Orientation([f32; 4]),
+    Vector([f32; 3]),
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GffStruct {
+    pub struct_type: u32,
+    pub fields: Vec<(String, GffValue)>,
+}
+
+impl GffStruct {
+    pub fn get(&self, label: &str) -> Option<&GffValue> {
+        self.fields
+            .iter()
+            .find(|(name, _)| name == label)
+            .map(|(_, value)| value)
+    }
+
+    /// Reads a numeric field as `f32`, whatever its stored type.
+    pub fn get_f32(&self, label: &str) -> Option<f32> {
+        match self.get(label)? {
+            GffValue::Float(value) => Some(*value),
+            GffValue::Double(value) => Some(*value as f32),
+            other => other.as_i64().map(|value| value as f32),
+        }
+    }
+
+    /// Reads an integer field as `i64`, whatever its stored type.
+    pub fn get_i64(&self, label: &str) -> Option<i64> {
+        self.get(label)?.as_i64()
+    }
+
+    /// Reads a string, resref or the first embedded localized string.
+    pub fn get_str(&self, label: &str) -> Option<&str> {
+        match self.get(label)? {
+            GffValue::String(value) | GffValue::ResRef(value) => Some(value),
+            GffValue::LocString(value) => value.strings.first().map(|(_, s)| s.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Returns the list or an empty slice when there's no such list.
+    pub fn get_list(&self, label: &str) -> &[GffStruct] {
+        match self.get(label) {
+            Some(GffValue::List(list)) => list,
+            _ => &[],
+        }
+    }
+
+    pub fn get_mut(&mut self, label: &str) -> Option<&mut GffValue> {
+        self.fields
+            .iter_mut()
+            .find(|(name, _)| name == label)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the list for editing or `None` when there's no such list.
+    pub fn get_list_mut(&mut self, label: &str) -> Option<&mut Vec<GffStruct>> {
+        match self.get_mut(label)? {
+            GffValue::List(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    /// Replaces the value of a field, adding the field when there's none.
+    pub fn set(&mut self, label: &str, value: GffValue) {
+        match self.get_mut(label) {
+            Some(existing) => *existing = value,
+            None => self.fields.push((label.to_string(), value)),
+        }
+    }
+}
+
+impl GffValue {
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self {
+            GffValue::Byte(value) => Some(value.into()),
+            GffValue::Char(value) => Some(value.into()),
+            GffValue::Word(value) => Some(value.into()),
+            GffValue::Short(value) => Some(value.into()),
+            GffValue::Dword(value) => Some(value.into()),
+            GffValue::Int(value) => Some(value.into()),
+            GffValue::Dword64(value) => i64::try_from(value).ok(),
+            GffValue::Int64(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gff {
+    /// Four character file type, e.g. `GIT `
+    pub file_type: String,
+    pub root: GffStruct,
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn bytes(data: &[u8], offset: usize, length: usize) -> io::Result<&[u8]> {
+    offset
+        .checked_add(length)
+        .and_then(|end| data.get(offset..end))
+        .ok_or_else(|| invalid_data("GFF ended in the middle of a value"))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> io::Result<u32> {
+    let b = bytes(data, offset, 4)?;
+    Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+fn read_f32s<const N: usize>(data: &[u8], offset: usize) -> io::Result<[f32; N]> {
+    let mut values = [0.0; N];
+    for (i, value) in values.iter_mut().enumerate() {
+        *value = f32::from_bits(read_u32(data, offset + i * 4)?);
+    }
+    Ok(values)
+}
+
+/// Strings are Windows-1252, Latin-1 gets everything but a few punctuation marks right.
+fn decode(bytes: &[u8]) -> String {
+    bytes.iter().map(|&b| b as char).collect()
+}
+
+/// Inverse of `decode`, characters Latin-1 doesn't have become question marks.
+fn encode(value: &str) -> Vec<u8> {
+    value
+        .chars()
+        .map(|c| u8::try_from(u32::from(c)).unwrap_or(b'?'))
+        .collect()
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    struct_offset: usize,
+    struct_count: usize,
+    field_offset: usize,
+    field_count: usize,
+    label_offset: usize,
+    field_data_offset: usize,
+    field_indices_offset: usize,
+    list_indices_offset: usize,
+}
+
+/// Structs can't legitimately nest deeper than this, deeper means a reference loop
+const MAX_DEPTH: usize = 64;
+
+impl Reader<'_> {
+    fn read_struct(&self, index: usize, depth: usize) -> io::Result<GffStruct> {
+        if index >= self.struct_count || depth > MAX_DEPTH {
+            return Err(invalid_data("GFF struct index out of range"));
+        }
+
+        let offset = self.struct_offset + index * STRUCT_SIZE;
+        let struct_type = read_u32(self.data, offset)?;
+        let data_or_offset = read_u32(self.data, offset + 4)? as usize;
+        let field_count = read_u32(self.data, offset + 8)? as usize;
+
+        // Single field structs point to the field directly
+        let field_indices = if field_count == 1 {
+            vec![data_or_offset]
+        } else {
+            (0..field_count)
+                .map(|i| {
+                    read_u32(
+                        self.data,
+                        self.field_indices_offset + data_or_offset + i * 4,
+                    )
+                    .map(|index| index as usize)
+                })
+                .collect::<io::Result<Vec<_>>>()?
+        };
+
+        let fields = field_indices
+            .into_iter()
+            .map(|field| self.read_field(field, depth))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        Ok(GffStruct {
+            struct_type,
+            fields,
+        })
+    }
+
+    fn read_field(&self, index: usize, depth: usize) -> io::Result<(String, GffValue)> {
+        if index >= self.field_count {
+            return Err(invalid_data("GFF field index out of range"));
+        }
+
+        let offset = self.field_offset + index * FIELD_SIZE;
+        let field_type = read_u32(self.data, offset)?;
+        let label_index = read_u32(self.data, offset + 4)? as usize;
+        let raw = bytes(self.data, offset + 8, 4)?;
+        let inline = read_u32(self.data, offset + 8)?;
+        let data = self.field_data_offset + inline as usize;
+
+        let label = bytes(
+            self.data,
+            self.label_offset + label_index * LABEL_SIZE,
+            LABEL_SIZE,
+        )?;
+        let label_length = label.iter().position(|&b| b == 0).unwrap_or(LABEL_SIZE);
+        let label = decode(&label[..label_length]);
+
+        let value = match field_type {
+            0 => GffValue::Byte(raw[0]),
+            1 => GffValue::Char(raw[0] as i8),
+            2 => GffValue::Word(u16::from_le_bytes([raw[0], raw[1]])),
+            3 => GffValue::Short(i16::from_le_bytes([raw[0], raw[1]])),
+            4 => GffValue::Dword(inline),
+            5 => GffValue::Int(inline as i32),
+            6 | 7 | 9 => {
+                let b = bytes(self.data, data, 8)?;
+                let raw = u64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]);
+                match field_type {
+                    6 => GffValue::Dword64(raw),
+                    7 => GffValue::Int64(raw as i64),
+                    _ => GffValue::Double(f64::from_bits(raw)),
+                }
+            }
+            8 => GffValue::Float(f32::from_bits(inline)),
+            10 => {
+                let length = read_u32(self.data, data)? as usize;
+                GffValue::String(decode(bytes(self.data, data + 4, length)?))
+            }
+            11 => {
+                let length = bytes(self.data, data, 1)?[0] as usize;
+                GffValue::ResRef(decode(bytes(self.data, data + 1, length)?))
+            }
+            12 => {
+                // Total size comes first, then the StrRef and the embedded strings
+                let strref = read_u32(self.data, data + 4)?;
+                let count = read_u32(self.data, data + 8)? as usize;
+                let mut strings = Vec::new();
+                let mut offset = data + 12;
+                for _ in 0..count {
+                    let id = read_u32(self.data, offset)?;
+                    let length = read_u32(self.data, offset + 4)? as usize;
+                    strings.push((id, decode(bytes(self.data, offset + 8, length)?)));
+                    offset += 8 + length;
+                }
+                GffValue::LocString(LocString { strref, strings })
+            }
+            13 => {
+                let length = read_u32(self.data, data)? as usize;
+                GffValue::Void(bytes(self.data, data + 4, length)?.to_vec())
+            }
+            14 => GffValue::Struct(self.read_struct(inline as usize, depth + 1)?),
+            15 => {
+                let list = self.list_indices_offset + inline as usize;
+                let count = read_u32(self.data, list)? as usize;
+                let structs = (0..count)
+                    .map(|i| {
+                        let index = read_u32(self.data, list + 4 + i * 4)? as usize;
+                        self.read_struct(index, depth + 1)
+                    })
+                    .collect::<io::Result<Vec<_>>>()?;
+                GffValue::List(structs)
+            }
+            16 => GffValue::Orientation(read_f32s(self.data, data)?),
+            17 => GffValue::Vector(read_f32s(self.data, data)?),
+            _ => return Err(invalid_data("Unknown GFF field type")),
+        };
+
+        Ok((label, value))
+    }
+}
+
+impl Gff {
+    pub fn parse(data: &[u8]) -> io::Result<Gff> {
+        if data.len() < HEADER_SIZE || &data[4..8] != b"V3.2" {
+            return Err(invalid_data("Not a GFF V3.2 file"));
+        }
+
+        let header = |index: usize| read_u32(data, 8 + index * 4).map(|value| value as usize);
+        let reader = Reader {
+            data,
+            struct_offset: header(0)?,
+            struct_count: header(1)?,
+            field_offset: header(2)?,
+            field_count: header(3)?,
+            label_offset: header(4)?,
+            field_data_offset: header(6)?,
+            field_indices_offset: header(8)?,
+            list_indices_offset: header(10)?,
+        };
+
+        Ok(Gff {
+            file_type: decode(&data[0..4]),
+            root: reader.read_struct(0, 0)?,
+        })
+    }
+
+    /// Hexdump of a GFF file with the header, every struct, field and label, and the field data
+    /// of each field stored there labeled. Doesn't need the file to parse past the header.
+    pub fn annotate(data: &[u8]) -> io::Result<Hexdump<'_>> {
+        if data.len() < HEADER_SIZE || &data[4..8] != b"V3.2" {
+            return Err(invalid_data("Not a GFF V3.2 file"));
+        }
+
+        let mut dump = Hexdump::new(data);
+        dump.add(0..4, "file type").add(4..8, "version");
+        for (i, label) in HEADER_FIELDS.iter().enumerate() {
+            dump.add(8 + i * 4..12 + i * 4, *label);
+        }
+
+        let header = |index: usize| read_u32(data, 8 + index * 4).map(|value| value as usize);
+        // Counts can be anything in a broken file, there's no more entries than there's data
+        let entries = |offset: usize, count: usize, size: usize| {
+            (0..count.min(data.len() / size)).map(move |i| offset + i * size)
+        };
+
+        for (i, offset) in entries(header(0)?, header(1)?, STRUCT_SIZE).enumerate() {
+            let fields = read_u32(data, offset + 8).unwrap_or_default();
+            dump.add(
+                offset..offset + STRUCT_SIZE,
+                format!("struct {i}, {fields} fields"),
+            );
+        }
+
+        let (label_offset, field_data_offset) = (header(4)?, header(6)?);
+        for (i, offset) in entries(header(2)?, header(3)?, FIELD_SIZE).enumerate() {
+            let field_type = read_u32(data, offset).unwrap_or(u32::MAX) as usize;
+            let label = read_u32(data, offset + 4)
+                .and_then(|index| {
+                    bytes(data, label_offset + index as usize * LABEL_SIZE, LABEL_SIZE)
+                })
+                .map(|label| decode(label).trim_end_matches('\0').to_string())
+                .unwrap_or_default();
+            let type_name = FIELD_TYPES.get(field_type).unwrap_or(&"unknown");
+            dump.add(
+                offset..offset + FIELD_SIZE,
+                format!("field {i}: {label} ({type_name})"),
+            );
+
+            let Ok(inline) = read_u32(data, offset + 8) else {
+                continue;
+            };
+            let data_offset = field_data_offset + inline as usize;
+            let length = |offset: usize| read_u32(data, offset).map(|length| length as usize);
+            let size = match field_type {
+                6 | 7 | 9 => Ok(8),
+                10 | 13 => length(data_offset).map(|length| 4 + length),
+                11 => bytes(data, data_offset, 1).map(|length| 1 + length[0] as usize),
+                // Total size doesn't count itself
+                12 => length(data_offset).map(|length| 4 + length),
+                16 => Ok(16),
+                17 => Ok(12),
+                _ => continue,
+            };
+            if let Ok(size) = size {
+                dump.add(data_offset..data_offset + size, format!("{label} data"));
+            }
+        }
+
+        for offset in entries(label_offset, header(5)?, LABEL_SIZE) {
+            let Ok(label) = bytes(data, offset, LABEL_SIZE).map(decode) else {
+                continue;
+            };
+            dump.add(
+                offset..offset + LABEL_SIZE,
+                format!("label {}", label.trim_end_matches('\0')),
+            );
+        }
+
+        for (label, offset, size) in [
+            ("field data", field_data_offset, header(7)?),
+            ("field indices", header(8)?, header(9)?),
+            ("list indices", header(10)?, header(11)?),
+        ] {
+            dump.add(offset..offset.saturating_add(size), label);
+        }
+        Ok(dump)
+    }
+    /// Serializes the tree, the root struct first.
+    pub fn to_bytes(&self) -> io::Result<Vec<u8>> {
+        let mut writer = Writer::default();
+        writer.write_struct(&self.root)?;
+
+        let labels: Vec<u8> = writer.labels.concat();
+        let sections: [(usize, usize); 6] = [
+            (writer.structs.len() * STRUCT_SIZE, writer.structs.len()),
+            (writer.fields.len() * FIELD_SIZE, writer.fields.len()),
+            (labels.len(), writer.labels.len()),
+            (writer.field_data.len(), writer.field_data.len()),
+            (writer.field_indices.len(), writer.field_indices.len()),
+            (writer.list_indices.len(), writer.list_indices.len()),
+        ];
+
+        let mut file_type = encode(&self.file_type);
+        file_type.resize(4, b' ');
+        let mut data = file_type[..4].to_vec();
+        data.extend_from_slice(b"V3.2");
+
+        // Each section is laid out right after the previous one, offset followed by its count
+        let mut offset = HEADER_SIZE;
+        for (size, count) in sections {
+            data.extend_from_slice(&(offset as u32).to_le_bytes());
+            data.extend_from_slice(&(count as u32).to_le_bytes());
+            offset += size;
+        }
+
+        for entry in writer.structs.iter().chain(&writer.fields) {
+            entry
+                .iter()
+                .for_each(|value| data.extend_from_slice(&value.to_le_bytes()));
+        }
+        data.extend_from_slice(&labels);
+        data.extend_from_slice(&writer.field_data);
+        data.extend_from_slice(&writer.field_indices);
+        data.extend_from_slice(&writer.list_indices);
+        Ok(data)
+    }
+}
+
+#[derive(Default)]
+struct Writer {
+    structs: Vec<[u32; 3]>,
+    fields: Vec<[u32; 3]>,
+    /// Labels are shared by every field with the same name
+    labels: Vec<[u8; LABEL_SIZE]>,
+    field_data: Vec<u8>,
+    field_indices: Vec<u8>,
+    list_indices: Vec<u8>,
+}
+
+impl Writer {
+    fn label(&mut self, label: &str) -> io::Result<u32> {
+        let bytes = encode(label);
+        if bytes.len() > LABEL_SIZE {
+            return Err(invalid_data("GFF label is longer than 16 characters"));
+        }
+
+        let mut padded = [0u8; LABEL_SIZE];
+        padded[..bytes.len()].copy_from_slice(&bytes);
+        let index = match self.labels.iter().position(|existing| *existing == padded) {
+            Some(index) => index,
+            None => {
+                self.labels.push(padded);
+                self.labels.len() - 1
+            }
+        };
+        Ok(index as u32)
+    }
+
+    /// Appends to the field data, returns the offset the value starts at.
+    fn data(&mut self, bytes: &[u8]) -> u32 {
+        let offset = self.field_data.len() as u32;
+        self.field_data.extend_from_slice(bytes);
+        offset
+    }
+
+    /// Appends a length prefixed value to the field data.
+    fn sized_data(&mut self, bytes: &[u8]) -> u32 {
+        let offset = self.data(&(bytes.len() as u32).to_le_bytes());
+        self.field_data.extend_from_slice(bytes);
+        offset
+    }
+
+    fn floats(&mut self, values: &[f32]) -> u32 {
+        let bytes: Vec<u8> = values
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect();
+        self.data(&bytes)
+    }
+
+    fn write_struct(&mut self, value: &GffStruct) -> io::Result<u32> {
+        // Reserve the slot first, the root has to stay at index zero
+        let index = self.structs.len();
+        self.structs.push([value.struct_type, 0, 0]);
+
+        let fields = value
+            .fields
+            .iter()
+            .map(|(label, value)| self.write_field(label, value))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        // Single field structs point to the field directly
+        let data_or_offset = match fields.as_slice() {
+            [field] => *field,
+            _ => {
+                let offset = self.field_indices.len() as u32;
+                for field in &fields {
+                    self.field_indices.extend_from_slice(&field.to_le_bytes());
+                }
+                offset
+            }
+        };
+
+        self.structs[index] = [value.struct_type, data_or_offset, fields.len() as u32];
+        Ok(index as u32)
+    }
+
+    fn write_field(&mut self, label: &str, value: &GffValue) -> io::Result<u32> {
+        let label = self.label(label)?;
+
+        let (field_type, inline) = match value {
+            GffValue::Byte(value) => (0, u32::from(*value)),
+            GffValue::Char(value) => (1, u32::from(*value as u8)),
+            GffValue::Word(value) => (2, u32::from(*value)),
+            GffValue::Short(value) => (3, u32::from(*value as u16)),
+            GffValue::Dword(value) => (4, *value),
+            GffValue::Int(value) => (5, *value as u32),
+            GffValue::Dword64(value) => (6, self.data(&value.to_le_bytes())),
+            GffValue::Int64(value) => (7, self.data(&value.to_le_bytes())),
+            GffValue::Float(value) => (8, value.to_bits()),
+            GffValue::Double(value) => (9, self.data(&value.to_le_bytes())),
+            GffValue::String(value) => (10, self.sized_data(&encode(value))),
+            GffValue::ResRef(value) => {
+                let bytes = encode(value);
+                let length = u8::try_from(bytes.len())
+                    .map_err(|_| invalid_data("GFF resref is too long"))?;
+                let offset = self.data(&[length]);
+                self.field_data.extend_from_slice(&bytes);
+                (11, offset)
+            }
+            GffValue::LocString(value) => {
+                let mut bytes = Vec::new();
+                bytes.extend_from_slice(&value.strref.to_le_bytes());
+                bytes.extend_from_slice(&(value.strings.len() as u32).to_le_bytes());
+                for (id, string) in &value.strings {
+                    let string = encode(string);
+                    bytes.extend_from_slice(&id.to_le_bytes());
+                    bytes.extend_from_slice(&(string.len() as u32).to_le_bytes());
+                    bytes.extend_from_slice(&string);
+                }
+                (12, self.sized_data(&bytes))
+            }
+            GffValue::Void(value) => (13, self.sized_data(value)),
+            GffValue::Struct(value) => (14, self.write_struct(value)?),
+            GffValue::List(structs) => {
+                // Children first, they may add lists of their own
+                let indices = structs
+                    .iter()
+                    .map(|value| self.write_struct(value))
+                    .collect::<io::Result<Vec<_>>>()?;
+
+                let offset = self.list_indices.len() as u32;
+                self.list_indices
+                    .extend_from_slice(&(indices.len() as u32).to_le_bytes());
+                for index in indices {
+                    self.list_indices.extend_from_slice(&index.to_le_bytes());
+                }
+                (15, offset)
+            }
+            GffValue::Orientation(value) => (16, self.floats(value)),
+            GffValue::Vector(value) => (17, self.floats(value)),
+        };
+
+        self.fields.push([field_type, label, inline]);
+        Ok(self.fields.len() as u32 - 1)
+    }
+}
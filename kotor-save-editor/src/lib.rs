@@ -0,0 +1,5 @@
+pub mod erf;
+pub mod gff;
+pub mod rim;
+pub mod save;
+pub mod ui;
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use kotor_save_editor::ui::run_terminal_ui;
+
+fn main() -> ExitCode {
+    run_terminal_ui()
+}
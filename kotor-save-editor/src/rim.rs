@@ -0,0 +1,71 @@
+/// RIM reader. The game ships modules as RIM archives in `modules`, e.g. `danm13.rim` and
+/// `danm13_s.rim`, a simpler ERF without descriptions where each key holds its resource's
+/// offset and size.
+use std::io::{self, Read};
+
+use archive::{Entry, ReadableArchive};
+
+use crate::erf::{self, Resource};
+
+const HEADER_SIZE: usize = 120;
+const KEY_SIZE: usize = 32;
+const RESREF_SIZE: usize = 16;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rim {
+    pub resources: Vec<Resource>,
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn bytes(data: &[u8], offset: usize, length: usize) -> io::Result<&[u8]> {
+    offset
+        .checked_add(length)
+        .and_then(|end| data.get(offset..end))
+        .ok_or_else(|| invalid_data("RIM ended in the middle of a value"))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> io::Result<u32> {
+    let b = bytes(data, offset, 4)?;
+    Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+impl Rim {
+    pub fn parse(data: &[u8]) -> io::Result<Rim> {
+        if data.len() < HEADER_SIZE || &data[0..8] != b"RIM V1.0" {
+            return Err(invalid_data("Not a RIM V1.0 file"));
+        }
+
+        let entry_count = read_u32(data, 12)? as usize;
+        let key_offset = read_u32(data, 16)? as usize;
+
+        // Each entry takes a key, so a count larger than the file can hold is corrupt
+        let mut resources = Vec::with_capacity(entry_count.min(data.len() / KEY_SIZE));
+        for i in 0..entry_count {
+            let key = key_offset + i * KEY_SIZE;
+            let resref = bytes(data, key, RESREF_SIZE)?;
+            let name_length = resref.iter().position(|&b| b == 0).unwrap_or(RESREF_SIZE);
+            let offset = read_u32(data, key + 24)? as usize;
+            let size = read_u32(data, key + 28)? as usize;
+
+            resources.push(Resource {
+                name: String::from_utf8_lossy(&resref[..name_length]).to_ascii_lowercase(),
+                resource_type: read_u32(data, key + 16)? as u16,
+                data: bytes(data, offset, size)?.to_vec(),
+            });
+        }
+        Ok(Rim { resources })
+    }
+}
+
+impl ReadableArchive for Rim {
+    fn entries(&self) -> Vec<Entry> {
+        erf::entries(&self.resources)
+    }
+
+    fn open(&self, name: &str) -> io::Result<Box<dyn Read + '_>> {
+        erf::open(&self.resources, name)
+    }
+}
@@ -0,0 +1,325 @@
+/// A KOTOR save directory, e.g. `saves/000001 - Game0`.
+///
+/// Globals, the party and the load menu info are GFF files of their own next to `SAVEGAME.sav`.
+/// The inventory lives inside the archive as `INVENTORY.res`. Only the files something was changed
+/// in get written back, each one after copying the original to `<file>.bak`.
+use std::{
+    collections::BTreeSet,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    erf::{Erf, RESOURCE_TYPE_RES},
+    gff::{Gff, GffStruct, GffValue},
+};
+
+const SAVE_INFO_FILE: &str = "savenfo.res";
+const GLOBALS_FILE: &str = "GLOBALVARS.res";
+const PARTY_TABLE_FILE: &str = "PARTYTABLE.res";
+const ARCHIVE_FILE: &str = "SAVEGAME.sav";
+const INVENTORY_RESOURCE: &str = "inventory";
+
+/// Companions in the order the party table lists them
+pub const NPC_NAMES: [&str; 9] = [
+    "bastila",
+    "canderous",
+    "carth",
+    "hk47",
+    "jolee",
+    "juhani",
+    "mission",
+    "t3m4",
+    "zaalbar",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Companion {
+    pub name: &'static str,
+    /// Recruited, can be picked from the party selection
+    pub available: bool,
+    /// Not locked out of the party selection by the plot
+    pub selectable: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Party {
+    pub gold: u32,
+    pub xp_pool: i32,
+    /// Companion ids of the active party, the player isn't listed
+    pub members: Vec<i32>,
+    pub companions: Vec<Companion>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub tag: String,
+    pub stack_size: u16,
+}
+
+#[derive(Debug)]
+pub struct KotorSave {
+    pub directory: PathBuf,
+    pub info: Gff,
+    pub globals: Gff,
+    pub party_table: Gff,
+    pub archive: Erf,
+    modified: BTreeSet<&'static str>,
+}
+
+fn not_found(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::NotFound, message)
+}
+
+/// Adds the path to read and parse errors, a bare "file not found" doesn't say which one
+fn with_path(path: &Path, e: io::Error) -> io::Error {
+    io::Error::new(e.kind(), format!("{}: {e}", path.display()))
+}
+
+fn read_gff(directory: &Path, file: &str) -> io::Result<Gff> {
+    let path = directory.join(file);
+    fs::read(&path)
+        .and_then(|data| Gff::parse(&data))
+        .map_err(|e| with_path(&path, e))
+}
+
+/// Names of a `Cat*` list in `GLOBALVARS.res`, in the order their values are stored
+fn global_names(root: &GffStruct, category: &str) -> Vec<String> {
+    root.get_list(category)
+        .iter()
+        .map(|global| global.get_str("Name").unwrap_or_default().to_string())
+        .collect()
+}
+
+fn global_index(root: &GffStruct, category: &str, name: &str) -> io::Result<usize> {
+    global_names(root, category)
+        .iter()
+        .position(|candidate| candidate.eq_ignore_ascii_case(name))
+        .ok_or_else(|| not_found(format!("No global named '{name}' in {category}")))
+}
+
+/// Values of a `Val*` field, created empty when the save has none
+fn global_values<'a>(root: &'a mut GffStruct, field: &str) -> &'a mut Vec<u8> {
+    if !matches!(root.get(field), Some(GffValue::Void(_))) {
+        root.set(field, GffValue::Void(Vec::new()));
+    }
+    match root.get_mut(field) {
+        Some(GffValue::Void(values)) => values,
+        _ => unreachable!("value was just set"),
+    }
+}
+
+impl KotorSave {
+    pub fn load(directory: &Path) -> io::Result<KotorSave> {
+        let archive_path = directory.join(ARCHIVE_FILE);
+        let archive = fs::read(&archive_path)
+            .and_then(|data| Erf::parse(&data))
+            .map_err(|e| with_path(&archive_path, e))?;
+
+        Ok(KotorSave {
+            directory: directory.to_path_buf(),
+            info: read_gff(directory, SAVE_INFO_FILE)?,
+            globals: read_gff(directory, GLOBALS_FILE)?,
+            party_table: read_gff(directory, PARTY_TABLE_FILE)?,
+            archive,
+            modified: BTreeSet::new(),
+        })
+    }
+
+    /// Writes the changed files back, keeping a backup of each original.
+    pub fn write(&mut self) -> io::Result<()> {
+        for file in &self.modified {
+            let data = match *file {
+                SAVE_INFO_FILE => self.info.to_bytes()?,
+                GLOBALS_FILE => self.globals.to_bytes()?,
+                PARTY_TABLE_FILE => self.party_table.to_bytes()?,
+                _ => self.archive.to_bytes()?,
+            };
+
+            let path = self.directory.join(file);
+            fs::copy(&path, path.with_file_name(format!("{file}.bak")))?;
+            fs::write(&path, data)?;
+        }
+        self.modified.clear();
+        Ok(())
+    }
+
+    /// Name given in the save menu
+    pub fn name(&self) -> &str {
+        self.info.root.get_str("SAVEGAMENAME").unwrap_or_default()
+    }
+
+    /// Module the save was made in
+    pub fn module(&self) -> &str {
+        self.info.root.get_str("LASTMODULE").unwrap_or_default()
+    }
+
+    pub fn booleans(&self) -> Vec<(String, bool)> {
+        let root = &self.globals.root;
+        // Packed eight to a byte, the first global in the most significant bit
+        let bits = match root.get("ValBoolean") {
+            Some(GffValue::Void(bits)) => bits.as_slice(),
+            _ => &[],
+        };
+        global_names(root, "CatBoolean")
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let byte = bits.get(i / 8).copied().unwrap_or_default();
+                (name, byte & (0x80 >> (i % 8)) != 0)
+            })
+            .collect()
+    }
+
+    pub fn numbers(&self) -> Vec<(String, u8)> {
+        let root = &self.globals.root;
+        let values = match root.get("ValNumber") {
+            Some(GffValue::Void(values)) => values.as_slice(),
+            _ => &[],
+        };
+        global_names(root, "CatNumber")
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| (name, values.get(i).copied().unwrap_or_default()))
+            .collect()
+    }
+
+    /// Sets an existing boolean global, new globals can only come from the game's scripts.
+    pub fn set_boolean(&mut self, name: &str, value: bool) -> io::Result<()> {
+        let index = global_index(&self.globals.root, "CatBoolean", name)?;
+        let bits = global_values(&mut self.globals.root, "ValBoolean");
+        if bits.len() <= index / 8 {
+            bits.resize(index / 8 + 1, 0);
+        }
+
+        let mask = 0x80 >> (index % 8);
+        if value {
+            bits[index / 8] |= mask;
+        } else {
+            bits[index / 8] &= !mask;
+        }
+        self.modified.insert(GLOBALS_FILE);
+        Ok(())
+    }
+
+    /// Sets an existing number global.
+    pub fn set_number(&mut self, name: &str, value: u8) -> io::Result<()> {
+        let index = global_index(&self.globals.root, "CatNumber", name)?;
+        let values = global_values(&mut self.globals.root, "ValNumber");
+        if values.len() <= index {
+            values.resize(index + 1, 0);
+        }
+
+        values[index] = value;
+        self.modified.insert(GLOBALS_FILE);
+        Ok(())
+    }
+
+    pub fn party(&self) -> Party {
+        let root = &self.party_table.root;
+        let companions = root.get_list("PT_AVAIL_NPCS");
+
+        Party {
+            gold: root.get_i64("PT_GOLD").unwrap_or_default() as u32,
+            xp_pool: root.get_i64("PT_XP_POOL").unwrap_or_default() as i32,
+            members: root
+                .get_list("PT_MEMBERS")
+                .iter()
+                .filter_map(|member| member.get_i64("PT_MEMBER_ID"))
+                .map(|id| id as i32)
+                .collect(),
+            companions: NPC_NAMES
+                .iter()
+                .zip(companions)
+                .map(|(name, companion)| Companion {
+                    name,
+                    available: companion.get_i64("PT_NPC_AVAIL").unwrap_or_default() != 0,
+                    selectable: companion.get_i64("PT_NPC_SELECT").unwrap_or_default() != 0,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn set_gold(&mut self, gold: u32) {
+        self.party_table.root.set("PT_GOLD", GffValue::Dword(gold));
+        self.modified.insert(PARTY_TABLE_FILE);
+    }
+
+    /// Makes a companion available for the party selection or takes them out of it.
+    pub fn set_companion_available(&mut self, name: &str, available: bool) -> io::Result<()> {
+        let index = NPC_NAMES
+            .iter()
+            .position(|candidate| candidate.eq_ignore_ascii_case(name))
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "Unknown companion '{name}', expected one of {}",
+                        NPC_NAMES.join(", ")
+                    ),
+                )
+            })?;
+
+        let companion = self
+            .party_table
+            .root
+            .get_list_mut("PT_AVAIL_NPCS")
+            .and_then(|companions| companions.get_mut(index))
+            .ok_or_else(|| not_found(format!("The save has no party entry for {name}")))?;
+        companion.set("PT_NPC_AVAIL", GffValue::Byte(available as u8));
+        companion.set("PT_NPC_SELECT", GffValue::Byte(available as u8));
+        self.modified.insert(PARTY_TABLE_FILE);
+        Ok(())
+    }
+
+    fn inventory_gff(&self) -> io::Result<Gff> {
+        let resource = self
+            .archive
+            .get(INVENTORY_RESOURCE, RESOURCE_TYPE_RES)
+            .ok_or_else(|| not_found(format!("No inventory in {ARCHIVE_FILE}")))?;
+        Gff::parse(&resource.data)
+    }
+
+    pub fn inventory(&self) -> io::Result<Vec<Item>> {
+        Ok(self
+            .inventory_gff()?
+            .root
+            .get_list("ItemList")
+            .iter()
+            .map(|item| Item {
+                tag: item.get_str("Tag").unwrap_or_default().to_string(),
+                stack_size: item.get_i64("StackSize").unwrap_or(1) as u16,
+            })
+            .collect())
+    }
+
+    /// Sets the stack size of every item with the given tag. Returns how many stacks there were.
+    pub fn set_stack_size(&mut self, tag: &str, stack_size: u16) -> io::Result<usize> {
+        let mut inventory = self.inventory_gff()?;
+        let items = inventory
+            .root
+            .get_list_mut("ItemList")
+            .ok_or_else(|| not_found("The inventory has no item list".to_string()))?;
+
+        let mut stacks = 0;
+        for item in items.iter_mut().filter(|item| {
+            item.get_str("Tag")
+                .is_some_and(|t| t.eq_ignore_ascii_case(tag))
+        }) {
+            item.set("StackSize", GffValue::Word(stack_size));
+            stacks += 1;
+        }
+        if stacks == 0 {
+            return Err(not_found(format!(
+                "No item tagged '{tag}' in the inventory"
+            )));
+        }
+
+        let data = inventory.to_bytes()?;
+        if let Some(resource) = self.archive.get_mut(INVENTORY_RESOURCE, RESOURCE_TYPE_RES) {
+            resource.data = data;
+        }
+        self.modified.insert(ARCHIVE_FILE);
+        Ok(stacks)
+    }
+}
@@ -0,0 +1,280 @@
+use std::{
+    fs,
+    io::{self, IsTerminal, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use archive::ReadableArchive;
+
+use clap::{ArgAction, Parser, Subcommand};
+
+use crate::{erf::Erf, gff::Gff, rim::Rim, save::KotorSave};
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Shows the save name, module and how much the save holds
+    Info,
+    /// Lists global variables, only those starting with the prefix when one is given
+    Globals { prefix: Option<String> },
+    /// Sets a boolean global, e.g. set-boolean K_SWG_HELENA true
+    SetBoolean {
+        name: String,
+        #[arg(action = ArgAction::Set)]
+        value: bool,
+    },
+    /// Sets a number global
+    SetNumber { name: String, value: u8 },
+    /// Shows gold, the XP pool, the active party and which companions are available
+    Party,
+    /// Sets the party's gold
+    SetGold { gold: u32 },
+    /// Makes a companion available or unavailable in the party selection, e.g. hk47 true
+    SetAvailable {
+        companion: String,
+        #[arg(action = ArgAction::Set)]
+        available: bool,
+    },
+    /// Lists the items in the party inventory
+    Inventory,
+    /// Sets the stack size of the items with the given tag, e.g. set-stack-size G_I_MEDEQPMNT01 10
+    SetStackSize { tag: String, stack_size: u16 },
+    /// Hexdump of a file in the save directory, e.g. GLOBALVARS.res, or of a resource in
+    /// SAVEGAME.sav, e.g. inventory, with the known fields labeled
+    Hexdump {
+        file: String,
+        /// Writes an HTML page instead, hovering a byte shows its field
+        #[arg(long)]
+        html: bool,
+    },
+    /// Lists the resources of SAVEGAME.sav, or of another ERF or RIM archive, only those
+    /// matching the pattern when one is given, e.g. "*.utc"
+    Entries {
+        pattern: Option<String>,
+        /// Archive to read instead, e.g. modules/danm13.rim
+        #[arg(short, long)]
+        archive: Option<PathBuf>,
+    },
+    /// Extracts the resources of SAVEGAME.sav, or of another archive, matching the pattern
+    Extract {
+        pattern: String,
+        /// Archive to read instead, e.g. modules/danm13.rim
+        #[arg(short, long)]
+        archive: Option<PathBuf>,
+        /// Directory to extract into
+        #[arg(short, long, default_value = ".")]
+        out: PathBuf,
+    },
+    /// Lists the resources of SAVEGAME.sav, or of another archive, containing the text
+    Search {
+        text: String,
+        /// Archive to read instead, e.g. modules/danm13.rim
+        #[arg(short, long)]
+        archive: Option<PathBuf>,
+        /// Only searches the resources matching the pattern
+        #[arg(short, long, default_value = "*")]
+        pattern: String,
+    },
+}
+
+/// Program to inspect and edit Star Wars: Knights of the Old Republic saves
+#[derive(Parser)]
+#[command(author, version, about, long_about = None)]
+#[command(propagate_version = true)]
+pub struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+
+    /// Path to the save directory, e.g. "saves/000001 - Game0"
+    #[arg(short, long)]
+    save_path: PathBuf,
+}
+
+fn info(save: &KotorSave) -> io::Result<()> {
+    println!("{} in {}", save.name(), save.module());
+    println!(
+        "{} boolean and {} number globals",
+        save.booleans().len(),
+        save.numbers().len()
+    );
+    println!("{} items in the inventory", save.inventory()?.len());
+    Ok(())
+}
+
+fn globals(save: &KotorSave, prefix: &str) {
+    let booleans = save
+        .booleans()
+        .into_iter()
+        .map(|(name, value)| (name, value.to_string()));
+    let numbers = save
+        .numbers()
+        .into_iter()
+        .map(|(name, value)| (name, value.to_string()));
+
+    for (name, value) in booleans.chain(numbers) {
+        if name
+            .to_ascii_lowercase()
+            .starts_with(&prefix.to_ascii_lowercase())
+        {
+            println!("{name} {value}");
+        }
+    }
+}
+
+fn party(save: &KotorSave) {
+    let party = save.party();
+    println!("Gold {}, XP pool {}", party.gold, party.xp_pool);
+
+    let members: Vec<&str> = party
+        .members
+        .iter()
+        .map(|&id| {
+            party
+                .companions
+                .get(id as usize)
+                .map_or("unknown", |companion| companion.name)
+        })
+        .collect();
+    println!("Active party: {}", members.join(", "));
+
+    for companion in &party.companions {
+        let state = match (companion.available, companion.selectable) {
+            (false, _) => "not recruited",
+            (true, false) => "locked",
+            (true, true) => "available",
+        };
+        println!("{:<10} {state}", companion.name);
+    }
+}
+
+fn hexdump(save: &KotorSave, file: &str, html: bool) -> io::Result<()> {
+    let path = save.directory.join(file);
+    let data = if path.is_file() {
+        fs::read(&path)?
+    } else {
+        save.archive
+            .resources
+            .iter()
+            .find(|resource| resource.name.eq_ignore_ascii_case(file))
+            .map(|resource| resource.data.clone())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("No file or resource named '{file}'"),
+                )
+            })?
+    };
+
+    let dump = match data.get(4..8) {
+        Some(b"V3.2") => Gff::annotate(&data)?,
+        Some(b"V1.0") => Erf::annotate(&data)?,
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("'{file}' is not a GFF file or an ERF archive"),
+            ))
+        }
+    };
+
+    let out = if html {
+        dump.html(file)
+    } else {
+        dump.terminal(io::stdout().is_terminal())
+    };
+    io::stdout().write_all(out.as_bytes())
+}
+
+/// The archive at the path, or the save's SAVEGAME.sav when there's none.
+fn open_archive(save: &KotorSave, path: Option<&Path>) -> io::Result<Box<dyn ReadableArchive>> {
+    let Some(path) = path else {
+        return Ok(Box::new(save.archive.clone()));
+    };
+    let data = fs::read(path)?;
+    Ok(match data.get(0..4) {
+        Some(b"RIM ") => Box::new(Rim::parse(&data)?),
+        _ => Box::new(Erf::parse(&data)?),
+    })
+}
+
+fn extract(archive: &dyn ReadableArchive, pattern: &str, out: &Path) -> io::Result<()> {
+    let written = archive::extract(archive, pattern, out)?;
+    println!("Extracted {} resources to {}", written.len(), out.display());
+    Ok(())
+}
+
+fn search(archive: &dyn ReadableArchive, text: &str, pattern: &str) -> io::Result<()> {
+    for (name, offset) in archive::search(archive, pattern, text.as_bytes())? {
+        println!("{name} at {offset:#x}");
+    }
+    Ok(())
+}
+
+fn edit(cli: Cli) -> io::Result<()> {
+    let mut save = KotorSave::load(&cli.save_path)?;
+
+    match cli.command {
+        Commands::Info => return info(&save),
+        Commands::Globals { prefix } => {
+            globals(&save, prefix.as_deref().unwrap_or_default());
+            return Ok(());
+        }
+        Commands::Party => {
+            party(&save);
+            return Ok(());
+        }
+        Commands::Inventory => {
+            for item in save.inventory()? {
+                println!("{} x{}", item.tag, item.stack_size);
+            }
+            return Ok(());
+        }
+        Commands::Hexdump { file, html } => return hexdump(&save, &file, html),
+        Commands::Entries { pattern, archive } => {
+            let archive = open_archive(&save, archive.as_deref())?;
+            print!(
+                "{}",
+                archive::render(&*archive, pattern.as_deref().unwrap_or("*"))
+            );
+            return Ok(());
+        }
+        Commands::Extract {
+            pattern,
+            archive,
+            out,
+        } => return extract(&*open_archive(&save, archive.as_deref())?, &pattern, &out),
+        Commands::Search {
+            text,
+            archive,
+            pattern,
+        } => return search(&*open_archive(&save, archive.as_deref())?, &text, &pattern),
+        Commands::SetBoolean { name, value } => save.set_boolean(&name, value)?,
+        Commands::SetNumber { name, value } => save.set_number(&name, value)?,
+        Commands::SetGold { gold } => save.set_gold(gold),
+        Commands::SetAvailable {
+            companion,
+            available,
+        } => save.set_companion_available(&companion, available)?,
+        Commands::SetStackSize { tag, stack_size } => {
+            let stacks = save.set_stack_size(&tag, stack_size)?;
+            println!("Changed {stacks} stacks");
+        }
+    }
+
+    save.write()?;
+    println!("Saved, the original files were kept with a .bak suffix");
+    Ok(())
+}
+
+pub fn run(cli: Cli) -> ExitCode {
+    match edit(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+pub fn run_terminal_ui() -> ExitCode {
+    run(Cli::parse())
+}
@@ -0,0 +1,362 @@
+use std::{fs, path::PathBuf};
+
+use archive::ReadableArchive;
+use kotor_save_editor::{
+    erf::{Erf, Resource, RESOURCE_TYPE_RES, RESOURCE_TYPE_UTC},
+    gff::{Gff, GffStruct, GffValue, LocString},
+    rim::Rim,
+    save::KotorSave,
+};
+
+fn gff(file_type: &str, fields: Vec<(&str, GffValue)>) -> Gff {
+    Gff {
+        file_type: file_type.to_string(),
+        root: GffStruct {
+            struct_type: u32::MAX,
+            fields: fields
+                .into_iter()
+                .map(|(label, value)| (label.to_string(), value))
+                .collect(),
+        },
+    }
+}
+
+fn list(entries: Vec<Vec<(&str, GffValue)>>) -> GffValue {
+    GffValue::List(
+        entries
+            .into_iter()
+            .map(|fields| gff("", fields).root)
+            .collect(),
+    )
+}
+
+fn names(names: &[&str]) -> GffValue {
+    list(
+        names
+            .iter()
+            .map(|name| vec![("Name", GffValue::String(name.to_string()))])
+            .collect(),
+    )
+}
+
+fn item(tag: &str, stack_size: u16) -> Vec<(&str, GffValue)> {
+    vec![
+        ("Tag", GffValue::String(tag.to_string())),
+        ("StackSize", GffValue::Word(stack_size)),
+    ]
+}
+
+/// Writes a minimal save with the files the editor touches into a directory of its own.
+fn write_save(test: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("kotor-save-editor-{test}"));
+    let _ = fs::remove_dir_all(&directory);
+    fs::create_dir_all(&directory).unwrap();
+
+    let info = gff(
+        "NFO ",
+        vec![
+            (
+                "SAVEGAMENAME",
+                GffValue::String("Before Leviathan".to_string()),
+            ),
+            ("LASTMODULE", GffValue::String("tar_m02aa".to_string())),
+        ],
+    );
+    let globals = gff(
+        "GVT ",
+        vec![
+            (
+                "CatBoolean",
+                names(&["TAR_CARTH", "TAR_BASTILA", "K_SWG_HELENA"]),
+            ),
+            ("ValBoolean", GffValue::Void(vec![0b0100_0000])),
+            ("CatNumber", names(&["TAR_PAZAAK", "K_MISSION"])),
+            ("ValNumber", GffValue::Void(vec![3, 7])),
+        ],
+    );
+    let party_table = gff(
+        "PT  ",
+        vec![
+            ("PT_GOLD", GffValue::Dword(120)),
+            ("PT_XP_POOL", GffValue::Int(0)),
+            (
+                "PT_MEMBERS",
+                list(vec![vec![("PT_MEMBER_ID", GffValue::Int(2))]]),
+            ),
+            (
+                "PT_AVAIL_NPCS",
+                list(
+                    (0..9)
+                        .map(|npc| {
+                            let available = u8::from(npc == 2);
+                            vec![
+                                ("PT_NPC_AVAIL", GffValue::Byte(available)),
+                                ("PT_NPC_SELECT", GffValue::Byte(available)),
+                            ]
+                        })
+                        .collect(),
+                ),
+            ),
+        ],
+    );
+    let inventory = gff(
+        "INV ",
+        vec![(
+            "ItemList",
+            list(vec![
+                item("G_I_MEDEQPMNT01", 2),
+                item("G_W_BLSTRPSTL001", 1),
+            ]),
+        )],
+    );
+    let mut archive = Erf::new("MOD ");
+    archive.resources.push(Resource {
+        name: "inventory".to_string(),
+        resource_type: RESOURCE_TYPE_RES,
+        data: inventory.to_bytes().unwrap(),
+    });
+
+    fs::write(directory.join("savenfo.res"), info.to_bytes().unwrap()).unwrap();
+    fs::write(
+        directory.join("GLOBALVARS.res"),
+        globals.to_bytes().unwrap(),
+    )
+    .unwrap();
+    fs::write(
+        directory.join("PARTYTABLE.res"),
+        party_table.to_bytes().unwrap(),
+    )
+    .unwrap();
+    fs::write(directory.join("SAVEGAME.sav"), archive.to_bytes().unwrap()).unwrap();
+    directory
+}
+
+#[test]
+fn gff_round_trip_keeps_every_value_type() {
+    let original = gff(
+        "UTC ",
+        vec![
+            ("Byte", GffValue::Byte(200)),
+            ("Char", GffValue::Char(-5)),
+            ("Word", GffValue::Word(60000)),
+            ("Short", GffValue::Short(-300)),
+            ("Dword", GffValue::Dword(4_000_000_000)),
+            ("Int", GffValue::Int(-70000)),
+            ("Dword64", GffValue::Dword64(u64::MAX)),
+            ("Int64", GffValue::Int64(i64::MIN)),
+            ("Float", GffValue::Float(1.5)),
+            ("Double", GffValue::Double(-2.25)),
+            ("String", GffValue::String("Carth Onasi".to_string())),
+            ("ResRef", GffValue::ResRef("p_carth".to_string())),
+            (
+                "LocString",
+                GffValue::LocString(LocString {
+                    strref: 12345,
+                    strings: vec![(0, "Carth".to_string())],
+                }),
+            ),
+            ("Void", GffValue::Void(vec![1, 2, 3])),
+            (
+                "Struct",
+                GffValue::Struct(gff("", vec![("Nested", GffValue::Byte(1))]).root),
+            ),
+            ("List", list(vec![item("a", 1), vec![], item("b", 2)])),
+            ("Orientation", GffValue::Orientation([0.0, 0.0, 0.5, 1.0])),
+            ("Vector", GffValue::Vector([1.0, 2.0, 3.0])),
+        ],
+    );
+
+    let parsed = Gff::parse(&original.to_bytes().unwrap()).unwrap();
+
+    assert_eq!(parsed, original);
+}
+
+#[test]
+fn erf_round_trip_keeps_resources() {
+    let mut archive = Erf::new("MOD ");
+    archive.resources.push(Resource {
+        name: "availnpc2".to_string(),
+        resource_type: RESOURCE_TYPE_UTC,
+        data: vec![1, 2, 3, 4],
+    });
+    archive.resources.push(Resource {
+        name: "inventory".to_string(),
+        resource_type: RESOURCE_TYPE_RES,
+        data: vec![],
+    });
+
+    let parsed = Erf::parse(&archive.to_bytes().unwrap()).unwrap();
+
+    assert_eq!(parsed, archive);
+}
+
+#[test]
+fn annotates_gff_fields_and_erf_resources() {
+    let original = gff(
+        "IFO ",
+        vec![
+            ("Tag", GffValue::String("carth".to_string())),
+            ("Gold", GffValue::Dword(120)),
+        ],
+    );
+    let data = original.to_bytes().unwrap();
+    let dump = Gff::annotate(&data).unwrap();
+    let labels: Vec<&str> = dump
+        .spans()
+        .iter()
+        .map(|span| span.label.as_str())
+        .collect();
+
+    assert!(labels.contains(&"field 0: Tag (string)"));
+    assert!(labels.contains(&"field 1: Gold (dword)"));
+    assert!(labels.contains(&"label Gold"));
+    let tag = dump
+        .spans()
+        .iter()
+        .find(|span| span.label == "Tag data")
+        .unwrap();
+    assert_eq!(&data[tag.range.clone()], b"\x05\0\0\0carth");
+
+    let mut archive = Erf::new("SAV ");
+    archive.resources.push(Resource {
+        name: "inventory".to_string(),
+        resource_type: RESOURCE_TYPE_RES,
+        data,
+    });
+    let data = archive.to_bytes().unwrap();
+    let dump = Erf::annotate(&data).unwrap();
+    let inventory = dump
+        .spans()
+        .iter()
+        .find(|span| span.label == "inventory")
+        .unwrap();
+
+    assert_eq!(
+        Gff::parse(&data[inventory.range.clone()]).unwrap(),
+        original
+    );
+}
+
+#[test]
+fn erf_with_too_many_entries_is_an_error() {
+    let mut data = Erf::new("MOD ").to_bytes().unwrap();
+    // Entry count in the header
+    data[16..20].copy_from_slice(&u32::MAX.to_le_bytes());
+
+    assert!(Erf::parse(&data).is_err());
+}
+
+#[test]
+fn erf_resources_are_archive_entries() {
+    let mut archive = Erf::new("MOD ");
+    archive.resources.push(Resource {
+        name: "availnpc2".to_string(),
+        resource_type: RESOURCE_TYPE_UTC,
+        data: b"UTC V3.2".to_vec(),
+    });
+    archive.resources.push(Resource {
+        name: "mystery".to_string(),
+        resource_type: 9999,
+        data: vec![],
+    });
+
+    let names: Vec<String> = archive
+        .entries()
+        .into_iter()
+        .map(|entry| entry.name)
+        .collect();
+    assert_eq!(names, vec!["availnpc2.utc", "mystery.9999"]);
+    assert_eq!(archive.read("AVAILNPC2.UTC").unwrap(), b"UTC V3.2");
+    assert!(archive.read("availnpc2.res").is_err());
+}
+
+#[test]
+fn reads_rim() {
+    let mut data = b"RIM V1.0".to_vec();
+    data.extend_from_slice(&0u32.to_le_bytes());
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(&120u32.to_le_bytes());
+    data.resize(120, 0);
+
+    let mut resref = b"Module".to_vec();
+    resref.resize(16, 0);
+    data.extend_from_slice(&resref);
+    for value in [2014u32, 0, 152, 8] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(b"IFO V3.2");
+
+    let rim = Rim::parse(&data).unwrap();
+    assert_eq!(rim.resources[0].name, "module");
+    assert_eq!(rim.stat("module.ifo").unwrap().size, 8);
+    assert_eq!(rim.read("module.ifo").unwrap(), b"IFO V3.2");
+    assert_eq!(
+        archive::search(&rim, "*.ifo", b"V3.2").unwrap(),
+        vec![("module.ifo".to_string(), 4)]
+    );
+
+    data.truncate(150);
+    assert!(Rim::parse(&data).is_err());
+}
+
+#[test]
+fn reads_save() {
+    let save = KotorSave::load(&write_save("read")).unwrap();
+
+    assert_eq!(save.name(), "Before Leviathan");
+    assert_eq!(save.module(), "tar_m02aa");
+    assert_eq!(
+        save.booleans(),
+        vec![
+            ("TAR_CARTH".to_string(), false),
+            ("TAR_BASTILA".to_string(), true),
+            ("K_SWG_HELENA".to_string(), false),
+        ]
+    );
+    assert_eq!(
+        save.numbers(),
+        vec![("TAR_PAZAAK".to_string(), 3), ("K_MISSION".to_string(), 7)]
+    );
+
+    let party = save.party();
+    assert_eq!(party.gold, 120);
+    assert_eq!(party.members, vec![2]);
+    assert!(party.companions[2].available);
+    assert!(!party.companions[3].available);
+
+    let inventory = save.inventory().unwrap();
+    assert_eq!(inventory.len(), 2);
+    assert_eq!(inventory[0].tag, "G_I_MEDEQPMNT01");
+    assert_eq!(inventory[0].stack_size, 2);
+}
+
+#[test]
+fn edits_are_written_back() {
+    let directory = write_save("edit");
+    let mut save = KotorSave::load(&directory).unwrap();
+
+    save.set_boolean("k_swg_helena", true).unwrap();
+    save.set_number("TAR_PAZAAK", 42).unwrap();
+    save.set_gold(99999);
+    save.set_companion_available("HK47", true).unwrap();
+    assert_eq!(save.set_stack_size("g_i_medeqpmnt01", 10).unwrap(), 1);
+    save.write().unwrap();
+
+    let save = KotorSave::load(&directory).unwrap();
+    assert_eq!(save.booleans()[1], ("TAR_BASTILA".to_string(), true));
+    assert_eq!(save.booleans()[2], ("K_SWG_HELENA".to_string(), true));
+    assert_eq!(save.numbers()[0], ("TAR_PAZAAK".to_string(), 42));
+    assert_eq!(save.party().gold, 99999);
+    assert!(save.party().companions[3].available);
+    assert_eq!(save.inventory().unwrap()[0].stack_size, 10);
+    assert!(directory.join("GLOBALVARS.res.bak").exists());
+    assert!(!directory.join("savenfo.res.bak").exists());
+}
+
+#[test]
+fn unknown_globals_are_not_added() {
+    let mut save = KotorSave::load(&write_save("unknown")).unwrap();
+
+    assert!(save.set_boolean("NOT_A_GLOBAL", true).is_err());
+    assert!(save.set_stack_size("not_an_item", 1).is_err());
+}
@@ -0,0 +1,89 @@
+/// TOML config files.
+///
+/// Missing keys are left to serde defaults, old config files keep working when new options are
+/// added. CLIs keep their config in a directory of their own under `config_dir`, swkotor-mod next
+/// to the game.
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use log::{error, info};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// `XDG_CONFIG_HOME`, falling back to `~/.config` and then to `%APPDATA%` on Windows.
+pub fn config_dir() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+        .or_else(|| env::var_os("APPDATA").map(PathBuf::from))
+}
+
+pub fn load<T: DeserializeOwned>(path: &Path) -> io::Result<T> {
+    let contents = fs::read_to_string(path)?;
+    toml::from_str(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+pub fn save<T: Serialize>(path: &Path, config: &T) -> io::Result<()> {
+    let contents = toml::to_string_pretty(config).map_err(io::Error::other)?;
+    fs::write(path, contents)
+}
+
+/// Loads the config, falling back to defaults on errors. A missing file is created with the
+/// defaults so there's something to edit.
+pub fn load_or_default<T: Default + Serialize + DeserializeOwned>(path: &Path) -> T {
+    match load(path) {
+        Ok(config) => {
+            info!("Loaded config from {}", path.display());
+            config
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            info!("No config at {}, writing defaults", path.display());
+            let config = T::default();
+            if let Err(e) = save(path, &config) {
+                error!("Could not write default config: {e}");
+            }
+            config
+        }
+        Err(e) => {
+            error!(
+                "Could not load config from {}, using defaults: {e}",
+                path.display()
+            );
+            T::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(default)]
+    struct Example {
+        name: String,
+        count: u32,
+    }
+
+    #[test]
+    fn writes_defaults_and_reads_them_back() {
+        let root = env::temp_dir().join(format!("molokki-common-config-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        let path = root.join("example.toml");
+
+        let example: Example = load_or_default(&path);
+        assert_eq!(example, Example::default());
+        assert!(path.exists());
+
+        fs::write(&path, "count = 3").unwrap();
+        assert_eq!(load::<Example>(&path).unwrap().count, 3);
+        fs::write(&path, "count = 'three'").unwrap();
+        let error = load::<Example>(&path).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
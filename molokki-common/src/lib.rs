@@ -0,0 +1,4 @@
+/// Config files and logging shared by the tools in molokki, the CLIs and swkotor-mod alike, so
+/// that every tool finds its config and writes its logs the same way.
+pub mod config;
+pub mod logging;
@@ -0,0 +1,165 @@
+/// Logging to stderr for the CLIs and log files rotated by size for anything running longer.
+///
+/// A log file is rotated once it grows past its size limit, `tool.log` becomes `tool.1.log` and so
+/// on, only the given number of old files is kept. Every new file starts with the header, if one
+/// has been set.
+use std::{
+    fs::{self, File},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use log::LevelFilter;
+
+/// Size log files of the CLIs are rotated at
+pub const MAX_LOG_SIZE: u64 = 10 * 1024 * 1024;
+/// Old log files of the CLIs kept
+pub const KEPT_LOGS: usize = 3;
+
+/// Path of the `index`th old log, e.g. `swkotor-mod.1.log`.
+pub fn rotated_path(path: &Path, index: usize) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let name = match path.extension() {
+        Some(extension) => format!("{stem}.{index}.{}", extension.to_string_lossy()),
+        None => format!("{stem}.{index}"),
+    };
+    path.with_file_name(name)
+}
+
+/// Shifts old logs up by one, dropping the oldest past `kept`, and moves `path` to the first slot.
+pub fn rotate(path: &Path, kept: usize) -> io::Result<()> {
+    if !path.exists() {
+        return Ok(());
+    }
+
+    let oldest = rotated_path(path, kept);
+    if oldest.exists() {
+        fs::remove_file(oldest)?;
+    }
+
+    for index in (1..kept).rev() {
+        let from = rotated_path(path, index);
+        if from.exists() {
+            fs::rename(from, rotated_path(path, index + 1))?;
+        }
+    }
+
+    fs::rename(path, rotated_path(path, 1))
+}
+
+/// Log file rotating itself once it grows past `max_size`. Records are never split between files.
+pub struct RotatingFile {
+    path: PathBuf,
+    file: File,
+    /// Bytes written to the current file
+    written: u64,
+    max_size: u64,
+    kept: usize,
+    /// Written at the top of every file rotated to
+    header: Option<Vec<u8>>,
+}
+
+impl RotatingFile {
+    /// Creates the log at `path`, rotating the previous log away.
+    pub fn create(path: &Path, max_size: u64, kept: usize) -> io::Result<RotatingFile> {
+        rotate(path, kept)?;
+        Ok(RotatingFile {
+            path: path.to_path_buf(),
+            file: File::create(path)?,
+            written: 0,
+            max_size,
+            kept,
+            header: None,
+        })
+    }
+
+    /// Sets what the files rotated to start with. Not written to the current file.
+    pub fn set_header(&mut self, header: Vec<u8>) {
+        self.header = Some(header);
+    }
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, record: &[u8]) -> io::Result<usize> {
+        if self.written + record.len() as u64 > self.max_size && self.written > 0 {
+            self.file.flush()?;
+            rotate(&self.path, self.kept)?;
+            self.file = File::create(&self.path)?;
+            self.written = 0;
+
+            if let Some(header) = &self.header {
+                self.file.write_all(header)?;
+                self.written += header.len() as u64;
+            }
+        }
+
+        self.file.write_all(record)?;
+        self.written += record.len() as u64;
+        Ok(record.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+/// `default` raised a level for each `-v`.
+pub fn level(default: LevelFilter, verbose: u8) -> LevelFilter {
+    LevelFilter::iter()
+        .find(|level| *level as usize == default as usize + verbose as usize)
+        .unwrap_or(LevelFilter::max())
+}
+
+/// Logs at `default` raised by `verbose`, `RUST_LOG` wins when set. Logs to `file` when given,
+/// rotated at `MAX_LOG_SIZE`, to stderr otherwise.
+pub fn init(default: LevelFilter, verbose: u8, file: Option<&Path>) -> io::Result<()> {
+    let mut builder = env_logger::Builder::new();
+    builder
+        .filter_level(level(default, verbose))
+        .parse_default_env();
+    if let Some(path) = file {
+        let file = RotatingFile::create(path, MAX_LOG_SIZE, KEPT_LOGS)?;
+        builder.target(env_logger::Target::Pipe(Box::new(file)));
+    }
+    builder.try_init().map_err(io::Error::other)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotates_past_the_size_limit() {
+        let root = std::env::temp_dir().join(format!("molokki-common-log-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        let path = root.join("tool.log");
+        fs::write(&path, "previous run\n").unwrap();
+
+        let mut file = RotatingFile::create(&path, 10, 2).unwrap();
+        file.set_header(b"header\n".to_vec());
+        file.write_all(b"first\n").unwrap();
+        file.write_all(b"second\n").unwrap();
+        file.write_all(b"third\n").unwrap();
+        file.flush().unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "header\nthird\n");
+        assert_eq!(
+            fs::read_to_string(rotated_path(&path, 1)).unwrap(),
+            "header\nsecond\n"
+        );
+        assert_eq!(
+            fs::read_to_string(rotated_path(&path, 2)).unwrap(),
+            "first\n"
+        );
+        assert!(!rotated_path(&path, 3).exists());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn raises_the_level_per_verbose_flag() {
+        assert_eq!(level(LevelFilter::Warn, 0), LevelFilter::Warn);
+        assert_eq!(level(LevelFilter::Warn, 2), LevelFilter::Debug);
+        assert_eq!(level(LevelFilter::Info, 9), LevelFilter::Trace);
+    }
+}
@@ -0,0 +1,19 @@
+{
+  pkgs,
+}:
+{
+  package = pkgs.rustPlatform.buildRustPackage {
+    pname = "molokki";
+    version = "0.1.0";
+    cargoLock.lockFile = ./Cargo.lock;
+    # The tools are path dependencies, they have to be in the source too
+    src = pkgs.lib.cleanSource ./..;
+    cargoRoot = "molokki";
+    buildAndTestSubdir = "molokki";
+  };
+  devShell = pkgs.mkShell {
+    buildInputs = with pkgs; [
+      cargo
+    ];
+  };
+}
@@ -0,0 +1,139 @@
+/// Defaults for the options of the tools, so that e.g. the save directory doesn't have to be given
+/// on every run.
+///
+/// Read from `molokki/config.toml` in the config directory of `molokki_common::config`, or from
+/// the file `MOLOKKI_CONFIG` points to. Each table is named after a subcommand and sets its
+/// options by their long name, options given on the command line win:
+///
+/// ```toml
+/// [kotor-save]
+/// save-path = "/games/swkotor/saves/000001 - Game0"
+///
+/// [inject]
+/// game = 'C:\Games\swkotor\swkotor.exe'
+/// ```
+use std::{collections::BTreeMap, env, io, path::PathBuf};
+
+use clap::Command;
+use molokki_common::config::{config_dir, load as load_file};
+use toml::Value;
+
+/// Option values by subcommand and option name
+pub type Config = BTreeMap<String, BTreeMap<String, Value>>;
+
+fn path() -> Option<PathBuf> {
+    if let Some(path) = env::var_os("MOLOKKI_CONFIG") {
+        return Some(PathBuf::from(path));
+    }
+    Some(config_dir()?.join("molokki").join("config.toml"))
+}
+
+/// Reads the config and where it was read from. No config file is the same as an empty one.
+pub fn load() -> Result<(Option<PathBuf>, Config), String> {
+    let Some(path) = path() else {
+        return Ok((None, Config::new()));
+    };
+
+    match load_file(&path) {
+        Ok(config) => Ok((Some(path), config)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok((None, Config::new())),
+        Err(e) => Err(format!("Could not load {}: {e}", path.display())),
+    }
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// Sets the options in `config` as defaults in `command`. Returns the command and the options
+/// that don't exist.
+pub fn apply(mut command: Command, config: &Config) -> (Command, Vec<String>) {
+    let mut unknown = Vec::new();
+
+    for (name, options) in config {
+        let Some(subcommand) = command.find_subcommand(name) else {
+            unknown.push(format!("[{name}]"));
+            continue;
+        };
+        let subcommand_name = subcommand.get_name().to_string();
+
+        let mut defaults = Vec::new();
+        for (option, value) in options {
+            let id = subcommand
+                .get_arguments()
+                .find(|arg| arg.get_long() == Some(option) || arg.get_id() == option)
+                .map(|arg| arg.get_id().clone());
+            match id {
+                Some(id) => defaults.push((id, text(value))),
+                None => unknown.push(format!("{name}.{option}")),
+            }
+        }
+
+        command = command.mut_subcommand(subcommand_name, |mut subcommand| {
+            for (id, value) in defaults {
+                subcommand = subcommand.mut_arg(id, |arg| arg.default_value(value).required(false));
+            }
+            subcommand
+        });
+    }
+
+    (command, unknown)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use clap::CommandFactory;
+
+    use super::*;
+    use crate::Cli;
+
+    #[test]
+    fn sets_defaults_from_the_config() {
+        let config: Config = toml::from_str(
+            r#"
+            [kotor-save]
+            save-path = "saves/000001 - Game0"
+            missing = 1
+
+            [fallout]
+            save_file_path = "SLOT01/SAVE.DAT"
+
+            [nothing]
+            "#,
+        )
+        .unwrap();
+        let (command, unknown) = apply(Cli::command(), &config);
+        assert_eq!(unknown, ["kotor-save.missing", "[nothing]"]);
+
+        let matches = command
+            .clone()
+            .try_get_matches_from(["molokki", "kotor-save", "party"])
+            .unwrap();
+        let kotor = matches.subcommand_matches("kotor-save").unwrap();
+        assert_eq!(
+            kotor.get_one::<PathBuf>("save_path").unwrap(),
+            Path::new("saves/000001 - Game0")
+        );
+
+        // Command line wins
+        let matches = command
+            .try_get_matches_from([
+                "molokki",
+                "fallout",
+                "-s",
+                "SLOT02/SAVE.DAT",
+                "fix-ncr-cop-aggro",
+            ])
+            .unwrap();
+        let fallout = matches.subcommand_matches("fallout").unwrap();
+        assert_eq!(
+            fallout.get_one::<String>("save_file_path").unwrap(),
+            "SLOT02/SAVE.DAT"
+        );
+    }
+}
@@ -0,0 +1,75 @@
+/// One executable for the tools in molokki, each as a subcommand of its own.
+///
+/// The subcommands take the same arguments as the standalone tools, e.g.
+/// `molokki kotor-save --save-path "saves/000001 - Game0" party`. Logging and the config file are
+/// handled here for all of them, see `config` for the latter.
+mod config;
+
+#[cfg(all(windows, target_arch = "x86"))]
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{ArgAction, CommandFactory, FromArgMatches, Parser, Subcommand};
+use log::{info, warn, LevelFilter};
+use molokki_common::logging;
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Fix Fallout 2 saves
+    Fallout(fallout_save_editor::ui::Cli),
+    /// Inspect and edit Star Wars: Knights of the Old Republic saves
+    KotorSave(kotor_save_editor::ui::Cli),
+    /// Start Star Wars: Knights of the Old Republic with swkotor-mod loaded
+    #[cfg(all(windows, target_arch = "x86"))]
+    Inject {
+        /// Game executable, swkotor.exe in the working directory by default
+        game: Option<PathBuf>,
+        /// Mod DLL, swkotor_mod.dll next to this executable by default
+        dll: Option<PathBuf>,
+    },
+}
+
+/// All the tools of molokki in one
+#[derive(Parser)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+
+    /// Log more, repeat for even more
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+}
+
+fn main() -> ExitCode {
+    let (config_path, config) = match config::load() {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (command, unknown) = config::apply(Cli::command(), &config);
+    let cli = match Cli::from_arg_matches(&command.get_matches()) {
+        Ok(cli) => cli,
+        Err(e) => e.exit(),
+    };
+
+    if let Err(e) = logging::init(LevelFilter::Warn, cli.verbose, None) {
+        eprintln!("Could not set up logging: {e}");
+    }
+    if let Some(path) = config_path {
+        info!("Using defaults from {}", path.display());
+    }
+    for key in unknown {
+        warn!("Ignoring {key} in the config, there's no such option");
+    }
+
+    match cli.command {
+        Commands::Fallout(cli) => fallout_save_editor::ui::run(cli),
+        Commands::KotorSave(cli) => kotor_save_editor::ui::run(cli),
+        #[cfg(all(windows, target_arch = "x86"))]
+        Commands::Inject { game, dll } => swkotor_inject::run(game, dll),
+    }
+}
@@ -0,0 +1,20 @@
+{
+  pkgs,
+}:
+{
+  package = pkgs.rustPlatform.buildRustPackage {
+    pname = "save-backup";
+    version = "0.1.0";
+    cargoLock.lockFile = ./Cargo.lock;
+    src = pkgs.lib.cleanSource ./.;
+
+    checkPhase = ''
+      cargo test
+    '';
+  };
+  devShell = pkgs.mkShell {
+    buildInputs = with pkgs; [
+      cargo
+    ];
+  };
+}
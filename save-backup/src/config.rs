@@ -0,0 +1,110 @@
+/// What the daemon watches, from a TOML file given on the command line.
+///
+/// ```toml
+/// backups = "/home/me/save-backups"
+/// log_file = "/home/me/save-backups/save-backup.log"
+///
+/// [[watch]]
+/// name = "fallout2"
+/// saves = "/games/fallout2/data/SAVEGAME"
+/// keep = 20
+///
+/// [[watch]]
+/// name = "kotor"
+/// saves = "/games/swkotor/saves"
+/// max_age_days = 30
+/// ```
+use std::{path::Path, path::PathBuf, time::Duration};
+
+use serde::Deserialize;
+
+use save_backup::Retention;
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Watch {
+    /// Backups of these saves go to `<backups>/<name>`
+    pub name: String,
+    /// Directory with a directory per save, e.g. Fallout 2's `data/SAVEGAME`
+    pub saves: PathBuf,
+    /// Newest backups kept of each save
+    #[serde(default = "default_keep")]
+    pub keep: usize,
+    /// Older backups are removed, the newest one of each save is always kept
+    pub max_age_days: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub backups: PathBuf,
+    /// Logs go here instead of stderr, rotated as they grow
+    pub log_file: Option<PathBuf>,
+    /// How often the saves are checked for changes
+    #[serde(default = "default_interval_seconds")]
+    pub interval_seconds: u64,
+    /// A save is backed up once it hasn't changed for this long, games write saves a file at a time
+    #[serde(default = "default_settle_seconds")]
+    pub settle_seconds: u64,
+    #[serde(default)]
+    pub watch: Vec<Watch>,
+}
+
+fn default_keep() -> usize {
+    Retention::default().keep
+}
+
+fn default_interval_seconds() -> u64 {
+    5
+}
+
+fn default_settle_seconds() -> u64 {
+    2
+}
+
+impl Watch {
+    pub fn retention(&self) -> Retention {
+        Retention {
+            keep: self.keep,
+            max_age: self
+                .max_age_days
+                .map(|days| Duration::from_secs(days * 24 * 60 * 60)),
+        }
+    }
+}
+
+impl Config {
+    pub fn load(path: &Path) -> Result<Config, String> {
+        molokki_common::config::load(path)
+            .map_err(|e| format!("Could not load {}: {e}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_watches_with_defaults() {
+        let config: Config = toml::from_str(
+            r#"
+            backups = "/backups"
+
+            [[watch]]
+            name = "kotor"
+            saves = "/games/swkotor/saves"
+            max_age_days = 2
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.interval_seconds, 5);
+        assert_eq!(
+            config.watch[0].retention(),
+            Retention {
+                keep: 10,
+                max_age: Some(Duration::from_secs(2 * 24 * 60 * 60)),
+            }
+        );
+        assert!(toml::from_str::<Config>("backups = '/b'\nkeep = 1").is_err());
+    }
+}
@@ -0,0 +1,216 @@
+/// Timestamped copies of save directories, rotated by a retention policy.
+///
+/// Both games keep every save in a directory of its own, Fallout 2 e.g. `SLOT01` in
+/// `data/SAVEGAME` and KOTOR e.g. `000001 - Game0` in `saves`. A backup copies the whole directory
+/// to `<backups>/<save directory name>/<timestamp>`. Timestamps are milliseconds since the Unix
+/// epoch, the newest backup sorts last. swkotor-mod takes its backups with this crate too.
+use std::{
+    ffi::OsStr,
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Which backups of a save to keep.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Retention {
+    /// Newest backups kept
+    pub keep: usize,
+    /// Backups older than this are removed, the newest one is always kept
+    pub max_age: Option<Duration>,
+}
+
+impl Retention {
+    /// Keeps every backup, for callers that leave rotating to whatever else takes backups
+    pub fn keep_all() -> Self {
+        Retention {
+            keep: usize::MAX,
+            max_age: None,
+        }
+    }
+}
+
+impl Default for Retention {
+    fn default() -> Self {
+        Retention {
+            keep: 10,
+            max_age: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Backup {
+    pub path: PathBuf,
+    /// Milliseconds since the Unix epoch
+    pub taken: u128,
+}
+
+fn millis(time: SystemTime) -> u128 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis()
+}
+
+fn copy_directory(from: &Path, to: &Path) -> io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_directory(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+/// When anything in the save directory `save` was last modified, in milliseconds since the Unix
+/// epoch.
+pub fn modified(save: &Path) -> io::Result<u128> {
+    let mut latest = millis(fs::metadata(save)?.modified()?);
+    for entry in fs::read_dir(save)? {
+        let entry = entry?;
+        let modified = if entry.file_type()?.is_dir() {
+            modified(&entry.path())?
+        } else {
+            millis(entry.metadata()?.modified()?)
+        };
+        latest = latest.max(modified);
+    }
+    Ok(latest)
+}
+
+/// Backups of the save named `name` in `backups`, oldest first. None when there's no backup yet.
+pub fn backups(backups: &Path, name: &OsStr) -> io::Result<Vec<Backup>> {
+    let entries = match fs::read_dir(backups.join(name)) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    let mut found: Vec<Backup> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let taken = path.file_name()?.to_str()?.parse().ok()?;
+            Some(Backup { path, taken })
+        })
+        .collect();
+    found.sort_unstable_by_key(|backup| backup.taken);
+    Ok(found)
+}
+
+/// Removes the backups of the save named `name` that `retention` doesn't keep at `now`.
+fn rotate(backups_path: &Path, name: &OsStr, retention: &Retention, now: u128) -> io::Result<()> {
+    let mut found = backups(backups_path, name)?;
+    // Newest is kept no matter what
+    found.pop();
+
+    let excess = (found.len() + 1).saturating_sub(retention.keep.max(1));
+    for (index, backup) in found.iter().enumerate() {
+        let expired = retention
+            .max_age
+            .is_some_and(|max_age| now.saturating_sub(backup.taken) > max_age.as_millis());
+        if index < excess || expired {
+            fs::remove_dir_all(&backup.path)?;
+        }
+    }
+    Ok(())
+}
+
+fn save_name(save: &Path) -> io::Result<&OsStr> {
+    save.file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Save has no directory name"))
+}
+
+/// Copies the save directory `save` into `backups` and removes the backups of the same save that
+/// `retention` doesn't keep. Returns where the copy went.
+pub fn backup(save: &Path, backups: &Path, retention: &Retention) -> io::Result<PathBuf> {
+    let name = save_name(save)?;
+    let now = millis(SystemTime::now());
+    let backup = backups.join(name).join(now.to_string());
+    copy_directory(save, &backup)?;
+    rotate(backups, name, retention, now)?;
+    Ok(backup)
+}
+
+/// Replaces the save directory `save` with the backup in `backup`. Files the backup doesn't have,
+/// e.g. maps visited after it was taken, are removed.
+pub fn restore(backup: &Path, save: &Path) -> io::Result<()> {
+    match fs::remove_dir_all(save) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+        _ => {}
+    }
+    copy_directory(backup, save)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("save-backup-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        root
+    }
+
+    fn taken(backups_path: &Path, name: &str) -> Vec<u128> {
+        backups(backups_path, OsStr::new(name))
+            .unwrap()
+            .iter()
+            .map(|backup| backup.taken)
+            .collect()
+    }
+
+    #[test]
+    fn rotates_by_count_and_age() {
+        let root = temp_dir("rotate");
+        for taken in ["1000", "2000", "3000", "4000", "not-a-backup"] {
+            fs::create_dir_all(root.join("SLOT01").join(taken)).unwrap();
+        }
+
+        let name = OsStr::new("SLOT01");
+        let keep_three = Retention {
+            keep: 3,
+            max_age: None,
+        };
+        rotate(&root, name, &keep_three, 5000).unwrap();
+        assert_eq!(taken(&root, "SLOT01"), [2000, 3000, 4000]);
+
+        let young = Retention {
+            keep: 10,
+            max_age: Some(Duration::from_millis(2500)),
+        };
+        rotate(&root, name, &young, 5000).unwrap();
+        assert_eq!(taken(&root, "SLOT01"), [3000, 4000]);
+
+        // Newest stays even when too old
+        rotate(&root, name, &young, 100_000).unwrap();
+        assert_eq!(taken(&root, "SLOT01"), [4000]);
+        assert!(taken(&root, "SLOT02").is_empty());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn backs_up_and_restores() {
+        let root = temp_dir("restore");
+        let save = root.join("saves").join("000002 - Game2");
+        fs::create_dir_all(&save).unwrap();
+        fs::write(save.join("savenfo.res"), b"before").unwrap();
+
+        let backups_path = root.join("backups");
+        let copy = backup(&save, &backups_path, &Retention::default()).unwrap();
+        assert_eq!(fs::read(copy.join("savenfo.res")).unwrap(), b"before");
+        assert!(modified(&save).unwrap() > 0);
+
+        fs::write(save.join("savenfo.res"), b"after").unwrap();
+        fs::write(save.join("NEWMAP.SAV"), b"map").unwrap();
+        restore(&copy, &save).unwrap();
+        assert_eq!(fs::read(save.join("savenfo.res")).unwrap(), b"before");
+        assert!(!save.join("NEWMAP.SAV").exists());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,117 @@
+/// Keeps rotating backups of game saves, for games that have no backups of their own.
+///
+/// Every few seconds each watched saves directory is checked for saves changed since their latest
+/// backup. A changed save is copied once the game has stopped writing it, see `save_backup` for
+/// the layout and the retention.
+///
+/// Usage: `save-backup [--once] <config.toml>`, see `config` for the file.
+mod config;
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use clap::{ArgAction, Parser};
+use log::{error, info, LevelFilter};
+use molokki_common::logging;
+
+use config::{Config, Watch};
+
+/// Program to back up game saves as they change
+#[derive(Parser)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    /// Path to the config file
+    config: PathBuf,
+
+    /// Check the saves once and exit instead of watching them
+    #[arg(long)]
+    once: bool,
+
+    /// Log more, repeat for even more
+    #[arg(short, long, action = ArgAction::Count)]
+    verbose: u8,
+}
+
+/// Backs up the saves in `watch` changed since their latest backup, unless the game is still
+/// writing them.
+fn check(watch: &Watch, backups: &Path, settle: Duration) -> io::Result<()> {
+    let backups = backups.join(&watch.name);
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+
+    for entry in fs::read_dir(&watch.saves)? {
+        let save = entry?.path();
+        let Some(name) = save.file_name() else {
+            continue;
+        };
+        if !save.is_dir() {
+            continue;
+        }
+
+        let backed_up = || -> io::Result<Option<PathBuf>> {
+            let modified = save_backup::modified(&save)?;
+            let latest = save_backup::backups(&backups, name)?.pop();
+            if latest.is_some_and(|latest| latest.taken >= modified)
+                || now.saturating_sub(modified) < settle.as_millis()
+            {
+                return Ok(None);
+            }
+            save_backup::backup(&save, &backups, &watch.retention()).map(Some)
+        };
+        match backed_up() {
+            Ok(Some(copy)) => info!("Backed up {} to {}", save.display(), copy.display()),
+            Ok(None) => {}
+            Err(e) => error!("Could not back up {}: {e}", save.display()),
+        }
+    }
+    Ok(())
+}
+
+fn check_all(config: &Config) {
+    let settle = Duration::from_secs(config.settle_seconds);
+    for watch in &config.watch {
+        if let Err(e) = check(watch, &config.backups, settle) {
+            error!("Could not read {}: {e}", watch.saves.display());
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let config = match Config::load(&cli.config) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(e) = logging::init(LevelFilter::Info, cli.verbose, config.log_file.as_deref()) {
+        eprintln!("Could not set up logging: {e}");
+        return ExitCode::FAILURE;
+    }
+    if config.watch.is_empty() {
+        error!("Nothing to watch in {}", cli.config.display());
+        return ExitCode::FAILURE;
+    }
+
+    if cli.once {
+        check_all(&config);
+        return ExitCode::SUCCESS;
+    }
+
+    for watch in &config.watch {
+        info!("Watching {} as {}", watch.saves.display(), watch.name);
+    }
+    loop {
+        check_all(&config);
+        thread::sleep(Duration::from_secs(config.interval_seconds));
+    }
+}
@@ -0,0 +1,97 @@
+/// IPC client, speaking the mod's JSON requests over the named pipe or a WebSocket.
+use std::{
+    error::Error,
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    net::TcpStream,
+};
+
+use serde_json::{Map, Value};
+use tungstenite::{stream::MaybeTlsStream, Message, WebSocket};
+
+/// Same as `ipc::PIPE_NAME` in the mod
+pub const PIPE_NAME: &str = r"\\.\pipe\swkotor-mod";
+
+/// Fields of an `ok` response, values are strings
+pub type Fields = Map<String, Value>;
+
+pub enum Client {
+    Pipe {
+        reader: BufReader<File>,
+        writer: File,
+    },
+    WebSocket(Box<WebSocket<MaybeTlsStream<TcpStream>>>),
+}
+
+impl Client {
+    /// Connects to the WebSocket server at `address`, e.g. `ws://127.0.0.1:8787`, or to the named
+    /// pipe of the game running on this machine when there's none.
+    pub fn connect(address: Option<&str>) -> Result<Client, Box<dyn Error>> {
+        match address {
+            Some(address) => {
+                let (socket, _) = tungstenite::connect(address)?;
+                Ok(Client::WebSocket(Box::new(socket)))
+            }
+            None => {
+                let writer = OpenOptions::new().read(true).write(true).open(PIPE_NAME)?;
+                let reader = BufReader::new(writer.try_clone()?);
+                Ok(Client::Pipe { reader, writer })
+            }
+        }
+    }
+
+    /// Sends `request` and waits for its response.
+    pub fn request(&mut self, request: Value) -> Result<Fields, Box<dyn Error>> {
+        let text = match self {
+            Client::Pipe { reader, writer } => {
+                writeln!(writer, "{request}")?;
+                writer.flush()?;
+                let mut line = String::new();
+                if reader.read_line(&mut line)? == 0 {
+                    return Err("The game closed the pipe".into());
+                }
+                line
+            }
+            Client::WebSocket(socket) => {
+                socket.send(Message::text(request.to_string()))?;
+                loop {
+                    match socket.read()? {
+                        Message::Text(text) => break text.to_string(),
+                        // Pings are answered by tungstenite
+                        _ => continue,
+                    }
+                }
+            }
+        };
+        parse_response(&text)
+    }
+}
+
+/// Fields of a `{"ok": {...}}` response, the message of an `{"err": "..."}` one as the error.
+pub fn parse_response(text: &str) -> Result<Fields, Box<dyn Error>> {
+    let response: Value = serde_json::from_str(text)?;
+    if let Some(Value::Object(fields)) = response.get("ok") {
+        return Ok(fields.clone());
+    }
+    match response.get("err").and_then(Value::as_str) {
+        Some(message) => Err(message.into()),
+        None => Err(format!("Unexpected response {text}").into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_responses() {
+        let fields = parse_response(r#"{"ok": {"module": "end_m01aa", "loads": "2"}}"#).unwrap();
+        assert_eq!(fields["module"], "end_m01aa");
+        assert_eq!(fields["loads"], "2");
+
+        let error = parse_response(r#"{"err": "Unknown toggle 'fly'"}"#).unwrap_err();
+        assert_eq!(error.to_string(), "Unknown toggle 'fly'");
+        assert!(parse_response(r#"{"ok": 1}"#).is_err());
+        assert!(parse_response("ok module=end_m01aa").is_err());
+    }
+}
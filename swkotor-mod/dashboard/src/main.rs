@@ -0,0 +1,58 @@
+/// Desktop dashboard mirroring the mod's diagnostics in a window of its own, for a second monitor
+/// or a stream layout.
+///
+/// Polls the speedrun timer, the frame times of the last few seconds and the new log lines over
+/// the mod's IPC protocol a few times a second, and reconnects whenever the game is restarted.
+///
+/// Usage: `swkotor-mod-dashboard [ws://address:port]`. Connects to the named pipe of the game on
+/// this machine by default, or to the mod's WebSocket server when given its address, see
+/// `websocket.enabled` in the mod's config.
+mod client;
+mod state;
+mod window;
+
+use std::{env, process::ExitCode, thread, time::Duration};
+
+use client::{Client, PIPE_NAME};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+/// Wait between attempts while the game isn't running
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Keeps the state up to date, forever.
+fn poll_game(address: Option<String>) {
+    let target = address.clone().unwrap_or_else(|| PIPE_NAME.to_string());
+    loop {
+        let mut client = match Client::connect(address.as_deref()) {
+            Ok(client) => client,
+            Err(e) => {
+                state::set_connection(format!("Waiting for the game at {target}: {e}"), false);
+                thread::sleep(RECONNECT_INTERVAL);
+                continue;
+            }
+        };
+        state::set_connection(format!("Connected to {target}"), true);
+
+        loop {
+            if let Err(e) = state::poll(&mut client) {
+                state::set_connection(format!("Lost {target}: {e}"), false);
+                break;
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+        thread::sleep(RECONNECT_INTERVAL);
+    }
+}
+
+fn main() -> ExitCode {
+    let address = env::args().nth(1);
+    thread::spawn(move || poll_game(address));
+
+    match window::run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Could not open the dashboard window: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,181 @@
+/// What the dashboard shows, polled from the game and drawn by the window.
+use std::{collections::VecDeque, error::Error, sync::Mutex};
+
+use serde_json::json;
+
+use crate::client::{Client, Fields};
+
+/// Log lines kept, older ones scroll out
+const KEPT_LOG_LINES: usize = 1000;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Timer {
+    pub running: bool,
+    pub rta_ms: u64,
+    pub igt_ms: u64,
+    pub splits: u64,
+    pub loading: bool,
+    pub loads: u64,
+}
+
+pub struct State {
+    /// Where the dashboard is connected to, or why it isn't
+    pub connection: String,
+    pub connected: bool,
+    pub timer: Option<Timer>,
+    /// Frame times in milliseconds, oldest first
+    pub frames: Vec<f32>,
+    pub logs: VecDeque<String>,
+    /// Number of the latest log line received
+    last_log: u64,
+}
+
+pub static STATE: Mutex<State> = Mutex::new(State {
+    connection: String::new(),
+    connected: false,
+    timer: None,
+    frames: Vec::new(),
+    logs: VecDeque::new(),
+    last_log: 0,
+});
+
+fn field<'a>(fields: &'a Fields, key: &str) -> Result<&'a str, Box<dyn Error>> {
+    fields
+        .get(key)
+        .and_then(|value| value.as_str())
+        .ok_or_else(|| format!("Response has no {key}").into())
+}
+
+fn number(fields: &Fields, key: &str) -> Result<u64, Box<dyn Error>> {
+    Ok(field(fields, key)?.parse()?)
+}
+
+impl Timer {
+    pub fn from_fields(fields: &Fields) -> Result<Timer, Box<dyn Error>> {
+        Ok(Timer {
+            running: field(fields, "running")? == "true",
+            rta_ms: number(fields, "rta_ms")?,
+            igt_ms: number(fields, "igt_ms")?,
+            splits: number(fields, "splits")?,
+            loading: field(fields, "loading")? == "true",
+            loads: number(fields, "loads")?,
+        })
+    }
+}
+
+/// Parses the `ms` field of a `frames` response, e.g. `16.7,16.6,33.4`.
+pub fn parse_frames(text: &str) -> Vec<f32> {
+    text.split(',')
+        .filter_map(|time| time.parse().ok())
+        .collect()
+}
+
+/// `h:mm:ss.mmm`, hours left out while zero.
+pub fn format_time(milliseconds: u64) -> String {
+    let seconds = milliseconds / 1000;
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    let milliseconds = milliseconds % 1000;
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}.{milliseconds:03}")
+    } else {
+        format!("{minutes}:{seconds:02}.{milliseconds:03}")
+    }
+}
+
+impl State {
+    /// Appends the lines of a `logs` response.
+    fn add_logs(&mut self, fields: &Fields) -> Result<(), Box<dyn Error>> {
+        let last = number(fields, "last")?;
+        if last < self.last_log {
+            // Numbers start over when the game is restarted
+            self.logs.clear();
+        }
+
+        let mut lines: Vec<(u64, &str)> = fields
+            .iter()
+            .filter_map(|(key, value)| {
+                let number = key.strip_prefix("line.")?.parse().ok()?;
+                Some((number, value.as_str()?))
+            })
+            .collect();
+        lines.sort_by_key(|(number, _)| *number);
+        for (_, line) in lines {
+            if self.logs.len() >= KEPT_LOG_LINES {
+                self.logs.pop_front();
+            }
+            self.logs.push_back(line.to_string());
+        }
+        self.last_log = last;
+        Ok(())
+    }
+}
+
+/// Asks the game for everything shown, once.
+pub fn poll(client: &mut Client) -> Result<(), Box<dyn Error>> {
+    let timer = Timer::from_fields(&client.request(json!({ "request": "timer" }))?)?;
+    let frames = client.request(json!({ "request": "frames" }))?;
+    let frames = parse_frames(field(&frames, "ms")?);
+    let after = STATE.lock().unwrap().last_log;
+    let logs = client.request(json!({ "request": "logs", "after": after }))?;
+
+    let mut state = STATE.lock().unwrap();
+    state.timer = Some(timer);
+    state.frames = frames;
+    state.add_logs(&logs)
+}
+
+/// Shows `connection` as the state of the connection, old values stay on screen.
+pub fn set_connection(connection: String, connected: bool) {
+    let mut state = STATE.lock().unwrap();
+    state.connection = connection;
+    state.connected = connected;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::client::parse_response;
+
+    fn state() -> State {
+        State {
+            connection: String::new(),
+            connected: true,
+            timer: None,
+            frames: Vec::new(),
+            logs: VecDeque::new(),
+            last_log: 0,
+        }
+    }
+
+    #[test]
+    fn parses_timer_and_frames() {
+        let fields = parse_response(
+            r#"{"ok": {"running": "true", "rta_ms": "61500", "igt_ms": "60250", "splits": "3",
+                "loading": "false", "loads": "2", "load_ms": "1250"}}"#,
+        )
+        .unwrap();
+        let timer = Timer::from_fields(&fields).unwrap();
+        assert!(timer.running);
+        assert_eq!(format_time(timer.rta_ms), "1:01.500");
+        assert_eq!(format_time(3_723_004), "1:02:03.004");
+        assert_eq!(parse_frames("16.7,33.4"), vec![16.7, 33.4]);
+        assert!(parse_frames("").is_empty());
+    }
+
+    #[test]
+    fn appends_log_lines_in_order() {
+        let mut state = state();
+        let fields =
+            parse_response(r#"{"ok": {"last": "11", "line.11": "second", "line.9": "first"}}"#)
+                .unwrap();
+        state.add_logs(&fields).unwrap();
+        assert_eq!(state.logs, ["first", "second"]);
+        assert_eq!(state.last_log, 11);
+
+        // Restarted game numbers from 1 again
+        let fields = parse_response(r#"{"ok": {"last": "1", "line.1": "restarted"}}"#).unwrap();
+        state.add_logs(&fields).unwrap();
+        assert_eq!(state.logs, ["restarted"]);
+    }
+}
@@ -0,0 +1,255 @@
+/// Resizable desktop window drawing the state with GDI.
+///
+/// The window repaints on a timer, a few times a second is plenty for a second monitor. Painting
+/// goes through an off-screen bitmap, drawing straight to the window flickers when resizing.
+use std::error::Error;
+
+use windows::{
+    core::{w, HSTRING},
+    Win32::{
+        Foundation::{COLORREF, HWND, LPARAM, LRESULT, POINT, RECT, WPARAM},
+        Graphics::Gdi::{
+            BeginPaint, BitBlt, CreateCompatibleBitmap, CreateCompatibleDC, CreateFontW, CreatePen,
+            CreateSolidBrush, DeleteDC, DeleteObject, EndPaint, FillRect, InvalidateRect, Polyline,
+            SelectObject, SetBkMode, SetTextColor, TextOutW, ANSI_CHARSET, CLEARTYPE_QUALITY,
+            CLIP_DEFAULT_PRECIS, FIXED_PITCH, FW_NORMAL, HDC, OUT_TT_PRECIS, PAINTSTRUCT, PS_DOT,
+            PS_SOLID, SRCCOPY, TRANSPARENT,
+        },
+        System::LibraryLoader::GetModuleHandleW,
+        UI::WindowsAndMessaging::{
+            CreateWindowExW, DefWindowProcW, DispatchMessageW, GetClientRect, GetMessageW,
+            LoadCursorW, PostQuitMessage, RegisterClassW, SetTimer, TranslateMessage,
+            CW_USEDEFAULT, IDC_ARROW, MSG, WINDOW_EX_STYLE, WM_DESTROY, WM_ERASEBKGND, WM_PAINT,
+            WM_SIZE, WM_TIMER, WNDCLASSW, WS_OVERLAPPEDWINDOW, WS_VISIBLE,
+        },
+    },
+};
+
+use crate::state::{format_time, STATE};
+
+const REPAINT_INTERVAL_MS: u32 = 250;
+const LINE_HEIGHT: i32 = 18;
+const MARGIN: i32 = 10;
+/// Share of the window height the frame graph takes
+const GRAPH_SHARE: f32 = 0.35;
+/// Graph goes up to at least this, so a smooth 60fps doesn't fill it
+const GRAPH_MIN_MS: f32 = 50.0;
+
+const BACKGROUND: COLORREF = rgb(0x1e, 0x1e, 0x1e);
+const TEXT: COLORREF = rgb(0xe0, 0xe0, 0xe0);
+const MUTED: COLORREF = rgb(0x90, 0x90, 0x90);
+const GRAPH: COLORREF = rgb(0x56, 0xb4, 0xe9);
+const WARNING: COLORREF = rgb(0xe6, 0x9f, 0x00);
+
+const fn rgb(r: u8, g: u8, b: u8) -> COLORREF {
+    COLORREF(r as u32 | (g as u32) << 8 | (b as u32) << 16)
+}
+
+unsafe fn text(hdc: HDC, x: i32, y: i32, text: &str, color: COLORREF) {
+    SetTextColor(hdc, color);
+    let _ = TextOutW(hdc, x, y, &HSTRING::from(text));
+}
+
+/// Horizontal line across `rect` at `y`.
+unsafe fn guide(hdc: HDC, rect: &RECT, y: i32) {
+    let pen = CreatePen(PS_DOT, 1, MUTED);
+    let previous = SelectObject(hdc, pen.into());
+    let _ = Polyline(
+        hdc,
+        &[POINT { x: rect.left, y }, POINT { x: rect.right, y }],
+    );
+    SelectObject(hdc, previous);
+    let _ = DeleteObject(pen.into());
+}
+
+/// Frame times as a line graph filling `rect`, with guides at 60fps and 30fps.
+unsafe fn draw_frames(hdc: HDC, rect: &RECT, frames: &[f32]) {
+    let height = (rect.bottom - rect.top) as f32;
+    let top_ms = frames.iter().copied().fold(GRAPH_MIN_MS, f32::max);
+    let to_y = |ms: f32| rect.bottom - (ms / top_ms * height) as i32;
+
+    for (fps, ms) in [(60, 1000.0 / 60.0), (30, 1000.0 / 30.0)] {
+        let y = to_y(ms);
+        guide(hdc, rect, y);
+        text(hdc, rect.left, y - LINE_HEIGHT, &format!("{fps}fps"), MUTED);
+    }
+
+    if frames.len() > 1 {
+        let step = (rect.right - rect.left) as f32 / (frames.len() - 1) as f32;
+        let points: Vec<POINT> = frames
+            .iter()
+            .enumerate()
+            .map(|(i, ms)| POINT {
+                x: rect.left + (i as f32 * step) as i32,
+                y: to_y(*ms),
+            })
+            .collect();
+        let pen = CreatePen(PS_SOLID, 2, GRAPH);
+        let previous = SelectObject(hdc, pen.into());
+        let _ = Polyline(hdc, &points);
+        SelectObject(hdc, previous);
+        let _ = DeleteObject(pen.into());
+    }
+}
+
+unsafe fn draw(hdc: HDC, client: &RECT) {
+    let background = CreateSolidBrush(BACKGROUND);
+    FillRect(hdc, client, background);
+    let _ = DeleteObject(background.into());
+    SetBkMode(hdc, TRANSPARENT);
+
+    let state = STATE.lock().unwrap();
+    let mut y = MARGIN;
+    let status = if state.connected { MUTED } else { WARNING };
+    text(hdc, MARGIN, y, &state.connection, status);
+    y += LINE_HEIGHT;
+
+    if let Some(timer) = &state.timer {
+        let line = format!(
+            "RTA {}  IGT {}  splits {}  loads {}{}{}",
+            format_time(timer.rta_ms),
+            format_time(timer.igt_ms),
+            timer.splits,
+            timer.loads,
+            if timer.running { "" } else { "  stopped" },
+            if timer.loading { "  loading" } else { "" },
+        );
+        text(hdc, MARGIN, y, &line, TEXT);
+    }
+    y += LINE_HEIGHT;
+
+    if !state.frames.is_empty() {
+        let worst = state.frames.iter().copied().fold(0.0, f32::max);
+        let mean = state.frames.iter().sum::<f32>() / state.frames.len() as f32;
+        text(
+            hdc,
+            MARGIN,
+            y,
+            &format!("Frame time mean {mean:.1}ms, worst {worst:.1}ms"),
+            TEXT,
+        );
+    }
+    y += LINE_HEIGHT;
+
+    let graph_height = ((client.bottom - y) as f32 * GRAPH_SHARE) as i32;
+    let graph = RECT {
+        left: MARGIN,
+        top: y + LINE_HEIGHT,
+        right: client.right - MARGIN,
+        bottom: y + graph_height,
+    };
+    draw_frames(hdc, &graph, &state.frames);
+    y = graph.bottom + MARGIN;
+
+    // Newest lines at the bottom, as many as fit
+    let fitting = ((client.bottom - MARGIN - y) / LINE_HEIGHT).max(0) as usize;
+    let skipped = state.logs.len().saturating_sub(fitting);
+    for line in state.logs.iter().skip(skipped) {
+        text(hdc, MARGIN, y, line, TEXT);
+        y += LINE_HEIGHT;
+    }
+}
+
+unsafe fn paint(hwnd: HWND) {
+    let mut paint = PAINTSTRUCT::default();
+    let hdc = BeginPaint(hwnd, &mut paint);
+    let mut client = RECT::default();
+    if GetClientRect(hwnd, &mut client).is_ok() {
+        let (width, height) = (client.right, client.bottom);
+        let buffer = CreateCompatibleDC(Some(hdc));
+        let bitmap = CreateCompatibleBitmap(hdc, width, height);
+        let previous_bitmap = SelectObject(buffer, bitmap.into());
+        let font = CreateFontW(
+            LINE_HEIGHT - 2,
+            0,
+            0,
+            0,
+            FW_NORMAL.0 as i32,
+            0,
+            0,
+            0,
+            ANSI_CHARSET,
+            OUT_TT_PRECIS,
+            CLIP_DEFAULT_PRECIS,
+            CLEARTYPE_QUALITY,
+            FIXED_PITCH.0 as u32,
+            w!("Consolas"),
+        );
+        let previous_font = SelectObject(buffer, font.into());
+
+        draw(buffer, &client);
+        let _ = BitBlt(hdc, 0, 0, width, height, Some(buffer), 0, 0, SRCCOPY);
+
+        SelectObject(buffer, previous_font);
+        SelectObject(buffer, previous_bitmap);
+        let _ = DeleteObject(font.into());
+        let _ = DeleteObject(bitmap.into());
+        let _ = DeleteDC(buffer);
+    }
+    let _ = EndPaint(hwnd, &paint);
+}
+
+unsafe extern "system" fn window_proc(
+    hwnd: HWND,
+    message: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    match message {
+        WM_PAINT => {
+            paint(hwnd);
+            LRESULT(0)
+        }
+        WM_TIMER | WM_SIZE => {
+            let _ = InvalidateRect(Some(hwnd), None, false);
+            LRESULT(0)
+        }
+        // Painting covers the whole window
+        WM_ERASEBKGND => LRESULT(1),
+        WM_DESTROY => {
+            PostQuitMessage(0);
+            LRESULT(0)
+        }
+        _ => DefWindowProcW(hwnd, message, wparam, lparam),
+    }
+}
+
+/// Opens the window and runs its message loop until it's closed.
+pub fn run() -> Result<(), Box<dyn Error>> {
+    unsafe {
+        let instance = GetModuleHandleW(None)?;
+        let class = WNDCLASSW {
+            lpfnWndProc: Some(window_proc),
+            hInstance: instance.into(),
+            hCursor: LoadCursorW(None, IDC_ARROW)?,
+            lpszClassName: w!("swkotor-mod-dashboard"),
+            ..Default::default()
+        };
+        if RegisterClassW(&class) == 0 {
+            return Err("Could not register the window class".into());
+        }
+
+        let hwnd = CreateWindowExW(
+            WINDOW_EX_STYLE::default(),
+            class.lpszClassName,
+            w!("swkotor-mod dashboard"),
+            WS_OVERLAPPEDWINDOW | WS_VISIBLE,
+            CW_USEDEFAULT,
+            CW_USEDEFAULT,
+            900,
+            600,
+            None,
+            None,
+            Some(instance.into()),
+            None,
+        )?;
+        SetTimer(Some(hwnd), 1, REPAINT_INTERVAL_MS, None);
+
+        let mut message = MSG::default();
+        while GetMessageW(&mut message, None, 0, 0).as_bool() {
+            let _ = TranslateMessage(&message);
+            DispatchMessageW(&message);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,11 @@
+#![no_main]
+
+#[path = "../../src/formats/gff.rs"]
+#[allow(dead_code)]
+mod gff;
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = gff::Gff::parse(data);
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+#[path = "../../src/formats/twoda.rs"]
+#[allow(dead_code)]
+mod twoda;
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = twoda::TwoDa::parse(data);
+});
@@ -0,0 +1,173 @@
+/// Starts the game with the mod loaded, without copying the mod over dinput8.dll.
+///
+/// swkotor.exe is created suspended, the mod DLL gets loaded into it with a remote LoadLibraryW
+/// call and only then the main thread is resumed. The game hasn't run a single instruction of its
+/// own by the time the mod initializes, the mod waits for the Steam DRM to unpack the executable
+/// the same way it does when loaded as dinput8.dll.
+///
+/// Built as `swkotor-inject` and as the `inject` subcommand of `molokki`. Either has to be built
+/// for the same target as the game, i686-pc-windows.
+use std::{
+    env,
+    error::Error,
+    ffi::c_void,
+    io, mem,
+    path::{self, Path, PathBuf},
+    process::ExitCode,
+};
+
+use windows::{
+    core::{s, w, HSTRING},
+    Win32::{
+        Foundation::{CloseHandle, HANDLE},
+        System::{
+            Diagnostics::Debug::WriteProcessMemory,
+            LibraryLoader::{GetModuleHandleW, GetProcAddress},
+            Memory::{
+                VirtualAllocEx, VirtualFreeEx, MEM_COMMIT, MEM_RELEASE, MEM_RESERVE, PAGE_READWRITE,
+            },
+            Threading::{
+                CreateProcessW, CreateRemoteThread, GetExitCodeThread, ResumeThread,
+                TerminateProcess, WaitForSingleObject, CREATE_SUSPENDED, INFINITE,
+                LPTHREAD_START_ROUTINE, PROCESS_INFORMATION, STARTUPINFOW,
+            },
+        },
+    },
+};
+
+const DEFAULT_GAME: &str = "swkotor.exe";
+const DEFAULT_MOD: &str = "swkotor_mod.dll";
+
+/// Writes `path` as a null terminated wide string into the game's memory.
+unsafe fn write_path(process: HANDLE, path: &Path) -> io::Result<*mut c_void> {
+    let wide: Vec<u16> = HSTRING::from(path).iter().copied().chain([0]).collect();
+    let size = wide.len() * mem::size_of::<u16>();
+
+    let remote = VirtualAllocEx(
+        process,
+        None,
+        size,
+        MEM_COMMIT | MEM_RESERVE,
+        PAGE_READWRITE,
+    );
+    if remote.is_null() {
+        return Err(io::Error::last_os_error());
+    }
+
+    WriteProcessMemory(process, remote, wide.as_ptr() as *const c_void, size, None)?;
+    Ok(remote)
+}
+
+/// Loads `dll` into the suspended game by running LoadLibraryW on a thread of its own.
+unsafe fn load_library(process: HANDLE, dll: &Path) -> Result<(), Box<dyn Error>> {
+    // kernel32 is mapped at the same address in every process of the same bitness, which is why
+    // this has to be built for the same target as the game
+    let kernel32 = GetModuleHandleW(w!("kernel32.dll"))?;
+    let load_library = GetProcAddress(kernel32, s!("LoadLibraryW")).ok_or("No LoadLibraryW")?;
+    let start: LPTHREAD_START_ROUTINE = Some(mem::transmute::<
+        unsafe extern "system" fn() -> isize,
+        unsafe extern "system" fn(*mut c_void) -> u32,
+    >(load_library));
+
+    let remote_path = write_path(process, dll)?;
+    let result = CreateRemoteThread(process, None, 0, start, Some(remote_path), 0, None)
+        .map_err(Box::<dyn Error>::from)
+        .and_then(|thread| {
+            WaitForSingleObject(thread, INFINITE);
+            // Exit code is the low half of the module handle, zero when loading failed
+            let mut module = 0;
+            let exit_code = GetExitCodeThread(thread, &mut module);
+            let _ = CloseHandle(thread);
+            exit_code?;
+
+            if module == 0 {
+                Err(format!("Game could not load {}", dll.display()).into())
+            } else {
+                Ok(())
+            }
+        });
+
+    let _ = VirtualFreeEx(process, remote_path, 0, MEM_RELEASE);
+    result
+}
+
+/// Starts `game` suspended, loads `dll` into it and lets it run.
+pub fn inject(game: &Path, dll: &Path) -> Result<(), Box<dyn Error>> {
+    // The game looks for its data relative to the working directory
+    let game = path::absolute(game)?;
+    let directory = game.parent().ok_or("Game executable has no directory")?;
+    // The DLL path is resolved by the game process, which has a working directory of its own
+    let dll = path::absolute(dll)?;
+
+    let startup_info = STARTUPINFOW {
+        cb: mem::size_of::<STARTUPINFOW>() as u32,
+        ..Default::default()
+    };
+    let mut process_info = PROCESS_INFORMATION::default();
+
+    unsafe {
+        CreateProcessW(
+            &HSTRING::from(game.as_path()),
+            None,
+            None,
+            None,
+            false,
+            CREATE_SUSPENDED,
+            None,
+            &HSTRING::from(directory),
+            &startup_info,
+            &mut process_info,
+        )?;
+
+        let result = load_library(process_info.hProcess, &dll);
+        match result {
+            Ok(()) => {
+                ResumeThread(process_info.hThread);
+            }
+            Err(_) => {
+                // Don't leave a suspended game lying around
+                let _ = TerminateProcess(process_info.hProcess, 1);
+            }
+        }
+
+        let _ = CloseHandle(process_info.hThread);
+        let _ = CloseHandle(process_info.hProcess);
+        result
+    }
+}
+
+fn default_mod_path() -> io::Result<PathBuf> {
+    let executable = env::current_exe()?;
+    Ok(executable.with_file_name(DEFAULT_MOD))
+}
+
+/// Starts the game with the mod, reporting how it went. The game defaults to `swkotor.exe` in the
+/// working directory and the mod to `swkotor_mod.dll` next to the running executable.
+pub fn run(game: Option<PathBuf>, dll: Option<PathBuf>) -> ExitCode {
+    let game = game.unwrap_or_else(|| PathBuf::from(DEFAULT_GAME));
+    let dll = match dll {
+        Some(dll) => dll,
+        None => match default_mod_path() {
+            Ok(dll) => dll,
+            Err(e) => {
+                eprintln!("Could not find the mod DLL: {e}");
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+
+    match inject(&game, &dll) {
+        Ok(()) => {
+            println!("Started {} with {}", game.display(), dll.display());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!(
+                "Could not start {} with {}: {e}",
+                game.display(),
+                dll.display()
+            );
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,9 @@
+/// Usage: `swkotor-inject [game executable] [mod dll]`, see the library for what it does.
+use std::{env, path::PathBuf, process::ExitCode};
+
+fn main() -> ExitCode {
+    let mut args = env::args_os().skip(1).map(PathBuf::from);
+    let game = args.next();
+    let dll = args.next();
+    swkotor_inject::run(game, dll)
+}
@@ -0,0 +1,218 @@
+/// Loader for hot reloading swkotor-mod.
+///
+/// The game loads this as dinput8.dll. DirectInput8Create is forwarded to the system dinput8 and
+/// the mod itself is loaded from `swkotor_mod.dll` in the game directory. Not from there directly
+/// though, Windows locks a loaded DLL and the mod couldn't be rebuilt while the game runs. Every
+/// load copies the mod to the temp directory first and loads the copy.
+///
+/// The mod gets a callback for requesting a reload, e.g. from its `reload` console command. On
+/// reload the mod removes its hooks and stops its threads, the copy is freed and a fresh copy of
+/// `swkotor_mod.dll` is loaded. Keep this tiny, the loader itself can't be reloaded.
+use std::{
+    env,
+    ffi::c_void,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    process,
+    sync::{
+        atomic::{AtomicBool, AtomicU32, Ordering},
+        Mutex, OnceLock,
+    },
+    thread,
+};
+
+use windows::{
+    core::{s, GUID, HRESULT, HSTRING},
+    Win32::{
+        Foundation::{FreeLibrary, E_FAIL, HINSTANCE, HMODULE},
+        System::{
+            LibraryLoader::{GetProcAddress, LoadLibraryW},
+            SystemInformation::GetSystemDirectoryW,
+            SystemServices::DLL_PROCESS_ATTACH,
+        },
+    },
+};
+
+/// Relative to the game directory
+const PAYLOAD_PATH: &str = "swkotor_mod.dll";
+const LOG_PATH: &str = "swkotor-mod-loader.log";
+
+// Results of `swkotor_mod_unload`, see `system::reload` in the mod
+const UNLOAD_FAILED: u32 = 0;
+const UNLOADED: u32 = 1;
+
+type ReloadFn = unsafe extern "C" fn();
+type SetReloadCallbackFn = unsafe extern "C" fn(reload: ReloadFn);
+type UnloadFn = unsafe extern "C" fn() -> u32;
+type DirectInput8CreateFn = unsafe extern "system" fn(
+    HINSTANCE,
+    u32,
+    *const GUID,
+    *mut *mut c_void,
+    *mut c_void,
+) -> HRESULT;
+
+/// A loaded copy of the mod.
+struct Payload {
+    module: HMODULE,
+    /// The copy in the temp directory
+    path: PathBuf,
+}
+
+// Module handle is only used behind the mutex
+unsafe impl Send for Payload {}
+
+static PAYLOAD: Mutex<Option<Payload>> = Mutex::new(None);
+
+/// Numbers the copies, a copy that couldn't be freed is still locked
+static GENERATION: AtomicU32 = AtomicU32::new(0);
+
+static RELOADING: AtomicBool = AtomicBool::new(false);
+
+static SYSTEM_DIRECT_INPUT8_CREATE: OnceLock<Option<DirectInput8CreateFn>> = OnceLock::new();
+
+/// The mod's logging is gone whenever it isn't loaded, the loader writes a log of its own.
+fn log(message: &str) {
+    let file = OpenOptions::new().create(true).append(true).open(LOG_PATH);
+    if let Ok(mut file) = file {
+        let _ = writeln!(file, "{message}");
+    }
+}
+
+/// Copies the mod to the temp directory and loads the copy.
+fn load_payload() -> io::Result<Payload> {
+    let generation = GENERATION.fetch_add(1, Ordering::Relaxed);
+    let path = env::temp_dir().join(format!("swkotor_mod.{}.{generation}.dll", process::id()));
+    fs::copy(PAYLOAD_PATH, &path)?;
+
+    let module = unsafe { LoadLibraryW(&HSTRING::from(path.as_path()))? };
+    match unsafe { GetProcAddress(module, s!("swkotor_mod_set_reload_callback")) } {
+        Some(set_reload_callback) => unsafe {
+            let set_reload_callback: SetReloadCallbackFn = std::mem::transmute(set_reload_callback);
+            set_reload_callback(request_reload);
+        },
+        None => log("Mod has no reload callback, it can't ask for reloads"),
+    }
+
+    log(&format!("Loaded {}", path.display()));
+    Ok(Payload { module, path })
+}
+
+/// Asks the mod to unload and frees it if it could stop everything. Gives the payload back if
+/// the mod is still hooked in.
+fn unload_payload(payload: Payload) -> Result<(), Payload> {
+    let Some(unload) = (unsafe { GetProcAddress(payload.module, s!("swkotor_mod_unload")) }) else {
+        log("Mod can't be unloaded, it has no swkotor_mod_unload");
+        return Err(payload);
+    };
+    let unload: UnloadFn = unsafe { std::mem::transmute(unload) };
+
+    match unsafe { unload() } {
+        UNLOAD_FAILED => {
+            log("Mod could not remove its hooks, keeping it loaded");
+            Err(payload)
+        }
+        UNLOADED => {
+            if let Err(e) = unsafe { FreeLibrary(payload.module) } {
+                log(&format!("Could not free {}: {e}", payload.path.display()));
+                return Ok(());
+            }
+            let _ = fs::remove_file(&payload.path);
+            log(&format!("Unloaded {}", payload.path.display()));
+            Ok(())
+        }
+        // Some of the mod's code might still run, it has to stay
+        _ => {
+            log(&format!(
+                "Mod threads are still running, leaving {} loaded",
+                payload.path.display()
+            ));
+            Ok(())
+        }
+    }
+}
+
+fn reload() {
+    let mut payload = PAYLOAD.lock().unwrap();
+    if let Some(previous) = payload.take() {
+        if let Err(previous) = unload_payload(previous) {
+            *payload = Some(previous);
+            return;
+        }
+    }
+
+    match load_payload() {
+        Ok(loaded) => *payload = Some(loaded),
+        Err(e) => log(&format!("Could not load {PAYLOAD_PATH}: {e}")),
+    }
+}
+
+/// Handed to the mod. Reloads on a thread of its own, the mod can't unload itself from its own
+/// threads.
+unsafe extern "C" fn request_reload() {
+    if RELOADING.swap(true, Ordering::Relaxed) {
+        return;
+    }
+
+    let spawned = thread::Builder::new()
+        .name("loader-reload".to_string())
+        .spawn(|| {
+            reload();
+            RELOADING.store(false, Ordering::Relaxed);
+        });
+    if let Err(e) = spawned {
+        log(&format!("Could not start reloading: {e}"));
+        RELOADING.store(false, Ordering::Relaxed);
+    }
+}
+
+fn load_system_direct_input8_create() -> io::Result<DirectInput8CreateFn> {
+    let mut directory = [0u16; 260];
+    let length = unsafe { GetSystemDirectoryW(Some(&mut directory)) } as usize;
+    if length == 0 || length > directory.len() {
+        return Err(io::Error::last_os_error());
+    }
+    let path = PathBuf::from(String::from_utf16_lossy(&directory[..length])).join("dinput8.dll");
+
+    unsafe {
+        let module = LoadLibraryW(&HSTRING::from(path.as_path()))?;
+        let create = GetProcAddress(module, s!("DirectInput8Create"))
+            .ok_or_else(|| io::Error::other("System dinput8.dll has no DirectInput8Create"))?;
+        Ok(std::mem::transmute::<
+            unsafe extern "system" fn() -> isize,
+            DirectInput8CreateFn,
+        >(create))
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn DirectInput8Create(
+    hinst: HINSTANCE,
+    dw_version: u32,
+    riidltf: *const GUID,
+    ppv_out: *mut *mut c_void,
+    punk_outer: *mut c_void,
+) -> HRESULT {
+    let create = SYSTEM_DIRECT_INPUT8_CREATE.get_or_init(|| {
+        load_system_direct_input8_create()
+            .inspect_err(|e| log(&format!("Could not load the system dinput8.dll: {e}")))
+            .ok()
+    });
+
+    match create {
+        Some(create) => unsafe { create(hinst, dw_version, riidltf, ppv_out, punk_outer) },
+        None => E_FAIL,
+    }
+}
+
+#[no_mangle]
+#[allow(non_snake_case)]
+extern "system" fn DllMain(_dll_module: HINSTANCE, call_reason: u32, _: *mut ()) -> bool {
+    if call_reason == DLL_PROCESS_ATTACH {
+        // Loading libraries while the loader lock is held is asking for deadlocks, the thread
+        // only gets going once DllMain has returned
+        unsafe { request_reload() };
+    }
+    true
+}
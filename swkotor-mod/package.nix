@@ -41,7 +41,14 @@ let
   craneLib = (crane.mkLib pkgs).overrideToolchain toolchain;
 
   swkotor-mod = craneLib.buildPackage rec {
-    src = craneLib.cleanCargoSource ./.;
+    # molokki-common is a path dependency, it has to be in the source too
+    src = craneLib.cleanCargoSource ./..;
+    cargoLock = ./Cargo.lock;
+    cargoToml = ./Cargo.toml;
+    postUnpack = ''
+      cd $sourceRoot/swkotor-mod
+      sourceRoot="."
+    '';
 
     strictDeps = true;
     doCheck = false;
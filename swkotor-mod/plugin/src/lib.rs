@@ -0,0 +1,127 @@
+/// Interface for swkotor-mod plugins.
+///
+/// A plugin is a DLL of its own, put in the `plugins` directory of the game. swkotor-mod loads
+/// every DLL there on startup and calls into the plugin every frame, on game events and for
+/// console commands it doesn't know itself. Plugins get to draw on the overlay without forking
+/// the mod:
+///
+/// ```ignore
+/// use swkotor_mod_plugin::{export_plugin, Canvas, Color, Event, Plugin};
+///
+/// #[derive(Default)]
+/// struct Hello {
+///     module: String,
+/// }
+///
+/// impl Plugin for Hello {
+///     fn name(&self) -> &str {
+///         "hello"
+///     }
+///
+///     fn on_frame(&mut self, canvas: &mut dyn Canvas) {
+///         canvas.text(10.0, 10.0, &format!("Hello from {}", self.module), Color::WHITE);
+///     }
+///
+///     fn on_event(&mut self, event: &Event) {
+///         if let Event::ModuleEntered(module) = event {
+///             self.module = module.to_string();
+///         }
+///     }
+/// }
+///
+/// export_plugin!(Hello::default);
+/// ```
+///
+/// The plugin is built as a `cdylib` for `i686-pc-windows-gnu` like the mod. Trait objects are
+/// passed between the DLLs as is, Rust has no stable ABI, so plugins must be built with the same
+/// compiler and the same version of this crate as the mod. `API_VERSION` catches the latter.
+use std::time::Duration;
+
+/// Bumped whenever anything in this crate changes in a way that breaks built plugins
+pub const API_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl Color {
+    pub const WHITE: Color = Color::rgba(1.0, 1.0, 1.0, 1.0);
+    /// Translucent black the mod's own widgets are drawn on
+    pub const BACKGROUND: Color = Color::rgba(0.0, 0.0, 0.0, 0.6);
+
+    pub const fn rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Color { r, g, b, a }
+    }
+}
+
+/// Overlay drawing in screen coordinates, origin at the top left.
+pub trait Canvas {
+    /// Screen width in pixels.
+    fn width(&self) -> f32;
+    /// Screen height in pixels.
+    fn height(&self) -> f32;
+    /// Width and height of a character of the monospaced overlay font.
+    fn font_size(&self) -> (f32, f32);
+    fn fill_rect(&mut self, x: f32, y: f32, width: f32, height: f32, color: Color);
+    /// Draws `text` with its top left corner at `(x, y)`.
+    fn text(&mut self, x: f32, y: f32, text: &str, color: Color);
+}
+
+/// Game events passed to `Plugin::on_event`.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Event<'a> {
+    /// Party arrived in a module, e.g. `end_m01aa`
+    ModuleEntered(&'a str),
+    /// Module is being unloaded, e.g. for a transition or loading a save
+    ModuleExited(&'a str),
+    /// Load screen went away after being up for the duration
+    LoadFinished(Duration),
+}
+
+/// A plugin. Every method runs with the plugin locked, a call never overlaps another one.
+pub trait Plugin: Send {
+    /// Short name used in the log and by `status`.
+    fn name(&self) -> &str;
+
+    /// Called every frame with the overlay. Runs on the game's main thread, keep it quick.
+    fn on_frame(&mut self, _canvas: &mut dyn Canvas) {}
+
+    /// Called on game events, on the main thread.
+    fn on_event(&mut self, _event: &Event) {}
+
+    /// Called for console commands the mod doesn't know. Returns `None` for commands that
+    /// aren't the plugin's, otherwise the output or an error message. Runs on the console thread.
+    fn on_command(&mut self, _command: &str, _args: &[&str]) -> Option<Result<String, String>> {
+        None
+    }
+}
+
+/// What a plugin DLL exports, see `export_plugin!`.
+#[repr(C)]
+pub struct PluginDeclaration {
+    pub api_version: u32,
+    pub create: fn() -> Box<dyn Plugin>,
+}
+
+/// Exports the plugin created by `$create`, a function or closure returning the plugin.
+#[macro_export]
+macro_rules! export_plugin {
+    ($create:expr) => {
+        #[no_mangle]
+        #[allow(non_upper_case_globals)]
+        pub static swkotor_mod_plugin: $crate::PluginDeclaration = $crate::PluginDeclaration {
+            api_version: $crate::API_VERSION,
+            create: {
+                fn create() -> Box<dyn $crate::Plugin> {
+                    Box::new(($create)())
+                }
+                create
+            },
+        };
+    };
+}
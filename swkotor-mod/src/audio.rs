@@ -0,0 +1,176 @@
+/// Separate volumes for music, voice over and effects on top of the game's own.
+///
+/// The game plays everything through Miles: music and voice over are streamed from files, effects
+/// are samples. The Miles imports are hooked, streams get their category from the directory they
+/// are opened from and every volume the game sets is scaled by its category's multiplier. The
+/// game's volumes are kept so changing a multiplier applies right away.
+use std::{
+    collections::HashMap,
+    fmt, io,
+    sync::{LazyLock, Mutex},
+};
+
+use log::{error, info};
+
+use crate::config::config;
+use crate::util::iat::miles::{self, HSample, HStream};
+
+/// Miles volumes go from silent to full
+const MAX_VOLUME: i32 = 127;
+
+/// Highest multiplier, past this everything is clipped at full volume anyway
+pub const MAX_MULTIPLIER: f32 = 4.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    Music,
+    Voice,
+    Effects,
+}
+
+impl Category {
+    pub const ALL: [Category; 3] = [Category::Music, Category::Voice, Category::Effects];
+
+    pub fn parse(name: &str) -> Option<Category> {
+        Category::ALL
+            .into_iter()
+            .find(|category| category.to_string() == name)
+    }
+
+    /// Category of a stream opened from `filename`.
+    fn of_stream(filename: &str) -> Category {
+        let filename = filename.to_ascii_lowercase();
+        if filename.contains("streammusic") {
+            Category::Music
+        } else if filename.contains("streamwaves") || filename.contains("streamvoice") {
+            Category::Voice
+        } else {
+            Category::Effects
+        }
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Category::Music => write!(f, "music"),
+            Category::Voice => write!(f, "voice"),
+            Category::Effects => write!(f, "effects"),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Mixer {
+    music: f32,
+    voice: f32,
+    effects: f32,
+    /// Addresses of open streams, their category and the volume the game last set
+    streams: HashMap<usize, (Category, i32)>,
+    /// Addresses of samples and the volume the game last set
+    samples: HashMap<usize, i32>,
+}
+
+impl Mixer {
+    fn multiplier(&self, category: Category) -> f32 {
+        match category {
+            Category::Music => self.music,
+            Category::Voice => self.voice,
+            Category::Effects => self.effects,
+        }
+    }
+
+    fn scale(&self, category: Category, volume: i32) -> i32 {
+        ((volume as f32 * self.multiplier(category)).round() as i32).clamp(0, MAX_VOLUME)
+    }
+}
+
+static MIXER: LazyLock<Mutex<Mixer>> = LazyLock::new(|| {
+    let volumes = config().audio.clone();
+    Mutex::new(Mixer {
+        music: volumes.music.clamp(0.0, MAX_MULTIPLIER),
+        voice: volumes.voice.clamp(0.0, MAX_MULTIPLIER),
+        effects: volumes.effects.clamp(0.0, MAX_MULTIPLIER),
+        ..Mixer::default()
+    })
+});
+
+/// Tracks a stream the game opened, at full volume until the game sets one.
+pub(crate) fn stream_opened(stream: HStream, filename: &str) {
+    let category = Category::of_stream(filename);
+    let mut mixer = MIXER.lock().unwrap();
+    mixer
+        .streams
+        .insert(stream as usize, (category, MAX_VOLUME));
+
+    if mixer.multiplier(category) != 1.0 {
+        miles::set_stream_volume(stream, mixer.scale(category, MAX_VOLUME));
+    }
+}
+
+pub(crate) fn stream_closed(stream: HStream) {
+    MIXER.lock().unwrap().streams.remove(&(stream as usize));
+}
+
+/// Scales the volume the game sets on a stream.
+pub(crate) fn stream_volume(stream: HStream, volume: i32) -> i32 {
+    let mut mixer = MIXER.lock().unwrap();
+    // Streams opened before the hooks went in count as effects
+    let category = mixer
+        .streams
+        .get(&(stream as usize))
+        .map_or(Category::Effects, |&(category, _)| category);
+    mixer.streams.insert(stream as usize, (category, volume));
+    mixer.scale(category, volume)
+}
+
+/// Scales the volume the game sets on a sample.
+pub(crate) fn sample_volume(sample: HSample, volume: i32) -> i32 {
+    let mut mixer = MIXER.lock().unwrap();
+    mixer.samples.insert(sample as usize, volume);
+    mixer.scale(Category::Effects, volume)
+}
+
+pub fn volume(category: Category) -> f32 {
+    MIXER.lock().unwrap().multiplier(category)
+}
+
+/// Sets the multiplier of the category and applies it to everything playing.
+///
+/// Must be called on the main thread.
+pub fn set_volume(category: Category, multiplier: f32) -> io::Result<()> {
+    if !(0.0..=MAX_MULTIPLIER).contains(&multiplier) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Volume must be between 0 and {MAX_MULTIPLIER}"),
+        ));
+    }
+
+    let mut mixer = MIXER.lock().unwrap();
+    match category {
+        Category::Music => mixer.music = multiplier,
+        Category::Voice => mixer.voice = multiplier,
+        Category::Effects => mixer.effects = multiplier,
+    }
+
+    for (&stream, &(stream_category, volume)) in &mixer.streams {
+        if stream_category == category {
+            miles::set_stream_volume(stream as HStream, mixer.scale(category, volume));
+        }
+    }
+    if category == Category::Effects {
+        for (&sample, &volume) in &mixer.samples {
+            miles::set_sample_volume(sample as HSample, mixer.scale(category, volume));
+        }
+    }
+
+    info!("Set {category} volume to {multiplier}");
+    Ok(())
+}
+
+/// Starts scaling the game's volumes. Must be called on the main thread.
+pub fn init() {
+    if let Err(e) = miles::install_miles_hooks() {
+        error!("Could not hook Miles, volumes can't be adjusted. {e}");
+    }
+}
@@ -0,0 +1,434 @@
+/// Mod configuration, read from `swkotor-mod.toml` in the game directory.
+///
+/// Missing file is created with the defaults so there's something to edit. Missing keys fall back
+/// to defaults as well, old config files keep working when new options are added.
+use std::{
+    io,
+    path::Path,
+    sync::{LazyLock, RwLock, RwLockReadGuard},
+};
+
+use molokki_common::config::{load_or_default, save};
+use serde::{Deserialize, Serialize};
+
+pub const CONFIG_PATH: &str = "swkotor-mod.toml";
+
+/// Position on screen in pixels. Negative values are measured from the right or bottom edge.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ScreenPosition {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TimerConfig {
+    pub enabled: bool,
+    pub position: ScreenPosition,
+    /// Split automatically on entering a module
+    pub split_on_module: bool,
+}
+
+impl Default for TimerConfig {
+    fn default() -> Self {
+        TimerConfig {
+            enabled: true,
+            position: ScreenPosition { x: -10, y: 10 },
+            split_on_module: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MemoryConfig {
+    pub enabled: bool,
+    pub position: ScreenPosition,
+}
+
+impl Default for MemoryConfig {
+    fn default() -> Self {
+        MemoryConfig {
+            enabled: false,
+            position: ScreenPosition { x: 10, y: 10 },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TransformConfig {
+    pub enabled: bool,
+    pub position: ScreenPosition,
+}
+
+impl Default for TransformConfig {
+    fn default() -> Self {
+        TransformConfig {
+            enabled: false,
+            position: ScreenPosition { x: 10, y: -10 },
+        }
+    }
+}
+
+/// Things the minimap can show. Everything but the party comes from the area's instance file, so
+/// creatures are shown where they spawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MinimapIcon {
+    Party,
+    Creatures,
+    Doors,
+    Placeables,
+    Waypoints,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MinimapConfig {
+    pub enabled: bool,
+    pub position: ScreenPosition,
+    /// Width and height in pixels
+    pub size: u32,
+    /// Pixels per meter
+    pub zoom: f32,
+    pub icons: Vec<MinimapIcon>,
+}
+
+impl Default for MinimapConfig {
+    fn default() -> Self {
+        MinimapConfig {
+            enabled: false,
+            position: ScreenPosition { x: -10, y: -10 },
+            size: 200,
+            zoom: 4.0,
+            icons: vec![
+                MinimapIcon::Party,
+                MinimapIcon::Creatures,
+                MinimapIcon::Doors,
+                MinimapIcon::Placeables,
+                MinimapIcon::Waypoints,
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScreenshotConfig {
+    /// Relative paths are relative to the game directory
+    pub directory: String,
+    pub hide_overlay: bool,
+    /// Take a screenshot after every area transition, see `telemetry::timeline`
+    pub on_transition: bool,
+}
+
+impl Default for ScreenshotConfig {
+    fn default() -> Self {
+        ScreenshotConfig {
+            directory: "screenshots".to_string(),
+            hide_overlay: true,
+            on_transition: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OverlayConfig {
+    /// Size of the overlay relative to 1080p, picked from the resolution and DPI when left out
+    pub scale: Option<f32>,
+    /// Color preset, see `overlay::theme`
+    pub theme: String,
+    /// Multiplies the opacity of everything the overlay draws
+    pub opacity: f32,
+    /// Monospaced font face, the theme's when left out
+    pub font: Option<String>,
+}
+
+impl Default for OverlayConfig {
+    fn default() -> Self {
+        OverlayConfig {
+            scale: None,
+            theme: "default".to_string(),
+            opacity: 1.0,
+            font: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisplayConfig {
+    /// Vertical field of view in degrees, the game decides when left out
+    pub field_of_view: Option<f32>,
+    /// Keep the GUI at 4:3 instead of stretching it on wide screens
+    pub gui_aspect_correction: bool,
+    /// Step the world at most at 60fps, see `engine::clock`
+    pub high_frame_rate_fix: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RoutePlanConfig {
+    /// Route plan loaded on startup, relative paths are relative to the game directory
+    pub file: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WindowConfig {
+    /// Go borderless on startup
+    pub borderless: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RngConfig {
+    /// Seed forced on every seeding of the engine's RNG, the engine seeds from the clock when left
+    /// out
+    pub seed: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StringsConfig {
+    /// TOML file of dialog.tlk replacements by StrRef, relative paths are relative to the game
+    /// directory
+    pub file: String,
+}
+
+impl Default for StringsConfig {
+    fn default() -> Self {
+        StringsConfig {
+            file: "strings.toml".to_string(),
+        }
+    }
+}
+
+/// Multipliers on the volumes the game sets, 1.0 leaves the game's volume as is.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AudioConfig {
+    pub music: f32,
+    pub voice: f32,
+    pub effects: f32,
+}
+
+impl Default for AudioConfig {
+    fn default() -> Self {
+        AudioConfig {
+            music: 1.0,
+            voice: 1.0,
+            effects: 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TexturesConfig {
+    /// Write every texture the game loads to `dump_directory` as TGA
+    pub dump: bool,
+    pub dump_directory: String,
+    /// Load TGA files in `replace_directory` in place of the textures of the same name
+    pub replace: bool,
+    pub replace_directory: String,
+}
+
+impl Default for TexturesConfig {
+    fn default() -> Self {
+        TexturesConfig {
+            dump: false,
+            dump_directory: "textures/dump".to_string(),
+            replace: true,
+            replace_directory: "textures/replace".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GamepadConfig {
+    /// Open the toggle menu with Back and Start on a gamepad
+    pub enabled: bool,
+}
+
+impl Default for GamepadConfig {
+    fn default() -> Self {
+        GamepadConfig { enabled: true }
+    }
+}
+
+/// WebSocket mirror of the IPC protocol, off by default as it opens a TCP port.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WebSocketConfig {
+    pub enabled: bool,
+    /// Keep this on localhost unless you want anyone on the network flipping cheats
+    pub address: String,
+    pub port: u16,
+}
+
+impl Default for WebSocketConfig {
+    fn default() -> Self {
+        WebSocketConfig {
+            enabled: false,
+            address: "127.0.0.1".to_string(),
+            port: 8787,
+        }
+    }
+}
+
+/// Prometheus metrics endpoint, off by default as it opens a TCP port.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MetricsConfig {
+    pub enabled: bool,
+    pub address: String,
+    pub port: u16,
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        MetricsConfig {
+            enabled: false,
+            address: "127.0.0.1".to_string(),
+            port: 9187,
+        }
+    }
+}
+
+/// Copies of every save the game writes, see `savegame`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SaveBackupConfig {
+    pub enabled: bool,
+    pub directory: String,
+    /// Copies kept of each save, the oldest go first
+    pub keep: usize,
+}
+
+impl Default for SaveBackupConfig {
+    fn default() -> Self {
+        SaveBackupConfig {
+            enabled: true,
+            directory: "saves_backup".to_string(),
+            keep: 5,
+        }
+    }
+}
+
+/// Loads a module on startup, takes a screenshot and exits, see `liveqa::smoke`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SmokeTestConfig {
+    pub enabled: bool,
+    pub module: String,
+    /// Frames drawn in the module before the screenshot
+    pub frames: u64,
+}
+
+impl Default for SmokeTestConfig {
+    fn default() -> Self {
+        SmokeTestConfig {
+            enabled: false,
+            module: "end_m01aa".to_string(),
+            frames: 300,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuickStartConfig {
+    /// Module a new game is started in on startup, the main menu as usual when left out
+    pub module: Option<String>,
+    /// Premade character's class, `soldier`, `scout` or `scoundrel`
+    pub class: String,
+    /// `male` or `female`
+    pub gender: String,
+}
+
+impl Default for QuickStartConfig {
+    fn default() -> Self {
+        QuickStartConfig {
+            module: None,
+            class: "soldier".to_string(),
+            gender: "male".to_string(),
+        }
+    }
+}
+
+/// Performance alert thresholds, see `telemetry::alerts`. Left out thresholds don't alert.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlertsConfig {
+    /// Frame time in milliseconds counted as slow
+    pub frame_time_ms: Option<f32>,
+    /// Seconds frames have to stay slow before alerting
+    pub frame_time_seconds: f32,
+    /// Memory committed by the process in megabytes
+    pub memory_mb: Option<u32>,
+}
+
+impl Default for AlertsConfig {
+    fn default() -> Self {
+        AlertsConfig {
+            frame_time_ms: None,
+            frame_time_seconds: 2.0,
+            memory_mb: None,
+        }
+    }
+}
+
+/// A key bound to console commands, see `liveqa::hotkeys`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MacroConfig {
+    /// e.g. `Ctrl+F7`
+    pub key: String,
+    pub commands: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub timer: TimerConfig,
+    pub minimap: MinimapConfig,
+    pub memory: MemoryConfig,
+    pub transform: TransformConfig,
+    pub route_plan: RoutePlanConfig,
+    pub screenshot: ScreenshotConfig,
+    pub window: WindowConfig,
+    pub gamepad: GamepadConfig,
+    pub display: DisplayConfig,
+    pub overlay: OverlayConfig,
+    pub rng: RngConfig,
+    pub websocket: WebSocketConfig,
+    pub metrics: MetricsConfig,
+    pub textures: TexturesConfig,
+    pub audio: AudioConfig,
+    pub strings: StringsConfig,
+    pub save_backup: SaveBackupConfig,
+    pub smoke_test: SmokeTestConfig,
+    pub quick_start: QuickStartConfig,
+    pub alerts: AlertsConfig,
+    pub macros: Vec<MacroConfig>,
+}
+
+// Broken config falls back to defaults, it should not keep the game from starting
+static CONFIG: LazyLock<RwLock<Config>> =
+    LazyLock::new(|| RwLock::new(load_or_default(Path::new(CONFIG_PATH))));
+
+/// Returns the current config. Don't hold on to it, writers are blocked while it's alive.
+pub fn config() -> RwLockReadGuard<'static, Config> {
+    CONFIG.read().unwrap()
+}
+
+/// Changes the current config with `change` and writes it to the config file. The change is kept
+/// even if writing fails.
+pub fn update_config(change: impl FnOnce(&mut Config)) -> io::Result<()> {
+    let mut config = CONFIG.write().unwrap();
+    change(&mut config);
+    save(Path::new(CONFIG_PATH), &*config)
+}
@@ -0,0 +1,78 @@
+/// The engine calls QA logic needs, behind a trait so the logic can be tested without the game.
+///
+/// `Game` calls into the running engine and, like the functions it wraps, must be used on the main
+/// thread. Tests use `mock::MockEngine` instead, see `engine::mock`.
+use std::io;
+
+use super::{globals, items, module, objects, objects::ObjectKind, party, party::MemberStats};
+
+pub trait EngineApi {
+    /// Resref of the loaded module, `None` e.g. in the main menu.
+    fn current_module(&self) -> io::Result<Option<String>>;
+    fn warp(&self, module: &str) -> io::Result<()>;
+    fn party_stats(&self) -> io::Result<Vec<MemberStats>>;
+    fn leader_position(&self) -> io::Result<[f32; 3]>;
+    fn give_item(&self, template: &str, count: u16) -> io::Result<()>;
+    fn global_boolean(&self, name: &str) -> io::Result<bool>;
+    fn global_number(&self, name: &str) -> io::Result<u8>;
+    fn set_global_boolean(&self, name: &str, value: bool) -> io::Result<()>;
+    fn set_global_number(&self, name: &str, value: u8) -> io::Result<()>;
+    /// Returns the id of the created object.
+    fn spawn_object(&self, kind: ObjectKind, template: &str, position: [f32; 3])
+        -> io::Result<u32>;
+    fn play_movie(&self, movie: &str) -> io::Result<()>;
+}
+
+/// The running game.
+pub struct Game;
+
+impl EngineApi for Game {
+    fn current_module(&self) -> io::Result<Option<String>> {
+        module::current_module()
+    }
+
+    fn warp(&self, module: &str) -> io::Result<()> {
+        module::warp(module)
+    }
+
+    fn party_stats(&self) -> io::Result<Vec<MemberStats>> {
+        party::party_stats()
+    }
+
+    fn leader_position(&self) -> io::Result<[f32; 3]> {
+        party::leader_position()
+    }
+
+    fn give_item(&self, template: &str, count: u16) -> io::Result<()> {
+        items::give_item(template, count)
+    }
+
+    fn global_boolean(&self, name: &str) -> io::Result<bool> {
+        globals::global_boolean(name)
+    }
+
+    fn global_number(&self, name: &str) -> io::Result<u8> {
+        globals::global_number(name)
+    }
+
+    fn set_global_boolean(&self, name: &str, value: bool) -> io::Result<()> {
+        globals::set_global_boolean(name, value)
+    }
+
+    fn set_global_number(&self, name: &str, value: u8) -> io::Result<()> {
+        globals::set_global_number(name, value)
+    }
+
+    fn spawn_object(
+        &self,
+        kind: ObjectKind,
+        template: &str,
+        position: [f32; 3],
+    ) -> io::Result<u32> {
+        objects::spawn_object(kind, template, position)
+    }
+
+    fn play_movie(&self, movie: &str) -> io::Result<()> {
+        objects::play_movie(movie)
+    }
+}
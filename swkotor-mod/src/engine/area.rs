@@ -0,0 +1,389 @@
+/// Live objects of the loaded area.
+///
+/// The area's instance file only says where things started. This reads what the engine has right
+/// now: spawned objects included, destroyed ones gone, doors as they were left.
+use std::{
+    collections::HashMap,
+    ffi::c_void,
+    fs, io,
+    mem::transmute,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::mem::{Memory, Process};
+
+use super::{
+    module::server_exo_app,
+    symbols,
+    types::{CExoString, CResRef},
+};
+
+/// Offset of the area's object id list in the area object, the engine's dynamic array: a pointer to
+/// the ids followed by their count. Verify against the disassembly when locating
+/// `get_current_area`.
+const AREA_OBJECTS_OFFSET: usize = 0x18c;
+
+/// More objects than this in an area means the list isn't one
+const MAX_AREA_OBJECTS: usize = 0x10000;
+
+/// Local booleans every object has, the indexes scripts can use
+const LOCAL_BOOLEAN_COUNT: i32 = 64;
+
+// Object type constants of the scripting language
+const OBJECT_TYPE_CREATURE: i32 = 1;
+const OBJECT_TYPE_DOOR: i32 = 8;
+const OBJECT_TYPE_PLACEABLE: i32 = 64;
+
+type GetCurrentAreaFn = unsafe extern "thiscall" fn(server_app: *mut c_void) -> *mut c_void;
+type GetGameObjectFn = unsafe extern "thiscall" fn(server_app: *mut c_void, id: u32) -> *mut c_void;
+type GetObjectTypeFn = unsafe extern "thiscall" fn(object: *mut c_void) -> i32;
+type GetObjectTagFn = unsafe extern "thiscall" fn(object: *mut c_void) -> *const CExoString;
+type GetObjectTemplateFn = unsafe extern "thiscall" fn(object: *mut c_void) -> *const CResRef;
+type GetObjectVectorFn = unsafe extern "thiscall" fn(object: *mut c_void) -> *const [f32; 3];
+type GetFlagFn = unsafe extern "thiscall" fn(object: *mut c_void) -> i32;
+type GetPointsFn = unsafe extern "thiscall" fn(creature: *mut c_void) -> i16;
+type GetLocalBooleanFn = unsafe extern "thiscall" fn(object: *mut c_void, index: i32) -> i32;
+type SetLocalBooleanFn = unsafe extern "thiscall" fn(object: *mut c_void, index: i32, value: i32);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AreaObject {
+    pub id: u32,
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub tag: String,
+    /// Blueprint the object was created from, empty for ones the engine made up
+    pub template: String,
+    pub position: [f32; 3],
+    /// Unit vector of the direction the object faces
+    pub orientation: [f32; 3],
+    pub plot: bool,
+    /// Creatures only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hit_points: Option<i16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_hit_points: Option<i16>,
+    /// Doors and placeables only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locked: Option<bool>,
+}
+
+/// Object type constants and the names `AreaObject::kind` uses for them
+pub const OBJECT_TYPES: &[(i32, &str)] = &[
+    (OBJECT_TYPE_CREATURE, "creature"),
+    (2, "item"),
+    (4, "trigger"),
+    (OBJECT_TYPE_DOOR, "door"),
+    (16, "area_of_effect"),
+    (32, "waypoint"),
+    (OBJECT_TYPE_PLACEABLE, "placeable"),
+    (128, "store"),
+    (256, "encounter"),
+    (512, "sound"),
+];
+
+fn type_name(object_type: i32) -> &'static str {
+    OBJECT_TYPES
+        .iter()
+        .find(|(value, _)| *value == object_type)
+        .map_or("unknown", |(_, name)| name)
+}
+
+/// Engine functions reading an object, resolved once per read of the area
+struct Readers {
+    object_type: GetObjectTypeFn,
+    tag: GetObjectTagFn,
+    template: GetObjectTemplateFn,
+    position: GetObjectVectorFn,
+    orientation: GetObjectVectorFn,
+    plot: GetFlagFn,
+    is_open: GetFlagFn,
+    is_locked: GetFlagFn,
+    hit_points: GetPointsFn,
+    max_hit_points: GetPointsFn,
+}
+
+impl Readers {
+    fn resolve() -> io::Result<Readers> {
+        unsafe {
+            Ok(Readers {
+                object_type: transmute::<usize, GetObjectTypeFn>(
+                    symbols::GET_OBJECT_TYPE.resolve()?,
+                ),
+                tag: transmute::<usize, GetObjectTagFn>(symbols::GET_OBJECT_TAG.resolve()?),
+                template: transmute::<usize, GetObjectTemplateFn>(
+                    symbols::GET_OBJECT_TEMPLATE.resolve()?,
+                ),
+                position: transmute::<usize, GetObjectVectorFn>(
+                    symbols::GET_OBJECT_POSITION.resolve()?,
+                ),
+                orientation: transmute::<usize, GetObjectVectorFn>(
+                    symbols::GET_OBJECT_ORIENTATION.resolve()?,
+                ),
+                plot: transmute::<usize, GetFlagFn>(symbols::GET_PLOT_FLAG.resolve()?),
+                is_open: transmute::<usize, GetFlagFn>(symbols::IS_OPEN.resolve()?),
+                is_locked: transmute::<usize, GetFlagFn>(symbols::IS_LOCKED.resolve()?),
+                hit_points: transmute::<usize, GetPointsFn>(
+                    symbols::GET_CURRENT_HIT_POINTS.resolve()?,
+                ),
+                max_hit_points: transmute::<usize, GetPointsFn>(
+                    symbols::GET_MAX_HIT_POINTS.resolve()?,
+                ),
+            })
+        }
+    }
+
+    unsafe fn read(&self, id: u32, object: *mut c_void) -> AreaObject {
+        let object_type = (self.object_type)(object);
+        let tag = (self.tag)(object);
+        let template = (self.template)(object);
+        let is_creature = object_type == OBJECT_TYPE_CREATURE;
+        let can_open = object_type == OBJECT_TYPE_DOOR || object_type == OBJECT_TYPE_PLACEABLE;
+
+        AreaObject {
+            id,
+            kind: type_name(object_type),
+            tag: if tag.is_null() {
+                String::new()
+            } else {
+                (*tag).to_string_lossy()
+            },
+            template: if template.is_null() {
+                String::new()
+            } else {
+                (*template).to_string_lossy()
+            },
+            position: *(self.position)(object),
+            orientation: *(self.orientation)(object),
+            plot: (self.plot)(object) != 0,
+            hit_points: is_creature.then(|| (self.hit_points)(object)),
+            max_hit_points: is_creature.then(|| (self.max_hit_points)(object)),
+            open: can_open.then(|| (self.is_open)(object) != 0),
+            locked: can_open.then(|| (self.is_locked)(object) != 0),
+        }
+    }
+}
+
+/// Returns the area object of the area the party is in.
+///
+/// Must be called on the main thread.
+pub(crate) fn current_area() -> io::Result<usize> {
+    let get_current_area: GetCurrentAreaFn =
+        unsafe { std::mem::transmute(symbols::GET_CURRENT_AREA.resolve()?) };
+    let area = unsafe { get_current_area(server_exo_app()?) };
+    if area.is_null() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "No area loaded, load a save first",
+        ));
+    }
+    Ok(area as usize)
+}
+
+/// Reads the object ids listed in the area object at `area` out of `memory`.
+pub fn read_object_ids(memory: &impl Memory, area: usize) -> io::Result<Vec<u32>> {
+    let list = area + AREA_OBJECTS_OFFSET;
+    let ids = memory.read_u32(list)? as usize;
+    let count = memory.read_u32(list + 4)? as usize;
+    if ids == 0 {
+        return Ok(Vec::new());
+    }
+    if count > MAX_AREA_OBJECTS {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Area at {area:#010x} claims to have {count} objects"),
+        ));
+    }
+
+    let mut bytes = vec![0u8; count * 4];
+    memory.read(ids, &mut bytes)?;
+    Ok(bytes
+        .chunks_exact(4)
+        .map(|id| u32::from_le_bytes([id[0], id[1], id[2], id[3]]))
+        .collect())
+}
+
+/// Ids and objects of everything in the area the party is in, in the engine's order.
+///
+/// Must be called on the main thread.
+fn objects() -> io::Result<Vec<(u32, *mut c_void)>> {
+    let get_game_object: GetGameObjectFn =
+        unsafe { std::mem::transmute(symbols::GET_GAME_OBJECT.resolve()?) };
+    let server_app = server_exo_app()?;
+    let ids = read_object_ids(&Process, current_area()?)?;
+
+    Ok(ids
+        .into_iter()
+        .filter_map(|id| {
+            let object = unsafe { get_game_object(server_app, id) };
+            (!object.is_null()).then_some((id, object))
+        })
+        .collect())
+}
+
+/// Reads every object in the area the party is in, in the engine's order.
+///
+/// Must be called on the main thread.
+pub fn area_objects() -> io::Result<Vec<AreaObject>> {
+    let readers = Readers::resolve()?;
+    Ok(objects()?
+        .into_iter()
+        .map(|(id, object)| unsafe { readers.read(id, object) })
+        .collect())
+}
+
+/// Local booleans of every object in the area by object id, bit `n` being local boolean `n`.
+///
+/// Must be called on the main thread.
+pub fn local_booleans() -> io::Result<HashMap<u32, u64>> {
+    let get_local_boolean: GetLocalBooleanFn =
+        unsafe { transmute(symbols::GET_LOCAL_BOOLEAN.resolve()?) };
+
+    Ok(objects()?
+        .into_iter()
+        .map(|(id, object)| {
+            let bits = (0..LOCAL_BOOLEAN_COUNT)
+                .filter(|&index| unsafe { get_local_boolean(object, index) } != 0)
+                .fold(0u64, |bits, index| bits | 1 << index);
+            (id, bits)
+        })
+        .collect())
+}
+
+/// Sets the local booleans of the objects in `values` still in the area to what they were when
+/// read with `local_booleans`. Returns how many objects had any changed back.
+///
+/// Must be called on the main thread.
+pub fn set_local_booleans(values: &HashMap<u32, u64>) -> io::Result<usize> {
+    let get_local_boolean: GetLocalBooleanFn =
+        unsafe { transmute(symbols::GET_LOCAL_BOOLEAN.resolve()?) };
+    let set_local_boolean: SetLocalBooleanFn =
+        unsafe { transmute(symbols::SET_LOCAL_BOOLEAN.resolve()?) };
+
+    let mut restored = 0;
+    for (id, object) in objects()? {
+        let Some(bits) = values.get(&id) else {
+            continue;
+        };
+        let mut changed = false;
+        for index in 0..LOCAL_BOOLEAN_COUNT {
+            let value = (bits >> index & 1) as i32;
+            unsafe {
+                if (get_local_boolean(object, index) != 0) != (value != 0) {
+                    set_local_boolean(object, index, value);
+                    changed = true;
+                }
+            }
+        }
+        restored += changed as usize;
+    }
+    Ok(restored)
+}
+
+/// JSON Schema of the dumps `write_dump` writes, published as
+/// `data/schemas/area-dump.schema.json` for tools reading them.
+pub fn dump_schema() -> Value {
+    let types: Vec<&str> = OBJECT_TYPES
+        .iter()
+        .map(|(_, name)| *name)
+        .chain(["unknown"])
+        .collect();
+    let vector = |description: &str| {
+        json!({
+            "type": "array",
+            "items": { "type": "number" },
+            "minItems": 3,
+            "maxItems": 3,
+            "description": description,
+        })
+    };
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "swkotor-mod area dump",
+        "description": "Live objects of the loaded area, written by the `objects dump` console \
+                        command",
+        "type": "array",
+        "items": {
+            "type": "object",
+            "properties": {
+                "id": { "type": "integer", "minimum": 0, "description": "Engine object id" },
+                "type": { "enum": types },
+                "tag": { "type": "string" },
+                "template": {
+                    "type": "string",
+                    "description": "Blueprint the object was created from, empty for ones the \
+                                    engine made up",
+                },
+                "position": vector("x, y and z in meters"),
+                "orientation": vector("Unit vector of the direction the object faces"),
+                "plot": { "type": "boolean" },
+                "hit_points": { "type": "integer", "description": "Creatures only" },
+                "max_hit_points": { "type": "integer", "description": "Creatures only" },
+                "open": { "type": "boolean", "description": "Doors and placeables only" },
+                "locked": { "type": "boolean", "description": "Doors and placeables only" },
+            },
+            "required": ["id", "type", "tag", "template", "position", "orientation", "plot"],
+            "additionalProperties": false,
+        },
+    })
+}
+
+/// Writes `objects` as JSON to `swkotor-mod-area-<module>-<timestamp>.json` in the game directory,
+/// returns the file name.
+pub fn write_dump(module: &str, objects: &[AreaObject]) -> io::Result<String> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let path = format!("swkotor-mod-area-{module}-{timestamp}.json");
+    fs::write(&path, serde_json::to_string_pretty(objects)?)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_only_fields_the_type_has() {
+        let door = AreaObject {
+            id: 12,
+            kind: type_name(OBJECT_TYPE_DOOR),
+            tag: "end_door01".to_string(),
+            template: "end_door01".to_string(),
+            position: [1.0, 2.0, 0.0],
+            orientation: [0.0, 1.0, 0.0],
+            plot: false,
+            hit_points: None,
+            max_hit_points: None,
+            open: Some(true),
+            locked: Some(false),
+        };
+
+        let json = serde_json::to_value(&door).unwrap();
+        assert_eq!(json["type"], "door");
+        assert_eq!(json["open"], true);
+        assert!(json.get("hit_points").is_none());
+
+        let schema = dump_schema();
+        let properties = schema["items"]["properties"].as_object().unwrap();
+        for key in json.as_object().unwrap().keys() {
+            assert!(properties.contains_key(key), "{key} is not in the schema");
+        }
+    }
+
+    #[test]
+    fn published_schema_is_up_to_date() {
+        let published: Value =
+            serde_json::from_str(include_str!("../../data/schemas/area-dump.schema.json")).unwrap();
+        assert_eq!(
+            published,
+            dump_schema(),
+            "data/schemas/area-dump.schema.json differs from dump_schema()"
+        );
+    }
+}
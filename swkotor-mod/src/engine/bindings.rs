@@ -0,0 +1,455 @@
+/// Curated engine functions callable from the console, QA scripts and IPC alike.
+///
+/// Every binding declares its parameters. Arguments are checked against them before anything
+/// runs, and the call itself is made on the main thread, so callers on any thread get the same
+/// safety whichever way they come in. Arguments come in as words, the way the console and IPC
+/// deliver them, and scripts pass theirs as text too.
+///
+/// Bindings call the engine through `EngineApi`, tests run them against `mock::MockEngine`.
+use std::{error::Error, io, time::Duration};
+
+use super::{
+    api::{EngineApi, Game},
+    frame, objects,
+};
+
+/// How long to wait for the main thread to make the call
+const MAIN_THREAD_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamKind {
+    Text,
+    Number,
+    /// `true`/`false`, `on`/`off` or `1`/`0`
+    Flag,
+}
+
+pub struct Param {
+    pub name: &'static str,
+    pub kind: ParamKind,
+    /// Optional parameters come last
+    pub optional: bool,
+}
+
+const fn param(name: &'static str, kind: ParamKind) -> Param {
+    Param {
+        name,
+        kind,
+        optional: false,
+    }
+}
+
+const fn optional(name: &'static str, kind: ParamKind) -> Param {
+    Param {
+        name,
+        kind,
+        optional: true,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Text(String),
+    Number(f64),
+    Flag(bool),
+}
+
+/// Arguments of a call, already checked against the binding's parameters.
+pub struct Args(Vec<Value>);
+
+impl Args {
+    fn text(&self, index: usize) -> &str {
+        match &self.0[index] {
+            Value::Text(text) => text,
+            value => panic!("Argument {index} should be text, was {value:?}"),
+        }
+    }
+
+    fn number(&self, index: usize) -> Option<f64> {
+        match self.0.get(index)? {
+            Value::Number(number) => Some(*number),
+            value => panic!("Argument {index} should be a number, was {value:?}"),
+        }
+    }
+
+    fn flag(&self, index: usize) -> bool {
+        match &self.0[index] {
+            Value::Flag(flag) => *flag,
+            value => panic!("Argument {index} should be a flag, was {value:?}"),
+        }
+    }
+}
+
+pub struct Binding {
+    pub name: &'static str,
+    pub help: &'static str,
+    pub params: &'static [Param],
+    /// Runs on the main thread with arguments matching `params`, returns a short result
+    call: fn(&dyn EngineApi, &Args) -> io::Result<String>,
+}
+
+impl Binding {
+    /// Parameter synopsis, e.g. `<template> [<count>]`.
+    pub fn usage(&self) -> String {
+        self.params
+            .iter()
+            .map(|param| {
+                if param.optional {
+                    format!("[<{}>]", param.name)
+                } else {
+                    format!("<{}>", param.name)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+fn invalid_input(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+fn in_range<T: TryFrom<i64>>(value: f64, what: &str) -> io::Result<T> {
+    if value.fract() != 0.0 {
+        return Err(invalid_input(format!("{what} must be a whole number")));
+    }
+    T::try_from(value as i64).map_err(|_| invalid_input(format!("{what} is out of range")))
+}
+
+fn spawn_object(engine: &dyn EngineApi, args: &Args) -> io::Result<String> {
+    let kind = objects::ObjectKind::parse(args.text(0)).ok_or_else(|| {
+        invalid_input("Object kind must be creature, item or placeable".to_string())
+    })?;
+    let position = match (args.number(2), args.number(3), args.number(4)) {
+        (Some(x), Some(y), Some(z)) => [x as f32, y as f32, z as f32],
+        (None, None, None) => engine.leader_position()?,
+        _ => return Err(invalid_input("Give all of x, y and z or none".to_string())),
+    };
+    let id = engine.spawn_object(kind, args.text(1), position)?;
+    Ok(format!("{id:#x}"))
+}
+
+fn set_global_boolean(engine: &dyn EngineApi, args: &Args) -> io::Result<String> {
+    engine.set_global_boolean(args.text(0), args.flag(1))?;
+    Ok(args.flag(1).to_string())
+}
+
+fn set_global_number(engine: &dyn EngineApi, args: &Args) -> io::Result<String> {
+    let value: u8 = in_range(args.number(1).unwrap_or_default(), "Value")?;
+    engine.set_global_number(args.text(0), value)?;
+    Ok(value.to_string())
+}
+
+fn global_boolean(engine: &dyn EngineApi, args: &Args) -> io::Result<String> {
+    Ok(engine.global_boolean(args.text(0))?.to_string())
+}
+
+fn global_number(engine: &dyn EngineApi, args: &Args) -> io::Result<String> {
+    Ok(engine.global_number(args.text(0))?.to_string())
+}
+
+fn give_item(engine: &dyn EngineApi, args: &Args) -> io::Result<String> {
+    let count: u16 = in_range(args.number(1).unwrap_or(1.0), "Count")?;
+    engine.give_item(args.text(0), count)?;
+    Ok(count.to_string())
+}
+
+fn warp(engine: &dyn EngineApi, args: &Args) -> io::Result<String> {
+    engine.warp(args.text(0))?;
+    Ok(args.text(0).to_string())
+}
+
+fn play_movie(engine: &dyn EngineApi, args: &Args) -> io::Result<String> {
+    engine.play_movie(args.text(0))?;
+    Ok(args.text(0).to_string())
+}
+
+pub const BINDINGS: &[Binding] = &[
+    Binding {
+        name: "spawn_object",
+        help: "Creates a creature, item or placeable from its template, at the party leader by default",
+        params: &[
+            param("kind", ParamKind::Text),
+            param("template", ParamKind::Text),
+            optional("x", ParamKind::Number),
+            optional("y", ParamKind::Number),
+            optional("z", ParamKind::Number),
+        ],
+        call: spawn_object,
+    },
+    Binding {
+        name: "set_global_boolean",
+        help: "Sets a boolean script global",
+        params: &[
+            param("name", ParamKind::Text),
+            param("value", ParamKind::Flag),
+        ],
+        call: set_global_boolean,
+    },
+    Binding {
+        name: "set_global_number",
+        help: "Sets a number script global, 0 to 255",
+        params: &[
+            param("name", ParamKind::Text),
+            param("value", ParamKind::Number),
+        ],
+        call: set_global_number,
+    },
+    Binding {
+        name: "global_boolean",
+        help: "Reads a boolean script global",
+        params: &[param("name", ParamKind::Text)],
+        call: global_boolean,
+    },
+    Binding {
+        name: "global_number",
+        help: "Reads a number script global",
+        params: &[param("name", ParamKind::Text)],
+        call: global_number,
+    },
+    Binding {
+        name: "give_item",
+        help: "Creates items into the party inventory",
+        params: &[
+            param("template", ParamKind::Text),
+            optional("count", ParamKind::Number),
+        ],
+        call: give_item,
+    },
+    Binding {
+        name: "warp",
+        help: "Transitions the party to another module",
+        params: &[param("module", ParamKind::Text)],
+        call: warp,
+    },
+    Binding {
+        name: "play_movie",
+        help: "Plays a movie from the movies directory, e.g. a cutscene",
+        params: &[param("movie", ParamKind::Text)],
+        call: play_movie,
+    },
+];
+
+fn parse_flag(word: &str) -> Option<bool> {
+    match word {
+        "true" | "on" | "1" => Some(true),
+        "false" | "off" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+/// Checks `words` against the parameters of `binding` and converts them to values.
+fn parse_args(binding: &Binding, words: &[&str]) -> Result<Args, Box<dyn Error>> {
+    let required = binding
+        .params
+        .iter()
+        .filter(|param| !param.optional)
+        .count();
+    if words.len() < required || words.len() > binding.params.len() {
+        return Err(format!("Usage: {} {}", binding.name, binding.usage()).into());
+    }
+
+    let values = binding
+        .params
+        .iter()
+        .zip(words)
+        .map(|(param, word)| match param.kind {
+            ParamKind::Text => Ok(Value::Text(word.to_string())),
+            ParamKind::Number => word
+                .parse()
+                .map(Value::Number)
+                .map_err(|_| format!("{} must be a number, got '{word}'", param.name)),
+            ParamKind::Flag => parse_flag(word)
+                .map(Value::Flag)
+                .ok_or_else(|| format!("{} must be true or false, got '{word}'", param.name)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Args(values))
+}
+
+pub fn find(name: &str) -> Result<&'static Binding, Box<dyn Error>> {
+    BINDINGS
+        .iter()
+        .find(|binding| binding.name == name)
+        .ok_or_else(|| format!("Unknown engine function '{name}'").into())
+}
+
+/// Calls the binding `name` with `words` as arguments on `engine` right away.
+pub fn call_with(
+    engine: &dyn EngineApi,
+    name: &str,
+    words: &[&str],
+) -> Result<String, Box<dyn Error>> {
+    let binding = find(name)?;
+    let args = parse_args(binding, words)?;
+    Ok((binding.call)(engine, &args)?)
+}
+
+/// Calls the binding `name` with `words` as arguments on the main thread and waits for the
+/// result.
+///
+/// Must not be called from the main thread.
+pub fn call(name: &str, words: &[&str]) -> Result<String, Box<dyn Error>> {
+    let binding = find(name)?;
+    let args = parse_args(binding, words)?;
+    let call = binding.call;
+    Ok(frame::call_on_main_thread(
+        MAIN_THREAD_TIMEOUT,
+        move || call(&Game, &args),
+    )??)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::mock::{MockEngine, SpawnedObject};
+    use crate::engine::objects::ObjectKind;
+
+    #[test]
+    fn spawns_at_leader_by_default() {
+        let engine = MockEngine {
+            leader_position: [1.0, 2.0, 3.0],
+            ..Default::default()
+        };
+
+        let id = call_with(&engine, "spawn_object", &["creature", "end_trask"]).unwrap();
+
+        assert_eq!(id, "0x1");
+        assert_eq!(
+            *engine.spawned.borrow(),
+            [SpawnedObject {
+                id: 1,
+                kind: ObjectKind::Creature,
+                template: "end_trask".to_string(),
+                position: [1.0, 2.0, 3.0],
+            }]
+        );
+    }
+
+    #[test]
+    fn spawns_at_given_position() {
+        let engine = MockEngine::default();
+
+        call_with(
+            &engine,
+            "spawn_object",
+            &["placeable", "plc_box", "4", "5.5", "-6"],
+        )
+        .unwrap();
+
+        assert_eq!(engine.spawned.borrow()[0].position, [4.0, 5.5, -6.0]);
+    }
+
+    #[test]
+    fn spawn_needs_whole_position() {
+        let engine = MockEngine::default();
+
+        let result = call_with(
+            &engine,
+            "spawn_object",
+            &["item", "g_i_medeqpmnt01", "4", "5"],
+        );
+
+        assert!(result.is_err());
+        assert!(engine.spawned.borrow().is_empty());
+    }
+
+    #[test]
+    fn spawn_rejects_unknown_kind() {
+        let engine = MockEngine::default();
+
+        assert!(call_with(&engine, "spawn_object", &["door", "end_door"]).is_err());
+    }
+
+    #[test]
+    fn sets_and_reads_globals() {
+        let engine = MockEngine::default();
+
+        call_with(&engine, "set_global_boolean", &["END_TRASK_DLG", "on"]).unwrap();
+        call_with(&engine, "set_global_number", &["END_DOOR_STATE", "2"]).unwrap();
+
+        assert_eq!(
+            call_with(&engine, "global_boolean", &["END_TRASK_DLG"]).unwrap(),
+            "true"
+        );
+        assert_eq!(
+            call_with(&engine, "global_number", &["END_DOOR_STATE"]).unwrap(),
+            "2"
+        );
+    }
+
+    #[test]
+    fn global_number_must_fit_a_byte() {
+        let engine = MockEngine::default();
+
+        assert!(call_with(&engine, "set_global_number", &["END_DOOR_STATE", "256"]).is_err());
+        assert!(call_with(&engine, "set_global_number", &["END_DOOR_STATE", "1.5"]).is_err());
+        assert!(engine.numbers.borrow().is_empty());
+    }
+
+    #[test]
+    fn flags_must_be_flags() {
+        let engine = MockEngine::default();
+
+        assert!(call_with(&engine, "set_global_boolean", &["END_TRASK_DLG", "maybe"]).is_err());
+    }
+
+    #[test]
+    fn gives_one_item_by_default() {
+        let engine = MockEngine::default();
+
+        call_with(&engine, "give_item", &["g_w_blstrpstl001"]).unwrap();
+        call_with(&engine, "give_item", &["g_i_medeqpmnt01", "5"]).unwrap();
+
+        assert_eq!(
+            *engine.items.borrow(),
+            [
+                ("g_w_blstrpstl001".to_string(), 1),
+                ("g_i_medeqpmnt01".to_string(), 5)
+            ]
+        );
+    }
+
+    #[test]
+    fn warps() {
+        let engine = MockEngine::default();
+
+        call_with(&engine, "warp", &["end_m01aa"]).unwrap();
+
+        assert_eq!(*engine.module.borrow(), Some("end_m01aa".to_string()));
+    }
+
+    #[test]
+    fn checks_argument_count() {
+        let engine = MockEngine::default();
+
+        let error = call_with(&engine, "warp", &[]).unwrap_err();
+        assert_eq!(error.to_string(), "Usage: warp <module>");
+        assert!(call_with(&engine, "warp", &["end_m01aa", "end_m01ab"]).is_err());
+        assert!(engine.module.borrow().is_none());
+    }
+
+    #[test]
+    fn rejects_unknown_functions() {
+        let engine = MockEngine::default();
+
+        assert!(call_with(&engine, "destroy_everything", &[]).is_err());
+    }
+
+    #[test]
+    fn optional_parameters_come_last() {
+        for binding in BINDINGS {
+            let first_optional = binding.params.iter().position(|param| param.optional);
+            if let Some(first_optional) = first_optional {
+                assert!(
+                    binding.params[first_optional..]
+                        .iter()
+                        .all(|param| param.optional),
+                    "{} has required parameters after optional ones",
+                    binding.name
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+/// Telling apart the swkotor.exe builds the mod may find itself in.
+///
+/// The original 1.03 executable, as Steam and GOG ship it, is what every address in `symbols` is
+/// for. Aspyr's updates rebuilt the game with a modern compiler and moved every function around,
+/// so addresses of the original would point into the middle of unrelated code there. Symbols
+/// carry an address per build and resolve to nothing on builds they haven't been located in, the
+/// features needing them stay off.
+///
+/// Builds are told apart by the linker version in the PE header. It's readable right away, the
+/// DRM only encrypts the code, and the original was linked long before MSVC 14.
+use std::{fmt, sync::OnceLock};
+
+use log::info;
+use windows::Win32::System::{
+    Diagnostics::Debug::IMAGE_NT_HEADERS32, LibraryLoader::GetModuleHandleA,
+    SystemServices::IMAGE_DOS_HEADER,
+};
+
+/// Linker of Visual Studio 2015, every Aspyr build has been linked with it or newer
+const MODERN_LINKER_MAJOR_VERSION: u8 = 14;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameBuild {
+    /// The original 1.03 executable, which Steam ships
+    Steam,
+    /// Aspyr's updated executable
+    Aspyr,
+    /// Not an executable we know, e.g. when the mod is loaded into something else
+    Unknown,
+}
+
+impl fmt::Display for GameBuild {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameBuild::Steam => write!(f, "Steam"),
+            GameBuild::Aspyr => write!(f, "Aspyr"),
+            GameBuild::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+static BUILD: OnceLock<GameBuild> = OnceLock::new();
+
+/// Reads the linker version from the PE header of the process's executable.
+fn linker_version() -> Option<(u8, u8)> {
+    unsafe {
+        let base = GetModuleHandleA(None).ok()?.0 as *const u8;
+        let dos_header = &*(base as *const IMAGE_DOS_HEADER);
+        // "MZ"
+        if dos_header.e_magic != 0x5a4d {
+            return None;
+        }
+
+        let nt_headers = &*(base.offset(dos_header.e_lfanew as isize) as *const IMAGE_NT_HEADERS32);
+        // "PE\0\0"
+        if nt_headers.Signature != 0x4550 {
+            return None;
+        }
+
+        let optional_header = &nt_headers.OptionalHeader;
+        Some((
+            optional_header.MajorLinkerVersion,
+            optional_header.MinorLinkerVersion,
+        ))
+    }
+}
+
+fn detect() -> GameBuild {
+    let Some((major, minor)) = linker_version() else {
+        return GameBuild::Unknown;
+    };
+
+    let build = if major >= MODERN_LINKER_MAJOR_VERSION {
+        GameBuild::Aspyr
+    } else {
+        GameBuild::Steam
+    };
+    info!("Detected the {build} build of the game, linker {major}.{minor}");
+    build
+}
+
+/// Returns the build of the game the mod runs in.
+pub fn game_build() -> GameBuild {
+    *BUILD.get_or_init(detect)
+}
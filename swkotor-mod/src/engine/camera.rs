@@ -0,0 +1,113 @@
+/// Scene camera, for drawing things in the world.
+use std::io;
+
+use serde::{Deserialize, Serialize};
+
+use crate::mem::{Memory, Process};
+
+use super::symbols;
+
+/// Column major 4x4 matrix as OpenGL takes it
+pub type Matrix = [f32; 16];
+
+/// Returns the combined projection and view matrix the last frame was drawn with. Loading it as
+/// the GL projection matrix makes world coordinates line up with the scene.
+///
+/// Must be called on the main thread.
+pub fn view_projection() -> io::Result<Matrix> {
+    let address = symbols::CAMERA_VIEW_PROJECTION.resolve()?;
+    Ok(unsafe { *(address as *const Matrix) })
+}
+
+/// Reads the view projection matrix at `address` out of `memory`.
+pub fn read_view_projection(memory: &impl Memory, address: usize) -> io::Result<Matrix> {
+    let mut matrix = [0.0; 16];
+    for (i, value) in matrix.iter_mut().enumerate() {
+        *value = memory.read_f32(address + i * 4)?;
+    }
+    Ok(matrix)
+}
+
+/// Where the scene camera is and where it looks, in world coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CameraTransform {
+    pub position: [f32; 3],
+    /// Unit vector the camera looks along
+    pub direction: [f32; 3],
+}
+
+impl CameraTransform {
+    /// Heading in degrees, counterclockwise from the x axis like object facings.
+    pub fn yaw(&self) -> f32 {
+        self.direction[1].atan2(self.direction[0]).to_degrees()
+    }
+
+    /// Degrees above the horizon, negative when looking down.
+    pub fn pitch(&self) -> f32 {
+        self.direction[2].clamp(-1.0, 1.0).asin().to_degrees()
+    }
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// Recovers the camera from the view projection matrix, the engine doesn't keep the view matrix
+/// around on its own.
+///
+/// The eye is the one point a perspective projection sends to x = y = w = 0, and it looks along
+/// the line projecting to the middle of the screen, towards growing w.
+fn camera_transform(matrix: &Matrix) -> io::Result<CameraTransform> {
+    // Rows of the column major matrix, split into the linear part and the translation
+    let row = |r: usize| [matrix[r], matrix[4 + r], matrix[8 + r]];
+    let translation = |r: usize| matrix[12 + r];
+    let (x, y, w) = (row(0), row(1), row(3));
+
+    // Solve x·p = -tx, y·p = -ty, w·p = -tw with Cramer's rule
+    let determinant = dot(x, cross(y, w));
+    if determinant.abs() < f32::EPSILON {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "Camera matrix is not a perspective projection",
+        ));
+    }
+    let right = [-translation(0), -translation(1), -translation(3)];
+    let solve = |column: usize| {
+        let mut columns = [[x[0], y[0], w[0]], [x[1], y[1], w[1]], [x[2], y[2], w[2]]];
+        columns[column] = right;
+        dot(columns[0], cross(columns[1], columns[2])) / determinant
+    };
+    let position = [solve(0), solve(1), solve(2)];
+
+    let mut direction = cross(x, y);
+    if dot(direction, w) < 0.0 {
+        direction = direction.map(|component| -component);
+    }
+    let length = dot(direction, direction).sqrt();
+    let direction = direction.map(|component| component / length);
+
+    Ok(CameraTransform {
+        position,
+        direction,
+    })
+}
+
+/// Reads the view projection matrix at `address` out of `memory` and recovers the camera from it.
+pub fn read_transform(memory: &impl Memory, address: usize) -> io::Result<CameraTransform> {
+    camera_transform(&read_view_projection(memory, address)?)
+}
+
+/// Returns where the camera was for the last frame.
+///
+/// Must be called on the main thread.
+pub fn transform() -> io::Result<CameraTransform> {
+    read_transform(&Process, symbols::CAMERA_VIEW_PROJECTION.resolve()?)
+}
@@ -0,0 +1,211 @@
+/// Cheats meant for QA, toggled from the console.
+///
+/// Simple toggles are patches on the engine functions, turning a cheat off reverts the patch and
+/// leaves the engine as it was. Cheats that only apply to the party are detours, installed on
+/// first use and left in place, the hook checks the toggle on every call.
+///
+/// All setters must be called on the main thread.
+use std::{
+    ffi::c_void,
+    io,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex, OnceLock,
+    },
+};
+
+use log::info;
+
+use crate::mem::{AppliedPatch, Detour, Patch};
+
+use super::{hooks::ensure_detour, party, symbols, trace};
+
+/// Argument bytes the collision test pops off the stack, see `symbols::CREATURE_COLLISION_TEST`.
+const CREATURE_COLLISION_TEST_STACK_SIZE: u16 = 12;
+
+// Bytes of whole instructions copied out of the hooked functions, verify against the disassembly
+// when locating the functions.
+const APPLY_DAMAGE_PROLOGUE_LENGTH: usize = 6;
+const SPEND_FORCE_POINTS_PROLOGUE_LENGTH: usize = 6;
+const USE_ITEM_CHARGES_PROLOGUE_LENGTH: usize = 6;
+
+/// Holds the applied noclip patch while noclip is on.
+static NOCLIP: Mutex<Option<AppliedPatch<8>>> = Mutex::new(None);
+
+static GOD_MODE: AtomicBool = AtomicBool::new(false);
+static INFINITE_FORCE: AtomicBool = AtomicBool::new(false);
+static INFINITE_ITEMS: AtomicBool = AtomicBool::new(false);
+
+static APPLY_DAMAGE_DETOUR: OnceLock<Detour> = OnceLock::new();
+static SPEND_FORCE_POINTS_DETOUR: OnceLock<Detour> = OnceLock::new();
+static USE_ITEM_CHARGES_DETOUR: OnceLock<Detour> = OnceLock::new();
+
+type ApplyDamageFn =
+    unsafe extern "thiscall" fn(creature: *mut c_void, amount: i32, damager: u32) -> i32;
+type SpendForcePointsFn = unsafe extern "thiscall" fn(creature: *mut c_void, amount: i32);
+type UseItemChargesFn =
+    unsafe extern "thiscall" fn(creature: *mut c_void, item: *mut c_void, count: i32);
+
+fn log_toggle(name: &str, enabled: bool) {
+    info!("{name} {}", if enabled { "enabled" } else { "disabled" });
+}
+
+pub fn noclip_enabled() -> bool {
+    NOCLIP.lock().unwrap().is_some()
+}
+
+/// Turns player collision checks off or back on. Setting the current state again does nothing.
+pub fn set_noclip(enabled: bool) -> io::Result<()> {
+    let mut noclip = NOCLIP.lock().unwrap();
+
+    match (enabled, noclip.as_ref()) {
+        (true, None) => {
+            let address = symbols::CREATURE_COLLISION_TEST.resolve()?;
+            let applied = unsafe {
+                // Report every step as unblocked
+                Patch::early_return(
+                    "noclip".to_string(),
+                    address,
+                    0,
+                    CREATURE_COLLISION_TEST_STACK_SIZE,
+                )
+                .apply()?
+            };
+            *noclip = Some(applied);
+        }
+        (false, Some(applied)) => {
+            // Kept on failure so turning noclip off can be tried again
+            unsafe { applied.revert()? };
+            *noclip = None;
+        }
+        _ => {}
+    }
+
+    log_toggle("Noclip", enabled);
+    Ok(())
+}
+
+unsafe extern "thiscall" fn apply_damage_hook(
+    creature: *mut c_void,
+    amount: i32,
+    damager: u32,
+) -> i32 {
+    trace::call(
+        &symbols::APPLY_DAMAGE,
+        || format!("creature={creature:?}, amount={amount}, damager={damager:#x}"),
+        || {
+            if GOD_MODE.load(Ordering::Relaxed) && party::is_in_active_party(creature) {
+                return 0;
+            }
+
+            let detour = APPLY_DAMAGE_DETOUR
+                .get()
+                .expect("hook should not run before the detour is installed");
+            let original: ApplyDamageFn = std::mem::transmute(detour.trampoline());
+            original(creature, amount, damager)
+        },
+    )
+}
+
+unsafe extern "thiscall" fn spend_force_points_hook(creature: *mut c_void, amount: i32) {
+    trace::call(
+        &symbols::SPEND_FORCE_POINTS,
+        || format!("creature={creature:?}, amount={amount}"),
+        || {
+            if INFINITE_FORCE.load(Ordering::Relaxed) && party::is_in_active_party(creature) {
+                return;
+            }
+
+            let detour = SPEND_FORCE_POINTS_DETOUR
+                .get()
+                .expect("hook should not run before the detour is installed");
+            let original: SpendForcePointsFn = std::mem::transmute(detour.trampoline());
+            original(creature, amount)
+        },
+    )
+}
+
+unsafe extern "thiscall" fn use_item_charges_hook(
+    creature: *mut c_void,
+    item: *mut c_void,
+    count: i32,
+) {
+    trace::call(
+        &symbols::USE_ITEM_CHARGES,
+        || format!("creature={creature:?}, item={item:?}, count={count}"),
+        || {
+            if INFINITE_ITEMS.load(Ordering::Relaxed) && party::is_in_active_party(creature) {
+                return;
+            }
+
+            let detour = USE_ITEM_CHARGES_DETOUR
+                .get()
+                .expect("hook should not run before the detour is installed");
+            let original: UseItemChargesFn = std::mem::transmute(detour.trampoline());
+            original(creature, item, count)
+        },
+    )
+}
+
+pub(super) fn install_apply_damage_hook() -> io::Result<()> {
+    ensure_detour(
+        &APPLY_DAMAGE_DETOUR,
+        &symbols::APPLY_DAMAGE,
+        APPLY_DAMAGE_PROLOGUE_LENGTH,
+        apply_damage_hook as ApplyDamageFn as usize,
+    )
+}
+
+pub(super) fn install_spend_force_points_hook() -> io::Result<()> {
+    ensure_detour(
+        &SPEND_FORCE_POINTS_DETOUR,
+        &symbols::SPEND_FORCE_POINTS,
+        SPEND_FORCE_POINTS_PROLOGUE_LENGTH,
+        spend_force_points_hook as SpendForcePointsFn as usize,
+    )
+}
+
+pub(super) fn install_use_item_charges_hook() -> io::Result<()> {
+    ensure_detour(
+        &USE_ITEM_CHARGES_DETOUR,
+        &symbols::USE_ITEM_CHARGES,
+        USE_ITEM_CHARGES_PROLOGUE_LENGTH,
+        use_item_charges_hook as UseItemChargesFn as usize,
+    )
+}
+
+pub fn god_mode_enabled() -> bool {
+    GOD_MODE.load(Ordering::Relaxed)
+}
+
+/// Makes the active party take no damage.
+pub fn set_god_mode(enabled: bool) -> io::Result<()> {
+    install_apply_damage_hook()?;
+    GOD_MODE.store(enabled, Ordering::Relaxed);
+    log_toggle("God mode", enabled);
+    Ok(())
+}
+
+pub fn infinite_force_enabled() -> bool {
+    INFINITE_FORCE.load(Ordering::Relaxed)
+}
+
+/// Makes casting force powers free for the active party.
+pub fn set_infinite_force(enabled: bool) -> io::Result<()> {
+    install_spend_force_points_hook()?;
+    INFINITE_FORCE.store(enabled, Ordering::Relaxed);
+    log_toggle("Infinite force", enabled);
+    Ok(())
+}
+
+pub fn infinite_items_enabled() -> bool {
+    INFINITE_ITEMS.load(Ordering::Relaxed)
+}
+
+/// Keeps medpacs, stims, grenades and other consumables from running out for the active party.
+pub fn set_infinite_items(enabled: bool) -> io::Result<()> {
+    install_use_item_charges_hook()?;
+    INFINITE_ITEMS.store(enabled, Ordering::Relaxed);
+    log_toggle("Infinite items", enabled);
+    Ok(())
+}
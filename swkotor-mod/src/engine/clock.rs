@@ -0,0 +1,234 @@
+/// Control over the game clock: pausing the world, stepping it a frame at a time and scaling how
+/// fast it runs.
+///
+/// The world update is separate from rendering, so skipping it freezes AI, movement, combat and
+/// scripts while the game keeps drawing and the overlay and console keep working. Scaling the
+/// frame time handed to the update slows the world down or speeds it up the same way.
+///
+/// The engine was tuned for 60fps. Above that, animation blending and some physics, e.g. the
+/// swoop races and the turret minigames, jitter and drift from being stepped with frame times of a
+/// few whole milliseconds. The high frame rate fix holds the world update back until a 60fps
+/// frame's worth of time has passed and hands it the time of every frame held back, the world
+/// keeps its real speed while rendering runs as fast as it likes.
+use std::{
+    ffi::c_void,
+    io,
+    sync::{
+        atomic::{AtomicBool, AtomicU32, Ordering},
+        OnceLock,
+    },
+};
+
+use log::info;
+
+use crate::mem::Detour;
+
+use super::{
+    build::{game_build, GameBuild},
+    hooks::ensure_detour,
+    symbols, trace,
+};
+
+/// Allowed time scale range
+pub const MIN_TIME_SCALE: f32 = 0.1;
+pub const MAX_TIME_SCALE: f32 = 5.0;
+
+/// World time a single step advances, a frame at 60fps. Fixed so stepping is repeatable.
+pub const STEP_MILLISECONDS: u32 = 16;
+
+/// Shortest world update with the high frame rate fix on
+pub const MIN_WORLD_STEP_MILLISECONDS: u32 = 16;
+
+// Verify against the disassembly when locating the function
+const UPDATE_WORLD_PROLOGUE_LENGTH: usize = 6;
+
+static PAUSED: AtomicBool = AtomicBool::new(false);
+/// Frames to run while paused
+static PENDING_STEPS: AtomicU32 = AtomicU32::new(0);
+/// Time scale as raw `f32` bits, there's no atomic float
+static TIME_SCALE: AtomicU32 = AtomicU32::new(0x3F80_0000); // 1.0
+/// Fractions of a millisecond left over from scaling, carried to the next frame so slow motion
+/// doesn't round down to a standstill. Raw `f32` bits, only touched on the main thread.
+static SCALED_REMAINDER: AtomicU32 = AtomicU32::new(0);
+static HIGH_FRAME_RATE_FIX: AtomicBool = AtomicBool::new(false);
+/// Milliseconds of the frames whose world update has been held back
+static HELD_BACK: AtomicU32 = AtomicU32::new(0);
+
+static UPDATE_WORLD_DETOUR: OnceLock<Detour> = OnceLock::new();
+
+type UpdateWorldFn = unsafe extern "thiscall" fn(app: *mut c_void, milliseconds: u32) -> i32;
+
+/// Scales the frame time, carrying what doesn't make a whole millisecond over to the next frame.
+fn scale_frame_time(milliseconds: u32) -> u32 {
+    let scale = time_scale();
+    if scale == 1.0 {
+        return milliseconds;
+    }
+
+    let scaled =
+        milliseconds as f32 * scale + f32::from_bits(SCALED_REMAINDER.load(Ordering::Relaxed));
+    let whole = scaled.floor();
+    SCALED_REMAINDER.store((scaled - whole).to_bits(), Ordering::Relaxed);
+    whole as u32
+}
+
+/// Adds the frame time to the time held back, returns all of it once there's enough for a world
+/// update and `None` while the update should be skipped.
+fn pace_frame_time(milliseconds: u32) -> Option<u32> {
+    if !HIGH_FRAME_RATE_FIX.load(Ordering::Relaxed) {
+        return Some(milliseconds);
+    }
+
+    let held = HELD_BACK.load(Ordering::Relaxed) + milliseconds;
+    if held < MIN_WORLD_STEP_MILLISECONDS {
+        HELD_BACK.store(held, Ordering::Relaxed);
+        return None;
+    }
+    HELD_BACK.store(0, Ordering::Relaxed);
+    Some(held)
+}
+
+/// Takes one pending step, returns false if there were none.
+fn take_step() -> bool {
+    PENDING_STEPS
+        .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |steps| {
+            steps.checked_sub(1)
+        })
+        .is_ok()
+}
+
+unsafe extern "thiscall" fn update_world_hook(app: *mut c_void, milliseconds: u32) -> i32 {
+    trace::call(
+        &symbols::UPDATE_WORLD,
+        || format!("app={app:?}, milliseconds={milliseconds}"),
+        || {
+            let milliseconds = if !PAUSED.load(Ordering::Relaxed) {
+                match pace_frame_time(scale_frame_time(milliseconds)) {
+                    Some(milliseconds) => milliseconds,
+                    None => return 1,
+                }
+            } else if take_step() {
+                STEP_MILLISECONDS
+            } else {
+                // Reported as a successful update, the engine carries on with rendering
+                return 1;
+            };
+
+            let detour = UPDATE_WORLD_DETOUR
+                .get()
+                .expect("hook should not run before the detour is installed");
+            let original: UpdateWorldFn = std::mem::transmute(detour.trampoline());
+            original(app, milliseconds)
+        },
+    )
+}
+
+pub(super) fn install_update_world_hook() -> io::Result<()> {
+    ensure_detour(
+        &UPDATE_WORLD_DETOUR,
+        &symbols::UPDATE_WORLD,
+        UPDATE_WORLD_PROLOGUE_LENGTH,
+        update_world_hook as UpdateWorldFn as usize,
+    )
+}
+
+pub fn paused() -> bool {
+    PAUSED.load(Ordering::Relaxed)
+}
+
+/// Freezes or resumes the world. Must be called on the main thread.
+pub fn set_paused(paused: bool) -> io::Result<()> {
+    install_update_world_hook()?;
+    PENDING_STEPS.store(0, Ordering::Relaxed);
+    PAUSED.store(paused, Ordering::Relaxed);
+    info!("World {}", if paused { "paused" } else { "resumed" });
+    Ok(())
+}
+
+/// Runs the paused world for `frames` frames.
+pub fn step(frames: u32) -> io::Result<()> {
+    if !paused() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "World is not paused, pause it first",
+        ));
+    }
+
+    PENDING_STEPS.fetch_add(frames, Ordering::Relaxed);
+    Ok(())
+}
+
+pub fn time_scale() -> f32 {
+    f32::from_bits(TIME_SCALE.load(Ordering::Relaxed))
+}
+
+pub fn validate_time_scale(scale: f32) -> io::Result<()> {
+    if (MIN_TIME_SCALE..=MAX_TIME_SCALE).contains(&scale) {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Time scale has to be {MIN_TIME_SCALE}-{MAX_TIME_SCALE}"),
+        ))
+    }
+}
+
+/// Runs the world at `scale` times the normal speed.
+///
+/// Must be called on the main thread.
+pub fn set_time_scale(scale: f32) -> io::Result<()> {
+    validate_time_scale(scale)?;
+    install_update_world_hook()?;
+
+    TIME_SCALE.store(scale.to_bits(), Ordering::Relaxed);
+    SCALED_REMAINDER.store(0, Ordering::Relaxed);
+    info!("Time scale set to {scale}");
+    Ok(())
+}
+
+pub fn high_frame_rate_fix_enabled() -> bool {
+    HIGH_FRAME_RATE_FIX.load(Ordering::Relaxed)
+}
+
+/// Steps the world at most at 60fps however fast the game renders. Only for the builds we know,
+/// other builds may well have their own frame timing.
+///
+/// Must be called on the main thread.
+pub fn set_high_frame_rate_fix(enabled: bool) -> io::Result<()> {
+    if enabled {
+        let build = game_build();
+        if build == GameBuild::Unknown {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("High frame rate fix is not available for the {build} build"),
+            ));
+        }
+        install_update_world_hook()?;
+    }
+
+    HELD_BACK.store(0, Ordering::Relaxed);
+    HIGH_FRAME_RATE_FIX.store(enabled, Ordering::Relaxed);
+    info!(
+        "High frame rate fix {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn holds_world_updates_back_to_60fps() {
+        HIGH_FRAME_RATE_FIX.store(true, Ordering::Relaxed);
+        // 144fps, the engine's whole milliseconds alternating 6 and 7
+        assert_eq!(pace_frame_time(7), None);
+        assert_eq!(pace_frame_time(6), None);
+        assert_eq!(pace_frame_time(7), Some(20));
+        // Slow frames go through as they are
+        assert_eq!(pace_frame_time(33), Some(33));
+        HIGH_FRAME_RATE_FIX.store(false, Ordering::Relaxed);
+        assert_eq!(pace_frame_time(7), Some(7));
+    }
+}
@@ -0,0 +1,72 @@
+/// Dialogue fast-forward.
+///
+/// While enabled, holding the fast-forward key skips spoken conversation entries one after
+/// another, voice over included. Skipping stops on reply lists, the player still picks the path
+/// through the conversation.
+use std::{
+    io,
+    sync::atomic::{AtomicBool, AtomicU32, Ordering},
+};
+
+use log::{error, info};
+use windows::Win32::UI::Input::KeyboardAndMouse::{VK_CONTROL, VK_D};
+
+use crate::liveqa::hotkeys::{game_has_focus, is_key_down};
+
+use super::{frame, symbols};
+
+/// Frames between skips. Engine needs a few frames to move to the next entry, skipping faster just
+/// eats the skips.
+const FRAMES_BETWEEN_SKIPS: u32 = 6;
+
+static FAST_FORWARD: AtomicBool = AtomicBool::new(false);
+static FRAME_CALLBACK_REGISTERED: AtomicBool = AtomicBool::new(false);
+static FRAMES_SINCE_SKIP: AtomicU32 = AtomicU32::new(0);
+
+type SkipConversationEntryFn = unsafe extern "system" fn() -> i32;
+
+fn fast_forward_held() -> bool {
+    game_has_focus() && is_key_down(VK_CONTROL) && is_key_down(VK_D)
+}
+
+fn fast_forward_frame() {
+    if !FAST_FORWARD.load(Ordering::Relaxed) || !fast_forward_held() {
+        return;
+    }
+
+    if FRAMES_SINCE_SKIP.fetch_add(1, Ordering::Relaxed) + 1 < FRAMES_BETWEEN_SKIPS {
+        return;
+    }
+    FRAMES_SINCE_SKIP.store(0, Ordering::Relaxed);
+
+    // Resolved when enabling, failing here means the symbols changed under us
+    match symbols::SKIP_CONVERSATION_ENTRY.resolve() {
+        Ok(address) => unsafe {
+            let skip: SkipConversationEntryFn = std::mem::transmute(address);
+            skip();
+        },
+        Err(e) => error!("Dialogue fast-forward stopped: {e}"),
+    }
+}
+
+pub fn fast_forward_enabled() -> bool {
+    FAST_FORWARD.load(Ordering::Relaxed)
+}
+
+/// Enables or disables holding Ctrl+D to fast-forward dialogue.
+pub fn set_fast_forward(enabled: bool) -> io::Result<()> {
+    if enabled {
+        symbols::SKIP_CONVERSATION_ENTRY.resolve()?;
+
+        if !FRAME_CALLBACK_REGISTERED.swap(true, Ordering::Relaxed) {
+            frame::on_every_frame(fast_forward_frame);
+        }
+    }
+
+    FAST_FORWARD.store(enabled, Ordering::Relaxed);
+    info!(
+        "Dialogue fast-forward {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(())
+}
@@ -3,9 +3,7 @@ use std::ffi::c_void;
 use windows::core::{GUID, HRESULT};
 use windows::Win32::Foundation::HINSTANCE;
 
-use crate::SW_KOTOR_MOD_ENGINE;
-
-use super::SWKotorModEngine;
+use super::{input, sw_kotor_mod_engine, SWKotorModEngine};
 
 pub type REFIID = *const GUID;
 pub type LPUNKNOWN = *mut core::ffi::c_void;
@@ -28,14 +26,16 @@ pub extern "system" fn DirectInput8Create(
     punk_outer: LPUNKNOWN,
 ) -> HRESULT {
     trace!("Calling original DirectInput8Create from wrapper");
-    SW_KOTOR_MOD_ENGINE
-        .lock()
-        .unwrap()
-        .direct_input8_create(hinst, dw_version, riidltf, ppv_out, punk_outer)
+    unsafe {
+        sw_kotor_mod_engine().direct_input8_create(hinst, dw_version, riidltf, ppv_out, punk_outer)
+    }
 }
 
 impl SWKotorModEngine {
-    pub fn direct_input8_create(
+    /// # Safety
+    ///
+    /// `ppv_out` must be null or point to where the created interface is written.
+    pub unsafe fn direct_input8_create(
         &self,
         hinst: HINSTANCE,
         dw_version: u32,
@@ -44,6 +44,14 @@ impl SWKotorModEngine {
         punk_outer: LPUNKNOWN,
     ) -> HRESULT {
         trace!("Calling original DirectInput8Create from wrapper");
-        unsafe { (&self.direct_input8_create_fn)(hinst, dw_version, riidltf, ppv_out, punk_outer) }
+        unsafe {
+            let result =
+                (&self.direct_input8_create_fn)(hinst, dw_version, riidltf, ppv_out, punk_outer);
+            // Keyboard and mouse go to the overlay instead of the game while it has them
+            if result.is_ok() && !ppv_out.is_null() {
+                input::hook_direct_input(*ppv_out);
+            }
+            result
+        }
     }
 }
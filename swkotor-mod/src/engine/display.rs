@@ -0,0 +1,184 @@
+/// Field of view and GUI aspect ratio patches for modern resolutions.
+///
+/// The camera field of view is vertical, so wider screens already see more to the sides. It's
+/// just narrow to begin with, the FOV override replaces whatever the game asks for. GUI is laid out
+/// for 4:3 and stretched to the screen, the aspect correction widens the GUI projection to match
+/// the screen instead so the GUI keeps its shape.
+use std::{
+    ffi::c_void,
+    io,
+    sync::{
+        atomic::{AtomicBool, AtomicU32, Ordering},
+        OnceLock,
+    },
+};
+
+use log::{error, info};
+use windows::Win32::Graphics::OpenGL::{glGetIntegerv, GL_VIEWPORT};
+
+use crate::config::config;
+use crate::mem::Detour;
+
+use super::{clock, hooks::ensure_detour, symbols, trace};
+
+/// Allowed field of view range in degrees, outside this the world turns into a fisheye or a scope.
+pub const MIN_FIELD_OF_VIEW: f32 = 30.0;
+pub const MAX_FIELD_OF_VIEW: f32 = 120.0;
+
+/// Aspect ratio the GUI has been laid out for
+const GUI_ASPECT_RATIO: f32 = 4.0 / 3.0;
+
+// Verify against the disassembly when locating the functions
+const SET_CAMERA_FIELD_OF_VIEW_PROLOGUE_LENGTH: usize = 6;
+const SET_GUI_PROJECTION_PROLOGUE_LENGTH: usize = 6;
+
+/// Field of view override as raw `f32` bits, zero uses the game's own
+static FIELD_OF_VIEW: AtomicU32 = AtomicU32::new(0);
+static GUI_ASPECT_CORRECTION: AtomicBool = AtomicBool::new(false);
+
+static SET_CAMERA_FIELD_OF_VIEW_DETOUR: OnceLock<Detour> = OnceLock::new();
+static SET_GUI_PROJECTION_DETOUR: OnceLock<Detour> = OnceLock::new();
+
+type SetCameraFieldOfViewFn = unsafe extern "thiscall" fn(camera: *mut c_void, degrees: f32);
+type SetGuiProjectionFn =
+    unsafe extern "thiscall" fn(renderer: *mut c_void, width: f32, height: f32);
+
+unsafe extern "thiscall" fn set_camera_field_of_view_hook(camera: *mut c_void, degrees: f32) {
+    trace::call(
+        &symbols::SET_CAMERA_FIELD_OF_VIEW,
+        || format!("camera={camera:?}, degrees={degrees}"),
+        || {
+            let degrees = field_of_view().unwrap_or(degrees);
+
+            let detour = SET_CAMERA_FIELD_OF_VIEW_DETOUR
+                .get()
+                .expect("hook should not run before the detour is installed");
+            let original: SetCameraFieldOfViewFn = std::mem::transmute(detour.trampoline());
+            original(camera, degrees)
+        },
+    )
+}
+
+unsafe extern "thiscall" fn set_gui_projection_hook(
+    renderer: *mut c_void,
+    width: f32,
+    height: f32,
+) {
+    trace::call(
+        &symbols::SET_GUI_PROJECTION,
+        || format!("renderer={renderer:?}, width={width}, height={height}"),
+        || {
+            let mut width = width;
+
+            if GUI_ASPECT_CORRECTION.load(Ordering::Relaxed) {
+                let mut viewport = [0i32; 4];
+                glGetIntegerv(GL_VIEWPORT, viewport.as_mut_ptr());
+                let screen_aspect_ratio = viewport[2] as f32 / viewport[3].max(1) as f32;
+
+                // Narrower screens than 4:3 are left alone, the GUI would not fit
+                if screen_aspect_ratio > GUI_ASPECT_RATIO {
+                    width = height * screen_aspect_ratio;
+                }
+            }
+
+            let detour = SET_GUI_PROJECTION_DETOUR
+                .get()
+                .expect("hook should not run before the detour is installed");
+            let original: SetGuiProjectionFn = std::mem::transmute(detour.trampoline());
+            original(renderer, width, height)
+        },
+    )
+}
+
+pub(super) fn install_set_camera_field_of_view_hook() -> io::Result<()> {
+    ensure_detour(
+        &SET_CAMERA_FIELD_OF_VIEW_DETOUR,
+        &symbols::SET_CAMERA_FIELD_OF_VIEW,
+        SET_CAMERA_FIELD_OF_VIEW_PROLOGUE_LENGTH,
+        set_camera_field_of_view_hook as SetCameraFieldOfViewFn as usize,
+    )
+}
+
+pub(super) fn install_set_gui_projection_hook() -> io::Result<()> {
+    ensure_detour(
+        &SET_GUI_PROJECTION_DETOUR,
+        &symbols::SET_GUI_PROJECTION,
+        SET_GUI_PROJECTION_PROLOGUE_LENGTH,
+        set_gui_projection_hook as SetGuiProjectionFn as usize,
+    )
+}
+
+/// Returns the field of view override, `None` when the game decides.
+pub fn field_of_view() -> Option<f32> {
+    let degrees = f32::from_bits(FIELD_OF_VIEW.load(Ordering::Relaxed));
+    (degrees != 0.0).then_some(degrees)
+}
+
+pub fn validate_field_of_view(degrees: f32) -> io::Result<()> {
+    if (MIN_FIELD_OF_VIEW..=MAX_FIELD_OF_VIEW).contains(&degrees) {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Field of view has to be {MIN_FIELD_OF_VIEW}-{MAX_FIELD_OF_VIEW} degrees"),
+        ))
+    }
+}
+
+/// Overrides the camera field of view, `None` goes back to the game's own. Takes effect on the
+/// next camera change.
+///
+/// Must be called on the main thread.
+pub fn set_field_of_view(degrees: Option<f32>) -> io::Result<()> {
+    if let Some(degrees) = degrees {
+        validate_field_of_view(degrees)?;
+        install_set_camera_field_of_view_hook()?;
+    }
+
+    FIELD_OF_VIEW.store(degrees.unwrap_or(0.0).to_bits(), Ordering::Relaxed);
+    match degrees {
+        Some(degrees) => info!("Field of view set to {degrees}"),
+        None => info!("Field of view back to the game's own"),
+    }
+    Ok(())
+}
+
+pub fn gui_aspect_correction_enabled() -> bool {
+    GUI_ASPECT_CORRECTION.load(Ordering::Relaxed)
+}
+
+/// Keeps the GUI at its own aspect ratio instead of stretching it to the screen.
+///
+/// Must be called on the main thread.
+pub fn set_gui_aspect_correction(enabled: bool) -> io::Result<()> {
+    install_set_gui_projection_hook()?;
+    GUI_ASPECT_CORRECTION.store(enabled, Ordering::Relaxed);
+    info!(
+        "GUI aspect correction {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(())
+}
+
+/// Applies the display settings from the config. Must be called on the main thread.
+pub fn init() {
+    let display = config().display.clone();
+
+    if display.field_of_view.is_some() {
+        if let Err(e) = set_field_of_view(display.field_of_view) {
+            error!("Could not apply the configured field of view: {e}");
+        }
+    }
+
+    if display.gui_aspect_correction {
+        if let Err(e) = set_gui_aspect_correction(true) {
+            error!("Could not apply GUI aspect correction: {e}");
+        }
+    }
+
+    if display.high_frame_rate_fix {
+        if let Err(e) = clock::set_high_frame_rate_fix(true) {
+            error!("Could not apply the high frame rate fix: {e}");
+        }
+    }
+}
@@ -0,0 +1,239 @@
+/// Engine state fixtures: recordings of the engine memory the accessors read, for testing them
+/// offline.
+///
+/// The `fixture` command runs the accessors of the object table, the camera and the module name
+/// against the live game through a recorder that keeps every byte they read, and writes those
+/// bytes with what the accessors returned to `fixtures/<name>.json`. Fixtures copied into
+/// `data/fixtures` are replayed by the unit tests: the accessors run against the recorded bytes and
+/// have to come up with the same values, so a change to how they read engine structs can be
+/// checked without the game. `synthetic.json` is written by hand and keeps the replay itself
+/// tested until real recordings are added.
+use std::{
+    cell::RefCell,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use log::info;
+use serde::{Deserialize, Serialize};
+
+use crate::mem::{Memory, Process};
+
+use super::{
+    area,
+    build::game_build,
+    camera::{self, CameraTransform},
+    module, symbols,
+    types::{read_exo_string, validate_resref},
+};
+
+/// Directory in the game directory fixtures are written to
+pub const FIXTURE_DIRECTORY: &str = "fixtures";
+
+/// Bytes read from `address`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Region {
+    pub address: usize,
+    /// Lowercase hex, two digits a byte
+    pub bytes: String,
+}
+
+/// Addresses the accessors start from, `None` for what couldn't be found when recording
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Roots {
+    /// Area object of the area the party is in
+    pub area: Option<usize>,
+    /// The camera's view projection matrix
+    pub camera: Option<usize>,
+    /// The `CExoString` holding the module's resref
+    pub module_name: Option<usize>,
+}
+
+/// What the accessors read, `None` where they failed
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct State {
+    pub module: Option<String>,
+    pub object_ids: Option<Vec<u32>>,
+    pub camera: Option<CameraTransform>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Fixture {
+    /// Game build recorded on, addresses only make sense for it
+    pub build: String,
+    pub roots: Roots,
+    pub expected: State,
+    pub regions: Vec<Region>,
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn from_hex(text: &str) -> io::Result<Vec<u8>> {
+    let invalid = || io::Error::new(io::ErrorKind::InvalidData, "Region bytes are not hex");
+    if !text.len().is_multiple_of(2) {
+        return Err(invalid());
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|i| {
+            text.get(i..i + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or_else(invalid)
+        })
+        .collect()
+}
+
+/// Runs every accessor against `memory`.
+pub fn read_state(memory: &impl Memory, roots: &Roots) -> State {
+    State {
+        module: roots
+            .module_name
+            .and_then(|address| read_exo_string(memory, address).ok()),
+        object_ids: roots
+            .area
+            .and_then(|area| area::read_object_ids(memory, area).ok()),
+        camera: roots
+            .camera
+            .and_then(|address| camera::read_transform(memory, address).ok()),
+    }
+}
+
+/// Reads through to `memory`, keeping a copy of everything read.
+struct Recorder<'a, M: Memory> {
+    memory: &'a M,
+    regions: RefCell<Vec<Region>>,
+}
+
+impl<M: Memory> Memory for Recorder<'_, M> {
+    fn read(&self, address: usize, buffer: &mut [u8]) -> io::Result<()> {
+        self.memory.read(address, buffer)?;
+        self.regions.borrow_mut().push(Region {
+            address,
+            bytes: to_hex(buffer),
+        });
+        Ok(())
+    }
+}
+
+/// Recorded memory, reads outside the recorded regions fail like unmapped memory would.
+impl Memory for Fixture {
+    fn read(&self, address: usize, buffer: &mut [u8]) -> io::Result<()> {
+        for region in &self.regions {
+            let bytes = from_hex(&region.bytes)?;
+            let Some(start) = address.checked_sub(region.address) else {
+                continue;
+            };
+            if let Some(recorded) = bytes.get(start..start + buffer.len()) {
+                buffer.copy_from_slice(recorded);
+                return Ok(());
+            }
+        }
+        Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "{} bytes at {address:#010x} were not recorded",
+                buffer.len()
+            ),
+        ))
+    }
+}
+
+impl Fixture {
+    pub fn load(path: &Path) -> io::Result<Fixture> {
+        serde_json::from_str(&fs::read_to_string(path)?)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+/// Records the accessors against the running game into `fixtures/<name>.json`, returns the path.
+///
+/// Must be called on the main thread.
+pub fn record(name: &str) -> io::Result<PathBuf> {
+    validate_resref("fixture", name)?;
+
+    let roots = Roots {
+        area: area::current_area().ok(),
+        camera: symbols::CAMERA_VIEW_PROJECTION.resolve().ok(),
+        module_name: module::module_name()
+            .ok()
+            .filter(|name| !name.is_null())
+            .map(|name| name as usize),
+    };
+    let recorder = Recorder {
+        memory: &Process,
+        regions: RefCell::new(Vec::new()),
+    };
+    let expected = read_state(&recorder, &roots);
+    if expected == State::default() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "Nothing could be read, load a save first",
+        ));
+    }
+
+    let fixture = Fixture {
+        build: game_build().to_string(),
+        roots,
+        expected,
+        regions: recorder.regions.into_inner(),
+    };
+    fs::create_dir_all(FIXTURE_DIRECTORY)?;
+    let path = Path::new(FIXTURE_DIRECTORY).join(format!("{name}.json"));
+    let json = serde_json::to_string_pretty(&fixture)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    fs::write(&path, json + "\n")?;
+    info!(
+        "Recorded {} regions to {}",
+        fixture.regions.len(),
+        path.display()
+    );
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replays_recorded_fixtures() {
+        let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("data/fixtures");
+        let mut replayed = 0;
+        for entry in fs::read_dir(directory).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_none_or(|extension| extension != "json") {
+                continue;
+            }
+
+            let fixture = Fixture::load(&path).unwrap();
+            assert_eq!(
+                read_state(&fixture, &fixture.roots),
+                fixture.expected,
+                "Accessors read {} differently",
+                path.display()
+            );
+            replayed += 1;
+        }
+        assert!(replayed > 0, "No fixtures in data/fixtures");
+    }
+
+    #[test]
+    fn unrecorded_reads_fail() {
+        let fixture = Fixture {
+            build: "Steam".to_string(),
+            roots: Roots::default(),
+            expected: State::default(),
+            regions: vec![Region {
+                address: 0x1000,
+                bytes: "01020304".to_string(),
+            }],
+        };
+        assert_eq!(fixture.read_u32(0x1000).unwrap(), 0x04030201);
+        let mut buffer = [0u8; 2];
+        fixture.read(0x1002, &mut buffer).unwrap();
+        assert_eq!(buffer, [3, 4]);
+        assert!(fixture.read(0x1003, &mut buffer).is_err());
+        assert!(fixture.read(0x0fff, &mut buffer).is_err());
+    }
+}
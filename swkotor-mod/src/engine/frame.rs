@@ -0,0 +1,125 @@
+/// Per frame callback on the game's main thread.
+///
+/// Engine is not thread safe, anything touching engine state has to run on the main thread.
+/// Other threads, like the console, queue their work here and it gets run on the next frame.
+/// Features that need to do something every frame register a callback instead.
+///
+/// Everything here runs inside the SwapBuffers hook, a panic unwinding into the game would abort
+/// it. Panicking tasks are dropped and panicking callbacks unregistered, the feature behind them
+/// stops while the game and the rest of the mod keep going.
+use std::{
+    collections::VecDeque,
+    io,
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Mutex},
+    time::{Duration, Instant},
+};
+
+use log::trace;
+
+use crate::system::status;
+
+type Task = Box<dyn FnOnce() + Send>;
+
+static TASKS: Mutex<Vec<Task>> = Mutex::new(Vec::new());
+
+static FRAME_CALLBACKS: Mutex<Vec<fn()>> = Mutex::new(Vec::new());
+
+/// Frame times kept for `frame_times`, four seconds at 60fps
+const FRAME_HISTORY: usize = 240;
+
+struct FrameTimes {
+    last_frame: Option<Instant>,
+    /// Oldest first
+    times: VecDeque<Duration>,
+}
+
+static FRAME_TIMES: Mutex<FrameTimes> = Mutex::new(FrameTimes {
+    last_frame: None,
+    times: VecDeque::new(),
+});
+
+/// Time between each of the last few seconds of frames, oldest first.
+pub fn frame_times() -> Vec<Duration> {
+    FRAME_TIMES.lock().unwrap().times.iter().copied().collect()
+}
+
+fn record_frame_time() {
+    let now = Instant::now();
+    let mut frame_times = FRAME_TIMES.lock().unwrap();
+    if let Some(last_frame) = frame_times.last_frame.replace(now) {
+        if frame_times.times.len() >= FRAME_HISTORY {
+            frame_times.times.pop_front();
+        }
+        frame_times.times.push_back(now.duration_since(last_frame));
+    }
+}
+
+/// Registers `callback` to be run on the main thread every frame, after queued tasks.
+pub fn on_every_frame(callback: fn()) {
+    FRAME_CALLBACKS.lock().unwrap().push(callback);
+}
+
+/// Queues `task` to be run on the main thread at the start of the next frame.
+pub fn run_on_main_thread<F>(task: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    TASKS.lock().unwrap().push(Box::new(task));
+}
+
+/// Runs `task` on the main thread and waits for its result. Gives up after `timeout`, e.g. when
+/// the game is stuck on a load or the frame hook isn't installed. The task still runs later.
+///
+/// Must not be called from the main thread, it would wait on itself.
+pub fn call_on_main_thread<F, T>(timeout: Duration, task: F) -> io::Result<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    run_on_main_thread(move || {
+        // Caller might have timed out already, nobody to tell
+        let _ = sender.send(task());
+    });
+
+    receiver.recv_timeout(timeout).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::TimedOut,
+            "Main thread did not get to the task in time",
+        )
+    })
+}
+
+/// Called from the SwapBuffers hook once per frame.
+pub(crate) fn on_frame() {
+    record_frame_time();
+
+    // Take the tasks out first, tasks are allowed to queue more work for the next frame
+    let tasks = std::mem::take(&mut *TASKS.lock().unwrap());
+
+    if !tasks.is_empty() {
+        trace!("Running {} queued tasks", tasks.len());
+    }
+
+    for task in tasks {
+        // The panic hook has logged what went wrong, the task is gone either way
+        let _ = panic::catch_unwind(AssertUnwindSafe(task));
+    }
+
+    // Copy the callbacks so they can register new ones without deadlocking
+    let callbacks = FRAME_CALLBACKS.lock().unwrap().clone();
+    for callback in callbacks {
+        if panic::catch_unwind(callback).is_err() {
+            let address = callback as usize;
+            FRAME_CALLBACKS
+                .lock()
+                .unwrap()
+                .retain(|&registered| registered as usize != address);
+            status::disable_feature(
+                &format!("frame callback {address:#x}"),
+                "panicked, see the log",
+            );
+        }
+    }
+}
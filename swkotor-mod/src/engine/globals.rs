@@ -0,0 +1,56 @@
+/// Live values of script globals and journal states, the same state a save stores in
+/// `GLOBALVARS.res` and `PARTYTABLE.res`.
+use std::{
+    ffi::{c_void, CString},
+    io,
+};
+
+use super::{module::server_exo_app, symbols, types::CExoString};
+
+type GetByNameFn =
+    unsafe extern "thiscall" fn(server_app: *mut c_void, name: *const CExoString) -> i32;
+type SetByNameFn =
+    unsafe extern "thiscall" fn(server_app: *mut c_void, name: *const CExoString, value: i32);
+
+fn get_by_name(symbol: &symbols::Symbol, name: &str) -> io::Result<i32> {
+    let get: GetByNameFn = unsafe { std::mem::transmute(symbol.resolve()?) };
+    let server_app = server_exo_app()?;
+    let name = CString::new(name)?;
+
+    Ok(unsafe { get(server_app, &CExoString::borrowed(&name)) })
+}
+
+fn set_by_name(symbol: &symbols::Symbol, name: &str, value: i32) -> io::Result<()> {
+    let set: SetByNameFn = unsafe { std::mem::transmute(symbol.resolve()?) };
+    let server_app = server_exo_app()?;
+    let name = CString::new(name)?;
+
+    unsafe { set(server_app, &CExoString::borrowed(&name), value) };
+    Ok(())
+}
+
+/// Reads a boolean global. Must be called on the main thread.
+pub fn global_boolean(name: &str) -> io::Result<bool> {
+    Ok(get_by_name(&symbols::GET_GLOBAL_BOOLEAN, name)? != 0)
+}
+
+/// Reads a number global, numbers are a byte each. Must be called on the main thread.
+pub fn global_number(name: &str) -> io::Result<u8> {
+    Ok(get_by_name(&symbols::GET_GLOBAL_NUMBER, name)? as u8)
+}
+
+/// Sets a boolean global. Must be called on the main thread.
+pub fn set_global_boolean(name: &str, value: bool) -> io::Result<()> {
+    set_by_name(&symbols::SET_GLOBAL_BOOLEAN, name, value as i32)
+}
+
+/// Sets a number global. Must be called on the main thread.
+pub fn set_global_number(name: &str, value: u8) -> io::Result<()> {
+    set_by_name(&symbols::SET_GLOBAL_NUMBER, name, value as i32)
+}
+
+/// Reads the journal state of the quest `plot_id`, zero when it's not in the journal. Must be
+/// called on the main thread.
+pub fn journal_state(plot_id: &str) -> io::Result<i32> {
+    get_by_name(&symbols::GET_JOURNAL_STATE, plot_id)
+}
@@ -0,0 +1,63 @@
+/// Helpers for hooking engine functions.
+use std::{
+    io,
+    sync::{Mutex, OnceLock},
+};
+
+use crate::mem::Detour;
+
+use super::symbols::Symbol;
+
+/// Every detour installed so far, in install order
+static INSTALLED: Mutex<Vec<&'static Detour>> = Mutex::new(Vec::new());
+
+/// Installs a detour on `symbol` once, later calls do nothing. Hooks get the original function
+/// through the stored detour's trampoline.
+///
+/// Must be called on the main thread.
+pub(crate) fn ensure_detour(
+    detour: &'static OnceLock<Detour>,
+    symbol: &Symbol,
+    prologue_length: usize,
+    hook_address: usize,
+) -> io::Result<()> {
+    if detour.get().is_some() {
+        return Ok(());
+    }
+
+    let installed = unsafe {
+        Detour::install(
+            symbol.name.to_string(),
+            symbol.resolve()?,
+            prologue_length,
+            hook_address,
+        )?
+    };
+    let _ = detour.set(installed);
+    if let Some(installed) = detour.get() {
+        INSTALLED.lock().unwrap().push(installed);
+    }
+    Ok(())
+}
+
+/// Names of the installed detours, in install order.
+pub(crate) fn installed_detours() -> Vec<String> {
+    INSTALLED
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|detour| detour.name().to_string())
+        .collect()
+}
+
+/// Restores every hooked function, newest first. The detours can't be installed again, this is
+/// only for unloading the mod.
+///
+/// Must be called on the main thread.
+pub(crate) fn uninstall_detours() -> io::Result<()> {
+    let mut installed = INSTALLED.lock().unwrap();
+    while let Some(detour) = installed.pop() {
+        unsafe { detour.uninstall()? };
+    }
+    Ok(())
+}
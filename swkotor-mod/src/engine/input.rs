@@ -0,0 +1,250 @@
+/// Keeps keyboard and mouse input away from the game while the mod's own UI has it.
+///
+/// The game reads the keyboard and mouse through DirectInput, which we already proxy. Once the
+/// game has its `IDirectInput8`, `CreateDevice` is hooked in its vtable and the keyboard and mouse
+/// devices it creates get their `GetDeviceState` and `GetDeviceData` hooked the same way. While
+/// anything registered with `capture_while` says so, the game sees no keys down, no mouse movement
+/// and no buffered events. Buffered events are drained rather than left queued, closing the UI
+/// must not replay everything typed into it.
+///
+/// Only keyboard and mouse devices are captured, gamepads are left alone.
+use std::{
+    ffi::c_void,
+    io,
+    mem::size_of,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+
+use log::{error, trace};
+use windows::core::{GUID, HRESULT};
+
+use crate::mem::{AppliedPatch, Patch};
+
+const POINTER_SIZE: usize = size_of::<usize>();
+
+/// `IDirectInput8A::CreateDevice`
+const CREATE_DEVICE_INDEX: usize = 3;
+/// `IDirectInputDevice8A::GetDeviceState`
+const GET_DEVICE_STATE_INDEX: usize = 9;
+/// `IDirectInputDevice8A::GetDeviceData`
+const GET_DEVICE_DATA_INDEX: usize = 10;
+
+const GUID_SYS_MOUSE: GUID = GUID::from_u128(0x6f1d2b60_d5a0_11cf_bfc7_444553540000);
+const GUID_SYS_KEYBOARD: GUID = GUID::from_u128(0x6f1d2b61_d5a0_11cf_bfc7_444553540000);
+
+type CreateDeviceFn = unsafe extern "system" fn(
+    this: *mut c_void,
+    guid: *const GUID,
+    device: *mut *mut c_void,
+    outer: *mut c_void,
+) -> HRESULT;
+type GetDeviceStateFn =
+    unsafe extern "system" fn(this: *mut c_void, size: u32, data: *mut c_void) -> HRESULT;
+type GetDeviceDataFn = unsafe extern "system" fn(
+    this: *mut c_void,
+    object_data_size: u32,
+    object_data: *mut c_void,
+    count: *mut u32,
+    flags: u32,
+) -> HRESULT;
+
+/// Vtable of a hooked device class and the functions its slots pointed to
+struct DeviceVtable {
+    address: usize,
+    get_device_state: GetDeviceStateFn,
+    get_device_data: GetDeviceDataFn,
+}
+
+/// Conditions for keeping input from the game, any one of them being true is enough
+static CAPTURES: Mutex<Vec<fn() -> bool>> = Mutex::new(Vec::new());
+
+/// The vtable slots pointing to our hooks, reverting a patch restores its slot
+static VTABLE_PATCHES: Mutex<Vec<AppliedPatch<POINTER_SIZE>>> = Mutex::new(Vec::new());
+
+// Address of the original CreateDevice, zero until hooked. Left in place on uninstall like
+// SwapBuffers.
+static REAL_CREATE_DEVICE: AtomicUsize = AtomicUsize::new(0);
+
+static DEVICE_VTABLES: Mutex<Vec<DeviceVtable>> = Mutex::new(Vec::new());
+
+/// Keyboard and mouse devices the game created, other devices share their vtable
+static CAPTURED_DEVICES: Mutex<Vec<usize>> = Mutex::new(Vec::new());
+
+/// Keeps keyboard and mouse input from the game whenever `condition` returns true. Called from
+/// the game's input polling, keep it cheap.
+pub fn capture_while(condition: fn() -> bool) {
+    CAPTURES.lock().unwrap().push(condition);
+}
+
+/// Whether input is currently kept from the game.
+pub fn captured() -> bool {
+    // Copy the conditions out, they may well take locks of their own
+    let captures = CAPTURES.lock().unwrap().clone();
+    captures.iter().any(|condition| condition())
+}
+
+pub fn input_hooked() -> bool {
+    !VTABLE_PATCHES.lock().unwrap().is_empty()
+}
+
+/// Points the vtable slot `index` of `object` to `hook`, returns what the slot pointed to.
+unsafe fn patch_vtable_slot(object: *mut c_void, index: usize, hook: usize) -> io::Result<usize> {
+    let vtable = *(object as *const usize);
+    let slot = vtable + index * POINTER_SIZE;
+    let original = *(slot as *const usize);
+
+    let patch = Patch::bytes(
+        format!("vtable {vtable:#010x} slot {index}"),
+        slot,
+        original.to_le_bytes(),
+        hook.to_le_bytes(),
+    );
+    let applied = patch.apply()?;
+    VTABLE_PATCHES.lock().unwrap().push(applied);
+    Ok(original)
+}
+
+/// Vtable address and the originals for a device, `None` if its vtable isn't hooked.
+fn device_vtable<T>(this: *mut c_void, original: impl Fn(&DeviceVtable) -> T) -> Option<T> {
+    let address = unsafe { *(this as *const usize) };
+    DEVICE_VTABLES
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|vtable| vtable.address == address)
+        .map(original)
+}
+
+fn is_captured_device(this: *mut c_void) -> bool {
+    CAPTURED_DEVICES.lock().unwrap().contains(&(this as usize)) && captured()
+}
+
+unsafe extern "system" fn my_get_device_state(
+    this: *mut c_void,
+    size: u32,
+    data: *mut c_void,
+) -> HRESULT {
+    let Some(real_fn) = device_vtable(this, |vtable| vtable.get_device_state) else {
+        error!("Cannot run GetDeviceState. Bug. No hook stored");
+        return HRESULT(-1);
+    };
+
+    let result = real_fn(this, size, data);
+    if result.is_ok() && !data.is_null() && is_captured_device(this) {
+        // Nothing pressed and no movement
+        std::ptr::write_bytes(data as *mut u8, 0, size as usize);
+    }
+    result
+}
+
+unsafe extern "system" fn my_get_device_data(
+    this: *mut c_void,
+    object_data_size: u32,
+    object_data: *mut c_void,
+    count: *mut u32,
+    flags: u32,
+) -> HRESULT {
+    let Some(real_fn) = device_vtable(this, |vtable| vtable.get_device_data) else {
+        error!("Cannot run GetDeviceData. Bug. No hook stored");
+        return HRESULT(-1);
+    };
+
+    let result = real_fn(this, object_data_size, object_data, count, flags);
+    if result.is_ok() && !count.is_null() && is_captured_device(this) {
+        // The events were read off the buffer all the same, the game just never hears of them
+        *count = 0;
+    }
+    result
+}
+
+/// Hooks the vtable of a new device, devices of the same class share one and it's hooked once.
+unsafe fn hook_device(device: *mut c_void) -> io::Result<()> {
+    let address = *(device as *const usize);
+    if DEVICE_VTABLES
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|vtable| vtable.address == address)
+    {
+        return Ok(());
+    }
+
+    let get_device_state = patch_vtable_slot(
+        device,
+        GET_DEVICE_STATE_INDEX,
+        my_get_device_state as GetDeviceStateFn as usize,
+    )?;
+    let get_device_data = patch_vtable_slot(
+        device,
+        GET_DEVICE_DATA_INDEX,
+        my_get_device_data as GetDeviceDataFn as usize,
+    )?;
+    DEVICE_VTABLES.lock().unwrap().push(DeviceVtable {
+        address,
+        get_device_state: std::mem::transmute::<usize, GetDeviceStateFn>(get_device_state),
+        get_device_data: std::mem::transmute::<usize, GetDeviceDataFn>(get_device_data),
+    });
+    Ok(())
+}
+
+unsafe extern "system" fn my_create_device(
+    this: *mut c_void,
+    guid: *const GUID,
+    device: *mut *mut c_void,
+    outer: *mut c_void,
+) -> HRESULT {
+    let real_fn = match REAL_CREATE_DEVICE.load(Ordering::Acquire) {
+        0 => {
+            error!("Cannot run CreateDevice. Bug. No hook stored");
+            return HRESULT(-1);
+        }
+        address => std::mem::transmute::<usize, CreateDeviceFn>(address),
+    };
+
+    let result = real_fn(this, guid, device, outer);
+    if result.is_err() || guid.is_null() || device.is_null() || (*device).is_null() {
+        return result;
+    }
+    if *guid != GUID_SYS_KEYBOARD && *guid != GUID_SYS_MOUSE {
+        return result;
+    }
+
+    trace!("Game created input device {:?}", *guid);
+    CAPTURED_DEVICES.lock().unwrap().push(*device as usize);
+    if let Err(e) = hook_device(*device) {
+        error!("Could not hook input device, the game keeps its input under the overlay. {e}");
+    }
+    result
+}
+
+/// Hooks `CreateDevice` of the `IDirectInput8A` the game just created.
+pub(crate) unsafe fn hook_direct_input(direct_input: *mut c_void) {
+    if direct_input.is_null() || REAL_CREATE_DEVICE.load(Ordering::Acquire) != 0 {
+        return;
+    }
+
+    match patch_vtable_slot(
+        direct_input,
+        CREATE_DEVICE_INDEX,
+        my_create_device as CreateDeviceFn as usize,
+    ) {
+        Ok(original) => REAL_CREATE_DEVICE.store(original, Ordering::Release),
+        Err(e) => {
+            error!("Could not hook DirectInput, the game keeps its input under the overlay. {e}")
+        }
+    }
+}
+
+/// Puts every hooked vtable slot back, newest first.
+///
+/// Must be called on the main thread.
+pub(crate) fn uninstall_input_hooks() -> io::Result<()> {
+    let mut patches = VTABLE_PATCHES.lock().unwrap();
+    while let Some(patch) = patches.pop() {
+        unsafe { patch.revert()? };
+    }
+    Ok(())
+}
@@ -0,0 +1,63 @@
+/// Item creation.
+use std::{
+    ffi::{c_void, CString},
+    io,
+};
+
+use log::info;
+
+use super::{
+    party, symbols,
+    types::{validate_resref, CExoString},
+};
+
+/// Largest stack the engine allows for any item.
+pub const MAX_STACK_SIZE: u16 = 100;
+
+type CreateItemOnObjectFn = unsafe extern "thiscall" fn(
+    creature: *mut c_void,
+    template: *const CExoString,
+    stack_size: u16,
+) -> *mut c_void;
+
+/// Checks the arguments for `give_item` without touching the engine.
+pub fn validate_item(template: &str, count: u16) -> io::Result<()> {
+    validate_resref("item template", template)?;
+
+    if count == 0 || count > MAX_STACK_SIZE {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Item count has to be between 1 and {MAX_STACK_SIZE}"),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Creates `count` items from `template` into the party inventory. The inventory is shared, it's
+/// owned by the party leader.
+///
+/// Must be called on the main thread.
+pub fn give_item(template: &str, count: u16) -> io::Result<()> {
+    validate_item(template, count)?;
+
+    let create_item: CreateItemOnObjectFn =
+        unsafe { std::mem::transmute(symbols::CREATE_ITEM_ON_OBJECT.resolve()?) };
+    let leader = *party::active_party()?
+        .first()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Party is empty"))?;
+
+    let name = CString::new(template.to_ascii_lowercase())?;
+
+    info!("Giving {count}x {template}");
+    let item = unsafe { create_item(leader, &CExoString::borrowed(&name), count) };
+
+    if item.is_null() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("Engine could not create item {template}, is the template correct?"),
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+/// Quests the game knows about and every script global, for inspecting quest progression live.
+///
+/// Quests come from `global.jrl`, the journal file listing every quest with its entries. A quest
+/// is in the journal when its state is non-zero, the state being the id of the entry shown. The
+/// globals come from `globalcat.2da`, which lists every global a save stores, including the plot
+/// variables the journal never shows.
+use std::io;
+
+use crate::formats::gff::{Gff, GffStruct, GffValue};
+
+use super::{
+    globals,
+    resources::{read_resource, ResourceType},
+    tables::read_table,
+    types::CResRef,
+};
+
+/// StrRef of localized strings that have none
+const NO_STRREF: u32 = u32::MAX;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuestEntry {
+    pub id: i32,
+    /// The quest is done once it reaches an end entry
+    pub end: bool,
+    pub text: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quest {
+    /// Plot id scripts and `journal_state` use
+    pub tag: String,
+    pub name: u32,
+    pub entries: Vec<QuestEntry>,
+}
+
+impl Quest {
+    pub fn entry(&self, id: i32) -> Option<&QuestEntry> {
+        self.entries.iter().find(|entry| entry.id == id)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlobalKind {
+    Boolean,
+    Number,
+}
+
+fn strref(quest: &GffStruct, label: &str) -> u32 {
+    match quest.get(label) {
+        Some(GffValue::LocString(text)) => text.strref,
+        _ => NO_STRREF,
+    }
+}
+
+fn parse_quest(category: &GffStruct) -> Quest {
+    Quest {
+        tag: category.get_str("Tag").unwrap_or_default().to_string(),
+        name: strref(category, "Name"),
+        entries: category
+            .get_list("EntryList")
+            .iter()
+            .map(|entry| QuestEntry {
+                id: entry.get_i64("ID").unwrap_or_default() as i32,
+                end: entry.get_i64("End").unwrap_or_default() != 0,
+                text: strref(entry, "Text"),
+            })
+            .collect(),
+    }
+}
+
+/// Reads every quest in `global.jrl`. Must be called on the main thread.
+pub fn quests() -> io::Result<Vec<Quest>> {
+    let journal = Gff::parse(&read_resource(
+        &CResRef::new("journal", "global")?,
+        ResourceType::Jrl,
+    )?)?;
+    Ok(journal
+        .root
+        .get_list("Categories")
+        .iter()
+        .map(parse_quest)
+        .collect())
+}
+
+/// Names and kinds of every global in `globalcat.2da`. Must be called on the main thread.
+pub fn global_catalog() -> io::Result<Vec<(String, GlobalKind)>> {
+    let table = read_table("globalcat")?;
+    Ok((0..table.rows.len())
+        .filter_map(|row| {
+            let kind = match table.get(row, "type")? {
+                "Boolean" => GlobalKind::Boolean,
+                "Number" => GlobalKind::Number,
+                // Strings and locations, no script sets those in KOTOR
+                _ => return None,
+            };
+            Some((table.get(row, "name")?.to_string(), kind))
+        })
+        .collect())
+}
+
+/// Sets a global of either kind from a number, booleans being set by non-zero. Must be called on
+/// the main thread.
+pub fn set_global_value(name: &str, kind: GlobalKind, value: i32) -> io::Result<()> {
+    match kind {
+        GlobalKind::Boolean => globals::set_global_boolean(name, value != 0),
+        GlobalKind::Number => globals::set_global_number(name, value as u8),
+    }
+}
+
+/// Reads a global of either kind as a number. Must be called on the main thread.
+pub fn global_value(name: &str, kind: GlobalKind) -> io::Result<i32> {
+    match kind {
+        GlobalKind::Boolean => globals::global_boolean(name).map(i32::from),
+        GlobalKind::Number => globals::global_number(name).map(i32::from),
+    }
+}
@@ -0,0 +1,264 @@
+/// Load time telemetry.
+///
+/// Loads are measured from the load screen being shown until it's taken down. Every finished load
+/// is logged and passed on to the registered listeners, the speedrun timer uses this to remove
+/// loads from IGT. The session keeps a history of loads with the module each one loaded, to
+/// compare how long modules take to load across machines.
+///
+/// Until the load screen functions are located, loads are guessed from long gaps between frames
+/// instead. The game only redraws the loading screen when the progress bar moves.
+use std::{
+    ffi::c_void,
+    io,
+    sync::{Mutex, OnceLock},
+    time::{Duration, Instant},
+};
+
+use log::{error, info, warn};
+
+use crate::mem::Detour;
+
+use super::{frame, hooks::ensure_detour, module, symbols, trace};
+
+// Verify against the disassembly when locating the functions
+const SHOW_LOAD_SCREEN_PROLOGUE_LENGTH: usize = 6;
+const HIDE_LOAD_SCREEN_PROLOGUE_LENGTH: usize = 6;
+
+/// Frame gaps longer than this are counted as loads when the load screen isn't hooked. Normal
+/// hitches stay well under this.
+const LOAD_GAP_THRESHOLD: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LoadStats {
+    pub count: u32,
+    pub total: Duration,
+    pub longest: Duration,
+    pub last: Option<Duration>,
+}
+
+impl LoadStats {
+    const fn new() -> Self {
+        LoadStats {
+            count: 0,
+            total: Duration::ZERO,
+            longest: Duration::ZERO,
+            last: None,
+        }
+    }
+
+    fn record(&mut self, duration: Duration) {
+        self.count += 1;
+        self.total += duration;
+        self.longest = self.longest.max(duration);
+        self.last = Some(duration);
+    }
+
+    pub fn mean(&self) -> Duration {
+        match self.count {
+            0 => Duration::ZERO,
+            count => self.total / count,
+        }
+    }
+}
+
+/// A finished load
+#[derive(Debug, Clone, PartialEq)]
+pub struct Load {
+    /// Module loaded, empty e.g. when a load ended in the main menu
+    pub module: String,
+    pub duration: Duration,
+}
+
+static STATS: Mutex<LoadStats> = Mutex::new(LoadStats::new());
+
+/// Every load of the session, oldest first
+static HISTORY: Mutex<Vec<Load>> = Mutex::new(Vec::new());
+
+/// When the load screen currently up was shown
+static LOAD_STARTED: Mutex<Option<Instant>> = Mutex::new(None);
+
+static LAST_FRAME: Mutex<Option<Instant>> = Mutex::new(None);
+
+static LISTENERS: Mutex<Vec<fn(Duration)>> = Mutex::new(Vec::new());
+
+static SHOW_LOAD_SCREEN_DETOUR: OnceLock<Detour> = OnceLock::new();
+static HIDE_LOAD_SCREEN_DETOUR: OnceLock<Detour> = OnceLock::new();
+
+type LoadScreenFn = unsafe extern "thiscall" fn(load_screen: *mut c_void);
+
+/// Registers `listener` to be called with the duration of every finished load.
+pub fn on_load_finished(listener: fn(Duration)) {
+    LISTENERS.lock().unwrap().push(listener);
+}
+
+/// Returns the loads measured since the game started.
+pub fn stats() -> LoadStats {
+    *STATS.lock().unwrap()
+}
+
+/// Returns every load of the session, oldest first.
+pub fn history() -> Vec<Load> {
+    HISTORY.lock().unwrap().clone()
+}
+
+/// Statistics of every module loaded, in the order they were first loaded.
+fn group_by_module(history: &[Load]) -> Vec<(String, LoadStats)> {
+    let mut modules: Vec<(String, LoadStats)> = Vec::new();
+    for load in history {
+        match modules
+            .iter_mut()
+            .find(|(module, _)| *module == load.module)
+        {
+            Some((_, stats)) => stats.record(load.duration),
+            None => {
+                let mut stats = LoadStats::new();
+                stats.record(load.duration);
+                modules.push((load.module.clone(), stats));
+            }
+        }
+    }
+    modules
+}
+
+/// Returns the loads of the session by module, in the order modules were first loaded.
+pub fn stats_by_module() -> Vec<(String, LoadStats)> {
+    group_by_module(&HISTORY.lock().unwrap())
+}
+
+/// Returns true while the load screen is up. Always false when loads are guessed from frame gaps.
+pub fn is_loading() -> bool {
+    LOAD_STARTED.lock().unwrap().is_some()
+}
+
+/// Runs on the main thread, from the load screen hook or the frame after a long gap.
+fn finish_load(duration: Duration) {
+    // The module is up by the time its load screen comes down. Without the module name function
+    // located loads are still kept, just without their module.
+    let module = module::current_module().ok().flatten().unwrap_or_default();
+
+    STATS.lock().unwrap().record(duration);
+    HISTORY.lock().unwrap().push(Load {
+        module: module.clone(),
+        duration,
+    });
+    info!("Load of {module} took {duration:?}");
+
+    // Copy the listeners out, they're free to register more
+    let listeners = LISTENERS.lock().unwrap().clone();
+    for listener in listeners {
+        listener(duration);
+    }
+}
+
+unsafe extern "thiscall" fn show_load_screen_hook(load_screen: *mut c_void) {
+    trace::call(
+        &symbols::SHOW_LOAD_SCREEN,
+        || format!("load_screen={load_screen:?}"),
+        || {
+            if LOAD_STARTED
+                .lock()
+                .unwrap()
+                .replace(Instant::now())
+                .is_some()
+            {
+                warn!("Load screen shown while already loading, restarting the measurement");
+            }
+
+            let detour = SHOW_LOAD_SCREEN_DETOUR
+                .get()
+                .expect("hook should not run before the detour is installed");
+            let original: LoadScreenFn = std::mem::transmute(detour.trampoline());
+            original(load_screen)
+        },
+    )
+}
+
+unsafe extern "thiscall" fn hide_load_screen_hook(load_screen: *mut c_void) {
+    trace::call(
+        &symbols::HIDE_LOAD_SCREEN,
+        || format!("load_screen={load_screen:?}"),
+        || {
+            let detour = HIDE_LOAD_SCREEN_DETOUR
+                .get()
+                .expect("hook should not run before the detour is installed");
+            let original: LoadScreenFn = std::mem::transmute(detour.trampoline());
+            original(load_screen);
+
+            let started = LOAD_STARTED.lock().unwrap().take();
+            match started {
+                Some(started) => finish_load(started.elapsed()),
+                None => warn!("Load screen hidden without being shown"),
+            }
+        },
+    )
+}
+
+fn detect_load_from_frame_gap() {
+    let now = Instant::now();
+    let last_frame = LAST_FRAME.lock().unwrap().replace(now);
+
+    if let Some(last_frame) = last_frame {
+        let gap = now.saturating_duration_since(last_frame);
+        if gap > LOAD_GAP_THRESHOLD {
+            finish_load(gap);
+        }
+    }
+}
+
+pub(super) fn install_load_screen_hooks() -> io::Result<()> {
+    ensure_detour(
+        &SHOW_LOAD_SCREEN_DETOUR,
+        &symbols::SHOW_LOAD_SCREEN,
+        SHOW_LOAD_SCREEN_PROLOGUE_LENGTH,
+        show_load_screen_hook as LoadScreenFn as usize,
+    )?;
+    ensure_detour(
+        &HIDE_LOAD_SCREEN_DETOUR,
+        &symbols::HIDE_LOAD_SCREEN,
+        HIDE_LOAD_SCREEN_PROLOGUE_LENGTH,
+        hide_load_screen_hook as LoadScreenFn as usize,
+    )
+}
+
+/// Starts measuring loads. Must be called on the main thread.
+pub fn init() {
+    // Both symbols are checked up front, hooking only one end would never finish a load
+    let resolved = symbols::SHOW_LOAD_SCREEN
+        .resolve()
+        .and(symbols::HIDE_LOAD_SCREEN.resolve());
+
+    match resolved.and_then(|_| install_load_screen_hooks()) {
+        Ok(()) => info!("Measuring loads from the load screen"),
+        Err(e) => {
+            error!("Could not hook the load screen, guessing loads from frame gaps: {e}");
+            frame::on_every_frame(detect_load_from_frame_gap);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_loads_by_module() {
+        let load = |module: &str, millis: u64| Load {
+            module: module.to_string(),
+            duration: Duration::from_millis(millis),
+        };
+        let history = [
+            load("end_m01aa", 3000),
+            load("end_m01ab", 1000),
+            load("end_m01aa", 5000),
+        ];
+
+        let modules = group_by_module(&history);
+        assert_eq!(modules.len(), 2);
+        let (module, stats) = &modules[0];
+        assert_eq!(module, "end_m01aa");
+        assert_eq!(stats.count, 2);
+        assert_eq!(stats.mean(), Duration::from_millis(4000));
+        assert_eq!(stats.longest, Duration::from_millis(5000));
+        assert_eq!(modules[1].1.mean(), Duration::from_millis(1000));
+    }
+}
@@ -0,0 +1,110 @@
+/// Engine stand-in for unit tests. Keeps just enough state for the calls to be checked
+/// afterwards and validates arguments the same way the real functions do before they reach the
+/// engine.
+use std::{cell::RefCell, collections::HashMap, io};
+
+use super::{
+    api::EngineApi, items::validate_item, module::validate_module_name, objects::ObjectKind,
+    party::MemberStats, types::validate_resref,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpawnedObject {
+    pub id: u32,
+    pub kind: ObjectKind,
+    pub template: String,
+    pub position: [f32; 3],
+}
+
+#[derive(Debug, Default)]
+pub struct MockEngine {
+    pub module: RefCell<Option<String>>,
+    pub party: Vec<MemberStats>,
+    pub leader_position: [f32; 3],
+    pub booleans: RefCell<HashMap<String, bool>>,
+    pub numbers: RefCell<HashMap<String, u8>>,
+    /// Templates and counts given, in order
+    pub items: RefCell<Vec<(String, u16)>>,
+    pub spawned: RefCell<Vec<SpawnedObject>>,
+    pub movies: RefCell<Vec<String>>,
+}
+
+fn unknown_global(name: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::NotFound, format!("No global named {name}"))
+}
+
+impl EngineApi for MockEngine {
+    fn current_module(&self) -> io::Result<Option<String>> {
+        Ok(self.module.borrow().clone())
+    }
+
+    fn warp(&self, module: &str) -> io::Result<()> {
+        validate_module_name(module)?;
+        *self.module.borrow_mut() = Some(module.to_string());
+        Ok(())
+    }
+
+    fn party_stats(&self) -> io::Result<Vec<MemberStats>> {
+        Ok(self.party.clone())
+    }
+
+    fn leader_position(&self) -> io::Result<[f32; 3]> {
+        Ok(self.leader_position)
+    }
+
+    fn give_item(&self, template: &str, count: u16) -> io::Result<()> {
+        validate_item(template, count)?;
+        self.items.borrow_mut().push((template.to_string(), count));
+        Ok(())
+    }
+
+    fn global_boolean(&self, name: &str) -> io::Result<bool> {
+        self.booleans
+            .borrow()
+            .get(name)
+            .copied()
+            .ok_or_else(|| unknown_global(name))
+    }
+
+    fn global_number(&self, name: &str) -> io::Result<u8> {
+        self.numbers
+            .borrow()
+            .get(name)
+            .copied()
+            .ok_or_else(|| unknown_global(name))
+    }
+
+    fn set_global_boolean(&self, name: &str, value: bool) -> io::Result<()> {
+        self.booleans.borrow_mut().insert(name.to_string(), value);
+        Ok(())
+    }
+
+    fn set_global_number(&self, name: &str, value: u8) -> io::Result<()> {
+        self.numbers.borrow_mut().insert(name.to_string(), value);
+        Ok(())
+    }
+
+    fn spawn_object(
+        &self,
+        kind: ObjectKind,
+        template: &str,
+        position: [f32; 3],
+    ) -> io::Result<u32> {
+        validate_resref("template", template)?;
+        let mut spawned = self.spawned.borrow_mut();
+        let id = spawned.len() as u32 + 1;
+        spawned.push(SpawnedObject {
+            id,
+            kind,
+            template: template.to_string(),
+            position,
+        });
+        Ok(id)
+    }
+
+    fn play_movie(&self, movie: &str) -> io::Result<()> {
+        validate_resref("movie", movie)?;
+        self.movies.borrow_mut().push(movie.to_string());
+        Ok(())
+    }
+}
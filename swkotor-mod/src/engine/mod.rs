@@ -1,9 +1,50 @@
+pub mod api;
+pub mod area;
+pub mod bindings;
+pub mod build;
+pub mod camera;
+pub mod cheats;
+pub mod clock;
+pub mod dialog;
 mod dinput8_dll;
+pub mod display;
+pub mod fixtures;
+pub mod frame;
+pub mod globals;
+mod hooks;
+pub mod input;
+pub mod items;
+pub mod journal;
 mod kotor;
+pub mod loading;
+#[cfg(test)]
+pub mod mock;
+pub mod models;
+pub mod module;
+pub mod movement;
+pub mod objects;
+pub mod overrides;
+pub mod party;
+pub mod pathfinding;
+pub mod pointers;
+pub mod resources;
+pub mod rng;
+pub mod saves;
+pub mod snapshots;
+pub mod stats;
+pub mod strings;
+mod symbols;
+pub mod tables;
+pub mod textures;
+pub mod trace;
+pub mod types;
+pub mod watch;
 use std::{
-    sync::{LazyLock, Mutex},
+    io,
+    path::Path,
+    sync::{Mutex, OnceLock},
     thread,
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use dinput8_dll::DirectInput8CreateFn;
@@ -11,12 +52,29 @@ use env_logger::Env;
 use kotor::filter_resolutions;
 use log::trace;
 
-use crate::liveqa;
+use crate::{audio, config::config, liveqa, overlay, plugins, savegame, speedrun, telemetry};
 use crate::{
-    mem::Patch,
-    system::dll_loader::{get_proc_address, load_system_library_a, DllLibrary},
+    mem::{AppliedPatch, Patch},
+    system::{
+        about,
+        dll_loader::{get_proc_address, load_system_library_a, DllLibrary},
+        hardware, ipc,
+        log_writer::AsyncLogWriter,
+        memory, metrics, services, status, websocket, window,
+    },
+    util::iat::{
+        miles::uninstall_miles_hooks,
+        swapbuffers::{install_swapbuffers_hook, uninstall_swapbuffers_hook},
+    },
 };
 
+/// How long to wait for the DRM to decrypt the code the patches expect. Decrypting is done well
+/// before the main menu, patches not matching by then won't ever.
+const DRM_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Patches applied once the DRM is done, kept for reverting them on unload
+static APPLIED_PATCHES: Mutex<Vec<AppliedPatch<5>>> = Mutex::new(Vec::new());
+
 // Holds the global state of our mod engine.
 //
 // Throughout the sources you'll find the plain windows functions in pascal case and snake case.
@@ -45,12 +103,20 @@ impl SWKotorModEngine {
         trace!("Done loading engine libraries");
 
         unsafe {
-            let patches = vec![Patch::call_instruction_to_function(
-                "filter_resolutions - 0x006e09a8".to_string(),
-                [0xe8, 0x03, 0xd9, 0xf0, 0xff],
-                0x006e09a8,
-                filter_resolutions,
-            )];
+            // Patches come from the symbol file of the build. Ones not located for it stay off,
+            // the rest of the mod copes with missing symbols the same way.
+            let patches = match symbols::patch_site("filter_resolutions") {
+                Ok(site) => vec![Patch::call_instruction_to_function(
+                    format!("filter_resolutions - {:#010x}", site.address),
+                    site.original,
+                    site.address,
+                    filter_resolutions,
+                )],
+                Err(e) => {
+                    status::disable_feature("filter_resolutions", e);
+                    Vec::new()
+                }
+            };
 
             // SteamWorks DRM encrypts the executable, postpone patching until it's done. I haven't
             // found a better way than to just poll in a quick loop.
@@ -60,20 +126,29 @@ impl SWKotorModEngine {
             // miss the whole initialization and might cause bugs due to timing issues.
             //
             // TODO(tatu): Maybe we could hook to bink dll as videos are played first?
-            let _handle = thread::spawn(move || loop {
-                thread::sleep(Duration::from_secs(1));
-
-                if patches.iter().all(|p| p.can_apply()) {
-                    trace!("Safe to apply patches, applying");
-                    patches.iter().for_each(|p| {
-                        trace!("Applying patch");
-                        p.apply().expect("patch should have applied");
-                    });
+            //
+            // Patches that never match mean a build we don't know. After `DRM_TIMEOUT` the
+            // patches are given up on and everything else starts anyway.
+            let spawned = services::spawn("patcher", move || {
+                let started = Instant::now();
+                while !services::stopping() {
+                    thread::sleep(Duration::from_secs(1));
+
+                    let matching = patches.iter().all(|p| p.can_apply());
+                    if !matching && started.elapsed() < DRM_TIMEOUT {
+                        trace!("Patches don't match, are you on steam?");
+                        continue;
+                    }
+
+                    trace!("Done waiting for the DRM, applying patches");
+                    apply_patches(&patches);
+                    start_features();
                     break;
-                } else {
-                    trace!("Patches don't match, are you on steam?");
                 }
             });
+            if let Err(e) = spawned {
+                log::error!("Could not start waiting for the DRM, nothing gets patched. {e}");
+            }
         }
 
         SWKotorModEngine {
@@ -83,20 +158,162 @@ impl SWKotorModEngine {
 }
 
 fn setup_logging() {
-    // Dump all logs to a file. For that, we'll need a pipe to pass to env_logger.
-    let file = std::fs::File::create("swkotor-mod.log")
-        .expect("Failed to initialize logging file for piping.");
+    // Dump all logs to a file. For that, we'll need a pipe to pass to env_logger. Writing happens
+    // on a background thread, logging from hooks must not wait on the disk.
+    //
+    // Without a log file the mod still runs, logging to stderr where nobody will see it.
+    let writer = match AsyncLogWriter::spawn(Path::new("swkotor-mod.log")) {
+        Ok(writer) => Some(writer),
+        Err(e) => {
+            eprintln!("Could not open swkotor-mod.log, logging to stderr. {e}");
+            None
+        }
+    };
     let mut builder = env_logger::Builder::from_env(Env::default().default_filter_or("trace"));
-    builder.target(env_logger::Target::Pipe(Box::new(file)));
-    builder.init();
+    if let Some(writer) = &writer {
+        builder.target(env_logger::Target::Pipe(Box::new(writer.clone())));
+    }
+    status::init_logger(builder.build());
+    status::install_panic_hook();
+
+    // Only now, reading the config and detecting the build log and need the logger up
+    if let Some(mut writer) = writer {
+        let header: String = about::summary()
+            .iter()
+            .map(|line| format!("==== {line}\n"))
+            .collect();
+        if let Err(e) = writer.set_header(&header) {
+            log::error!("Could not write the log header: {e}");
+        }
+    }
+}
+
+/// Applies the patches that match, the ones that don't are left off. Patches failing must not
+/// keep the rest of the mod from starting.
+unsafe fn apply_patches(patches: &[Patch<5>]) {
+    let mut applied = APPLIED_PATCHES.lock().unwrap();
+    for patch in patches {
+        if !patch.can_apply() {
+            status::disable_feature(
+                patch.name(),
+                "original code didn't match, is this an unknown build?",
+            );
+            continue;
+        }
+
+        trace!("Applying patch {}", patch.name());
+        match patch.apply() {
+            Ok(applied_patch) => applied.push(applied_patch),
+            Err(e) => status::disable_feature(patch.name(), e),
+        }
+    }
 }
 
-// TODO(tatu): Provide a more ergonomic function for this?
-pub static SW_KOTOR_MOD_ENGINE: LazyLock<Mutex<SWKotorModEngine>> = LazyLock::new(|| {
+/// Starts the features that hook the engine. Each one copes with its symbols missing on its own.
+fn start_features() {
+    if let Err(e) = install_swapbuffers_hook() {
+        log::error!("Could not hook SwapBuffers, queued engine calls won't run. {e}");
+    }
+    frame::run_on_main_thread(loading::init);
+    frame::run_on_main_thread(module::init);
+    frame::run_on_main_thread(pointers::init);
+    frame::run_on_main_thread(overrides::init);
+    frame::run_on_main_thread(textures::init);
+    frame::run_on_main_thread(display::init);
+    frame::run_on_main_thread(tables::init);
+    frame::run_on_main_thread(strings::init);
+    frame::run_on_main_thread(rng::init);
+    frame::run_on_main_thread(audio::init);
+    frame::run_on_main_thread(saves::init);
+    // Loading libraries from DllMain under the loader lock risks deadlocks
+    frame::run_on_main_thread(plugins::init);
+    if config().window.borderless {
+        frame::run_on_main_thread(|| {
+            if let Err(e) = window::set_borderless(true) {
+                log::error!("Could not make the window borderless. {e}");
+            }
+        });
+    }
+}
+
+/// Leaves the engine as it was before the mod touched it: removes every hook and patch and turns
+/// off what changed engine state outside of them. Used for unloading the mod.
+///
+/// Must be called on the main thread.
+pub(crate) fn uninstall_hooks() -> io::Result<()> {
+    trace!("Uninstalling hooks");
+    cheats::set_noclip(false)?;
+    if window::borderless_enabled() {
+        window::set_borderless(false)?;
+    }
+    hooks::uninstall_detours()?;
+
+    let mut applied = APPLIED_PATCHES.lock().unwrap();
+    while let Some(patch) = applied.pop() {
+        unsafe { patch.revert()? };
+    }
+
+    input::uninstall_input_hooks()?;
+    uninstall_miles_hooks();
+    uninstall_swapbuffers_hook();
+    Ok(())
+}
+
+// Engine is only written once, on initialization. It's read from DllMain and from the game's
+// calls into our exports, those must never wait on a lock. Anything that changes later keeps its
+// own state and lock.
+static SW_KOTOR_MOD_ENGINE: OnceLock<SWKotorModEngine> = OnceLock::new();
+
+/// Returns the engine, initializing the whole mod on first call. Safe to call multiple times.
+pub fn sw_kotor_mod_engine() -> &'static SWKotorModEngine {
+    SW_KOTOR_MOD_ENGINE.get_or_init(initialize)
+}
+
+fn initialize() -> SWKotorModEngine {
     // Is this safe to do here?
     setup_logging();
+    hardware::probe();
 
     liveqa::runner::run_live_qa_tests();
 
-    Mutex::new(SWKotorModEngine::new())
-});
+    liveqa::commands::register_commands();
+    liveqa::console::spawn_console();
+    liveqa::hotkeys::spawn_hotkey_listener(liveqa::hotkeys::DEFAULT_HOTKEYS);
+    liveqa::gamepad::spawn_gamepad_listener();
+    ipc::spawn_ipc_server();
+    websocket::spawn_websocket_server();
+    metrics::spawn_metrics_server();
+    memory::spawn_memory_sampler();
+
+    speedrun::timer::init();
+    speedrun::plan::init();
+    overlay::theme::init();
+    overlay::timer::init();
+    overlay::walkmesh::init();
+    overlay::volumes::init();
+    overlay::route::init();
+    overlay::scene::init();
+    overlay::minimap::init();
+    overlay::plan::init();
+    overlay::memory::init();
+    overlay::rng::init();
+    overlay::ghost::init();
+    overlay::clock::init();
+    overlay::sheet::init();
+    overlay::savegame::init();
+    overlay::journal::init();
+    overlay::nameplates::init();
+    overlay::watch::init();
+    overlay::loads::init();
+    overlay::transform::init();
+    overlay::menu::init();
+    overlay::alerts::init();
+    overlay::banner::init();
+    savegame::init();
+    telemetry::timeline::init();
+    telemetry::alerts::init();
+    liveqa::smoke::init();
+    liveqa::quickstart::init();
+
+    SWKotorModEngine::new()
+}
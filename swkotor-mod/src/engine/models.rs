@@ -0,0 +1,48 @@
+/// Model diagnostics: which model an object is drawn with and what its headers say.
+use std::{ffi::c_void, io};
+
+use crate::formats::mdl::ModelHeader;
+
+use super::{
+    resources::{read_resource, ResourceType},
+    symbols,
+    types::CResRef,
+};
+
+type GetObjectUnderCursorFn = unsafe extern "system" fn() -> *mut c_void;
+type GetObjectModelFn = unsafe extern "thiscall" fn(object: *mut c_void) -> *const CResRef;
+
+/// Resref of the model the object under the mouse cursor is drawn with, `None` when the cursor
+/// isn't over an object.
+///
+/// Must be called on the main thread.
+pub fn model_under_cursor() -> io::Result<Option<String>> {
+    let get_object_under_cursor: GetObjectUnderCursorFn =
+        unsafe { std::mem::transmute(symbols::GET_OBJECT_UNDER_CURSOR.resolve()?) };
+    let get_object_model: GetObjectModelFn =
+        unsafe { std::mem::transmute(symbols::GET_OBJECT_MODEL.resolve()?) };
+
+    let object = unsafe { get_object_under_cursor() };
+    if object.is_null() {
+        return Ok(None);
+    }
+
+    let model = unsafe { get_object_model(object) };
+    if model.is_null() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "Object under the cursor has no model",
+        ));
+    }
+    Ok(Some(unsafe { &*model }.to_string_lossy()))
+}
+
+/// Reads the headers of the model `name` the way the game finds it, override folder included.
+///
+/// Must be called on the main thread.
+pub fn model_header(name: &str) -> io::Result<ModelHeader> {
+    ModelHeader::parse(&read_resource(
+        &CResRef::new("model", name)?,
+        ResourceType::Mdl,
+    )?)
+}
@@ -0,0 +1,310 @@
+/// Module (area) transitions.
+///
+/// Entering and leaving modules is announced to the registered listeners, features that care
+/// about the current module subscribe here instead of hooking the loads themselves. Until the
+/// load functions are located, transitions are picked up from the module name changing between
+/// frames instead.
+use std::{
+    ffi::{c_void, CString},
+    io,
+    sync::{Mutex, OnceLock},
+};
+
+use log::{error, info};
+
+use crate::mem::Detour;
+
+use super::{
+    frame,
+    hooks::ensure_detour,
+    pointers, symbols, trace,
+    types::{validate_resref, CExoString},
+};
+
+// Verify against the disassembly when locating the functions
+const LOAD_MODULE_PROLOGUE_LENGTH: usize = 6;
+const UNLOAD_MODULE_PROLOGUE_LENGTH: usize = 6;
+
+/// Module the listeners were last told about
+static ENTERED: Mutex<Option<String>> = Mutex::new(None);
+
+static ENTER_LISTENERS: Mutex<Vec<fn(&str)>> = Mutex::new(Vec::new());
+static EXIT_LISTENERS: Mutex<Vec<fn(&str)>> = Mutex::new(Vec::new());
+
+static LOAD_MODULE_DETOUR: OnceLock<Detour> = OnceLock::new();
+static UNLOAD_MODULE_DETOUR: OnceLock<Detour> = OnceLock::new();
+
+type StartNewModuleFn =
+    unsafe extern "thiscall" fn(server_app: *mut c_void, module: *const CExoString) -> i32;
+type GetModuleNameFn = unsafe extern "thiscall" fn(server_app: *mut c_void) -> *const CExoString;
+type LoadModuleFn =
+    unsafe extern "thiscall" fn(server_app: *mut c_void, module: *const CExoString) -> i32;
+type UnloadModuleFn = unsafe extern "thiscall" fn(server_app: *mut c_void);
+type CreateQuickCharacterFn =
+    unsafe extern "thiscall" fn(server_app: *mut c_void, class: i32, gender: i32) -> i32;
+
+/// Classes of the premade quick characters, in the engine's order
+pub const QUICK_CHARACTER_CLASSES: &[&str] = &["soldier", "scout", "scoundrel"];
+pub const QUICK_CHARACTER_GENDERS: &[&str] = &["male", "female"];
+
+/// Checks that `module` looks like a module resref before it gets anywhere near the engine.
+pub fn validate_module_name(module: &str) -> io::Result<()> {
+    validate_resref("module", module)
+}
+
+/// Reads the server application object, fails if the game hasn't created it yet.
+pub(crate) fn server_exo_app() -> io::Result<*mut c_void> {
+    pointers::SERVER_APP
+        .resolve()
+        .map(|address| address as *mut c_void)
+        .map_err(|e| match e.kind() {
+            io::ErrorKind::NotConnected => io::Error::new(
+                io::ErrorKind::NotConnected,
+                "Server application has not been created yet",
+            ),
+            _ => e,
+        })
+}
+
+/// Transitions the party straight to `module` through the engine's own area transition code.
+///
+/// Must be called on the main thread.
+pub fn warp(module: &str) -> io::Result<()> {
+    validate_module_name(module)?;
+
+    let start_new_module: StartNewModuleFn =
+        unsafe { std::mem::transmute(symbols::START_NEW_MODULE.resolve()?) };
+    let server_app = server_exo_app()?;
+
+    // Module resrefs are lowercase on disk, the engine does not care but logs read better
+    let name = CString::new(module.to_ascii_lowercase())?;
+
+    info!("Warping to module {module}");
+    let result = unsafe { start_new_module(server_app, &CExoString::borrowed(&name)) };
+
+    if result == 0 {
+        return Err(io::Error::other(format!(
+            "Engine refused to load module {module}"
+        )));
+    }
+
+    Ok(())
+}
+
+fn quick_character_index(what: &str, names: &[&str], name: &str) -> io::Result<i32> {
+    names
+        .iter()
+        .position(|known| known.eq_ignore_ascii_case(name))
+        .map(|index| index as i32)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "Unknown {what} '{name}', expected one of {}",
+                    names.join(", ")
+                ),
+            )
+        })
+}
+
+/// Checks the quick start arguments, returns the class and gender as the engine numbers them.
+pub fn validate_quick_start(module: &str, class: &str, gender: &str) -> io::Result<(i32, i32)> {
+    validate_module_name(module)?;
+    Ok((
+        quick_character_index("class", QUICK_CHARACTER_CLASSES, class)?,
+        quick_character_index("gender", QUICK_CHARACTER_GENDERS, gender)?,
+    ))
+}
+
+/// Starts a new game in `module` from the main menu with a premade character of `class` and
+/// `gender`, the one Quick Character would make, skipping character generation and the prologue.
+///
+/// Must be called on the main thread.
+pub fn quick_start(module: &str, class: &str, gender: &str) -> io::Result<()> {
+    let (class_index, gender_index) = validate_quick_start(module, class, gender)?;
+
+    let create_quick_character: CreateQuickCharacterFn =
+        unsafe { std::mem::transmute(symbols::CREATE_QUICK_CHARACTER.resolve()?) };
+    let server_app = server_exo_app()?;
+
+    info!("Creating a premade {gender} {class}");
+    if unsafe { create_quick_character(server_app, class_index, gender_index) } == 0 {
+        return Err(io::Error::other(
+            "Engine refused to create the quick character",
+        ));
+    }
+    warp(module)
+}
+
+/// Returns the resref of the loaded module, `None` while no module is loaded.
+///
+/// Must be called on the main thread.
+pub fn current_module() -> io::Result<Option<String>> {
+    let name = module_name()?;
+    Ok((!name.is_null()).then(|| unsafe { (*name).to_string_lossy() }))
+}
+
+/// Returns the engine's string holding the resref of the loaded module, null while no module is
+/// loaded.
+///
+/// Must be called on the main thread.
+pub(crate) fn module_name() -> io::Result<*const CExoString> {
+    let get_module_name: GetModuleNameFn =
+        unsafe { std::mem::transmute(symbols::GET_MODULE_NAME.resolve()?) };
+    Ok(unsafe { get_module_name(server_exo_app()?) })
+}
+
+/// Registers `listener` to be called with the module's resref whenever a module has been loaded.
+/// Listeners run on the main thread.
+pub fn on_module_enter(listener: fn(&str)) {
+    ENTER_LISTENERS.lock().unwrap().push(listener);
+}
+
+/// Registers `listener` to be called with the module's resref before a module is torn down.
+/// Listeners run on the main thread.
+pub fn on_module_exit(listener: fn(&str)) {
+    EXIT_LISTENERS.lock().unwrap().push(listener);
+}
+
+/// Returns the module last entered, `None` in the main menu and while loading. Unlike
+/// `current_module` this doesn't ask the engine and can be called from any thread.
+pub fn entered_module() -> Option<String> {
+    ENTERED.lock().unwrap().clone()
+}
+
+fn enter(module: String) {
+    // Missed the exit, e.g. when guessing from the module name
+    exit();
+
+    info!("Entered module {module}");
+    *ENTERED.lock().unwrap() = Some(module.clone());
+
+    // Copy the listeners out, they're free to register more
+    let listeners = ENTER_LISTENERS.lock().unwrap().clone();
+    for listener in listeners {
+        listener(&module);
+    }
+}
+
+fn exit() {
+    let Some(module) = ENTERED.lock().unwrap().take() else {
+        return;
+    };
+
+    info!("Left module {module}");
+    let listeners = EXIT_LISTENERS.lock().unwrap().clone();
+    for listener in listeners {
+        listener(&module);
+    }
+}
+
+unsafe extern "thiscall" fn load_module_hook(
+    server_app: *mut c_void,
+    module: *const CExoString,
+) -> i32 {
+    let name = || {
+        if module.is_null() {
+            String::new()
+        } else {
+            (*module).to_string_lossy()
+        }
+    };
+
+    trace::call(
+        &symbols::LOAD_MODULE,
+        || format!("server_app={server_app:?}, module={}", name()),
+        || {
+            let detour = LOAD_MODULE_DETOUR
+                .get()
+                .expect("hook should not run before the detour is installed");
+            let original: LoadModuleFn = std::mem::transmute(detour.trampoline());
+            let result = original(server_app, module);
+
+            if result != 0 {
+                // Prefer the engine's own spelling of the resref
+                let entered = current_module().ok().flatten().unwrap_or_else(name);
+                enter(entered.to_ascii_lowercase());
+            }
+            result
+        },
+    )
+}
+
+unsafe extern "thiscall" fn unload_module_hook(server_app: *mut c_void) {
+    trace::call(
+        &symbols::UNLOAD_MODULE,
+        || format!("server_app={server_app:?}"),
+        || {
+            exit();
+
+            let detour = UNLOAD_MODULE_DETOUR
+                .get()
+                .expect("hook should not run before the detour is installed");
+            let original: UnloadModuleFn = std::mem::transmute(detour.trampoline());
+            original(server_app)
+        },
+    )
+}
+
+fn detect_module_change() {
+    // Unknown is not the same as no module, don't announce anything when the engine can't tell
+    let Ok(module) = current_module() else {
+        return;
+    };
+    let module = module.map(|module| module.to_ascii_lowercase());
+
+    if module == entered_module() {
+        return;
+    }
+    match module {
+        Some(module) => enter(module),
+        None => exit(),
+    }
+}
+
+pub(super) fn install_module_hooks() -> io::Result<()> {
+    ensure_detour(
+        &LOAD_MODULE_DETOUR,
+        &symbols::LOAD_MODULE,
+        LOAD_MODULE_PROLOGUE_LENGTH,
+        load_module_hook as LoadModuleFn as usize,
+    )?;
+    ensure_detour(
+        &UNLOAD_MODULE_DETOUR,
+        &symbols::UNLOAD_MODULE,
+        UNLOAD_MODULE_PROLOGUE_LENGTH,
+        unload_module_hook as UnloadModuleFn as usize,
+    )
+}
+
+/// Starts announcing module transitions. Must be called on the main thread.
+pub fn init() {
+    // Both symbols are checked up front, hooking only one end would leave modules entered forever
+    let resolved = symbols::LOAD_MODULE
+        .resolve()
+        .and(symbols::UNLOAD_MODULE.resolve());
+
+    match resolved.and_then(|_| install_module_hooks()) {
+        Ok(()) => info!("Watching module loads"),
+        Err(e) => {
+            error!("Could not hook module loads, watching the module name instead: {e}");
+            frame::on_every_frame(detect_module_change);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_quick_start() {
+        assert_eq!(
+            validate_quick_start("end_m01aa", "Scoundrel", "female").unwrap(),
+            (2, 1)
+        );
+        assert!(validate_quick_start("end_m01aa", "jedi", "male").is_err());
+        assert!(validate_quick_start("end_m01aa", "soldier", "droid").is_err());
+        assert!(validate_quick_start("../end_m01aa", "soldier", "male").is_err());
+    }
+}
@@ -0,0 +1,92 @@
+/// Movement speed multiplier for the active party.
+///
+/// The engine looks up the movement rate each time a creature moves, so scaling the value it gets
+/// back changes the speed live without touching the creature stats.
+use std::{
+    ffi::c_void,
+    io,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        OnceLock,
+    },
+};
+
+use log::info;
+
+use crate::mem::Detour;
+
+use super::{hooks::ensure_detour, party, symbols, trace};
+
+/// Allowed multiplier range, past this animations and collisions go haywire.
+pub const MIN_SPEED_MULTIPLIER: f32 = 0.1;
+pub const MAX_SPEED_MULTIPLIER: f32 = 10.0;
+
+// Verify against the disassembly when locating the function
+const GET_MOVEMENT_RATE_PROLOGUE_LENGTH: usize = 6;
+
+/// Multiplier as raw `f32` bits, there's no atomic float
+static SPEED_MULTIPLIER: AtomicU32 = AtomicU32::new(0x3F80_0000); // 1.0
+
+static GET_MOVEMENT_RATE_DETOUR: OnceLock<Detour> = OnceLock::new();
+
+type GetMovementRateFn = unsafe extern "thiscall" fn(creature: *mut c_void) -> f32;
+
+unsafe extern "thiscall" fn get_movement_rate_hook(creature: *mut c_void) -> f32 {
+    trace::call(
+        &symbols::GET_MOVEMENT_RATE,
+        || format!("creature={creature:?}"),
+        || {
+            let detour = GET_MOVEMENT_RATE_DETOUR
+                .get()
+                .expect("hook should not run before the detour is installed");
+            let original: GetMovementRateFn = std::mem::transmute(detour.trampoline());
+            let rate = original(creature);
+
+            let multiplier = speed_multiplier();
+            if multiplier != 1.0 && party::is_in_active_party(creature) {
+                rate * multiplier
+            } else {
+                rate
+            }
+        },
+    )
+}
+
+pub(super) fn install_get_movement_rate_hook() -> io::Result<()> {
+    ensure_detour(
+        &GET_MOVEMENT_RATE_DETOUR,
+        &symbols::GET_MOVEMENT_RATE,
+        GET_MOVEMENT_RATE_PROLOGUE_LENGTH,
+        get_movement_rate_hook as GetMovementRateFn as usize,
+    )
+}
+
+pub fn speed_multiplier() -> f32 {
+    f32::from_bits(SPEED_MULTIPLIER.load(Ordering::Relaxed))
+}
+
+/// Checks that `multiplier` is within the allowed range.
+pub fn validate_speed_multiplier(multiplier: f32) -> io::Result<()> {
+    if (MIN_SPEED_MULTIPLIER..=MAX_SPEED_MULTIPLIER).contains(&multiplier) {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "Speed multiplier has to be between {MIN_SPEED_MULTIPLIER} and {MAX_SPEED_MULTIPLIER}"
+            ),
+        ))
+    }
+}
+
+/// Sets the movement speed multiplier for the active party, 1.0 being the normal speed.
+///
+/// Must be called on the main thread.
+pub fn set_speed_multiplier(multiplier: f32) -> io::Result<()> {
+    validate_speed_multiplier(multiplier)?;
+    install_get_movement_rate_hook()?;
+
+    SPEED_MULTIPLIER.store(multiplier.to_bits(), Ordering::Relaxed);
+    info!("Movement speed multiplier set to {multiplier}");
+    Ok(())
+}
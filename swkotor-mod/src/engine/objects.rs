@@ -0,0 +1,108 @@
+/// Creating objects in the current area and playing movies, the way scripts do.
+use std::{
+    ffi::{c_void, CString},
+    fmt, io,
+};
+
+use log::info;
+
+use super::{
+    module::server_exo_app,
+    symbols,
+    types::{validate_resref, CExoString},
+};
+
+/// Object id the engine returns for objects that don't exist
+const OBJECT_INVALID: u32 = 0x7f00_0000;
+
+/// Object types `spawn_object` can create, values are the script constants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectKind {
+    Creature = 1,
+    Item = 2,
+    Placeable = 64,
+}
+
+impl ObjectKind {
+    pub fn parse(name: &str) -> Option<ObjectKind> {
+        match name {
+            "creature" => Some(ObjectKind::Creature),
+            "item" => Some(ObjectKind::Item),
+            "placeable" => Some(ObjectKind::Placeable),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ObjectKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ObjectKind::Creature => write!(f, "creature"),
+            ObjectKind::Item => write!(f, "item"),
+            ObjectKind::Placeable => write!(f, "placeable"),
+        }
+    }
+}
+
+type CreateObjectFn = unsafe extern "thiscall" fn(
+    server_app: *mut c_void,
+    object_type: i32,
+    template: *const CExoString,
+    position: *const [f32; 3],
+    facing: f32,
+) -> u32;
+type PlayMovieFn =
+    unsafe extern "thiscall" fn(server_app: *mut c_void, movie: *const CExoString) -> i32;
+
+/// Creates a `kind` object from `template` at `position` in the current area. Returns the id of
+/// the new object.
+///
+/// Must be called on the main thread.
+pub fn spawn_object(kind: ObjectKind, template: &str, position: [f32; 3]) -> io::Result<u32> {
+    validate_resref("template", template)?;
+
+    let create_object: CreateObjectFn =
+        unsafe { std::mem::transmute(symbols::CREATE_OBJECT.resolve()?) };
+    let server_app = server_exo_app()?;
+    let name = CString::new(template.to_ascii_lowercase())?;
+
+    let [x, y, z] = position;
+    info!("Spawning {kind} {template} at {x:.2} {y:.2} {z:.2}");
+    let id = unsafe {
+        create_object(
+            server_app,
+            kind as i32,
+            &CExoString::borrowed(&name),
+            &position,
+            0.0,
+        )
+    };
+
+    if id == OBJECT_INVALID {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("Engine could not create {kind} {template}, is the template correct?"),
+        ));
+    }
+    Ok(id)
+}
+
+/// Plays the movie `movie` from the movies directory, e.g. `01a`.
+///
+/// Must be called on the main thread.
+pub fn play_movie(movie: &str) -> io::Result<()> {
+    validate_resref("movie", movie)?;
+
+    let play: PlayMovieFn = unsafe { std::mem::transmute(symbols::PLAY_MOVIE.resolve()?) };
+    let server_app = server_exo_app()?;
+    let name = CString::new(movie.to_ascii_lowercase())?;
+
+    info!("Playing movie {movie}");
+    if unsafe { play(server_app, &CExoString::borrowed(&name)) } == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("Engine could not play movie {movie}"),
+        ));
+    }
+    Ok(())
+}
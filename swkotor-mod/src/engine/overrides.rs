@@ -0,0 +1,233 @@
+/// Loose file overrides from `override_ext`, taking precedence over everything the engine has.
+///
+/// The game's own `override` folder only covers part of the resources, anything the engine finds
+/// in a module archive first still comes from there. Resources demanded from the resource manager
+/// are looked up in `override_ext` before the engine gets to them, so dropping `<resref>.<ext>`
+/// in there replaces the resource without repacking any archive.
+///
+/// The directory is indexed on startup and on `rescan`, file contents are read on first demand.
+/// Textures the engine found itself are passed on to `textures` for dumping and replacement.
+/// Served data is never freed, the engine holds on to the pointer for as long as it likes.
+use std::{
+    collections::HashMap,
+    ffi::c_void,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{LazyLock, Mutex, OnceLock},
+};
+
+use log::{debug, error, info};
+
+use crate::mem::Detour;
+
+use super::{hooks::ensure_detour, symbols, textures, trace, types::CResRef};
+
+/// Relative to the game directory
+pub const OVERRIDE_PATH: &str = "override_ext";
+
+// Verify against the disassembly when locating the function
+const DEMAND_RESOURCE_PROLOGUE_LENGTH: usize = 6;
+
+/// File extensions and the resource type ids the engine uses for them
+const EXTENSIONS: &[(&str, u16)] = &[
+    ("bmp", 1),
+    ("tga", 3),
+    ("wav", 4),
+    ("mdl", 2002),
+    ("nss", 2009),
+    ("ncs", 2010),
+    ("are", 2012),
+    ("ifo", 2014),
+    ("wok", 2016),
+    ("2da", 2017),
+    ("txi", 2022),
+    ("git", 2023),
+    ("uti", 2025),
+    ("utc", 2027),
+    ("dlg", 2029),
+    ("utt", 2032),
+    ("uts", 2035),
+    ("utd", 2042),
+    ("utp", 2044),
+    ("gui", 2047),
+    ("utm", 2051),
+    ("jrl", 2056),
+    ("utw", 2058),
+    ("ssf", 2060),
+    ("lyt", 3000),
+    ("vis", 3001),
+    ("lip", 3004),
+    ("tpc", 3007),
+    ("mdx", 3008),
+];
+
+/// Lower case resref and resource type
+type Key = (String, u16);
+
+/// Files found in the override directory
+static INDEX: LazyLock<Mutex<HashMap<Key, PathBuf>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Contents handed to the engine, kept for good
+static LOADED: LazyLock<Mutex<HashMap<Key, &'static [u8]>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+static DEMAND_RESOURCE_DETOUR: OnceLock<Detour> = OnceLock::new();
+
+type DemandResourceFn = unsafe extern "thiscall" fn(
+    resource_manager: *mut c_void,
+    resref: *const CResRef,
+    resource_type: u16,
+    size: *mut u32,
+) -> *const u8;
+
+fn resource_type(extension: &str) -> Option<u16> {
+    EXTENSIONS
+        .iter()
+        .find(|(candidate, _)| candidate.eq_ignore_ascii_case(extension))
+        .map(|&(_, resource_type)| resource_type)
+}
+
+fn extension(resource_type: u16) -> Option<&'static str> {
+    EXTENSIONS
+        .iter()
+        .find(|&&(_, candidate)| candidate == resource_type)
+        .map(|&(extension, _)| extension)
+}
+
+/// Indexes the files in `directory` by resref and type. Files of unknown types are skipped.
+fn index_directory(directory: &Path) -> io::Result<HashMap<Key, PathBuf>> {
+    let mut index = HashMap::new();
+
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+        let (Some(stem), Some(extension)) = (path.file_stem(), path.extension()) else {
+            continue;
+        };
+        let Some(resource_type) = resource_type(&extension.to_string_lossy()) else {
+            debug!("Skipping override {}, unknown type", path.display());
+            continue;
+        };
+
+        let resref = stem.to_string_lossy().to_ascii_lowercase();
+        index.insert((resref, resource_type), path);
+    }
+
+    Ok(index)
+}
+
+/// Returns the override for the resource, reading it on first use.
+fn find_override(resref: &CResRef, resource_type: u16) -> Option<&'static [u8]> {
+    let key = (resref.to_string_lossy().to_ascii_lowercase(), resource_type);
+    let path = INDEX.lock().unwrap().get(&key)?.clone();
+
+    let mut loaded = LOADED.lock().unwrap();
+    if let Some(data) = loaded.get(&key) {
+        return Some(data);
+    }
+
+    match fs::read(&path) {
+        Ok(data) => {
+            info!("Overriding {} with {}", key.0, path.display());
+            let data: &'static [u8] = Box::leak(data.into_boxed_slice());
+            loaded.insert(key, data);
+            Some(data)
+        }
+        Err(e) => {
+            // Fall back to the engine's copy, the file might still be getting written
+            error!("Could not read override {}: {e}", path.display());
+            None
+        }
+    }
+}
+
+unsafe extern "thiscall" fn demand_resource_hook(
+    resource_manager: *mut c_void,
+    resref: *const CResRef,
+    resource_type: u16,
+    size: *mut u32,
+) -> *const u8 {
+    trace::call(
+        &symbols::DEMAND_RESOURCE,
+        || {
+            let name = (!resref.is_null()).then(|| (*resref).to_string_lossy());
+            format!("resref={name:?}, resource_type={resource_type}")
+        },
+        || {
+            if !resref.is_null() && !size.is_null() {
+                if let Some(data) = find_override(&*resref, resource_type) {
+                    *size = data.len() as u32;
+                    return data.as_ptr();
+                }
+            }
+
+            let detour = DEMAND_RESOURCE_DETOUR
+                .get()
+                .expect("hook should not run before the detour is installed");
+            let original: DemandResourceFn = std::mem::transmute(detour.trampoline());
+            let data = original(resource_manager, resref, resource_type, size);
+
+            if !data.is_null() && !resref.is_null() && textures::is_texture(resource_type) {
+                let texture = std::slice::from_raw_parts(data, *size as usize);
+                if let Some(replacement) = textures::loaded(&*resref, resource_type, texture) {
+                    *size = replacement.len() as u32;
+                    return replacement.as_ptr();
+                }
+            }
+            data
+        },
+    )
+}
+
+pub(super) fn install_demand_resource_hook() -> io::Result<()> {
+    ensure_detour(
+        &DEMAND_RESOURCE_DETOUR,
+        &symbols::DEMAND_RESOURCE,
+        DEMAND_RESOURCE_PROLOGUE_LENGTH,
+        demand_resource_hook as DemandResourceFn as usize,
+    )
+}
+
+/// Overridden resources as file names, sorted.
+pub fn overrides() -> Vec<String> {
+    let mut names: Vec<String> = INDEX
+        .lock()
+        .unwrap()
+        .keys()
+        .map(|(resref, resource_type)| {
+            format!("{resref}.{}", extension(*resource_type).unwrap_or_default())
+        })
+        .collect();
+    names.sort_unstable();
+    names
+}
+
+/// Indexes the override directory again, changed files are read again on their next demand.
+/// Returns how many overrides there are.
+///
+/// Must be called on the main thread.
+pub fn rescan() -> io::Result<usize> {
+    let index = index_directory(Path::new(OVERRIDE_PATH))?;
+    if !index.is_empty() {
+        install_demand_resource_hook()?;
+    }
+
+    let count = index.len();
+    *INDEX.lock().unwrap() = index;
+    // Already served data stays alive, only the lookup forgets it
+    LOADED.lock().unwrap().clear();
+
+    info!("{count} overrides in {OVERRIDE_PATH}");
+    Ok(count)
+}
+
+/// Starts serving overrides, if there are any. Must be called on the main thread.
+pub fn init() {
+    if !Path::new(OVERRIDE_PATH).is_dir() {
+        info!("No {OVERRIDE_PATH} directory, nothing to override");
+        return;
+    }
+
+    if let Err(e) = rescan() {
+        error!("Could not load overrides from {OVERRIDE_PATH}: {e}");
+    }
+}
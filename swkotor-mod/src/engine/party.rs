@@ -0,0 +1,100 @@
+/// Access to the active party, the creatures the player controls.
+use std::{ffi::c_void, io};
+
+use super::{module::server_exo_app, symbols};
+
+/// Party members on the field, the player included.
+pub const ACTIVE_PARTY_SIZE: i32 = 3;
+
+type GetPartyMemberFn =
+    unsafe extern "thiscall" fn(server_app: *mut c_void, slot: i32) -> *mut c_void;
+type GetPointsFn = unsafe extern "thiscall" fn(creature: *mut c_void) -> i16;
+type GetObjectPositionFn = unsafe extern "thiscall" fn(object: *mut c_void) -> *const [f32; 3];
+
+#[derive(Debug, Clone, Copy)]
+pub struct MemberStats {
+    /// Active party slot, 0 being the party leader
+    pub slot: usize,
+    pub hit_points: i16,
+    pub max_hit_points: i16,
+    pub force_points: i16,
+    pub max_force_points: i16,
+}
+
+/// Returns the creatures in the active party. Must be called on the main thread.
+pub fn active_party() -> io::Result<Vec<*mut c_void>> {
+    let get_party_member: GetPartyMemberFn =
+        unsafe { std::mem::transmute(symbols::GET_PARTY_MEMBER.resolve()?) };
+    let server_app = server_exo_app()?;
+
+    Ok((0..ACTIVE_PARTY_SIZE)
+        .map(|slot| unsafe { get_party_member(server_app, slot) })
+        .filter(|creature| !creature.is_null())
+        .collect())
+}
+
+/// Checks if `creature` is in the active party. Errors are treated as not being in the party.
+pub fn is_in_active_party(creature: *mut c_void) -> bool {
+    active_party()
+        .map(|party| party.contains(&creature))
+        .unwrap_or(false)
+}
+
+/// Reads hit points and force points of the active party. Must be called on the main thread.
+pub fn party_stats() -> io::Result<Vec<MemberStats>> {
+    let getter = |symbol: &symbols::Symbol| -> io::Result<GetPointsFn> {
+        Ok(unsafe { std::mem::transmute::<usize, GetPointsFn>(symbol.resolve()?) })
+    };
+    let current_hit_points = getter(&symbols::GET_CURRENT_HIT_POINTS)?;
+    let max_hit_points = getter(&symbols::GET_MAX_HIT_POINTS)?;
+    let current_force_points = getter(&symbols::GET_CURRENT_FORCE_POINTS)?;
+    let max_force_points = getter(&symbols::GET_MAX_FORCE_POINTS)?;
+
+    Ok(active_party()?
+        .into_iter()
+        .enumerate()
+        .map(|(slot, creature)| unsafe {
+            MemberStats {
+                slot,
+                hit_points: current_hit_points(creature),
+                max_hit_points: max_hit_points(creature),
+                force_points: current_force_points(creature),
+                max_force_points: max_force_points(creature),
+            }
+        })
+        .collect())
+}
+
+/// Returns the party leader, the creature the player controls. Must be called on the main thread.
+pub fn leader() -> io::Result<*mut c_void> {
+    active_party()?
+        .first()
+        .copied()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Party is empty"))
+}
+
+fn object_position(object: *mut c_void) -> io::Result<[f32; 3]> {
+    let get_position: GetObjectPositionFn =
+        unsafe { std::mem::transmute(symbols::GET_OBJECT_POSITION.resolve()?) };
+    unsafe { Ok(*get_position(object)) }
+}
+
+/// Returns the world position of the party leader. Must be called on the main thread.
+pub fn leader_position() -> io::Result<[f32; 3]> {
+    object_position(leader()?)
+}
+
+/// Returns the direction the party leader faces in degrees, counterclockwise from the x axis.
+/// Must be called on the main thread.
+pub fn leader_facing() -> io::Result<f32> {
+    let get_orientation: GetObjectPositionFn =
+        unsafe { std::mem::transmute(symbols::GET_OBJECT_ORIENTATION.resolve()?) };
+    let [x, y, _] = unsafe { *get_orientation(leader()?) };
+    Ok(y.atan2(x).to_degrees())
+}
+
+/// Returns the world positions of the active party, the leader first. Must be called on the main
+/// thread.
+pub fn party_positions() -> io::Result<Vec<[f32; 3]>> {
+    active_party()?.into_iter().map(object_position).collect()
+}
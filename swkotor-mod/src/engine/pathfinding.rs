@@ -0,0 +1,60 @@
+/// Routes the engine has computed for moving creatures.
+use std::{ffi::c_void, io, slice};
+
+use super::{party, symbols};
+
+/// Path as the engine keeps it on a moving creature.
+///
+/// TODO(tatu): Verify the layout when locating `GET_CREATURE_PATH`
+#[repr(C)]
+struct EnginePath {
+    points: *const [f32; 3],
+    point_count: i32,
+    /// Index of the point the creature is walking towards
+    next_point: i32,
+}
+
+type GetCreaturePathFn = unsafe extern "thiscall" fn(creature: *mut c_void) -> *const EnginePath;
+
+/// Route of a moving creature.
+#[derive(Debug, Clone)]
+pub struct Path {
+    pub waypoints: Vec<[f32; 3]>,
+    /// Index of the waypoint being walked towards, the ones before it have been passed
+    pub next: usize,
+}
+
+impl Path {
+    /// Final destination of the route.
+    pub fn target(&self) -> Option<[f32; 3]> {
+        self.waypoints.last().copied()
+    }
+}
+
+/// Checks the path can be read in this build, without needing a party.
+pub fn ensure_located() -> io::Result<()> {
+    symbols::GET_CREATURE_PATH.resolve().map(|_| ())
+}
+
+/// Returns the route the party leader is following, `None` when standing still.
+///
+/// Must be called on the main thread.
+pub fn leader_path() -> io::Result<Option<Path>> {
+    let get_path: GetCreaturePathFn =
+        unsafe { std::mem::transmute(symbols::GET_CREATURE_PATH.resolve()?) };
+    let leader = party::leader()?;
+
+    unsafe {
+        let path = get_path(leader);
+        if path.is_null() || (*path).points.is_null() || (*path).point_count <= 0 {
+            return Ok(None);
+        }
+
+        let path = &*path;
+        let waypoints = slice::from_raw_parts(path.points, path.point_count as usize).to_vec();
+        Ok(Some(Path {
+            next: (path.next_point.max(0) as usize).min(waypoints.len() - 1),
+            waypoints,
+        }))
+    }
+}
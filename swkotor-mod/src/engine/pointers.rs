@@ -0,0 +1,125 @@
+/// Multi-level pointer paths from the symbol file, resolved at runtime.
+///
+/// Much of the engine's state hangs off a handful of globals, a pointer to an object holding a
+/// pointer to another and so on. A path names the global and the offsets to follow, the same as a
+/// pointer path in Cheat Engine, so reaching a new piece of state is a data change like locating
+/// a symbol. Resolved addresses are cached until the next module transition, when the engine
+/// tears down and recreates most of its objects. Pointers along the path are read through
+/// `mem::read_memory`, a broken path is an error rather than a crash.
+use std::{
+    collections::HashMap,
+    fmt, io,
+    mem::size_of,
+    sync::{LazyLock, Mutex},
+};
+
+use log::trace;
+
+use crate::mem::read_memory;
+
+use super::{module, symbols};
+
+/// Resolved addresses by path name, emptied on module transitions
+static CACHE: LazyLock<Mutex<HashMap<String, usize>>> = LazyLock::new(Default::default);
+
+pub struct Pointer {
+    /// Key under `[pointers]` in the symbol files
+    pub name: &'static str,
+}
+
+// The server application object, which most engine calls are made on
+pub const SERVER_APP: Pointer = Pointer::named("server_app");
+
+impl Pointer {
+    const fn named(name: &'static str) -> Self {
+        Pointer { name }
+    }
+
+    pub fn resolve(&self) -> io::Result<usize> {
+        resolve(self.name)
+    }
+}
+
+impl fmt::Display for Pointer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match resolve(self.name) {
+            Ok(address) => write!(f, "{} - {:#010x}", self.name, address),
+            Err(_) => write!(f, "{} - unresolved", self.name),
+        }
+    }
+}
+
+fn read_pointer(address: usize) -> io::Result<usize> {
+    let mut bytes = [0u8; size_of::<usize>()];
+    read_memory(address, &mut bytes)?;
+    Ok(usize::from_le_bytes(bytes))
+}
+
+/// Follows `offsets` from `base`, reading the pointer at every step with `read`.
+fn follow(
+    name: &str,
+    base: usize,
+    offsets: &[isize],
+    read: impl Fn(usize) -> io::Result<usize>,
+) -> io::Result<usize> {
+    let mut address = base;
+    for (level, offset) in offsets.iter().enumerate() {
+        let pointer = read(address)?;
+        if pointer == 0 {
+            // Usually just not created yet, e.g. before the first module has loaded
+            return Err(io::Error::new(
+                io::ErrorKind::NotConnected,
+                format!("{name} is null at level {level}"),
+            ));
+        }
+        address = pointer.wrapping_add_signed(*offset);
+    }
+    Ok(address)
+}
+
+/// Returns the address the path `name` leads to. Failures aren't cached, a path that is null
+/// now may well be set up on the next frame.
+pub fn resolve(name: &str) -> io::Result<usize> {
+    if let Some(address) = CACHE.lock().unwrap().get(name) {
+        return Ok(*address);
+    }
+
+    let path = symbols::pointer_path(name)?;
+    let base = symbols::resolve_name(&path.base)?;
+    let address = follow(name, base, &path.offsets, read_pointer)?;
+    trace!("Pointer path {name} resolved to {address:#010x}");
+    CACHE.lock().unwrap().insert(name.to_string(), address);
+    Ok(address)
+}
+
+/// Forgets every resolved path, they're resolved again on next use.
+pub fn invalidate() {
+    CACHE.lock().unwrap().clear();
+}
+
+pub fn init() {
+    module::on_module_exit(|_| invalidate());
+    module::on_module_enter(|_| invalidate());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn follows_offsets_and_stops_at_null() {
+        let memory = HashMap::from([(0x1000, 0x2000), (0x2010, 0x3000), (0x3008, 0)]);
+        let read = |address: usize| {
+            memory
+                .get(&address)
+                .copied()
+                .ok_or_else(|| io::Error::other(format!("unmapped {address:#x}")))
+        };
+
+        assert_eq!(follow("path", 0x1000, &[], read).unwrap(), 0x1000);
+        assert_eq!(follow("path", 0x1000, &[0x10, -4], read).unwrap(), 0x2ffc);
+        let error = follow("path", 0x1000, &[0x10, 0x8, 0x4], read).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotConnected);
+        assert!(follow("path", 0x1000, &[0x20, 0], read).is_err());
+    }
+}
@@ -0,0 +1,67 @@
+/// Reading game resources through the engine's resource manager.
+///
+/// Going through the engine gets us the exact resource the game uses, override folder and module
+/// archives included, without having to reimplement the lookup order.
+use std::{ffi::c_void, io};
+
+use super::{symbols, types::CResRef};
+
+/// Resource type ids the engine uses, the same ones as in the KEY and ERF archives.
+#[derive(Debug, Clone, Copy)]
+#[repr(u16)]
+pub enum ResourceType {
+    Tga = 3,
+    Mdl = 2002,
+    Wok = 2016,
+    TwoDa = 2017,
+    Git = 2023,
+    Jrl = 2056,
+    Lyt = 3000,
+    Tpc = 3007,
+}
+
+type DemandResourceFn = unsafe extern "thiscall" fn(
+    resource_manager: *mut c_void,
+    resref: *const CResRef,
+    resource_type: u16,
+    size: *mut u32,
+) -> *const u8;
+
+fn resource_manager() -> io::Result<*mut c_void> {
+    let address = symbols::RESOURCE_MANAGER.resolve()?;
+    let resource_manager = unsafe { *(address as *const *mut c_void) };
+
+    if resource_manager.is_null() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotConnected,
+            "Resource manager has not been created yet",
+        ));
+    }
+
+    Ok(resource_manager)
+}
+
+/// Copies the resource out of the engine's cache.
+///
+/// Must be called on the main thread.
+pub fn read_resource(resref: &CResRef, resource_type: ResourceType) -> io::Result<Vec<u8>> {
+    let demand_resource: DemandResourceFn =
+        unsafe { std::mem::transmute(symbols::DEMAND_RESOURCE.resolve()?) };
+    let resource_manager = resource_manager()?;
+
+    let mut size = 0;
+    let data =
+        unsafe { demand_resource(resource_manager, resref, resource_type as u16, &mut size) };
+
+    if data.is_null() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "No {resource_type:?} resource named {}",
+                resref.to_string_lossy()
+            ),
+        ));
+    }
+
+    Ok(unsafe { std::slice::from_raw_parts(data, size as usize) }.to_vec())
+}
@@ -0,0 +1,154 @@
+/// Inspection and seeding of the engine's random number generator.
+///
+/// Dice rolls, loot and AI decisions all draw from the same generator. The hooks keep the latest
+/// rolls for the overlay and can force the seed, replacing whatever the engine seeds with so runs
+/// can be repeated.
+use std::{
+    collections::VecDeque,
+    ffi::c_void,
+    io,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, OnceLock,
+    },
+};
+
+use log::{error, info};
+
+use crate::config::config;
+use crate::mem::Detour;
+
+use super::{hooks::ensure_detour, symbols, trace};
+
+/// Rolls kept for display
+pub const KEPT_ROLLS: usize = 16;
+
+// Verify against the disassembly when locating the functions
+const RANDOM_PROLOGUE_LENGTH: usize = 6;
+const SEED_RANDOM_PROLOGUE_LENGTH: usize = 6;
+
+static RECENT_ROLLS: Mutex<VecDeque<u32>> = Mutex::new(VecDeque::new());
+static FORCED_SEED: Mutex<Option<u32>> = Mutex::new(None);
+/// Generator object the engine last used, null until the first roll or seeding
+static GENERATOR: AtomicUsize = AtomicUsize::new(0);
+
+static RANDOM_DETOUR: OnceLock<Detour> = OnceLock::new();
+static SEED_RANDOM_DETOUR: OnceLock<Detour> = OnceLock::new();
+
+type RandomFn = unsafe extern "thiscall" fn(generator: *mut c_void) -> u32;
+type SeedRandomFn = unsafe extern "thiscall" fn(generator: *mut c_void, seed: u32);
+
+unsafe extern "thiscall" fn random_hook(generator: *mut c_void) -> u32 {
+    trace::call(
+        &symbols::RANDOM,
+        || format!("generator={generator:?}"),
+        || {
+            GENERATOR.store(generator as usize, Ordering::Relaxed);
+
+            let detour = RANDOM_DETOUR
+                .get()
+                .expect("hook should not run before the detour is installed");
+            let original: RandomFn = std::mem::transmute(detour.trampoline());
+            let roll = original(generator);
+
+            let mut rolls = RECENT_ROLLS.lock().unwrap();
+            if rolls.len() >= KEPT_ROLLS {
+                rolls.pop_front();
+            }
+            rolls.push_back(roll);
+            roll
+        },
+    )
+}
+
+unsafe extern "thiscall" fn seed_random_hook(generator: *mut c_void, seed: u32) {
+    trace::call(
+        &symbols::SEED_RANDOM,
+        || format!("generator={generator:?}, seed={seed:#x}"),
+        || {
+            GENERATOR.store(generator as usize, Ordering::Relaxed);
+            let seed = forced_seed().unwrap_or(seed);
+
+            let detour = SEED_RANDOM_DETOUR
+                .get()
+                .expect("hook should not run before the detour is installed");
+            let original: SeedRandomFn = std::mem::transmute(detour.trampoline());
+            original(generator, seed)
+        },
+    )
+}
+
+pub(super) fn install_random_hook() -> io::Result<()> {
+    ensure_detour(
+        &RANDOM_DETOUR,
+        &symbols::RANDOM,
+        RANDOM_PROLOGUE_LENGTH,
+        random_hook as RandomFn as usize,
+    )
+}
+
+pub(super) fn install_seed_random_hook() -> io::Result<()> {
+    ensure_detour(
+        &SEED_RANDOM_DETOUR,
+        &symbols::SEED_RANDOM,
+        SEED_RANDOM_PROLOGUE_LENGTH,
+        seed_random_hook as SeedRandomFn as usize,
+    )
+}
+
+/// Starts keeping the latest rolls. Must be called on the main thread.
+pub fn watch_rolls() -> io::Result<()> {
+    install_random_hook()
+}
+
+/// Latest rolls, oldest first. Empty until `watch_rolls` has been called and the engine rolls.
+pub fn recent_rolls() -> Vec<u32> {
+    RECENT_ROLLS.lock().unwrap().iter().copied().collect()
+}
+
+pub fn forced_seed() -> Option<u32> {
+    *FORCED_SEED.lock().unwrap()
+}
+
+/// Forces every seeding to use `seed`, and reseeds right away if the engine has used the
+/// generator already. `None` lets the engine seed as it likes again.
+///
+/// Must be called on the main thread.
+pub fn set_forced_seed(seed: Option<u32>) -> io::Result<()> {
+    install_seed_random_hook()?;
+    // Catches the generator object on the first roll if the engine seeded before the hook
+    install_random_hook()?;
+    *FORCED_SEED.lock().unwrap() = seed;
+
+    let Some(seed) = seed else {
+        info!("RNG seed no longer forced");
+        return Ok(());
+    };
+
+    let generator = GENERATOR.load(Ordering::Relaxed) as *mut c_void;
+    if generator.is_null() {
+        info!("RNG seed forced to {seed}, applied when the engine next seeds");
+    } else {
+        let detour = SEED_RANDOM_DETOUR
+            .get()
+            .expect("detour was installed above");
+        unsafe {
+            let original: SeedRandomFn = std::mem::transmute(detour.trampoline());
+            original(generator, seed);
+        }
+        RECENT_ROLLS.lock().unwrap().clear();
+        info!("RNG reseeded with {seed}");
+    }
+    Ok(())
+}
+
+/// Forces the seed set in the config, if any. Must be called on the main thread.
+pub fn init() {
+    let Some(seed) = config().rng.seed else {
+        return;
+    };
+
+    if let Err(e) = set_forced_seed(Some(seed)) {
+        error!("Could not force the configured RNG seed: {e}");
+    }
+}
@@ -0,0 +1,81 @@
+/// Save events.
+///
+/// The save routine is hooked and every save the engine finishes is announced to the registered
+/// listeners, with the slot it went to. Listeners run on the main thread right after the save has
+/// been written, the save directory is complete by then.
+use std::{
+    ffi::c_void,
+    io,
+    sync::{Mutex, OnceLock},
+};
+
+use log::{error, info};
+
+use crate::mem::Detour;
+
+use super::{hooks::ensure_detour, symbols, trace, types::CExoString};
+
+// Verify against the disassembly when locating the function
+const SAVE_GAME_PROLOGUE_LENGTH: usize = 6;
+
+static LISTENERS: Mutex<Vec<fn(i32)>> = Mutex::new(Vec::new());
+
+static SAVE_GAME_DETOUR: OnceLock<Detour> = OnceLock::new();
+
+type SaveGameFn = unsafe extern "thiscall" fn(
+    server_app: *mut c_void,
+    slot: i32,
+    name: *const CExoString,
+    silent: i32,
+) -> i32;
+
+/// Registers `listener` to be called with the slot of every save the engine writes. Listeners run
+/// on the main thread.
+pub fn on_game_saved(listener: fn(i32)) {
+    LISTENERS.lock().unwrap().push(listener);
+}
+
+unsafe extern "thiscall" fn save_game_hook(
+    server_app: *mut c_void,
+    slot: i32,
+    name: *const CExoString,
+    silent: i32,
+) -> i32 {
+    trace::call(
+        &symbols::SAVE_GAME,
+        || format!("server_app={server_app:?}, slot={slot}, silent={silent}"),
+        || {
+            let detour = SAVE_GAME_DETOUR
+                .get()
+                .expect("hook should not run before the detour is installed");
+            let original: SaveGameFn = std::mem::transmute(detour.trampoline());
+            let result = original(server_app, slot, name, silent);
+
+            // Zero when saving wasn't allowed, nothing was written
+            if result != 0 {
+                let listeners = LISTENERS.lock().unwrap().clone();
+                for listener in listeners {
+                    listener(slot);
+                }
+            }
+            result
+        },
+    )
+}
+
+pub(super) fn install_save_game_hook() -> io::Result<()> {
+    ensure_detour(
+        &SAVE_GAME_DETOUR,
+        &symbols::SAVE_GAME,
+        SAVE_GAME_PROLOGUE_LENGTH,
+        save_game_hook as SaveGameFn as usize,
+    )
+}
+
+/// Starts announcing saves. Must be called on the main thread.
+pub fn init() {
+    match install_save_game_hook() {
+        Ok(()) => info!("Watching saves"),
+        Err(e) => error!("Could not hook saving, save events won't be announced: {e}"),
+    }
+}
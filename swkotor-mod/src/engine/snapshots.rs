@@ -0,0 +1,61 @@
+/// Practice snapshots, a savestate-like quick save and restore for retrying a fight or a trick.
+///
+/// Snapshots go through the engine's own save path into a save slot of their own, far past the
+/// slots the save menu hands out, so they never overwrite a real save. Saving skips the save
+/// screen, restoring is a normal load.
+use std::{
+    ffi::{c_void, CString},
+    io,
+};
+
+use log::info;
+
+use super::{module::server_exo_app, symbols, types::CExoString};
+
+/// Save slot snapshots are kept in. The save menu numbers slots from zero up.
+pub const SNAPSHOT_SLOT: i32 = 9999;
+const SNAPSHOT_NAME: &str = "Practice snapshot";
+
+type SaveGameFn = unsafe extern "thiscall" fn(
+    server_app: *mut c_void,
+    slot: i32,
+    name: *const CExoString,
+    silent: i32,
+) -> i32;
+type LoadGameFn = unsafe extern "thiscall" fn(server_app: *mut c_void, slot: i32) -> i32;
+
+/// Snapshots the game state over the previous snapshot.
+///
+/// Must be called on the main thread.
+pub fn save_snapshot() -> io::Result<()> {
+    let save_game: SaveGameFn = unsafe { std::mem::transmute(symbols::SAVE_GAME.resolve()?) };
+    let server_app = server_exo_app()?;
+    let name = CString::new(SNAPSHOT_NAME)?;
+
+    let result = unsafe { save_game(server_app, SNAPSHOT_SLOT, &CExoString::borrowed(&name), 1) };
+    if result == 0 {
+        // E.g. in combat or dialog, the engine checks the same things as for a normal save
+        return Err(io::Error::other("Engine refused to save right now"));
+    }
+
+    info!("Saved practice snapshot");
+    Ok(())
+}
+
+/// Restores the latest snapshot.
+///
+/// Must be called on the main thread.
+pub fn restore_snapshot() -> io::Result<()> {
+    let load_game: LoadGameFn = unsafe { std::mem::transmute(symbols::LOAD_GAME.resolve()?) };
+    let server_app = server_exo_app()?;
+
+    if unsafe { load_game(server_app, SNAPSHOT_SLOT) } == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "No practice snapshot to restore, save one first",
+        ));
+    }
+
+    info!("Restoring practice snapshot");
+    Ok(())
+}
@@ -0,0 +1,174 @@
+/// Reading and editing party member stats through the engine's own accessors, for setting up QA
+/// scenarios. Setters go through the same code as leveling up, so derived values like attack
+/// bonuses and skill totals follow.
+use std::{ffi::c_void, io};
+
+use log::info;
+
+use super::{party, symbols};
+
+/// Ability short names in the engine's order
+pub const ABILITIES: [&str; 6] = ["str", "dex", "con", "int", "wis", "cha"];
+
+/// Skill names in the engine's order, the same as the rows of `skills.2da`
+pub const SKILLS: [&str; 8] = [
+    "computeruse",
+    "demolitions",
+    "stealth",
+    "awareness",
+    "persuade",
+    "repair",
+    "security",
+    "treatinjury",
+];
+
+type GetAbilityScoreFn = unsafe extern "thiscall" fn(creature: *mut c_void, ability: i32) -> u8;
+type SetAbilityScoreFn =
+    unsafe extern "thiscall" fn(creature: *mut c_void, ability: i32, score: u8);
+type GetSkillRankFn = unsafe extern "thiscall" fn(creature: *mut c_void, skill: i32) -> i8;
+type SetSkillRankFn = unsafe extern "thiscall" fn(creature: *mut c_void, skill: i32, rank: i8);
+type GetPointsFn = unsafe extern "thiscall" fn(creature: *mut c_void) -> i16;
+type SetCurrentHitPointsFn = unsafe extern "thiscall" fn(creature: *mut c_void, hit_points: i16);
+type GetFeatCountFn = unsafe extern "thiscall" fn(creature: *mut c_void) -> u16;
+type GetFeatFn = unsafe extern "thiscall" fn(creature: *mut c_void, index: u16) -> u16;
+type FeatFn = unsafe extern "thiscall" fn(creature: *mut c_void, feat: u16);
+type ForcePowerFn = unsafe extern "thiscall" fn(creature: *mut c_void, power: u16);
+
+#[derive(Debug, Clone)]
+pub struct MemberSheet {
+    pub abilities: [u8; 6],
+    pub skills: [i8; 8],
+    pub hit_points: i16,
+    pub max_hit_points: i16,
+    /// Rows of `feat.2da`
+    pub feats: Vec<u16>,
+}
+
+fn member(slot: usize) -> io::Result<*mut c_void> {
+    party::active_party()?.get(slot).copied().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("No party member in slot {slot}"),
+        )
+    })
+}
+
+fn index_of(names: &[&str], name: &str, kind: &str) -> io::Result<i32> {
+    names
+        .iter()
+        .position(|candidate| candidate.eq_ignore_ascii_case(name))
+        .map(|index| index as i32)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "Unknown {kind} '{name}', expected one of {}",
+                    names.join(", ")
+                ),
+            )
+        })
+}
+
+/// Reads the sheet of the party member in `slot`. Must be called on the main thread.
+pub fn read_member(slot: usize) -> io::Result<MemberSheet> {
+    let get_ability_score: GetAbilityScoreFn =
+        unsafe { std::mem::transmute(symbols::GET_ABILITY_SCORE.resolve()?) };
+    let get_skill_rank: GetSkillRankFn =
+        unsafe { std::mem::transmute(symbols::GET_SKILL_RANK.resolve()?) };
+    let current_hit_points: GetPointsFn =
+        unsafe { std::mem::transmute(symbols::GET_CURRENT_HIT_POINTS.resolve()?) };
+    let max_hit_points: GetPointsFn =
+        unsafe { std::mem::transmute(symbols::GET_MAX_HIT_POINTS.resolve()?) };
+    let get_feat_count: GetFeatCountFn =
+        unsafe { std::mem::transmute(symbols::GET_FEAT_COUNT.resolve()?) };
+    let get_feat: GetFeatFn = unsafe { std::mem::transmute(symbols::GET_FEAT.resolve()?) };
+    let creature = member(slot)?;
+
+    unsafe {
+        Ok(MemberSheet {
+            abilities: std::array::from_fn(|i| get_ability_score(creature, i as i32)),
+            skills: std::array::from_fn(|i| get_skill_rank(creature, i as i32)),
+            hit_points: current_hit_points(creature),
+            max_hit_points: max_hit_points(creature),
+            feats: (0..get_feat_count(creature))
+                .map(|index| get_feat(creature, index))
+                .collect(),
+        })
+    }
+}
+
+/// Sets an ability score, `ability` being one of `ABILITIES`. Must be called on the main thread.
+pub fn set_ability(slot: usize, ability: &str, score: u8) -> io::Result<()> {
+    let set_ability_score: SetAbilityScoreFn =
+        unsafe { std::mem::transmute(symbols::SET_ABILITY_SCORE.resolve()?) };
+    let index = index_of(&ABILITIES, ability, "ability")?;
+    let creature = member(slot)?;
+
+    unsafe { set_ability_score(creature, index, score) };
+    info!("Set {ability} of party slot {slot} to {score}");
+    Ok(())
+}
+
+/// Sets a skill rank, `skill` being one of `SKILLS`. Must be called on the main thread.
+pub fn set_skill(slot: usize, skill: &str, rank: i8) -> io::Result<()> {
+    let set_skill_rank: SetSkillRankFn =
+        unsafe { std::mem::transmute(symbols::SET_SKILL_RANK.resolve()?) };
+    let index = index_of(&SKILLS, skill, "skill")?;
+    let creature = member(slot)?;
+
+    unsafe { set_skill_rank(creature, index, rank) };
+    info!("Set {skill} of party slot {slot} to {rank}");
+    Ok(())
+}
+
+/// Sets current hit points, capped to the maximum. Must be called on the main thread.
+pub fn set_hit_points(slot: usize, hit_points: i16) -> io::Result<()> {
+    let set_current_hit_points: SetCurrentHitPointsFn =
+        unsafe { std::mem::transmute(symbols::SET_CURRENT_HIT_POINTS.resolve()?) };
+    let max_hit_points: GetPointsFn =
+        unsafe { std::mem::transmute(symbols::GET_MAX_HIT_POINTS.resolve()?) };
+    let creature = member(slot)?;
+
+    unsafe {
+        let hit_points = hit_points.min(max_hit_points(creature));
+        set_current_hit_points(creature, hit_points);
+        info!("Set hit points of party slot {slot} to {hit_points}");
+    }
+    Ok(())
+}
+
+/// Grants or takes away a feat, a row of `feat.2da`. Must be called on the main thread.
+pub fn set_feat(slot: usize, feat: u16, granted: bool) -> io::Result<()> {
+    let symbol = if granted {
+        &symbols::ADD_FEAT
+    } else {
+        &symbols::REMOVE_FEAT
+    };
+    let change_feat: FeatFn = unsafe { std::mem::transmute(symbol.resolve()?) };
+    let creature = member(slot)?;
+
+    unsafe { change_feat(creature, feat) };
+    info!(
+        "{} feat {feat} for party slot {slot}",
+        if granted { "Granted" } else { "Removed" }
+    );
+    Ok(())
+}
+
+/// Teaches or takes away a force power, a row of `spells.2da`. Must be called on the main thread.
+pub fn set_force_power(slot: usize, power: u16, granted: bool) -> io::Result<()> {
+    let symbol = if granted {
+        &symbols::ADD_FORCE_POWER
+    } else {
+        &symbols::REMOVE_FORCE_POWER
+    };
+    let change_power: ForcePowerFn = unsafe { std::mem::transmute(symbol.resolve()?) };
+    let creature = member(slot)?;
+
+    unsafe { change_power(creature, power) };
+    info!(
+        "{} force power {power} for party slot {slot}",
+        if granted { "Granted" } else { "Removed" }
+    );
+    Ok(())
+}
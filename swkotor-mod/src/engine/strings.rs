@@ -0,0 +1,170 @@
+/// Replacing dialog.tlk strings at runtime.
+///
+/// Replacements are read from a TOML file mapping StrRefs to text:
+///
+/// ```toml
+/// 12345 = "Power Attack"
+/// 42 = "Fixed typo"
+/// ```
+///
+/// The engine's talk table lookup is hooked and the text it returns is swapped for the
+/// replacement, so fixes show up without touching dialog.tlk. Strings the engine has already
+/// copied, e.g. names of creatures that are already spawned, change on their next lookup.
+use std::{
+    collections::HashMap,
+    ffi::{c_void, CString},
+    fs, io,
+    path::Path,
+    sync::{LazyLock, Mutex, OnceLock},
+};
+
+use log::{error, info};
+
+use crate::{config::config, mem::Detour};
+
+use super::{hooks::ensure_detour, symbols, trace, types::CExoString};
+
+// Verify against the disassembly when locating the function
+const GET_TLK_STRING_PROLOGUE_LENGTH: usize = 6;
+
+/// Replacement text by StrRef, kept as C strings for handing them to the engine
+static REPLACEMENTS: LazyLock<Mutex<HashMap<u32, CString>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+static GET_TLK_STRING_DETOUR: OnceLock<Detour> = OnceLock::new();
+
+type GetTlkStringFn = unsafe extern "thiscall" fn(
+    talk_table: *mut c_void,
+    text: *mut CExoString,
+    strref: u32,
+) -> *mut CExoString;
+type ExoStringAssignFn =
+    unsafe extern "thiscall" fn(string: *mut CExoString, value: *const i8) -> *mut CExoString;
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Reads the StrRef to text mapping from a TOML file.
+fn load_replacements(path: &Path) -> io::Result<HashMap<u32, CString>> {
+    let contents = fs::read_to_string(path)?;
+    let table: HashMap<String, String> =
+        toml::from_str(&contents).map_err(|e| invalid_data(e.to_string()))?;
+
+    table
+        .into_iter()
+        .map(|(strref, text)| {
+            let strref = strref
+                .parse::<u32>()
+                .map_err(|_| invalid_data(format!("'{strref}' is not a StrRef")))?;
+            let text = CString::new(text)
+                .map_err(|_| invalid_data(format!("Text of StrRef {strref} contains a NUL")))?;
+            Ok((strref, text))
+        })
+        .collect()
+}
+
+unsafe extern "thiscall" fn get_tlk_string_hook(
+    talk_table: *mut c_void,
+    text: *mut CExoString,
+    strref: u32,
+) -> *mut CExoString {
+    trace::call(
+        &symbols::GET_TLK_STRING,
+        || format!("strref={strref}"),
+        || {
+            let detour = GET_TLK_STRING_DETOUR
+                .get()
+                .expect("hook should not run before the detour is installed");
+            let original: GetTlkStringFn = std::mem::transmute(detour.trampoline());
+            let result = original(talk_table, text, strref);
+
+            if result.is_null() {
+                return result;
+            }
+
+            if let Some(replacement) = REPLACEMENTS.lock().unwrap().get(&strref) {
+                // The engine frees the text with its own allocator, it has to allocate it too
+                match symbols::EXO_STRING_ASSIGN.resolve() {
+                    Ok(address) => {
+                        let assign: ExoStringAssignFn = std::mem::transmute(address);
+                        assign(result, replacement.as_ptr());
+                    }
+                    Err(e) => error!("Could not replace StrRef {strref}: {e}"),
+                }
+            }
+            result
+        },
+    )
+}
+
+pub(super) fn install_get_tlk_string_hook() -> io::Result<()> {
+    // Replacing needs both, don't hook the lookup just to fail in it
+    symbols::EXO_STRING_ASSIGN.resolve()?;
+    ensure_detour(
+        &GET_TLK_STRING_DETOUR,
+        &symbols::GET_TLK_STRING,
+        GET_TLK_STRING_PROLOGUE_LENGTH,
+        get_tlk_string_hook as GetTlkStringFn as usize,
+    )
+}
+
+/// Returns the replacement text of `strref`, if it has one.
+pub fn replacement(strref: u32) -> Option<String> {
+    REPLACEMENTS
+        .lock()
+        .unwrap()
+        .get(&strref)
+        .map(|text| text.to_string_lossy().into_owned())
+}
+
+/// StrRefs with a replacement, sorted.
+pub fn replaced() -> Vec<u32> {
+    let mut strrefs: Vec<u32> = REPLACEMENTS.lock().unwrap().keys().copied().collect();
+    strrefs.sort_unstable();
+    strrefs
+}
+
+/// Replaces the text of `strref` until the replacements are reloaded. Must be called on the main
+/// thread.
+pub fn set_replacement(strref: u32, text: &str) -> io::Result<()> {
+    let text = CString::new(text)?;
+    install_get_tlk_string_hook()?;
+    REPLACEMENTS.lock().unwrap().insert(strref, text);
+    Ok(())
+}
+
+/// Goes back to the dialog.tlk text of `strref`. Returns false if it wasn't replaced.
+pub fn remove_replacement(strref: u32) -> bool {
+    REPLACEMENTS.lock().unwrap().remove(&strref).is_some()
+}
+
+/// Reads the replacement file again, dropping replacements made from the console. Returns how
+/// many strings are replaced.
+///
+/// Must be called on the main thread.
+pub fn reload() -> io::Result<usize> {
+    let file = config().strings.file.clone();
+    let replacements = load_replacements(Path::new(&file))?;
+    if !replacements.is_empty() {
+        install_get_tlk_string_hook()?;
+    }
+
+    let count = replacements.len();
+    *REPLACEMENTS.lock().unwrap() = replacements;
+    info!("Loaded {count} string replacements from {file}");
+    Ok(count)
+}
+
+/// Loads the replacement file if there is one. Must be called on the main thread.
+pub fn init() {
+    let file = config().strings.file.clone();
+    if !Path::new(&file).is_file() {
+        info!("No {file}, no strings to replace");
+        return;
+    }
+
+    if let Err(e) = reload() {
+        error!("Could not load string replacements from {file}: {e}");
+    }
+}
@@ -0,0 +1,550 @@
+/// Addresses of engine functions and globals the mod hooks or reads.
+///
+/// Addresses live in a symbol file per game build, `data/symbols/<build>.toml`, bundled into the
+/// mod. A file of the same name in the `symbols` directory of the game is read instead when
+/// present, so a newly located function or a patch for another build is a data change. Symbols
+/// are `name = address` pairs under `[symbols]`, patches give their address and the original code:
+///
+/// ```toml
+/// [patches.filter_resolutions]
+/// address = 0x006e09a8
+/// original = [0xe8, 0x03, 0xd9, 0xf0, 0xff]
+/// ```
+///
+/// Engine state that's only reachable through a chain of pointers is described as a pointer path
+/// under `[pointers]`, a symbol and the offsets to follow from it, see `engine::pointers`:
+///
+/// ```toml
+/// [pointers.server_app]
+/// base = "server_exo_app"
+/// offsets = [0x0]
+/// ```
+///
+/// Anything that hasn't been located in the disassembly of the running build is left out of its
+/// file. Features depending on an unresolved symbol refuse to turn on instead of writing to random
+/// memory.
+use std::{collections::HashMap, fmt, fs, io, path::Path, sync::OnceLock};
+
+use log::{error, info};
+use serde::Deserialize;
+
+use super::build::{game_build, GameBuild};
+
+/// Directory in the game directory searched for symbol files before the bundled ones
+pub const SYMBOL_DIRECTORY: &str = "symbols";
+
+const STEAM_SYMBOLS: &str = include_str!("../../data/symbols/steam.toml");
+const ASPYR_SYMBOLS: &str = include_str!("../../data/symbols/aspyr.toml");
+
+/// Code the mod patches, the bytes expected there guard against patching the wrong build.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PatchSite {
+    pub address: usize,
+    /// Every patch so far replaces a single 5 byte call instruction
+    pub original: [u8; 5],
+}
+
+/// Multi-level pointer, followed from the address of the symbol `base`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PointerPath {
+    pub base: String,
+    /// For every offset the pointer at the current address is read and the offset added to it
+    pub offsets: Vec<isize>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct SymbolFile {
+    symbols: HashMap<String, usize>,
+    patches: HashMap<String, PatchSite>,
+    pointers: HashMap<String, PointerPath>,
+}
+
+static SYMBOL_FILE: OnceLock<SymbolFile> = OnceLock::new();
+
+fn parse(contents: &str) -> io::Result<SymbolFile> {
+    toml::from_str(contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn bundled(build: GameBuild) -> &'static str {
+    match build {
+        GameBuild::Steam => STEAM_SYMBOLS,
+        GameBuild::Aspyr => ASPYR_SYMBOLS,
+        GameBuild::Unknown => "",
+    }
+}
+
+/// Reads the symbol file of the running build, the one in the game directory first. A broken
+/// file leaves every symbol unresolved rather than keeping the game from starting.
+fn load() -> SymbolFile {
+    let build = game_build();
+    let file_name = format!("{}.toml", build.to_string().to_lowercase());
+    let path = Path::new(SYMBOL_DIRECTORY).join(&file_name);
+
+    let loaded = match fs::read_to_string(&path) {
+        Ok(contents) => {
+            info!("Reading symbols from {}", path.display());
+            parse(&contents)
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => parse(bundled(build)),
+        Err(e) => Err(e),
+    };
+
+    match loaded {
+        Ok(file) => {
+            info!(
+                "{} symbols, {} patches and {} pointer paths located for the {build} build",
+                file.symbols.len(),
+                file.patches.len(),
+                file.pointers.len()
+            );
+            file
+        }
+        Err(e) => {
+            error!("Could not read the symbols of the {build} build from {file_name}: {e}");
+            SymbolFile::default()
+        }
+    }
+}
+
+fn symbol_file() -> &'static SymbolFile {
+    SYMBOL_FILE.get_or_init(load)
+}
+
+/// Returns where the patch `name` goes in this build.
+pub fn patch_site(name: &str) -> io::Result<PatchSite> {
+    symbol_file().patches.get(name).cloned().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "Patch {name} has not been located for the {} build",
+                game_build()
+            ),
+        )
+    })
+}
+
+/// Address of the symbol `name` in this build, for symbols only known at runtime, e.g. typed into
+/// the console.
+pub fn resolve_name(name: &str) -> io::Result<usize> {
+    symbol_file().symbols.get(name).copied().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("{name} has not been located for the {} build", game_build()),
+        )
+    })
+}
+
+/// Returns the pointer path `name` of this build.
+pub fn pointer_path(name: &str) -> io::Result<PointerPath> {
+    symbol_file().pointers.get(name).cloned().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "Pointer path {name} has not been located for the {} build",
+                game_build()
+            ),
+        )
+    })
+}
+
+pub struct Symbol {
+    /// Key in the symbol files, also used in logs and errors
+    pub name: &'static str,
+}
+
+impl Symbol {
+    const fn named(name: &'static str) -> Self {
+        Symbol { name }
+    }
+
+    fn address(&self) -> Option<usize> {
+        symbol_file().symbols.get(self.name).copied()
+    }
+
+    /// Whether the symbol has been located for this build.
+    pub fn is_resolved(&self) -> bool {
+        self.address().is_some()
+    }
+
+    /// Returns the address of the symbol or an error if it hasn't been located for this build.
+    pub fn resolve(&self) -> io::Result<usize> {
+        resolve_name(self.name)
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.address() {
+            Some(address) => write!(f, "{} - {:#010x}", self.name, address),
+            None => write!(f, "{} - unresolved", self.name),
+        }
+    }
+}
+
+// Walkmesh collision test run for creatures on every movement step. Returns non-zero when the
+// step is blocked. Expected to be __thiscall with three stack arguments (start position, end
+// position and the creature radius), verify this when locating it.
+//
+// TODO(tatu): Locate in the Steam build
+pub const CREATURE_COLLISION_TEST: Symbol = Symbol::named("creature_collision_test");
+
+// Starts the transition to another module, the same path area exits and the debug console take.
+// __thiscall on the server application object, takes the module resref as CExoString.
+//
+// TODO(tatu): Locate in the Steam build
+pub const START_NEW_MODULE: Symbol = Symbol::named("start_new_module");
+
+// Creates one of the premade player characters the main menu's Quick Character button offers and
+// makes it the party leader, skipping character generation. __thiscall on the server application
+// object, takes the class (0 soldier, 1 scout, 2 scoundrel) and the gender (0 male, 1 female),
+// returns zero on failure.
+//
+// TODO(tatu): Locate in the Steam build
+pub const CREATE_QUICK_CHARACTER: Symbol = Symbol::named("create_quick_character");
+
+// Returns the creature in the given active party slot or null for an empty slot. __thiscall on
+// the server application object, slot index as the only argument.
+//
+// TODO(tatu): Locate in the Steam build
+pub const GET_PARTY_MEMBER: Symbol = Symbol::named("get_party_member");
+
+// Applies damage to a creature after resistances have been calculated. __thiscall on the target
+// creature, takes the amount and the object id of the damager. Returns the damage dealt.
+//
+// TODO(tatu): Locate in the Steam build
+pub const APPLY_DAMAGE: Symbol = Symbol::named("apply_damage");
+
+// Deducts force points when a power is cast. __thiscall on the casting creature, takes the amount.
+//
+// TODO(tatu): Locate in the Steam build
+pub const SPEND_FORCE_POINTS: Symbol = Symbol::named("spend_force_points");
+
+// Creates an item from its template into a creature's inventory, the routine behind the
+// CreateItemOnObject script function. __thiscall on the receiving creature, takes the template
+// resref as CExoString and the stack size. Returns the created item or null.
+//
+// TODO(tatu): Locate in the Steam build
+pub const CREATE_ITEM_ON_OBJECT: Symbol = Symbol::named("create_item_on_object");
+
+// Creates an object from its template in the current area, the routine behind the CreateObject
+// script function. __thiscall on the server application object, takes the script's object type
+// constant, the template resref as CExoString, the position as three floats by pointer and the
+// facing in radians. Returns the new object's id, OBJECT_INVALID (0x7f000000) on failure.
+//
+// TODO(tatu): Locate in the Steam build
+pub const CREATE_OBJECT: Symbol = Symbol::named("create_object");
+
+// Plays a Bink movie from the movies directory full screen, the routine behind the PlayMovie
+// script function. __thiscall on the server application object, takes the movie resref as
+// CExoString. The game continues once the movie ends or is skipped.
+//
+// TODO(tatu): Locate in the Steam build
+pub const PLAY_MOVIE: Symbol = Symbol::named("play_movie");
+
+// Looks up the current movement rate of a creature in meters per second, used every time a
+// creature moves. __thiscall on the creature, returns a float.
+//
+// TODO(tatu): Locate in the Steam build
+pub const GET_MOVEMENT_RATE: Symbol = Symbol::named("get_movement_rate");
+
+// Skips the conversation entry currently being spoken, same as pressing space in a dialogue. Stops
+// the voice over as a side effect. __stdcall without arguments, returns zero when there's nothing
+// to skip: no conversation running or the player has to pick a reply.
+//
+// TODO(tatu): Locate in the Steam build
+pub const SKIP_CONVERSATION_ENTRY: Symbol = Symbol::named("skip_conversation_entry");
+
+// Brings up the loading screen when a module or save starts loading. __thiscall on the load screen
+// panel without arguments.
+//
+// TODO(tatu): Locate in the Steam build
+pub const SHOW_LOAD_SCREEN: Symbol = Symbol::named("show_load_screen");
+
+// Takes the loading screen down once the module is ready to play. __thiscall on the load screen
+// panel without arguments.
+//
+// TODO(tatu): Locate in the Steam build
+pub const HIDE_LOAD_SCREEN: Symbol = Symbol::named("hide_load_screen");
+
+// Returns the resref of the module currently loaded, null while no module is loaded. __thiscall on
+// the server application object, returns a pointer to the engine's own CExoString.
+//
+// TODO(tatu): Locate in the Steam build
+pub const GET_MODULE_NAME: Symbol = Symbol::named("get_module_name");
+
+// Loads a module once the transition has started, the area is ready to play when it returns.
+// __thiscall on the server application object, takes the module resref as CExoString and returns
+// non-zero on success.
+//
+// TODO(tatu): Locate in the Steam build
+pub const LOAD_MODULE: Symbol = Symbol::named("load_module");
+
+// Tears the loaded module down, before loading another one or going back to the main menu.
+// __thiscall on the server application object without arguments.
+//
+// TODO(tatu): Locate in the Steam build
+pub const UNLOAD_MODULE: Symbol = Symbol::named("unload_module");
+
+// Hit point and force point getters on a creature's stats, the ones the character sheet uses.
+// __thiscall on the creature without arguments, each returns a 16 bit value.
+//
+// TODO(tatu): Locate in the Steam build
+pub const GET_CURRENT_HIT_POINTS: Symbol = Symbol::named("get_current_hit_points");
+pub const GET_MAX_HIT_POINTS: Symbol = Symbol::named("get_max_hit_points");
+pub const GET_CURRENT_FORCE_POINTS: Symbol = Symbol::named("get_current_force_points");
+pub const GET_MAX_FORCE_POINTS: Symbol = Symbol::named("get_max_force_points");
+
+// Returns the world position of a game object, a pointer to three floats (x, y, z). __thiscall on
+// the object without arguments.
+//
+// TODO(tatu): Locate in the Steam build
+pub const GET_OBJECT_POSITION: Symbol = Symbol::named("get_object_position");
+
+// Returns the direction a game object faces, a pointer to a unit vector of three floats.
+// __thiscall on the object without arguments.
+//
+// TODO(tatu): Locate in the Steam build
+pub const GET_OBJECT_ORIENTATION: Symbol = Symbol::named("get_object_orientation");
+
+// Sets the vertical field of view of a camera in degrees. __thiscall on the camera, takes the field
+// of view as a float. Every camera change goes through here.
+//
+// TODO(tatu): Locate in the Steam build
+pub const SET_CAMERA_FIELD_OF_VIEW: Symbol = Symbol::named("set_camera_field_of_view");
+
+// Sets up the orthographic projection the GUI is drawn with. __thiscall on the renderer, takes the
+// GUI width and height as floats. The GUI is laid out for 4:3, at wider resolutions the engine
+// stretches it to the screen.
+//
+// TODO(tatu): Locate in the Steam build
+pub const SET_GUI_PROJECTION: Symbol = Symbol::named("set_gui_projection");
+
+// Global holding the pointer to the resource manager, which finds resources from the override
+// folder, module archives and the BIFs in that order.
+//
+// TODO(tatu): Locate in the Steam build
+pub const RESOURCE_MANAGER: Symbol = Symbol::named("resource_manager");
+
+// Loads a resource into the resource manager's cache. __thiscall on the resource manager, takes the
+// resref as CResRef by pointer, the resource type and a pointer the data size is written to.
+// Returns the cached data or null when no such resource exists.
+//
+// TODO(tatu): Locate in the Steam build
+pub const DEMAND_RESOURCE: Symbol = Symbol::named("demand_resource");
+
+// Loads a 2DA table into a table object, every table the game uses comes through here once.
+// __thiscall on the table object, takes the table resref as CResRef by pointer. Returns non-zero on
+// success.
+//
+// TODO(tatu): Locate in the Steam build
+pub const LOAD_2DA: Symbol = Symbol::named("load_2da");
+
+// Combined projection and view matrix of the scene camera for the frame being rendered, 16 floats
+// in OpenGL's column major order. Global, updated when the scene is drawn.
+//
+// TODO(tatu): Locate in the Steam build
+pub const CAMERA_VIEW_PROJECTION: Symbol = Symbol::named("camera_view_projection");
+
+// Returns the path a creature is walking, null when it is not moving. __thiscall on the creature
+// without arguments. The path holds a pointer to the waypoints as three floats each, the waypoint
+// count and the index of the waypoint being walked towards.
+//
+// TODO(tatu): Locate in the Steam build
+pub const GET_CREATURE_PATH: Symbol = Symbol::named("get_creature_path");
+
+// The engine's random number generator. Dice rolls, loot and AI decisions all draw from it.
+// RANDOM returns the next 32 bit value, __thiscall on the generator object without arguments.
+// SEED_RANDOM reseeds it, __thiscall on the generator taking the seed as a 32 bit value, the engine
+// seeds from the clock on startup and module loads.
+//
+// TODO(tatu): Locate in the Steam build
+pub const RANDOM: Symbol = Symbol::named("random");
+pub const SEED_RANDOM: Symbol = Symbol::named("seed_random");
+
+// Saves the game into a numbered save slot, the path the save menu takes. __thiscall on the
+// server application object, takes the slot number, the save name as CExoString and a flag to
+// skip the save screen. Returns zero when saving isn't allowed right now.
+//
+// TODO(tatu): Locate in the Steam build
+pub const SAVE_GAME: Symbol = Symbol::named("save_game");
+
+// Loads the save in a numbered slot, the path the load menu takes. __thiscall on the server
+// application object, takes the slot number. Returns zero when there's no such save.
+//
+// TODO(tatu): Locate in the Steam build
+pub const LOAD_GAME: Symbol = Symbol::named("load_game");
+
+// Advances the game world by a frame: AI, movement, combat and scripts. __thiscall on the client
+// application object, takes the time since the previous frame in milliseconds. Returns non-zero
+// to keep the game running. Rendering happens after it in the main loop, not in here.
+//
+// TODO(tatu): Locate in the Steam build
+pub const UPDATE_WORLD: Symbol = Symbol::named("update_world");
+
+// Ability score accessors of a creature, the character sheet and level up go through these.
+// __thiscall on the creature, take the ability index (strength, dexterity, constitution,
+// intelligence, wisdom, charisma) and the getter returns the base score as a byte.
+//
+// TODO(tatu): Locate in the Steam build
+pub const GET_ABILITY_SCORE: Symbol = Symbol::named("get_ability_score");
+pub const SET_ABILITY_SCORE: Symbol = Symbol::named("set_ability_score");
+
+// Skill rank accessors of a creature. __thiscall on the creature, take the skill index, a row of
+// skills.2da, and the getter returns the rank as a signed byte.
+//
+// TODO(tatu): Locate in the Steam build
+pub const GET_SKILL_RANK: Symbol = Symbol::named("get_skill_rank");
+pub const SET_SKILL_RANK: Symbol = Symbol::named("set_skill_rank");
+
+// Sets a creature's current hit points without going through damage. __thiscall on the creature,
+// takes the hit points as a 16 bit value.
+//
+// TODO(tatu): Locate in the Steam build
+pub const SET_CURRENT_HIT_POINTS: Symbol = Symbol::named("set_current_hit_points");
+
+// Feat list of a creature. __thiscall on the creature. GET_FEAT_COUNT takes no arguments,
+// GET_FEAT takes an index into the list and returns the feat, a row of feat.2da. ADD_FEAT and
+// REMOVE_FEAT take the feat.
+//
+// TODO(tatu): Locate in the Steam build
+pub const GET_FEAT_COUNT: Symbol = Symbol::named("get_feat_count");
+pub const GET_FEAT: Symbol = Symbol::named("get_feat");
+pub const ADD_FEAT: Symbol = Symbol::named("add_feat");
+pub const REMOVE_FEAT: Symbol = Symbol::named("remove_feat");
+
+// Force powers a creature knows. __thiscall on the creature, take the power, a row of spells.2da.
+// Powers are added to and removed from the creature's first class that can use the force.
+//
+// TODO(tatu): Locate in the Steam build
+pub const ADD_FORCE_POWER: Symbol = Symbol::named("add_force_power");
+pub const REMOVE_FORCE_POWER: Symbol = Symbol::named("remove_force_power");
+
+// Global variable getters the scripting functions GetGlobalBoolean and GetGlobalNumber use.
+// __thiscall on the server application object, take the variable name as CExoString by pointer.
+// Both return zero for a variable that doesn't exist.
+//
+// TODO(tatu): Locate in the Steam build
+pub const GET_GLOBAL_BOOLEAN: Symbol = Symbol::named("get_global_boolean");
+pub const GET_GLOBAL_NUMBER: Symbol = Symbol::named("get_global_number");
+
+// Setters behind SetGlobalBoolean and SetGlobalNumber. __thiscall on the server application
+// object with the variable name as CExoString by pointer and the value as an int. Setting a
+// variable that doesn't exist does nothing.
+//
+// TODO(tatu): Locate in the Steam build
+pub const SET_GLOBAL_BOOLEAN: Symbol = Symbol::named("set_global_boolean");
+pub const SET_GLOBAL_NUMBER: Symbol = Symbol::named("set_global_number");
+
+// Returns the journal state of a quest, zero when the quest isn't in the journal. __thiscall on
+// the server application object, takes the plot id as CExoString by pointer.
+//
+// TODO(tatu): Locate in the Steam build
+pub const GET_JOURNAL_STATE: Symbol = Symbol::named("get_journal_state");
+
+// Looks up a string of dialog.tlk. __thiscall on the talk table, takes a pointer to the
+// CExoString the text is written into and the StrRef. Returns the pointer it was given.
+//
+// TODO(tatu): Locate in the Steam build
+pub const GET_TLK_STRING: Symbol = Symbol::named("get_tlk_string");
+
+// CExoString assignment from a C string. __thiscall on the CExoString, takes the C string and
+// returns the CExoString. Frees the old text and copies the new one with the engine's allocator.
+//
+// TODO(tatu): Locate in the Steam build
+pub const EXO_STRING_ASSIGN: Symbol = Symbol::named("exo_string_assign");
+
+// Returns the game object the mouse cursor is over, the one its tooltip and action icon are for.
+// __stdcall without arguments, returns null when the cursor is over nothing.
+//
+// TODO(tatu): Locate in the Steam build
+pub const GET_OBJECT_UNDER_CURSOR: Symbol = Symbol::named("get_object_under_cursor");
+
+// Returns the resref of the model a game object is drawn with, the appearance row decides it for
+// creatures. __thiscall on the object without arguments, returns a pointer to the object's CResRef.
+//
+// TODO(tatu): Locate in the Steam build
+pub const GET_OBJECT_MODEL: Symbol = Symbol::named("get_object_model");
+
+// Returns the area the party is in, null in the main menu. __thiscall on the server application
+// object without arguments.
+//
+// TODO(tatu): Locate in the Steam build
+pub const GET_CURRENT_AREA: Symbol = Symbol::named("get_current_area");
+
+// Looks up a game object by its id. __thiscall on the server application object, takes the id and
+// returns the object or null when there's no such object.
+//
+// TODO(tatu): Locate in the Steam build
+pub const GET_GAME_OBJECT: Symbol = Symbol::named("get_game_object");
+
+// Accessors every game object has. __thiscall on the object without arguments. GET_OBJECT_TYPE
+// returns the script's object type constant, GET_OBJECT_TAG a pointer to the object's CExoString,
+// GET_OBJECT_TEMPLATE a pointer to the CResRef of its blueprint and GET_PLOT_FLAG non-zero for plot
+// objects.
+//
+// TODO(tatu): Locate in the Steam build
+pub const GET_OBJECT_TYPE: Symbol = Symbol::named("get_object_type");
+pub const GET_OBJECT_TAG: Symbol = Symbol::named("get_object_tag");
+pub const GET_OBJECT_TEMPLATE: Symbol = Symbol::named("get_object_template");
+pub const GET_PLOT_FLAG: Symbol = Symbol::named("get_plot_flag");
+
+// Door and placeable state. __thiscall on the door or placeable without arguments, non-zero when
+// open or locked.
+//
+// TODO(tatu): Locate in the Steam build
+pub const IS_OPEN: Symbol = Symbol::named("is_open");
+pub const IS_LOCKED: Symbol = Symbol::named("is_locked");
+
+// Local booleans of a game object, the ones GetLocalBoolean and SetLocalBoolean script functions
+// use. Triggers and conversations keep their "already happened" flags in them. __thiscall on the
+// object, take the index (0-63) and for setting the value.
+//
+// TODO(tatu): Locate in the Steam build
+pub const GET_LOCAL_BOOLEAN: Symbol = Symbol::named("get_local_boolean");
+pub const SET_LOCAL_BOOLEAN: Symbol = Symbol::named("set_local_boolean");
+
+// Uses up an item charge or one of a stack, when a medpac, stim or grenade is used. __thiscall on
+// the creature using the item, takes the item and the number used.
+//
+// TODO(tatu): Locate in the Steam build
+pub const USE_ITEM_CHARGES: Symbol = Symbol::named("use_item_charges");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_symbol_files_parse() {
+        for build in [GameBuild::Steam, GameBuild::Aspyr, GameBuild::Unknown] {
+            if let Err(e) = parse(bundled(build)) {
+                panic!("Symbols of the {build} build don't parse: {e}");
+            }
+        }
+    }
+
+    #[test]
+    fn steam_build_has_the_resolution_patch() {
+        let file = parse(STEAM_SYMBOLS).unwrap();
+        let site = &file.patches["filter_resolutions"];
+        assert_eq!(site.address, 0x006e09a8);
+        assert_eq!(site.original, [0xe8, 0x03, 0xd9, 0xf0, 0xff]);
+    }
+
+    #[test]
+    fn bundled_pointer_paths_start_from_known_symbols() {
+        for build in [GameBuild::Steam, GameBuild::Aspyr] {
+            let file = parse(bundled(build)).unwrap();
+            let contents = bundled(build);
+            for (name, path) in &file.pointers {
+                // Bases are listed under [symbols], commented out until located
+                assert!(
+                    contents.contains(&format!("{} =", path.base)),
+                    "Pointer path {name} of the {build} build starts from unknown {}",
+                    path.base
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,243 @@
+/// Runtime 2DA table inspection.
+///
+/// Tables the game loads are recorded by name. Reading a table goes through the resource manager,
+/// so the values are the ones the engine got, override folder included.
+///
+/// 2DA files changed in `override_ext` are reloaded into the tables the game already has, so
+/// balance tweaks show up without a restart. Only changed values are taken, a table that gained
+/// or lost rows or columns needs a restart: the engine sizes its own data by them when it starts.
+use std::{
+    collections::{BTreeMap, HashMap},
+    ffi::c_void,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use log::{error, info, trace, warn};
+
+use crate::formats::twoda::TwoDa;
+use crate::mem::Detour;
+use crate::system::services;
+
+use super::{
+    frame,
+    hooks::ensure_detour,
+    overrides::{self, OVERRIDE_PATH},
+    resources::{read_resource, ResourceType},
+    symbols,
+    types::CResRef,
+};
+
+// Verify against the disassembly when locating the function
+const LOAD_2DA_PROLOGUE_LENGTH: usize = 6;
+
+/// Offset of the resref in the engine's table object. Verify against the disassembly when locating
+/// `load_2da`.
+const TABLE_RESREF_OFFSET: usize = 0x0c;
+
+/// How often the override directory is checked for changed tables
+const WATCH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Addresses of the table objects each table was loaded into, by table name
+static LOADED_TABLES: Mutex<BTreeMap<String, Vec<usize>>> = Mutex::new(BTreeMap::new());
+
+static LOAD_2DA_DETOUR: OnceLock<Detour> = OnceLock::new();
+
+type Load2DaFn = unsafe extern "thiscall" fn(table: *mut c_void, resref: *const CResRef) -> i32;
+
+unsafe extern "thiscall" fn load_2da_hook(table: *mut c_void, resref: *const CResRef) -> i32 {
+    // Plain `trace` is the log macro here
+    super::trace::call(
+        &symbols::LOAD_2DA,
+        || {
+            let name = (!resref.is_null()).then(|| (*resref).to_string_lossy());
+            format!("table={table:?}, resref={name:?}")
+        },
+        || {
+            let detour = LOAD_2DA_DETOUR
+                .get()
+                .expect("hook should not run before the detour is installed");
+            let original: Load2DaFn = std::mem::transmute(detour.trampoline());
+            let result = original(table, resref);
+
+            if result != 0 && !resref.is_null() {
+                let name = (*resref).to_string_lossy();
+                trace!("Loaded 2DA {name}");
+                let mut loaded = LOADED_TABLES.lock().unwrap();
+                let tables = loaded.entry(name).or_default();
+                if !tables.contains(&(table as usize)) {
+                    tables.push(table as usize);
+                }
+            }
+
+            result
+        },
+    )
+}
+
+pub(super) fn install_load_2da_hook() -> io::Result<()> {
+    ensure_detour(
+        &LOAD_2DA_DETOUR,
+        &symbols::LOAD_2DA,
+        LOAD_2DA_PROLOGUE_LENGTH,
+        load_2da_hook as Load2DaFn as usize,
+    )
+}
+
+/// Names of the tables the game has loaded so far.
+pub fn loaded_tables() -> Vec<String> {
+    LOADED_TABLES.lock().unwrap().keys().cloned().collect()
+}
+
+/// Reads and parses the table the engine uses for `name`.
+///
+/// Must be called on the main thread.
+pub fn read_table(name: &str) -> io::Result<TwoDa> {
+    let resref = CResRef::new("2DA", name)?;
+    TwoDa::parse(&read_resource(&resref, ResourceType::TwoDa)?)
+}
+
+/// Whether the object at `table` still holds the table `resref`. Some tables are only loaded for a
+/// moment and freed, those must not be loaded into again.
+unsafe fn holds_table(table: usize, resref: &CResRef) -> bool {
+    let current = &*((table + TABLE_RESREF_OFFSET) as *const CResRef);
+    current.to_string_lossy() == resref.to_string_lossy()
+}
+
+/// Cells that differ between two tables, `None` if the tables differ in rows or columns.
+fn changed_cells(old: &TwoDa, new: &TwoDa) -> Option<usize> {
+    if old.columns != new.columns || old.rows.len() != new.rows.len() {
+        return None;
+    }
+
+    Some(
+        old.rows
+            .iter()
+            .zip(&new.rows)
+            .map(|(old, new)| {
+                old.cells
+                    .iter()
+                    .zip(&new.cells)
+                    .filter(|(old, new)| old != new)
+                    .count()
+            })
+            .sum(),
+    )
+}
+
+/// Picks up the changed `name` table from the override directory and loads it into every table
+/// object the game has for it. Returns how many values changed.
+///
+/// Must be called on the main thread.
+pub fn reload_table(name: &str) -> io::Result<usize> {
+    let resref = CResRef::new("2DA", name)?;
+    let name = resref.to_string_lossy();
+    let old = read_table(&name)?;
+    overrides::rescan()?;
+    let new = read_table(&name)?;
+
+    let changed = changed_cells(&old, &new).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{name}.2da gained or lost rows or columns, restart the game to use it"),
+        )
+    })?;
+    if changed == 0 {
+        return Ok(0);
+    }
+
+    let detour = LOAD_2DA_DETOUR
+        .get()
+        .ok_or_else(|| io::Error::other("2DA loading is not hooked"))?;
+    let load: Load2DaFn = unsafe { std::mem::transmute(detour.trampoline()) };
+
+    let tables = LOADED_TABLES
+        .lock()
+        .unwrap()
+        .get(&name)
+        .cloned()
+        .unwrap_or_default();
+    let mut live = Vec::new();
+    for table in tables {
+        if !unsafe { holds_table(table, &resref) } {
+            trace!("Table object {table:#x} no longer holds {name}");
+            continue;
+        }
+        if unsafe { load(table as *mut c_void, &resref) } == 0 {
+            error!("Engine could not reload {name} into {table:#x}");
+        }
+        live.push(table);
+    }
+
+    let reloaded = live.len();
+    LOADED_TABLES.lock().unwrap().insert(name.clone(), live);
+    info!("Reloaded {name}, {changed} values changed in {reloaded} tables");
+    Ok(changed)
+}
+
+/// Modification times of the 2DA files in the override directory.
+fn table_files() -> HashMap<PathBuf, SystemTime> {
+    let Ok(entries) = fs::read_dir(OVERRIDE_PATH) else {
+        return HashMap::new();
+    };
+
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension.eq_ignore_ascii_case("2da"))
+        })
+        .filter_map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            Some((path, modified))
+        })
+        .collect()
+}
+
+fn watch_tables() {
+    let mut known = table_files();
+    while !services::stopping() {
+        thread::sleep(WATCH_INTERVAL);
+
+        let current = table_files();
+        for (path, modified) in &current {
+            if known.get(path) == Some(modified) {
+                continue;
+            }
+            let Some(name) = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+            else {
+                continue;
+            };
+
+            info!("{} changed, reloading", path.display());
+            frame::run_on_main_thread(move || {
+                if let Err(e) = reload_table(&name) {
+                    warn!("Could not reload {name}: {e}");
+                }
+            });
+        }
+        known = current;
+    }
+}
+
+/// Starts recording loaded tables and watching the override directory for changed ones. Must be
+/// called on the main thread.
+pub fn init() {
+    if let Err(e) = install_load_2da_hook() {
+        error!("Could not hook 2DA loading, loaded tables won't be listed: {e}");
+        return;
+    }
+
+    if !Path::new(OVERRIDE_PATH).is_dir() {
+        return;
+    }
+    if let Err(e) = services::spawn("2da-watcher", watch_tables) {
+        error!("Could not start watching {OVERRIDE_PATH} for changed tables: {e}");
+    }
+}
@@ -0,0 +1,210 @@
+/// Dumping the textures the game loads and replacing them with images from disk.
+///
+/// Textures come through the resource manager like any other resource, the `override_ext` hook
+/// hands every TPC and TGA it sees to `loaded`. Dumped textures are written as TGA, with the TXI
+/// next to them when the texture has one. Replacements are TGA files named after the texture and
+/// may be any size, for a TPC the replacement is converted into an uncompressed TPC keeping the
+/// original's alpha test and TXI.
+///
+/// Like overrides, served replacements are never freed.
+use std::{
+    collections::{HashMap, HashSet},
+    fs::{self, File},
+    io::{self, BufWriter},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        LazyLock, Mutex,
+    },
+};
+
+use log::{debug, error, info};
+
+use crate::{
+    config::config,
+    formats::{tga::Image, tpc::Texture},
+};
+
+use super::{overrides, resources::ResourceType, types::CResRef};
+
+/// Lower case resref and the resource type the texture was served as
+type Key = (String, u16);
+
+static DUMPING: AtomicBool = AtomicBool::new(false);
+
+/// Lower case resrefs already dumped, every texture is written once per session
+static DUMPED: LazyLock<Mutex<HashSet<String>>> = LazyLock::new(|| Mutex::new(HashSet::new()));
+
+/// Replacement images by lower case resref
+static REPLACEMENTS: LazyLock<Mutex<HashMap<String, PathBuf>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Replacement data handed to the engine
+static SERVED: LazyLock<Mutex<HashMap<Key, &'static [u8]>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+pub fn is_texture(resource_type: u16) -> bool {
+    resource_type == ResourceType::Tga as u16 || resource_type == ResourceType::Tpc as u16
+}
+
+/// Converts the texture to a TGA, writing its TXI next to it.
+fn dump_texture(directory: &Path, resref: &str, resource_type: u16, data: &[u8]) -> io::Result<()> {
+    fs::create_dir_all(directory)?;
+    let path = directory.join(format!("{resref}.tga"));
+
+    if resource_type == ResourceType::Tga as u16 {
+        return fs::write(path, data);
+    }
+
+    let texture = Texture::parse(data)?;
+    texture
+        .image
+        .write(&mut BufWriter::new(File::create(path)?))?;
+    if !texture.txi.is_empty() {
+        fs::write(directory.join(format!("{resref}.txi")), &texture.txi)?;
+    }
+    Ok(())
+}
+
+fn dump(resref: &str, resource_type: u16, data: &[u8]) {
+    if !DUMPED.lock().unwrap().insert(resref.to_string()) {
+        return;
+    }
+
+    let directory = PathBuf::from(&config().textures.dump_directory);
+    match dump_texture(&directory, resref, resource_type, data) {
+        Ok(()) => debug!("Dumped texture {resref}"),
+        Err(e) => error!("Could not dump texture {resref}: {e}"),
+    }
+}
+
+/// Reads the replacement image and turns it into the type the engine asked for.
+fn build_replacement(path: &Path, resource_type: u16, original: &[u8]) -> io::Result<Vec<u8>> {
+    let tga = fs::read(path)?;
+    let image = Image::parse(&tga)?;
+    if resource_type == ResourceType::Tga as u16 {
+        return Ok(tga);
+    }
+
+    let original = Texture::parse(original)?;
+    let mut tpc = Vec::new();
+    Texture {
+        image,
+        alpha_test: original.alpha_test,
+        txi: original.txi,
+    }
+    .write(&mut tpc)?;
+    Ok(tpc)
+}
+
+fn replacement(resref: &str, resource_type: u16, original: &[u8]) -> Option<&'static [u8]> {
+    let path = REPLACEMENTS.lock().unwrap().get(resref)?.clone();
+
+    let key = (resref.to_string(), resource_type);
+    let mut served = SERVED.lock().unwrap();
+    if let Some(data) = served.get(&key) {
+        return Some(data);
+    }
+
+    match build_replacement(&path, resource_type, original) {
+        Ok(data) => {
+            info!("Replacing texture {resref} with {}", path.display());
+            let data: &'static [u8] = Box::leak(data.into_boxed_slice());
+            served.insert(key, data);
+            Some(data)
+        }
+        Err(e) => {
+            error!(
+                "Could not replace texture {resref} with {}: {e}",
+                path.display()
+            );
+            None
+        }
+    }
+}
+
+/// Called with every texture the engine loads. Dumps it if dumping is on and returns the
+/// replacement to hand to the engine instead, if there is one.
+pub(super) fn loaded(resref: &CResRef, resource_type: u16, data: &[u8]) -> Option<&'static [u8]> {
+    let resref = resref.to_string_lossy().to_ascii_lowercase();
+    if DUMPING.load(Ordering::Relaxed) {
+        dump(&resref, resource_type, data);
+    }
+    replacement(&resref, resource_type, data)
+}
+
+/// Starts or stops dumping textures. Must be called on the main thread.
+pub fn set_dumping(enabled: bool) -> io::Result<()> {
+    if enabled {
+        overrides::install_demand_resource_hook()?;
+    }
+    DUMPING.store(enabled, Ordering::Relaxed);
+    Ok(())
+}
+
+pub fn is_dumping() -> bool {
+    DUMPING.load(Ordering::Relaxed)
+}
+
+/// Replaced textures, sorted.
+pub fn replacements() -> Vec<String> {
+    let mut names: Vec<String> = REPLACEMENTS.lock().unwrap().keys().cloned().collect();
+    names.sort_unstable();
+    names
+}
+
+/// Indexes the replacement directory again, changed images are read again when the texture is
+/// next loaded. Returns how many replacements there are.
+///
+/// Must be called on the main thread.
+pub fn rescan() -> io::Result<usize> {
+    let directory = PathBuf::from(&config().textures.replace_directory);
+    let mut replacements = HashMap::new();
+
+    for entry in fs::read_dir(&directory)? {
+        let path = entry?.path();
+        let is_tga = path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("tga"));
+        let Some(stem) = path.file_stem().filter(|_| is_tga) else {
+            continue;
+        };
+        replacements.insert(stem.to_string_lossy().to_ascii_lowercase(), path);
+    }
+
+    if !replacements.is_empty() {
+        overrides::install_demand_resource_hook()?;
+    }
+
+    let count = replacements.len();
+    *REPLACEMENTS.lock().unwrap() = replacements;
+    // Already served data stays alive, only the lookup forgets it
+    SERVED.lock().unwrap().clear();
+
+    info!("{count} texture replacements in {}", directory.display());
+    Ok(count)
+}
+
+/// Applies the texture config. Must be called on the main thread.
+pub fn init() {
+    let (dump, replace, replace_directory) = {
+        let config = config();
+        (
+            config.textures.dump,
+            config.textures.replace,
+            config.textures.replace_directory.clone(),
+        )
+    };
+
+    if dump {
+        if let Err(e) = set_dumping(true) {
+            error!("Could not start dumping textures: {e}");
+        }
+    }
+
+    if replace && Path::new(&replace_directory).is_dir() {
+        if let Err(e) = rescan() {
+            error!("Could not load texture replacements from {replace_directory}: {e}");
+        }
+    }
+}
@@ -0,0 +1,245 @@
+/// Enter/exit tracing of hooked engine functions, for reverse engineering and regression hunting.
+///
+/// Tracing a function installs its hook if the feature using it hasn't already, the hook passes
+/// calls through untouched while the feature is off. Every traced call logs its arguments on enter
+/// and its result and duration on exit. Hot functions would drown the log, so each function logs
+/// at most `MAX_CALLS_PER_SECOND` calls a second and notes how many it left out.
+use std::{
+    collections::HashMap,
+    fmt, io,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        LazyLock, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use log::info;
+
+use super::{
+    cheats, clock, display, hooks, loading, module, movement, overrides, rng, saves, strings,
+    symbols::{self, Symbol},
+    tables,
+};
+
+pub const MAX_CALLS_PER_SECOND: u32 = 20;
+
+const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(1);
+
+/// A hooked function that can be traced.
+struct Traceable {
+    symbol: &'static Symbol,
+    install: fn() -> io::Result<()>,
+}
+
+const TRACEABLE: &[Traceable] = &[
+    Traceable {
+        symbol: &symbols::APPLY_DAMAGE,
+        install: cheats::install_apply_damage_hook,
+    },
+    Traceable {
+        symbol: &symbols::SPEND_FORCE_POINTS,
+        install: cheats::install_spend_force_points_hook,
+    },
+    Traceable {
+        symbol: &symbols::USE_ITEM_CHARGES,
+        install: cheats::install_use_item_charges_hook,
+    },
+    Traceable {
+        symbol: &symbols::GET_MOVEMENT_RATE,
+        install: movement::install_get_movement_rate_hook,
+    },
+    Traceable {
+        symbol: &symbols::SET_CAMERA_FIELD_OF_VIEW,
+        install: display::install_set_camera_field_of_view_hook,
+    },
+    Traceable {
+        symbol: &symbols::SET_GUI_PROJECTION,
+        install: display::install_set_gui_projection_hook,
+    },
+    Traceable {
+        symbol: &symbols::SHOW_LOAD_SCREEN,
+        install: loading::install_load_screen_hooks,
+    },
+    Traceable {
+        symbol: &symbols::HIDE_LOAD_SCREEN,
+        install: loading::install_load_screen_hooks,
+    },
+    Traceable {
+        symbol: &symbols::LOAD_MODULE,
+        install: module::install_module_hooks,
+    },
+    Traceable {
+        symbol: &symbols::UNLOAD_MODULE,
+        install: module::install_module_hooks,
+    },
+    Traceable {
+        symbol: &symbols::SAVE_GAME,
+        install: saves::install_save_game_hook,
+    },
+    Traceable {
+        symbol: &symbols::DEMAND_RESOURCE,
+        install: overrides::install_demand_resource_hook,
+    },
+    Traceable {
+        symbol: &symbols::GET_TLK_STRING,
+        install: strings::install_get_tlk_string_hook,
+    },
+    Traceable {
+        symbol: &symbols::LOAD_2DA,
+        install: tables::install_load_2da_hook,
+    },
+    Traceable {
+        symbol: &symbols::UPDATE_WORLD,
+        install: clock::install_update_world_hook,
+    },
+    Traceable {
+        symbol: &symbols::RANDOM,
+        install: rng::install_random_hook,
+    },
+    Traceable {
+        symbol: &symbols::SEED_RANDOM,
+        install: rng::install_seed_random_hook,
+    },
+];
+
+#[derive(Debug)]
+struct RateLimit {
+    window_started: Instant,
+    calls: u32,
+    /// Calls left out of the log in the current window
+    dropped: u32,
+}
+
+impl RateLimit {
+    fn new() -> Self {
+        RateLimit {
+            window_started: Instant::now(),
+            calls: 0,
+            dropped: 0,
+        }
+    }
+
+    /// Returns whether the call should be logged, and how many calls were left out before it
+    /// when a new window starts.
+    fn allow(&mut self, now: Instant) -> (bool, u32) {
+        let mut dropped = 0;
+        if now.saturating_duration_since(self.window_started) >= RATE_LIMIT_WINDOW {
+            dropped = std::mem::take(&mut self.dropped);
+            self.window_started = now;
+            self.calls = 0;
+        }
+
+        if self.calls < MAX_CALLS_PER_SECOND {
+            self.calls += 1;
+            (true, dropped)
+        } else {
+            self.dropped += 1;
+            (false, dropped)
+        }
+    }
+}
+
+/// Skips locking `TRACED` in hooks while nothing is traced
+static ANY_TRACED: AtomicBool = AtomicBool::new(false);
+static TRACED: LazyLock<Mutex<HashMap<&'static str, RateLimit>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn should_log(name: &str) -> bool {
+    if !ANY_TRACED.load(Ordering::Relaxed) {
+        return false;
+    }
+
+    let mut traced = TRACED.lock().unwrap();
+    let Some(rate_limit) = traced.get_mut(name) else {
+        return false;
+    };
+
+    let (allowed, dropped) = rate_limit.allow(Instant::now());
+    if dropped > 0 {
+        info!("{name}: {dropped} calls left out, over {MAX_CALLS_PER_SECOND} a second");
+    }
+    allowed
+}
+
+/// Runs `call` for the hooked function `symbol`, logging the call if it's being traced. `args`
+/// formats the arguments and is only run when logging.
+pub(super) fn call<R: fmt::Debug>(
+    symbol: &Symbol,
+    args: impl FnOnce() -> String,
+    call: impl FnOnce() -> R,
+) -> R {
+    if !should_log(symbol.name) {
+        return call();
+    }
+
+    info!("-> {}({})", symbol.name, args());
+    let started = Instant::now();
+    let result = call();
+    info!("<- {} = {result:?} in {:?}", symbol.name, started.elapsed());
+    result
+}
+
+/// State of the hook on a function that can be traced.
+pub struct HookStatus {
+    pub name: &'static str,
+    /// Located in this build of the game
+    pub resolved: bool,
+    /// Hooked by a feature or by tracing
+    pub installed: bool,
+}
+
+/// Every hookable function and whether it's hooked.
+pub fn hooks() -> Vec<HookStatus> {
+    let installed = hooks::installed_detours();
+    TRACEABLE
+        .iter()
+        .map(|traceable| HookStatus {
+            name: traceable.symbol.name,
+            resolved: traceable.symbol.is_resolved(),
+            installed: installed.iter().any(|name| name == traceable.symbol.name),
+        })
+        .collect()
+}
+
+/// Names of the functions that can be traced.
+pub fn traceable() -> impl Iterator<Item = &'static str> {
+    TRACEABLE.iter().map(|traceable| traceable.symbol.name)
+}
+
+/// Names of the functions being traced.
+pub fn traced() -> Vec<&'static str> {
+    let mut names: Vec<_> = TRACED.lock().unwrap().keys().copied().collect();
+    names.sort_unstable();
+    names
+}
+
+/// Starts or stops tracing `name`.
+///
+/// Must be called on the main thread.
+pub fn set_tracing(name: &str, enabled: bool) -> io::Result<()> {
+    let traceable = TRACEABLE
+        .iter()
+        .find(|traceable| traceable.symbol.name == name)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("'{name}' is not a traceable function"),
+            )
+        })?;
+
+    let mut traced = TRACED.lock().unwrap();
+    if enabled {
+        (traceable.install)()?;
+        traced.insert(traceable.symbol.name, RateLimit::new());
+    } else {
+        traced.remove(traceable.symbol.name);
+    }
+    ANY_TRACED.store(!traced.is_empty(), Ordering::Relaxed);
+
+    info!(
+        "Tracing of {name} {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(())
+}
@@ -0,0 +1,100 @@
+/// Engine data types we need to pass to or read from engine functions.
+use std::{
+    ffi::{c_char, CStr},
+    io,
+};
+
+use crate::mem::Memory;
+
+/// Resource references (module names, item templates etc.) are capped at 16 characters.
+const MAX_RESREF_LENGTH: usize = 16;
+/// Longer engine strings than this are taken for garbage rather than read
+const MAX_EXO_STRING_LENGTH: u32 = 0x10000;
+
+/// Checks that `value` looks like a resref before it gets anywhere near the engine. `kind` is used
+/// in the error message only.
+pub fn validate_resref(kind: &str, value: &str) -> io::Result<()> {
+    let valid = !value.is_empty()
+        && value.len() <= MAX_RESREF_LENGTH
+        && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("'{value}' is not a valid {kind} name"),
+        ))
+    }
+}
+
+/// Fixed size resource reference, padded with nulls and not null terminated when full.
+#[repr(C)]
+pub struct CResRef([u8; MAX_RESREF_LENGTH]);
+
+impl CResRef {
+    /// Validates `value` as a resref of the given `kind`, see `validate_resref`.
+    pub fn new(kind: &str, value: &str) -> io::Result<Self> {
+        validate_resref(kind, value)?;
+
+        let mut resref = [0u8; MAX_RESREF_LENGTH];
+        resref[..value.len()].copy_from_slice(value.to_ascii_lowercase().as_bytes());
+        Ok(CResRef(resref))
+    }
+
+    pub fn to_string_lossy(&self) -> String {
+        let length = self.0.iter().position(|&b| b == 0).unwrap_or(self.0.len());
+        String::from_utf8_lossy(&self.0[..length]).into_owned()
+    }
+}
+
+/// Engine's own string type. The engine copies strings passed to it by reference, so we can
+/// hand it memory we own for the duration of the call.
+#[repr(C)]
+pub struct CExoString {
+    c_string: *const c_char,
+    length: u32,
+}
+
+impl CExoString {
+    /// Borrows `value` for the engine. The returned value must not outlive `value`.
+    pub fn borrowed(value: &CStr) -> Self {
+        CExoString {
+            c_string: value.as_ptr(),
+            length: value.to_bytes().len() as u32,
+        }
+    }
+
+    /// Copies the string out of engine memory. Engine strings are not guaranteed to be UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// `self` has to point to a live engine string.
+    pub unsafe fn to_string_lossy(&self) -> String {
+        if self.c_string.is_null() {
+            return String::new();
+        }
+
+        let bytes = std::slice::from_raw_parts(self.c_string as *const u8, self.length as usize);
+        String::from_utf8_lossy(bytes).into_owned()
+    }
+}
+
+/// Reads the `CExoString` at `address` out of `memory`, the pointer and length then the text.
+pub fn read_exo_string(memory: &impl Memory, address: usize) -> io::Result<String> {
+    let text = memory.read_u32(address)? as usize;
+    let length = memory.read_u32(address + 4)?;
+    if text == 0 {
+        return Ok(String::new());
+    }
+    if length > MAX_EXO_STRING_LENGTH {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("String at {address:#010x} claims to be {length} bytes long"),
+        ));
+    }
+
+    let mut bytes = vec![0u8; length as usize];
+    memory.read(text, &mut bytes)?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
@@ -0,0 +1,273 @@
+/// Memory watches: an address, or a symbol or pointer path from the symbol file plus an offset,
+/// read as a value of a given type.
+///
+/// Watches are typed into the console by mod developers poking at engine state, so their
+/// addresses can't be trusted. Memory is read through `mem::read_memory`, an address that isn't
+/// mapped shows up as an error rather than taking the game down.
+use std::{fmt, io};
+
+use crate::mem::read_memory;
+
+use super::{pointers, symbols};
+
+/// Longest string shown, strings are read up to their terminator or this many bytes
+const STRING_LENGTH: usize = 64;
+const PAGE_SIZE: usize = 0x1000;
+
+/// Where a watch reads from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    Address(usize),
+    /// Symbol file entry, resolved on every read as the file is per build
+    Symbol {
+        name: String,
+        offset: isize,
+    },
+    /// Pointer path from the symbol file, see `engine::pointers`
+    Pointer {
+        name: String,
+        offset: isize,
+    },
+}
+
+/// How the bytes at the target are shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueKind {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    F32,
+    F64,
+    /// 32-bit value in hex
+    Pointer,
+    /// Null terminated ANSI string
+    String,
+}
+
+/// Names accepted by `ValueKind::parse`, in the order the console lists them
+pub const VALUE_KINDS: &[(&str, ValueKind)] = &[
+    ("i8", ValueKind::I8),
+    ("u8", ValueKind::U8),
+    ("i16", ValueKind::I16),
+    ("u16", ValueKind::U16),
+    ("i32", ValueKind::I32),
+    ("u32", ValueKind::U32),
+    ("f32", ValueKind::F32),
+    ("f64", ValueKind::F64),
+    ("ptr", ValueKind::Pointer),
+    ("string", ValueKind::String),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Watch {
+    pub name: String,
+    pub target: Target,
+    pub kind: ValueKind,
+}
+
+fn invalid_input(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+/// Parses `0x1f` as hex and anything else as decimal.
+fn parse_number(text: &str) -> Option<usize> {
+    match text.strip_prefix("0x") {
+        Some(hex) => usize::from_str_radix(hex, 16).ok(),
+        None => text.parse().ok(),
+    }
+}
+
+impl Target {
+    /// Parses `0x007a1234`, `symbol`, `symbol+0x18` and `symbol-4`, or pointer paths with an `@`
+    /// in front, e.g. `@server_app+0x4`.
+    pub fn parse(text: &str) -> io::Result<Target> {
+        if text.starts_with("0x") {
+            return parse_number(text)
+                .map(Target::Address)
+                .ok_or_else(|| invalid_input(format!("Invalid address {text}")));
+        }
+
+        let (pointer, named) = match text.strip_prefix('@') {
+            Some(named) => (true, named),
+            None => (false, text),
+        };
+        let (name, offset) = match named.find(['+', '-']) {
+            Some(sign) => {
+                let offset = parse_number(&named[sign + 1..])
+                    .ok_or_else(|| invalid_input(format!("Invalid offset in {text}")))?
+                    as isize;
+                let offset = if named[sign..].starts_with('-') {
+                    -offset
+                } else {
+                    offset
+                };
+                (&named[..sign], offset)
+            }
+            None => (named, 0),
+        };
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(invalid_input(format!("Invalid symbol name in {text}")));
+        }
+
+        let name = name.to_string();
+        Ok(if pointer {
+            Target::Pointer { name, offset }
+        } else {
+            Target::Symbol { name, offset }
+        })
+    }
+
+    pub fn resolve(&self) -> io::Result<usize> {
+        match self {
+            Target::Address(address) => Ok(*address),
+            Target::Symbol { name, offset } => {
+                Ok(symbols::resolve_name(name)?.wrapping_add_signed(*offset))
+            }
+            Target::Pointer { name, offset } => {
+                Ok(pointers::resolve(name)?.wrapping_add_signed(*offset))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (name, offset) = match self {
+            Target::Address(address) => return write!(f, "{address:#010x}"),
+            Target::Symbol { name, offset } => (name.clone(), *offset),
+            Target::Pointer { name, offset } => (format!("@{name}"), *offset),
+        };
+        match offset {
+            0 => write!(f, "{name}"),
+            offset if offset < 0 => write!(f, "{name}-{:#x}", -offset),
+            offset => write!(f, "{name}+{offset:#x}"),
+        }
+    }
+}
+
+impl ValueKind {
+    pub fn parse(text: &str) -> io::Result<ValueKind> {
+        VALUE_KINDS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(text))
+            .map(|(_, kind)| *kind)
+            .ok_or_else(|| {
+                let names: Vec<&str> = VALUE_KINDS.iter().map(|(name, _)| *name).collect();
+                invalid_input(format!(
+                    "Unknown type {text}, expected one of {}",
+                    names.join(", ")
+                ))
+            })
+    }
+
+    pub fn name(&self) -> &'static str {
+        VALUE_KINDS
+            .iter()
+            .find(|(_, kind)| kind == self)
+            .map(|(name, _)| *name)
+            .unwrap_or_default()
+    }
+
+    /// Bytes read for the value, strings are read until their terminator.
+    fn size(&self) -> usize {
+        match self {
+            ValueKind::I8 | ValueKind::U8 => 1,
+            ValueKind::I16 | ValueKind::U16 => 2,
+            ValueKind::I32 | ValueKind::U32 | ValueKind::F32 | ValueKind::Pointer => 4,
+            ValueKind::F64 => 8,
+            ValueKind::String => STRING_LENGTH,
+        }
+    }
+
+    /// Formats the `size` bytes read for the value.
+    fn format(&self, bytes: &[u8]) -> String {
+        let word = |count: usize| {
+            let mut value = [0u8; 8];
+            value[..count].copy_from_slice(&bytes[..count]);
+            u64::from_le_bytes(value)
+        };
+        match self {
+            ValueKind::I8 => (bytes[0] as i8).to_string(),
+            ValueKind::U8 => bytes[0].to_string(),
+            ValueKind::I16 => (word(2) as u16 as i16).to_string(),
+            ValueKind::U16 => word(2).to_string(),
+            ValueKind::I32 => (word(4) as u32 as i32).to_string(),
+            ValueKind::U32 => word(4).to_string(),
+            ValueKind::F32 => f32::from_bits(word(4) as u32).to_string(),
+            ValueKind::F64 => f64::from_bits(word(8)).to_string(),
+            ValueKind::Pointer => format!("{:#010x}", word(4)),
+            ValueKind::String => {
+                let length = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+                format!("{:?}", String::from_utf8_lossy(&bytes[..length]))
+            }
+        }
+    }
+}
+
+impl Watch {
+    /// Reads and formats the current value.
+    pub fn read(&self) -> io::Result<String> {
+        let address = self.target.resolve()?;
+        let size = match self.kind {
+            // Stop at the end of the page, the string likely ends before it and the next page
+            // may well not be mapped
+            ValueKind::String => self.kind.size().min(PAGE_SIZE - address % PAGE_SIZE),
+            kind => kind.size(),
+        };
+        let mut bytes = vec![0u8; size];
+        read_memory(address, &mut bytes)?;
+        Ok(self.kind.format(&bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_targets_and_formats_values() {
+        assert_eq!(
+            Target::parse("0x007a1234").unwrap(),
+            Target::Address(0x007a1234)
+        );
+        let target = Target::parse("get_module-0x10").unwrap();
+        assert_eq!(
+            target,
+            Target::Symbol {
+                name: "get_module".to_string(),
+                offset: -0x10
+            }
+        );
+        assert_eq!(target.to_string(), "get_module-0x10");
+        assert_eq!(Target::parse("party+24").unwrap().to_string(), "party+0x18");
+        assert_eq!(
+            Target::parse("@server_app+0x4").unwrap(),
+            Target::Pointer {
+                name: "server_app".to_string(),
+                offset: 4
+            }
+        );
+        assert_eq!(
+            Target::parse("@server_app").unwrap().to_string(),
+            "@server_app"
+        );
+        assert!(Target::parse("0xzz").is_err());
+        assert!(Target::parse("+4").is_err());
+
+        assert_eq!(ValueKind::parse("F32").unwrap(), ValueKind::F32);
+        assert!(ValueKind::parse("u128").is_err());
+        assert_eq!(ValueKind::I16.format(&[0xfe, 0xff]), "-2");
+        assert_eq!(ValueKind::F32.format(&1.5f32.to_le_bytes()), "1.5");
+        assert_eq!(
+            ValueKind::Pointer.format(&[0x34, 0x12, 0x7a, 0x00]),
+            "0x007a1234"
+        );
+        assert_eq!(
+            ValueKind::String.format(b"end_m01aa\0junk"),
+            "\"end_m01aa\""
+        );
+    }
+}
@@ -0,0 +1,126 @@
+/// Walkmesh (`BWM V1.0`) parser. Area walkmeshes (`.wok`) use the same format as the door and
+/// placeable ones (`.dwk`, `.pwk`).
+///
+/// Only the geometry and face materials are read, the AABB tree and adjacency data are for the
+/// engine's own queries.
+use std::{collections::BTreeMap, io};
+
+const SIGNATURE: &[u8] = b"BWM V1.0";
+const HEADER_SIZE: usize = 0x88;
+
+/// Surface materials from `surfacemat.2da` that creatures can walk on
+const WALKABLE_MATERIALS: &[u32] = &[1, 3, 4, 5, 6, 9, 10, 11, 12, 13, 14, 16, 18, 20, 21, 22, 30];
+
+pub type Vertex = [f32; 3];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Face {
+    pub vertices: [u32; 3],
+    pub material: u32,
+}
+
+impl Face {
+    pub fn is_walkable(&self) -> bool {
+        WALKABLE_MATERIALS.contains(&self.material)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Walkmesh {
+    /// Area walkmesh vertices are in world coordinates, door and placeable ones are relative to
+    /// the object
+    pub vertices: Vec<Vertex>,
+    pub faces: Vec<Face>,
+}
+
+/// Edge between two vertices, walkable if any face sharing it is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Edge {
+    pub start: Vertex,
+    pub end: Vertex,
+    pub walkable: bool,
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn read_u32(data: &[u8], offset: usize) -> io::Result<u32> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        .ok_or_else(|| invalid_data("Walkmesh ended in the middle of a value"))
+}
+
+fn read_f32(data: &[u8], offset: usize) -> io::Result<f32> {
+    read_u32(data, offset).map(f32::from_bits)
+}
+
+impl Walkmesh {
+    pub fn parse(data: &[u8]) -> io::Result<Walkmesh> {
+        if !data.starts_with(SIGNATURE) || data.len() < HEADER_SIZE {
+            return Err(invalid_data("Not a BWM V1.0 walkmesh"));
+        }
+
+        let vertex_count = read_u32(data, 0x48)? as usize;
+        let vertex_offset = read_u32(data, 0x4C)? as usize;
+        let face_count = read_u32(data, 0x50)? as usize;
+        let face_offset = read_u32(data, 0x54)? as usize;
+        let material_offset = read_u32(data, 0x58)? as usize;
+
+        let vertices = (0..vertex_count)
+            .map(|i| {
+                let offset = vertex_offset + i * 12;
+                Ok([
+                    read_f32(data, offset)?,
+                    read_f32(data, offset + 4)?,
+                    read_f32(data, offset + 8)?,
+                ])
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let faces = (0..face_count)
+            .map(|i| {
+                let offset = face_offset + i * 12;
+                let face = Face {
+                    vertices: [
+                        read_u32(data, offset)?,
+                        read_u32(data, offset + 4)?,
+                        read_u32(data, offset + 8)?,
+                    ],
+                    material: read_u32(data, material_offset + i * 4)?,
+                };
+
+                if face.vertices.iter().any(|&v| v as usize >= vertex_count) {
+                    return Err(invalid_data("Walkmesh face points to a missing vertex"));
+                }
+                Ok(face)
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        Ok(Walkmesh { vertices, faces })
+    }
+
+    /// Returns every edge once, shared edges are merged.
+    pub fn edges(&self) -> Vec<Edge> {
+        let mut edges: BTreeMap<(u32, u32), bool> = BTreeMap::new();
+
+        for face in &self.faces {
+            let [a, b, c] = face.vertices;
+            for (start, end) in [(a, b), (b, c), (c, a)] {
+                let walkable = edges
+                    .entry((start.min(end), start.max(end)))
+                    .or_insert(false);
+                *walkable |= face.is_walkable();
+            }
+        }
+
+        edges
+            .into_iter()
+            .map(|((start, end), walkable)| Edge {
+                start: self.vertices[start as usize],
+                end: self.vertices[end as usize],
+                walkable,
+            })
+            .collect()
+    }
+}
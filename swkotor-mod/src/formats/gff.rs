@@ -0,0 +1,300 @@
+/// GFF (Generic File Format) parser. Most of the game's data is GFF: module info (`.ifo`), area
+/// instances (`.git`), templates (`.utc`, `.uti`, ...), dialogues and saves.
+///
+/// The whole file is read into a tree of structs. Files are small enough that lazy access would
+/// not be worth the trouble.
+use std::io;
+
+const HEADER_SIZE: usize = 56;
+const STRUCT_SIZE: usize = 12;
+const FIELD_SIZE: usize = 12;
+const LABEL_SIZE: usize = 16;
+
+/// Localized string, a StrRef to `dialog.tlk` and optional strings embedded per language
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocString {
+    pub strref: u32,
+    pub strings: Vec<(u32, String)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GffValue {
+    Byte(u8),
+    Char(i8),
+    Word(u16),
+    Short(i16),
+    Dword(u32),
+    Int(i32),
+    Dword64(u64),
+    Int64(i64),
+    Float(f32),
+    Double(f64),
+    String(String),
+    ResRef(String),
+    LocString(LocString),
+    Void(Vec<u8>),
+    Struct(GffStruct),
+    List(Vec<GffStruct>),
+    Orientation([f32; 4]),
+    Vector([f32; 3]),
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GffStruct {
+    pub struct_type: u32,
+    pub fields: Vec<(String, GffValue)>,
+}
+
+impl GffStruct {
+    pub fn get(&self, label: &str) -> Option<&GffValue> {
+        self.fields
+            .iter()
+            .find(|(name, _)| name == label)
+            .map(|(_, value)| value)
+    }
+
+    /// Reads a numeric field as `f32`, whatever its stored type.
+    pub fn get_f32(&self, label: &str) -> Option<f32> {
+        match self.get(label)? {
+            GffValue::Float(value) => Some(*value),
+            GffValue::Double(value) => Some(*value as f32),
+            other => other.as_i64().map(|value| value as f32),
+        }
+    }
+
+    /// Reads an integer field as `i64`, whatever its stored type.
+    pub fn get_i64(&self, label: &str) -> Option<i64> {
+        self.get(label)?.as_i64()
+    }
+
+    /// Reads a string, resref or the first embedded localized string.
+    pub fn get_str(&self, label: &str) -> Option<&str> {
+        match self.get(label)? {
+            GffValue::String(value) | GffValue::ResRef(value) => Some(value),
+            GffValue::LocString(value) => value.strings.first().map(|(_, s)| s.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Returns the list or an empty slice when there's no such list.
+    pub fn get_list(&self, label: &str) -> &[GffStruct] {
+        match self.get(label) {
+            Some(GffValue::List(list)) => list,
+            _ => &[],
+        }
+    }
+}
+
+impl GffValue {
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self {
+            GffValue::Byte(value) => Some(value.into()),
+            GffValue::Char(value) => Some(value.into()),
+            GffValue::Word(value) => Some(value.into()),
+            GffValue::Short(value) => Some(value.into()),
+            GffValue::Dword(value) => Some(value.into()),
+            GffValue::Int(value) => Some(value.into()),
+            GffValue::Dword64(value) => i64::try_from(value).ok(),
+            GffValue::Int64(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gff {
+    /// Four character file type, e.g. `GIT `
+    pub file_type: String,
+    pub root: GffStruct,
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn bytes(data: &[u8], offset: usize, length: usize) -> io::Result<&[u8]> {
+    offset
+        .checked_add(length)
+        .and_then(|end| data.get(offset..end))
+        .ok_or_else(|| invalid_data("GFF ended in the middle of a value"))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> io::Result<u32> {
+    let b = bytes(data, offset, 4)?;
+    Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+fn read_f32s<const N: usize>(data: &[u8], offset: usize) -> io::Result<[f32; N]> {
+    let mut values = [0.0; N];
+    for (i, value) in values.iter_mut().enumerate() {
+        *value = f32::from_bits(read_u32(data, offset + i * 4)?);
+    }
+    Ok(values)
+}
+
+/// Strings are Windows-1252, Latin-1 gets everything but a few punctuation marks right.
+fn decode(bytes: &[u8]) -> String {
+    bytes.iter().map(|&b| b as char).collect()
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    struct_offset: usize,
+    struct_count: usize,
+    field_offset: usize,
+    field_count: usize,
+    label_offset: usize,
+    field_data_offset: usize,
+    field_indices_offset: usize,
+    list_indices_offset: usize,
+}
+
+/// Structs can't legitimately nest deeper than this, deeper means a reference loop
+const MAX_DEPTH: usize = 64;
+
+impl Reader<'_> {
+    fn read_struct(&self, index: usize, depth: usize) -> io::Result<GffStruct> {
+        if index >= self.struct_count || depth > MAX_DEPTH {
+            return Err(invalid_data("GFF struct index out of range"));
+        }
+
+        let offset = self.struct_offset + index * STRUCT_SIZE;
+        let struct_type = read_u32(self.data, offset)?;
+        let data_or_offset = read_u32(self.data, offset + 4)? as usize;
+        let field_count = read_u32(self.data, offset + 8)? as usize;
+
+        // Single field structs point to the field directly
+        let field_indices = if field_count == 1 {
+            vec![data_or_offset]
+        } else {
+            (0..field_count)
+                .map(|i| {
+                    read_u32(
+                        self.data,
+                        self.field_indices_offset + data_or_offset + i * 4,
+                    )
+                    .map(|index| index as usize)
+                })
+                .collect::<io::Result<Vec<_>>>()?
+        };
+
+        let fields = field_indices
+            .into_iter()
+            .map(|field| self.read_field(field, depth))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        Ok(GffStruct {
+            struct_type,
+            fields,
+        })
+    }
+
+    fn read_field(&self, index: usize, depth: usize) -> io::Result<(String, GffValue)> {
+        if index >= self.field_count {
+            return Err(invalid_data("GFF field index out of range"));
+        }
+
+        let offset = self.field_offset + index * FIELD_SIZE;
+        let field_type = read_u32(self.data, offset)?;
+        let label_index = read_u32(self.data, offset + 4)? as usize;
+        let raw = bytes(self.data, offset + 8, 4)?;
+        let inline = read_u32(self.data, offset + 8)?;
+        let data = self.field_data_offset + inline as usize;
+
+        let label = bytes(
+            self.data,
+            self.label_offset + label_index * LABEL_SIZE,
+            LABEL_SIZE,
+        )?;
+        let label_length = label.iter().position(|&b| b == 0).unwrap_or(LABEL_SIZE);
+        let label = decode(&label[..label_length]);
+
+        let value = match field_type {
+            0 => GffValue::Byte(raw[0]),
+            1 => GffValue::Char(raw[0] as i8),
+            2 => GffValue::Word(u16::from_le_bytes([raw[0], raw[1]])),
+            3 => GffValue::Short(i16::from_le_bytes([raw[0], raw[1]])),
+            4 => GffValue::Dword(inline),
+            5 => GffValue::Int(inline as i32),
+            6 | 7 | 9 => {
+                let b = bytes(self.data, data, 8)?;
+                let raw = u64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]);
+                match field_type {
+                    6 => GffValue::Dword64(raw),
+                    7 => GffValue::Int64(raw as i64),
+                    _ => GffValue::Double(f64::from_bits(raw)),
+                }
+            }
+            8 => GffValue::Float(f32::from_bits(inline)),
+            10 => {
+                let length = read_u32(self.data, data)? as usize;
+                GffValue::String(decode(bytes(self.data, data + 4, length)?))
+            }
+            11 => {
+                let length = bytes(self.data, data, 1)?[0] as usize;
+                GffValue::ResRef(decode(bytes(self.data, data + 1, length)?))
+            }
+            12 => {
+                // Total size comes first, then the StrRef and the embedded strings
+                let strref = read_u32(self.data, data + 4)?;
+                let count = read_u32(self.data, data + 8)? as usize;
+                let mut strings = Vec::new();
+                let mut offset = data + 12;
+                for _ in 0..count {
+                    let id = read_u32(self.data, offset)?;
+                    let length = read_u32(self.data, offset + 4)? as usize;
+                    strings.push((id, decode(bytes(self.data, offset + 8, length)?)));
+                    offset += 8 + length;
+                }
+                GffValue::LocString(LocString { strref, strings })
+            }
+            13 => {
+                let length = read_u32(self.data, data)? as usize;
+                GffValue::Void(bytes(self.data, data + 4, length)?.to_vec())
+            }
+            14 => GffValue::Struct(self.read_struct(inline as usize, depth + 1)?),
+            15 => {
+                let list = self.list_indices_offset + inline as usize;
+                let count = read_u32(self.data, list)? as usize;
+                let structs = (0..count)
+                    .map(|i| {
+                        let index = read_u32(self.data, list + 4 + i * 4)? as usize;
+                        self.read_struct(index, depth + 1)
+                    })
+                    .collect::<io::Result<Vec<_>>>()?;
+                GffValue::List(structs)
+            }
+            16 => GffValue::Orientation(read_f32s(self.data, data)?),
+            17 => GffValue::Vector(read_f32s(self.data, data)?),
+            _ => return Err(invalid_data("Unknown GFF field type")),
+        };
+
+        Ok((label, value))
+    }
+}
+
+impl Gff {
+    pub fn parse(data: &[u8]) -> io::Result<Gff> {
+        if data.len() < HEADER_SIZE || &data[4..8] != b"V3.2" {
+            return Err(invalid_data("Not a GFF V3.2 file"));
+        }
+
+        let header = |index: usize| read_u32(data, 8 + index * 4).map(|value| value as usize);
+        let reader = Reader {
+            data,
+            struct_offset: header(0)?,
+            struct_count: header(1)?,
+            field_offset: header(2)?,
+            field_count: header(3)?,
+            label_offset: header(4)?,
+            field_data_offset: header(6)?,
+            field_indices_offset: header(8)?,
+            list_indices_offset: header(10)?,
+        };
+
+        Ok(Gff {
+            file_type: decode(&data[0..4]),
+            root: reader.read_struct(0, 0)?,
+        })
+    }
+}
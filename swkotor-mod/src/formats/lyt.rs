@@ -0,0 +1,72 @@
+/// Area layout (`.lyt`) parser. Layouts are text files listing the rooms of an area, each room
+/// has a model and a walkmesh with the same name.
+///
+/// ```text
+/// beginlayout
+/// roomcount 2
+///   m01aa_01a 0.0 0.0 0.0
+///   m01aa_01b 10.0 0.0 0.0
+/// donelayout
+/// ```
+///
+/// Track, obstacle and door hook sections are skipped.
+use std::io;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Room {
+    pub name: String,
+    pub position: [f32; 3],
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Layout {
+    pub rooms: Vec<Room>,
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+impl Layout {
+    pub fn parse(text: &str) -> io::Result<Layout> {
+        let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+        let mut rooms = Vec::new();
+
+        while let Some(line) = lines.next() {
+            let mut words = line.split_whitespace();
+            if words.next() != Some("roomcount") {
+                continue;
+            }
+
+            let count: usize = words
+                .next()
+                .and_then(|count| count.parse().ok())
+                .ok_or_else(|| invalid_data(format!("Invalid room count line '{line}'")))?;
+
+            for _ in 0..count {
+                let line = lines
+                    .next()
+                    .ok_or_else(|| invalid_data("Layout ended before all rooms".to_string()))?;
+                rooms.push(parse_room(line)?);
+            }
+        }
+
+        Ok(Layout { rooms })
+    }
+}
+
+fn parse_room(line: &str) -> io::Result<Room> {
+    let invalid = || invalid_data(format!("Invalid room line '{line}'"));
+
+    let mut words = line.split_whitespace();
+    let name = words.next().ok_or_else(invalid)?.to_ascii_lowercase();
+    let mut position = [0.0; 3];
+    for coordinate in &mut position {
+        *coordinate = words
+            .next()
+            .and_then(|value| value.parse().ok())
+            .ok_or_else(invalid)?;
+    }
+
+    Ok(Room { name, position })
+}
@@ -0,0 +1,160 @@
+/// Binary model (`.mdl`) header parser, for diagnostics.
+///
+/// A model is split in two files: the `.mdl` has the node tree, animations and mesh headers, the
+/// `.mdx` the vertex data the meshes point into. Only the headers are read here: the model's
+/// geometry header, its model header and the header of each animation. Offsets inside the model
+/// are relative to the end of the 12 byte file header.
+use std::io;
+
+const FILE_HEADER_SIZE: usize = 12;
+/// Geometry header (80 bytes) followed by the model header proper
+const MODEL_HEADER_SIZE: usize = 196;
+/// Geometry header of the animation followed by its own fields
+const ANIMATION_HEADER_SIZE: usize = 136;
+const NAME_LENGTH: usize = 32;
+
+/// What the model is for, from the classification byte of the model header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Classification {
+    Other,
+    Effect,
+    Tile,
+    Character,
+    Door,
+    Lightsaber,
+    Placeable,
+    Flyer,
+    Unknown(u8),
+}
+
+impl Classification {
+    fn from_byte(byte: u8) -> Classification {
+        match byte {
+            0x00 => Classification::Other,
+            0x01 => Classification::Effect,
+            0x02 => Classification::Tile,
+            0x04 => Classification::Character,
+            0x08 => Classification::Door,
+            0x10 => Classification::Lightsaber,
+            0x20 => Classification::Placeable,
+            0x40 => Classification::Flyer,
+            other => Classification::Unknown(other),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Animation {
+    pub name: String,
+    /// Length in seconds
+    pub length: f32,
+    /// Seconds blended from the previous animation
+    pub transition: f32,
+    /// Node the animation starts from, usually the model's root
+    pub root: String,
+    pub node_count: u32,
+    pub event_count: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelHeader {
+    pub name: String,
+    /// Model animations are inherited from, `NULL` when none
+    pub supermodel: String,
+    pub classification: Classification,
+    pub node_count: u32,
+    /// Names of every node, including the ones only animations have
+    pub node_name_count: u32,
+    pub bounding_box: ([f32; 3], [f32; 3]),
+    pub radius: f32,
+    pub animation_scale: f32,
+    /// Size of the vertex data in the `.mdx`
+    pub mdx_size: u32,
+    pub animations: Vec<Animation>,
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn read_u32(data: &[u8], offset: usize) -> io::Result<u32> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        .ok_or_else(|| invalid_data("Model ended in the middle of a value"))
+}
+
+fn read_f32(data: &[u8], offset: usize) -> io::Result<f32> {
+    read_u32(data, offset).map(f32::from_bits)
+}
+
+fn read_vector(data: &[u8], offset: usize) -> io::Result<[f32; 3]> {
+    Ok([
+        read_f32(data, offset)?,
+        read_f32(data, offset + 4)?,
+        read_f32(data, offset + 8)?,
+    ])
+}
+
+/// Null padded name of `NAME_LENGTH` bytes.
+fn read_name(data: &[u8], offset: usize) -> io::Result<String> {
+    let bytes = data
+        .get(offset..offset + NAME_LENGTH)
+        .ok_or_else(|| invalid_data("Model ended in the middle of a name"))?;
+    let length = bytes.iter().position(|&b| b == 0).unwrap_or(NAME_LENGTH);
+    Ok(String::from_utf8_lossy(&bytes[..length]).into_owned())
+}
+
+fn parse_animation(model: &[u8], offset: usize) -> io::Result<Animation> {
+    if model.len() < offset + ANIMATION_HEADER_SIZE {
+        return Err(invalid_data(
+            "Animation header points past the end of the model",
+        ));
+    }
+
+    Ok(Animation {
+        name: read_name(model, offset + 8)?,
+        node_count: read_u32(model, offset + 44)?,
+        length: read_f32(model, offset + 80)?,
+        transition: read_f32(model, offset + 84)?,
+        root: read_name(model, offset + 88)?,
+        event_count: read_u32(model, offset + 124)?,
+    })
+}
+
+impl ModelHeader {
+    /// Parses the headers of a binary `.mdl`. ASCII models, which the game can't load either, are
+    /// rejected.
+    pub fn parse(data: &[u8]) -> io::Result<ModelHeader> {
+        if data.len() < FILE_HEADER_SIZE + MODEL_HEADER_SIZE {
+            return Err(invalid_data("Too short for a model"));
+        }
+        // Binary models start with a zero, ASCII ones with text
+        if read_u32(data, 0)? != 0 {
+            return Err(invalid_data("Not a binary model"));
+        }
+        let mdx_size = read_u32(data, 8)?;
+        let model = &data[FILE_HEADER_SIZE..];
+
+        let animation_offset = read_u32(model, 88)? as usize;
+        let animation_count = read_u32(model, 92)? as usize;
+        let animations = (0..animation_count)
+            .map(|i| {
+                let offset = read_u32(model, animation_offset + i * 4)? as usize;
+                parse_animation(model, offset)
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        Ok(ModelHeader {
+            name: read_name(model, 8)?,
+            node_count: read_u32(model, 44)?,
+            classification: Classification::from_byte(model[80]),
+            bounding_box: (read_vector(model, 104)?, read_vector(model, 116)?),
+            radius: read_f32(model, 128)?,
+            animation_scale: read_f32(model, 132)?,
+            supermodel: read_name(model, 136)?,
+            node_name_count: read_u32(model, 188)?,
+            mdx_size,
+            animations,
+        })
+    }
+}
@@ -0,0 +1,8 @@
+pub mod bwm;
+pub mod gff;
+pub mod lyt;
+pub mod mdl;
+pub mod tga;
+pub mod tlk;
+pub mod tpc;
+pub mod twoda;
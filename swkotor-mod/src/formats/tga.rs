@@ -0,0 +1,163 @@
+/// Truevision TGA reader and writer, for the textures the game loads as loose `.tga` files.
+///
+/// Reads uncompressed and run-length encoded true color and grayscale images, which is what the
+/// game and common image editors produce. Color mapped images are not supported. Writes
+/// uncompressed 32-bit images.
+use std::io::{self, Write};
+
+const HEADER_SIZE: usize = 18;
+
+// Image types
+const TRUE_COLOR: u8 = 2;
+const GRAYSCALE: u8 = 3;
+const RLE_TRUE_COLOR: u8 = 10;
+const RLE_GRAYSCALE: u8 = 11;
+
+/// Image descriptor bit telling the first row is the top one
+const TOP_TO_BOTTOM: u8 = 0x20;
+/// Image descriptor bits for 8 alpha bits per pixel
+const ALPHA_8: u8 = 0x08;
+
+/// RGBA pixels, rows from the bottom up as TGA stores them and OpenGL takes them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<u8>,
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn read_u16(data: &[u8], offset: usize) -> io::Result<u16> {
+    data.get(offset..offset + 2)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+        .ok_or_else(|| invalid_data("TGA ended in the middle of the header"))
+}
+
+/// Converts a stored pixel, BGR(A) or gray, to RGBA.
+fn to_rgba(pixel: &[u8]) -> [u8; 4] {
+    match *pixel {
+        [gray] => [gray, gray, gray, 0xff],
+        [blue, green, red] => [red, green, blue, 0xff],
+        [blue, green, red, alpha] => [red, green, blue, alpha],
+        _ => unreachable!("pixel sizes are checked when parsing the header"),
+    }
+}
+
+impl Image {
+    pub fn parse(data: &[u8]) -> io::Result<Image> {
+        if data.len() < HEADER_SIZE {
+            return Err(invalid_data("Not a TGA image"));
+        }
+
+        let id_length = data[0] as usize;
+        let color_map_type = data[1];
+        let image_type = data[2];
+        let width = read_u16(data, 12)? as u32;
+        let height = read_u16(data, 14)? as u32;
+        let bits_per_pixel = data[16];
+        let descriptor = data[17];
+
+        if width == 0 || height == 0 {
+            return Err(invalid_data("TGA image has no pixels"));
+        }
+        if color_map_type != 0 {
+            return Err(invalid_data("Color mapped TGA images are not supported"));
+        }
+        let pixel_size = match (image_type, bits_per_pixel) {
+            (TRUE_COLOR | RLE_TRUE_COLOR, 24) => 3,
+            (TRUE_COLOR | RLE_TRUE_COLOR, 32) => 4,
+            (GRAYSCALE | RLE_GRAYSCALE, 8) => 1,
+            _ => {
+                return Err(invalid_data(&format!(
+                    "Unsupported TGA image type {image_type} with {bits_per_pixel} bits per pixel"
+                )))
+            }
+        };
+
+        let pixel_count = width as usize * height as usize;
+        let mut stored = data
+            .get(HEADER_SIZE + id_length..)
+            .ok_or_else(|| invalid_data("TGA ended before the image data"))?;
+        let mut pixels = Vec::with_capacity(pixel_count * 4);
+
+        if image_type == RLE_TRUE_COLOR || image_type == RLE_GRAYSCALE {
+            while pixels.len() < pixel_count * 4 {
+                let (&packet, rest) = stored
+                    .split_first()
+                    .ok_or_else(|| invalid_data("TGA ended in the middle of the image data"))?;
+                let count = (packet & 0x7f) as usize + 1;
+                let packet_size = if packet & 0x80 != 0 {
+                    pixel_size
+                } else {
+                    count * pixel_size
+                };
+                let packet_data = rest
+                    .get(..packet_size)
+                    .ok_or_else(|| invalid_data("TGA ended in the middle of the image data"))?;
+
+                if packet & 0x80 != 0 {
+                    let pixel = to_rgba(packet_data);
+                    for _ in 0..count {
+                        pixels.extend_from_slice(&pixel);
+                    }
+                } else {
+                    for pixel in packet_data.chunks_exact(pixel_size) {
+                        pixels.extend_from_slice(&to_rgba(pixel));
+                    }
+                }
+                stored = &rest[packet_size..];
+            }
+            // A run may cross the end of the image
+            pixels.truncate(pixel_count * 4);
+        } else {
+            let stored = stored
+                .get(..pixel_count * pixel_size)
+                .ok_or_else(|| invalid_data("TGA ended in the middle of the image data"))?;
+            for pixel in stored.chunks_exact(pixel_size) {
+                pixels.extend_from_slice(&to_rgba(pixel));
+            }
+        }
+
+        if descriptor & TOP_TO_BOTTOM != 0 {
+            let row_length = width as usize * 4;
+            pixels = pixels
+                .chunks_exact(row_length)
+                .rev()
+                .flatten()
+                .copied()
+                .collect();
+        }
+
+        Ok(Image {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    /// Writes the image as an uncompressed 32-bit TGA.
+    pub fn write(&self, writer: &mut impl Write) -> io::Result<()> {
+        let width =
+            u16::try_from(self.width).map_err(|_| invalid_data("Image is too wide for a TGA"))?;
+        let height =
+            u16::try_from(self.height).map_err(|_| invalid_data("Image is too tall for a TGA"))?;
+
+        let mut header = [0u8; HEADER_SIZE];
+        header[2] = TRUE_COLOR;
+        header[12..14].copy_from_slice(&width.to_le_bytes());
+        header[14..16].copy_from_slice(&height.to_le_bytes());
+        header[16] = 32;
+        header[17] = ALPHA_8;
+        writer.write_all(&header)?;
+
+        let stored: Vec<u8> = self
+            .pixels
+            .chunks_exact(4)
+            .flat_map(|pixel| [pixel[2], pixel[1], pixel[0], pixel[3]])
+            .collect();
+        writer.write_all(&stored)
+    }
+}
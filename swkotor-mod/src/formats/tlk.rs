@@ -0,0 +1,91 @@
+/// Talk table (`TLK V3.0`) parser, the `dialog.tlk` holding every string the game shows.
+///
+/// Strings are referenced by index, a StrRef. The table is kept as raw bytes and strings are
+/// decoded on lookup, `dialog.tlk` is big and diagnostics only ever need a handful of strings.
+use std::{fs, io, path::Path};
+
+const SIGNATURE: &[u8] = b"TLK V3.0";
+const HEADER_SIZE: usize = 20;
+const ENTRY_SIZE: usize = 40;
+
+/// Entry flag telling the entry has text, entries without it are placeholders
+const TEXT_PRESENT: u32 = 0x1;
+
+pub struct TalkTable {
+    data: Vec<u8>,
+    language_id: u32,
+    string_count: u32,
+    strings_offset: usize,
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+impl TalkTable {
+    pub fn load(path: &Path) -> io::Result<TalkTable> {
+        TalkTable::parse(fs::read(path)?)
+    }
+
+    pub fn parse(data: Vec<u8>) -> io::Result<TalkTable> {
+        if !data.starts_with(SIGNATURE) {
+            return Err(invalid_data("Not a TLK V3.0 file"));
+        }
+
+        let header = |offset| read_u32(&data, offset).ok_or_else(|| invalid_data("TLK too short"));
+        let language_id = header(8)?;
+        let string_count = header(12)?;
+        let strings_offset = header(16)? as usize;
+
+        if HEADER_SIZE + string_count as usize * ENTRY_SIZE > data.len() {
+            return Err(invalid_data(
+                "TLK entry table goes past the end of the file",
+            ));
+        }
+
+        Ok(TalkTable {
+            data,
+            language_id,
+            string_count,
+            strings_offset,
+        })
+    }
+
+    pub fn language_id(&self) -> u32 {
+        self.language_id
+    }
+
+    pub fn len(&self) -> usize {
+        self.string_count as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.string_count == 0
+    }
+
+    /// Returns the string for `strref`, `None` for unknown StrRefs and entries without text.
+    pub fn get(&self, strref: u32) -> Option<String> {
+        if strref >= self.string_count {
+            return None;
+        }
+
+        let entry = HEADER_SIZE + strref as usize * ENTRY_SIZE;
+        let flags = read_u32(&self.data, entry)?;
+        if flags & TEXT_PRESENT == 0 {
+            return None;
+        }
+
+        // Flags, sound resref and the volume and pitch variances come before the string location
+        let offset = self.strings_offset + read_u32(&self.data, entry + 28)? as usize;
+        let size = read_u32(&self.data, entry + 32)? as usize;
+        let bytes = self.data.get(offset..offset + size)?;
+
+        // Strings are Windows-1252, Latin-1 gets everything but a few punctuation marks right
+        Some(bytes.iter().map(|&b| b as char).collect())
+    }
+}
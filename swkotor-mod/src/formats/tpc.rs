@@ -0,0 +1,242 @@
+/// Texture (`.tpc`) reader and writer. TPC is the engine's own texture format: a 128 byte header,
+/// the mipmaps either uncompressed or DXT compressed, then optionally the texture's TXI text.
+///
+/// Only the top mipmap is decoded. Cube maps, six faces stacked on top of each other, are not
+/// supported. Writes uncompressed RGBA textures with a single mipmap, the engine is fine without
+/// the rest.
+use std::io::{self, Write};
+
+use super::tga::Image;
+
+const HEADER_SIZE: usize = 128;
+
+// Pixel encodings, RGB and RGBA are DXT1 and DXT5 when compressed
+const GRAYSCALE: u8 = 1;
+const RGB: u8 = 2;
+const RGBA: u8 = 4;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Texture {
+    /// Rows in the order the engine uploads them, the same as in a TGA
+    pub image: Image,
+    /// Alpha below which pixels are discarded
+    pub alpha_test: f32,
+    /// TXI text after the pixel data, empty when there is none
+    pub txi: Vec<u8>,
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn read_u16(data: &[u8], offset: usize) -> io::Result<u16> {
+    data.get(offset..offset + 2)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+        .ok_or_else(|| invalid_data("TPC ended in the middle of the header"))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> io::Result<u32> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        .ok_or_else(|| invalid_data("TPC ended in the middle of the header"))
+}
+
+/// Size in bytes of a mipmap of the given size.
+fn mipmap_size(width: usize, height: usize, encoding: u8, compressed: bool) -> usize {
+    if compressed {
+        let blocks = width.div_ceil(4).max(1) * height.div_ceil(4).max(1);
+        let block_size = if encoding == RGB { 8 } else { 16 };
+        blocks * block_size
+    } else {
+        let pixel_size = match encoding {
+            GRAYSCALE => 1,
+            RGB => 3,
+            _ => 4,
+        };
+        width * height * pixel_size
+    }
+}
+
+fn rgb565(color: u16) -> [u8; 3] {
+    let red = (color >> 11 & 0x1f) as u8;
+    let green = (color >> 5 & 0x3f) as u8;
+    let blue = (color & 0x1f) as u8;
+    [
+        red << 3 | red >> 2,
+        green << 2 | green >> 4,
+        blue << 3 | blue >> 2,
+    ]
+}
+
+fn mix(a: [u8; 3], b: [u8; 3], a_weight: u16, b_weight: u16) -> [u8; 3] {
+    let total = a_weight + b_weight;
+    [0, 1, 2].map(|i| ((a[i] as u16 * a_weight + b[i] as u16 * b_weight) / total) as u8)
+}
+
+/// Decodes the color half of a DXT block into 16 RGBA pixels. DXT1 blocks with the first color
+/// not above the second have three colors and transparent black.
+fn decode_color_block(block: &[u8], dxt1: bool) -> [[u8; 4]; 16] {
+    let first = u16::from_le_bytes([block[0], block[1]]);
+    let second = u16::from_le_bytes([block[2], block[3]]);
+    let indices = u32::from_le_bytes([block[4], block[5], block[6], block[7]]);
+
+    let (c0, c1) = (rgb565(first), rgb565(second));
+    let opaque = |[r, g, b]: [u8; 3]| [r, g, b, 0xff];
+    let palette = if first > second || !dxt1 {
+        [
+            opaque(c0),
+            opaque(c1),
+            opaque(mix(c0, c1, 2, 1)),
+            opaque(mix(c0, c1, 1, 2)),
+        ]
+    } else {
+        [opaque(c0), opaque(c1), opaque(mix(c0, c1, 1, 1)), [0; 4]]
+    };
+
+    std::array::from_fn(|i| palette[(indices >> (i * 2) & 0x3) as usize])
+}
+
+/// Decodes the alpha half of a DXT5 block into 16 alpha values.
+fn decode_alpha_block(block: &[u8]) -> [u8; 16] {
+    let (a0, a1) = (block[0] as u16, block[1] as u16);
+    let mut bits = [0u8; 8];
+    bits[..6].copy_from_slice(&block[2..8]);
+    let indices = u64::from_le_bytes(bits);
+
+    let alpha = |index: u16| -> u8 {
+        match index {
+            0 => a0 as u8,
+            1 => a1 as u8,
+            _ if a0 > a1 => ((a0 * (8 - index) + a1 * (index - 1)) / 7) as u8,
+            6 => 0,
+            7 => 0xff,
+            _ => ((a0 * (6 - index) + a1 * (index - 1)) / 5) as u8,
+        }
+    };
+
+    std::array::from_fn(|i| alpha((indices >> (i * 3) & 0x7) as u16))
+}
+
+fn decode_dxt(data: &[u8], width: usize, height: usize, dxt1: bool) -> Vec<u8> {
+    let block_size = if dxt1 { 8 } else { 16 };
+    let blocks_across = width.div_ceil(4);
+    let mut pixels = vec![0u8; width * height * 4];
+
+    for (index, block) in data.chunks_exact(block_size).enumerate() {
+        let (block_x, block_y) = (index % blocks_across * 4, index / blocks_across * 4);
+        let (colors, alphas) = if dxt1 {
+            (decode_color_block(block, true), None)
+        } else {
+            (
+                decode_color_block(&block[8..], false),
+                Some(decode_alpha_block(block)),
+            )
+        };
+
+        for (i, mut color) in colors.into_iter().enumerate() {
+            let (x, y) = (block_x + i % 4, block_y + i / 4);
+            // Blocks of textures smaller than 4x4 hang over the edge
+            if x >= width || y >= height {
+                continue;
+            }
+            if let Some(alphas) = alphas {
+                color[3] = alphas[i];
+            }
+            let offset = (y * width + x) * 4;
+            pixels[offset..offset + 4].copy_from_slice(&color);
+        }
+    }
+
+    pixels
+}
+
+impl Texture {
+    pub fn parse(data: &[u8]) -> io::Result<Texture> {
+        if data.len() < HEADER_SIZE {
+            return Err(invalid_data("Not a TPC texture"));
+        }
+
+        let data_size = read_u32(data, 0)? as usize;
+        let alpha_test = f32::from_bits(read_u32(data, 4)?);
+        let width = read_u16(data, 8)? as usize;
+        let height = read_u16(data, 10)? as usize;
+        let encoding = data[12];
+        let mipmap_count = data[13].max(1) as usize;
+        let compressed = data_size != 0;
+
+        if width == 0 || height == 0 {
+            return Err(invalid_data("TPC texture has no pixels"));
+        }
+        if height == width * 6 {
+            return Err(invalid_data("TPC cube maps are not supported"));
+        }
+        if !matches!(encoding, GRAYSCALE | RGB | RGBA) || (compressed && encoding == GRAYSCALE) {
+            return Err(invalid_data(&format!(
+                "Unsupported TPC encoding {encoding}"
+            )));
+        }
+
+        let top_size = mipmap_size(width, height, encoding, compressed);
+        let top = data
+            .get(HEADER_SIZE..HEADER_SIZE + top_size)
+            .ok_or_else(|| invalid_data("TPC ended in the middle of the pixel data"))?;
+
+        let pixels = if compressed {
+            decode_dxt(top, width, height, encoding == RGB)
+        } else {
+            let pixel_size = top_size / (width * height);
+            top.chunks_exact(pixel_size)
+                .flat_map(|pixel| match *pixel {
+                    [gray] => [gray, gray, gray, 0xff],
+                    [red, green, blue] => [red, green, blue, 0xff],
+                    [red, green, blue, alpha] => [red, green, blue, alpha],
+                    _ => unreachable!("pixel sizes come from the encoding"),
+                })
+                .collect()
+        };
+
+        let pixel_data_size: usize = (0..mipmap_count)
+            .map(|level| {
+                mipmap_size(
+                    (width >> level).max(1),
+                    (height >> level).max(1),
+                    encoding,
+                    compressed,
+                )
+            })
+            .sum();
+        let txi = data
+            .get(HEADER_SIZE + pixel_data_size..)
+            .unwrap_or_default()
+            .to_vec();
+
+        Ok(Texture {
+            image: Image {
+                width: width as u32,
+                height: height as u32,
+                pixels,
+            },
+            alpha_test,
+            txi,
+        })
+    }
+
+    /// Writes the texture uncompressed with a single mipmap.
+    pub fn write(&self, writer: &mut impl Write) -> io::Result<()> {
+        let width = u16::try_from(self.image.width)
+            .map_err(|_| invalid_data("Image is too wide for a TPC"))?;
+        let height = u16::try_from(self.image.height)
+            .map_err(|_| invalid_data("Image is too tall for a TPC"))?;
+
+        let mut header = [0u8; HEADER_SIZE];
+        header[4..8].copy_from_slice(&self.alpha_test.to_le_bytes());
+        header[8..10].copy_from_slice(&width.to_le_bytes());
+        header[10..12].copy_from_slice(&height.to_le_bytes());
+        header[12] = RGBA;
+        header[13] = 1;
+
+        writer.write_all(&header)?;
+        writer.write_all(&self.image.pixels)?;
+        writer.write_all(&self.txi)
+    }
+}
@@ -0,0 +1,205 @@
+/// 2DA table parser, both the binary (`2DA V2.b`) and the text (`2DA V2.0`) flavor.
+///
+/// Tables shipped with the game are binary, tables in the override folder are often text as that's
+/// what most modding tools write. Empty cells (`****` in text tables) are `None`.
+use std::{fmt, io};
+
+const BINARY_SIGNATURE: &[u8] = b"2DA V2.b";
+const TEXT_SIGNATURE: &[u8] = b"2DA V2.0";
+
+/// Text tables mark empty cells with this
+const EMPTY_CELL: &str = "****";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TwoDaRow {
+    pub label: String,
+    pub cells: Vec<Option<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TwoDa {
+    pub columns: Vec<String>,
+    pub rows: Vec<TwoDaRow>,
+}
+
+fn invalid_data(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+/// Reads a little endian integer at `offset`, fails if the data ends before it.
+fn read_u16(data: &[u8], offset: usize) -> io::Result<u16> {
+    data.get(offset..offset + 2)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+        .ok_or_else(|| invalid_data("2DA ended in the middle of a value"))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> io::Result<u32> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        .ok_or_else(|| invalid_data("2DA ended in the middle of a value"))
+}
+
+/// Reads tab terminated strings starting at `offset` until `terminator` or `count` strings.
+/// Returns the strings and the offset after them.
+fn read_tab_terminated(
+    data: &[u8],
+    mut offset: usize,
+    count: Option<usize>,
+) -> io::Result<(Vec<String>, usize)> {
+    let mut strings = Vec::new();
+
+    loop {
+        if count.is_some_and(|count| strings.len() == count) {
+            return Ok((strings, offset));
+        }
+
+        match data.get(offset) {
+            // Column header list ends with a null
+            Some(0) if count.is_none() => return Ok((strings, offset + 1)),
+            Some(_) => {
+                let end = data[offset..]
+                    .iter()
+                    .position(|&b| b == b'\t')
+                    .ok_or_else(|| invalid_data("Unterminated 2DA header string"))?;
+                strings.push(String::from_utf8_lossy(&data[offset..offset + end]).into_owned());
+                offset += end + 1;
+            }
+            None => return Err(invalid_data("2DA ended in the middle of the header")),
+        }
+    }
+}
+
+impl TwoDa {
+    /// Parses a table, the flavor is detected from the signature.
+    pub fn parse(data: &[u8]) -> io::Result<TwoDa> {
+        if data.starts_with(BINARY_SIGNATURE) {
+            TwoDa::parse_binary(data)
+        } else if data.starts_with(TEXT_SIGNATURE) {
+            TwoDa::parse_text(&String::from_utf8_lossy(data))
+        } else {
+            Err(invalid_data("Not a 2DA file"))
+        }
+    }
+
+    fn parse_binary(data: &[u8]) -> io::Result<TwoDa> {
+        // Signature is followed by a newline
+        let (columns, offset) = read_tab_terminated(data, BINARY_SIGNATURE.len() + 1, None)?;
+        let row_count = read_u32(data, offset)? as usize;
+        let (labels, offset) = read_tab_terminated(data, offset + 4, Some(row_count))?;
+
+        let cell_count = row_count * columns.len();
+        let offsets_start = offset;
+        // Cell offsets are followed by the size of the string data, which we don't need
+        let strings_start = offsets_start + cell_count * 2 + 2;
+
+        let mut rows = Vec::with_capacity(row_count);
+        for (row, label) in labels.into_iter().enumerate() {
+            let cells = (0..columns.len())
+                .map(|column| {
+                    let cell = row * columns.len() + column;
+                    let string_offset =
+                        strings_start + read_u16(data, offsets_start + cell * 2)? as usize;
+                    let string = data
+                        .get(string_offset..)
+                        .and_then(|rest| rest.split(|&b| b == 0).next())
+                        .ok_or_else(|| invalid_data("2DA cell points outside the file"))?;
+
+                    Ok((!string.is_empty()).then(|| String::from_utf8_lossy(string).into_owned()))
+                })
+                .collect::<io::Result<Vec<_>>>()?;
+
+            rows.push(TwoDaRow { label, cells });
+        }
+
+        Ok(TwoDa { columns, rows })
+    }
+
+    fn parse_text(text: &str) -> io::Result<TwoDa> {
+        // Signature, an optional "DEFAULT:" line and then the column headers
+        let mut lines = text.lines().skip(1).filter(|line| !line.trim().is_empty());
+        let mut header = lines
+            .next()
+            .ok_or_else(|| invalid_data("2DA has no column headers"))?;
+        if header.trim_start().starts_with("DEFAULT:") {
+            header = lines
+                .next()
+                .ok_or_else(|| invalid_data("2DA has no column headers"))?;
+        }
+        let columns: Vec<String> = split_text_row(header);
+
+        let rows = lines
+            .map(|line| {
+                let mut values = split_text_row(line).into_iter();
+                let label = values.next().unwrap_or_default();
+                let mut cells: Vec<Option<String>> = values
+                    .map(|value| (value != EMPTY_CELL).then_some(value))
+                    .collect();
+                // Trailing empty cells are sometimes left out entirely
+                cells.resize(columns.len(), None);
+                TwoDaRow { label, cells }
+            })
+            .collect();
+
+        Ok(TwoDa { columns, rows })
+    }
+
+    pub fn column_index(&self, column: &str) -> Option<usize> {
+        self.columns
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(column))
+    }
+
+    /// Returns the cell at `row` in `column`, `None` for empty cells and unknown rows or columns.
+    pub fn get(&self, row: usize, column: &str) -> Option<&str> {
+        let column = self.column_index(column)?;
+        self.rows.get(row)?.cells.get(column)?.as_deref()
+    }
+}
+
+/// Splits a text 2DA line on whitespace, keeping quoted values with spaces together.
+fn split_text_row(line: &str) -> Vec<String> {
+    let mut values = Vec::new();
+    let mut chars = line.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '"' {
+            chars.next();
+            values.push(chars.by_ref().take_while(|&c| c != '"').collect());
+        } else {
+            let mut value = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() {
+                    break;
+                }
+                value.push(c);
+                chars.next();
+            }
+            values.push(value);
+        }
+    }
+
+    values
+}
+
+/// Dumps the table in the text 2DA format.
+impl fmt::Display for TwoDa {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "2DA V2.0")?;
+        writeln!(f)?;
+        writeln!(f, "\t{}", self.columns.join("\t"))?;
+        for row in &self.rows {
+            write!(f, "{}", row.label)?;
+            for cell in &row.cells {
+                match cell {
+                    Some(value) if value.contains(' ') => write!(f, "\t\"{value}\"")?,
+                    Some(value) => write!(f, "\t{value}")?,
+                    None => write!(f, "\t{EMPTY_CELL}")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
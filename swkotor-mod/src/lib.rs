@@ -1,11 +1,24 @@
+mod audio;
+mod config;
 pub mod engine;
+pub mod formats;
 pub mod liveqa;
 mod mem;
+pub mod overlay;
+mod plugins;
+mod savegame;
+mod screenshot;
+mod settings;
+mod speedrun;
+mod strref;
 pub mod system;
+mod telemetry;
 pub mod util;
 use crate::system::dll_loader::DllLibrary;
-use engine::SW_KOTOR_MOD_ENGINE;
-use log::trace;
+use engine::sw_kotor_mod_engine;
+use log::{trace, warn};
+use util::iat::miles::uninstall_miles_hooks;
+use util::iat::swapbuffers::uninstall_swapbuffers_hook;
 use windows::Win32::Foundation::HINSTANCE;
 use windows::Win32::System::SystemServices::*;
 
@@ -13,30 +26,29 @@ pub const DLL_MOCK_SELF: DllLibrary = DllLibrary::Dinput8;
 
 #[no_mangle]
 #[allow(non_snake_case, unused_variables)]
-extern "system" fn DllMain(dll_module: HINSTANCE, call_reason: u32, _: *mut ()) -> bool {
-    {
-        // Touch the engine to trigger initialize. This is safe to do multiple times. We'll want to
-        // do it before anything else to initialize logging. DllMain can be called with JUST
-        // deattach in error cases.
-        //
-        // TODO(tatu): Maybe we should still tell the engine if we're starting or already shutting
-        // down? Right now it'll load libraries in case of detach and might fail again.
-        let _unused = SW_KOTOR_MOD_ENGINE.lock().unwrap();
-    }
-
+extern "system" fn DllMain(dll_module: HINSTANCE, call_reason: u32, reserved: *mut ()) -> bool {
     match call_reason {
         DLL_PROCESS_ATTACH => {
+            // Touch the engine to trigger initialize. This is safe to do multiple times. We'll
+            // want to do it before anything else to initialize logging.
+            sw_kotor_mod_engine();
             trace!("Attaching dll");
         }
         DLL_PROCESS_DETACH => {
+            // DllMain can be called with JUST deattach in error cases, don't initialize the
+            // engine here. When the process is exiting `reserved` is set and the game won't draw
+            // another frame. When we're freed the game keeps going and must not end up in the
+            // SwapBuffers or Miles hooks.
+            if reserved.is_null() {
+                uninstall_swapbuffers_hook();
+                uninstall_miles_hooks();
+            }
             trace!("Detaching dll or dll loading failed early");
         }
         // We can ignore these safely
         DLL_THREAD_ATTACH | DLL_THREAD_DETACH => (),
-        _ => {
-            trace!("Unknown dll call reason {call_reason:?}");
-            panic!("Unknown dll call reason {call_reason:?}");
-        }
+        // Panicking here would take the game down with us
+        _ => warn!("Unknown dll call reason {call_reason:?}"),
     };
 
     true
@@ -0,0 +1,151 @@
+/// Feats and force powers by name, for the `feat` and `power` commands.
+///
+/// Rows of `feat.2da` and `spells.2da` can be picked by row number, by label or by the name the
+/// game shows, so nobody has to look up that Master Speed is row 25 first.
+use std::io;
+
+use crate::engine::tables;
+use crate::strref;
+
+/// Matches shown when a name is ambiguous
+const SHOWN_CANDIDATES: usize = 5;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub row: u16,
+    pub label: String,
+    /// Text of the `name` StrRef, `None` when the row has none
+    pub name: Option<String>,
+}
+
+impl Entry {
+    pub fn describe(&self) -> String {
+        match &self.name {
+            Some(name) => format!("{} {} {name:?}", self.row, self.label),
+            None => format!("{} {}", self.row, self.label),
+        }
+    }
+
+    fn matches(&self, filter: &str) -> bool {
+        self.label.to_ascii_lowercase().contains(filter)
+            || self
+                .name
+                .as_ref()
+                .is_some_and(|name| name.to_ascii_lowercase().contains(filter))
+    }
+}
+
+/// Reads the rows of `table` with their names, e.g. `feat` or `spells`. Rows without a label are
+/// left out, the game leaves those unused.
+///
+/// Must be called on the main thread.
+pub fn entries(table: &str) -> io::Result<Vec<Entry>> {
+    let table = tables::read_table(table)?;
+    Ok((0..table.rows.len())
+        .filter_map(|row| {
+            let label = table.get(row, "label")?.to_string();
+            let name = table
+                .get(row, "name")
+                .and_then(|strref| strref.parse().ok())
+                .and_then(strref::lookup);
+            Some(Entry {
+                row: row as u16,
+                label,
+                name,
+            })
+        })
+        .collect())
+}
+
+/// Entries whose label or name contains `filter`, ignoring case.
+pub fn search<'a>(entries: &'a [Entry], filter: &str) -> Vec<&'a Entry> {
+    let filter = filter.to_ascii_lowercase();
+    entries
+        .iter()
+        .filter(|entry| entry.matches(&filter))
+        .collect()
+}
+
+/// Finds the row `query` means: a row number, a label or a name, exact matches first. A partial
+/// match is taken only when it's the only one.
+pub fn find(entries: &[Entry], query: &str) -> Result<u16, String> {
+    if let Ok(row) = query.parse::<u16>() {
+        return Ok(row);
+    }
+
+    let exact = entries.iter().find(|entry| {
+        entry.label.eq_ignore_ascii_case(query)
+            || entry
+                .name
+                .as_ref()
+                .is_some_and(|name| name.eq_ignore_ascii_case(query))
+    });
+    if let Some(entry) = exact {
+        return Ok(entry.row);
+    }
+
+    match search(entries, query).as_slice() {
+        [] => Err(format!("Nothing named '{query}'")),
+        [entry] => Ok(entry.row),
+        candidates => {
+            let shown: Vec<String> = candidates
+                .iter()
+                .take(SHOWN_CANDIDATES)
+                .map(|entry| entry.describe())
+                .collect();
+            Err(format!(
+                "'{query}' matches {} rows, e.g. {}",
+                candidates.len(),
+                shown.join(", ")
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feats() -> Vec<Entry> {
+        [
+            (11, "FEAT_POWER_ATTACK", Some("Power Attack")),
+            (
+                12,
+                "FEAT_IMPROVED_POWER_ATTACK",
+                Some("Improved Power Attack"),
+            ),
+            (25, "FEAT_MASTER_SPEED", Some("Master Speed")),
+            (30, "FEAT_UNUSED", None),
+        ]
+        .into_iter()
+        .map(|(row, label, name)| Entry {
+            row,
+            label: label.to_string(),
+            name: name.map(str::to_string),
+        })
+        .collect()
+    }
+
+    #[test]
+    fn finds_by_row_label_and_name() {
+        let feats = feats();
+        assert_eq!(find(&feats, "25"), Ok(25));
+        assert_eq!(find(&feats, "feat_master_speed"), Ok(25));
+        assert_eq!(find(&feats, "master speed"), Ok(25));
+    }
+
+    #[test]
+    fn exact_match_wins_over_partial_ones() {
+        assert_eq!(find(&feats(), "power attack"), Ok(11));
+    }
+
+    #[test]
+    fn partial_match_must_be_unique() {
+        let feats = feats();
+        assert_eq!(find(&feats, "speed"), Ok(25));
+        assert!(find(&feats, "attack")
+            .unwrap_err()
+            .contains("matches 2 rows"));
+        assert!(find(&feats, "cleave").is_err());
+    }
+}
@@ -0,0 +1,1597 @@
+/// Commands for poking the running game while testing.
+///
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fs, io,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use log::error;
+
+use crate::audio::{self, Category};
+use crate::engine::{
+    area, bindings, cheats, clock, dialog, display, fixtures, frame, items, loading, models,
+    module, movement, overrides, party, rng, snapshots, stats, strings, tables, textures, trace,
+    watch as engine_watch,
+};
+use crate::formats::mdl::ModelHeader;
+use crate::liveqa::catalog::{self, Entry};
+use crate::liveqa::console::{self, parse_toggle, register_command, Command, CommandResult};
+use crate::liveqa::script;
+use crate::overlay::{
+    journal, loads as loads_overlay, memory, minimap, nameplates, rng as rng_overlay, route,
+    savegame,
+    scene::{self, Shape},
+    sheet, theme, transform, volumes, walkmesh, watch,
+};
+use crate::screenshot::request_screenshot;
+use crate::settings::{self, SETTINGS};
+use crate::speedrun::{
+    ghost::{Ghost, GhostRun, GHOST},
+    plan::{self, PlannedWaypoint, RoutePlan, PLAN},
+    practice,
+    timer::{format_duration, TIMER},
+};
+use crate::strref;
+use crate::system::{self, window};
+use crate::telemetry::{alerts, recorder};
+
+pub fn register_commands() {
+    register_command(Command {
+        name: "help",
+        usage: "",
+        help: "Lists all commands",
+        run: console::help,
+    });
+    register_command(Command {
+        name: "noclip",
+        usage: "[on|off]",
+        help: "Disables player collision checks",
+        run: noclip,
+    });
+    register_command(Command {
+        name: "god",
+        usage: "[on|off]",
+        help: "Makes the active party invulnerable",
+        run: god,
+    });
+    register_command(Command {
+        name: "infiniteforce",
+        usage: "[on|off]",
+        help: "Force powers cost no force points for the active party",
+        run: infinite_force,
+    });
+    register_command(Command {
+        name: "infiniteitems",
+        usage: "[on|off]",
+        help: "Medpacs, stims, grenades and other consumables don't run out for the active party",
+        run: infinite_items,
+    });
+    register_command(Command {
+        name: "fastforward",
+        usage: "[on|off]",
+        help: "Hold Ctrl+D to skip through dialogue while enabled",
+        run: fast_forward,
+    });
+    register_command(Command {
+        name: "speed",
+        usage: "[multiplier]",
+        help: "Shows or sets the party movement speed multiplier, e.g. speed 2.5",
+        run: speed,
+    });
+    register_command(Command {
+        name: "give",
+        usage: "<item> [count]",
+        help: "Adds items to the party inventory, e.g. give g_w_lghtsbr01",
+        run: give,
+    });
+    register_command(Command {
+        name: "journal",
+        usage: "[on|off|filter [text]]",
+        help: "Shows the quests in the journal and the globals behind them, filter picks globals by name",
+        run: journal_command,
+    });
+    register_command(Command {
+        name: "sheet",
+        usage: "[on|off|slot]",
+        help: "Shows abilities, skills, hit points and feats of a party member, e.g. sheet 1",
+        run: sheet_command,
+    });
+    register_command(Command {
+        name: "savegame",
+        usage: "[on|off|globals [prefix]|journal]",
+        help: "Shows what changed since the latest save, on and off show it on the overlay instead",
+        run: savegame_command,
+    });
+    register_command(Command {
+        name: "stat",
+        usage: "<slot> <ability|skill|hp> <value>",
+        help: "Sets an ability score, skill rank or hit points, e.g. stat 0 str 18",
+        run: stat,
+    });
+    register_command(Command {
+        name: "feat",
+        usage: "<slot> <add|remove> <feat>|list [filter]",
+        help: "Grants or removes a feat by its feat.2da row, label or name, e.g. feat 0 add master speed",
+        run: feat,
+    });
+    register_command(Command {
+        name: "power",
+        usage: "<slot> <add|remove> <power>|list [filter]",
+        help: "Grants or removes a force power by its spells.2da row, label or name, e.g. power 0 add force push",
+        run: power,
+    });
+    register_command(Command {
+        name: "warp",
+        usage: "<module>",
+        help: "Loads the given module, e.g. warp end_m01aa",
+        run: warp,
+    });
+    register_command(Command {
+        name: "pause",
+        usage: "[on|off]",
+        help: "Freezes the world while the overlay and console keep working",
+        run: pause,
+    });
+    register_command(Command {
+        name: "step",
+        usage: "[frames]",
+        help: "Runs the paused world for a frame or the given number of frames",
+        run: step,
+    });
+    register_command(Command {
+        name: "quickstart",
+        usage: "<module> [soldier|scout|scoundrel] [male|female]",
+        help: "Starts a new game in a module from the main menu with a premade character, e.g. quickstart tar_m02aa scout",
+        run: quick_start,
+    });
+    register_command(Command {
+        name: "highfps",
+        usage: "[on|off]",
+        help: "Steps the world at most at 60fps, fixes animations and physics jittering at high frame rates",
+        run: high_fps,
+    });
+    register_command(Command {
+        name: "timescale",
+        usage: "[scale]",
+        help: "Shows or sets how fast the world runs, e.g. timescale 0.25",
+        run: time_scale,
+    });
+    register_command(Command {
+        name: "timer",
+        usage: "<start|split|reset>",
+        help: "Controls the speedrun timer (Ctrl+F1/F2/F3)",
+        run: timer,
+    });
+    register_command(Command {
+        name: "loads",
+        usage: "[on|off|history]",
+        help: "Shows load time statistics by module, history lists every load of the session",
+        run: loads,
+    });
+    register_command(Command {
+        name: "alerts",
+        usage: "",
+        help: "Lists the performance alerts raised this session, see alerts in the config",
+        run: alerts_command,
+    });
+    register_command(Command {
+        name: "record",
+        usage: "[on|off]",
+        help: "Records per frame telemetry to a CSV file",
+        run: record,
+    });
+    register_command(Command {
+        name: "screenshot",
+        usage: "",
+        help: "Saves a screenshot at the end of the next frame (Ctrl+F12)",
+        run: screenshot,
+    });
+    register_command(Command {
+        name: "borderless",
+        usage: "[on|off]",
+        help: "Stretches the game window borderless over the monitor (Ctrl+F11)",
+        run: borderless,
+    });
+    register_command(Command {
+        name: "fov",
+        usage: "[degrees|default]",
+        help: "Shows or overrides the camera field of view, e.g. fov 75",
+        run: fov,
+    });
+    register_command(Command {
+        name: "guiaspect",
+        usage: "[on|off]",
+        help: "Keeps the GUI at 4:3 instead of stretching it on wide screens",
+        run: gui_aspect,
+    });
+    register_command(Command {
+        name: "twoda",
+        usage: "[table] [row]",
+        help: "Lists loaded 2DA tables or dumps the values the engine uses, e.g. twoda feat 12",
+        run: twoda,
+    });
+    register_command(Command {
+        name: "strref",
+        usage: "<strref>",
+        help: "Shows the dialog.tlk string for a StrRef",
+        run: strref_command,
+    });
+    register_command(Command {
+        name: "strings",
+        usage: "[reload|set <strref> <text>|unset <strref>]",
+        help: "Lists or replaces dialog.tlk strings, reload reads the replacement file again",
+        run: strings_command,
+    });
+    register_command(Command {
+        name: "model",
+        usage: "[<model>]",
+        help: "Dumps the headers of the model under the mouse cursor or the one named, e.g. model c_drdast",
+        run: model_command,
+    });
+    register_command(Command {
+        name: "nameplates",
+        usage: "[on|off|types [<type>...]]",
+        help: "Labels objects near the party with their tag, hit points or open and locked state, e.g. nameplates types creature door",
+        run: nameplates_command,
+    });
+    register_command(Command {
+        name: "fixture",
+        usage: "<name>",
+        help: "Records the object table, camera and module name to fixtures/<name>.json for the offline tests",
+        run: fixture_command,
+    });
+    register_command(Command {
+        name: "objects",
+        usage: "[dump]",
+        help: "Counts the objects in the current area by type, dump writes every one of them to a JSON file",
+        run: objects_command,
+    });
+    register_command(Command {
+        name: "schemas",
+        usage: "",
+        help: "Writes the JSON Schemas of the area dumps and the telemetry CSV to the schemas directory",
+        run: schemas_command,
+    });
+    register_command(Command {
+        name: "watch",
+        usage: "[on|off|add <name> <address|[@]symbol[+offset]> <type>|remove <name>|list]",
+        help: "Shows memory values live on the overlay, e.g. watch add app @server_app ptr",
+        run: watch_command,
+    });
+    register_command(Command {
+        name: "walkmesh",
+        usage: "[on|off]",
+        help: "Draws the walkmesh of the current area, walkable edges green and blocked red",
+        run: walkmesh_command,
+    });
+    register_command(Command {
+        name: "triggers",
+        usage: "[on|off]",
+        help: "Draws the trigger volumes of the current area",
+        run: triggers,
+    });
+    register_command(Command {
+        name: "encounters",
+        usage: "[on|off]",
+        help: "Draws the encounter zones of the current area",
+        run: encounters,
+    });
+    register_command(Command {
+        name: "route",
+        usage: "[on|off]",
+        help: "Draws the path the controlled character is walking",
+        run: route_command,
+    });
+    register_command(Command {
+        name: "memory",
+        usage: "[on|off]",
+        help: "Shows memory usage, on and off show it on the overlay instead",
+        run: memory_command,
+    });
+    register_command(Command {
+        name: "transform",
+        usage: "[on|off|copy]",
+        help:
+            "Shows the controlled character and camera transforms, copy puts them on the clipboard",
+        run: transform_command,
+    });
+    register_command(Command {
+        name: "rng",
+        usage: "[on|off|seed <seed|off>]",
+        help: "Shows the latest RNG rolls or forces the seed, e.g. rng seed 1234",
+        run: rng_command,
+    });
+    register_command(Command {
+        name: "practice",
+        usage: "[on|off|reset]",
+        help: "Marks a practice point, reset re-arms the triggers and conversations and restores globals and hit points",
+        run: practice_command,
+    });
+    register_command(Command {
+        name: "snapshot",
+        usage: "<save|restore>",
+        help: "Quick saves into a practice slot or loads it back, to retry a fight or a trick",
+        run: snapshot,
+    });
+    register_command(Command {
+        name: "minimap",
+        usage: "[on|off|zoom [pixels per meter]]",
+        help: "Shows the top-down minimap or sets its zoom, e.g. minimap zoom 8",
+        run: minimap_command,
+    });
+    register_command(Command {
+        name: "plan",
+        usage: "[load <file>|save <file>|add [note]|clear]",
+        help: "Shows the speedrun route plan in the world, add appends the current position",
+        run: plan_command,
+    });
+    register_command(Command {
+        name: "trace",
+        usage: "[function [on|off]]",
+        help: "Logs calls to a hooked engine function, lists the traceable ones without arguments",
+        run: trace_command,
+    });
+    register_command(Command {
+        name: "ghost",
+        usage: "[load <recording>|play|stop]",
+        help: "Plays back a recorded run as a ghost marker in the world",
+        run: ghost,
+    });
+    register_command(Command {
+        name: "scene",
+        usage: "[clear]",
+        help: "Shows how many primitives are drawn in the world or removes them all",
+        run: scene_command,
+    });
+    register_command(Command {
+        name: "mark",
+        usage: "[label]",
+        help: "Marks the controlled character's position in the world until 'scene clear'",
+        run: mark,
+    });
+    register_command(Command {
+        name: "overrides",
+        usage: "[rescan]",
+        help: "Lists the resources replaced from override_ext, rescan picks up new files",
+        run: overrides_command,
+    });
+    register_command(Command {
+        name: "textures",
+        usage: "[rescan|dump [on|off]]",
+        help: "Lists the replaced textures, rescan picks up new replacements, dump writes loaded textures to disk",
+        run: textures_command,
+    });
+    register_command(Command {
+        name: "volume",
+        usage: "[music|voice|effects <multiplier>]",
+        help: "Shows or scales the volume of music, voice over or effects on top of the game's options",
+        run: volume_command,
+    });
+    register_command(Command {
+        name: "settings",
+        usage: "[<name> [<value>]]",
+        help: "Shows the settings or changes one, changes are written to swkotor-mod.toml",
+        run: settings_command,
+    });
+    register_command(Command {
+        name: "script",
+        usage: "[run <name>|stop]",
+        help:
+            "Lists the QA scripts in the scripts directory, runs one in the background or stops it",
+        run: script_command,
+    });
+    register_command(Command {
+        name: "call",
+        usage: "[<function> [<args>...]]",
+        help: "Calls an engine function, lists them when given no function",
+        run: call_command,
+    });
+    register_command(Command {
+        name: "status",
+        usage: "",
+        help: "Reports hooks, symbols missing in this build, the last error of each subsystem and the overlay's frame cost",
+        run: status_command,
+    });
+    register_command(Command {
+        name: "reload",
+        usage: "",
+        help: "Unhooks the mod and loads swkotor_mod.dll again from disk, needs the mod loader",
+        run: reload,
+    });
+}
+
+/// How long commands reading engine state wait for the main thread
+const MAIN_THREAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+const MARK_ALPHA: f32 = 0.9;
+/// Marker size in pixels
+const MARK_SIZE: f32 = 8.0;
+/// Label is lifted above the marker so they don't overlap
+const MARK_LABEL_HEIGHT: f32 = 0.5;
+
+/// A feature that can be turned on and off, from the console or over IPC.
+pub struct Toggle {
+    pub name: &'static str,
+    pub enabled: fn() -> bool,
+    set: fn(bool) -> io::Result<()>,
+}
+
+pub const TOGGLES: &[Toggle] = &[
+    Toggle {
+        name: "noclip",
+        enabled: cheats::noclip_enabled,
+        set: cheats::set_noclip,
+    },
+    Toggle {
+        name: "god",
+        enabled: cheats::god_mode_enabled,
+        set: cheats::set_god_mode,
+    },
+    Toggle {
+        name: "infiniteforce",
+        enabled: cheats::infinite_force_enabled,
+        set: cheats::set_infinite_force,
+    },
+    Toggle {
+        name: "infiniteitems",
+        enabled: cheats::infinite_items_enabled,
+        set: cheats::set_infinite_items,
+    },
+    Toggle {
+        name: "fastforward",
+        enabled: dialog::fast_forward_enabled,
+        set: dialog::set_fast_forward,
+    },
+    Toggle {
+        name: "pause",
+        enabled: clock::paused,
+        set: clock::set_paused,
+    },
+    Toggle {
+        name: "highfps",
+        enabled: clock::high_frame_rate_fix_enabled,
+        set: clock::set_high_frame_rate_fix,
+    },
+    Toggle {
+        name: "record",
+        enabled: recorder::recording_enabled,
+        set: recorder::set_recording,
+    },
+    Toggle {
+        name: "borderless",
+        enabled: window::borderless_enabled,
+        set: window::set_borderless,
+    },
+    Toggle {
+        name: "guiaspect",
+        enabled: display::gui_aspect_correction_enabled,
+        set: display::set_gui_aspect_correction,
+    },
+    Toggle {
+        name: "practice",
+        enabled: practice::practice_enabled,
+        set: practice::set_practice,
+    },
+    Toggle {
+        name: "walkmesh",
+        enabled: walkmesh::walkmesh_enabled,
+        set: walkmesh::set_walkmesh,
+    },
+    Toggle {
+        name: "triggers",
+        enabled: volumes::triggers_enabled,
+        set: volumes::set_triggers,
+    },
+    Toggle {
+        name: "encounters",
+        enabled: volumes::encounters_enabled,
+        set: volumes::set_encounters,
+    },
+    Toggle {
+        name: "route",
+        enabled: route::route_enabled,
+        set: route::set_route,
+    },
+    Toggle {
+        name: "memory",
+        enabled: memory::memory_enabled,
+        set: memory::set_memory,
+    },
+    Toggle {
+        name: "rng",
+        enabled: rng_overlay::rng_enabled,
+        set: rng_overlay::set_rng,
+    },
+    Toggle {
+        name: "sheet",
+        enabled: sheet::sheet_enabled,
+        set: sheet::set_sheet,
+    },
+    Toggle {
+        name: "savegame",
+        enabled: savegame::savegame_enabled,
+        set: savegame::set_savegame,
+    },
+    Toggle {
+        name: "journal",
+        enabled: journal::journal_enabled,
+        set: journal::set_journal,
+    },
+    Toggle {
+        name: "nameplates",
+        enabled: nameplates::nameplates_enabled,
+        set: nameplates::set_nameplates,
+    },
+    Toggle {
+        name: "minimap",
+        enabled: minimap::minimap_enabled,
+        set: minimap::set_minimap,
+    },
+    Toggle {
+        name: "transform",
+        enabled: transform::transform_enabled,
+        set: transform::set_transform,
+    },
+    Toggle {
+        name: "loads",
+        enabled: loads_overlay::loads_enabled,
+        set: loads_overlay::set_loads,
+    },
+    Toggle {
+        name: "watch",
+        enabled: watch::watch_enabled,
+        set: watch::set_watch,
+    },
+    Toggle {
+        name: "texturedump",
+        enabled: textures::is_dumping,
+        set: textures::set_dumping,
+    },
+];
+
+/// Sets the named toggle, flipping it when `enabled` is `None`. Returns the new state.
+///
+/// The setter runs on the main thread, the engine must not be patched under its feet.
+pub fn set_toggle(name: &str, enabled: Option<bool>) -> Result<bool, Box<dyn Error>> {
+    let toggle = TOGGLES
+        .iter()
+        .find(|toggle| toggle.name == name)
+        .ok_or_else(|| format!("Unknown toggle '{name}'"))?;
+    let enabled = enabled.unwrap_or_else(|| !(toggle.enabled)());
+
+    let (name, set) = (toggle.name, toggle.set);
+    frame::run_on_main_thread(move || {
+        if let Err(e) = set(enabled) {
+            error!("Could not toggle {name}: {e}");
+        }
+    });
+
+    Ok(enabled)
+}
+
+fn run_toggle(name: &str, args: &[&str]) -> CommandResult {
+    let current = TOGGLES
+        .iter()
+        .find(|toggle| toggle.name == name)
+        .map(|toggle| (toggle.enabled)())
+        .unwrap_or_default();
+    let enabled = set_toggle(name, Some(parse_toggle(args, current)?))?;
+    Ok(format!("{name} {}", if enabled { "on" } else { "off" }))
+}
+
+fn noclip(args: &[&str]) -> CommandResult {
+    run_toggle("noclip", args)
+}
+
+fn god(args: &[&str]) -> CommandResult {
+    run_toggle("god", args)
+}
+
+fn infinite_force(args: &[&str]) -> CommandResult {
+    run_toggle("infiniteforce", args)
+}
+
+fn infinite_items(args: &[&str]) -> CommandResult {
+    run_toggle("infiniteitems", args)
+}
+
+fn record(args: &[&str]) -> CommandResult {
+    run_toggle("record", args)
+}
+
+fn borderless(args: &[&str]) -> CommandResult {
+    run_toggle("borderless", args)
+}
+
+fn gui_aspect(args: &[&str]) -> CommandResult {
+    run_toggle("guiaspect", args)
+}
+
+fn walkmesh_command(args: &[&str]) -> CommandResult {
+    run_toggle("walkmesh", args)
+}
+
+fn triggers(args: &[&str]) -> CommandResult {
+    run_toggle("triggers", args)
+}
+
+fn encounters(args: &[&str]) -> CommandResult {
+    run_toggle("encounters", args)
+}
+
+fn route_command(args: &[&str]) -> CommandResult {
+    run_toggle("route", args)
+}
+
+fn memory_command(args: &[&str]) -> CommandResult {
+    if !args.is_empty() {
+        return run_toggle("memory", args);
+    }
+
+    let stats = system::memory::stats().ok_or("Memory has not been sampled yet")?;
+    Ok(memory::describe(&stats).join("\n"))
+}
+
+fn transform_command(args: &[&str]) -> CommandResult {
+    let copy = match args {
+        [] => false,
+        ["copy"] => true,
+        _ => return run_toggle("transform", args),
+    };
+
+    let lines = frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, transform::describe)??;
+    let text = lines.join("\n");
+    if copy {
+        system::clipboard::set_text(&text)?;
+        return Ok(format!("{text}\nCopied to the clipboard"));
+    }
+    Ok(text)
+}
+
+fn rng_command(args: &[&str]) -> CommandResult {
+    let seed = match args {
+        [] => {
+            let rolls: Vec<String> = rng::recent_rolls()
+                .iter()
+                .map(|roll| format!("{roll:#010x}"))
+                .collect();
+            let seed = rng::forced_seed()
+                .map(|seed| seed.to_string())
+                .unwrap_or_else(|| "engine".to_string());
+            return Ok(format!("seed {seed}, latest rolls {}", rolls.join(" ")));
+        }
+        ["seed", "off"] => None,
+        ["seed", seed] => Some(seed.parse::<u32>()?),
+        _ => return run_toggle("rng", args),
+    };
+
+    frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, move || rng::set_forced_seed(seed))??;
+    Ok(match seed {
+        Some(seed) => format!("rng seed {seed}"),
+        None => "rng seed off".to_string(),
+    })
+}
+
+fn practice_command(args: &[&str]) -> CommandResult {
+    match args {
+        ["reset"] => {
+            let restored = frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, practice::reset)??;
+            Ok(format!(
+                "Reset to the practice point, {} globals and {} objects changed back",
+                restored.globals, restored.objects
+            ))
+        }
+        _ => run_toggle("practice", args),
+    }
+}
+
+fn snapshot(args: &[&str]) -> CommandResult {
+    match args {
+        ["save"] => {
+            frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, snapshots::save_snapshot)??;
+            Ok("Snapshot saved".to_string())
+        }
+        ["restore"] => {
+            frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, snapshots::restore_snapshot)??;
+            Ok("Restoring snapshot".to_string())
+        }
+        _ => Err("Expected save or restore".into()),
+    }
+}
+
+fn minimap_command(args: &[&str]) -> CommandResult {
+    match args {
+        ["zoom"] => Ok(format!("minimap zoom {}", minimap::zoom())),
+        ["zoom", zoom] => {
+            minimap::set_zoom(zoom.parse()?)?;
+            Ok(format!("minimap zoom {zoom}"))
+        }
+        _ => run_toggle("minimap", args),
+    }
+}
+
+fn plan_command(args: &[&str]) -> CommandResult {
+    match args {
+        [] => {
+            // Main thread locks the plan to draw it, don't hold it while waiting
+            let module = frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, module::current_module)??;
+            let plan = PLAN.lock().unwrap();
+            let in_module = module
+                .as_deref()
+                .map(|module| plan.in_module(module).count())
+                .unwrap_or_default();
+            Ok(format!(
+                "{} waypoints, {in_module} in this module",
+                plan.waypoints.len()
+            ))
+        }
+        ["load", file] => {
+            let loaded = RoutePlan::load(Path::new(file))?;
+            let count = loaded.waypoints.len();
+            plan::modify(|plan| *plan = loaded);
+            Ok(format!("Loaded {count} waypoints"))
+        }
+        ["save", file] => {
+            PLAN.lock().unwrap().save(Path::new(file))?;
+            Ok(format!("Saved to {file}"))
+        }
+        ["add", note @ ..] => {
+            let (module, position) = frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, || {
+                Ok::<_, io::Error>((module::current_module()?, party::leader_position()?))
+            })??;
+            let module = module.ok_or("No module loaded")?;
+            let count = plan::modify(|plan| {
+                plan.waypoints.push(PlannedWaypoint {
+                    module,
+                    position,
+                    note: note.join(" "),
+                });
+                plan.waypoints.len()
+            });
+            Ok(format!("Added waypoint {count}"))
+        }
+        ["clear"] => {
+            plan::modify(|plan| plan.waypoints.clear());
+            Ok("Route plan cleared".to_string())
+        }
+        _ => Err("Expected load, save, add or clear".into()),
+    }
+}
+
+fn trace_command(args: &[&str]) -> CommandResult {
+    let (name, enabled) = match args {
+        [] => {
+            let traced = trace::traced();
+            let functions: Vec<String> = trace::traceable()
+                .map(|name| {
+                    let state = if traced.contains(&name) { "on" } else { "off" };
+                    format!("{name} {state}")
+                })
+                .collect();
+            return Ok(functions.join("\n"));
+        }
+        [name, state @ ..] => {
+            let current = trace::traced().contains(name);
+            (name.to_string(), parse_toggle(state, current)?)
+        }
+    };
+
+    let message = format!("Tracing {name} {}", if enabled { "on" } else { "off" });
+    frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, move || {
+        trace::set_tracing(&name, enabled)
+    })??;
+    Ok(message)
+}
+
+fn ghost(args: &[&str]) -> CommandResult {
+    let mut ghost = GHOST.lock().unwrap();
+    match args {
+        [] => Ok(match ghost.as_ref() {
+            None => "No ghost run loaded".to_string(),
+            Some(Ghost {
+                run,
+                started: Some(started),
+            }) => format!(
+                "Ghost playing, {} of {}",
+                format_duration(started.elapsed()),
+                format_duration(run.duration())
+            ),
+            Some(Ghost { run, started: None }) => {
+                format!("Ghost stopped, run is {}", format_duration(run.duration()))
+            }
+        }),
+        ["load", recording] => {
+            let run = GhostRun::load(Path::new(recording))?;
+            let duration = run.duration();
+            *ghost = Some(Ghost { run, started: None });
+            Ok(format!(
+                "Loaded a {} ghost run, play it with 'ghost play'",
+                format_duration(duration)
+            ))
+        }
+        ["play"] => {
+            let ghost = ghost.as_mut().ok_or("Load a ghost run first")?;
+            ghost.started = Some(Instant::now());
+            Ok("Ghost playing".to_string())
+        }
+        ["stop"] => {
+            if let Some(ghost) = ghost.as_mut() {
+                ghost.started = None;
+            }
+            Ok("Ghost stopped".to_string())
+        }
+        _ => Err("Expected load, play or stop".into()),
+    }
+}
+
+fn scene_command(args: &[&str]) -> CommandResult {
+    match args {
+        [] => Ok(format!("{} primitives", scene::len())),
+        ["clear"] => Ok(format!("Removed {} primitives", scene::clear())),
+        _ => Err("Expected nothing or 'clear'".into()),
+    }
+}
+
+fn mark(args: &[&str]) -> CommandResult {
+    let [x, y, z] = frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, party::leader_position)??;
+    let label = if args.is_empty() {
+        format!("{x:.1} {y:.1} {z:.1}")
+    } else {
+        args.join(" ")
+    };
+
+    let color = theme::palette().target.with_alpha(MARK_ALPHA);
+    scene::add(
+        Shape::Billboard {
+            position: [x, y, z],
+            size: MARK_SIZE,
+        },
+        color,
+        None,
+    );
+    scene::add(
+        Shape::Text {
+            position: [x, y, z + MARK_LABEL_HEIGHT],
+            text: label.clone(),
+        },
+        color,
+        None,
+    );
+    Ok(format!("Marked '{label}'"))
+}
+
+fn sheet_command(args: &[&str]) -> CommandResult {
+    match args {
+        [slot] if slot.parse::<usize>().is_ok() => {
+            sheet::set_slot(slot.parse()?)?;
+            run_toggle("sheet", &["on"])
+        }
+        _ => run_toggle("sheet", args),
+    }
+}
+
+fn journal_command(args: &[&str]) -> CommandResult {
+    match args {
+        ["filter"] => {
+            journal::set_filter(None);
+            Ok("Showing globals changed since the journal was shown".to_string())
+        }
+        ["filter", filter] => {
+            journal::set_filter(Some(filter));
+            Ok(format!("Showing globals matching '{filter}'"))
+        }
+        _ => run_toggle("journal", args),
+    }
+}
+
+fn savegame_command(args: &[&str]) -> CommandResult {
+    match args {
+        [] => {
+            let (save, differences) =
+                frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, savegame::compare_latest)??;
+            Ok(savegame::describe(&save, &differences).join("\n"))
+        }
+        ["globals", filter @ ..] => {
+            let save = savegame::latest_save()?;
+            let prefix = filter.first().copied().unwrap_or_default();
+            let booleans = save
+                .booleans
+                .iter()
+                .map(|(name, value)| (name, *value as u8));
+            let lines: Vec<String> = booleans
+                .chain(save.numbers.iter().map(|(name, value)| (name, *value)))
+                .filter(|(name, _)| name.starts_with(prefix))
+                .map(|(name, value)| format!("{name} {value}"))
+                .collect();
+            Ok(lines.join("\n"))
+        }
+        ["journal"] => {
+            let save = savegame::latest_save()?;
+            let lines: Vec<String> = save
+                .journal
+                .iter()
+                .map(|entry| format!("{} {}", entry.plot_id, entry.state))
+                .collect();
+            Ok(lines.join("\n"))
+        }
+        _ => run_toggle("savegame", args),
+    }
+}
+
+fn stat(args: &[&str]) -> CommandResult {
+    let [slot, name, value] = args else {
+        return Err("Expected a party slot, what to set and the value".into());
+    };
+    let slot = slot.parse::<usize>()?;
+    let name = name.to_ascii_lowercase();
+
+    if name == "hp" {
+        let hit_points = value.parse::<i16>()?;
+        frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, move || {
+            stats::set_hit_points(slot, hit_points)
+        })??;
+    } else if stats::ABILITIES.contains(&name.as_str()) {
+        let score = value.parse::<u8>()?;
+        frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, move || {
+            stats::set_ability(slot, &name, score)
+        })??;
+    } else {
+        let rank = value.parse::<i8>()?;
+        frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, move || {
+            stats::set_skill(slot, &name, rank)
+        })??;
+    }
+    Ok(format!("stat {} {value}", args[1]))
+}
+
+/// Lists the rows of `table` matching the filter words, or grants or removes one from a party
+/// member with `set`. Shared by `feat` and `power`, `what` names the thing in messages.
+fn change_from_table(
+    args: &[&str],
+    table: &'static str,
+    what: &str,
+    set: fn(usize, u16, bool) -> io::Result<()>,
+) -> CommandResult {
+    let (slot, granted, query) = match args {
+        ["list", filter @ ..] => {
+            let filter = filter.join(" ");
+            let entries =
+                frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, move || catalog::entries(table))??;
+            let lines: Vec<String> = catalog::search(&entries, &filter)
+                .into_iter()
+                .map(Entry::describe)
+                .collect();
+            if lines.is_empty() {
+                return Err(format!("No {what} matches '{filter}'").into());
+            }
+            return Ok(lines.join("\n"));
+        }
+        [slot, "add", query @ ..] if !query.is_empty() => (slot, true, query.join(" ")),
+        [slot, "remove", query @ ..] if !query.is_empty() => (slot, false, query.join(" ")),
+        _ => {
+            return Err(format!(
+                "Expected 'list [filter]' or a party slot, add or remove and the {what}"
+            )
+            .into())
+        }
+    };
+    let slot = slot.parse::<usize>()?;
+
+    let described = frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, move || {
+        // Row numbers work without the table, e.g. when it can't be read
+        let entries = match query.parse::<u16>() {
+            Ok(_) => Vec::new(),
+            Err(_) => catalog::entries(table)?,
+        };
+        let row = catalog::find(&entries, &query)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        set(slot, row, granted)?;
+        Ok::<_, io::Error>(
+            entries
+                .iter()
+                .find(|entry| entry.row == row)
+                .map_or_else(|| row.to_string(), Entry::describe),
+        )
+    })??;
+    Ok(format!(
+        "{what} {described} {}",
+        if granted { "added" } else { "removed" }
+    ))
+}
+
+fn feat(args: &[&str]) -> CommandResult {
+    change_from_table(args, "feat", "Feat", stats::set_feat)
+}
+
+fn power(args: &[&str]) -> CommandResult {
+    change_from_table(args, "spells", "Force power", stats::set_force_power)
+}
+
+fn warp(args: &[&str]) -> CommandResult {
+    let [module_name] = args else {
+        return Err("Expected exactly one module name".into());
+    };
+    module::validate_module_name(module_name)?;
+
+    let module_name = module_name.to_string();
+    let reply = format!("Warping to {module_name}");
+    frame::run_on_main_thread(move || {
+        if let Err(e) = module::warp(&module_name) {
+            error!("Warp to {module_name} failed: {e}");
+        }
+    });
+
+    Ok(reply)
+}
+
+fn give(args: &[&str]) -> CommandResult {
+    let (template, count) = match args {
+        [template] => (template.to_string(), 1),
+        [template, count] => (template.to_string(), count.parse::<u16>()?),
+        _ => return Err("Expected an item template and an optional count".into()),
+    };
+    items::validate_item(&template, count)?;
+
+    let reply = format!("Giving {count}x {template}");
+    frame::run_on_main_thread(move || {
+        if let Err(e) = items::give_item(&template, count) {
+            error!("Giving {template} failed: {e}");
+        }
+    });
+
+    Ok(reply)
+}
+
+fn fast_forward(args: &[&str]) -> CommandResult {
+    run_toggle("fastforward", args)
+}
+
+fn speed(args: &[&str]) -> CommandResult {
+    let multiplier = match args {
+        [] => return Ok(format!("speed {}", movement::speed_multiplier())),
+        [multiplier] => multiplier.parse::<f32>()?,
+        _ => return Err("Expected a single multiplier".into()),
+    };
+    movement::validate_speed_multiplier(multiplier)?;
+
+    frame::run_on_main_thread(move || {
+        if let Err(e) = movement::set_speed_multiplier(multiplier) {
+            error!("Could not set speed multiplier: {e}");
+        }
+    });
+
+    Ok(format!("speed {multiplier}"))
+}
+
+fn pause(args: &[&str]) -> CommandResult {
+    run_toggle("pause", args)
+}
+
+fn step(args: &[&str]) -> CommandResult {
+    let frames = match args {
+        [] => 1,
+        [frames] => frames.parse::<u32>()?,
+        _ => return Err("Expected the number of frames or nothing".into()),
+    };
+
+    clock::step(frames)?;
+    Ok(format!("Stepping {frames} frames"))
+}
+
+fn quick_start(args: &[&str]) -> CommandResult {
+    let (module, class, gender) = match args {
+        [module] => (*module, "soldier", "male"),
+        [module, class] => (*module, *class, "male"),
+        [module, class, gender] => (*module, *class, *gender),
+        _ => return Err("Expected a module, and optionally a class and a gender".into()),
+    };
+    module::validate_quick_start(module, class, gender)?;
+
+    let message = format!("Quick starting into {module} as a {gender} {class}");
+    let (module, class, gender) = (module.to_string(), class.to_string(), gender.to_string());
+    frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, move || {
+        module::quick_start(&module, &class, &gender)
+    })??;
+    Ok(message)
+}
+
+fn high_fps(args: &[&str]) -> CommandResult {
+    run_toggle("highfps", args)
+}
+
+fn time_scale(args: &[&str]) -> CommandResult {
+    let scale = match args {
+        [] => return Ok(format!("timescale {}", clock::time_scale())),
+        [scale] => scale.parse::<f32>()?,
+        _ => return Err("Expected a single scale".into()),
+    };
+    clock::validate_time_scale(scale)?;
+
+    frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, move || clock::set_time_scale(scale))??;
+    Ok(format!("timescale {scale}"))
+}
+
+fn timer(args: &[&str]) -> CommandResult {
+    // Take the time before locking, a hotkey press should not wait on the renderer
+    let now = Instant::now();
+    let mut timer = TIMER.lock().unwrap();
+
+    match args {
+        ["start"] => {
+            timer.start(now)?;
+            Ok("Timer started".to_string())
+        }
+        ["split"] => {
+            let split = timer.split(now)?;
+            Ok(format!(
+                "Split {}: RTA {} IGT {}",
+                timer.splits().len(),
+                format_duration(split.rta),
+                format_duration(split.igt)
+            ))
+        }
+        ["reset"] => {
+            timer.reset();
+            Ok("Timer reset".to_string())
+        }
+        _ => Err("Expected start, split or reset".into()),
+    }
+}
+
+fn loads(args: &[&str]) -> CommandResult {
+    match args {
+        [] => {
+            let stats = loading::stats();
+            let last = stats
+                .last
+                .map(format_duration)
+                .unwrap_or_else(|| "-".to_string());
+
+            let mut lines = vec![format!(
+                "{} loads, total {}, longest {}, last {last}",
+                stats.count,
+                format_duration(stats.total),
+                format_duration(stats.longest)
+            )];
+            // The first line is the session total, already covered above
+            lines.extend(loads_overlay::describe().into_iter().skip(1));
+            Ok(lines.join("\n"))
+        }
+        ["history"] => {
+            let history = loading::history();
+            if history.is_empty() {
+                return Ok("No loads yet".to_string());
+            }
+            let lines: Vec<String> = history
+                .iter()
+                .enumerate()
+                .map(|(i, load)| {
+                    let module = if load.module.is_empty() {
+                        "-"
+                    } else {
+                        &load.module
+                    };
+                    format!(
+                        "{:>3} {module:<16} {}",
+                        i + 1,
+                        loads_overlay::format_seconds(load.duration)
+                    )
+                })
+                .collect();
+            Ok(lines.join("\n"))
+        }
+        _ => run_toggle("loads", args),
+    }
+}
+
+fn alerts_command(_args: &[&str]) -> CommandResult {
+    let history = alerts::history();
+    if history.is_empty() {
+        return Ok("No alerts raised".to_string());
+    }
+    let lines: Vec<String> = history
+        .iter()
+        .map(|alert| {
+            format!(
+                "{} ago {:<10} {}",
+                format_duration(alert.raised.elapsed()),
+                alert.name,
+                alert.message
+            )
+        })
+        .collect();
+    Ok(lines.join("\n"))
+}
+
+fn overrides_command(args: &[&str]) -> CommandResult {
+    match args {
+        [] => {
+            let overrides = overrides::overrides();
+            if overrides.is_empty() {
+                return Ok("No overrides".to_string());
+            }
+            Ok(overrides.join("\n"))
+        }
+        ["rescan"] => {
+            let count = frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, overrides::rescan)??;
+            Ok(format!("{count} overrides"))
+        }
+        _ => Err("Expected 'rescan' or nothing".into()),
+    }
+}
+
+fn textures_command(args: &[&str]) -> CommandResult {
+    match args {
+        [] => {
+            let replacements = textures::replacements();
+            if replacements.is_empty() {
+                return Ok("No texture replacements".to_string());
+            }
+            Ok(replacements.join("\n"))
+        }
+        ["rescan"] => {
+            let count = frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, textures::rescan)??;
+            Ok(format!("{count} texture replacements"))
+        }
+        ["dump", rest @ ..] => run_toggle("texturedump", rest),
+        _ => Err("Expected 'rescan', 'dump' or nothing".into()),
+    }
+}
+
+fn volume_command(args: &[&str]) -> CommandResult {
+    match args {
+        [] => Ok(Category::ALL
+            .iter()
+            .map(|&category| format!("{category} {}", audio::volume(category)))
+            .collect::<Vec<_>>()
+            .join("\n")),
+        [category, multiplier] => {
+            let category =
+                Category::parse(category).ok_or_else(|| format!("Unknown volume '{category}'"))?;
+            let multiplier: f32 = multiplier.parse()?;
+            frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, move || {
+                audio::set_volume(category, multiplier)
+            })??;
+            Ok(format!("{category} {multiplier}"))
+        }
+        _ => Err("Expected a volume and a multiplier, e.g. 'music 0.5'".into()),
+    }
+}
+
+fn settings_command(args: &[&str]) -> CommandResult {
+    match args {
+        [] => Ok(SETTINGS
+            .iter()
+            .map(|setting| format!("{} {}", setting.name, settings::value(setting)))
+            .collect::<Vec<_>>()
+            .join("\n")),
+        [name] => {
+            let setting = settings::find(name)?;
+            Ok(format!("{name} {}", settings::value(setting)))
+        }
+        [name, value] => {
+            let setting = settings::find(name)?;
+            settings::set_from_str(setting, value)?;
+            Ok(format!("{name} {}", settings::value(setting)))
+        }
+        _ => Err("Expected a setting and a value, e.g. 'minimap.zoom 8'".into()),
+    }
+}
+
+fn script_command(args: &[&str]) -> CommandResult {
+    match args {
+        [] => {
+            let scripts = script::scripts()
+                .map_err(|e| format!("Could not list {}: {e}", script::SCRIPT_DIRECTORY))?;
+            let running = match script::running() {
+                Some(name) => format!("Running {name}"),
+                None => "No script running".to_string(),
+            };
+            Ok(std::iter::once(running)
+                .chain(scripts)
+                .collect::<Vec<_>>()
+                .join("\n"))
+        }
+        ["run", name] => {
+            script::run(name)?;
+            Ok(format!("Running {name}"))
+        }
+        ["stop"] => Ok(if script::stop() {
+            "Stopping the script".to_string()
+        } else {
+            "No script running".to_string()
+        }),
+        _ => Err("Expected 'run <name>', 'stop' or nothing to list".into()),
+    }
+}
+
+fn call_command(args: &[&str]) -> CommandResult {
+    match args {
+        [] => Ok(bindings::BINDINGS
+            .iter()
+            .map(|binding| format!("{} {}: {}", binding.name, binding.usage(), binding.help))
+            .collect::<Vec<_>>()
+            .join("\n")),
+        [function, args @ ..] => bindings::call(function, args),
+    }
+}
+
+fn status_command(_args: &[&str]) -> CommandResult {
+    Ok(system::status::report().join("\n"))
+}
+
+fn reload(_args: &[&str]) -> CommandResult {
+    system::reload::request_reload()?;
+    Ok("Reloading the mod".to_string())
+}
+
+fn screenshot(_args: &[&str]) -> CommandResult {
+    request_screenshot();
+    Ok("Taking a screenshot".to_string())
+}
+
+fn fov(args: &[&str]) -> CommandResult {
+    let degrees = match args {
+        [] => {
+            return Ok(match display::field_of_view() {
+                Some(degrees) => format!("fov {degrees}"),
+                None => "fov default".to_string(),
+            })
+        }
+        ["default"] => None,
+        [degrees] => Some(degrees.parse::<f32>()?),
+        _ => return Err("Expected degrees or 'default'".into()),
+    };
+    if let Some(degrees) = degrees {
+        display::validate_field_of_view(degrees)?;
+    }
+
+    frame::run_on_main_thread(move || {
+        if let Err(e) = display::set_field_of_view(degrees) {
+            error!("Could not set field of view: {e}");
+        }
+    });
+
+    Ok(match degrees {
+        Some(degrees) => format!("fov {degrees}"),
+        None => "fov default".to_string(),
+    })
+}
+
+/// Columns holding StrRefs by the naming conventions the game's tables use
+fn is_strref_column(column: &str) -> bool {
+    let column = column.to_ascii_lowercase();
+    ["name", "description", "desc"].contains(&column.as_str()) || column.contains("strref")
+}
+
+fn twoda(args: &[&str]) -> CommandResult {
+    let (name, row) = match args {
+        [] => return Ok(tables::loaded_tables().join("\n")),
+        [name] => (name.to_string(), None),
+        [name, row] => (name.to_string(), Some(row.parse::<usize>()?)),
+        _ => return Err("Expected a table name and an optional row".into()),
+    };
+
+    let table =
+        frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, move || tables::read_table(&name))??;
+
+    let Some(row) = row else {
+        return Ok(table.to_string());
+    };
+    let cells = &table
+        .rows
+        .get(row)
+        .ok_or_else(|| format!("Table has only {} rows", table.rows.len()))?
+        .cells;
+
+    let lines: Vec<String> = table
+        .columns
+        .iter()
+        .zip(cells)
+        .map(|(column, value)| {
+            let value = match value.as_deref() {
+                None => "****".to_string(),
+                Some(value) if is_strref_column(column) => match value.parse::<u32>() {
+                    Ok(strref) => strref::describe(strref),
+                    Err(_) => value.to_string(),
+                },
+                Some(value) => value.to_string(),
+            };
+            format!("{column:<24} {value}")
+        })
+        .collect();
+    Ok(lines.join("\n"))
+}
+
+fn strings_command(args: &[&str]) -> CommandResult {
+    match args {
+        [] => {
+            let replaced = strings::replaced();
+            if replaced.is_empty() {
+                return Ok("No strings replaced".to_string());
+            }
+            let lines: Vec<String> = replaced.into_iter().map(strref::describe).collect();
+            Ok(lines.join("\n"))
+        }
+        ["reload"] => {
+            let count = frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, strings::reload)??;
+            Ok(format!("{count} strings replaced"))
+        }
+        ["set", strref, text @ ..] if !text.is_empty() => {
+            let strref = strref.parse::<u32>()?;
+            let text = text.join(" ");
+            frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, move || {
+                strings::set_replacement(strref, &text)
+            })??;
+            Ok(strref::describe(strref))
+        }
+        ["unset", strref] => {
+            let strref = strref.parse::<u32>()?;
+            if !strings::remove_replacement(strref) {
+                return Err(format!("StrRef {strref} is not replaced").into());
+            }
+            Ok(strref::describe(strref))
+        }
+        _ => Err("Expected 'reload', 'set <strref> <text>' or 'unset <strref>'".into()),
+    }
+}
+
+fn strref_command(args: &[&str]) -> CommandResult {
+    let [strref] = args else {
+        return Err("Expected exactly one StrRef".into());
+    };
+    let strref = strref.parse::<u32>()?;
+
+    strref::lookup(strref).ok_or_else(|| format!("No string for StrRef {strref}").into())
+}
+
+fn describe_model(model: &ModelHeader) -> Vec<String> {
+    let ([min_x, min_y, min_z], [max_x, max_y, max_z]) = model.bounding_box;
+    let mut lines = vec![
+        format!(
+            "{} ({:?}), supermodel {}",
+            model.name, model.classification, model.supermodel
+        ),
+        format!(
+            "Nodes: {}, node names: {}",
+            model.node_count, model.node_name_count
+        ),
+        format!(
+            "Bounding box: {min_x:.2} {min_y:.2} {min_z:.2} to {max_x:.2} {max_y:.2} {max_z:.2}, radius {:.2}",
+            model.radius
+        ),
+        format!(
+            "Animation scale {:.2}, MDX {} bytes",
+            model.animation_scale, model.mdx_size
+        ),
+        format!("Animations: {}", model.animations.len()),
+    ];
+    lines.extend(model.animations.iter().map(|animation| {
+        format!(
+            "  {} {:.2}s, transition {:.2}s, root {}, {} nodes, {} events",
+            animation.name,
+            animation.length,
+            animation.transition,
+            animation.root,
+            animation.node_count,
+            animation.event_count
+        )
+    }));
+    lines
+}
+
+fn model_command(args: &[&str]) -> CommandResult {
+    let name = match args {
+        [] => frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, models::model_under_cursor)??
+            .ok_or("The mouse cursor is not over an object")?,
+        [name] => name.to_string(),
+        _ => return Err("Expected a model name or nothing for the one under the cursor".into()),
+    };
+
+    let model =
+        frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, move || models::model_header(&name))??;
+    Ok(describe_model(&model).join("\n"))
+}
+
+fn nameplates_command(args: &[&str]) -> CommandResult {
+    match args {
+        ["types"] => Ok(format!(
+            "nameplates types {}",
+            nameplates::types().join(" ")
+        )),
+        ["types", types @ ..] => {
+            nameplates::set_types(types)?;
+            Ok(format!("nameplates types {}", types.join(" ")))
+        }
+        _ => run_toggle("nameplates", args),
+    }
+}
+
+fn watch_command(args: &[&str]) -> CommandResult {
+    match args {
+        ["add", name, target, kind] => {
+            let target = engine_watch::Target::parse(target)?;
+            let kind = engine_watch::ValueKind::parse(kind)?;
+            watch::add_watch(engine_watch::Watch {
+                name: name.to_string(),
+                target,
+                kind,
+            });
+            watch::set_watch(true)?;
+            Ok(format!("Watching {name}"))
+        }
+        ["remove", name] => {
+            watch::remove_watch(name)?;
+            Ok(format!("Removed watch {name}"))
+        }
+        ["list"] => {
+            let lines: Vec<String> = watch::describe()
+                .into_iter()
+                .map(|(line, _)| line)
+                .collect();
+            if lines.is_empty() {
+                return Ok("No watches".to_string());
+            }
+            Ok(lines.join("\n"))
+        }
+        _ => run_toggle("watch", args),
+    }
+}
+
+fn fixture_command(args: &[&str]) -> CommandResult {
+    let [name] = args else {
+        return Err("Expected a fixture name".into());
+    };
+    let name = name.to_string();
+    let path = frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, move || fixtures::record(&name))??;
+    Ok(format!("Recorded {}", path.display()))
+}
+
+fn objects_command(args: &[&str]) -> CommandResult {
+    match args {
+        [] => {
+            let objects = frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, area::area_objects)??;
+            let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+            for object in &objects {
+                *counts.entry(object.kind).or_default() += 1;
+            }
+            let mut lines = vec![format!("{} objects", objects.len())];
+            lines.extend(
+                counts
+                    .iter()
+                    .map(|(kind, count)| format!("  {kind}: {count}")),
+            );
+            Ok(lines.join("\n"))
+        }
+        ["dump"] => {
+            let (path, count) = dump_area_objects()?;
+            Ok(format!("Wrote {count} objects to {path}"))
+        }
+        _ => Err("Expected dump or nothing".into()),
+    }
+}
+
+fn schemas_command(_args: &[&str]) -> CommandResult {
+    let directory = Path::new("schemas");
+    fs::create_dir_all(directory)?;
+    for (name, schema) in [
+        ("area-dump", area::dump_schema()),
+        ("telemetry", recorder::csv_schema()),
+    ] {
+        let path = directory.join(format!("{name}.schema.json"));
+        fs::write(path, serde_json::to_string_pretty(&schema)? + "\n")?;
+    }
+    Ok(format!("Wrote the schemas to {}", directory.display()))
+}
+
+/// Writes the objects of the current area to a JSON file, returns the file and the object count.
+pub fn dump_area_objects() -> Result<(String, usize), Box<dyn Error>> {
+    let (module, objects) = frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, || {
+        Ok::<_, io::Error>((module::current_module()?, area::area_objects()?))
+    })??;
+    let path = area::write_dump(&module.unwrap_or_default(), &objects)?;
+    Ok((path, objects.len()))
+}
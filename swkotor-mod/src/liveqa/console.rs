@@ -0,0 +1,167 @@
+/// Module for the QA console.
+///
+/// Opens a separate console window next to the game and runs each typed line as a command.
+/// Commands are registered at startup and dispatched by name, so anything else that wants to run
+/// them can go through `execute` as well.
+///
+use std::{
+    error::Error,
+    io::{self, BufRead, Write},
+    sync::{LazyLock, Mutex},
+};
+
+use log::{error, info, trace};
+use windows::Win32::{
+    System::Console::{
+        AllocConsole, FreeConsole, GetConsoleWindow, GetStdHandle, WriteConsoleInputW,
+        INPUT_RECORD, INPUT_RECORD_0, KEY_EVENT, KEY_EVENT_RECORD, KEY_EVENT_RECORD_0,
+        STD_INPUT_HANDLE,
+    },
+    UI::{Input::KeyboardAndMouse::VK_RETURN, WindowsAndMessaging::GetForegroundWindow},
+};
+
+use crate::engine::input;
+use crate::plugins;
+use crate::system::services;
+
+pub type CommandResult = Result<String, Box<dyn Error>>;
+
+/// A single console command.
+pub struct Command {
+    pub name: &'static str,
+    /// Argument synopsis shown in help, e.g. `[on|off]`
+    pub usage: &'static str,
+    pub help: &'static str,
+    pub run: fn(&[&str]) -> CommandResult,
+}
+
+static COMMANDS: LazyLock<Mutex<Vec<Command>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+
+pub fn register_command(command: Command) {
+    trace!("Registering console command {}", command.name);
+    COMMANDS.lock().unwrap().push(command);
+}
+
+/// Runs a single command line, first word being the command name and the rest its arguments.
+pub fn execute(line: &str) -> CommandResult {
+    let mut words = line.split_whitespace();
+    let Some(name) = words.next() else {
+        return Ok(String::new());
+    };
+    let args: Vec<&str> = words.collect();
+
+    // Copy the handler out so commands can use the registry themselves without deadlocking
+    let run = {
+        let commands = COMMANDS.lock().unwrap();
+        commands.iter().find(|c| c.name == name).map(|c| c.run)
+    };
+
+    match run {
+        Some(run) => run(&args),
+        None => match plugins::run_command(name, &args) {
+            Some(output) => output.map_err(Into::into),
+            None => Err(format!("Unknown command '{name}', try 'help'").into()),
+        },
+    }
+}
+
+/// Parses an optional `on`/`off` argument for toggle commands. Without arguments the current
+/// state is flipped.
+pub fn parse_toggle(args: &[&str], current: bool) -> Result<bool, Box<dyn Error>> {
+    match args {
+        [] => Ok(!current),
+        ["on"] => Ok(true),
+        ["off"] => Ok(false),
+        _ => Err("Expected 'on', 'off' or nothing to toggle".into()),
+    }
+}
+
+pub fn help(_args: &[&str]) -> CommandResult {
+    let commands = COMMANDS.lock().unwrap();
+    let lines: Vec<String> = commands
+        .iter()
+        .map(|c| format!("{:<24} {}", format!("{} {}", c.name, c.usage), c.help))
+        .collect();
+    Ok(lines.join("\n"))
+}
+
+/// Whether the console window is in front, typing into it must not move the character.
+fn console_has_focus() -> bool {
+    let console = unsafe { GetConsoleWindow() };
+    !console.is_invalid() && console == unsafe { GetForegroundWindow() }
+}
+
+/// Opens the console window and starts reading commands from it in a background thread.
+pub fn spawn_console() {
+    services::on_stop(wake_console);
+    input::capture_while(console_has_focus);
+    let spawned = services::spawn("console", || {
+        if let Err(e) = unsafe { AllocConsole() } {
+            error!("Could not open the QA console: {e}");
+            return;
+        }
+
+        println!("swkotor-mod console, type 'help' for a list of commands");
+        prompt();
+
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            if services::stopping() {
+                break;
+            }
+
+            info!("Console: {line}");
+            match execute(&line) {
+                Ok(output) if output.is_empty() => (),
+                Ok(output) => println!("{output}"),
+                Err(e) => println!("error: {e}"),
+            }
+            prompt();
+        }
+
+        trace!("Console input closed");
+        // Let a reloaded mod open a console of its own
+        let _ = unsafe { FreeConsole() };
+    });
+
+    if let Err(e) = spawned {
+        error!("Could not start the QA console: {e}");
+    }
+}
+
+/// Types an empty line into the console, the blocked read returns and sees the stop.
+fn wake_console() {
+    let enter = |key_down| INPUT_RECORD {
+        EventType: KEY_EVENT as u16,
+        Event: INPUT_RECORD_0 {
+            KeyEvent: KEY_EVENT_RECORD {
+                bKeyDown: key_down,
+                wRepeatCount: 1,
+                wVirtualKeyCode: VK_RETURN.0,
+                uChar: KEY_EVENT_RECORD_0 {
+                    UnicodeChar: '\r' as u16,
+                },
+                ..Default::default()
+            },
+        },
+    };
+
+    unsafe {
+        let Ok(input) = GetStdHandle(STD_INPUT_HANDLE) else {
+            return;
+        };
+        let mut written = 0;
+        let _ = WriteConsoleInputW(
+            input,
+            &[enter(true.into()), enter(false.into())],
+            &mut written,
+        );
+    }
+}
+
+fn prompt() {
+    print!("> ");
+    let _ = io::stdout().flush();
+}
@@ -0,0 +1,131 @@
+/// Module for navigating the overlay menu with a gamepad.
+///
+/// The PC game has no controller support of its own, so the buttons are free for us. Pads are
+/// polled through XInput from a background thread and only while the game window has focus, like
+/// the hotkeys. Back and Start together open the menu, the shoulder buttons switch between toggles
+/// and settings, the D-pad moves and adjusts, A toggles and B closes.
+use std::{thread, time::Duration};
+
+use log::{error, info, trace};
+use windows::Win32::{
+    Foundation::ERROR_SUCCESS,
+    UI::Input::XboxController::{
+        XInputGetState, XINPUT_GAMEPAD_A, XINPUT_GAMEPAD_B, XINPUT_GAMEPAD_BACK,
+        XINPUT_GAMEPAD_BUTTON_FLAGS, XINPUT_GAMEPAD_DPAD_DOWN, XINPUT_GAMEPAD_DPAD_LEFT,
+        XINPUT_GAMEPAD_DPAD_RIGHT, XINPUT_GAMEPAD_DPAD_UP, XINPUT_GAMEPAD_LEFT_SHOULDER,
+        XINPUT_GAMEPAD_RIGHT_SHOULDER, XINPUT_GAMEPAD_START, XINPUT_STATE,
+    },
+};
+
+use crate::{config::config, overlay::menu, system::services};
+
+use super::hotkeys::{game_has_focus, POLL_INTERVAL};
+
+/// XInput supports at most four pads
+const MAX_PADS: u32 = 4;
+/// Querying disconnected pads is slow, don't do it every frame when nothing is plugged in
+const DISCONNECTED_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Returns the buttons held on any connected pad, or `None` when no pad is connected.
+fn buttons_down() -> Option<XINPUT_GAMEPAD_BUTTON_FLAGS> {
+    let mut buttons = XINPUT_GAMEPAD_BUTTON_FLAGS(0);
+    let mut connected = false;
+
+    for pad in 0..MAX_PADS {
+        let mut state = XINPUT_STATE::default();
+        if unsafe { XInputGetState(pad, &mut state) } == ERROR_SUCCESS.0 {
+            connected = true;
+            buttons |= state.Gamepad.wButtons;
+        }
+    }
+
+    connected.then_some(buttons)
+}
+
+fn pressed(
+    down: XINPUT_GAMEPAD_BUTTON_FLAGS,
+    was_down: XINPUT_GAMEPAD_BUTTON_FLAGS,
+    button: XINPUT_GAMEPAD_BUTTON_FLAGS,
+) -> bool {
+    down.contains(button) && !was_down.contains(button)
+}
+
+fn handle_buttons(down: XINPUT_GAMEPAD_BUTTON_FLAGS, was_down: XINPUT_GAMEPAD_BUTTON_FLAGS) {
+    let open_combo = XINPUT_GAMEPAD_BACK | XINPUT_GAMEPAD_START;
+    if down.contains(open_combo) && !was_down.contains(open_combo) {
+        menu::toggle_open();
+        return;
+    }
+
+    if !menu::is_open() {
+        return;
+    }
+
+    if pressed(down, was_down, XINPUT_GAMEPAD_DPAD_UP) {
+        menu::move_selection(-1);
+    }
+    if pressed(down, was_down, XINPUT_GAMEPAD_DPAD_DOWN) {
+        menu::move_selection(1);
+    }
+    if pressed(down, was_down, XINPUT_GAMEPAD_DPAD_LEFT) {
+        menu::adjust(-1);
+    }
+    if pressed(down, was_down, XINPUT_GAMEPAD_DPAD_RIGHT) {
+        menu::adjust(1);
+    }
+    if pressed(down, was_down, XINPUT_GAMEPAD_LEFT_SHOULDER) {
+        menu::show_page(false);
+    }
+    if pressed(down, was_down, XINPUT_GAMEPAD_RIGHT_SHOULDER) {
+        menu::show_page(true);
+    }
+    if pressed(down, was_down, XINPUT_GAMEPAD_A) {
+        menu::activate();
+    }
+    if pressed(down, was_down, XINPUT_GAMEPAD_B) {
+        menu::close();
+    }
+}
+
+/// Starts polling connected gamepads in a background thread.
+pub fn spawn_gamepad_listener() {
+    if !config().gamepad.enabled {
+        trace!("Gamepad navigation disabled");
+        return;
+    }
+
+    let spawned = services::spawn("gamepad", || {
+        let mut was_down = XINPUT_GAMEPAD_BUTTON_FLAGS(0);
+        let mut was_connected = false;
+
+        while !services::stopping() {
+            thread::sleep(POLL_INTERVAL);
+
+            if !game_has_focus() {
+                continue;
+            }
+
+            let Some(down) = buttons_down() else {
+                if was_connected {
+                    info!("Gamepad disconnected");
+                    was_connected = false;
+                    was_down = XINPUT_GAMEPAD_BUTTON_FLAGS(0);
+                }
+                thread::sleep(DISCONNECTED_POLL_INTERVAL);
+                continue;
+            };
+            if !was_connected {
+                info!("Gamepad connected");
+                was_connected = true;
+            }
+
+            // Fire once per press, not for every poll the button is held
+            handle_buttons(down, was_down);
+            was_down = down;
+        }
+    });
+
+    if let Err(e) = spawned {
+        error!("Could not start the gamepad listener: {e}");
+    }
+}
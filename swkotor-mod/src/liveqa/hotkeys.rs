@@ -0,0 +1,261 @@
+/// Module for binding console commands to keys.
+///
+/// Keys are polled from a background thread and only while the game window has focus. Bound
+/// commands go through the console dispatcher, so a hotkey behaves exactly like typing the
+/// command.
+///
+/// Macros from the config bind a key to a list of commands, run in order until one fails. A macro
+/// on the same key as a default hotkey replaces it.
+///
+use std::{thread, time::Duration};
+
+use log::{error, info, trace, warn};
+use windows::Win32::{
+    System::Threading::GetCurrentProcessId,
+    UI::{
+        Input::KeyboardAndMouse::{
+            GetAsyncKeyState, VIRTUAL_KEY, VK_CONTROL, VK_F, VK_F1, VK_F11, VK_F12, VK_F2, VK_F3,
+            VK_F5, VK_F6, VK_F8, VK_F9, VK_G, VK_N, VK_O, VK_P,
+        },
+        WindowsAndMessaging::{GetForegroundWindow, GetWindowThreadProcessId},
+    },
+};
+
+use crate::config::config;
+use crate::system::services;
+
+use super::console;
+
+/// Roughly once per frame at 60fps, fast enough to not miss a tap.
+pub(crate) const POLL_INTERVAL: Duration = Duration::from_millis(16);
+
+pub struct Hotkey {
+    /// Require control to be held, keeps us from fighting the game over plain keys
+    pub ctrl: bool,
+    pub key: VIRTUAL_KEY,
+    pub command: &'static str,
+}
+
+pub const DEFAULT_HOTKEYS: &[Hotkey] = &[
+    Hotkey {
+        ctrl: true,
+        key: VK_N,
+        command: "noclip",
+    },
+    Hotkey {
+        ctrl: true,
+        key: VK_G,
+        command: "god",
+    },
+    Hotkey {
+        ctrl: true,
+        key: VK_F,
+        command: "infiniteforce",
+    },
+    Hotkey {
+        ctrl: true,
+        key: VK_P,
+        command: "pause",
+    },
+    Hotkey {
+        ctrl: true,
+        key: VK_O,
+        command: "step",
+    },
+    Hotkey {
+        ctrl: true,
+        key: VK_F1,
+        command: "timer start",
+    },
+    Hotkey {
+        ctrl: true,
+        key: VK_F2,
+        command: "timer split",
+    },
+    Hotkey {
+        ctrl: true,
+        key: VK_F3,
+        command: "timer reset",
+    },
+    Hotkey {
+        ctrl: true,
+        key: VK_F5,
+        command: "snapshot save",
+    },
+    Hotkey {
+        ctrl: true,
+        key: VK_F6,
+        command: "practice on",
+    },
+    Hotkey {
+        ctrl: true,
+        key: VK_F8,
+        command: "practice reset",
+    },
+    Hotkey {
+        ctrl: true,
+        key: VK_F9,
+        command: "snapshot restore",
+    },
+    Hotkey {
+        ctrl: true,
+        key: VK_F11,
+        command: "borderless",
+    },
+    Hotkey {
+        ctrl: true,
+        key: VK_F12,
+        command: "screenshot",
+    },
+];
+
+/// Key and modifier a binding fires on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyCombo {
+    pub ctrl: bool,
+    pub key: VIRTUAL_KEY,
+}
+
+/// Parses a key like `Ctrl+F7`, `ctrl+k` or `F10`. Letters, digits and F1-F24 are supported.
+pub fn parse_key(text: &str) -> Result<KeyCombo, String> {
+    let (ctrl, key) = match text.split_once('+') {
+        Some((modifier, key)) if modifier.trim().eq_ignore_ascii_case("ctrl") => (true, key),
+        Some((modifier, _)) => return Err(format!("Unknown modifier '{modifier}', expected Ctrl")),
+        None => (false, text),
+    };
+
+    let key = key.trim().to_ascii_uppercase();
+    let virtual_key = match key.as_bytes() {
+        [c] if c.is_ascii_alphanumeric() => VIRTUAL_KEY(*c as u16),
+        [b'F', number @ ..] => match std::str::from_utf8(number).unwrap_or("").parse::<u16>() {
+            Ok(number @ 1..=24) => VIRTUAL_KEY(VK_F1.0 + number - 1),
+            _ => return Err(format!("Unknown key '{key}'")),
+        },
+        _ => return Err(format!("Unknown key '{key}'")),
+    };
+    Ok(KeyCombo {
+        ctrl,
+        key: virtual_key,
+    })
+}
+
+/// A key and the commands it runs
+struct Binding {
+    combo: KeyCombo,
+    commands: Vec<String>,
+}
+
+/// Macros from the config followed by the default hotkeys they don't replace.
+fn bindings(hotkeys: &[Hotkey]) -> Vec<Binding> {
+    let mut bindings = Vec::new();
+    for macro_config in &config().macros {
+        match parse_key(&macro_config.key) {
+            Ok(combo) => bindings.push(Binding {
+                combo,
+                commands: macro_config.commands.clone(),
+            }),
+            Err(e) => warn!("Skipping macro on '{}': {e}", macro_config.key),
+        }
+    }
+
+    for hotkey in hotkeys {
+        let combo = KeyCombo {
+            ctrl: hotkey.ctrl,
+            key: hotkey.key,
+        };
+        if bindings.iter().any(|binding| binding.combo == combo) {
+            info!("Macro replaces the '{}' hotkey", hotkey.command);
+            continue;
+        }
+        bindings.push(Binding {
+            combo,
+            commands: vec![hotkey.command.to_string()],
+        });
+    }
+    bindings
+}
+
+/// Runs the commands of a binding in order, stopping at the first one that fails.
+fn run_binding(binding: &Binding) {
+    for command in &binding.commands {
+        info!("Hotkey: {command}");
+        if let Err(e) = console::execute(command) {
+            error!("Hotkey command '{command}' failed: {e}");
+            return;
+        }
+    }
+}
+
+pub(crate) fn is_key_down(key: VIRTUAL_KEY) -> bool {
+    // Most significant bit tells if the key is currently down
+    unsafe { GetAsyncKeyState(key.0 as i32) as u16 & 0x8000 != 0 }
+}
+
+pub(crate) fn game_has_focus() -> bool {
+    let mut process_id = 0;
+    unsafe {
+        GetWindowThreadProcessId(GetForegroundWindow(), Some(&mut process_id));
+        process_id == GetCurrentProcessId()
+    }
+}
+
+/// Starts polling for the given hotkeys in a background thread.
+pub fn spawn_hotkey_listener(hotkeys: &'static [Hotkey]) {
+    let bindings = bindings(hotkeys);
+    let spawned = services::spawn("hotkeys", move || {
+        trace!("Listening for {} hotkeys", bindings.len());
+        let mut was_down = vec![false; bindings.len()];
+
+        while !services::stopping() {
+            thread::sleep(POLL_INTERVAL);
+
+            if !game_has_focus() {
+                continue;
+            }
+
+            let ctrl_down = is_key_down(VK_CONTROL);
+            for (binding, was_down) in bindings.iter().zip(was_down.iter_mut()) {
+                let down = is_key_down(binding.combo.key) && binding.combo.ctrl == ctrl_down;
+
+                // Fire once per press, not for every poll the key is held
+                if down && !*was_down {
+                    run_binding(binding);
+                }
+                *was_down = down;
+            }
+        }
+    });
+
+    if let Err(e) = spawned {
+        error!("Could not start the hotkey listener: {e}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use windows::Win32::UI::Input::KeyboardAndMouse::VK_K;
+
+    use super::*;
+
+    #[test]
+    fn parses_keys() {
+        assert_eq!(
+            parse_key("Ctrl+F7").unwrap(),
+            KeyCombo {
+                ctrl: true,
+                key: VIRTUAL_KEY(VK_F1.0 + 6),
+            }
+        );
+        assert_eq!(
+            parse_key("ctrl + k").unwrap(),
+            KeyCombo {
+                ctrl: true,
+                key: VK_K,
+            }
+        );
+        assert_eq!(parse_key("F10").unwrap().key, VIRTUAL_KEY(VK_F1.0 + 9));
+        assert!(parse_key("Alt+K").is_err());
+        assert!(parse_key("Ctrl+F25").is_err());
+        assert!(parse_key("Ctrl+Space").is_err());
+    }
+}
@@ -1,2 +1,10 @@
+pub mod catalog;
+pub mod commands;
+pub mod console;
+pub mod gamepad;
+pub mod hotkeys;
 pub mod liveassert;
+pub mod quickstart;
 pub mod runner;
+pub mod script;
+pub mod smoke;
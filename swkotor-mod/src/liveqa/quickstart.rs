@@ -0,0 +1,66 @@
+/// Quick start: straight from launching the game into a module, skipping the main menu, character
+/// generation and the prologue.
+///
+/// With `quick_start.module` set the mod starts a new game in that module with a premade
+/// character as soon as the game is past its startup, the same as the `quickstart` command from
+/// the main menu. Testers get to the area they're testing in one step.
+use std::{
+    io, thread,
+    time::{Duration, Instant},
+};
+
+use log::{error, info};
+
+use crate::config::{config, QuickStartConfig};
+use crate::engine::{frame, module};
+use crate::system::services;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+const MAIN_THREAD_TIMEOUT: Duration = Duration::from_secs(1);
+/// How long the game gets to reach the main menu
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(120);
+
+fn run(settings: QuickStartConfig, module: String) {
+    let started = Instant::now();
+    // The server application shows up once the game is past its startup, starting fails before
+    while started.elapsed() < STARTUP_TIMEOUT && !services::stopping() {
+        let (target, settings) = (module.clone(), settings.clone());
+        match frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, move || {
+            module::quick_start(&target, &settings.class, &settings.gender)
+        }) {
+            Ok(Ok(())) => return,
+            Ok(Err(e)) if e.kind() == io::ErrorKind::NotConnected => {}
+            Ok(Err(e)) => {
+                error!("Could not quick start into {module}: {e}");
+                return;
+            }
+            // Still loading the game, the main thread is busy
+            Err(_) => {}
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+
+    if !services::stopping() {
+        error!("Could not quick start into {module}, the game never got to the main menu");
+    }
+}
+
+/// Starts the game in the configured module, when there is one.
+pub fn init() {
+    let settings = config().quick_start.clone();
+    let Some(module) = settings.module.clone() else {
+        return;
+    };
+    if let Err(e) = module::validate_quick_start(&module, &settings.class, &settings.gender) {
+        error!("Not quick starting, the quick_start settings are invalid: {e}");
+        return;
+    }
+
+    info!(
+        "Quick starting into {module} as a {} {}",
+        settings.gender, settings.class
+    );
+    if let Err(e) = services::spawn("quick-start", move || run(settings, module)) {
+        error!("Could not start the quick start: {e}");
+    }
+}
@@ -0,0 +1,272 @@
+/// Scripted QA scenarios.
+///
+/// Scenarios are [rhai](https://rhai.rs) scripts in the `scripts` directory of the game, run on
+/// demand with `script run <name>`. Scripts run on a thread of their own and call into the game
+/// through the same main thread queue as the console, waiting for the game where needed:
+///
+/// ```rhai
+/// warp("end_m01aa");
+/// wait_for_module("end_m01aa", 60);
+/// set_global("END_TRASK_DLG", true);
+/// give("g_w_blstrpstl001", 2);
+/// spawn("creature", "end_trask");
+/// play_movie("01a");
+/// command("noclip on");
+/// ```
+///
+/// Engine calls go through `engine::bindings`, `call("<function> <args>...")` reaches the ones
+/// without a function of their own here.
+///
+/// Only one script runs at a time. `script stop` stops it at the next statement or wait.
+use std::{
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use log::{error, info};
+use rhai::{Dynamic, Engine, EvalAltResult, INT};
+
+use crate::engine::{bindings, loading, module};
+use crate::system::services;
+
+use super::console;
+
+pub const SCRIPT_DIRECTORY: &str = "scripts";
+const SCRIPT_EXTENSION: &str = "rhai";
+
+/// How often waits check their condition and whether the script should stop
+const WAIT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+type ScriptResult<T> = Result<T, Box<EvalAltResult>>;
+
+/// Name of the running script
+static RUNNING: Mutex<Option<String>> = Mutex::new(None);
+static STOP: AtomicBool = AtomicBool::new(false);
+
+fn script_error(e: impl fmt::Display) -> Box<EvalAltResult> {
+    e.to_string().into()
+}
+
+fn should_stop() -> bool {
+    STOP.load(Ordering::Relaxed) || services::stopping()
+}
+
+/// Calls the engine binding `function`, arguments are passed as text like from the console.
+fn call_binding(function: &str, args: &[&str]) -> ScriptResult<String> {
+    bindings::call(function, args).map_err(script_error)
+}
+
+/// Polls `condition` until it holds, failing after `timeout_seconds` or when the script is
+/// stopped.
+fn wait_until(what: &str, timeout_seconds: INT, condition: impl Fn() -> bool) -> ScriptResult<()> {
+    let timeout = Duration::from_secs(timeout_seconds.max(0) as u64);
+    let started = Instant::now();
+    while !condition() {
+        if should_stop() {
+            return Err(script_error("Stopped"));
+        }
+        if started.elapsed() >= timeout {
+            return Err(script_error(format!(
+                "Timed out after {timeout_seconds}s waiting for {what}"
+            )));
+        }
+        thread::sleep(WAIT_POLL_INTERVAL);
+    }
+    Ok(())
+}
+
+fn command(line: &str) -> ScriptResult<String> {
+    console::execute(line).map_err(script_error)
+}
+
+/// Calls a binding from a single line, e.g. `call("set_global_number END_TRASK_DLG 2")`.
+fn call(line: &str) -> ScriptResult<String> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    match words.as_slice() {
+        [function, args @ ..] => call_binding(function, args),
+        [] => Err(script_error("Expected an engine function to call")),
+    }
+}
+
+fn warp(module_name: &str) -> ScriptResult<()> {
+    call_binding("warp", &[module_name]).map(drop)
+}
+
+fn give(template: &str, count: INT) -> ScriptResult<()> {
+    call_binding("give_item", &[template, &count.to_string()]).map(drop)
+}
+
+fn spawn(kind: &str, template: &str) -> ScriptResult<String> {
+    call_binding("spawn_object", &[kind, template])
+}
+
+fn spawn_at(kind: &str, template: &str, x: f64, y: f64, z: f64) -> ScriptResult<String> {
+    let position = [x.to_string(), y.to_string(), z.to_string()];
+    call_binding(
+        "spawn_object",
+        &[kind, template, &position[0], &position[1], &position[2]],
+    )
+}
+
+fn play_movie(movie: &str) -> ScriptResult<()> {
+    call_binding("play_movie", &[movie]).map(drop)
+}
+
+fn set_global_boolean(name: &str, value: bool) -> ScriptResult<()> {
+    call_binding("set_global_boolean", &[name, &value.to_string()]).map(drop)
+}
+
+fn set_global_number(name: &str, value: INT) -> ScriptResult<()> {
+    call_binding("set_global_number", &[name, &value.to_string()]).map(drop)
+}
+
+fn global_boolean(name: &str) -> ScriptResult<bool> {
+    call_binding("global_boolean", &[name])?
+        .parse()
+        .map_err(script_error)
+}
+
+fn global_number(name: &str) -> ScriptResult<INT> {
+    call_binding("global_number", &[name])?
+        .parse()
+        .map_err(script_error)
+}
+
+fn entered_module() -> String {
+    module::entered_module().unwrap_or_default()
+}
+
+fn wait_for_module(module_name: &str, timeout_seconds: INT) -> ScriptResult<()> {
+    let wanted = module_name.to_ascii_lowercase();
+    wait_until(&format!("module {module_name}"), timeout_seconds, || {
+        module::entered_module().is_some_and(|entered| entered.eq_ignore_ascii_case(&wanted))
+    })
+}
+
+fn wait_for_load(timeout_seconds: INT) -> ScriptResult<()> {
+    wait_until("the load to finish", timeout_seconds, || {
+        !loading::is_loading() && module::entered_module().is_some()
+    })
+}
+
+fn sleep(milliseconds: INT) -> ScriptResult<()> {
+    let until = Instant::now() + Duration::from_millis(milliseconds.max(0) as u64);
+    wait_until("sleep", INT::MAX, || Instant::now() >= until)
+}
+
+fn new_engine() -> Engine {
+    let mut engine = Engine::new();
+
+    engine
+        .register_fn("command", command)
+        .register_fn("call", call)
+        .register_fn("warp", warp)
+        .register_fn("give", |template: &str| give(template, 1))
+        .register_fn("give", give)
+        .register_fn("spawn", spawn)
+        .register_fn("spawn", spawn_at)
+        .register_fn("play_movie", play_movie)
+        .register_fn("set_global", set_global_boolean)
+        .register_fn("set_global", set_global_number)
+        .register_fn("global_boolean", global_boolean)
+        .register_fn("global_number", global_number)
+        .register_fn("module", entered_module)
+        .register_fn("wait_for_module", wait_for_module)
+        .register_fn("wait_for_load", wait_for_load)
+        .register_fn("sleep", sleep);
+
+    engine.on_print(|text| {
+        info!("Script: {text}");
+        println!("{text}");
+    });
+    // Stops scripts busy in a loop of their own, not only the ones waiting on us
+    engine.on_progress(|_| should_stop().then_some(Dynamic::UNIT));
+
+    engine
+}
+
+/// Path of the script `name`, the extension can be left out.
+fn script_path(name: &str) -> PathBuf {
+    let path = Path::new(SCRIPT_DIRECTORY).join(name);
+    if path.extension().is_some() {
+        path
+    } else {
+        path.with_extension(SCRIPT_EXTENSION)
+    }
+}
+
+/// Names of the scripts in the script directory, sorted.
+pub fn scripts() -> io::Result<Vec<String>> {
+    let mut names = Vec::new();
+    for entry in fs::read_dir(SCRIPT_DIRECTORY)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == SCRIPT_EXTENSION) {
+            if let Some(stem) = path.file_stem() {
+                names.push(stem.to_string_lossy().into_owned());
+            }
+        }
+    }
+    names.sort_unstable();
+    Ok(names)
+}
+
+/// Name of the running script, if any.
+pub fn running() -> Option<String> {
+    RUNNING.lock().unwrap().clone()
+}
+
+/// Starts running the script `name` in the background. Fails if another script is running.
+pub fn run(name: &str) -> Result<(), Box<dyn Error>> {
+    let path = script_path(name);
+    if !path.is_file() {
+        return Err(format!("No script at {}", path.display()).into());
+    }
+
+    {
+        let mut running = RUNNING.lock().unwrap();
+        if let Some(running) = running.as_ref() {
+            return Err(format!("Script {running} is still running, stop it first").into());
+        }
+        *running = Some(name.to_string());
+    }
+    STOP.store(false, Ordering::Relaxed);
+
+    let script = name.to_string();
+    let spawned = services::spawn("script", move || {
+        info!("Running script {}", path.display());
+        let started = Instant::now();
+        match new_engine().run_file(path) {
+            Ok(()) => {
+                info!("Script {script} finished in {:?}", started.elapsed());
+                println!("Script {script} finished");
+            }
+            Err(e) => {
+                error!("Script {script} failed: {e}");
+                println!("Script {script} failed: {e}");
+            }
+        }
+        *RUNNING.lock().unwrap() = None;
+    });
+
+    if let Err(e) = spawned {
+        *RUNNING.lock().unwrap() = None;
+        return Err(e.into());
+    }
+    Ok(())
+}
+
+/// Asks the running script to stop. Returns false if nothing was running.
+pub fn stop() -> bool {
+    if running().is_none() {
+        return false;
+    }
+    STOP.store(true, Ordering::Relaxed);
+    true
+}
@@ -0,0 +1,149 @@
+/// Scripted smoke test, a repeatable sanity check after engine-layer changes.
+///
+/// With `smoke_test.enabled` set the mod warps to `smoke_test.module` as soon as the game lets it,
+/// waits for the load and `smoke_test.frames` more frames, takes a screenshot, writes a report and
+/// exits the game. The exit code is 0 when every step got done, 1 otherwise, so the game can be
+/// run from a script. The report has the steps taken and the `status` report, look for it and the
+/// screenshot next to each other when something's off.
+use std::{
+    fs,
+    path::PathBuf,
+    sync::atomic::{AtomicU64, Ordering},
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use log::{error, info};
+
+use crate::config::{config, SmokeTestConfig};
+use crate::engine::{frame, loading, module};
+use crate::screenshot;
+use crate::system::{services, status};
+
+/// How often the steps check whether the game got there
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+const MAIN_THREAD_TIMEOUT: Duration = Duration::from_secs(1);
+/// How long the game gets to reach the main menu, load the module and save the screenshot
+const STEP_TIMEOUT: Duration = Duration::from_secs(120);
+
+static FRAMES: AtomicU64 = AtomicU64::new(0);
+
+fn count_frame() {
+    FRAMES.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Polls `done` until it returns something, fails after `STEP_TIMEOUT`.
+fn wait_for<T>(step: &str, mut done: impl FnMut() -> Option<T>) -> Result<T, String> {
+    let started = Instant::now();
+    while started.elapsed() < STEP_TIMEOUT {
+        if services::stopping() {
+            return Err(format!("Stopped while waiting to {step}"));
+        }
+        if let Some(value) = done() {
+            return Ok(value);
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+    Err(format!("Timed out waiting to {step}"))
+}
+
+/// Runs the steps, adding a line to `log` for each one done.
+fn run_steps(settings: &SmokeTestConfig, log: &mut Vec<String>) -> Result<(), String> {
+    let target = settings.module.clone();
+    module::validate_module_name(&target).map_err(|e| e.to_string())?;
+
+    // The server application shows up once the game is past its startup, warping fails before
+    wait_for("warp", || {
+        let target = target.clone();
+        match frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, move || module::warp(&target)) {
+            Ok(Ok(())) => Some(Ok(())),
+            Ok(Err(e)) if e.kind() == std::io::ErrorKind::NotConnected => None,
+            Ok(Err(e)) => Some(Err(e.to_string())),
+            // Still loading the game, the main thread is busy
+            Err(_) => None,
+        }
+    })??;
+    log.push(format!("Warped to {target}"));
+
+    wait_for("load the module", || {
+        let entered = module::entered_module().is_some_and(|module| module == target);
+        (entered && !loading::is_loading()).then_some(())
+    })?;
+    log.push(format!("Loaded {target}"));
+
+    let start = FRAMES.load(Ordering::Relaxed);
+    wait_for("draw the frames", || {
+        (FRAMES.load(Ordering::Relaxed) - start >= settings.frames).then_some(())
+    })?;
+    log.push(format!("Drew {} frames", settings.frames));
+
+    let previous = screenshot::last_screenshot();
+    screenshot::request_screenshot();
+    let path = wait_for("save the screenshot", || {
+        screenshot::last_screenshot().filter(|path| Some(path) != previous.as_ref())
+    })?;
+    log.push(format!("Saved screenshot to {}", path.display()));
+
+    Ok(())
+}
+
+fn write_report(log: &[String], result: &Result<(), String>) -> std::io::Result<PathBuf> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let path = PathBuf::from(format!("swkotor-mod-smoke-{timestamp}.txt"));
+
+    let mut lines = vec![match result {
+        Ok(()) => "Smoke test passed".to_string(),
+        Err(e) => format!("Smoke test failed: {e}"),
+    }];
+    lines.extend(log.iter().map(|line| format!("  {line}")));
+    lines.push(String::new());
+    lines.extend(status::report());
+    fs::write(&path, lines.join("\n") + "\n")?;
+    Ok(path)
+}
+
+fn run(settings: SmokeTestConfig) {
+    info!(
+        "Smoke test: loading {} and exiting after {} frames",
+        settings.module, settings.frames
+    );
+
+    let mut log = Vec::new();
+    let result = run_steps(&settings, &mut log);
+    if services::stopping() {
+        // Hot reload, not a failed test, leave the game running
+        return;
+    }
+
+    match write_report(&log, &result) {
+        Ok(path) => info!("Wrote smoke test report to {}", path.display()),
+        Err(e) => error!("Could not write the smoke test report: {e}"),
+    }
+    let code = match result {
+        Ok(()) => {
+            info!("Smoke test passed, exiting");
+            0
+        }
+        Err(e) => {
+            error!("Smoke test failed, exiting: {e}");
+            1
+        }
+    };
+    frame::run_on_main_thread(move || std::process::exit(code));
+}
+
+/// Starts the smoke test when it's enabled in the config.
+pub fn init() {
+    let settings = config().smoke_test.clone();
+    if !settings.enabled {
+        return;
+    }
+
+    frame::on_every_frame(count_frame);
+    if let Err(e) = services::spawn("smoke-test", move || run(settings)) {
+        error!("Could not start the smoke test: {e}");
+    }
+}
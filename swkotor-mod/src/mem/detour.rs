@@ -0,0 +1,129 @@
+use std::{ffi::c_void, io};
+
+use log::trace;
+use windows::Win32::System::Memory::{
+    VirtualAlloc, VirtualFree, MEM_COMMIT, MEM_RELEASE, MEM_RESERVE, PAGE_EXECUTE_READWRITE,
+};
+
+use super::with_virtual_protect;
+
+/// Size of a `JMP <rel32>` instruction
+const JMP_SIZE: usize = 5;
+
+/// Redirects an engine function to our hook while keeping the original callable.
+///
+/// The first `prologue_length` bytes of the target are copied into a trampoline followed by a jump
+/// back to the rest of the original function. Target start is then overwritten with a jump to the
+/// hook. Calling the trampoline is the same as calling the original function.
+///
+/// `prologue_length` has to cover whole instructions and at least 5 bytes. Prologue must not
+/// contain relative jumps or calls, they would point to garbage once copied. Check the
+/// disassembly.
+pub struct Detour {
+    /// This is for debugging means only
+    name: String,
+    target_address: usize,
+    /// Original bytes at the start of the target function
+    original_bytes: Vec<u8>,
+    /// Executable memory holding the original prologue and a jump back
+    trampoline: usize,
+}
+
+fn jmp_instruction(from: usize, to: usize) -> [u8; JMP_SIZE] {
+    let relative_offset = to.wrapping_sub(from + JMP_SIZE) as u32;
+    let mut jmp: [u8; JMP_SIZE] = [0xE9, 0, 0, 0, 0];
+    jmp[1..].copy_from_slice(&relative_offset.to_le_bytes());
+    jmp
+}
+
+impl Detour {
+    pub unsafe fn install(
+        name: String,
+        target_address: usize,
+        prologue_length: usize,
+        hook_address: usize,
+    ) -> io::Result<Detour> {
+        assert!(
+            prologue_length >= JMP_SIZE,
+            "Prologue has to fit a jump instruction"
+        );
+
+        let original_bytes =
+            std::slice::from_raw_parts(target_address as *const u8, prologue_length).to_vec();
+
+        let trampoline = VirtualAlloc(
+            None,
+            prologue_length + JMP_SIZE,
+            MEM_COMMIT | MEM_RESERVE,
+            PAGE_EXECUTE_READWRITE,
+        ) as usize;
+
+        if trampoline == 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let trampoline_ptr = trampoline as *mut u8;
+        trampoline_ptr.copy_from(original_bytes.as_ptr(), prologue_length);
+        trampoline_ptr.add(prologue_length).copy_from(
+            jmp_instruction(
+                trampoline + prologue_length,
+                target_address + prologue_length,
+            )
+            .as_ptr(),
+            JMP_SIZE,
+        );
+
+        // Pad the rest of the stolen prologue with NOPs, nothing should ever jump there
+        let mut patch = vec![0x90; prologue_length];
+        patch[..JMP_SIZE].copy_from_slice(&jmp_instruction(target_address, hook_address));
+
+        with_virtual_protect(
+            target_address,
+            PAGE_EXECUTE_READWRITE,
+            prologue_length,
+            || {
+                (target_address as *mut u8).copy_from(patch.as_ptr(), patch.len());
+                Ok(())
+            },
+        )?;
+
+        trace!("Installed detour {name} at {target_address:#x}, trampoline at {trampoline:#x}");
+
+        Ok(Detour {
+            name,
+            target_address,
+            original_bytes,
+            trampoline,
+        })
+    }
+
+    /// Name of the hooked function, for logs and diagnostics.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Address to call for the original function.
+    pub fn trampoline(&self) -> usize {
+        self.trampoline
+    }
+
+    /// Restores the original function and frees the trampoline, the detour must not be used
+    /// afterwards. Must not be called while the hook or trampoline might be running, i.e. call it
+    /// from the main thread.
+    pub unsafe fn uninstall(&self) -> io::Result<()> {
+        with_virtual_protect(
+            self.target_address,
+            PAGE_EXECUTE_READWRITE,
+            self.original_bytes.len(),
+            || {
+                (self.target_address as *mut u8)
+                    .copy_from(self.original_bytes.as_ptr(), self.original_bytes.len());
+                Ok(())
+            },
+        )?;
+
+        VirtualFree(self.trampoline as *mut c_void, 0, MEM_RELEASE)?;
+        trace!("Uninstalled detour {}", self.name);
+        Ok(())
+    }
+}
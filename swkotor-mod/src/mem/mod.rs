@@ -1,10 +1,16 @@
+mod detour;
+
 use std::{ffi::c_void, io};
 
 use log::trace;
-use windows::Win32::System::Memory::{
-    VirtualProtect, PAGE_EXECUTE_READWRITE, PAGE_PROTECTION_FLAGS, PAGE_READWRITE,
+use windows::Win32::System::{
+    Diagnostics::Debug::ReadProcessMemory,
+    Memory::{VirtualProtect, PAGE_EXECUTE_READWRITE, PAGE_PROTECTION_FLAGS, PAGE_READWRITE},
+    Threading::GetCurrentProcess,
 };
 
+pub use detour::Detour;
+
 /// Ergonomic RAII wrapper for changing [page protection flags](https://learn.microsoft.com/en-us/windows/win32/memory/memory-protection-constants).
 ///
 /// Use helper function `with_virtual_protect` to wrap a function that needs to change page
@@ -92,6 +98,64 @@ where
     res
 }
 
+/// Copies `buffer.len()` bytes at `address` of our own process into `buffer`.
+///
+/// Unlike dereferencing, reading unmapped or protected memory fails instead of crashing the game,
+/// for addresses that come from the user rather than the engine.
+pub fn read_memory(address: usize, buffer: &mut [u8]) -> io::Result<()> {
+    let mut read = 0;
+    unsafe {
+        ReadProcessMemory(
+            GetCurrentProcess(),
+            address as *const c_void,
+            buffer.as_mut_ptr().cast(),
+            buffer.len(),
+            Some(&mut read),
+        )
+    }
+    .map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!("Cannot read {} bytes at {address:#010x}", buffer.len()),
+        )
+    })?;
+    if read != buffer.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            format!(
+                "Read only {read} of {} bytes at {address:#010x}",
+                buffer.len()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Memory engine accessors read from: the running game, or a recording of it in offline tests,
+/// see `engine::fixtures`. The game is 32-bit, pointers are read as `u32`.
+pub trait Memory {
+    fn read(&self, address: usize, buffer: &mut [u8]) -> io::Result<()>;
+
+    fn read_u32(&self, address: usize) -> io::Result<u32> {
+        let mut bytes = [0u8; 4];
+        self.read(address, &mut bytes)?;
+        Ok(u32::from_le_bytes(bytes))
+    }
+
+    fn read_f32(&self, address: usize) -> io::Result<f32> {
+        self.read_u32(address).map(f32::from_bits)
+    }
+}
+
+/// Our own process, read through `read_memory`.
+pub struct Process;
+
+impl Memory for Process {
+    fn read(&self, address: usize, buffer: &mut [u8]) -> io::Result<()> {
+        read_memory(address, buffer)
+    }
+}
+
 pub struct Patch<const COUNT: usize> {
     /// This is for debugging means only
     name: String,
@@ -140,6 +204,36 @@ impl Patch<5> {
     }
 }
 
+impl Patch<8> {
+    /// Patches the start of a function to return `return_value` right away, skipping the original
+    /// body. `stack_size` is the amount of argument bytes the callee cleans up, zero for cdecl.
+    ///
+    /// The bytes currently in memory are taken as the expected original bytes, this is meant for
+    /// toggles applied at runtime after the executable has been unpacked.
+    pub unsafe fn early_return(
+        name: String,
+        target_address: usize,
+        return_value: u32,
+        stack_size: u16,
+    ) -> Patch<8> {
+        let mut original_bytes: [u8; 8] = [0; 8];
+        original_bytes.copy_from_slice(std::slice::from_raw_parts(target_address as *const u8, 8));
+
+        // `MOV EAX, <imm32>` followed by `RET <imm16>`
+        let mut patch: [u8; 8] = [0xB8, 0, 0, 0, 0, 0xC2, 0, 0];
+        patch[1..5].copy_from_slice(&return_value.to_le_bytes());
+        patch[6..8].copy_from_slice(&stack_size.to_le_bytes());
+
+        Patch {
+            name,
+            target_address,
+            original_bytes,
+            bytes: patch,
+            required_memory_protection_level: PAGE_EXECUTE_READWRITE,
+        }
+    }
+}
+
 impl<const COUNT: usize> Patch<COUNT> {
     pub fn bytes(
         name: String,
@@ -157,6 +251,10 @@ impl<const COUNT: usize> Patch<COUNT> {
         }
     }
 
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
     pub unsafe fn can_apply(&self) -> bool {
         let current = std::slice::from_raw_parts_mut(
             self.target_address as *mut u8,
@@ -186,14 +284,14 @@ impl<const COUNT: usize> Patch<COUNT> {
                 );
 
                 let mut sized_old_memory: [u8; COUNT] = [0; COUNT];
-                // sized_old_memory.copy_from_slice(old_memory);
+                sized_old_memory.copy_from_slice(old_memory);
 
                 trace!("copied le slice");
 
                 trace!(
                     "applying patch {} to {:x?}",
                     self.name,
-                    std::slice::from_raw_parts_mut(0x006e09a8 as *mut u8, self.bytes.len())
+                    std::slice::from_raw_parts(self.target_address as *const u8, self.bytes.len())
                 );
 
                 (self.target_address as *mut u8).copy_from(self.bytes.as_ptr(), self.bytes.len());
@@ -201,7 +299,7 @@ impl<const COUNT: usize> Patch<COUNT> {
                 trace!(
                     "applied patch {} with {:x?}",
                     self.name,
-                    std::slice::from_raw_parts_mut(0x006e09a8 as *mut u8, self.bytes.len())
+                    std::slice::from_raw_parts(self.target_address as *const u8, self.bytes.len())
                 );
 
                 Ok(AppliedPatch {
@@ -225,8 +323,7 @@ pub struct AppliedPatch<const COUNT: usize> {
 }
 
 impl<const COUNT: usize> AppliedPatch<COUNT> {
-    #[allow(dead_code)]
-    pub unsafe fn revert(self) -> io::Result<()> {
+    pub unsafe fn revert(&self) -> io::Result<()> {
         with_virtual_protect(
             self.target_address,
             self.required_memory_protection_level,
@@ -0,0 +1,43 @@
+/// Performance alerts raised in the last few seconds, see `telemetry::alerts`.
+use std::time::Duration;
+
+use crate::telemetry::alerts;
+
+use super::painter::{FONT_HEIGHT, FONT_WIDTH};
+use super::{theme, Painter};
+
+const PADDING: f32 = 6.0;
+const SHOWN_FOR: Duration = Duration::from_secs(5);
+/// Bottom center, above the startup banner
+const BOTTOM: i32 = -100;
+
+fn draw(painter: &mut Painter) {
+    let lines: Vec<String> = alerts::recent(SHOWN_FOR)
+        .into_iter()
+        .map(|alert| format!("! {}", alert.message))
+        .collect();
+    if lines.is_empty() {
+        return;
+    }
+
+    let longest = lines.iter().map(String::len).max().unwrap_or_default();
+    let width = longest as f32 * FONT_WIDTH + 2.0 * PADDING;
+    let height = lines.len() as f32 * FONT_HEIGHT + 2.0 * PADDING;
+    let x = (painter.width() - width) / 2.0;
+    let (_, y) = painter.anchor(0, BOTTOM, width, height);
+
+    let palette = theme::palette();
+    painter.fill_rect(x, y, width, height, palette.background);
+    for (i, line) in lines.iter().enumerate() {
+        painter.text(
+            x + PADDING,
+            y + PADDING + i as f32 * FONT_HEIGHT,
+            line,
+            palette.negative,
+        );
+    }
+}
+
+pub fn init() {
+    super::add_widget(draw);
+}
@@ -0,0 +1,62 @@
+/// Banner with the mod version, game build and enabled features, shown for a few seconds after
+/// the game first renders. Tells at a glance that the mod loaded and what it's going to do.
+use std::{
+    sync::OnceLock,
+    time::{Duration, Instant},
+};
+
+use crate::system::about;
+
+use super::painter::{FONT_HEIGHT, FONT_WIDTH};
+use super::{theme, Color, Painter};
+
+const PADDING: f32 = 6.0;
+const SHOWN_FOR: Duration = Duration::from_secs(6);
+/// Last part of `SHOWN_FOR` during which the banner fades out
+const FADE_OUT: Duration = Duration::from_secs(2);
+/// Bottom center, the other widgets keep to the corners and the top
+const BOTTOM: i32 = -40;
+
+/// Lines and when the banner was first drawn
+static BANNER: OnceLock<(Vec<String>, Instant)> = OnceLock::new();
+
+fn faded(color: Color, opacity: f32) -> Color {
+    color.with_alpha(color.a * opacity)
+}
+
+fn draw(painter: &mut Painter) {
+    // Timed from the first frame, startup and the DRM can take a while before anything is drawn
+    let (lines, shown_at) = BANNER.get_or_init(|| (about::summary(), Instant::now()));
+    let elapsed = shown_at.elapsed();
+    if elapsed >= SHOWN_FOR {
+        return;
+    }
+    let remaining = SHOWN_FOR - elapsed;
+    let opacity = (remaining.as_secs_f32() / FADE_OUT.as_secs_f32()).min(1.0);
+
+    let longest = lines.iter().map(String::len).max().unwrap_or_default();
+    let width = longest as f32 * FONT_WIDTH + 2.0 * PADDING;
+    let height = lines.len() as f32 * FONT_HEIGHT + 2.0 * PADDING;
+    let x = (painter.width() - width) / 2.0;
+    let (_, y) = painter.anchor(0, BOTTOM, width, height);
+
+    painter.fill_rect(
+        x,
+        y,
+        width,
+        height,
+        faded(theme::palette().background, opacity),
+    );
+    for (i, line) in lines.iter().enumerate() {
+        painter.text(
+            x + PADDING,
+            y + PADDING + i as f32 * FONT_HEIGHT,
+            line,
+            faded(theme::palette().text, opacity),
+        );
+    }
+}
+
+pub fn init() {
+    super::add_widget(draw);
+}
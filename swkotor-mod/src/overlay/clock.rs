@@ -0,0 +1,37 @@
+/// Shows that the world is paused or runs at another speed, a frozen or slowed down game looks
+/// much like a broken one.
+use crate::engine::clock;
+
+use super::painter::{FONT_HEIGHT, FONT_WIDTH};
+use super::{theme, Painter};
+
+const PADDING: f32 = 6.0;
+/// Top center of the screen, away from the other widgets
+const TOP: f32 = 10.0;
+
+fn draw(painter: &mut Painter) {
+    let scale = clock::time_scale();
+    let text = if clock::paused() {
+        "PAUSED".to_string()
+    } else if scale != 1.0 {
+        format!("TIME x{scale}")
+    } else {
+        return;
+    };
+
+    let width = text.len() as f32 * FONT_WIDTH + 2.0 * PADDING;
+    let height = FONT_HEIGHT + 2.0 * PADDING;
+    let x = (painter.width() - width) / 2.0;
+
+    painter.fill_rect(x, TOP, width, height, theme::palette().background);
+    painter.text(
+        x + PADDING,
+        TOP + PADDING,
+        &text,
+        theme::palette().highlight,
+    );
+}
+
+pub fn init() {
+    super::add_widget(draw);
+}
@@ -0,0 +1,74 @@
+/// Draws the ghost run being played back as a translucent marker at its position.
+///
+/// The marker is only shown while the ghost is in the same module as the player. It's moved by
+/// replacing its scene primitives every frame.
+use std::{sync::Mutex, time::Instant};
+
+use log::info;
+
+use crate::engine::{frame, module};
+use crate::speedrun::ghost::GHOST;
+
+use super::scene::{self, PrimitiveId, Shape};
+use super::theme;
+
+const GHOST_ALPHA: f32 = 0.5;
+/// Marker size in pixels
+const MARKER_SIZE: f32 = 14.0;
+/// Label is lifted above the marker so they don't overlap
+const LABEL_HEIGHT: f32 = 0.5;
+
+static SHOWN: Mutex<Vec<PrimitiveId>> = Mutex::new(Vec::new());
+
+fn update() {
+    let mut shown = SHOWN.lock().unwrap();
+    for id in shown.drain(..) {
+        scene::remove(id);
+    }
+
+    let mut ghost = GHOST.lock().unwrap();
+    let Some(playing) = ghost.as_mut() else {
+        return;
+    };
+    let Some(started) = playing.started else {
+        return;
+    };
+
+    let time = Instant::now() - started;
+    if time > playing.run.duration() {
+        info!("Ghost run finished");
+        playing.started = None;
+        return;
+    }
+    let Some(sample) = playing.run.sample_at(time) else {
+        return;
+    };
+
+    // Main menu and loading screens have no module, the ghost is hidden then
+    let module = module::entered_module();
+    if !module.is_some_and(|module| module.eq_ignore_ascii_case(&sample.module)) {
+        return;
+    }
+
+    let [x, y, z] = sample.position;
+    shown.push(scene::add(
+        Shape::Billboard {
+            position: sample.position,
+            size: MARKER_SIZE,
+        },
+        theme::palette().info.with_alpha(GHOST_ALPHA),
+        None,
+    ));
+    shown.push(scene::add(
+        Shape::Text {
+            position: [x, y, z + LABEL_HEIGHT],
+            text: "ghost".to_string(),
+        },
+        theme::palette().info.with_alpha(GHOST_ALPHA),
+        None,
+    ));
+}
+
+pub fn init() {
+    frame::on_every_frame(update);
+}
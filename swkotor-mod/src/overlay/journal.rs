@@ -0,0 +1,215 @@
+/// Journal panel: the quests in the journal with their states and entry text, and the script
+/// globals behind them, read live from the engine.
+///
+/// Quest progression bugs usually come down to a plot global that never got set. The panel lists
+/// the globals matching a filter, e.g. the planet prefix of a quest, or without a filter the ones
+/// that changed since the panel was shown, so walking through a quest shows what it touches.
+use std::{
+    collections::HashMap,
+    io,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use log::info;
+
+use crate::engine::{
+    globals,
+    journal::{self, GlobalKind, Quest},
+};
+use crate::strref;
+
+use super::painter::{FONT_HEIGHT, FONT_WIDTH};
+use super::{theme, Painter};
+
+const PADDING: f32 = 6.0;
+/// Right edge, below the timer and the RNG widget
+const POSITION: (i32, i32) = (-10, 200);
+/// Entry text is cut to keep the panel from covering the screen
+const TEXT_LENGTH: usize = 60;
+/// Globals listed before cutting the list short
+const SHOWN_GLOBALS: usize = 16;
+/// Asks the engine for every quest and global, not something to do every frame
+const REFRESH_INTERVAL: Duration = Duration::from_millis(500);
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// What the journal and globals are read against, loaded when the panel is shown
+struct Catalog {
+    quests: Vec<Quest>,
+    globals: Vec<(String, GlobalKind)>,
+    /// Global values when the panel was shown
+    baseline: HashMap<String, i32>,
+}
+
+struct Panel {
+    refreshed: Instant,
+    lines: Vec<String>,
+}
+
+static CATALOG: Mutex<Option<Catalog>> = Mutex::new(None);
+static PANEL: Mutex<Option<Panel>> = Mutex::new(None);
+/// Lowercase substring globals are shown by, changed ones are shown without
+static FILTER: Mutex<Option<String>> = Mutex::new(None);
+
+fn shorten(text: &str) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    match text.char_indices().nth(TEXT_LENGTH) {
+        Some((end, _)) => format!("{}...", &text[..end]),
+        None => text,
+    }
+}
+
+fn global_values(globals: &[(String, GlobalKind)]) -> io::Result<HashMap<String, i32>> {
+    globals
+        .iter()
+        .map(|(name, kind)| Ok((name.clone(), journal::global_value(name, *kind)?)))
+        .collect()
+}
+
+/// Lines shown on the overlay and by the console command. Must be called on the main thread.
+pub fn describe() -> io::Result<Vec<String>> {
+    let catalog = CATALOG.lock().unwrap();
+    let catalog = catalog
+        .as_ref()
+        .ok_or_else(|| io::Error::other("Journal panel is not shown"))?;
+
+    let mut active = Vec::new();
+    for quest in &catalog.quests {
+        let state = globals::journal_state(&quest.tag)?;
+        if state != 0 {
+            active.push((quest, state));
+        }
+    }
+
+    let mut lines = vec![format!(
+        "{} of {} quests in the journal",
+        active.len(),
+        catalog.quests.len()
+    )];
+    for (quest, state) in active {
+        let name = strref::lookup(quest.name).unwrap_or_else(|| quest.tag.clone());
+        let (done, text) = match quest.entry(state) {
+            Some(entry) => (entry.end, strref::lookup(entry.text).unwrap_or_default()),
+            None => (false, "no such entry".to_string()),
+        };
+        lines.push(format!(
+            "  {name} [{}] {state}{}",
+            quest.tag,
+            if done { " done" } else { "" }
+        ));
+        if !text.is_empty() {
+            lines.push(format!("    {}", shorten(&text)));
+        }
+    }
+
+    let values = global_values(&catalog.globals)?;
+    let filter = FILTER.lock().unwrap().clone();
+    let mut shown: Vec<(&String, i32)> = catalog
+        .globals
+        .iter()
+        .map(|(name, _)| (name, values[name]))
+        .filter(|(name, value)| match &filter {
+            Some(filter) => name.to_ascii_lowercase().contains(filter),
+            None => catalog.baseline.get(*name) != Some(value),
+        })
+        .collect();
+    shown.sort_unstable();
+
+    lines.push(match &filter {
+        Some(filter) => format!("{} globals matching '{filter}'", shown.len()),
+        None => format!("{} globals changed since shown", shown.len()),
+    });
+    lines.extend(
+        shown
+            .iter()
+            .take(SHOWN_GLOBALS)
+            .map(|(name, value)| format!("  {name} = {value}")),
+    );
+    if shown.len() > SHOWN_GLOBALS {
+        lines.push(format!("  and {} more", shown.len() - SHOWN_GLOBALS));
+    }
+    Ok(lines)
+}
+
+fn draw(painter: &mut Painter) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let mut panel = PANEL.lock().unwrap();
+    let panel = match panel.as_mut() {
+        Some(panel) if panel.refreshed.elapsed() < REFRESH_INTERVAL => panel,
+        _ => panel.insert(Panel {
+            refreshed: Instant::now(),
+            lines: describe().unwrap_or_else(|e| vec![format!("No journal to show: {e}")]),
+        }),
+    };
+
+    let longest = panel
+        .lines
+        .iter()
+        .map(String::len)
+        .max()
+        .unwrap_or_default();
+    let width = longest as f32 * FONT_WIDTH + 2.0 * PADDING;
+    let height = panel.lines.len() as f32 * FONT_HEIGHT + 2.0 * PADDING;
+    let (x, y) = painter.anchor(POSITION.0, POSITION.1, width, height);
+
+    painter.fill_rect(x, y, width, height, theme::palette().background);
+    for (i, line) in panel.lines.iter().enumerate() {
+        painter.text(
+            x + PADDING,
+            y + PADDING + i as f32 * FONT_HEIGHT,
+            line,
+            theme::palette().text,
+        );
+    }
+}
+
+pub fn journal_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Shows or hides the journal panel. Must be called on the main thread.
+pub fn set_journal(enabled: bool) -> io::Result<()> {
+    if enabled {
+        // Read up front, failing here instead of drawing an error panel
+        let quests = journal::quests()?;
+        let globals = journal::global_catalog()?;
+        let baseline = global_values(&globals)?;
+        info!(
+            "Read {} quests and {} globals for the journal panel",
+            quests.len(),
+            globals.len()
+        );
+        *CATALOG.lock().unwrap() = Some(Catalog {
+            quests,
+            globals,
+            baseline,
+        });
+    } else {
+        *CATALOG.lock().unwrap() = None;
+    }
+
+    *PANEL.lock().unwrap() = None;
+    ENABLED.store(enabled, Ordering::Relaxed);
+    info!(
+        "Journal overlay {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(())
+}
+
+/// Shows the globals whose names contain `filter`, or the changed ones when `None`.
+pub fn set_filter(filter: Option<&str>) {
+    *FILTER.lock().unwrap() = filter.map(str::to_ascii_lowercase);
+    *PANEL.lock().unwrap() = None;
+}
+
+pub fn init() {
+    super::add_widget(draw);
+}
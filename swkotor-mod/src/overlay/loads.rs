@@ -0,0 +1,90 @@
+/// Load times of the session by module, the mean and worst load of each.
+use std::{
+    io,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+use log::info;
+
+use crate::engine::loading::{self, LoadStats};
+
+use super::painter::{FONT_HEIGHT, FONT_WIDTH};
+use super::{theme, Painter};
+
+const PADDING: f32 = 6.0;
+/// Left edge, above the transform widget with its default position
+const POSITION: (i32, i32) = (10, -120);
+/// Modules listed, the slowest ones
+const SHOWN_MODULES: usize = 10;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Seconds with two decimals, loads are compared to a hundredth.
+pub fn format_seconds(duration: Duration) -> String {
+    format!("{:.2}s", duration.as_secs_f32())
+}
+
+fn describe_stats(stats: &LoadStats) -> String {
+    format!(
+        "{} loads, mean {}, worst {}",
+        stats.count,
+        format_seconds(stats.mean()),
+        format_seconds(stats.longest)
+    )
+}
+
+/// Lines shown on the overlay and by the console command.
+pub fn describe() -> Vec<String> {
+    let mut modules = loading::stats_by_module();
+    modules.sort_by_key(|(_, stats)| std::cmp::Reverse(stats.longest));
+
+    let mut lines = vec![describe_stats(&loading::stats())];
+    lines.extend(modules.iter().take(SHOWN_MODULES).map(|(module, stats)| {
+        let module = if module.is_empty() { "-" } else { module };
+        format!("  {module:<16} {}", describe_stats(stats))
+    }));
+    if modules.len() > SHOWN_MODULES {
+        lines.push(format!("  and {} more", modules.len() - SHOWN_MODULES));
+    }
+    lines
+}
+
+fn draw(painter: &mut Painter) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let lines = describe();
+    let longest = lines.iter().map(String::len).max().unwrap_or_default();
+    let width = longest as f32 * FONT_WIDTH + 2.0 * PADDING;
+    let height = lines.len() as f32 * FONT_HEIGHT + 2.0 * PADDING;
+    let (x, y) = painter.anchor(POSITION.0, POSITION.1, width, height);
+
+    painter.fill_rect(x, y, width, height, theme::palette().background);
+    for (i, line) in lines.iter().enumerate() {
+        painter.text(
+            x + PADDING,
+            y + PADDING + i as f32 * FONT_HEIGHT,
+            line,
+            theme::palette().text,
+        );
+    }
+}
+
+pub fn loads_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub fn set_loads(enabled: bool) -> io::Result<()> {
+    ENABLED.store(enabled, Ordering::Relaxed);
+    info!(
+        "Loads overlay {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(())
+}
+
+pub fn init() {
+    super::add_widget(draw);
+}
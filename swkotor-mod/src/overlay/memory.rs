@@ -0,0 +1,91 @@
+/// Memory usage widget, shows what `system::memory` samples.
+use std::{
+    io,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use log::info;
+
+use crate::config::config;
+use crate::system::memory::{self, MemoryStats};
+
+use super::painter::{FONT_HEIGHT, FONT_WIDTH};
+use super::{theme, Painter};
+
+const PADDING: f32 = 6.0;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Formats a byte count in megabytes.
+fn format_megabytes(bytes: isize) -> String {
+    format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+/// Lines shown on the overlay and by the console command.
+pub fn describe(stats: &MemoryStats) -> Vec<String> {
+    let sample = &stats.latest;
+    let minutes = stats.started.elapsed().as_secs() / 60;
+    vec![
+        format!(
+            "Working set {} (peak {})",
+            format_megabytes(sample.working_set as isize),
+            format_megabytes(sample.peak_working_set as isize)
+        ),
+        format!(
+            "Commit {} ({:+.1} MB in {minutes} min)",
+            format_megabytes(sample.commit as isize),
+            stats.commit_growth() as f64 / (1024.0 * 1024.0)
+        ),
+        format!(
+            "Heap {} used, {} committed in {} heaps",
+            format_megabytes(sample.heap_allocated as isize),
+            format_megabytes(sample.heap_committed as isize),
+            sample.heaps
+        ),
+    ]
+}
+
+fn draw(painter: &mut Painter) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    let Some(stats) = memory::stats() else {
+        return;
+    };
+
+    let position = config().memory.position;
+    let lines = describe(&stats);
+    let longest = lines.iter().map(String::len).max().unwrap_or_default();
+
+    let width = longest as f32 * FONT_WIDTH + 2.0 * PADDING;
+    let height = lines.len() as f32 * FONT_HEIGHT + 2.0 * PADDING;
+    let (x, y) = painter.anchor(position.x, position.y, width, height);
+
+    painter.fill_rect(x, y, width, height, theme::palette().background);
+    for (i, line) in lines.iter().enumerate() {
+        painter.text(
+            x + PADDING,
+            y + PADDING + i as f32 * FONT_HEIGHT,
+            line,
+            theme::palette().text,
+        );
+    }
+}
+
+pub fn memory_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub fn set_memory(enabled: bool) -> io::Result<()> {
+    ENABLED.store(enabled, Ordering::Relaxed);
+    info!(
+        "Memory overlay {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(())
+}
+
+pub fn init() {
+    ENABLED.store(config().memory.enabled, Ordering::Relaxed);
+    super::add_widget(draw);
+}
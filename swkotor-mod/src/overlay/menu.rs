@@ -0,0 +1,202 @@
+/// Quick menu for flipping toggles and changing settings without a keyboard, driven by a gamepad
+/// through `liveqa::gamepad`.
+///
+/// The toggles page lists every console toggle with its state. Toggling goes through the
+/// console's toggle dispatcher, so it does exactly what typing the command would. The settings
+/// page edits the config file, see `settings`.
+use std::sync::Mutex;
+
+use log::error;
+
+use crate::engine::input;
+use crate::liveqa::commands::{set_toggle, TOGGLES};
+use crate::settings::{self, SETTINGS};
+
+use super::painter::{FONT_HEIGHT, FONT_WIDTH};
+use super::{theme, Painter};
+
+const PADDING: f32 = 8.0;
+/// Below the clock widget at the top center
+const TOP: f32 = 40.0;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Page {
+    Toggles,
+    Settings,
+}
+
+impl Page {
+    fn title(self) -> &'static str {
+        match self {
+            Page::Toggles => "Toggles  [RB settings]",
+            Page::Settings => "[LB toggles]  Settings",
+        }
+    }
+
+    fn hint(self) -> &'static str {
+        match self {
+            Page::Toggles => "A toggle  B close",
+            Page::Settings => "A change  Left/Right adjust  B close",
+        }
+    }
+
+    fn len(self) -> usize {
+        match self {
+            Page::Toggles => TOGGLES.len(),
+            Page::Settings => SETTINGS.len(),
+        }
+    }
+}
+
+struct Menu {
+    open: bool,
+    page: Page,
+    /// Index into `TOGGLES` or `SETTINGS`, depending on the page
+    selected: usize,
+}
+
+static MENU: Mutex<Menu> = Mutex::new(Menu {
+    open: false,
+    page: Page::Toggles,
+    selected: 0,
+});
+
+pub fn is_open() -> bool {
+    MENU.lock().unwrap().open
+}
+
+pub fn toggle_open() {
+    let mut menu = MENU.lock().unwrap();
+    menu.open = !menu.open;
+}
+
+pub fn close() {
+    MENU.lock().unwrap().open = false;
+}
+
+/// Switches to the toggles page, or to the settings page when `settings` is set.
+pub fn show_page(settings: bool) {
+    let mut menu = MENU.lock().unwrap();
+    let page = if settings {
+        Page::Settings
+    } else {
+        Page::Toggles
+    };
+    if menu.page != page {
+        menu.page = page;
+        menu.selected = 0;
+    }
+}
+
+/// Moves the selection by `delta` entries, wrapping around at either end.
+pub fn move_selection(delta: isize) {
+    let mut menu = MENU.lock().unwrap();
+    let count = menu.page.len() as isize;
+    menu.selected = (menu.selected as isize + delta).rem_euclid(count) as usize;
+}
+
+/// Flips the selected toggle or flag, numbers are stepped up.
+pub fn activate() {
+    adjust(1);
+}
+
+/// Steps the selected setting by `steps`. Toggles flip whichever way.
+pub fn adjust(steps: i32) {
+    let (page, selected) = {
+        let menu = MENU.lock().unwrap();
+        (menu.page, menu.selected)
+    };
+
+    match page {
+        Page::Toggles => {
+            let name = TOGGLES[selected].name;
+            if let Err(e) = set_toggle(name, None) {
+                error!("Could not toggle {name} from the menu: {e}");
+            }
+        }
+        Page::Settings => {
+            let setting = &SETTINGS[selected];
+            if let Err(e) = settings::adjust(setting, steps) {
+                error!("Could not change {} from the menu: {e}", setting.name);
+            }
+        }
+    }
+}
+
+/// Name, value and whether the value counts as on, for every entry of the page.
+fn entries(page: Page) -> Vec<(&'static str, String, bool)> {
+    match page {
+        Page::Toggles => TOGGLES
+            .iter()
+            .map(|toggle| {
+                let enabled = (toggle.enabled)();
+                let value = if enabled { "on" } else { "off" };
+                (toggle.name, value.to_string(), enabled)
+            })
+            .collect(),
+        Page::Settings => SETTINGS
+            .iter()
+            .map(|setting| {
+                let value = settings::value(setting);
+                let on = value != "false";
+                (setting.name, value, on)
+            })
+            .collect(),
+    }
+}
+
+fn draw(painter: &mut Painter) {
+    let (page, selected) = {
+        let menu = MENU.lock().unwrap();
+        if !menu.open {
+            return;
+        }
+        (menu.page, menu.selected)
+    };
+
+    let entries = entries(page);
+    let name_width = entries
+        .iter()
+        .map(|(name, _, _)| name.len())
+        .max()
+        .unwrap_or_default();
+    let value_width = entries
+        .iter()
+        .map(|(_, value, _)| value.len())
+        .max()
+        .unwrap_or_default();
+    // Selection marker, name, a space and the value
+    let longest = (2 + name_width + 1 + value_width)
+        .max(page.title().len())
+        .max(page.hint().len());
+    // Title and hint with a blank line after the title
+    let line_count = entries.len() + 3;
+
+    let width = longest as f32 * FONT_WIDTH + 2.0 * PADDING;
+    let height = line_count as f32 * FONT_HEIGHT + 2.0 * PADDING;
+    let x = (painter.width() - width) / 2.0;
+
+    let palette = theme::palette();
+    painter.fill_rect(x, TOP, width, height, palette.background);
+    painter.text(x + PADDING, TOP + PADDING, page.title(), palette.text);
+
+    for (i, (name, value, on)) in entries.iter().enumerate() {
+        let y = TOP + PADDING + (i + 2) as f32 * FONT_HEIGHT;
+        let marker = if i == selected { ">" } else { " " };
+        let line = format!("{marker} {name:<name_width$} {value}");
+        let color = match (i == selected, on) {
+            (true, _) => palette.highlight,
+            (false, true) => palette.positive,
+            (false, false) => palette.muted,
+        };
+        painter.text(x + PADDING, y, &line, color);
+    }
+
+    let hint_y = TOP + PADDING + (line_count - 1) as f32 * FONT_HEIGHT;
+    painter.text(x + PADDING, hint_y, page.hint(), palette.muted);
+}
+
+pub fn init() {
+    super::add_widget(draw);
+    input::capture_while(is_open);
+}
@@ -0,0 +1,226 @@
+/// Top-down minimap in a corner of the screen, centered on the controlled character.
+///
+/// Walkmesh edges come from the walkmesh overlay's cache, so the area is read only once. The
+/// party is drawn at its live position, everything else at the position the area's instance file
+/// places it. North is up.
+use std::{
+    io,
+    sync::{
+        atomic::{AtomicBool, AtomicU32, Ordering},
+        Mutex,
+    },
+};
+
+use log::{error, info};
+
+use crate::config::{config, MinimapConfig, MinimapIcon};
+use crate::engine::{
+    module, party,
+    resources::{read_resource, ResourceType},
+    types::CResRef,
+};
+use crate::formats::gff::{Gff, GffStruct};
+
+use super::{theme, walkmesh, Color, Painter};
+
+/// Allowed zoom range in pixels per meter
+pub const MIN_ZOOM: f32 = 0.5;
+pub const MAX_ZOOM: f32 = 50.0;
+
+const WALKMESH_ALPHA: f32 = 0.8;
+/// Walkmesh is dimmed to keep the icons on it readable
+const WALKMESH_DIMMING: f32 = 0.8;
+const ICON_SIZE: f32 = 5.0;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+/// Zoom as raw `f32` bits, set from the config on init
+static ZOOM: AtomicU32 = AtomicU32::new(0);
+static ICONS: Mutex<Option<AreaIcons>> = Mutex::new(None);
+
+struct AreaIcons {
+    module: String,
+    icons: Vec<(MinimapIcon, [f32; 2])>,
+}
+
+fn icon_color(icon: MinimapIcon) -> Color {
+    let palette = theme::palette();
+    match icon {
+        MinimapIcon::Party => palette.positive,
+        MinimapIcon::Creatures => palette.negative,
+        MinimapIcon::Doors => palette.caution,
+        MinimapIcon::Placeables => palette.info,
+        MinimapIcon::Waypoints => palette.target,
+    }
+}
+
+/// Reads the positions of the area instances. Doors and placeables are positioned with `X`/`Y`,
+/// creatures and waypoints with `XPosition`/`YPosition`.
+fn load_area_icons(module: &str) -> io::Result<AreaIcons> {
+    let git = read_resource(&CResRef::new("area", module)?, ResourceType::Git)?;
+    let git = Gff::parse(&git)?;
+
+    let lists = [
+        (
+            MinimapIcon::Creatures,
+            "Creature List",
+            ["XPosition", "YPosition"],
+        ),
+        (MinimapIcon::Doors, "Door List", ["X", "Y"]),
+        (MinimapIcon::Placeables, "Placeable List", ["X", "Y"]),
+        (
+            MinimapIcon::Waypoints,
+            "WaypointList",
+            ["XPosition", "YPosition"],
+        ),
+    ];
+
+    let mut icons = Vec::new();
+    for (icon, list, labels) in lists {
+        let position =
+            |instance: &GffStruct| labels.map(|label| instance.get_f32(label).unwrap_or_default());
+        icons.extend(
+            git.root
+                .get_list(list)
+                .iter()
+                .map(|instance| (icon, position(instance))),
+        );
+    }
+
+    info!("Loaded {} minimap icons of {module}", icons.len());
+    Ok(AreaIcons {
+        module: module.to_string(),
+        icons,
+    })
+}
+
+fn draw(painter: &mut Painter) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    // Nothing to draw in the main menu or while loading
+    let Some(module) = module::entered_module() else {
+        return;
+    };
+    let Ok(party) = party::party_positions() else {
+        return;
+    };
+    let Some(&[center_x, center_y, _]) = party.first() else {
+        return;
+    };
+
+    let (position, size, filter) = {
+        let config = config();
+        let minimap = &config.minimap;
+        (minimap.position, minimap.size as f32, minimap.icons.clone())
+    };
+    let zoom = zoom();
+    let (x, y) = painter.anchor(position.x, position.y, size, size);
+
+    let to_screen = |world_x: f32, world_y: f32| {
+        (
+            x + size / 2.0 + (world_x - center_x) * zoom,
+            y + size / 2.0 - (world_y - center_y) * zoom,
+        )
+    };
+    let to_screen_segments = |segments: &[([f32; 3], [f32; 3])]| {
+        segments
+            .iter()
+            .map(|(start, end)| (to_screen(start[0], start[1]), to_screen(end[0], end[1])))
+            .collect::<Vec<_>>()
+    };
+
+    let (walkable, blocked) = walkmesh::with_area_walkmesh(&module, |area| {
+        (
+            to_screen_segments(&area.walkable),
+            to_screen_segments(&area.blocked),
+        )
+    });
+
+    let mut icons = ICONS.lock().unwrap();
+    let area = match icons.take() {
+        Some(area) if area.module == module => area,
+        _ => load_area_icons(&module).unwrap_or_else(|e| {
+            error!("Could not load minimap icons of {module}: {e}");
+            // Remember the failure, retrying every frame would flood the log
+            AreaIcons {
+                module: module.clone(),
+                icons: Vec::new(),
+            }
+        }),
+    };
+    let area = icons.insert(area);
+
+    let party_icons = party.iter().map(|&[x, y, _]| (MinimapIcon::Party, [x, y]));
+
+    let palette = theme::palette();
+    painter.fill_rect(x, y, size, size, palette.background);
+    painter.clipped(x, y, size, size, |painter| {
+        let walkmesh_color =
+            |color: Color| color.scaled(WALKMESH_DIMMING).with_alpha(WALKMESH_ALPHA);
+        painter.lines(&walkable, walkmesh_color(palette.muted));
+        painter.lines(&blocked, walkmesh_color(palette.negative));
+
+        // Party last so it's drawn on top
+        for (icon, [world_x, world_y]) in area.icons.iter().copied().chain(party_icons) {
+            if filter.contains(&icon) {
+                let (icon_x, icon_y) = to_screen(world_x, world_y);
+                painter.fill_rect(
+                    icon_x - ICON_SIZE / 2.0,
+                    icon_y - ICON_SIZE / 2.0,
+                    ICON_SIZE,
+                    ICON_SIZE,
+                    icon_color(icon),
+                );
+            }
+        }
+    });
+}
+
+pub fn minimap_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Shows or hides the minimap.
+///
+/// Must be called on the main thread.
+pub fn set_minimap(enabled: bool) -> io::Result<()> {
+    if enabled {
+        // Fail early instead of silently drawing nothing
+        party::party_positions()?;
+    }
+
+    ENABLED.store(enabled, Ordering::Relaxed);
+    info!("Minimap {}", if enabled { "enabled" } else { "disabled" });
+    Ok(())
+}
+
+/// Returns the zoom in pixels per meter.
+pub fn zoom() -> f32 {
+    f32::from_bits(ZOOM.load(Ordering::Relaxed))
+}
+
+pub fn set_zoom(zoom: f32) -> io::Result<()> {
+    if !(MIN_ZOOM..=MAX_ZOOM).contains(&zoom) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Zoom has to be {MIN_ZOOM}-{MAX_ZOOM} pixels per meter"),
+        ));
+    }
+
+    ZOOM.store(zoom.to_bits(), Ordering::Relaxed);
+    Ok(())
+}
+
+/// Applies the minimap settings from the config and registers the widget.
+pub fn init() {
+    let minimap = config().minimap.clone();
+
+    ENABLED.store(minimap.enabled, Ordering::Relaxed);
+    if let Err(e) = set_zoom(minimap.zoom) {
+        error!("Invalid minimap zoom in config, using the default: {e}");
+        ZOOM.store(MinimapConfig::default().zoom.to_bits(), Ordering::Relaxed);
+    }
+
+    super::add_widget(draw);
+}
@@ -0,0 +1,111 @@
+/// On screen overlay drawn on top of the game.
+///
+/// Widgets register a draw function, which gets called every frame from the SwapBuffers hook after
+/// the game has finished rendering.
+pub mod alerts;
+pub mod banner;
+pub mod clock;
+pub mod ghost;
+pub mod journal;
+pub mod loads;
+pub mod memory;
+pub mod menu;
+pub mod minimap;
+pub mod nameplates;
+mod painter;
+pub mod plan;
+pub mod rng;
+pub mod route;
+pub mod savegame;
+pub mod scene;
+pub mod sheet;
+pub mod theme;
+pub mod timer;
+pub mod transform;
+pub mod volumes;
+pub mod walkmesh;
+pub mod watch;
+
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use windows::Win32::Graphics::Gdi::HDC;
+
+use crate::system::status;
+
+pub use painter::{Color, Painter, FONT_HEIGHT, FONT_WIDTH};
+
+/// Weight of the latest frame in the average cost, roughly the last second at 60fps
+const AVERAGE_WEIGHT: f64 = 1.0 / 60.0;
+
+static WIDGETS: Mutex<Vec<fn(&mut Painter)>> = Mutex::new(Vec::new());
+static FRAME_COST: Mutex<FrameCost> = Mutex::new(FrameCost {
+    last: Duration::ZERO,
+    average: Duration::ZERO,
+    worst: Duration::ZERO,
+});
+
+/// Time spent drawing the overlay, on top of the game's own frame time.
+#[derive(Debug, Clone, Copy)]
+pub struct FrameCost {
+    pub last: Duration,
+    /// Moving average over about a second
+    pub average: Duration,
+    /// Since the mod was loaded
+    pub worst: Duration,
+}
+
+impl FrameCost {
+    fn record(&mut self, cost: Duration) {
+        self.last = cost;
+        self.worst = self.worst.max(cost);
+        self.average = if self.average.is_zero() {
+            cost
+        } else {
+            self.average
+                .mul_f64(1.0 - AVERAGE_WEIGHT)
+                .saturating_add(cost.mul_f64(AVERAGE_WEIGHT))
+        };
+    }
+}
+
+pub fn frame_cost() -> FrameCost {
+    *FRAME_COST.lock().unwrap()
+}
+
+/// Registers `draw` to be called every frame.
+pub fn add_widget(draw: fn(&mut Painter)) {
+    WIDGETS.lock().unwrap().push(draw);
+}
+
+/// Draws all widgets. Called from the SwapBuffers hook with the game's GL context current.
+pub(crate) unsafe fn render(hdc: HDC) {
+    // Copy the widgets so they can register new ones without deadlocking
+    let widgets = WIDGETS.lock().unwrap().clone();
+    if widgets.is_empty() {
+        return;
+    }
+
+    let started = Instant::now();
+    let mut painter = Painter::begin(hdc);
+    for draw in widgets {
+        // A panic must not unwind into the game, drop the widget and keep drawing the rest
+        if panic::catch_unwind(AssertUnwindSafe(|| draw(&mut painter))).is_err() {
+            let address = draw as usize;
+            WIDGETS
+                .lock()
+                .unwrap()
+                .retain(|&registered| registered as usize != address);
+            status::disable_feature(
+                &format!("overlay widget {address:#x}"),
+                "panicked, see the log",
+            );
+        }
+    }
+    // Painter restores the game's GL state when dropped, that's part of the cost too
+    drop(painter);
+    FRAME_COST.lock().unwrap().record(started.elapsed());
+}
@@ -0,0 +1,186 @@
+/// Nameplates: labels floating above the objects around the party with their tag and state.
+///
+/// Creatures show their hit points, doors and placeables whether they're open or locked, plot
+/// objects are marked. Objects are read live every frame, only the ones near the party leader are
+/// labeled to keep busy areas readable. Which object types get a label is picked from the console.
+use std::{
+    io,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+use log::info;
+
+use crate::engine::{
+    area::{self, AreaObject, OBJECT_TYPES},
+    camera, party,
+};
+
+use super::painter::{FONT_HEIGHT, FONT_WIDTH};
+use super::{theme, Color, Painter};
+
+/// Objects further than this from the party leader, in meters, get no label
+const LABEL_DISTANCE: f32 = 30.0;
+/// Height of the label above the object's position, which is at its feet
+const CREATURE_LABEL_HEIGHT: f32 = 2.2;
+const OBJECT_LABEL_HEIGHT: f32 = 1.2;
+const PADDING: f32 = 2.0;
+
+const DEFAULT_TYPES: &[&str] = &["creature", "door", "placeable"];
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+/// Object types labeled, names from `area::OBJECT_TYPES`
+static TYPES: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+
+fn distance(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| (a - b) * (a - b))
+        .sum::<f32>()
+        .sqrt()
+}
+
+fn state(object: &AreaObject) -> Option<String> {
+    if let (Some(hit_points), Some(max)) = (object.hit_points, object.max_hit_points) {
+        return Some(format!("HP {hit_points}/{max}"));
+    }
+    match (object.open, object.locked) {
+        (Some(true), _) => Some("open".to_string()),
+        (_, Some(true)) => Some("locked".to_string()),
+        (Some(false), _) => Some("closed".to_string()),
+        _ => None,
+    }
+}
+
+fn label(object: &AreaObject) -> Vec<String> {
+    let tag = if object.tag.is_empty() {
+        &object.template
+    } else {
+        &object.tag
+    };
+    let mut lines = vec![tag.clone()];
+    match (state(object), object.plot) {
+        (Some(state), true) => lines.push(format!("{state} plot")),
+        (Some(state), false) => lines.push(state),
+        (None, true) => lines.push("plot".to_string()),
+        (None, false) => {}
+    }
+    lines
+}
+
+fn color(object: &AreaObject) -> Color {
+    if object.hit_points.is_some_and(|hit_points| hit_points <= 0) {
+        theme::palette().muted
+    } else if object.plot {
+        theme::palette().highlight
+    } else {
+        theme::palette().text
+    }
+}
+
+fn draw(painter: &mut Painter) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    // Nothing to label in the main menu
+    let (Ok(view_projection), Ok(objects), Ok(leader)) = (
+        camera::view_projection(),
+        area::area_objects(),
+        party::leader_position(),
+    ) else {
+        return;
+    };
+    let types = TYPES.lock().unwrap().clone();
+
+    for object in &objects {
+        if !types.contains(&object.kind) || distance(object.position, leader) > LABEL_DISTANCE {
+            continue;
+        }
+
+        let height = if object.kind == "creature" {
+            CREATURE_LABEL_HEIGHT
+        } else {
+            OBJECT_LABEL_HEIGHT
+        };
+        let [x, y, z] = object.position;
+        let Some((x, y)) = painter.project(&view_projection, [x, y, z + height]) else {
+            continue;
+        };
+
+        let lines = label(object);
+        let longest = lines.iter().map(String::len).max().unwrap_or_default();
+        let width = longest as f32 * FONT_WIDTH + 2.0 * PADDING;
+        let box_height = lines.len() as f32 * FONT_HEIGHT + 2.0 * PADDING;
+        let (left, top) = (x - width / 2.0, y - box_height);
+
+        painter.fill_rect(left, top, width, box_height, theme::palette().background);
+        let color = color(object);
+        for (i, line) in lines.iter().enumerate() {
+            let line_width = line.len() as f32 * FONT_WIDTH;
+            painter.text(
+                x - line_width / 2.0,
+                top + PADDING + i as f32 * FONT_HEIGHT,
+                line,
+                color,
+            );
+        }
+    }
+}
+
+pub fn nameplates_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Shows or hides the nameplates. Must be called on the main thread.
+pub fn set_nameplates(enabled: bool) -> io::Result<()> {
+    if enabled {
+        // Fail early instead of silently drawing nothing
+        camera::view_projection()?;
+        area::area_objects()?;
+    }
+
+    ENABLED.store(enabled, Ordering::Relaxed);
+    info!(
+        "Nameplates {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(())
+}
+
+/// Object types labeled.
+pub fn types() -> Vec<&'static str> {
+    TYPES.lock().unwrap().clone()
+}
+
+/// Labels only objects of the named types, e.g. `creature`.
+pub fn set_types(names: &[&str]) -> io::Result<()> {
+    let types = names
+        .iter()
+        .map(|name| {
+            OBJECT_TYPES
+                .iter()
+                .map(|(_, type_name)| *type_name)
+                .find(|type_name| type_name.eq_ignore_ascii_case(name))
+                .ok_or_else(|| {
+                    let known: Vec<&str> = OBJECT_TYPES.iter().map(|(_, name)| *name).collect();
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!(
+                            "Unknown object type '{name}', expected any of {}",
+                            known.join(", ")
+                        ),
+                    )
+                })
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+
+    *TYPES.lock().unwrap() = types;
+    Ok(())
+}
+
+pub fn init() {
+    *TYPES.lock().unwrap() = DEFAULT_TYPES.to_vec();
+    super::add_widget(draw);
+}
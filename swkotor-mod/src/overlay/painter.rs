@@ -0,0 +1,447 @@
+/// Immediate mode OpenGL drawing for the overlay.
+///
+/// Kotor renders with the fixed function pipeline, so drawing on top of a finished frame is just
+/// a matter of saving the game's GL state, setting up a 2D projection and restoring everything
+/// once done.
+///
+/// Widgets lay themselves out for 1080p. The painter scales everything up on bigger screens and
+/// high DPI monitors, so widgets see a screen of `width() x height()` logical pixels. Colors come
+/// from the theme, see `theme`, and the configured opacity applies to everything drawn.
+use std::{ffi::CString, sync::Mutex};
+
+use log::{error, trace};
+use windows::{
+    core::PCSTR,
+    Win32::{
+        Graphics::{
+            Gdi::{
+                CreateFontA, DeleteObject, MonitorFromWindow, SelectObject, WindowFromDC,
+                ANSI_CHARSET, CLIP_DEFAULT_PRECIS, DEFAULT_PITCH, FW_BOLD, HDC,
+                MONITOR_DEFAULTTONEAREST, NONANTIALIASED_QUALITY, OUT_TT_PRECIS,
+            },
+            OpenGL::{
+                glBegin, glBlendFunc, glCallLists, glColor4f, glDeleteLists, glDisable, glEnable,
+                glEnd, glGenLists, glGetIntegerv, glLineWidth, glListBase, glLoadIdentity,
+                glLoadMatrixf, glMatrixMode, glOrtho, glPopAttrib, glPopMatrix, glPushAttrib,
+                glPushMatrix, glRasterPos2f, glScalef, glScissor, glVertex2f, glVertex3f,
+                wglGetCurrentContext, wglUseFontBitmapsA, GL_ALL_ATTRIB_BITS, GL_BLEND,
+                GL_CULL_FACE, GL_DEPTH_TEST, GL_FOG, GL_LIGHTING, GL_LINES, GL_MODELVIEW,
+                GL_ONE_MINUS_SRC_ALPHA, GL_PROJECTION, GL_QUADS, GL_SCISSOR_TEST, GL_SRC_ALPHA,
+                GL_TEXTURE_2D, GL_UNSIGNED_BYTE, GL_VIEWPORT,
+            },
+        },
+        UI::HiDpi::{GetDpiForMonitor, MDT_EFFECTIVE_DPI},
+    },
+};
+
+use crate::config::config;
+
+use super::theme;
+
+/// Glyph size of the overlay font in logical pixels. Font is monospaced so text width is easy to
+/// calculate.
+pub const FONT_HEIGHT: f32 = 16.0;
+pub const FONT_WIDTH: f32 = 8.0;
+
+/// Screen height the widgets were laid out for
+const REFERENCE_HEIGHT: f32 = 1080.0;
+/// DPI Windows calls 100%
+const REFERENCE_DPI: f32 = 96.0;
+const MIN_SCALE: f32 = 0.5;
+const MAX_SCALE: f32 = 4.0;
+
+/// Printable ASCII range uploaded as display lists
+const FIRST_GLYPH: u32 = 32;
+const GLYPH_COUNT: u32 = 96;
+
+/// Point in screen coordinates, `(x, y)`
+pub type ScreenPoint = (f32, f32);
+
+#[derive(Debug, Clone, Copy)]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl Color {
+    pub const fn rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Color { r, g, b, a }
+    }
+
+    /// Same color with the given alpha.
+    pub const fn with_alpha(self, a: f32) -> Self {
+        Color { a, ..self }
+    }
+
+    /// Darker by `factor`, alpha is kept.
+    pub const fn scaled(self, factor: f32) -> Self {
+        Color::rgba(self.r * factor, self.g * factor, self.b * factor, self.a)
+    }
+}
+
+/// Sets the GL color, faded by the overlay opacity.
+unsafe fn set_color(color: Color, opacity: f32) {
+    glColor4f(color.r, color.g, color.b, color.a * opacity);
+}
+
+/// Display lists are tied to the GL context, the game recreates the context when changing video
+/// settings. The glyphs are bitmaps, a new scale or face needs a new font.
+struct Font {
+    context: usize,
+    height: i32,
+    face: String,
+    list_base: u32,
+}
+
+static FONT: Mutex<Option<Font>> = Mutex::new(None);
+
+unsafe fn font_list_base(hdc: HDC, scale: f32, face: &str) -> Option<u32> {
+    let context = wglGetCurrentContext().0 as usize;
+    let height = (FONT_HEIGHT * scale).round() as i32;
+    let mut font = FONT.lock().unwrap();
+
+    if let Some(font) = &*font {
+        if font.context == context && font.height == height && font.face == face {
+            return Some(font.list_base);
+        }
+        // Lists of a destroyed context went with it
+        if font.context == context {
+            glDeleteLists(font.list_base, GLYPH_COUNT as i32);
+        }
+    }
+
+    trace!("Creating {height}px {face} overlay font for GL context {context:#x}");
+    // An empty face name lets GDI pick any font matching the rest
+    let face_name = CString::new(face).unwrap_or_default();
+    let list_base = glGenLists(GLYPH_COUNT as i32);
+    let gdi_font = CreateFontA(
+        height,
+        (FONT_WIDTH * scale).round() as i32,
+        0,
+        0,
+        FW_BOLD.0 as i32,
+        0,
+        0,
+        0,
+        ANSI_CHARSET,
+        OUT_TT_PRECIS,
+        CLIP_DEFAULT_PRECIS,
+        NONANTIALIASED_QUALITY,
+        DEFAULT_PITCH.0 as u32,
+        PCSTR(face_name.as_ptr() as *const u8),
+    );
+    let previous = SelectObject(hdc, gdi_font.into());
+    let result = wglUseFontBitmapsA(hdc, FIRST_GLYPH, GLYPH_COUNT, list_base);
+    SelectObject(hdc, previous);
+    let _ = DeleteObject(gdi_font.into());
+
+    if let Err(e) = result {
+        error!("Could not create overlay font: {e}");
+        return None;
+    }
+
+    *font = Some(Font {
+        context,
+        height,
+        face: face.to_string(),
+        list_base,
+    });
+    Some(list_base)
+}
+
+/// Scale for a screen `height` pixels tall on a monitor of `dpi`: whichever asks for bigger, never
+/// smaller than 1080p at 100%.
+fn auto_scale(height: f32, dpi: u32) -> f32 {
+    (height / REFERENCE_HEIGHT)
+        .max(dpi as f32 / REFERENCE_DPI)
+        .max(1.0)
+}
+
+/// DPI of the monitor the game is on, 96 if Windows won't tell.
+unsafe fn monitor_dpi(hdc: HDC) -> u32 {
+    let monitor = MonitorFromWindow(WindowFromDC(hdc), MONITOR_DEFAULTTONEAREST);
+    let (mut dpi_x, mut dpi_y) = (0, 0);
+    match GetDpiForMonitor(monitor, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y) {
+        Ok(()) if dpi_y > 0 => dpi_y,
+        _ => REFERENCE_DPI as u32,
+    }
+}
+
+/// Overlay scale for a screen `height` pixels tall, the configured one if set.
+unsafe fn overlay_scale(hdc: HDC, height: f32) -> f32 {
+    match config().overlay.scale {
+        Some(scale) => scale.clamp(MIN_SCALE, MAX_SCALE),
+        None => auto_scale(height, monitor_dpi(hdc)).min(MAX_SCALE),
+    }
+}
+
+/// Draws in screen coordinates, origin at the top left. Game's GL state is restored on drop.
+pub struct Painter {
+    /// Logical size, the screen size divided by `scale`
+    width: f32,
+    height: f32,
+    scale: f32,
+    /// `overlay.opacity`, multiplies the alpha of every color
+    opacity: f32,
+    font_list_base: Option<u32>,
+}
+
+impl Painter {
+    /// Sets up GL for 2D drawing.
+    ///
+    /// # Safety
+    ///
+    /// Must be called on the main thread with the game's context current, i.e. from the
+    /// SwapBuffers hook.
+    pub unsafe fn begin(hdc: HDC) -> Painter {
+        let mut viewport = [0i32; 4];
+        glGetIntegerv(GL_VIEWPORT, viewport.as_mut_ptr());
+        let width = viewport[2] as f32;
+        let height = viewport[3] as f32;
+        let scale = overlay_scale(hdc, height);
+        let (opacity, face) = {
+            let config = config();
+            let face =
+                (config.overlay.font.clone()).unwrap_or_else(|| theme::current().font.to_string());
+            (config.overlay.opacity.clamp(0.0, 1.0), face)
+        };
+
+        glPushAttrib(GL_ALL_ATTRIB_BITS);
+        glMatrixMode(GL_PROJECTION);
+        glPushMatrix();
+        glLoadIdentity();
+        glOrtho(0.0, width as f64, height as f64, 0.0, -1.0, 1.0);
+        glMatrixMode(GL_MODELVIEW);
+        glPushMatrix();
+        glLoadIdentity();
+        glScalef(scale, scale, 1.0);
+        glLineWidth(scale);
+
+        glDisable(GL_DEPTH_TEST);
+        glDisable(GL_LIGHTING);
+        glDisable(GL_TEXTURE_2D);
+        glDisable(GL_CULL_FACE);
+        glDisable(GL_FOG);
+        glEnable(GL_BLEND);
+        glBlendFunc(GL_SRC_ALPHA, GL_ONE_MINUS_SRC_ALPHA);
+
+        Painter {
+            width: width / scale,
+            height: height / scale,
+            scale,
+            opacity,
+            font_list_base: font_list_base(hdc, scale, &face),
+        }
+    }
+
+    /// Screen width in logical pixels.
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+
+    /// Screen height in logical pixels.
+    pub fn height(&self) -> f32 {
+        self.height
+    }
+
+    /// Resolves a configured position to the top left corner of a box of the given size.
+    pub fn anchor(&self, x: i32, y: i32, box_width: f32, box_height: f32) -> (f32, f32) {
+        let x = if x < 0 {
+            self.width + x as f32 - box_width
+        } else {
+            x as f32
+        };
+        let y = if y < 0 {
+            self.height + y as f32 - box_height
+        } else {
+            y as f32
+        };
+        (x, y)
+    }
+
+    pub fn fill_rect(&mut self, x: f32, y: f32, width: f32, height: f32, color: Color) {
+        unsafe {
+            set_color(color, self.opacity);
+            glBegin(GL_QUADS);
+            glVertex2f(x, y);
+            glVertex2f(x + width, y);
+            glVertex2f(x + width, y + height);
+            glVertex2f(x, y + height);
+            glEnd();
+        }
+    }
+
+    /// Draws straight lines in screen coordinates.
+    pub fn lines(&mut self, segments: &[(ScreenPoint, ScreenPoint)], color: Color) {
+        unsafe {
+            set_color(color, self.opacity);
+            glBegin(GL_LINES);
+            for ((start_x, start_y), (end_x, end_y)) in segments {
+                glVertex2f(*start_x, *start_y);
+                glVertex2f(*end_x, *end_y);
+            }
+            glEnd();
+        }
+    }
+
+    /// Clips anything `draw` does to the given rectangle.
+    pub fn clipped(
+        &mut self,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        draw: impl FnOnce(&mut Painter),
+    ) {
+        unsafe {
+            // Scissor box is in screen pixels with the origin at the bottom left corner
+            glScissor(
+                (x * self.scale) as i32,
+                ((self.height - y - height) * self.scale) as i32,
+                (width * self.scale) as i32,
+                (height * self.scale) as i32,
+            );
+            glEnable(GL_SCISSOR_TEST);
+        }
+
+        draw(self);
+
+        unsafe {
+            glDisable(GL_SCISSOR_TEST);
+        }
+    }
+
+    /// Draws a single line of ASCII text with its top left corner at `x`, `y`.
+    pub fn text(&mut self, x: f32, y: f32, text: &str, color: Color) {
+        let Some(list_base) = self.font_list_base else {
+            return;
+        };
+
+        unsafe {
+            set_color(color, self.opacity);
+            // Raster position is the baseline, leave room for descenders
+            glRasterPos2f(x, y + FONT_HEIGHT - 4.0);
+            glListBase(list_base - FIRST_GLYPH);
+            glCallLists(
+                text.len() as i32,
+                GL_UNSIGNED_BYTE,
+                text.as_ptr() as *const _,
+            );
+        }
+    }
+}
+
+impl Painter {
+    /// Projects a world point to screen coordinates, `None` when it's behind the camera.
+    pub fn project(&self, view_projection: &[f32; 16], point: [f32; 3]) -> Option<ScreenPoint> {
+        // Column major, element (row, column) is at column * 4 + row
+        let clip = |row: usize| {
+            (0..3)
+                .map(|column| view_projection[column * 4 + row] * point[column])
+                .sum::<f32>()
+                + view_projection[12 + row]
+        };
+        let w = clip(3);
+        if w <= f32::EPSILON {
+            return None;
+        }
+
+        let x = clip(0) / w;
+        let y = clip(1) / w;
+        Some(((x + 1.0) / 2.0 * self.width, (1.0 - y) / 2.0 * self.height))
+    }
+
+    /// Switches to world coordinates for the duration of `draw`, `view_projection` being the
+    /// camera the scene was drawn with. Drawn over the scene, walls don't hide anything.
+    pub fn world(&mut self, view_projection: &[f32; 16], draw: impl FnOnce(&mut WorldPainter)) {
+        unsafe {
+            glMatrixMode(GL_PROJECTION);
+            glPushMatrix();
+            glLoadMatrixf(view_projection.as_ptr());
+            // The overlay scale is for screen coordinates only
+            glMatrixMode(GL_MODELVIEW);
+            glPushMatrix();
+            glLoadIdentity();
+        }
+
+        draw(&mut WorldPainter {
+            opacity: self.opacity,
+        });
+
+        unsafe {
+            glPopMatrix();
+            glMatrixMode(GL_PROJECTION);
+            glPopMatrix();
+            glMatrixMode(GL_MODELVIEW);
+        }
+    }
+}
+
+/// Draws in world coordinates, see `Painter::world`.
+pub struct WorldPainter {
+    opacity: f32,
+}
+
+impl WorldPainter {
+    /// Draws straight lines from each start point to its end point.
+    pub fn lines(&mut self, segments: &[([f32; 3], [f32; 3])], color: Color) {
+        unsafe {
+            set_color(color, self.opacity);
+            glBegin(GL_LINES);
+            for (start, end) in segments {
+                glVertex3f(start[0], start[1], start[2]);
+                glVertex3f(end[0], end[1], end[2]);
+            }
+            glEnd();
+        }
+    }
+
+    /// Draws filled quads, corners in drawing order.
+    pub fn quads(&mut self, quads: &[[[f32; 3]; 4]], color: Color) {
+        unsafe {
+            set_color(color, self.opacity);
+            glBegin(GL_QUADS);
+            for corner in quads.iter().flatten() {
+                glVertex3f(corner[0], corner[1], corner[2]);
+            }
+            glEnd();
+        }
+    }
+}
+
+impl Drop for Painter {
+    fn drop(&mut self) {
+        unsafe {
+            glMatrixMode(GL_MODELVIEW);
+            glPopMatrix();
+            glMatrixMode(GL_PROJECTION);
+            glPopMatrix();
+            glPopAttrib();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auto_scale_follows_resolution() {
+        assert_eq!(auto_scale(1080.0, 96), 1.0);
+        assert_eq!(auto_scale(1440.0, 96), 1440.0 / 1080.0);
+        assert_eq!(auto_scale(2160.0, 96), 2.0);
+    }
+
+    #[test]
+    fn auto_scale_follows_dpi() {
+        assert_eq!(auto_scale(1080.0, 144), 1.5);
+        assert_eq!(auto_scale(2160.0, 144), 2.0);
+    }
+
+    #[test]
+    fn auto_scale_never_shrinks() {
+        assert_eq!(auto_scale(720.0, 96), 1.0);
+    }
+}
@@ -0,0 +1,99 @@
+/// Draws the speedrun route plan of the current module in the world.
+///
+/// Waypoints are numbered markers joined with lines in route order, notes next to the numbers.
+/// The plan is handed to the scene whenever the module or the plan changes.
+use std::sync::Mutex;
+
+use crate::engine::{frame, module};
+use crate::speedrun::plan::{self, PLAN};
+
+use super::scene::{self, PrimitiveId, Shape};
+use super::theme;
+
+const MARKER_ALPHA: f32 = 0.9;
+const LINE_ALPHA: f32 = 0.6;
+/// Marker size in pixels
+const MARKER_SIZE: f32 = 10.0;
+/// Labels are lifted above the markers so they don't overlap
+const LABEL_HEIGHT: f32 = 0.5;
+
+struct Shown {
+    module: Option<String>,
+    revision: u64,
+    primitives: Vec<PrimitiveId>,
+}
+
+static SHOWN: Mutex<Shown> = Mutex::new(Shown {
+    module: None,
+    revision: 0,
+    primitives: Vec::new(),
+});
+
+fn add_primitives(module: &str) -> Vec<PrimitiveId> {
+    let plan = PLAN.lock().unwrap();
+    let waypoints: Vec<_> = plan.in_module(module).collect();
+    let mut primitives = Vec::new();
+    let palette = theme::palette();
+
+    for pair in waypoints.windows(2) {
+        let line = Shape::Line {
+            start: pair[0].position,
+            end: pair[1].position,
+        };
+        primitives.push(scene::add(
+            line,
+            palette.caution.with_alpha(LINE_ALPHA),
+            None,
+        ));
+    }
+
+    for (i, waypoint) in waypoints.iter().enumerate() {
+        let [x, y, z] = waypoint.position;
+        let marker = Shape::Billboard {
+            position: waypoint.position,
+            size: MARKER_SIZE,
+        };
+        let label = Shape::Text {
+            position: [x, y, z + LABEL_HEIGHT],
+            text: format!("{} {}", i + 1, waypoint.note)
+                .trim_end()
+                .to_string(),
+        };
+        primitives.push(scene::add(
+            marker,
+            palette.caution.with_alpha(MARKER_ALPHA),
+            None,
+        ));
+        primitives.push(scene::add(
+            label,
+            palette.caution.with_alpha(MARKER_ALPHA),
+            None,
+        ));
+    }
+
+    primitives
+}
+
+fn sync() {
+    // Main menu and loading screens have no module, the plan is hidden then
+    let module = module::entered_module();
+    let revision = plan::revision();
+
+    let mut shown = SHOWN.lock().unwrap();
+    if shown.module == module && shown.revision == revision {
+        return;
+    }
+
+    for id in shown.primitives.drain(..) {
+        scene::remove(id);
+    }
+    if let Some(module) = &module {
+        shown.primitives = add_primitives(module);
+    }
+    shown.module = module;
+    shown.revision = revision;
+}
+
+pub fn init() {
+    frame::on_every_frame(sync);
+}
@@ -0,0 +1,76 @@
+/// Latest rolls of the engine's RNG, newest first, and the forced seed if there is one.
+use std::{
+    io,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use log::info;
+
+use crate::engine::rng;
+
+use super::painter::{FONT_HEIGHT, FONT_WIDTH};
+use super::{theme, Painter};
+
+const PADDING: f32 = 6.0;
+/// Rolls shown, fewer than kept so the widget stays small
+const SHOWN_ROLLS: usize = 8;
+/// Room for `seed 4294967295` and the rolls in hex
+const LINE_CHARACTERS: usize = 15;
+/// Below the timer with its default position
+const POSITION: (i32, i32) = (-10, 90);
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+fn draw(painter: &mut Painter) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let mut lines = vec![match rng::forced_seed() {
+        Some(seed) => format!("seed {seed}"),
+        None => "seed engine".to_string(),
+    }];
+    lines.extend(
+        rng::recent_rolls()
+            .iter()
+            .rev()
+            .take(SHOWN_ROLLS)
+            .map(|roll| format!("{roll:#010x}")),
+    );
+
+    let width = LINE_CHARACTERS as f32 * FONT_WIDTH + 2.0 * PADDING;
+    let height = lines.len() as f32 * FONT_HEIGHT + 2.0 * PADDING;
+    let (x, y) = painter.anchor(POSITION.0, POSITION.1, width, height);
+
+    painter.fill_rect(x, y, width, height, theme::palette().background);
+    for (i, line) in lines.iter().enumerate() {
+        painter.text(
+            x + PADDING,
+            y + PADDING + i as f32 * FONT_HEIGHT,
+            line,
+            theme::palette().text,
+        );
+    }
+}
+
+pub fn rng_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Shows or hides the latest rolls. Must be called on the main thread.
+pub fn set_rng(enabled: bool) -> io::Result<()> {
+    if enabled {
+        rng::watch_rolls()?;
+    }
+
+    ENABLED.store(enabled, Ordering::Relaxed);
+    info!(
+        "RNG overlay {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(())
+}
+
+pub fn init() {
+    super::add_widget(draw);
+}
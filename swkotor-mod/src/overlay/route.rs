@@ -0,0 +1,96 @@
+/// Route the controlled character is walking, to see movement bugs instead of guessing them.
+///
+/// Runs from the character to each remaining waypoint, the passed part of the route is dimmed. The
+/// destination is marked with a cross.
+use std::{
+    io,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use log::info;
+
+use crate::engine::{camera, party, pathfinding};
+
+use super::{theme, Painter};
+
+const REMAINING_ALPHA: f32 = 0.9;
+const PASSED_ALPHA: f32 = 0.3;
+
+/// Half the width of the destination cross in world units
+const TARGET_SIZE: f32 = 0.3;
+
+type Segment = ([f32; 3], [f32; 3]);
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+fn segments(points: &[[f32; 3]]) -> Vec<Segment> {
+    points.windows(2).map(|pair| (pair[0], pair[1])).collect()
+}
+
+fn cross([x, y, z]: [f32; 3]) -> [Segment; 2] {
+    [
+        (
+            [x - TARGET_SIZE, y - TARGET_SIZE, z],
+            [x + TARGET_SIZE, y + TARGET_SIZE, z],
+        ),
+        (
+            [x - TARGET_SIZE, y + TARGET_SIZE, z],
+            [x + TARGET_SIZE, y - TARGET_SIZE, z],
+        ),
+    ]
+}
+
+fn draw(painter: &mut Painter) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let Ok(Some(path)) = pathfinding::leader_path() else {
+        return;
+    };
+    let Ok(position) = party::leader_position() else {
+        return;
+    };
+    let Ok(view_projection) = camera::view_projection() else {
+        return;
+    };
+
+    let passed = segments(&path.waypoints[..=path.next.saturating_sub(1)]);
+    let mut remaining = vec![(position, path.waypoints[path.next])];
+    remaining.extend(segments(&path.waypoints[path.next..]));
+
+    let palette = theme::palette();
+    painter.world(&view_projection, |world| {
+        world.lines(&passed, palette.highlight.with_alpha(PASSED_ALPHA));
+        world.lines(&remaining, palette.highlight.with_alpha(REMAINING_ALPHA));
+        if let Some(target) = path.target() {
+            world.lines(&cross(target), palette.target.with_alpha(REMAINING_ALPHA));
+        }
+    });
+}
+
+pub fn route_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Shows or hides the route of the controlled character.
+///
+/// Must be called on the main thread.
+pub fn set_route(enabled: bool) -> io::Result<()> {
+    if enabled {
+        // Fail early instead of silently drawing nothing
+        camera::view_projection()?;
+        pathfinding::ensure_located()?;
+    }
+
+    ENABLED.store(enabled, Ordering::Relaxed);
+    info!(
+        "Route overlay {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(())
+}
+
+pub fn init() {
+    super::add_widget(draw);
+}
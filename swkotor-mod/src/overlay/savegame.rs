@@ -0,0 +1,158 @@
+/// Latest save panel: what the newest save in the `saves` directory holds and which of its globals
+/// and journal states the live game has changed since.
+use std::{
+    io,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use log::info;
+
+use crate::savegame::{self, Difference, SaveGame};
+
+use super::painter::{FONT_HEIGHT, FONT_WIDTH};
+use super::{theme, Painter};
+
+const PADDING: f32 = 6.0;
+/// Bottom left, out of the way of the sheet and the minimap
+const POSITION: (i32, i32) = (10, -10);
+/// Differences listed before cutting the list short
+const SHOWN_DIFFERENCES: usize = 12;
+/// Comparing asks the engine for every global in the save, not something to do every frame
+const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+struct Panel {
+    refreshed: Instant,
+    lines: Vec<String>,
+}
+
+static PANEL: Mutex<Option<Panel>> = Mutex::new(None);
+/// Parsed latest save, reparsed only when a newer save is written
+static LATEST: Mutex<Option<SaveGame>> = Mutex::new(None);
+
+/// Lines shown on the overlay and by the console command.
+pub fn describe(save: &SaveGame, differences: &[Difference]) -> Vec<String> {
+    let mut lines = vec![
+        format!("Save '{}' in {}", save.name, save.module),
+        format!(
+            "{} booleans, {} numbers, {} quests",
+            save.booleans.len(),
+            save.numbers.len(),
+            save.journal.len()
+        ),
+        format!("{} changed since saving", differences.len()),
+    ];
+    lines.extend(
+        differences
+            .iter()
+            .take(SHOWN_DIFFERENCES)
+            .map(|difference| {
+                format!(
+                    "  {} {} -> {}",
+                    difference.name, difference.saved, difference.live
+                )
+            }),
+    );
+    if differences.len() > SHOWN_DIFFERENCES {
+        lines.push(format!(
+            "  and {} more",
+            differences.len() - SHOWN_DIFFERENCES
+        ));
+    }
+    lines
+}
+
+/// Loads the latest save, reusing the previous parse when it's still the latest.
+pub fn latest_save() -> io::Result<SaveGame> {
+    let (directory, saved) = savegame::latest_save()?;
+    let mut latest = LATEST.lock().unwrap();
+
+    match latest.as_ref() {
+        Some(save) if save.directory == directory && save.saved == saved => Ok(save.clone()),
+        _ => {
+            let save = SaveGame::load(&directory)?;
+            info!("Read save {}", directory.display());
+            *latest = Some(save.clone());
+            Ok(save)
+        }
+    }
+}
+
+/// Reads the latest save and compares it with the live game. Must be called on the main thread.
+pub fn compare_latest() -> io::Result<(SaveGame, Vec<Difference>)> {
+    let save = latest_save()?;
+    let differences = save.diff_live()?;
+    Ok((save, differences))
+}
+
+fn refresh() -> Vec<String> {
+    match compare_latest() {
+        Ok((save, differences)) => describe(&save, &differences),
+        Err(e) => vec![format!("No save to show: {e}")],
+    }
+}
+
+fn draw(painter: &mut Painter) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let mut panel = PANEL.lock().unwrap();
+    let panel = match panel.as_mut() {
+        Some(panel) if panel.refreshed.elapsed() < REFRESH_INTERVAL => panel,
+        _ => panel.insert(Panel {
+            refreshed: Instant::now(),
+            lines: refresh(),
+        }),
+    };
+
+    let longest = panel
+        .lines
+        .iter()
+        .map(String::len)
+        .max()
+        .unwrap_or_default();
+    let width = longest as f32 * FONT_WIDTH + 2.0 * PADDING;
+    let height = panel.lines.len() as f32 * FONT_HEIGHT + 2.0 * PADDING;
+    let (x, y) = painter.anchor(POSITION.0, POSITION.1, width, height);
+
+    painter.fill_rect(x, y, width, height, theme::palette().background);
+    for (i, line) in panel.lines.iter().enumerate() {
+        painter.text(
+            x + PADDING,
+            y + PADDING + i as f32 * FONT_HEIGHT,
+            line,
+            theme::palette().text,
+        );
+    }
+}
+
+pub fn savegame_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Shows or hides the latest save panel. Must be called on the main thread.
+pub fn set_savegame(enabled: bool) -> io::Result<()> {
+    if enabled {
+        // Fail early instead of drawing an error panel
+        compare_latest()?;
+    }
+
+    // Start from a fresh comparison next time it's shown
+    *PANEL.lock().unwrap() = None;
+    ENABLED.store(enabled, Ordering::Relaxed);
+    info!(
+        "Savegame overlay {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(())
+}
+
+pub fn init() {
+    super::add_widget(draw);
+}
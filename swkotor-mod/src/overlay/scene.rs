@@ -0,0 +1,190 @@
+/// Retained drawing of world space primitives.
+///
+/// Anything in the mod can add boxes, lines, text and billboards from any thread. They stay in the
+/// scene until removed by id or until their lifetime runs out, and are drawn every frame from the
+/// SwapBuffers hook like the other widgets.
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use crate::engine::camera;
+
+use super::painter::{FONT_HEIGHT, FONT_WIDTH};
+use super::{Color, Painter};
+
+/// Box faces are drawn at a fraction of the edge alpha so whatever is inside stays visible
+const BOX_FACE_ALPHA: f32 = 0.3;
+
+#[derive(Debug, Clone)]
+pub enum Shape {
+    /// Axis aligned box between two opposite corners
+    Box {
+        min: [f32; 3],
+        max: [f32; 3],
+    },
+    Line {
+        start: [f32; 3],
+        end: [f32; 3],
+    },
+    /// Single line of ASCII text centered on `position`
+    Text {
+        position: [f32; 3],
+        text: String,
+    },
+    /// Square facing the screen, `size` in pixels so it stays readable at any distance
+    Billboard {
+        position: [f32; 3],
+        size: f32,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PrimitiveId(u64);
+
+struct Primitive {
+    id: PrimitiveId,
+    shape: Shape,
+    color: Color,
+    /// Removed once this has passed, `None` stays until removed
+    expires: Option<Instant>,
+}
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+static PRIMITIVES: Mutex<Vec<Primitive>> = Mutex::new(Vec::new());
+
+/// Adds a primitive to the scene. It's drawn for `lifetime`, or until removed if `None`.
+pub fn add(shape: Shape, color: Color, lifetime: Option<Duration>) -> PrimitiveId {
+    let id = PrimitiveId(NEXT_ID.fetch_add(1, Ordering::Relaxed));
+    PRIMITIVES.lock().unwrap().push(Primitive {
+        id,
+        shape,
+        color,
+        expires: lifetime.map(|lifetime| Instant::now() + lifetime),
+    });
+    id
+}
+
+/// Removes a primitive, returns false if it was already gone.
+pub fn remove(id: PrimitiveId) -> bool {
+    let mut primitives = PRIMITIVES.lock().unwrap();
+    let count = primitives.len();
+    primitives.retain(|primitive| primitive.id != id);
+    primitives.len() != count
+}
+
+/// Removes every primitive, returns how many there were.
+pub fn clear() -> usize {
+    std::mem::take(&mut *PRIMITIVES.lock().unwrap()).len()
+}
+
+/// Number of primitives in the scene, expired ones included until the next frame drops them.
+pub fn len() -> usize {
+    PRIMITIVES.lock().unwrap().len()
+}
+
+fn box_corners(min: [f32; 3], max: [f32; 3]) -> [[f32; 3]; 8] {
+    let corner = |i: usize| {
+        [
+            if i & 1 == 0 { min[0] } else { max[0] },
+            if i & 2 == 0 { min[1] } else { max[1] },
+            if i & 4 == 0 { min[2] } else { max[2] },
+        ]
+    };
+    std::array::from_fn(corner)
+}
+
+/// Corner indices of each face, see `box_corners`
+const BOX_FACES: [[usize; 4]; 6] = [
+    [0, 1, 3, 2],
+    [4, 5, 7, 6],
+    [0, 1, 5, 4],
+    [2, 3, 7, 6],
+    [0, 2, 6, 4],
+    [1, 3, 7, 5],
+];
+
+/// Corner indices of each edge, see `box_corners`
+const BOX_EDGES: [[usize; 2]; 12] = [
+    [0, 1],
+    [2, 3],
+    [4, 5],
+    [6, 7],
+    [0, 2],
+    [1, 3],
+    [4, 6],
+    [5, 7],
+    [0, 4],
+    [1, 5],
+    [2, 6],
+    [3, 7],
+];
+
+fn draw(painter: &mut Painter) {
+    let mut primitives = PRIMITIVES.lock().unwrap();
+    let now = Instant::now();
+    primitives.retain(|primitive| primitive.expires.is_none_or(|expires| expires > now));
+    if primitives.is_empty() {
+        return;
+    }
+
+    let Ok(view_projection) = camera::view_projection() else {
+        return;
+    };
+
+    painter.world(&view_projection, |world| {
+        for primitive in primitives.iter() {
+            match primitive.shape {
+                Shape::Box { min, max } => {
+                    let corners = box_corners(min, max);
+                    let faces = BOX_FACES.map(|face| face.map(|i| corners[i]));
+                    let edges = BOX_EDGES.map(|[start, end]| (corners[start], corners[end]));
+                    let face_color = Color {
+                        a: primitive.color.a * BOX_FACE_ALPHA,
+                        ..primitive.color
+                    };
+                    world.quads(&faces, face_color);
+                    world.lines(&edges, primitive.color);
+                }
+                Shape::Line { start, end } => world.lines(&[(start, end)], primitive.color),
+                Shape::Text { .. } | Shape::Billboard { .. } => {}
+            }
+        }
+    });
+
+    // Screen facing primitives are drawn in screen space at their projected position
+    for primitive in primitives.iter() {
+        match &primitive.shape {
+            Shape::Text { position, text } => {
+                if let Some((x, y)) = painter.project(&view_projection, *position) {
+                    let width = text.len() as f32 * FONT_WIDTH;
+                    painter.text(
+                        x - width / 2.0,
+                        y - FONT_HEIGHT / 2.0,
+                        text,
+                        primitive.color,
+                    );
+                }
+            }
+            Shape::Billboard { position, size } => {
+                if let Some((x, y)) = painter.project(&view_projection, *position) {
+                    painter.fill_rect(
+                        x - size / 2.0,
+                        y - size / 2.0,
+                        *size,
+                        *size,
+                        primitive.color,
+                    );
+                }
+            }
+            Shape::Box { .. } | Shape::Line { .. } => {}
+        }
+    }
+}
+
+pub fn init() {
+    super::add_widget(draw);
+}
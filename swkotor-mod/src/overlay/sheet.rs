@@ -0,0 +1,149 @@
+/// Party member sheet panel: abilities, skills, hit points and feats, read live every frame.
+///
+/// The panel only shows, editing goes through the `stat` and `feat` console commands so the
+/// game keeps the mouse and keyboard.
+use std::{
+    collections::HashMap,
+    io,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        LazyLock, Mutex,
+    },
+};
+
+use log::{error, info};
+
+use crate::engine::{
+    party,
+    stats::{self, ABILITIES, SKILLS},
+    tables,
+};
+
+use super::painter::{FONT_HEIGHT, FONT_WIDTH};
+use super::{theme, Painter};
+
+const PADDING: f32 = 6.0;
+/// Left edge, below the memory widget with its default position
+const POSITION: (i32, i32) = (10, 80);
+/// Feats listed before cutting the list short
+const SHOWN_FEATS: usize = 16;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static SLOT: AtomicUsize = AtomicUsize::new(0);
+/// Feat labels by `feat.2da` row, read on first use
+static FEAT_LABELS: LazyLock<Mutex<Option<HashMap<u16, String>>>> =
+    LazyLock::new(|| Mutex::new(None));
+
+fn feat_label(feat: u16) -> String {
+    let mut labels = FEAT_LABELS.lock().unwrap();
+    let labels = labels.get_or_insert_with(|| match tables::read_table("feat") {
+        Ok(table) => (0..table.rows.len())
+            .filter_map(|row| Some((row as u16, table.get(row, "label")?.to_string())))
+            .collect(),
+        Err(e) => {
+            // Remember the failure, retrying every frame would flood the log
+            error!("Could not read feat names: {e}");
+            HashMap::new()
+        }
+    });
+
+    labels
+        .get(&feat)
+        .cloned()
+        .unwrap_or_else(|| format!("feat {feat}"))
+}
+
+fn sheet_lines(slot: usize) -> io::Result<Vec<String>> {
+    let sheet = stats::read_member(slot)?;
+
+    let mut lines = vec![format!(
+        "Party slot {slot}  HP {}/{}",
+        sheet.hit_points, sheet.max_hit_points
+    )];
+    let abilities: Vec<String> = ABILITIES
+        .iter()
+        .zip(sheet.abilities)
+        .map(|(name, score)| format!("{} {score}", name.to_ascii_uppercase()))
+        .collect();
+    lines.push(abilities.join("  "));
+    lines.extend(
+        SKILLS
+            .iter()
+            .zip(sheet.skills)
+            .map(|(name, rank)| format!("{name:<12} {rank:>3}")),
+    );
+
+    lines.push(format!("{} feats", sheet.feats.len()));
+    lines.extend(
+        sheet
+            .feats
+            .iter()
+            .take(SHOWN_FEATS)
+            .map(|&feat| format!("  {feat:>4} {}", feat_label(feat))),
+    );
+    if sheet.feats.len() > SHOWN_FEATS {
+        lines.push(format!("  and {} more", sheet.feats.len() - SHOWN_FEATS));
+    }
+    Ok(lines)
+}
+
+fn draw(painter: &mut Painter) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    // Nothing to show in the main menu
+    let Ok(lines) = sheet_lines(SLOT.load(Ordering::Relaxed)) else {
+        return;
+    };
+
+    let longest = lines.iter().map(String::len).max().unwrap_or_default();
+    let width = longest as f32 * FONT_WIDTH + 2.0 * PADDING;
+    let height = lines.len() as f32 * FONT_HEIGHT + 2.0 * PADDING;
+    let (x, y) = painter.anchor(POSITION.0, POSITION.1, width, height);
+
+    painter.fill_rect(x, y, width, height, theme::palette().background);
+    for (i, line) in lines.iter().enumerate() {
+        painter.text(
+            x + PADDING,
+            y + PADDING + i as f32 * FONT_HEIGHT,
+            line,
+            theme::palette().text,
+        );
+    }
+}
+
+pub fn sheet_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Shows or hides the sheet panel. Must be called on the main thread.
+pub fn set_sheet(enabled: bool) -> io::Result<()> {
+    if enabled {
+        // Fail early instead of silently drawing nothing
+        stats::read_member(SLOT.load(Ordering::Relaxed))?;
+    }
+
+    ENABLED.store(enabled, Ordering::Relaxed);
+    info!(
+        "Party sheet {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(())
+}
+
+/// Picks the party member the panel shows.
+pub fn set_slot(slot: usize) -> io::Result<()> {
+    if slot >= party::ACTIVE_PARTY_SIZE as usize {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Party slot has to be 0-{}", party::ACTIVE_PARTY_SIZE - 1),
+        ));
+    }
+
+    SLOT.store(slot, Ordering::Relaxed);
+    Ok(())
+}
+
+pub fn init() {
+    super::add_widget(draw);
+}
@@ -0,0 +1,193 @@
+/// Colors, opacity and font of the overlay, picked from presets with `overlay.theme`.
+///
+/// Widgets don't pick colors of their own, they draw with the roles of the current palette, e.g.
+/// `positive` for walkable walkmesh faces and toggles that are on. The default palette tells
+/// things apart by red and green, which a good share of players can't. The protanopia and
+/// deuteranopia presets use the Okabe-Ito colors instead, telling the same things apart by blue
+/// and orange.
+use std::{
+    io,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use log::{error, info};
+
+use crate::config::{config, Config};
+
+use super::Color;
+
+/// Colors by what they mean, every widget draws with these
+#[derive(Debug, Clone, Copy)]
+pub struct Palette {
+    pub text: Color,
+    /// Behind panels of text
+    pub background: Color,
+    /// Selection and things to look at, e.g. plot characters
+    pub highlight: Color,
+    /// Walkable, on and other good news
+    pub positive: Color,
+    /// Blocked, hostile, failed
+    pub negative: Color,
+    /// Dead, disabled and hints
+    pub muted: Color,
+    /// Triggers and other markers that just tell where something is
+    pub info: Color,
+    /// Encounters, doors and planned routes
+    pub caution: Color,
+    /// Where the route or a mark leads
+    pub target: Color,
+}
+
+pub struct Theme {
+    /// Value of `overlay.theme`
+    pub name: &'static str,
+    pub palette: Palette,
+    /// Monospaced font face, unless `overlay.font` is set
+    pub font: &'static str,
+}
+
+const fn opaque(r: f32, g: f32, b: f32) -> Color {
+    Color::rgba(r, g, b, 1.0)
+}
+
+const WHITE: Color = opaque(1.0, 1.0, 1.0);
+const GRAY: Color = opaque(0.7, 0.7, 0.7);
+const BACKGROUND: Color = Color::rgba(0.0, 0.0, 0.0, 0.6);
+
+// Okabe-Ito, lightened where too dark to read on the background
+const ORANGE: Color = opaque(0.9, 0.62, 0.0);
+const SKY_BLUE: Color = opaque(0.34, 0.71, 0.91);
+const BLUISH_GREEN: Color = opaque(0.0, 0.62, 0.45);
+const YELLOW: Color = opaque(0.94, 0.89, 0.26);
+const VERMILLION: Color = opaque(0.9, 0.4, 0.0);
+const REDDISH_PURPLE: Color = opaque(0.8, 0.47, 0.65);
+
+pub const THEMES: &[Theme] = &[
+    Theme {
+        name: "default",
+        palette: Palette {
+            text: WHITE,
+            background: BACKGROUND,
+            highlight: opaque(1.0, 0.8, 0.2),
+            positive: opaque(0.3, 1.0, 0.3),
+            negative: opaque(1.0, 0.3, 0.3),
+            muted: GRAY,
+            info: opaque(0.2, 0.6, 1.0),
+            caution: opaque(1.0, 0.6, 0.1),
+            target: opaque(1.0, 0.3, 1.0),
+        },
+        font: "Courier New",
+    },
+    Theme {
+        name: "protanopia",
+        palette: Palette {
+            text: WHITE,
+            background: BACKGROUND,
+            highlight: YELLOW,
+            positive: SKY_BLUE,
+            // Reds look dark to protanopes, orange stays readable
+            negative: ORANGE,
+            muted: GRAY,
+            info: BLUISH_GREEN,
+            caution: REDDISH_PURPLE,
+            target: opaque(0.6, 0.6, 1.0),
+        },
+        font: "Courier New",
+    },
+    Theme {
+        name: "deuteranopia",
+        palette: Palette {
+            text: WHITE,
+            background: BACKGROUND,
+            highlight: YELLOW,
+            positive: SKY_BLUE,
+            negative: VERMILLION,
+            muted: GRAY,
+            info: BLUISH_GREEN,
+            caution: ORANGE,
+            target: REDDISH_PURPLE,
+        },
+        font: "Courier New",
+    },
+    Theme {
+        name: "high_contrast",
+        palette: Palette {
+            text: WHITE,
+            background: Color::rgba(0.0, 0.0, 0.0, 0.9),
+            highlight: opaque(1.0, 1.0, 0.0),
+            positive: opaque(0.0, 1.0, 1.0),
+            negative: opaque(1.0, 0.2, 0.6),
+            muted: opaque(0.8, 0.8, 0.8),
+            info: opaque(0.4, 0.7, 1.0),
+            caution: opaque(1.0, 0.6, 0.0),
+            target: opaque(1.0, 0.4, 1.0),
+        },
+        font: "Lucida Console",
+    },
+];
+
+/// Index into `THEMES`
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+
+fn position(name: &str) -> io::Result<usize> {
+    THEMES
+        .iter()
+        .position(|theme| theme.name.eq_ignore_ascii_case(name))
+        .ok_or_else(|| {
+            let names: Vec<&str> = THEMES.iter().map(|theme| theme.name).collect();
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Unknown theme {name}, expected one of {}", names.join(", ")),
+            )
+        })
+}
+
+pub fn find(name: &str) -> io::Result<&'static Theme> {
+    Ok(&THEMES[position(name)?])
+}
+
+pub fn current() -> &'static Theme {
+    &THEMES[CURRENT.load(Ordering::Relaxed)]
+}
+
+/// Palette of the current theme.
+pub fn palette() -> &'static Palette {
+    &current().palette
+}
+
+pub fn set_theme(name: &str) -> io::Result<()> {
+    let index = position(name)?;
+    CURRENT.store(index, Ordering::Relaxed);
+    info!("Overlay theme set to {}", THEMES[index].name);
+    Ok(())
+}
+
+/// Switches to the configured theme, the default one if it's unknown.
+pub fn apply(config: &Config) {
+    if let Err(e) = set_theme(&config.overlay.theme) {
+        error!("Could not apply overlay theme: {e}");
+        CURRENT.store(0, Ordering::Relaxed);
+    }
+}
+
+pub fn init() {
+    apply(&config());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colorblind_presets_tell_positive_and_negative_apart() {
+        assert_eq!(find("Deuteranopia").unwrap().name, "deuteranopia");
+        assert!(find("sepia").is_err());
+
+        for name in ["protanopia", "deuteranopia"] {
+            let palette = find(name).unwrap().palette;
+            // Blue against red or orange, the axis both deficiencies still see
+            assert!(palette.positive.b > palette.positive.r, "{name}");
+            assert!(palette.negative.b < palette.negative.r, "{name}");
+        }
+    }
+}
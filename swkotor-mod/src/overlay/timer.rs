@@ -0,0 +1,55 @@
+/// Speedrun timer widget, shows RTA, IGT and the latest split.
+use std::time::Instant;
+
+use crate::config::config;
+use crate::speedrun::timer::{format_duration, TIMER};
+
+use super::painter::{FONT_HEIGHT, FONT_WIDTH};
+use super::{theme, Painter};
+
+const PADDING: f32 = 6.0;
+/// Room for `h:mm:ss.t` after the label
+const LINE_CHARACTERS: usize = 16;
+
+pub fn init() {
+    super::add_widget(draw);
+}
+
+fn draw(painter: &mut Painter) {
+    let position = {
+        let config = config();
+        if !config.timer.enabled {
+            return;
+        }
+        config.timer.position
+    };
+
+    let now = Instant::now();
+    let mut lines = Vec::with_capacity(3);
+    {
+        let timer = TIMER.lock().unwrap();
+        lines.push(format!("RTA {:>12}", format_duration(timer.rta(now))));
+        lines.push(format!("IGT {:>12}", format_duration(timer.igt(now))));
+        if let Some(split) = timer.splits().last() {
+            lines.push(format!(
+                "#{:<2} {:>12}",
+                timer.splits().len(),
+                format_duration(split.igt)
+            ));
+        }
+    }
+
+    let width = LINE_CHARACTERS as f32 * FONT_WIDTH + 2.0 * PADDING;
+    let height = lines.len() as f32 * FONT_HEIGHT + 2.0 * PADDING;
+    let (x, y) = painter.anchor(position.x, position.y, width, height);
+
+    painter.fill_rect(x, y, width, height, theme::palette().background);
+    for (i, line) in lines.iter().enumerate() {
+        painter.text(
+            x + PADDING,
+            y + PADDING + i as f32 * FONT_HEIGHT,
+            line,
+            theme::palette().text,
+        );
+    }
+}
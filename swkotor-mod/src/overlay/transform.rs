@@ -0,0 +1,83 @@
+/// Camera and controlled character transform widget, exact locations for bug reports.
+use std::{
+    io,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use log::info;
+
+use crate::config::config;
+use crate::engine::{camera, module, party};
+
+use super::painter::{FONT_HEIGHT, FONT_WIDTH};
+use super::{theme, Painter};
+
+const PADDING: f32 = 6.0;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Lines shown on the overlay and by the console command, also what gets copied.
+///
+/// Must be called on the main thread.
+pub fn describe() -> io::Result<Vec<String>> {
+    let module = module::entered_module().unwrap_or_else(|| "none".to_string());
+    let [x, y, z] = party::leader_position()?;
+    let facing = party::leader_facing()?;
+    let camera = camera::transform()?;
+    let [camera_x, camera_y, camera_z] = camera.position;
+
+    Ok(vec![
+        format!("Module {module}"),
+        format!("Player {x:.2} {y:.2} {z:.2} facing {facing:.1}"),
+        format!(
+            "Camera {camera_x:.2} {camera_y:.2} {camera_z:.2} yaw {:.1} pitch {:.1}",
+            camera.yaw(),
+            camera.pitch()
+        ),
+    ])
+}
+
+fn draw(painter: &mut Painter) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    // Nothing to show in the main menu and while loading
+    let Ok(lines) = describe() else {
+        return;
+    };
+
+    let position = config().transform.position;
+    let longest = lines.iter().map(String::len).max().unwrap_or_default();
+
+    let width = longest as f32 * FONT_WIDTH + 2.0 * PADDING;
+    let height = lines.len() as f32 * FONT_HEIGHT + 2.0 * PADDING;
+    let (x, y) = painter.anchor(position.x, position.y, width, height);
+
+    painter.fill_rect(x, y, width, height, theme::palette().background);
+    for (i, line) in lines.iter().enumerate() {
+        painter.text(
+            x + PADDING,
+            y + PADDING + i as f32 * FONT_HEIGHT,
+            line,
+            theme::palette().text,
+        );
+    }
+}
+
+pub fn transform_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub fn set_transform(enabled: bool) -> io::Result<()> {
+    ENABLED.store(enabled, Ordering::Relaxed);
+    info!(
+        "Transform overlay {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(())
+}
+
+pub fn init() {
+    ENABLED.store(config().transform.enabled, Ordering::Relaxed);
+    super::add_widget(draw);
+}
@@ -0,0 +1,197 @@
+/// Trigger volumes and encounter zones of the current area, drawn as translucent walls.
+///
+/// Volumes are read from the area's instance file (`.git`) when the area changes. They are flat
+/// polygons in the engine, the walls are drawn `VOLUME_HEIGHT` high to make them visible. Each
+/// kind is toggled separately.
+use std::{
+    io,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+use log::{error, info};
+
+use crate::engine::{
+    camera, module,
+    resources::{read_resource, ResourceType},
+    types::CResRef,
+};
+use crate::formats::gff::{Gff, GffStruct};
+
+use super::{theme, Painter};
+
+const VOLUME_HEIGHT: f32 = 2.0;
+
+const WALL_ALPHA: f32 = 0.2;
+const EDGE_ALPHA: f32 = 0.8;
+
+type Point = [f32; 3];
+
+#[derive(Default)]
+struct Volumes {
+    walls: Vec<[Point; 4]>,
+    edges: Vec<(Point, Point)>,
+}
+
+impl Volumes {
+    fn add_polygon(&mut self, polygon: &[Point]) {
+        for (i, &bottom_start) in polygon.iter().enumerate() {
+            let bottom_end = polygon[(i + 1) % polygon.len()];
+            let top = |[x, y, z]: Point| [x, y, z + VOLUME_HEIGHT];
+
+            self.walls
+                .push([bottom_start, bottom_end, top(bottom_end), top(bottom_start)]);
+            self.edges.push((bottom_start, bottom_end));
+            self.edges.push((top(bottom_start), top(bottom_end)));
+            self.edges.push((bottom_start, top(bottom_start)));
+        }
+    }
+}
+
+struct AreaVolumes {
+    module: String,
+    triggers: Volumes,
+    encounters: Volumes,
+}
+
+static TRIGGERS: AtomicBool = AtomicBool::new(false);
+static ENCOUNTERS: AtomicBool = AtomicBool::new(false);
+static VOLUMES: Mutex<Option<AreaVolumes>> = Mutex::new(None);
+
+/// Reads a volume's polygon in world coordinates. Geometry points are relative to the object's
+/// position, `point_labels` being the labels of the point coordinates.
+fn read_polygon(object: &GffStruct, point_labels: [&str; 3]) -> Vec<Point> {
+    let position = ["XPosition", "YPosition", "ZPosition"]
+        .map(|label| object.get_f32(label).unwrap_or_default());
+
+    object
+        .get_list("Geometry")
+        .iter()
+        .map(|point| {
+            let mut world = position;
+            for (coordinate, label) in world.iter_mut().zip(point_labels) {
+                *coordinate += point.get_f32(label).unwrap_or_default();
+            }
+            world
+        })
+        .collect()
+}
+
+/// Reads the volumes from the area instances. Areas are named after their module.
+fn load_area_volumes(module: &str) -> io::Result<AreaVolumes> {
+    let git = read_resource(&CResRef::new("area", module)?, ResourceType::Git)?;
+    let git = Gff::parse(&git)?;
+
+    let mut area = AreaVolumes {
+        module: module.to_string(),
+        triggers: Volumes::default(),
+        encounters: Volumes::default(),
+    };
+
+    for trigger in git.root.get_list("TriggerList") {
+        area.triggers
+            .add_polygon(&read_polygon(trigger, ["PointX", "PointY", "PointZ"]));
+    }
+
+    // TODO(tatu): Check encounter geometry is relative like the trigger one
+    for encounter in git.root.get_list("Encounter List") {
+        area.encounters
+            .add_polygon(&read_polygon(encounter, ["X", "Y", "Z"]));
+    }
+
+    info!(
+        "Loaded volumes of {module}, {} triggers and {} encounters",
+        git.root.get_list("TriggerList").len(),
+        git.root.get_list("Encounter List").len()
+    );
+    Ok(area)
+}
+
+fn draw(painter: &mut Painter) {
+    let triggers = TRIGGERS.load(Ordering::Relaxed);
+    let encounters = ENCOUNTERS.load(Ordering::Relaxed);
+    if !triggers && !encounters {
+        return;
+    }
+
+    // Nothing to draw in the main menu or while loading
+    let Some(module) = module::entered_module() else {
+        return;
+    };
+    let Ok(view_projection) = camera::view_projection() else {
+        return;
+    };
+
+    let mut volumes = VOLUMES.lock().unwrap();
+    if volumes.as_ref().is_none_or(|area| area.module != module) {
+        let area = load_area_volumes(&module).unwrap_or_else(|e| {
+            error!("Could not load volumes of {module}: {e}");
+            // Remember the failure, retrying every frame would flood the log
+            AreaVolumes {
+                module: module.clone(),
+                triggers: Volumes::default(),
+                encounters: Volumes::default(),
+            }
+        });
+        *volumes = Some(area);
+    }
+
+    let Some(area) = volumes.as_ref() else {
+        return;
+    };
+    let palette = theme::palette();
+    painter.world(&view_projection, |world| {
+        if triggers {
+            world.quads(&area.triggers.walls, palette.info.with_alpha(WALL_ALPHA));
+            world.lines(&area.triggers.edges, palette.info.with_alpha(EDGE_ALPHA));
+        }
+        if encounters {
+            world.quads(
+                &area.encounters.walls,
+                palette.caution.with_alpha(WALL_ALPHA),
+            );
+            world.lines(
+                &area.encounters.edges,
+                palette.caution.with_alpha(EDGE_ALPHA),
+            );
+        }
+    });
+}
+
+fn set_volume_kind(kind: &AtomicBool, name: &str, enabled: bool) -> io::Result<()> {
+    if enabled {
+        // Fail early instead of silently drawing nothing
+        camera::view_projection()?;
+    }
+
+    kind.store(enabled, Ordering::Relaxed);
+    info!(
+        "{name} overlay {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(())
+}
+
+pub fn triggers_enabled() -> bool {
+    TRIGGERS.load(Ordering::Relaxed)
+}
+
+/// Shows or hides trigger volumes. Must be called on the main thread.
+pub fn set_triggers(enabled: bool) -> io::Result<()> {
+    set_volume_kind(&TRIGGERS, "Trigger", enabled)
+}
+
+pub fn encounters_enabled() -> bool {
+    ENCOUNTERS.load(Ordering::Relaxed)
+}
+
+/// Shows or hides encounter zones. Must be called on the main thread.
+pub fn set_encounters(enabled: bool) -> io::Result<()> {
+    set_volume_kind(&ENCOUNTERS, "Encounter", enabled)
+}
+
+pub fn init() {
+    super::add_widget(draw);
+}
@@ -0,0 +1,131 @@
+/// Walkmesh wireframe of the current area, for out of bounds tricks and stuck spot reports.
+///
+/// Walkable edges are green and edges only non-walkable faces share are red. The walkmesh is read
+/// through the resource manager when the area changes, the same data the engine collides with.
+use std::{
+    io,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+use log::{error, info};
+
+use crate::engine::{
+    camera, module,
+    resources::{read_resource, ResourceType},
+    types::CResRef,
+};
+use crate::formats::{bwm::Walkmesh, lyt::Layout};
+
+use super::{theme, Painter};
+
+const EDGE_ALPHA: f32 = 0.6;
+
+type Segment = ([f32; 3], [f32; 3]);
+
+pub(super) struct AreaWalkmesh {
+    module: String,
+    pub(super) walkable: Vec<Segment>,
+    pub(super) blocked: Vec<Segment>,
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static WALKMESH: Mutex<Option<AreaWalkmesh>> = Mutex::new(None);
+
+/// Reads the walkmeshes of every room in the area. Areas are named after their module.
+fn load_area_walkmesh(module: &str) -> io::Result<AreaWalkmesh> {
+    let layout = read_resource(&CResRef::new("area", module)?, ResourceType::Lyt)?;
+    let layout = Layout::parse(&String::from_utf8_lossy(&layout))?;
+
+    let mut area = AreaWalkmesh {
+        module: module.to_string(),
+        walkable: Vec::new(),
+        blocked: Vec::new(),
+    };
+
+    for room in &layout.rooms {
+        let walkmesh = read_resource(&CResRef::new("room", &room.name)?, ResourceType::Wok)?;
+        for edge in Walkmesh::parse(&walkmesh)?.edges() {
+            let segment = (edge.start, edge.end);
+            if edge.walkable {
+                area.walkable.push(segment);
+            } else {
+                area.blocked.push(segment);
+            }
+        }
+    }
+
+    info!(
+        "Loaded walkmesh of {module}, {} rooms and {} edges",
+        layout.rooms.len(),
+        area.walkable.len() + area.blocked.len()
+    );
+    Ok(area)
+}
+
+/// Runs `f` with the walkmesh of `module`, loading it first when the area has changed.
+pub(super) fn with_area_walkmesh<R>(module: &str, f: impl FnOnce(&AreaWalkmesh) -> R) -> R {
+    let mut walkmesh = WALKMESH.lock().unwrap();
+    let area = match walkmesh.take() {
+        Some(area) if area.module == module => area,
+        _ => load_area_walkmesh(module).unwrap_or_else(|e| {
+            error!("Could not load walkmesh of {module}: {e}");
+            // Remember the failure, retrying every frame would flood the log
+            AreaWalkmesh {
+                module: module.to_string(),
+                walkable: Vec::new(),
+                blocked: Vec::new(),
+            }
+        }),
+    };
+    f(walkmesh.insert(area))
+}
+
+fn draw(painter: &mut Painter) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    // Nothing to draw in the main menu or while loading
+    let Some(module) = module::entered_module() else {
+        return;
+    };
+    let Ok(view_projection) = camera::view_projection() else {
+        return;
+    };
+
+    let palette = theme::palette();
+    with_area_walkmesh(&module, |area| {
+        painter.world(&view_projection, |world| {
+            world.lines(&area.walkable, palette.positive.with_alpha(EDGE_ALPHA));
+            world.lines(&area.blocked, palette.negative.with_alpha(EDGE_ALPHA));
+        });
+    });
+}
+
+pub fn walkmesh_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Shows or hides the walkmesh wireframe.
+///
+/// Must be called on the main thread.
+pub fn set_walkmesh(enabled: bool) -> io::Result<()> {
+    if enabled {
+        // Fail early instead of silently drawing nothing
+        camera::view_projection()?;
+    }
+
+    ENABLED.store(enabled, Ordering::Relaxed);
+    info!(
+        "Walkmesh overlay {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(())
+}
+
+pub fn init() {
+    super::add_widget(draw);
+}
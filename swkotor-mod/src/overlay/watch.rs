@@ -0,0 +1,129 @@
+/// Memory watch panel: values at addresses or symbols registered from the console, read every
+/// frame. A lightweight in-game stand-in for Cheat Engine when working out what engine state does.
+use std::{
+    io,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+use log::info;
+
+use crate::engine::watch::Watch;
+
+use super::painter::{FONT_HEIGHT, FONT_WIDTH};
+use super::{theme, Painter};
+
+const PADDING: f32 = 6.0;
+/// Right edge, above the minimap with its default position and size
+const POSITION: (i32, i32) = (-10, -220);
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static WATCHES: Mutex<Vec<Watch>> = Mutex::new(Vec::new());
+
+/// Line for every watch, with whether its value could be read.
+pub fn describe() -> Vec<(String, bool)> {
+    WATCHES
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|watch| {
+            let (value, readable) = match watch.read() {
+                Ok(value) => (value, true),
+                Err(e) => (format!("unreadable, {e}"), false),
+            };
+            (
+                format!(
+                    "{} {} {}: {value}",
+                    watch.name,
+                    watch.target,
+                    watch.kind.name()
+                ),
+                readable,
+            )
+        })
+        .collect()
+}
+
+fn draw(painter: &mut Painter) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let lines = describe();
+    if lines.is_empty() {
+        return;
+    }
+
+    let longest = lines
+        .iter()
+        .map(|(line, _)| line.len())
+        .max()
+        .unwrap_or_default();
+    let width = longest as f32 * FONT_WIDTH + 2.0 * PADDING;
+    let height = lines.len() as f32 * FONT_HEIGHT + 2.0 * PADDING;
+    let (x, y) = painter.anchor(POSITION.0, POSITION.1, width, height);
+
+    painter.fill_rect(x, y, width, height, theme::palette().background);
+    for (i, (line, readable)) in lines.iter().enumerate() {
+        painter.text(
+            x + PADDING,
+            y + PADDING + i as f32 * FONT_HEIGHT,
+            line,
+            if *readable {
+                theme::palette().text
+            } else {
+                theme::palette().negative
+            },
+        );
+    }
+}
+
+pub fn watch_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub fn set_watch(enabled: bool) -> io::Result<()> {
+    ENABLED.store(enabled, Ordering::Relaxed);
+    info!(
+        "Watch overlay {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(())
+}
+
+/// Adds a watch, replacing the one with the same name.
+pub fn add_watch(watch: Watch) {
+    info!(
+        "Watching {} at {} as {}",
+        watch.name,
+        watch.target,
+        watch.kind.name()
+    );
+    let mut watches = WATCHES.lock().unwrap();
+    match watches
+        .iter_mut()
+        .find(|existing| existing.name == watch.name)
+    {
+        Some(existing) => *existing = watch,
+        None => watches.push(watch),
+    }
+}
+
+pub fn remove_watch(name: &str) -> io::Result<()> {
+    let mut watches = WATCHES.lock().unwrap();
+    let count = watches.len();
+    watches.retain(|watch| watch.name != name);
+    if watches.len() == count {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("No watch named {name}"),
+        ));
+    }
+    Ok(())
+}
+
+pub fn init() {
+    super::add_widget(draw);
+}
@@ -0,0 +1,178 @@
+/// Third-party plugins, companion DLLs implementing `swkotor_mod_plugin::Plugin`.
+///
+/// Every DLL in the `plugins` directory of the game is loaded on the first frame, once the mod
+/// is out of `DllMain` and the features it hooks are up. Plugins draw on the overlay after the
+/// mod's own widgets, hear about module transitions and loads, and get the console commands the
+/// mod doesn't know. A plugin that panics is dropped and listed as disabled by `status`, the game
+/// and the other plugins keep running.
+///
+/// Plugin DLLs stay loaded until the game exits, also across hot reloads of the mod.
+use std::{
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    sync::Mutex,
+    time::Duration,
+};
+
+use log::{info, trace};
+use swkotor_mod_plugin::{Canvas, Event, Plugin, PluginDeclaration, API_VERSION};
+use windows::{
+    core::{s, HSTRING},
+    Win32::System::LibraryLoader::{GetProcAddress, LoadLibraryW},
+};
+
+use crate::engine::{loading, module};
+use crate::overlay::{self, Color, Painter, FONT_HEIGHT, FONT_WIDTH};
+use crate::system::status;
+
+pub const PLUGIN_DIRECTORY: &str = "plugins";
+
+static PLUGINS: Mutex<Vec<Box<dyn Plugin>>> = Mutex::new(Vec::new());
+
+fn overlay_color(color: swkotor_mod_plugin::Color) -> Color {
+    Color::rgba(color.r, color.g, color.b, color.a)
+}
+
+impl Canvas for Painter {
+    fn width(&self) -> f32 {
+        Painter::width(self)
+    }
+
+    fn height(&self) -> f32 {
+        Painter::height(self)
+    }
+
+    fn font_size(&self) -> (f32, f32) {
+        (FONT_WIDTH, FONT_HEIGHT)
+    }
+
+    fn fill_rect(
+        &mut self,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        color: swkotor_mod_plugin::Color,
+    ) {
+        Painter::fill_rect(self, x, y, width, height, overlay_color(color));
+    }
+
+    fn text(&mut self, x: f32, y: f32, text: &str, color: swkotor_mod_plugin::Color) {
+        Painter::text(self, x, y, text, overlay_color(color));
+    }
+}
+
+/// Loads the plugin DLL at `path` and creates its plugin.
+fn load_plugin(path: &Path) -> Result<Box<dyn Plugin>, String> {
+    let library = unsafe { LoadLibraryW(&HSTRING::from(path)) }.map_err(|e| e.to_string())?;
+    let Some(declaration) = (unsafe { GetProcAddress(library, s!("swkotor_mod_plugin")) }) else {
+        return Err("Not a plugin, it doesn't export swkotor_mod_plugin".to_string());
+    };
+    let declaration = unsafe { &*(declaration as *const PluginDeclaration) };
+
+    if declaration.api_version != API_VERSION {
+        return Err(format!(
+            "Built for plugin API {}, the mod speaks {API_VERSION}",
+            declaration.api_version
+        ));
+    }
+
+    panic::catch_unwind(declaration.create).map_err(|_| "Panicked on creation".to_string())
+}
+
+/// Runs `call` on every plugin, dropping the ones that panic.
+fn for_each_plugin(what: &str, mut call: impl FnMut(&mut dyn Plugin)) {
+    let mut plugins = PLUGINS.lock().unwrap();
+    plugins.retain_mut(|plugin| {
+        if panic::catch_unwind(AssertUnwindSafe(|| call(plugin.as_mut()))).is_ok() {
+            return true;
+        }
+        status::disable_feature(
+            &format!("plugin {}", plugin.name()),
+            format!("panicked in {what}, see the log"),
+        );
+        false
+    });
+}
+
+fn draw(painter: &mut Painter) {
+    for_each_plugin("on_frame", |plugin| plugin.on_frame(painter));
+}
+
+fn on_module_enter(module: &str) {
+    for_each_plugin("on_event", |plugin| {
+        plugin.on_event(&Event::ModuleEntered(module))
+    });
+}
+
+fn on_module_exit(module: &str) {
+    for_each_plugin("on_event", |plugin| {
+        plugin.on_event(&Event::ModuleExited(module))
+    });
+}
+
+fn on_load_finished(duration: Duration) {
+    for_each_plugin("on_event", |plugin| {
+        plugin.on_event(&Event::LoadFinished(duration))
+    });
+}
+
+/// Offers `command` to the plugins. Returns `None` if none of them took it.
+pub fn run_command(command: &str, args: &[&str]) -> Option<Result<String, String>> {
+    let mut output = None;
+    for_each_plugin("on_command", |plugin| {
+        if output.is_none() {
+            output = plugin.on_command(command, args);
+        }
+    });
+    output
+}
+
+/// Names of the loaded plugins.
+pub fn loaded() -> Vec<String> {
+    PLUGINS
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|plugin| plugin.name().to_string())
+        .collect()
+}
+
+/// Loads the plugins in the plugin directory and hooks them up.
+pub fn init() {
+    let entries = match fs::read_dir(PLUGIN_DIRECTORY) {
+        Ok(entries) => entries,
+        Err(e) => {
+            trace!("No plugins loaded from {PLUGIN_DIRECTORY}: {e}");
+            return;
+        }
+    };
+
+    let mut plugins = Vec::new();
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if !path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("dll"))
+        {
+            continue;
+        }
+        match load_plugin(&path) {
+            Ok(plugin) => {
+                info!("Loaded plugin {} from {}", plugin.name(), path.display());
+                plugins.push(plugin);
+            }
+            Err(e) => status::disable_feature(&format!("plugin {}", path.display()), e),
+        }
+    }
+
+    if plugins.is_empty() {
+        return;
+    }
+    PLUGINS.lock().unwrap().extend(plugins);
+
+    overlay::add_widget(draw);
+    module::on_module_enter(on_module_enter);
+    module::on_module_exit(on_module_exit);
+    loading::on_load_finished(on_load_finished);
+}
@@ -0,0 +1,227 @@
+/// Reading the saves in the game's `saves` directory, for comparing what was saved with the live
+/// game, and backing them up as they're written.
+///
+/// Every save is a directory of its own. Next to the `SAVEGAME.sav` archive it holds GFF files:
+/// `savenfo.res` with what the load menu shows, `GLOBALVARS.res` with the script globals and
+/// `PARTYTABLE.res` with the party and the journal. Those are all we need here.
+///
+/// The game is known to corrupt saves, every save it writes is copied to
+/// `save_backup.directory` with the latest `save_backup.keep` copies of each save kept. Backups
+/// are taken by the `save-backup` crate, laid out the same as the ones it takes of any save.
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use log::{error, info};
+use save_backup::Retention;
+
+use crate::config::config;
+use crate::engine::{globals, saves, snapshots::SNAPSHOT_SLOT};
+use crate::formats::gff::{Gff, GffStruct, GffValue};
+use crate::system::services;
+
+/// Relative to the game directory
+const SAVES_PATH: &str = "saves";
+const SAVE_INFO_FILE: &str = "savenfo.res";
+const GLOBALS_FILE: &str = "GLOBALVARS.res";
+const PARTY_TABLE_FILE: &str = "PARTYTABLE.res";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JournalEntry {
+    pub plot_id: String,
+    pub state: i32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SaveGame {
+    pub directory: PathBuf,
+    /// When the save was written
+    pub saved: SystemTime,
+    /// Name given in the save menu
+    pub name: String,
+    pub module: String,
+    pub booleans: Vec<(String, bool)>,
+    pub numbers: Vec<(String, u8)>,
+    pub journal: Vec<JournalEntry>,
+}
+
+/// A value that differs between a save and the live game
+#[derive(Debug, Clone, PartialEq)]
+pub struct Difference {
+    /// Global name or quest plot id
+    pub name: String,
+    pub saved: i64,
+    pub live: i64,
+}
+
+fn read_gff(directory: &Path, file: &str) -> io::Result<Gff> {
+    let path = directory.join(file);
+    Gff::parse(&fs::read(&path)?).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {e}", path.display()),
+        )
+    })
+}
+
+/// Names of a `Cat*` list in `GLOBALVARS.res`, in the order their values are stored
+fn global_names(root: &GffStruct, category: &str) -> Vec<String> {
+    root.get_list(category)
+        .iter()
+        .map(|global| global.get_str("Name").unwrap_or_default().to_string())
+        .collect()
+}
+
+fn global_values<'a>(root: &'a GffStruct, values: &str) -> &'a [u8] {
+    match root.get(values) {
+        Some(GffValue::Void(bytes)) => bytes,
+        _ => &[],
+    }
+}
+
+fn parse_booleans(root: &GffStruct) -> Vec<(String, bool)> {
+    // Packed eight to a byte, the first global in the most significant bit
+    let bits = global_values(root, "ValBoolean");
+    global_names(root, "CatBoolean")
+        .into_iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let byte = bits.get(i / 8).copied().unwrap_or_default();
+            (name, byte & (0x80 >> (i % 8)) != 0)
+        })
+        .collect()
+}
+
+fn parse_numbers(root: &GffStruct) -> Vec<(String, u8)> {
+    let values = global_values(root, "ValNumber");
+    global_names(root, "CatNumber")
+        .into_iter()
+        .enumerate()
+        .map(|(i, name)| (name, values.get(i).copied().unwrap_or_default()))
+        .collect()
+}
+
+fn parse_journal(root: &GffStruct) -> Vec<JournalEntry> {
+    root.get_list("JNL_Entries")
+        .iter()
+        .map(|entry| JournalEntry {
+            plot_id: entry.get_str("JNL_PlotID").unwrap_or_default().to_string(),
+            state: entry.get_i64("JNL_State").unwrap_or_default() as i32,
+        })
+        .collect()
+}
+
+/// Returns the directory of the most recently written save and when it was written.
+pub fn latest_save() -> io::Result<(PathBuf, SystemTime)> {
+    let mut latest: Option<(PathBuf, SystemTime)> = None;
+
+    for entry in fs::read_dir(SAVES_PATH)? {
+        let path = entry?.path();
+        // Skip anything that isn't a finished save, the engine writes the info file last
+        let Ok(metadata) = fs::metadata(path.join(SAVE_INFO_FILE)) else {
+            continue;
+        };
+        let modified = metadata.modified()?;
+        if latest.as_ref().is_none_or(|(_, newest)| modified > *newest) {
+            latest = Some((path, modified));
+        }
+    }
+
+    latest
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("No saves in {SAVES_PATH}")))
+}
+
+impl SaveGame {
+    pub fn load(directory: &Path) -> io::Result<SaveGame> {
+        let info = read_gff(directory, SAVE_INFO_FILE)?;
+        let globals = read_gff(directory, GLOBALS_FILE)?;
+        let journal = parse_journal(&read_gff(directory, PARTY_TABLE_FILE)?.root);
+
+        Ok(SaveGame {
+            directory: directory.to_path_buf(),
+            saved: fs::metadata(directory.join(SAVE_INFO_FILE))?.modified()?,
+            name: info
+                .root
+                .get_str("SAVEGAMENAME")
+                .unwrap_or_default()
+                .to_string(),
+            module: info
+                .root
+                .get_str("LASTMODULE")
+                .unwrap_or_default()
+                .to_string(),
+            booleans: parse_booleans(&globals.root),
+            numbers: parse_numbers(&globals.root),
+            journal,
+        })
+    }
+
+    /// Compares the saved globals and journal with the live game. Quests that were added to the
+    /// journal after saving don't show up, only what the save knows about is compared.
+    ///
+    /// Must be called on the main thread.
+    pub fn diff_live(&self) -> io::Result<Vec<Difference>> {
+        let mut differences = Vec::new();
+        let mut compare = |name: &str, saved: i64, live: i64| {
+            if saved != live {
+                differences.push(Difference {
+                    name: name.to_string(),
+                    saved,
+                    live,
+                });
+            }
+        };
+
+        for (name, saved) in &self.booleans {
+            compare(name, *saved as i64, globals::global_boolean(name)? as i64);
+        }
+        for (name, saved) in &self.numbers {
+            compare(name, *saved as i64, globals::global_number(name)? as i64);
+        }
+        for entry in &self.journal {
+            let live = globals::journal_state(&entry.plot_id)?;
+            compare(&entry.plot_id, entry.state as i64, live as i64);
+        }
+        Ok(differences)
+    }
+}
+
+fn backup_latest_save(slot: i32) {
+    // Snapshots are overwritten all the time on purpose
+    if slot == SNAPSHOT_SLOT {
+        return;
+    }
+
+    let (directory, keep) = {
+        let config = config();
+        (
+            config.save_backup.directory.clone(),
+            config.save_backup.keep,
+        )
+    };
+    // Copying a save takes a while, keep the frame short
+    let spawned = services::spawn("save-backup", move || {
+        let retention = Retention {
+            keep,
+            ..Retention::default()
+        };
+        let backed_up = latest_save()
+            .and_then(|(save, _)| save_backup::backup(&save, Path::new(&directory), &retention));
+        match backed_up {
+            Ok(path) => info!("Backed up save to {}", path.display()),
+            Err(e) => error!("Could not back up save: {e}"),
+        }
+    });
+    if let Err(e) = spawned {
+        error!("Could not start backing up the save: {e}");
+    }
+}
+
+/// Starts backing up saves when it's enabled in the config.
+pub fn init() {
+    if config().save_backup.enabled {
+        saves::on_game_saved(backup_latest_save);
+    }
+}
@@ -0,0 +1,136 @@
+/// Screenshots of the game's backbuffer.
+///
+/// Capturing is requested from any thread and done in the SwapBuffers hook, where the finished
+/// frame is still in the backbuffer. The pixels are read on the main thread, encoding and writing
+/// the PNG happens on a background thread to keep the frame short.
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use log::{error, info};
+use windows::Win32::Graphics::OpenGL::{
+    glGetIntegerv, glPixelStorei, glReadBuffer, glReadPixels, GL_BACK, GL_PACK_ALIGNMENT, GL_RGBA,
+    GL_UNSIGNED_BYTE, GL_VIEWPORT,
+};
+
+use crate::{config::config, system::services};
+
+static REQUESTED: AtomicBool = AtomicBool::new(false);
+
+static LAST_SAVED: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+static SAVED_LISTENERS: Mutex<Vec<fn(&Path)>> = Mutex::new(Vec::new());
+
+/// Takes a screenshot at the end of the next frame.
+pub fn request_screenshot() {
+    REQUESTED.store(true, Ordering::Relaxed);
+}
+
+/// Path of the screenshot saved last, `None` until one has been.
+pub fn last_screenshot() -> Option<PathBuf> {
+    LAST_SAVED.lock().unwrap().clone()
+}
+
+/// Registers `listener` to be called with the path of every screenshot saved. Listeners run on
+/// the thread saving the screenshot.
+pub fn on_screenshot_saved(listener: fn(&Path)) {
+    SAVED_LISTENERS.lock().unwrap().push(listener);
+}
+
+/// Captures the backbuffer if a screenshot has been requested. Called from the SwapBuffers hook
+/// both before and after drawing the overlay, `overlay_drawn` tells which. Capture happens on the
+/// call matching the `hide_overlay` setting.
+pub(crate) unsafe fn capture_if_requested(overlay_drawn: bool) {
+    if !REQUESTED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let (directory, hide_overlay) = {
+        let config = config();
+        (
+            config.screenshot.directory.clone(),
+            config.screenshot.hide_overlay,
+        )
+    };
+
+    if overlay_drawn == hide_overlay {
+        return;
+    }
+    REQUESTED.store(false, Ordering::Relaxed);
+
+    let (width, height, pixels) = read_backbuffer();
+    let spawned = services::spawn("screenshot", move || {
+        match save_png(Path::new(&directory), width, height, &pixels) {
+            Ok(path) => {
+                info!("Saved screenshot to {}", path.display());
+                *LAST_SAVED.lock().unwrap() = Some(path.clone());
+                let listeners = SAVED_LISTENERS.lock().unwrap().clone();
+                for listener in listeners {
+                    listener(&path);
+                }
+            }
+            Err(e) => error!("Could not save screenshot: {e}"),
+        }
+    });
+    if let Err(e) = spawned {
+        error!("Could not start saving the screenshot: {e}");
+    }
+}
+
+/// Reads the backbuffer as top to bottom RGBA rows.
+unsafe fn read_backbuffer() -> (u32, u32, Vec<u8>) {
+    let mut viewport = [0i32; 4];
+    glGetIntegerv(GL_VIEWPORT, viewport.as_mut_ptr());
+    let [x, y, width, height] = viewport;
+
+    let row_length = width as usize * 4;
+    let mut pixels = vec![0u8; row_length * height as usize];
+    glPixelStorei(GL_PACK_ALIGNMENT, 1);
+    glReadBuffer(GL_BACK);
+    glReadPixels(
+        x,
+        y,
+        width,
+        height,
+        GL_RGBA,
+        GL_UNSIGNED_BYTE,
+        pixels.as_mut_ptr() as *mut _,
+    );
+
+    // GL rows start from the bottom
+    let flipped = pixels
+        .chunks_exact(row_length)
+        .rev()
+        .flatten()
+        .copied()
+        .collect();
+
+    (width as u32, height as u32, flipped)
+}
+
+fn save_png(directory: &Path, width: u32, height: u32, pixels: &[u8]) -> io::Result<PathBuf> {
+    fs::create_dir_all(directory)?;
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    let path = directory.join(format!("swkotor-{timestamp}.png"));
+
+    let mut encoder = png::Encoder::new(BufWriter::new(File::create(&path)?), width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    writer.write_image_data(pixels).map_err(io::Error::other)?;
+    writer.finish().map_err(io::Error::other)?;
+
+    Ok(path)
+}
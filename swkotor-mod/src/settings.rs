@@ -0,0 +1,329 @@
+/// Editing the config from inside the game.
+///
+/// Every setting here is a key of `swkotor-mod.toml`. Changes are written to the file right away
+/// and put into effect where the mod can do that without a restart, the rest apply on next start.
+use std::error::Error;
+
+use log::error;
+
+use crate::audio::{self, Category};
+use crate::config::{config, update_config, Config};
+use crate::engine::frame;
+use crate::liveqa::commands::set_toggle;
+use crate::overlay::theme::{self, THEMES};
+
+pub enum Kind {
+    Flag {
+        get: fn(&Config) -> bool,
+        set: fn(&mut Config, bool),
+    },
+    Number {
+        get: fn(&Config) -> f32,
+        set: fn(&mut Config, f32),
+        /// Change per step in the menu
+        step: f32,
+        min: f32,
+        max: f32,
+    },
+    /// One of a fixed list of names
+    Choice {
+        get: fn(&Config) -> String,
+        set: fn(&mut Config, String),
+        /// In the order the menu steps through them
+        options: fn() -> Vec<&'static str>,
+    },
+}
+
+pub struct Setting {
+    /// Key in the config file
+    pub name: &'static str,
+    pub kind: Kind,
+    /// Puts the changed value into effect, for settings that are only read on startup
+    apply: Option<fn(&Config)>,
+}
+
+pub const SETTINGS: &[Setting] = &[
+    Setting {
+        name: "timer.enabled",
+        kind: Kind::Flag {
+            get: |config| config.timer.enabled,
+            set: |config, value| config.timer.enabled = value,
+        },
+        apply: None,
+    },
+    Setting {
+        name: "timer.split_on_module",
+        kind: Kind::Flag {
+            get: |config| config.timer.split_on_module,
+            set: |config, value| config.timer.split_on_module = value,
+        },
+        apply: None,
+    },
+    Setting {
+        name: "memory.enabled",
+        kind: Kind::Flag {
+            get: |config| config.memory.enabled,
+            set: |config, value| config.memory.enabled = value,
+        },
+        apply: Some(|config| apply_toggle("memory", config.memory.enabled)),
+    },
+    Setting {
+        name: "transform.enabled",
+        kind: Kind::Flag {
+            get: |config| config.transform.enabled,
+            set: |config, value| config.transform.enabled = value,
+        },
+        apply: Some(|config| apply_toggle("transform", config.transform.enabled)),
+    },
+    Setting {
+        name: "minimap.enabled",
+        kind: Kind::Flag {
+            get: |config| config.minimap.enabled,
+            set: |config, value| config.minimap.enabled = value,
+        },
+        apply: Some(|config| apply_toggle("minimap", config.minimap.enabled)),
+    },
+    Setting {
+        name: "minimap.size",
+        kind: Kind::Number {
+            get: |config| config.minimap.size as f32,
+            set: |config, value| config.minimap.size = value as u32,
+            step: 25.0,
+            min: 100.0,
+            max: 800.0,
+        },
+        apply: None,
+    },
+    Setting {
+        name: "minimap.zoom",
+        kind: Kind::Number {
+            get: |config| config.minimap.zoom,
+            set: |config, value| config.minimap.zoom = value,
+            step: 1.0,
+            min: 1.0,
+            max: 32.0,
+        },
+        apply: None,
+    },
+    Setting {
+        name: "overlay.theme",
+        kind: Kind::Choice {
+            get: |config| config.overlay.theme.clone(),
+            set: |config, value| config.overlay.theme = value,
+            options: || THEMES.iter().map(|theme| theme.name).collect(),
+        },
+        apply: Some(theme::apply),
+    },
+    Setting {
+        name: "overlay.opacity",
+        kind: Kind::Number {
+            get: |config| config.overlay.opacity,
+            set: |config, value| config.overlay.opacity = value,
+            step: 0.1,
+            min: 0.2,
+            max: 1.0,
+        },
+        apply: None,
+    },
+    Setting {
+        name: "screenshot.hide_overlay",
+        kind: Kind::Flag {
+            get: |config| config.screenshot.hide_overlay,
+            set: |config, value| config.screenshot.hide_overlay = value,
+        },
+        apply: None,
+    },
+    Setting {
+        name: "window.borderless",
+        kind: Kind::Flag {
+            get: |config| config.window.borderless,
+            set: |config, value| config.window.borderless = value,
+        },
+        apply: Some(|config| apply_toggle("borderless", config.window.borderless)),
+    },
+    Setting {
+        name: "display.gui_aspect_correction",
+        kind: Kind::Flag {
+            get: |config| config.display.gui_aspect_correction,
+            set: |config, value| config.display.gui_aspect_correction = value,
+        },
+        apply: Some(|config| apply_toggle("guiaspect", config.display.gui_aspect_correction)),
+    },
+    Setting {
+        name: "audio.music",
+        kind: Kind::Number {
+            get: |config| config.audio.music,
+            set: |config, value| config.audio.music = value,
+            step: 0.1,
+            min: 0.0,
+            max: audio::MAX_MULTIPLIER,
+        },
+        apply: Some(|config| apply_volume(Category::Music, config.audio.music)),
+    },
+    Setting {
+        name: "audio.voice",
+        kind: Kind::Number {
+            get: |config| config.audio.voice,
+            set: |config, value| config.audio.voice = value,
+            step: 0.1,
+            min: 0.0,
+            max: audio::MAX_MULTIPLIER,
+        },
+        apply: Some(|config| apply_volume(Category::Voice, config.audio.voice)),
+    },
+    Setting {
+        name: "audio.effects",
+        kind: Kind::Number {
+            get: |config| config.audio.effects,
+            set: |config, value| config.audio.effects = value,
+            step: 0.1,
+            min: 0.0,
+            max: audio::MAX_MULTIPLIER,
+        },
+        apply: Some(|config| apply_volume(Category::Effects, config.audio.effects)),
+    },
+    Setting {
+        name: "textures.dump",
+        kind: Kind::Flag {
+            get: |config| config.textures.dump,
+            set: |config, value| config.textures.dump = value,
+        },
+        apply: Some(|config| apply_toggle("texturedump", config.textures.dump)),
+    },
+    Setting {
+        name: "textures.replace",
+        kind: Kind::Flag {
+            get: |config| config.textures.replace,
+            set: |config, value| config.textures.replace = value,
+        },
+        apply: None,
+    },
+    Setting {
+        name: "gamepad.enabled",
+        kind: Kind::Flag {
+            get: |config| config.gamepad.enabled,
+            set: |config, value| config.gamepad.enabled = value,
+        },
+        apply: None,
+    },
+    Setting {
+        name: "websocket.enabled",
+        kind: Kind::Flag {
+            get: |config| config.websocket.enabled,
+            set: |config, value| config.websocket.enabled = value,
+        },
+        apply: None,
+    },
+    Setting {
+        name: "metrics.enabled",
+        kind: Kind::Flag {
+            get: |config| config.metrics.enabled,
+            set: |config, value| config.metrics.enabled = value,
+        },
+        apply: None,
+    },
+];
+
+fn apply_toggle(name: &str, enabled: bool) {
+    if let Err(e) = set_toggle(name, Some(enabled)) {
+        error!("Could not apply setting to {name}: {e}");
+    }
+}
+
+fn apply_volume(category: Category, multiplier: f32) {
+    frame::run_on_main_thread(move || {
+        if let Err(e) = audio::set_volume(category, multiplier) {
+            error!("Could not apply {category} volume: {e}");
+        }
+    });
+}
+
+pub fn find(name: &str) -> Result<&'static Setting, Box<dyn Error>> {
+    SETTINGS
+        .iter()
+        .find(|setting| setting.name == name)
+        .ok_or_else(|| format!("Unknown setting '{name}'").into())
+}
+
+/// Current value of the setting as it's written in the config file.
+pub fn value(setting: &Setting) -> String {
+    let config = config();
+    match setting.kind {
+        Kind::Flag { get, .. } => get(&config).to_string(),
+        Kind::Number { get, step, .. } => {
+            // As many decimals as the step has
+            let decimals = if step.fract() == 0.0 { 0 } else { 1 };
+            format!("{:.decimals$}", get(&config))
+        }
+        Kind::Choice { get, .. } => get(&config),
+    }
+}
+
+fn update(setting: &Setting, change: impl FnOnce(&mut Config)) -> Result<(), Box<dyn Error>> {
+    let written = update_config(change);
+    if let Some(apply) = setting.apply {
+        apply(&config());
+    }
+    Ok(written?)
+}
+
+/// Flips a flag, moves a number `steps` steps, clamped to its range, or steps through choices,
+/// wrapping around.
+pub fn adjust(setting: &Setting, steps: i32) -> Result<(), Box<dyn Error>> {
+    match setting.kind {
+        Kind::Flag { get, set } => update(setting, |config| set(config, !get(config))),
+        Kind::Number {
+            get,
+            set,
+            step,
+            min,
+            max,
+        } => update(setting, |config| {
+            let value = get(config) + steps as f32 * step;
+            // Keep repeated steps from piling up float error, e.g. 0.30000001
+            let value = (value / step).round() * step;
+            set(config, value.clamp(min, max))
+        }),
+        Kind::Choice { get, set, options } => update(setting, |config| {
+            let options = options();
+            let current = get(config);
+            let index = options
+                .iter()
+                .position(|option| option.eq_ignore_ascii_case(&current))
+                .unwrap_or_default();
+            let next = (index as i32 + steps).rem_euclid(options.len() as i32);
+            set(config, options[next as usize].to_string())
+        }),
+    }
+}
+
+/// Sets the setting from text, `true` or `false` for flags and one of the options for choices.
+pub fn set_from_str(setting: &Setting, text: &str) -> Result<(), Box<dyn Error>> {
+    match setting.kind {
+        Kind::Flag { set, .. } => {
+            let value: bool = text
+                .parse()
+                .map_err(|_| format!("Expected 'true' or 'false' for {}", setting.name))?;
+            update(setting, |config| set(config, value))
+        }
+        Kind::Number { set, min, max, .. } => {
+            let value: f32 = text.parse()?;
+            if !(min..=max).contains(&value) {
+                return Err(format!("{} must be between {min} and {max}", setting.name).into());
+            }
+            update(setting, |config| set(config, value))
+        }
+        Kind::Choice { set, options, .. } => {
+            let options = options();
+            let Some(value) = options
+                .iter()
+                .find(|option| option.eq_ignore_ascii_case(text))
+            else {
+                return Err(
+                    format!("{} must be one of {}", setting.name, options.join(", ")).into(),
+                );
+            };
+            update(setting, |config| set(config, value.to_string()))
+        }
+    }
+}
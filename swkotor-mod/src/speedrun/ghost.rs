@@ -0,0 +1,134 @@
+/// Ghost runs, a previous run played back next to the current one for comparison.
+///
+/// Runs are telemetry recordings, see `telemetry::recorder`. Only the time, module and position
+/// columns are used, frames without a position (main menu, loads) are skipped.
+use std::{
+    fs, io,
+    path::Path,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, Clone)]
+pub struct GhostSample {
+    /// Time since the recording started
+    pub time: Duration,
+    pub module: String,
+    pub position: [f32; 3],
+}
+
+#[derive(Debug, Clone)]
+pub struct GhostRun {
+    samples: Vec<GhostSample>,
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+impl GhostRun {
+    pub fn parse(csv: &str) -> io::Result<GhostRun> {
+        let mut lines = csv.lines();
+        let header: Vec<&str> = lines
+            .next()
+            .ok_or_else(|| invalid_data("Recording is empty".to_string()))?
+            .split(',')
+            .collect();
+        let column = |name: &str| {
+            header
+                .iter()
+                .position(|column| *column == name)
+                .ok_or_else(|| invalid_data(format!("Recording has no {name} column")))
+        };
+        let time = column("time_ms")?;
+        let module = column("module")?;
+        let coordinates = [column("x")?, column("y")?, column("z")?];
+
+        let mut samples = Vec::new();
+        for (i, line) in lines.enumerate() {
+            let values: Vec<&str> = line.split(',').collect();
+            if values.len() != header.len() {
+                return Err(invalid_data(format!(
+                    "Line {} has {} values, expected {}",
+                    i + 2,
+                    values.len(),
+                    header.len()
+                )));
+            }
+            if values[module].is_empty() || coordinates.iter().any(|&c| values[c].is_empty()) {
+                continue;
+            }
+
+            let parse = |index: usize| {
+                values[index]
+                    .parse::<f64>()
+                    .map_err(|e| invalid_data(format!("Line {}: {e}", i + 2)))
+            };
+            let mut position = [0.0; 3];
+            for (value, index) in position.iter_mut().zip(coordinates) {
+                *value = parse(index)? as f32;
+            }
+            samples.push(GhostSample {
+                time: Duration::from_secs_f64(parse(time)?.max(0.0) / 1000.0),
+                module: values[module].to_string(),
+                position,
+            });
+        }
+
+        if samples.is_empty() {
+            return Err(invalid_data(
+                "Recording has no frames with a position".to_string(),
+            ));
+        }
+        Ok(GhostRun { samples })
+    }
+
+    pub fn load(path: &Path) -> io::Result<GhostRun> {
+        GhostRun::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.samples
+            .last()
+            .map(|sample| sample.time)
+            .unwrap_or_default()
+    }
+
+    /// Where the ghost was `time` into the run, interpolated between frames in the same module.
+    /// `None` before the first frame with a position and once the run is over.
+    pub fn sample_at(&self, time: Duration) -> Option<GhostSample> {
+        let next = self.samples.partition_point(|sample| sample.time <= time);
+        let previous = self.samples.get(next.checked_sub(1)?)?;
+        let Some(next) = self.samples.get(next) else {
+            return (time == previous.time).then(|| previous.clone());
+        };
+
+        if next.module != previous.module {
+            return Some(previous.clone());
+        }
+
+        let span = (next.time - previous.time).as_secs_f32();
+        let t = if span > 0.0 {
+            (time - previous.time).as_secs_f32() / span
+        } else {
+            0.0
+        };
+        let mut position = previous.position;
+        for (value, next) in position.iter_mut().zip(next.position) {
+            *value += (next - *value) * t;
+        }
+        Some(GhostSample {
+            time,
+            module: previous.module.clone(),
+            position,
+        })
+    }
+}
+
+pub struct Ghost {
+    pub run: GhostRun,
+    /// When playback started, `None` while stopped
+    pub started: Option<Instant>,
+}
+
+pub static GHOST: Mutex<Option<Ghost>> = Mutex::new(None);
@@ -0,0 +1,4 @@
+pub mod ghost;
+pub mod plan;
+pub mod practice;
+pub mod timer;
@@ -0,0 +1,99 @@
+/// Route plans for speedrun practice, the intended path as waypoints per module.
+///
+/// Plans are TOML files with a `[[waypoint]]` table for each waypoint, in the order they're run:
+///
+/// ```toml
+/// [[waypoint]]
+/// module = "end_m01aa"
+/// position = [12.5, 40.0, 0.0]
+/// note = "Skip the locker"
+/// ```
+use std::{
+    fs, io,
+    path::Path,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+};
+
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+
+use crate::config::config;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlannedWaypoint {
+    pub module: String,
+    pub position: [f32; 3],
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub note: String,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RoutePlan {
+    #[serde(default, rename = "waypoint")]
+    pub waypoints: Vec<PlannedWaypoint>,
+}
+
+impl RoutePlan {
+    pub const fn new() -> Self {
+        RoutePlan {
+            waypoints: Vec::new(),
+        }
+    }
+
+    pub fn load(path: &Path) -> io::Result<RoutePlan> {
+        let contents = fs::read_to_string(path)?;
+        toml::from_str(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let contents = toml::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, contents)
+    }
+
+    /// Waypoints of `module` in route order.
+    pub fn in_module<'a>(
+        &'a self,
+        module: &'a str,
+    ) -> impl Iterator<Item = &'a PlannedWaypoint> + 'a {
+        self.waypoints
+            .iter()
+            .filter(move |waypoint| waypoint.module.eq_ignore_ascii_case(module))
+    }
+}
+
+pub static PLAN: Mutex<RoutePlan> = Mutex::new(RoutePlan::new());
+
+/// Bumped on every change to `PLAN`, so drawing knows to pick the changes up
+static REVISION: AtomicU64 = AtomicU64::new(0);
+
+pub fn revision() -> u64 {
+    REVISION.load(Ordering::Relaxed)
+}
+
+/// Changes the plan through `change`.
+pub fn modify<R>(change: impl FnOnce(&mut RoutePlan) -> R) -> R {
+    let result = change(&mut PLAN.lock().unwrap());
+    REVISION.fetch_add(1, Ordering::Relaxed);
+    result
+}
+
+/// Loads the plan set in the config, if any.
+pub fn init() {
+    let Some(file) = config().route_plan.file.clone() else {
+        return;
+    };
+
+    match RoutePlan::load(Path::new(&file)) {
+        Ok(loaded) => {
+            info!(
+                "Loaded route plan {file}, {} waypoints",
+                loaded.waypoints.len()
+            );
+            modify(|plan| *plan = loaded);
+        }
+        Err(e) => error!("Could not load route plan {file}: {e}"),
+    }
+}
@@ -0,0 +1,120 @@
+/// Practice points, for retrying a trick in place without reloading a save.
+///
+/// A practice point remembers the script globals, the local booleans of every object in the area
+/// and the party's hit points. Resetting puts back whatever changed since: triggers that already
+/// fired and conversations that already played are armed again, as both keep their "already
+/// happened" flags in those. Objects destroyed since the point was marked, e.g. triggers that
+/// remove themselves, can't be brought back, a snapshot is the way for those.
+///
+/// All functions must be called on the main thread.
+use std::{collections::HashMap, io, sync::Mutex};
+
+use log::info;
+
+use crate::engine::{
+    area,
+    journal::{self, GlobalKind},
+    module, party, stats,
+};
+
+struct PracticePoint {
+    module: String,
+    globals: Vec<(String, GlobalKind, i32)>,
+    locals: HashMap<u32, u64>,
+    /// By active party slot
+    hit_points: Vec<i16>,
+}
+
+/// What a reset changed back
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Restored {
+    pub globals: usize,
+    pub objects: usize,
+}
+
+static POINT: Mutex<Option<PracticePoint>> = Mutex::new(None);
+
+pub fn practice_enabled() -> bool {
+    POINT.lock().unwrap().is_some()
+}
+
+/// Marks a practice point where the game is now, replacing the previous one.
+pub fn mark() -> io::Result<()> {
+    let module = module::current_module()?.ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            "No module loaded, load a save first",
+        )
+    })?;
+    let globals = journal::global_catalog()?
+        .into_iter()
+        .map(|(name, kind)| {
+            let value = journal::global_value(&name, kind)?;
+            Ok((name, kind, value))
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+    let locals = area::local_booleans()?;
+    let hit_points = party::party_stats()?
+        .iter()
+        .map(|member| member.hit_points)
+        .collect();
+
+    info!(
+        "Marked practice point in {module}, {} globals and {} objects",
+        globals.len(),
+        locals.len()
+    );
+    *POINT.lock().unwrap() = Some(PracticePoint {
+        module,
+        globals,
+        locals,
+        hit_points,
+    });
+    Ok(())
+}
+
+/// Puts the globals, local booleans and hit points back to the practice point.
+pub fn reset() -> io::Result<Restored> {
+    let point = POINT.lock().unwrap();
+    let point = point.as_ref().ok_or_else(|| {
+        io::Error::new(io::ErrorKind::NotFound, "No practice point, mark one first")
+    })?;
+
+    // Object ids are only meaningful in the area they were read in
+    if module::current_module()?.as_deref() != Some(point.module.as_str()) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Practice point is in {}, go back there first", point.module),
+        ));
+    }
+
+    let mut restored = Restored::default();
+    for (name, kind, value) in &point.globals {
+        if journal::global_value(name, *kind)? != *value {
+            journal::set_global_value(name, *kind, *value)?;
+            restored.globals += 1;
+        }
+    }
+    restored.objects = area::set_local_booleans(&point.locals)?;
+    for (slot, &hit_points) in point.hit_points.iter().enumerate() {
+        stats::set_hit_points(slot, hit_points)?;
+    }
+
+    info!(
+        "Reset to the practice point, {} globals and {} objects changed back",
+        restored.globals, restored.objects
+    );
+    Ok(restored)
+}
+
+/// Marks a practice point when enabled, forgets it when disabled.
+pub fn set_practice(enabled: bool) -> io::Result<()> {
+    if enabled {
+        return mark();
+    }
+
+    if POINT.lock().unwrap().take().is_some() {
+        info!("Practice point cleared");
+    }
+    Ok(())
+}
@@ -0,0 +1,134 @@
+/// Speedrun timer keeping both real time (RTA) and in-game time (IGT).
+///
+/// IGT is real time with loads removed, load durations come from `engine::loading`.
+use std::{
+    io,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use log::info;
+
+use crate::config::config;
+use crate::engine::{loading, module};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Split {
+    pub rta: Duration,
+    pub igt: Duration,
+}
+
+#[derive(Debug, Default)]
+pub struct SpeedrunTimer {
+    started: Option<Instant>,
+    /// Time spent loading since start, subtracted from IGT
+    load_time: Duration,
+    splits: Vec<Split>,
+}
+
+impl SpeedrunTimer {
+    pub const fn new() -> Self {
+        SpeedrunTimer {
+            started: None,
+            load_time: Duration::ZERO,
+            splits: Vec::new(),
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.started.is_some()
+    }
+
+    pub fn start(&mut self, now: Instant) -> io::Result<()> {
+        if self.is_running() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                "Timer is already running, reset it first",
+            ));
+        }
+
+        self.started = Some(now);
+        Ok(())
+    }
+
+    pub fn split(&mut self, now: Instant) -> io::Result<Split> {
+        if !self.is_running() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotConnected,
+                "Timer is not running",
+            ));
+        }
+
+        let split = Split {
+            rta: self.rta(now),
+            igt: self.igt(now),
+        };
+        self.splits.push(split);
+        Ok(split)
+    }
+
+    pub fn reset(&mut self) {
+        *self = SpeedrunTimer::new();
+    }
+
+    /// Excludes `duration` from IGT, does nothing if the timer is not running.
+    pub fn add_load_time(&mut self, duration: Duration) {
+        if self.is_running() {
+            self.load_time += duration;
+        }
+    }
+
+    pub fn rta(&self, now: Instant) -> Duration {
+        self.started
+            .map(|started| now.saturating_duration_since(started))
+            .unwrap_or_default()
+    }
+
+    pub fn igt(&self, now: Instant) -> Duration {
+        self.rta(now).saturating_sub(self.load_time)
+    }
+
+    pub fn splits(&self) -> &[Split] {
+        &self.splits
+    }
+}
+
+pub static TIMER: Mutex<SpeedrunTimer> = Mutex::new(SpeedrunTimer::new());
+
+fn remove_load(duration: Duration) {
+    TIMER.lock().unwrap().add_load_time(duration);
+}
+
+fn split_on_module(module: &str) {
+    if !config().timer.split_on_module {
+        return;
+    }
+
+    // Nothing to split before the run has started
+    if let Ok(split) = TIMER.lock().unwrap().split(Instant::now()) {
+        info!(
+            "Split on entering {module} at {} IGT",
+            format_duration(split.igt)
+        );
+    }
+}
+
+/// Starts removing loads from IGT and splitting on module transitions.
+pub fn init() {
+    loading::on_load_finished(remove_load);
+    module::on_module_enter(split_on_module);
+}
+
+/// Formats as `h:mm:ss.t`, hours are left out when zero.
+pub fn format_duration(duration: Duration) -> String {
+    let tenths = duration.subsec_millis() / 100;
+    let seconds = duration.as_secs() % 60;
+    let minutes = (duration.as_secs() / 60) % 60;
+    let hours = duration.as_secs() / 3600;
+
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}.{tenths}")
+    } else {
+        format!("{minutes}:{seconds:02}.{tenths}")
+    }
+}
@@ -0,0 +1,39 @@
+/// StrRef resolution for diagnostics.
+///
+/// Engine data refers to text by StrRef, an index to `dialog.tlk`. Anything shown to a human
+/// should go through here so it reads "Power Attack" instead of 12345.
+use std::{path::Path, sync::LazyLock};
+
+use log::{error, info};
+
+use crate::engine::strings;
+use crate::formats::tlk::TalkTable;
+
+/// Relative to the game directory
+const DIALOG_TLK_PATH: &str = "dialog.tlk";
+
+static DIALOG_TLK: LazyLock<Option<TalkTable>> =
+    LazyLock::new(|| match TalkTable::load(Path::new(DIALOG_TLK_PATH)) {
+        Ok(table) => {
+            info!("Loaded {} strings from {DIALOG_TLK_PATH}", table.len());
+            Some(table)
+        }
+        Err(e) => {
+            error!("Could not load {DIALOG_TLK_PATH}, StrRefs won't be resolved: {e}");
+            None
+        }
+    });
+
+/// Returns the text for `strref` as the game shows it, replacements included. `None` if there's
+/// no such string or the table failed to load.
+pub fn lookup(strref: u32) -> Option<String> {
+    strings::replacement(strref).or_else(|| DIALOG_TLK.as_ref()?.get(strref))
+}
+
+/// Formats `strref` for diagnostics, `12345 "Power Attack"` or just the number when unknown.
+pub fn describe(strref: u32) -> String {
+    match lookup(strref) {
+        Some(text) => format!("{strref} {text:?}"),
+        None => strref.to_string(),
+    }
+}
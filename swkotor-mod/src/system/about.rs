@@ -0,0 +1,54 @@
+/// What's running: version of the mod, the game build and the features the config turns on.
+///
+/// Shown on the startup banner and written at the top of every log file, so a log or a screenshot
+/// attached to a bug report tells what it came from.
+use std::path::Path;
+
+use crate::config::{config, Config};
+use crate::engine::build::game_build;
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Features the config turns on, in config file order.
+fn features(config: &Config) -> Vec<&'static str> {
+    let audio = &config.audio;
+    [
+        (config.timer.enabled, "timer"),
+        (config.memory.enabled, "memory"),
+        (config.transform.enabled, "transform"),
+        (config.minimap.enabled, "minimap"),
+        (config.route_plan.file.is_some(), "route plan"),
+        (config.window.borderless, "borderless"),
+        (config.display.field_of_view.is_some(), "fov"),
+        (config.display.gui_aspect_correction, "gui aspect"),
+        (config.rng.seed.is_some(), "rng seed"),
+        (config.websocket.enabled, "websocket"),
+        (config.gamepad.enabled, "gamepad"),
+        (config.textures.dump, "texture dump"),
+        (config.textures.replace, "texture replace"),
+        (
+            audio.music != 1.0 || audio.voice != 1.0 || audio.effects != 1.0,
+            "volume",
+        ),
+        (Path::new(&config.strings.file).is_file(), "strings"),
+    ]
+    .into_iter()
+    .filter_map(|(enabled, name)| enabled.then_some(name))
+    .collect()
+}
+
+/// Lines describing the mod and the game it runs in.
+pub fn summary() -> Vec<String> {
+    let features = features(&config());
+    let features = if features.is_empty() {
+        "none".to_string()
+    } else {
+        features.join(", ")
+    };
+
+    vec![
+        format!("swkotor-mod {VERSION}"),
+        format!("Game build: {}", game_build()),
+        format!("Features: {features}"),
+    ]
+}
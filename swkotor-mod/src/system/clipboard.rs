@@ -0,0 +1,47 @@
+/// Copying text to the Windows clipboard, for pasting values from the console into bug reports.
+use std::io;
+
+use windows::Win32::{
+    Foundation::{GlobalFree, HANDLE},
+    System::{
+        DataExchange::{CloseClipboard, EmptyClipboard, OpenClipboard, SetClipboardData},
+        Memory::{GlobalAlloc, GlobalLock, GlobalUnlock, GMEM_MOVEABLE},
+    },
+};
+
+/// Standard clipboard format for UTF-16 text
+const CF_UNICODETEXT: u32 = 13;
+
+/// Replaces the clipboard contents with `text`.
+pub fn set_text(text: &str) -> io::Result<()> {
+    let wide: Vec<u16> = text.encode_utf16().chain([0]).collect();
+
+    unsafe {
+        let memory = GlobalAlloc(GMEM_MOVEABLE, wide.len() * 2)?;
+        let target = GlobalLock(memory) as *mut u16;
+        if target.is_null() {
+            let _ = GlobalFree(Some(memory));
+            return Err(io::Error::last_os_error());
+        }
+        std::ptr::copy_nonoverlapping(wide.as_ptr(), target, wide.len());
+        // Fails with "not locked" when the lock count drops to zero, which is what we want
+        let _ = GlobalUnlock(memory);
+
+        if let Err(e) = OpenClipboard(None) {
+            let _ = GlobalFree(Some(memory));
+            return Err(e.into());
+        }
+        let result = EmptyClipboard()
+            .and_then(|()| SetClipboardData(CF_UNICODETEXT, Some(HANDLE(memory.0))));
+        let _ = CloseClipboard();
+
+        match result {
+            // The clipboard owns the memory now
+            Ok(_) => Ok(()),
+            Err(e) => {
+                let _ = GlobalFree(Some(memory));
+                Err(e.into())
+            }
+        }
+    }
+}
@@ -0,0 +1,228 @@
+/// Hardware, driver and OS probe, logged on startup.
+///
+/// Most rendering bugs in KOTOR turn out to be down to a GPU driver, so every log starts with
+/// what the game renders on. Everything is read on the first frame: the registry reads and the
+/// adapter enumeration don't belong in `DllMain`, and OpenGL can only be asked once the game has
+/// a context current.
+use std::{
+    ffi::{c_char, CStr},
+    sync::OnceLock,
+};
+
+use log::{debug, info, warn};
+use windows::{
+    core::{s, PCSTR},
+    Win32::{
+        Foundation::ERROR_SUCCESS,
+        Graphics::{
+            Gdi::{EnumDisplayDevicesA, DISPLAY_DEVICEA, DISPLAY_DEVICE_PRIMARY_DEVICE},
+            OpenGL::{glGetString, GL_EXTENSIONS, GL_RENDERER, GL_VENDOR, GL_VERSION},
+        },
+        System::{
+            LibraryLoader::{GetModuleHandleA, GetProcAddress},
+            Registry::{RegGetValueA, HKEY_LOCAL_MACHINE, RRF_RT_REG_SZ},
+            SystemInformation::OSVERSIONINFOW,
+        },
+    },
+};
+
+use crate::engine::frame;
+
+/// Prefix of the driver keys display adapters report, what follows is a key under HKLM
+const MACHINE_KEY_PREFIX: &str = "\\registry\\machine\\";
+const WINDOWS_VERSION_KEY: &str = "SOFTWARE\\Microsoft\\Windows NT\\CurrentVersion";
+
+type RtlGetVersionFn = unsafe extern "system" fn(info: *mut OSVERSIONINFOW) -> i32;
+type WineGetVersionFn = unsafe extern "C" fn() -> *const c_char;
+
+#[derive(Debug, Clone)]
+pub struct OpenGlInfo {
+    pub vendor: String,
+    pub renderer: String,
+    /// Usually ends with the driver version, e.g. `4.6.0 NVIDIA 537.58`
+    pub version: String,
+    pub extensions: Vec<String>,
+}
+
+static OPENGL: OnceLock<OpenGlInfo> = OnceLock::new();
+
+/// Null terminated ANSI string out of a fixed size buffer.
+fn fixed_string(buffer: &[i8]) -> String {
+    let bytes: Vec<u8> = buffer
+        .iter()
+        .take_while(|&&c| c != 0)
+        .map(|&c| c as u8)
+        .collect();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Reads a string value under HKLM, `None` when it's not there.
+fn registry_string(key: &str, value: &str) -> Option<String> {
+    let key = format!("{key}\0");
+    let value = format!("{value}\0");
+    let mut buffer = [0u8; 256];
+    let mut size = buffer.len() as u32;
+
+    let result = unsafe {
+        RegGetValueA(
+            HKEY_LOCAL_MACHINE,
+            PCSTR::from_raw(key.as_ptr()),
+            PCSTR::from_raw(value.as_ptr()),
+            RRF_RT_REG_SZ,
+            None,
+            Some(buffer.as_mut_ptr().cast()),
+            Some(&mut size),
+        )
+    };
+    if result != ERROR_SUCCESS {
+        return None;
+    }
+
+    let text = CStr::from_bytes_until_nul(&buffer).ok()?;
+    Some(text.to_string_lossy().into_owned())
+}
+
+/// Windows version as the kernel reports it. GetVersionEx would report whatever compatibility
+/// mode the game runs in.
+fn os_version() -> Option<String> {
+    unsafe {
+        let ntdll = GetModuleHandleA(s!("ntdll.dll")).ok()?;
+        let rtl_get_version: RtlGetVersionFn =
+            std::mem::transmute(GetProcAddress(ntdll, s!("RtlGetVersion"))?);
+        let mut info = OSVERSIONINFOW {
+            dwOSVersionInfoSize: std::mem::size_of::<OSVERSIONINFOW>() as u32,
+            ..Default::default()
+        };
+        if rtl_get_version(&mut info) != 0 {
+            return None;
+        }
+        Some(format!(
+            "{}.{}.{}",
+            info.dwMajorVersion, info.dwMinorVersion, info.dwBuildNumber
+        ))
+    }
+}
+
+/// Wine version when running under Wine or Proton, which only Wine's ntdll exports.
+fn wine_version() -> Option<String> {
+    unsafe {
+        let ntdll = GetModuleHandleA(s!("ntdll.dll")).ok()?;
+        let wine_get_version: WineGetVersionFn =
+            std::mem::transmute(GetProcAddress(ntdll, s!("wine_get_version"))?);
+        Some(
+            CStr::from_ptr(wine_get_version())
+                .to_string_lossy()
+                .into_owned(),
+        )
+    }
+}
+
+fn log_os() {
+    // Older Windows have no DisplayVersion, e.g. 22H2
+    let name: Vec<String> = ["ProductName", "DisplayVersion"]
+        .into_iter()
+        .filter_map(|value| registry_string(WINDOWS_VERSION_KEY, value))
+        .collect();
+    let version = os_version().unwrap_or_else(|| "unknown version".to_string());
+    info!("OS: {} ({version})", name.join(" "));
+
+    if let Some(wine) = wine_version() {
+        info!("Running under Wine {wine}");
+    }
+}
+
+/// Driver version and date from the adapter's registry key.
+fn driver(device_key: &str) -> Option<String> {
+    let prefix = device_key.get(..MACHINE_KEY_PREFIX.len())?;
+    if !prefix.eq_ignore_ascii_case(MACHINE_KEY_PREFIX) {
+        return None;
+    }
+    let key = &device_key[MACHINE_KEY_PREFIX.len()..];
+
+    let version = registry_string(key, "DriverVersion")?;
+    let date = registry_string(key, "DriverDate").unwrap_or_default();
+    Some(format!("{version} {date}").trim_end().to_string())
+}
+
+/// Logs every display adapter with its driver, the primary one being what the game renders on.
+fn log_display_adapters() {
+    // Adapters are listed once for every output, log each one once
+    let mut logged: Vec<String> = Vec::new();
+    for index in 0.. {
+        let mut device = DISPLAY_DEVICEA {
+            cb: std::mem::size_of::<DISPLAY_DEVICEA>() as u32,
+            ..Default::default()
+        };
+        if !unsafe { EnumDisplayDevicesA(None, index, &mut device, 0) }.as_bool() {
+            break;
+        }
+
+        let key = fixed_string(&device.DeviceKey);
+        let primary = device.StateFlags.contains(DISPLAY_DEVICE_PRIMARY_DEVICE);
+        if logged.contains(&key) {
+            continue;
+        }
+
+        info!(
+            "GPU: {}{}, driver {}",
+            fixed_string(&device.DeviceString),
+            if primary { " (primary)" } else { "" },
+            driver(&key).as_deref().unwrap_or("unknown")
+        );
+        logged.push(key);
+    }
+
+    if logged.is_empty() {
+        warn!("No display adapters found");
+    }
+}
+
+/// Reads a `glGetString` value, empty if the driver has nothing to say.
+unsafe fn gl_string(name: u32) -> String {
+    let value = glGetString(name);
+    if value.is_null() {
+        return String::new();
+    }
+    CStr::from_ptr(value as *const c_char)
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Asks the current GL context what it is. Runs on the main thread from the SwapBuffers hook.
+fn probe_opengl() {
+    let info = unsafe {
+        OpenGlInfo {
+            vendor: gl_string(GL_VENDOR),
+            renderer: gl_string(GL_RENDERER),
+            version: gl_string(GL_VERSION),
+            extensions: gl_string(GL_EXTENSIONS)
+                .split_whitespace()
+                .map(str::to_string)
+                .collect(),
+        }
+    };
+
+    info!(
+        "OpenGL: {} by {}, version {}, {} extensions",
+        info.renderer,
+        info.vendor,
+        info.version,
+        info.extensions.len()
+    );
+    debug!("OpenGL extensions: {}", info.extensions.join(" "));
+    let _ = OPENGL.set(info);
+}
+
+/// What the game's GL context reported, `None` before the first frame.
+pub fn opengl() -> Option<&'static OpenGlInfo> {
+    OPENGL.get()
+}
+
+/// Logs the OS, display adapters and OpenGL on the first frame.
+pub fn probe() {
+    frame::run_on_main_thread(|| {
+        log_os();
+        log_display_adapters();
+        probe_opengl();
+    });
+}
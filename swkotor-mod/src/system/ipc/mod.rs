@@ -0,0 +1,172 @@
+/// IPC server for external tools, e.g. split timers and stream overlays.
+///
+/// Listens on a named pipe, every client gets its own pipe instance and thread. Clients write
+/// request lines and read one response line per request, see `protocol` for the requests. Lines
+/// starting with `{` are JSON requests. Pipe only accepts local clients.
+pub mod protocol;
+
+use std::{
+    ffi::CStr,
+    io::{self, BufRead, BufReader, Read, Write},
+};
+
+use log::{error, info, trace};
+use windows::{
+    core::PCSTR,
+    Win32::{
+        Foundation::{CloseHandle, ERROR_BROKEN_PIPE, ERROR_PIPE_CONNECTED, HANDLE},
+        Storage::FileSystem::{
+            CreateFileA, FlushFileBuffers, ReadFile, WriteFile, FILE_FLAGS_AND_ATTRIBUTES,
+            FILE_GENERIC_READ, FILE_GENERIC_WRITE, FILE_SHARE_NONE, OPEN_EXISTING,
+            PIPE_ACCESS_DUPLEX,
+        },
+        System::Pipes::{
+            ConnectNamedPipe, CreateNamedPipeA, DisconnectNamedPipe, PIPE_READMODE_BYTE,
+            PIPE_REJECT_REMOTE_CLIENTS, PIPE_TYPE_BYTE, PIPE_UNLIMITED_INSTANCES, PIPE_WAIT,
+        },
+    },
+};
+
+use super::services;
+
+pub const PIPE_NAME: &CStr = cr"\\.\pipe\swkotor-mod";
+
+const BUFFER_SIZE: u32 = 4096;
+
+/// Server end of a connected pipe instance. Disconnects the client and closes on drop.
+struct PipeConnection {
+    handle: HANDLE,
+}
+
+impl PipeConnection {
+    /// Creates a new pipe instance and blocks until a client connects to it.
+    fn accept() -> io::Result<PipeConnection> {
+        let handle = unsafe {
+            CreateNamedPipeA(
+                PCSTR(PIPE_NAME.as_ptr() as *const u8),
+                PIPE_ACCESS_DUPLEX,
+                PIPE_TYPE_BYTE | PIPE_READMODE_BYTE | PIPE_WAIT | PIPE_REJECT_REMOTE_CLIENTS,
+                PIPE_UNLIMITED_INSTANCES,
+                BUFFER_SIZE,
+                BUFFER_SIZE,
+                0,
+                None,
+            )?
+        };
+        let connection = PipeConnection { handle };
+
+        match unsafe { ConnectNamedPipe(handle, None) } {
+            Ok(()) => Ok(connection),
+            // Client connected between creating and connecting, that's fine
+            Err(e) if e.code() == ERROR_PIPE_CONNECTED.to_hresult() => Ok(connection),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+// Handle is owned by the connection and only used from one thread at a time
+unsafe impl Send for PipeConnection {}
+
+impl Read for &PipeConnection {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut read = 0;
+        match unsafe { ReadFile(self.handle, Some(buf), Some(&mut read), None) } {
+            Ok(()) => Ok(read as usize),
+            // Client hung up, same as end of file
+            Err(e) if e.code() == ERROR_BROKEN_PIPE.to_hresult() => Ok(0),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+impl Write for &PipeConnection {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut written = 0;
+        unsafe { WriteFile(self.handle, Some(buf), Some(&mut written), None)? };
+        Ok(written as usize)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        unsafe { FlushFileBuffers(self.handle)? };
+        Ok(())
+    }
+}
+
+impl Drop for PipeConnection {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = DisconnectNamedPipe(self.handle);
+            let _ = CloseHandle(self.handle);
+        }
+    }
+}
+
+fn serve_client(connection: PipeConnection) -> io::Result<()> {
+    let mut writer = &connection;
+    for line in BufReader::new(&connection).lines() {
+        let line = line?;
+        trace!("IPC request: {line}");
+        let response = if line.trim_start().starts_with('{') {
+            protocol::handle_json(&line)
+        } else {
+            protocol::handle_line(&line)
+        };
+        writeln!(writer, "{response}")?;
+        writer.flush()?;
+    }
+
+    trace!("IPC client disconnected");
+    Ok(())
+}
+
+/// Starts accepting IPC clients in a background thread.
+pub fn spawn_ipc_server() {
+    services::on_stop(wake_server);
+    let spawned = services::spawn("ipc", || {
+        info!(
+            "Listening for IPC clients on {}",
+            PIPE_NAME.to_string_lossy()
+        );
+        loop {
+            match PipeConnection::accept() {
+                Ok(_) if services::stopping() => break,
+                Ok(connection) => {
+                    let spawned = services::spawn("ipc-client", move || {
+                        if let Err(e) = serve_client(connection) {
+                            error!("IPC client failed: {e}");
+                        }
+                    });
+                    if let Err(e) = spawned {
+                        error!("Could not start an IPC client thread: {e}");
+                    }
+                }
+                Err(e) => {
+                    error!("Could not accept IPC clients, stopping the server: {e}");
+                    break;
+                }
+            }
+        }
+    });
+
+    if let Err(e) = spawned {
+        error!("Could not start the IPC server: {e}");
+    }
+}
+
+/// Connects to the pipe so the blocked accept returns and sees the stop.
+fn wake_server() {
+    let client = unsafe {
+        CreateFileA(
+            PCSTR(PIPE_NAME.as_ptr() as *const u8),
+            FILE_GENERIC_READ.0 | FILE_GENERIC_WRITE.0,
+            FILE_SHARE_NONE,
+            None,
+            OPEN_EXISTING,
+            FILE_FLAGS_AND_ATTRIBUTES(0),
+            None,
+        )
+    };
+    if let Ok(client) = client {
+        let _ = unsafe { CloseHandle(client) };
+    }
+}
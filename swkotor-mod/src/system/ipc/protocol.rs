@@ -0,0 +1,377 @@
+/// Request/response protocol spoken over IPC.
+///
+/// Requests are single lines of words, same as console commands. Responses are single lines too,
+/// `ok` followed by `key=value` pairs or `err` followed by a message. Values have their whitespace
+/// replaced with underscores.
+///
+/// ```text
+/// > module
+/// < ok module=end_m01aa
+/// > toggle noclip on
+/// < ok noclip=on
+/// > objects
+/// < ok file=swkotor-mod-area-end_m01aa-1700000000.json objects=84
+/// > loads
+/// < ok loads=2 load_ms=6120 module.end_m01aa.count=1 module.end_m01aa.mean_ms=4210 ...
+/// > frames
+/// < ok frames=240 ms=16.7,16.6,33.4,...
+/// > logs 120
+/// < ok last=122 line.121=[...] line.122=[...]
+/// > call global_number END_TRASK_DLG
+/// < ok result=1
+/// > warp somewhere
+/// < err Unknown request 'warp'
+/// ```
+///
+/// The same requests can be sent as JSON, e.g.
+/// `{"request": "toggle", "name": "noclip", "enabled": true}`. JSON responses are `{"ok": {...}}`
+/// with the same fields or `{"err": "message"}`. Over the named pipe a line starting with `{` is
+/// taken as a JSON request and answered in JSON.
+///
+/// Error messages and reasons in `status` responses have their whitespace replaced with
+/// underscores in JSON too, log lines keep theirs.
+use std::{error::Error, fmt, time::Duration, time::Instant};
+
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::engine::{bindings, build::game_build, frame, loading, module, party};
+use crate::liveqa::commands;
+use crate::overlay;
+use crate::speedrun::timer::TIMER;
+use crate::system::{about, log_writer, status};
+
+/// How long to wait for the main thread to answer before giving up on a request
+const MAIN_THREAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(tag = "request", rename_all = "lowercase")]
+pub enum Request {
+    /// Resref of the loaded module
+    Module,
+    /// Hit points and force points of the active party
+    Party,
+    /// Speedrun timer and load time state
+    Timer,
+    /// Hooks, last errors and overlay frame cost, see `status`
+    Status,
+    /// Writes the objects of the current area to a JSON file, see `area::write_dump`
+    Objects,
+    /// Load times of the session by module and every load, see `loading::history`
+    Loads,
+    /// Frame times of the last few seconds, see `frame::frame_times`
+    Frames,
+    /// Log lines numbered above `after`, every line still kept when left out
+    Logs {
+        #[serde(default)]
+        after: u64,
+    },
+    /// Sets a toggle from `commands::TOGGLES`, flips it when no state is given
+    Toggle {
+        name: String,
+        #[serde(default)]
+        enabled: Option<bool>,
+    },
+    /// Calls an engine function from `bindings::BINDINGS`
+    Call {
+        function: String,
+        #[serde(default)]
+        args: Vec<String>,
+    },
+}
+
+impl Request {
+    pub fn parse(line: &str) -> Result<Request, Box<dyn Error>> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words.as_slice() {
+            ["module"] => Ok(Request::Module),
+            ["party"] => Ok(Request::Party),
+            ["timer"] => Ok(Request::Timer),
+            ["status"] => Ok(Request::Status),
+            ["objects"] => Ok(Request::Objects),
+            ["loads"] => Ok(Request::Loads),
+            ["frames"] => Ok(Request::Frames),
+            ["logs"] => Ok(Request::Logs { after: 0 }),
+            ["logs", after] => Ok(Request::Logs {
+                after: after
+                    .parse()
+                    .map_err(|_| format!("Invalid line number '{after}'"))?,
+            }),
+            ["toggle", name, state @ ..] => {
+                let enabled = match state {
+                    [] => None,
+                    ["on"] => Some(true),
+                    ["off"] => Some(false),
+                    _ => return Err("Expected 'on', 'off' or nothing to toggle".into()),
+                };
+                Ok(Request::Toggle {
+                    name: name.to_string(),
+                    enabled,
+                })
+            }
+            ["call", function, args @ ..] => Ok(Request::Call {
+                function: function.to_string(),
+                args: args.iter().map(|arg| arg.to_string()).collect(),
+            }),
+            [name, ..] => Err(format!("Unknown request '{name}'").into()),
+            [] => Err("Empty request".into()),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Response {
+    pub fields: Vec<(String, String)>,
+}
+
+impl Response {
+    fn field(mut self, key: impl Into<String>, value: impl fmt::Display) -> Self {
+        self.fields.push((key.into(), value.to_string()));
+        self
+    }
+}
+
+impl fmt::Display for Response {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ok")?;
+        for (key, value) in &self.fields {
+            write!(f, " {key}={}", without_spaces(value))?;
+        }
+        Ok(())
+    }
+}
+
+fn on_off(enabled: bool) -> &'static str {
+    if enabled {
+        "on"
+    } else {
+        "off"
+    }
+}
+
+fn without_spaces(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join("_")
+}
+
+pub fn handle(request: Request) -> Result<Response, Box<dyn Error>> {
+    match request {
+        Request::Module => {
+            let module = frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, module::current_module)??;
+            // Empty value while no module is loaded, e.g. in the main menu
+            Ok(Response::default().field("module", module.unwrap_or_default()))
+        }
+        Request::Party => {
+            let members = frame::call_on_main_thread(MAIN_THREAD_TIMEOUT, party::party_stats)??;
+            let mut response = Response::default().field("members", members.len());
+            for member in members {
+                let slot = member.slot;
+                response = response
+                    .field(format!("{slot}.hp"), member.hit_points)
+                    .field(format!("{slot}.max_hp"), member.max_hit_points)
+                    .field(format!("{slot}.fp"), member.force_points)
+                    .field(format!("{slot}.max_fp"), member.max_force_points);
+            }
+            Ok(response)
+        }
+        Request::Timer => {
+            let now = Instant::now();
+            let timer = TIMER.lock().unwrap();
+            let loads = loading::stats();
+            Ok(Response::default()
+                .field("running", timer.is_running())
+                .field("rta_ms", timer.rta(now).as_millis())
+                .field("igt_ms", timer.igt(now).as_millis())
+                .field("splits", timer.splits().len())
+                .field("loading", loading::is_loading())
+                .field("loads", loads.count)
+                .field("load_ms", loads.total.as_millis()))
+        }
+        Request::Status => {
+            let cost = overlay::frame_cost();
+            let mut response = Response::default()
+                .field("version", about::VERSION)
+                .field("build", game_build())
+                .field("overlay_us", cost.last.as_micros())
+                .field("overlay_avg_us", cost.average.as_micros())
+                .field("overlay_worst_us", cost.worst.as_micros());
+            for (name, state) in status::hooks() {
+                response = response.field(format!("hook.{name}"), state);
+            }
+            for (feature, reason) in status::disabled_features() {
+                let key = format!("disabled.{}", without_spaces(&feature));
+                response = response.field(key, without_spaces(&reason));
+            }
+            for (subsystem, message, _) in status::last_errors() {
+                response = response.field(format!("error.{subsystem}"), without_spaces(&message));
+            }
+            Ok(response)
+        }
+        Request::Objects => {
+            let (path, count) = commands::dump_area_objects()?;
+            Ok(Response::default()
+                .field("file", without_spaces(&path))
+                .field("objects", count))
+        }
+        Request::Loads => {
+            let stats = loading::stats();
+            let mut response = Response::default()
+                .field("loads", stats.count)
+                .field("load_ms", stats.total.as_millis());
+            // Loads ending outside a module, e.g. in the main menu, have no module name
+            let name = |module: &str| {
+                if module.is_empty() {
+                    "-".to_string()
+                } else {
+                    module.to_string()
+                }
+            };
+            for (module, stats) in loading::stats_by_module() {
+                let module = name(&module);
+                response = response
+                    .field(format!("module.{module}.count"), stats.count)
+                    .field(format!("module.{module}.mean_ms"), stats.mean().as_millis())
+                    .field(
+                        format!("module.{module}.worst_ms"),
+                        stats.longest.as_millis(),
+                    );
+            }
+            for (i, load) in loading::history().iter().enumerate() {
+                response = response
+                    .field(format!("load.{i}.module"), name(&load.module))
+                    .field(format!("load.{i}.ms"), load.duration.as_millis());
+            }
+            Ok(response)
+        }
+        Request::Frames => {
+            let times = frame::frame_times();
+            let milliseconds: Vec<String> = times
+                .iter()
+                .map(|time| format!("{:.1}", time.as_secs_f64() * 1000.0))
+                .collect();
+            Ok(Response::default()
+                .field("frames", times.len())
+                .field("ms", milliseconds.join(",")))
+        }
+        Request::Logs { after } => {
+            let (lines, last) = log_writer::recent_lines(after);
+            let mut response = Response::default().field("last", last);
+            for (number, line) in lines {
+                response = response.field(format!("line.{number}"), line);
+            }
+            Ok(response)
+        }
+        Request::Toggle { name, enabled } => {
+            let enabled = commands::set_toggle(&name, enabled)?;
+            Ok(Response::default().field(name, on_off(enabled)))
+        }
+        Request::Call { function, args } => {
+            let args: Vec<&str> = args.iter().map(String::as_str).collect();
+            let result = bindings::call(&function, &args)?;
+            Ok(Response::default().field("result", without_spaces(&result)))
+        }
+    }
+}
+
+/// Parses and handles a single request line, returning the response line.
+pub fn handle_line(line: &str) -> String {
+    match Request::parse(line).and_then(handle) {
+        Ok(response) => response.to_string(),
+        // Keep the response on a single line
+        Err(e) => format!("err {}", e.to_string().replace('\n', " ")),
+    }
+}
+
+/// JSON flavor of `handle_line`.
+pub fn handle_json(text: &str) -> String {
+    let result = serde_json::from_str::<Request>(text)
+        .map_err(Box::<dyn Error>::from)
+        .and_then(handle);
+
+    let response = match result {
+        Ok(response) => {
+            let fields: serde_json::Map<String, serde_json::Value> = response
+                .fields
+                .into_iter()
+                .map(|(key, value)| (key, value.into()))
+                .collect();
+            json!({ "ok": fields })
+        }
+        Err(e) => json!({ "err": e.to_string() }),
+    };
+    response.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_line_requests() {
+        assert_eq!(Request::parse("module").unwrap(), Request::Module);
+        assert_eq!(Request::parse("objects").unwrap(), Request::Objects);
+        assert_eq!(Request::parse("loads").unwrap(), Request::Loads);
+        assert_eq!(Request::parse("frames").unwrap(), Request::Frames);
+        assert_eq!(
+            Request::parse("logs 120").unwrap(),
+            Request::Logs { after: 120 }
+        );
+        assert_eq!(
+            Request::parse("toggle noclip on").unwrap(),
+            Request::Toggle {
+                name: "noclip".to_string(),
+                enabled: Some(true),
+            }
+        );
+        assert_eq!(
+            Request::parse("call give_item g_w_blstrpstl001 2").unwrap(),
+            Request::Call {
+                function: "give_item".to_string(),
+                args: vec!["g_w_blstrpstl001".to_string(), "2".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_bad_line_requests() {
+        assert!(Request::parse("").is_err());
+        assert!(Request::parse("warp end_m01aa").is_err());
+        assert!(Request::parse("toggle noclip sideways").is_err());
+        assert!(Request::parse("logs latest").is_err());
+    }
+
+    #[test]
+    fn parses_json_requests() {
+        let request: Request =
+            serde_json::from_str(r#"{"request": "toggle", "name": "noclip"}"#).unwrap();
+        assert_eq!(
+            request,
+            Request::Toggle {
+                name: "noclip".to_string(),
+                enabled: None,
+            }
+        );
+
+        let request: Request = serde_json::from_str(
+            r#"{"request": "call", "function": "play_movie", "args": ["01a"]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            request,
+            Request::Call {
+                function: "play_movie".to_string(),
+                args: vec!["01a".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn formats_responses_on_one_line() {
+        let response = Response::default()
+            .field("module", "end_m01aa")
+            .field("loads", 2);
+        assert_eq!(response.to_string(), "ok module=end_m01aa loads=2");
+        let response = Response::default().field("line.1", "[INFO] Loaded config");
+        assert_eq!(response.to_string(), "ok line.1=[INFO]_Loaded_config");
+        assert_eq!(without_spaces("No module\nloaded"), "No_module_loaded");
+    }
+}
@@ -0,0 +1,189 @@
+/// Asynchronous log file writer.
+///
+/// Logging from hooks runs on the game's main thread, a slow disk must not hitch a frame. Writes
+/// only copy the formatted record into a bounded ring buffer, a background thread drains it to the
+/// log file. When the buffer is full the oldest records are dropped and a note about it is written
+/// in their place.
+///
+/// Log file is rotated once it grows past `MAX_LOG_SIZE`, `swkotor-mod.log` becomes
+/// `swkotor-mod.1.log` and so on, the same way the CLIs rotate theirs. Only `KEPT_LOGS` old files
+/// are kept. Every new file starts with the header, if one has been set.
+///
+/// The last few hundred records are also kept in memory for `recent_lines`, for tools following
+/// the log over IPC.
+use std::{
+    collections::VecDeque,
+    io::{self, Write},
+    path::Path,
+    sync::{Arc, Condvar, Mutex},
+    time::Duration,
+};
+
+use molokki_common::logging::RotatingFile;
+
+use super::services;
+
+pub const MAX_LOG_SIZE: u64 = 10 * 1024 * 1024;
+pub const KEPT_LOGS: usize = 3;
+
+/// Records buffered before dropping the oldest ones
+const BUFFER_CAPACITY: usize = 4096;
+
+/// How often an idle writer checks if services are stopping
+const STOP_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Records kept for `recent_lines`
+const RECENT_CAPACITY: usize = 500;
+
+/// Latest records, numbered from 1 in the order they were logged
+struct Recent {
+    /// Number the next record gets
+    next: u64,
+    lines: VecDeque<String>,
+}
+
+static RECENT: Mutex<Recent> = Mutex::new(Recent {
+    next: 1,
+    lines: VecDeque::new(),
+});
+
+fn keep_recent(record: &[u8]) {
+    let mut recent = RECENT.lock().unwrap();
+    if recent.lines.len() >= RECENT_CAPACITY {
+        recent.lines.pop_front();
+    }
+    let line = String::from_utf8_lossy(record).trim_end().to_string();
+    recent.lines.push_back(line);
+    recent.next += 1;
+}
+
+/// Kept records numbered above `after` with their numbers, and the number of the latest record
+/// to ask after next time. Records that have dropped out of memory are skipped.
+pub fn recent_lines(after: u64) -> (Vec<(u64, String)>, u64) {
+    let recent = RECENT.lock().unwrap();
+    let first = recent.next - recent.lines.len() as u64;
+    let lines = recent
+        .lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| (first + i as u64, line.clone()))
+        .filter(|(number, _)| *number > after)
+        .collect();
+    (lines, recent.next - 1)
+}
+
+#[derive(Default)]
+struct Buffer {
+    records: VecDeque<Vec<u8>>,
+    /// Records dropped since the writer last ran
+    dropped: usize,
+}
+
+struct Shared {
+    buffer: Mutex<Buffer>,
+    /// Header set since the writer last ran, for the writer to put at the top of rotated files
+    header: Mutex<Option<Vec<u8>>>,
+    not_empty: Condvar,
+}
+
+/// Cheap handle passed to the logger, writes never touch the disk.
+#[derive(Clone)]
+pub struct AsyncLogWriter {
+    shared: Arc<Shared>,
+}
+
+impl AsyncLogWriter {
+    /// Opens the log at `path`, rotating the previous log away, and starts the writer thread.
+    pub fn spawn(path: &Path) -> io::Result<AsyncLogWriter> {
+        let file = RotatingFile::create(path, MAX_LOG_SIZE, KEPT_LOGS)?;
+
+        let shared = Arc::new(Shared {
+            buffer: Mutex::new(Buffer::default()),
+            header: Mutex::new(None),
+            not_empty: Condvar::new(),
+        });
+
+        let writer = FileWriter { file };
+        let thread_shared = shared.clone();
+        services::spawn("log-writer", move || writer.run(&thread_shared))?;
+
+        Ok(AsyncLogWriter { shared })
+    }
+
+    /// Writes `header` to the log and at the top of every file the log rotates to.
+    pub fn set_header(&mut self, header: &str) -> io::Result<()> {
+        *self.shared.header.lock().unwrap() = Some(header.as_bytes().to_vec());
+        self.write_all(header.as_bytes())
+    }
+}
+
+impl Write for AsyncLogWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        {
+            let mut buffer = self.shared.buffer.lock().unwrap();
+            if buffer.records.len() >= BUFFER_CAPACITY {
+                buffer.records.pop_front();
+                buffer.dropped += 1;
+            }
+            buffer.records.push_back(buf.to_vec());
+        }
+        keep_recent(buf);
+        self.shared.not_empty.notify_one();
+        Ok(buf.len())
+    }
+
+    /// Does not wait for the writer thread, flushing would block the caller on the disk.
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+struct FileWriter {
+    file: RotatingFile,
+}
+
+impl FileWriter {
+    /// Drains the buffer until services are stopped, records logged after that are lost.
+    fn run(mut self, shared: &Shared) {
+        loop {
+            let (records, dropped) = {
+                let mut buffer = shared.buffer.lock().unwrap();
+                while buffer.records.is_empty() {
+                    if services::stopping() {
+                        return;
+                    }
+                    buffer = shared
+                        .not_empty
+                        .wait_timeout(buffer, STOP_POLL_INTERVAL)
+                        .unwrap()
+                        .0;
+                }
+                (
+                    std::mem::take(&mut buffer.records),
+                    std::mem::take(&mut buffer.dropped),
+                )
+            };
+            if let Some(header) = shared.header.lock().unwrap().take() {
+                self.file.set_header(header);
+            }
+
+            // Nowhere to log our own errors to, stderr is the best we can do
+            if dropped > 0 {
+                let note = format!("[log-writer] Dropped {dropped} log records, buffer was full\n");
+                if let Err(e) = self.file.write_all(note.as_bytes()) {
+                    eprintln!("Could not write log: {e}");
+                }
+            }
+
+            for record in records {
+                if let Err(e) = self.file.write_all(&record) {
+                    eprintln!("Could not write log: {e}");
+                }
+            }
+
+            if let Err(e) = self.file.flush() {
+                eprintln!("Could not flush log: {e}");
+            }
+        }
+    }
+}
@@ -0,0 +1,143 @@
+/// Process memory sampling, to track down crashes from leaks on long sessions.
+///
+/// A background thread samples the process counters and the heaps once a second. The heap totals
+/// cover every heap in the process, the game's allocator included, as the CRT allocates from a
+/// Windows heap.
+use std::{
+    io,
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+use log::error;
+use windows::Win32::{
+    Foundation::HANDLE,
+    System::{
+        Memory::{GetProcessHeaps, HeapSummary, HEAP_SUMMARY},
+        ProcessStatus::{
+            GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS, PROCESS_MEMORY_COUNTERS_EX,
+        },
+        Threading::GetCurrentProcess,
+    },
+};
+
+use super::services;
+
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MemorySample {
+    pub working_set: usize,
+    pub peak_working_set: usize,
+    /// Private bytes committed by the process
+    pub commit: usize,
+    pub heaps: usize,
+    /// Bytes in use in the heaps
+    pub heap_allocated: usize,
+    pub heap_committed: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MemoryStats {
+    pub latest: MemorySample,
+    /// Commit at the first sample, to see growth over the session
+    pub first_commit: usize,
+    pub started: Instant,
+}
+
+impl MemoryStats {
+    /// Commit growth since the first sample in bytes, negative when it has shrunk.
+    pub fn commit_growth(&self) -> isize {
+        self.latest.commit as isize - self.first_commit as isize
+    }
+}
+
+static STATS: Mutex<Option<MemoryStats>> = Mutex::new(None);
+
+fn sample_heaps(sample: &mut MemorySample) {
+    unsafe {
+        // Heaps come and go, ask again if more were created in between
+        let mut heaps = vec![HANDLE::default(); 64];
+        loop {
+            let count = GetProcessHeaps(&mut heaps) as usize;
+            if count <= heaps.len() {
+                heaps.truncate(count);
+                break;
+            }
+            heaps.resize(count, HANDLE::default());
+        }
+
+        sample.heaps = heaps.len();
+        for heap in heaps {
+            let mut summary = HEAP_SUMMARY {
+                cb: std::mem::size_of::<HEAP_SUMMARY>() as u32,
+                ..Default::default()
+            };
+            if HeapSummary(heap, 0, &mut summary).as_bool() {
+                sample.heap_allocated += summary.cbAllocated;
+                sample.heap_committed += summary.cbCommitted;
+            }
+        }
+    }
+}
+
+pub fn sample() -> io::Result<MemorySample> {
+    let mut counters = PROCESS_MEMORY_COUNTERS_EX {
+        cb: std::mem::size_of::<PROCESS_MEMORY_COUNTERS_EX>() as u32,
+        ..Default::default()
+    };
+    unsafe {
+        GetProcessMemoryInfo(
+            GetCurrentProcess(),
+            &mut counters as *mut PROCESS_MEMORY_COUNTERS_EX as *mut PROCESS_MEMORY_COUNTERS,
+            counters.cb,
+        )?;
+    }
+
+    let mut sample = MemorySample {
+        working_set: counters.WorkingSetSize,
+        peak_working_set: counters.PeakWorkingSetSize,
+        commit: counters.PrivateUsage,
+        ..Default::default()
+    };
+    sample_heaps(&mut sample);
+    Ok(sample)
+}
+
+/// Returns the sampled stats, `None` before the first sample.
+pub fn stats() -> Option<MemoryStats> {
+    *STATS.lock().unwrap()
+}
+
+fn run_sampler() {
+    while !services::stopping() {
+        match sample() {
+            Ok(latest) => {
+                let mut stats = STATS.lock().unwrap();
+                match stats.as_mut() {
+                    Some(stats) => stats.latest = latest,
+                    None => {
+                        *stats = Some(MemoryStats {
+                            latest,
+                            first_commit: latest.commit,
+                            started: Instant::now(),
+                        })
+                    }
+                }
+            }
+            Err(e) => {
+                error!("Could not sample memory usage, stopping: {e}");
+                return;
+            }
+        }
+        thread::sleep(SAMPLE_INTERVAL);
+    }
+}
+
+/// Starts sampling in the background.
+pub fn spawn_memory_sampler() {
+    if let Err(e) = services::spawn("memory-sampler", run_sampler) {
+        error!("Could not start memory sampler: {e}");
+    }
+}
@@ -0,0 +1,293 @@
+/// Prometheus metrics over HTTP, for graphing long soak test sessions in Grafana.
+///
+/// `GET /metrics` answers with frame times, loads, memory usage and the state of every hook in the
+/// Prometheus text format. Anything else is a 404. Disabled unless turned on in the config.
+use std::{
+    error::Error,
+    fmt::Write as _,
+    io::{BufRead, BufReader, Write},
+    net::{Ipv4Addr, TcpListener, TcpStream},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use log::{error, info, trace};
+
+use crate::config::config;
+use crate::engine::{build::game_build, frame, loading};
+use crate::overlay;
+
+use super::{about, memory, services, status};
+
+/// Upper bounds of the frame time histogram buckets in seconds, around 60fps and 30fps and the
+/// hitches worth seeing
+const FRAME_TIME_BUCKETS: [f64; 8] = [0.008, 0.017, 0.034, 0.05, 0.1, 0.25, 0.5, 1.0];
+
+/// Frame time histogram, cumulative like Prometheus wants it
+struct FrameTimes {
+    last_frame: Option<Instant>,
+    buckets: [u64; FRAME_TIME_BUCKETS.len()],
+    count: u64,
+    sum: f64,
+}
+
+static FRAME_TIMES: Mutex<FrameTimes> = Mutex::new(FrameTimes {
+    last_frame: None,
+    buckets: [0; FRAME_TIME_BUCKETS.len()],
+    count: 0,
+    sum: 0.0,
+});
+
+fn record_frame() {
+    let now = Instant::now();
+    let mut times = FRAME_TIMES.lock().unwrap();
+    if let Some(last_frame) = times.last_frame.replace(now) {
+        let seconds = now.duration_since(last_frame).as_secs_f64();
+        for (bucket, upper_bound) in times.buckets.iter_mut().zip(FRAME_TIME_BUCKETS) {
+            if seconds <= upper_bound {
+                *bucket += 1;
+            }
+        }
+        times.count += 1;
+        times.sum += seconds;
+    }
+}
+
+/// Writes a metric with its help and type lines, `samples` are label sets and values.
+fn write_metric(
+    out: &mut String,
+    name: &str,
+    kind: &str,
+    help: &str,
+    samples: impl IntoIterator<Item = (String, f64)>,
+) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+    for (labels, value) in samples {
+        let _ = writeln!(out, "{name}{labels} {value}");
+    }
+}
+
+fn no_labels(value: f64) -> [(String, f64); 1] {
+    [(String::new(), value)]
+}
+
+/// Escapes a label value, the format wants backslashes, quotes and newlines escaped.
+fn label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn seconds(duration: Duration) -> f64 {
+    duration.as_secs_f64()
+}
+
+/// Renders every metric in the Prometheus text format.
+pub fn render() -> String {
+    let mut out = String::new();
+
+    write_metric(
+        &mut out,
+        "swkotor_mod_info",
+        "gauge",
+        "Mod version and game build",
+        [(
+            format!(
+                "{{version=\"{}\",build=\"{}\"}}",
+                about::VERSION,
+                label_value(&game_build().to_string())
+            ),
+            1.0,
+        )],
+    );
+
+    {
+        let times = FRAME_TIMES.lock().unwrap();
+        let mut samples: Vec<(String, f64)> = FRAME_TIME_BUCKETS
+            .iter()
+            .zip(times.buckets)
+            .map(|(upper_bound, count)| (format!("_bucket{{le=\"{upper_bound}\"}}"), count as f64))
+            .collect();
+        samples.push(("_bucket{le=\"+Inf\"}".to_string(), times.count as f64));
+        samples.push(("_sum".to_string(), times.sum));
+        samples.push(("_count".to_string(), times.count as f64));
+        write_metric(
+            &mut out,
+            "swkotor_frame_time_seconds",
+            "histogram",
+            "Time between frames",
+            samples,
+        );
+    }
+
+    let cost = overlay::frame_cost();
+    write_metric(
+        &mut out,
+        "swkotor_overlay_frame_seconds",
+        "gauge",
+        "Average time the overlay adds to a frame",
+        no_labels(seconds(cost.average)),
+    );
+
+    let loads = loading::stats();
+    write_metric(
+        &mut out,
+        "swkotor_loads_total",
+        "counter",
+        "Load screens shown",
+        no_labels(loads.count as f64),
+    );
+    write_metric(
+        &mut out,
+        "swkotor_load_seconds_total",
+        "counter",
+        "Time spent on load screens",
+        no_labels(seconds(loads.total)),
+    );
+    write_metric(
+        &mut out,
+        "swkotor_load_longest_seconds",
+        "gauge",
+        "Longest load screen",
+        no_labels(seconds(loads.longest)),
+    );
+
+    // The sampler has the latest numbers, sample here when it hasn't run yet
+    let sample = match memory::stats() {
+        Some(stats) => Ok(stats.latest),
+        None => memory::sample(),
+    };
+    match sample {
+        Ok(sample) => {
+            write_metric(
+                &mut out,
+                "swkotor_working_set_bytes",
+                "gauge",
+                "Working set of the game process",
+                no_labels(sample.working_set as f64),
+            );
+            write_metric(
+                &mut out,
+                "swkotor_commit_bytes",
+                "gauge",
+                "Private bytes committed by the game process",
+                no_labels(sample.commit as f64),
+            );
+            write_metric(
+                &mut out,
+                "swkotor_heap_allocated_bytes",
+                "gauge",
+                "Bytes in use in the process heaps",
+                no_labels(sample.heap_allocated as f64),
+            );
+        }
+        Err(e) => error!("Could not sample memory for metrics: {e}"),
+    }
+
+    write_metric(
+        &mut out,
+        "swkotor_hook_installed",
+        "gauge",
+        "Whether the hook is installed, 0 when idle or not located in this build",
+        status::hooks().into_iter().map(|(name, state)| {
+            let installed = if state == "installed" { 1.0 } else { 0.0 };
+            (format!("{{hook=\"{}\"}}", label_value(name)), installed)
+        }),
+    );
+    write_metric(
+        &mut out,
+        "swkotor_disabled_features",
+        "gauge",
+        "Features turned off after failing",
+        no_labels(status::disabled_features().len() as f64),
+    );
+
+    out
+}
+
+fn serve_client(stream: TcpStream) -> Result<(), Box<dyn Error>> {
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // Headers are of no interest, read them so the client isn't cut off mid request
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 2 {
+        header.clear();
+    }
+
+    let mut words = request_line.split_whitespace();
+    let (status, body) = match (words.next(), words.next()) {
+        (Some("GET"), Some("/metrics")) => ("200 OK", render()),
+        _ => ("404 Not Found", "Only GET /metrics is served\n".to_string()),
+    };
+    trace!("Metrics request {}: {status}", request_line.trim_end());
+
+    let mut stream = &stream;
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()?;
+    Ok(())
+}
+
+/// Starts serving metrics in a background thread, if enabled in the config.
+pub fn spawn_metrics_server() {
+    let settings = config().metrics.clone();
+    if !settings.enabled {
+        trace!("Metrics server disabled");
+        return;
+    }
+
+    frame::on_every_frame(record_frame);
+    services::on_stop(wake_server);
+    let spawned = services::spawn("metrics", move || {
+        let listener = match TcpListener::bind((settings.address.as_str(), settings.port)) {
+            Ok(listener) => listener,
+            Err(e) => {
+                error!(
+                    "Could not serve metrics on {}:{}: {e}",
+                    settings.address, settings.port
+                );
+                return;
+            }
+        };
+        info!(
+            "Serving metrics on http://{}:{}/metrics",
+            settings.address, settings.port
+        );
+
+        // Scrapes are rare and quick, one at a time is plenty
+        for stream in listener.incoming() {
+            if services::stopping() {
+                break;
+            }
+            match stream {
+                Ok(stream) => {
+                    if let Err(e) = serve_client(stream) {
+                        error!("Metrics request failed: {e}");
+                    }
+                }
+                Err(e) => error!("Could not accept metrics client: {e}"),
+            }
+        }
+    });
+
+    if let Err(e) = spawned {
+        error!("Could not start the metrics server: {e}");
+    }
+}
+
+/// Connects to the server so the blocked accept returns and sees the stop.
+fn wake_server() {
+    let settings = config().metrics.clone();
+    // Listening on every interface, loopback is one of them
+    let address = match settings.address.as_str() {
+        "0.0.0.0" => Ipv4Addr::LOCALHOST.to_string(),
+        address => address.to_string(),
+    };
+    let _ = TcpStream::connect((address.as_str(), settings.port));
+}
@@ -1 +1,13 @@
+pub mod about;
+pub mod clipboard;
 pub mod dll_loader;
+pub mod hardware;
+pub mod ipc;
+pub mod log_writer;
+pub mod memory;
+pub mod metrics;
+pub mod reload;
+pub mod services;
+pub mod status;
+pub mod websocket;
+pub mod window;
@@ -0,0 +1,91 @@
+/// Hot reload of the mod, when the game was started with `swkotor-mod-loader` as dinput8.dll.
+///
+/// The loader loads a copy of swkotor_mod.dll, which leaves the original free to be rebuilt while
+/// the game runs, and hands the copy a callback for requesting a reload. On reload the loader
+/// calls `swkotor_mod_unload` from a thread of its own, frees the copy and loads a fresh one.
+///
+/// Unloading removes every hook and patch on the main thread, then stops the mod's threads. The
+/// copy can only be freed once none of its code runs anymore. If a thread doesn't stop in time,
+/// e.g. an IPC client is still connected, the old copy is left loaded and idle.
+use std::{io, sync::Mutex, thread, time::Duration};
+
+use log::{error, info};
+
+use crate::engine::{self, frame, loading};
+use crate::telemetry::recorder;
+
+use super::services;
+
+/// Asks the loader to reload the mod. Returns right away, the reload happens on a loader thread.
+pub type ReloadFn = unsafe extern "C" fn();
+
+// Results of `swkotor_mod_unload`, the loader has the same values
+pub const UNLOAD_FAILED: u32 = 0;
+pub const UNLOADED: u32 = 1;
+/// Hooks are gone but some threads are still running, the DLL must not be freed
+pub const UNLOADED_STILL_RUNNING: u32 = 2;
+
+/// How long the main thread gets for removing the hooks
+const UNINSTALL_TIMEOUT: Duration = Duration::from_secs(5);
+/// How long the services get for stopping
+const STOP_TIMEOUT: Duration = Duration::from_secs(2);
+/// The main thread is still on its way out of the SwapBuffers hook after removing the hooks
+const LEAVE_HOOK_DELAY: Duration = Duration::from_millis(100);
+
+static RELOAD: Mutex<Option<ReloadFn>> = Mutex::new(None);
+
+/// Called by the loader right after loading the mod.
+#[no_mangle]
+pub extern "C" fn swkotor_mod_set_reload_callback(reload: ReloadFn) {
+    *RELOAD.lock().unwrap() = Some(reload);
+}
+
+/// Called by the loader before freeing the mod, returns one of the `UNLOAD*` results.
+#[no_mangle]
+pub extern "C" fn swkotor_mod_unload() -> u32 {
+    match unload() {
+        Ok(running) if running.is_empty() => {
+            info!("Mod unloaded");
+            UNLOADED
+        }
+        Ok(_) => UNLOADED_STILL_RUNNING,
+        Err(e) => {
+            error!("Could not unload the mod: {e}");
+            UNLOAD_FAILED
+        }
+    }
+}
+
+/// Removes the hooks and stops the services. Returns the services that didn't stop.
+fn unload() -> io::Result<Vec<String>> {
+    info!("Unloading the mod");
+    // Drops the writer's channel, its thread returns once the last frame is written
+    recorder::set_recording(false)?;
+
+    frame::call_on_main_thread(UNINSTALL_TIMEOUT, engine::uninstall_hooks)??;
+    thread::sleep(LEAVE_HOOK_DELAY);
+
+    Ok(services::stop(STOP_TIMEOUT))
+}
+
+/// Asks the loader to unload the mod, load swkotor_mod.dll again from disk and reinstall the
+/// hooks.
+pub fn request_reload() -> io::Result<()> {
+    let reload = RELOAD.lock().unwrap().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Hot reload needs the game started with swkotor-mod-loader as dinput8.dll",
+        )
+    })?;
+
+    // The engine renders the load screen from deep inside the load, under our hooks
+    if loading::is_loading() {
+        return Err(io::Error::other(
+            "Can't reload while loading, try again once the load is done",
+        ));
+    }
+
+    info!("Requesting a reload");
+    unsafe { reload() };
+    Ok(())
+}
@@ -0,0 +1,96 @@
+/// Background threads of the mod, tracked so hot reload can stop them before the DLL is freed.
+///
+/// Services check `stopping` between units of work and return once it's set. Services that block
+/// on I/O register a waker, which unblocks them when a stop is requested, e.g. by connecting to
+/// their own listener. Code of a freed DLL must not be running on any thread, if a service doesn't
+/// stop in time the old DLL has to be left loaded.
+use std::{
+    io,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Condvar, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use log::{info, trace, warn};
+
+static STOPPING: AtomicBool = AtomicBool::new(false);
+
+/// Names of the services that are still running
+static RUNNING: Mutex<Vec<String>> = Mutex::new(Vec::new());
+static STOPPED: Condvar = Condvar::new();
+
+static WAKERS: Mutex<Vec<fn()>> = Mutex::new(Vec::new());
+
+/// Removes the service from the running ones when its thread returns or panics.
+struct Running {
+    name: String,
+}
+
+impl Drop for Running {
+    fn drop(&mut self) {
+        let mut running = RUNNING.lock().unwrap();
+        if let Some(index) = running.iter().position(|name| *name == self.name) {
+            running.remove(index);
+        }
+        STOPPED.notify_all();
+    }
+}
+
+/// Runs `service` on a named thread of its own.
+pub fn spawn<F>(name: &str, service: F) -> io::Result<()>
+where
+    F: FnOnce() + Send + 'static,
+{
+    // Counted before the thread starts, a stop right after spawning still waits for it
+    RUNNING.lock().unwrap().push(name.to_string());
+    let running = Running {
+        name: name.to_string(),
+    };
+
+    thread::Builder::new()
+        .name(name.to_string())
+        .spawn(move || {
+            service();
+            trace!("Service {} stopped", running.name);
+        })?;
+    Ok(())
+}
+
+/// Registers `waker` to be called when services are asked to stop.
+pub fn on_stop(waker: fn()) {
+    WAKERS.lock().unwrap().push(waker);
+}
+
+/// Services return once this is set.
+pub fn stopping() -> bool {
+    STOPPING.load(Ordering::Relaxed)
+}
+
+/// Asks every service to stop and waits up to `timeout` for them. Returns the names of the ones
+/// still running.
+pub fn stop(timeout: Duration) -> Vec<String> {
+    info!("Stopping services");
+    STOPPING.store(true, Ordering::Relaxed);
+
+    let wakers = WAKERS.lock().unwrap().clone();
+    for waker in wakers {
+        waker();
+    }
+
+    let deadline = Instant::now() + timeout;
+    let mut running = RUNNING.lock().unwrap();
+    while !running.is_empty() {
+        let Some(left) = deadline.checked_duration_since(Instant::now()) else {
+            break;
+        };
+        running = STOPPED.wait_timeout(running, left).unwrap().0;
+    }
+
+    if !running.is_empty() {
+        warn!("Services still running: {}", running.join(", "));
+    }
+    running.clone()
+}
@@ -0,0 +1,191 @@
+/// Self-diagnostics: what's hooked, what couldn't be located in this build, the last error of
+/// every subsystem and what the overlay costs a frame. Support requests should come with this.
+///
+/// Errors are picked up from the log. The logger is wrapped so every `error!` is remembered by the
+/// module that logged it, no subsystem has to report its errors separately. Panics are logged as
+/// errors too.
+///
+/// Features that couldn't start, e.g. a patch whose code didn't match on an unknown build, are
+/// listed as disabled while the rest of the mod keeps running.
+use std::{
+    collections::HashMap,
+    fmt, panic,
+    sync::{LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+
+use log::{error, warn, Level, Log, Metadata, Record};
+
+use crate::engine::{input::input_hooked, trace};
+use crate::overlay;
+use crate::plugins;
+use crate::util::iat::{miles::miles_hooked, swapbuffers::swapbuffers_hooked};
+
+use super::{about, hardware};
+
+/// Logged by every module of the mod, left out of subsystem names
+const CRATE_TARGET_PREFIX: &str = "swkotor_mod::";
+
+struct LastError {
+    message: String,
+    at: Instant,
+}
+
+static LAST_ERRORS: LazyLock<Mutex<HashMap<String, LastError>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+/// Features turned off and why, in the order they were turned off
+static DISABLED: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+
+/// Passes records on to env_logger, remembering errors on the way.
+struct StatusLogger {
+    inner: env_logger::Logger,
+}
+
+impl Log for StatusLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.inner.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if record.level() == Level::Error {
+            let target = record.target();
+            let subsystem = target.strip_prefix(CRATE_TARGET_PREFIX).unwrap_or(target);
+            // Formatted before locking, formatting could log
+            let error = LastError {
+                message: record.args().to_string(),
+                at: Instant::now(),
+            };
+            LAST_ERRORS
+                .lock()
+                .unwrap()
+                .insert(subsystem.to_string(), error);
+        }
+        self.inner.log(record);
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
+/// Installs `logger` as the global logger, with errors tracked for `status`.
+pub fn init_logger(logger: env_logger::Logger) {
+    let max_level = logger.filter();
+    log::set_boxed_logger(Box::new(StatusLogger { inner: logger }))
+        .expect("Logger should only be initialized once");
+    log::set_max_level(max_level);
+}
+
+/// Logs panics before the default hook prints them to a console nobody reads. Panics caught on
+/// the main thread leave the game running, the log is the only trace of them.
+pub fn install_panic_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        error!("{info}");
+        default_hook(info);
+    }));
+}
+
+/// Records `feature` as turned off for `reason`. The rest of the mod keeps going.
+pub fn disable_feature(feature: &str, reason: impl fmt::Display) {
+    warn!("Disabled {feature}: {reason}");
+    DISABLED
+        .lock()
+        .unwrap()
+        .push((feature.to_string(), reason.to_string()));
+}
+
+/// Features turned off and why.
+pub fn disabled_features() -> Vec<(String, String)> {
+    DISABLED.lock().unwrap().clone()
+}
+
+/// Last error of every subsystem that has logged one, sorted by subsystem.
+pub fn last_errors() -> Vec<(String, String, Duration)> {
+    let mut errors: Vec<_> = LAST_ERRORS
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(subsystem, error)| (subsystem.clone(), error.message.clone(), error.at.elapsed()))
+        .collect();
+    errors.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    errors
+}
+
+fn hook_state(installed: bool, resolved: bool) -> &'static str {
+    match (installed, resolved) {
+        (true, _) => "installed",
+        (false, true) => "idle",
+        (false, false) => "unresolved",
+    }
+}
+
+/// Hook names and states, `installed`, `idle` when located but not needed yet, or `unresolved`.
+pub fn hooks() -> Vec<(&'static str, &'static str)> {
+    let mut hooks = vec![
+        ("SwapBuffers", hook_state(swapbuffers_hooked(), true)),
+        ("Miles", hook_state(miles_hooked(), true)),
+        ("DirectInput", hook_state(input_hooked(), true)),
+    ];
+    hooks.extend(
+        trace::hooks()
+            .into_iter()
+            .map(|hook| (hook.name, hook_state(hook.installed, hook.resolved))),
+    );
+    hooks
+}
+
+/// Human readable report for the console.
+pub fn report() -> Vec<String> {
+    let mut lines = about::summary();
+    if let Some(opengl) = hardware::opengl() {
+        lines.push(format!(
+            "OpenGL: {} by {}, {}",
+            opengl.renderer, opengl.vendor, opengl.version
+        ));
+    }
+
+    lines.push("Hooks:".to_string());
+    let hooks = hooks();
+    let width = hooks
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or_default();
+    for (name, state) in hooks {
+        lines.push(format!("  {name:<width$} {state}"));
+    }
+
+    let disabled = disabled_features();
+    if !disabled.is_empty() {
+        lines.push("Disabled:".to_string());
+        for (feature, reason) in disabled {
+            lines.push(format!("  {feature}: {reason}"));
+        }
+    }
+
+    let plugins = plugins::loaded();
+    if !plugins.is_empty() {
+        lines.push(format!("Plugins: {}", plugins.join(", ")));
+    }
+
+    let cost = overlay::frame_cost();
+    lines.push(format!(
+        "Overlay: {:.2} ms a frame, {:.2} ms on average, {:.2} ms at worst",
+        cost.last.as_secs_f64() * 1000.0,
+        cost.average.as_secs_f64() * 1000.0,
+        cost.worst.as_secs_f64() * 1000.0
+    ));
+
+    let errors = last_errors();
+    if errors.is_empty() {
+        lines.push("No errors".to_string());
+    } else {
+        lines.push("Last errors:".to_string());
+        for (subsystem, message, ago) in errors {
+            lines.push(format!("  {subsystem}, {}s ago: {message}", ago.as_secs()));
+        }
+    }
+
+    lines
+}
@@ -0,0 +1,96 @@
+/// WebSocket server mirroring the IPC protocol over JSON, for browser dashboards and stream
+/// overlays that can't open a named pipe.
+///
+/// Every text message is handled as a single JSON request and answered with a single JSON
+/// response, see `ipc::protocol`. Disabled unless turned on in the config.
+use std::{
+    error::Error,
+    net::{Ipv4Addr, TcpListener, TcpStream},
+};
+
+use log::{error, info, trace};
+use tungstenite::{Message, WebSocket};
+
+use crate::config::config;
+
+use super::{ipc::protocol, services};
+
+fn serve_client(stream: TcpStream) -> Result<(), Box<dyn Error>> {
+    let mut socket: WebSocket<TcpStream> =
+        tungstenite::accept(stream).map_err(|e| format!("Handshake failed: {e}"))?;
+
+    loop {
+        match socket.read() {
+            Ok(Message::Text(text)) => {
+                trace!("WebSocket request: {text}");
+                let response = protocol::handle_json(&text);
+                socket.send(Message::text(response))?;
+            }
+            // Pings are answered by tungstenite, binary messages are not part of the protocol
+            Ok(_) => (),
+            Err(tungstenite::Error::ConnectionClosed) => return Ok(()),
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+/// Starts accepting WebSocket clients in a background thread, if enabled in the config.
+pub fn spawn_websocket_server() {
+    let settings = config().websocket.clone();
+    if !settings.enabled {
+        trace!("WebSocket server disabled");
+        return;
+    }
+
+    services::on_stop(wake_server);
+    let spawned = services::spawn("websocket", move || {
+        let listener = match TcpListener::bind((settings.address.as_str(), settings.port)) {
+            Ok(listener) => listener,
+            Err(e) => {
+                error!(
+                    "Could not listen for WebSocket clients on {}:{}: {e}",
+                    settings.address, settings.port
+                );
+                return;
+            }
+        };
+        info!(
+            "Listening for WebSocket clients on {}:{}",
+            settings.address, settings.port
+        );
+
+        for stream in listener.incoming() {
+            if services::stopping() {
+                break;
+            }
+            match stream {
+                Ok(stream) => {
+                    let spawned = services::spawn("websocket-client", move || {
+                        if let Err(e) = serve_client(stream) {
+                            error!("WebSocket client failed: {e}");
+                        }
+                    });
+                    if let Err(e) = spawned {
+                        error!("Could not start a WebSocket client thread: {e}");
+                    }
+                }
+                Err(e) => error!("Could not accept WebSocket client: {e}"),
+            }
+        }
+    });
+
+    if let Err(e) = spawned {
+        error!("Could not start the WebSocket server: {e}");
+    }
+}
+
+/// Connects to the server so the blocked accept returns and sees the stop.
+fn wake_server() {
+    let settings = config().websocket.clone();
+    // Listening on every interface, loopback is one of them
+    let address = match settings.address.as_str() {
+        "0.0.0.0" => Ipv4Addr::LOCALHOST.to_string(),
+        address => address.to_string(),
+    };
+    let _ = TcpStream::connect((address.as_str(), settings.port));
+}
@@ -0,0 +1,137 @@
+/// Borderless windowed mode.
+///
+/// Strips the frame off the game window and stretches it over the monitor it's on. The original
+/// style and placement are kept so turning it off puts the window back where it was. Run the game
+/// windowed at the desktop resolution, exclusive fullscreen has no window frame to remove.
+use std::{io, sync::Mutex};
+
+use log::info;
+use windows::Win32::{
+    Foundation::{BOOL, HWND, LPARAM, RECT},
+    Graphics::Gdi::{GetMonitorInfoA, MonitorFromWindow, MONITORINFO, MONITOR_DEFAULTTONEAREST},
+    System::Threading::GetCurrentProcessId,
+    UI::WindowsAndMessaging::{
+        EnumWindows, GetWindow, GetWindowLongA, GetWindowRect, GetWindowThreadProcessId,
+        IsWindowVisible, SetWindowLongA, SetWindowPos, GWL_STYLE, GW_OWNER, HWND_TOP,
+        SWP_FRAMECHANGED, SWP_NOZORDER, WS_CAPTION, WS_MAXIMIZEBOX, WS_MINIMIZEBOX, WS_POPUP,
+        WS_SYSMENU, WS_THICKFRAME,
+    },
+};
+
+/// Style and placement before going borderless
+struct Windowed {
+    style: i32,
+    rect: RECT,
+}
+
+static WINDOWED: Mutex<Option<Windowed>> = Mutex::new(None);
+
+unsafe extern "system" fn find_game_window(hwnd: HWND, found: LPARAM) -> BOOL {
+    let mut process_id = 0;
+    GetWindowThreadProcessId(hwnd, Some(&mut process_id));
+
+    // Game window is the visible top level window, the console window belongs to conhost
+    let is_game_window = process_id == GetCurrentProcessId()
+        && IsWindowVisible(hwnd).as_bool()
+        && GetWindow(hwnd, GW_OWNER).is_err();
+
+    if is_game_window {
+        *(found.0 as *mut Option<HWND>) = Some(hwnd);
+        return false.into();
+    }
+    true.into()
+}
+
+fn game_window() -> io::Result<HWND> {
+    let mut found: Option<HWND> = None;
+    unsafe {
+        // Errors when the callback stops the enumeration early, the result tells what happened
+        let _ = EnumWindows(
+            Some(find_game_window),
+            LPARAM(&mut found as *mut Option<HWND> as isize),
+        );
+    }
+
+    found.ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Game window not found"))
+}
+
+pub fn borderless_enabled() -> bool {
+    WINDOWED.lock().unwrap().is_some()
+}
+
+/// Turns borderless windowed mode on or off. Setting the current state again does nothing.
+///
+/// Must be called on the main thread, it owns the window.
+pub fn set_borderless(enabled: bool) -> io::Result<()> {
+    let mut windowed = WINDOWED.lock().unwrap();
+    let hwnd = game_window()?;
+
+    match (enabled, windowed.as_ref()) {
+        (true, None) => unsafe {
+            let style = GetWindowLongA(hwnd, GWL_STYLE);
+            let mut rect = RECT::default();
+            GetWindowRect(hwnd, &mut rect)?;
+
+            let mut monitor_info = MONITORINFO {
+                cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+                ..Default::default()
+            };
+            let monitor = MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST);
+            if !GetMonitorInfoA(monitor, &mut monitor_info).as_bool() {
+                return Err(io::Error::other("Could not read monitor info"));
+            }
+
+            let frame =
+                (WS_CAPTION | WS_THICKFRAME | WS_SYSMENU | WS_MINIMIZEBOX | WS_MAXIMIZEBOX).0;
+            SetWindowLongA(
+                hwnd,
+                GWL_STYLE,
+                (style & !(frame as i32)) | WS_POPUP.0 as i32,
+            );
+
+            let monitor_rect = monitor_info.rcMonitor;
+            if let Err(error) = SetWindowPos(
+                hwnd,
+                Some(HWND_TOP),
+                monitor_rect.left,
+                monitor_rect.top,
+                monitor_rect.right - monitor_rect.left,
+                monitor_rect.bottom - monitor_rect.top,
+                SWP_FRAMECHANGED,
+            ) {
+                // Still windowed, the frame has to come back too
+                SetWindowLongA(hwnd, GWL_STYLE, style);
+                return Err(error.into());
+            }
+
+            *windowed = Some(Windowed { style, rect });
+        },
+        (false, Some(previous)) => unsafe {
+            let borderless = GetWindowLongA(hwnd, GWL_STYLE);
+            SetWindowLongA(hwnd, GWL_STYLE, previous.style);
+            let rect = previous.rect;
+            if let Err(error) = SetWindowPos(
+                hwnd,
+                None,
+                rect.left,
+                rect.top,
+                rect.right - rect.left,
+                rect.bottom - rect.top,
+                SWP_FRAMECHANGED | SWP_NOZORDER,
+            ) {
+                // Still borderless, the windowed state is kept so turning it off can be tried again
+                SetWindowLongA(hwnd, GWL_STYLE, borderless);
+                return Err(error.into());
+            }
+
+            *windowed = None;
+        },
+        _ => {}
+    }
+
+    info!(
+        "Borderless window {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(())
+}
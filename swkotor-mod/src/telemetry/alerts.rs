@@ -0,0 +1,181 @@
+/// Performance alerts: thresholds from the config that put a warning on the overlay, into the log
+/// and into the telemetry recording when crossed.
+///
+/// Intermittent hitching is easy to miss while playing and hard to find afterwards in a long
+/// recording. Frame time has to stay over its threshold for a while before it counts, single
+/// long frames are normal, e.g. when autosaving, and frames during loads are left out. Memory is
+/// the commit sampled by `system::memory`, alerted as soon as it's over. An alert is raised again
+/// only after its condition has cleared.
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use log::warn;
+
+use crate::config::config;
+use crate::engine::{frame, loading};
+use crate::system::memory;
+
+use super::recorder;
+
+const MEGABYTE: usize = 1024 * 1024;
+
+#[derive(Debug, Clone)]
+pub struct Alert {
+    /// Short name, written to the telemetry recording
+    pub name: &'static str,
+    pub message: String,
+    pub raised: Instant,
+}
+
+/// Condition that has to hold for a while before alerting.
+#[derive(Debug, Default)]
+struct Sustained {
+    since: Option<Instant>,
+    raised: bool,
+}
+
+impl Sustained {
+    /// Feeds whether the condition holds at `now`. True on the first update it has held for
+    /// `hold`, false after that until it clears.
+    fn update(&mut self, holds: bool, now: Instant, hold: Duration) -> bool {
+        if !holds {
+            *self = Sustained::default();
+            return false;
+        }
+        let since = *self.since.get_or_insert(now);
+        if self.raised || now.duration_since(since) < hold {
+            return false;
+        }
+        self.raised = true;
+        true
+    }
+}
+
+struct Monitor {
+    last_frame: Option<Instant>,
+    frame_time: Sustained,
+    memory: Sustained,
+}
+
+static MONITOR: Mutex<Monitor> = Mutex::new(Monitor {
+    last_frame: None,
+    frame_time: Sustained {
+        since: None,
+        raised: false,
+    },
+    memory: Sustained {
+        since: None,
+        raised: false,
+    },
+});
+
+/// Every alert of the session, oldest first
+static HISTORY: Mutex<Vec<Alert>> = Mutex::new(Vec::new());
+
+fn raise(name: &'static str, message: String) {
+    warn!("Performance alert: {message}");
+    recorder::mark_alert(name);
+    HISTORY.lock().unwrap().push(Alert {
+        name,
+        message,
+        raised: Instant::now(),
+    });
+}
+
+fn check() {
+    let (frame_time_ms, frame_time_seconds, memory_mb) = {
+        let config = config();
+        let alerts = &config.alerts;
+        (
+            alerts.frame_time_ms,
+            alerts.frame_time_seconds,
+            alerts.memory_mb,
+        )
+    };
+
+    let now = Instant::now();
+    let mut monitor = MONITOR.lock().unwrap();
+    let last_frame = monitor.last_frame.replace(now);
+
+    if let (Some(threshold), Some(last_frame)) = (frame_time_ms, last_frame) {
+        let frame_time = now.duration_since(last_frame).as_secs_f32() * 1000.0;
+        let slow = frame_time > threshold && !loading::is_loading();
+        let hold = Duration::from_secs_f32(frame_time_seconds.max(0.0));
+        if monitor.frame_time.update(slow, now, hold) {
+            raise(
+                "frame_time",
+                format!("Frame time over {threshold}ms for {frame_time_seconds}s"),
+            );
+        }
+    }
+
+    if let (Some(threshold), Some(stats)) = (memory_mb, memory::stats()) {
+        let commit = stats.latest.commit / MEGABYTE;
+        if monitor
+            .memory
+            .update(commit > threshold as usize, now, Duration::ZERO)
+        {
+            raise(
+                "memory",
+                format!("Memory commit {commit}MB over {threshold}MB"),
+            );
+        }
+    }
+}
+
+/// Alerts raised within `within`, oldest first.
+pub fn recent(within: Duration) -> Vec<Alert> {
+    HISTORY
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|alert| alert.raised.elapsed() < within)
+        .cloned()
+        .collect()
+}
+
+/// Every alert raised this session, oldest first.
+pub fn history() -> Vec<Alert> {
+    HISTORY.lock().unwrap().clone()
+}
+
+/// Starts checking the thresholds when any is set in the config.
+pub fn init() {
+    {
+        let config = config();
+        if config.alerts.frame_time_ms.is_none() && config.alerts.memory_mb.is_none() {
+            return;
+        }
+    }
+    frame::on_every_frame(check);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alerts_once_the_condition_has_held() {
+        let start = Instant::now();
+        let at = |seconds: f32| start + Duration::from_secs_f32(seconds);
+        let hold = Duration::from_secs(2);
+        let mut sustained = Sustained::default();
+
+        assert!(!sustained.update(true, at(0.0), hold));
+        assert!(!sustained.update(true, at(1.9), hold));
+        assert!(sustained.update(true, at(2.0), hold));
+        // Raised once until it clears
+        assert!(!sustained.update(true, at(3.0), hold));
+        assert!(!sustained.update(false, at(3.1), hold));
+        assert!(!sustained.update(true, at(3.2), hold));
+        // A fast frame in between starts over
+        assert!(!sustained.update(false, at(4.0), hold));
+        assert!(!sustained.update(true, at(5.0), hold));
+        assert!(sustained.update(true, at(7.0), hold));
+
+        let mut immediate = Sustained::default();
+        assert!(immediate.update(true, at(0.0), Duration::ZERO));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod alerts;
+pub mod recorder;
+pub mod timeline;
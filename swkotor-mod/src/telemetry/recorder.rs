@@ -0,0 +1,271 @@
+/// Per frame telemetry recorder for offline performance analysis.
+///
+/// While recording, every frame is sampled on the main thread and handed to a writer thread, which
+/// appends it to a CSV file named after the session start time. Values the engine can't provide,
+/// e.g. position in the main menu, are left empty. Frames a performance alert was raised on have
+/// its name in the `alert` column, see `telemetry::alerts`.
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Mutex,
+    },
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use log::{error, info};
+use serde_json::{json, Map, Value};
+
+use crate::engine::{frame, loading, module, party};
+use crate::system::services;
+
+const CSV_HEADER: &str = "frame,time_ms,frame_time_ms,module,x,y,z,loading,loads,alert";
+
+/// Columns of `CSV_HEADER`: the JSON Schema type of the values, whether they can be left empty and
+/// what they hold
+const COLUMNS: [(&str, &str, bool, &str); 10] = [
+    (
+        "frame",
+        "integer",
+        false,
+        "Frame number, counted from the start of the recording",
+    ),
+    (
+        "time_ms",
+        "number",
+        false,
+        "Milliseconds since the start of the recording",
+    ),
+    (
+        "frame_time_ms",
+        "number",
+        false,
+        "Milliseconds since the previous frame",
+    ),
+    (
+        "module",
+        "string",
+        true,
+        "Module the party is in, empty in the main menu",
+    ),
+    (
+        "x",
+        "number",
+        true,
+        "Position of the party leader, empty when there's none",
+    ),
+    (
+        "y",
+        "number",
+        true,
+        "Position of the party leader, empty when there's none",
+    ),
+    (
+        "z",
+        "number",
+        true,
+        "Position of the party leader, empty when there's none",
+    ),
+    (
+        "loading",
+        "integer",
+        false,
+        "1 while a load screen is up, 0 otherwise",
+    ),
+    (
+        "loads",
+        "integer",
+        false,
+        "Loads since the game was started",
+    ),
+    (
+        "alert",
+        "string",
+        true,
+        "Performance alert raised on the frame, e.g. frame_time",
+    ),
+];
+
+struct Sample {
+    frame: u64,
+    time_ms: f64,
+    frame_time_ms: f64,
+    module: Option<String>,
+    position: Option<[f32; 3]>,
+    loading: bool,
+    loads: u32,
+    alert: Option<&'static str>,
+}
+
+impl Sample {
+    fn write_csv(&self, out: &mut impl Write) -> io::Result<()> {
+        let module = self.module.as_deref().unwrap_or_default();
+        let alert = self.alert.unwrap_or_default();
+        let [x, y, z] = match self.position {
+            Some(position) => position.map(|value| value.to_string()),
+            None => Default::default(),
+        };
+        writeln!(
+            out,
+            "{},{:.3},{:.3},{module},{x},{y},{z},{},{},{alert}",
+            self.frame, self.time_ms, self.frame_time_ms, self.loading as u8, self.loads
+        )
+    }
+}
+
+struct Session {
+    started: Instant,
+    last_frame: Instant,
+    frame: u64,
+    sender: mpsc::Sender<Sample>,
+    /// Alert raised since the last sample
+    alert: Option<&'static str>,
+}
+
+static SESSION: Mutex<Option<Session>> = Mutex::new(None);
+static FRAME_CALLBACK_REGISTERED: AtomicBool = AtomicBool::new(false);
+
+fn record_frame() {
+    let mut session = SESSION.lock().unwrap();
+    let Some(session) = session.as_mut() else {
+        return;
+    };
+
+    let now = Instant::now();
+    let sample = Sample {
+        frame: session.frame,
+        time_ms: now.duration_since(session.started).as_secs_f64() * 1000.0,
+        frame_time_ms: now.duration_since(session.last_frame).as_secs_f64() * 1000.0,
+        module: module::entered_module(),
+        position: party::leader_position().ok(),
+        loading: loading::is_loading(),
+        loads: loading::stats().count,
+        alert: session.alert.take(),
+    };
+    session.frame += 1;
+    session.last_frame = now;
+
+    // Writer only goes away on errors, it has already logged why
+    let _ = session.sender.send(sample);
+}
+
+fn write_session(file: File, samples: mpsc::Receiver<Sample>) -> io::Result<()> {
+    let mut out = BufWriter::new(file);
+    writeln!(out, "{CSV_HEADER}")?;
+
+    // Ends once the session is stopped and the sender dropped
+    for sample in samples {
+        sample.write_csv(&mut out)?;
+    }
+
+    out.flush()
+}
+
+/// JSON Schema of the rows of the telemetry CSV, published as `data/schemas/telemetry.schema.json`
+/// for tools reading the recordings.
+pub fn csv_schema() -> Value {
+    let properties: Map<String, Value> = COLUMNS
+        .iter()
+        .map(|(name, kind, optional, description)| {
+            let kind = if *optional {
+                json!([kind, "null"])
+            } else {
+                json!(kind)
+            };
+            let column = json!({ "type": kind, "description": description });
+            (name.to_string(), column)
+        })
+        .collect();
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "swkotor-mod telemetry",
+        "description": format!(
+            "A row of swkotor-mod-telemetry-<timestamp>.csv as an object keyed by the header, \
+             empty cells as null. The header is {CSV_HEADER}"
+        ),
+        "type": "object",
+        "properties": properties,
+        "required": COLUMNS.iter().map(|(name, ..)| *name).collect::<Vec<_>>(),
+        "additionalProperties": false,
+    })
+}
+
+/// Marks the next recorded frame with alert `name`, nothing happens when not recording.
+pub fn mark_alert(name: &'static str) {
+    if let Some(session) = SESSION.lock().unwrap().as_mut() {
+        session.alert = Some(name);
+    }
+}
+
+pub fn recording_enabled() -> bool {
+    SESSION.lock().unwrap().is_some()
+}
+
+/// Starts recording into a new session file or stops the current session.
+///
+/// Must be called on the main thread.
+pub fn set_recording(enabled: bool) -> io::Result<()> {
+    let mut session = SESSION.lock().unwrap();
+
+    if !enabled {
+        if session.take().is_some() {
+            info!("Telemetry recording stopped");
+        }
+        return Ok(());
+    }
+
+    if session.is_some() {
+        return Ok(());
+    }
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let path = format!("swkotor-mod-telemetry-{timestamp}.csv");
+    let file = File::create(&path)?;
+
+    let (sender, receiver) = mpsc::channel();
+    let writer_path = path.clone();
+    services::spawn("telemetry-writer", move || {
+        if let Err(e) = write_session(file, receiver) {
+            error!("Could not write telemetry to {writer_path}: {e}");
+        }
+    })?;
+
+    let now = Instant::now();
+    *session = Some(Session {
+        started: now,
+        last_frame: now,
+        frame: 0,
+        sender,
+        alert: None,
+    });
+
+    if !FRAME_CALLBACK_REGISTERED.swap(true, Ordering::Relaxed) {
+        frame::on_every_frame(record_frame);
+    }
+
+    info!("Recording telemetry to {path}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn published_schema_is_up_to_date() {
+        let names: Vec<&str> = COLUMNS.iter().map(|(name, ..)| *name).collect();
+        assert_eq!(names.join(","), CSV_HEADER);
+
+        let published: Value =
+            serde_json::from_str(include_str!("../../data/schemas/telemetry.schema.json")).unwrap();
+        assert_eq!(
+            published,
+            csv_schema(),
+            "data/schemas/telemetry.schema.json differs from csv_schema()"
+        );
+    }
+}
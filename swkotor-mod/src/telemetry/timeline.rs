@@ -0,0 +1,132 @@
+/// Visual timeline of a session: a screenshot at every area transition.
+///
+/// With `screenshot.on_transition` set, a screenshot is taken on the first frame after each
+/// module has finished loading. Next to the screenshots a CSV file named after the session start
+/// time gets a row per transition, with when it happened, the module, where the party leader
+/// stood and which screenshot goes with it.
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use log::error;
+
+use crate::config::config;
+use crate::engine::{frame, loading, module, party};
+use crate::screenshot;
+
+const CSV_HEADER: &str = "time,session_s,module,x,y,z,screenshot";
+
+struct Transition {
+    /// Unix time in seconds
+    time: u64,
+    session: f64,
+    module: String,
+    position: Option<[f32; 3]>,
+}
+
+/// Session start and the timeline file, created on the first transition
+struct Session {
+    started: Instant,
+    path: PathBuf,
+}
+
+static SESSION: OnceLock<Session> = OnceLock::new();
+
+/// Module entered and waiting for its load to finish
+static ENTERED: Mutex<Option<String>> = Mutex::new(None);
+
+/// Transition waiting for its screenshot to be saved
+static CAPTURING: Mutex<Option<Transition>> = Mutex::new(None);
+
+fn session() -> &'static Session {
+    SESSION.get_or_init(|| {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let directory = config().screenshot.directory.clone();
+        Session {
+            started: Instant::now(),
+            path: Path::new(&directory).join(format!("timeline-{timestamp}.csv")),
+        }
+    })
+}
+
+fn module_entered(module: &str) {
+    *ENTERED.lock().unwrap() = Some(module.to_string());
+}
+
+/// Captures the transition once the load screen is gone.
+fn capture_when_loaded() {
+    if loading::is_loading() {
+        return;
+    }
+    let Some(module) = ENTERED.lock().unwrap().take() else {
+        return;
+    };
+
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    *CAPTURING.lock().unwrap() = Some(Transition {
+        time,
+        session: session().started.elapsed().as_secs_f64(),
+        module,
+        position: party::leader_position().ok(),
+    });
+    screenshot::request_screenshot();
+}
+
+fn append_row(path: &Path, transition: &Transition, screenshot: &Path) -> io::Result<()> {
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)?;
+    }
+    let new = !path.exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if new {
+        writeln!(file, "{CSV_HEADER}")?;
+    }
+
+    let [x, y, z] = match transition.position {
+        Some(position) => position.map(|value| format!("{value:.2}")),
+        None => Default::default(),
+    };
+    let screenshot = screenshot
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+    writeln!(
+        file,
+        "{},{:.1},{},{x},{y},{z},{screenshot}",
+        transition.time, transition.session, transition.module
+    )
+}
+
+/// Pairs the first screenshot saved after a transition with it.
+fn screenshot_saved(path: &Path) {
+    let Some(transition) = CAPTURING.lock().unwrap().take() else {
+        return;
+    };
+    let timeline = &session().path;
+    if let Err(e) = append_row(timeline, &transition, path) {
+        error!("Could not write to {}: {e}", timeline.display());
+    }
+}
+
+/// Starts the timeline when it's enabled in the config.
+pub fn init() {
+    if !config().screenshot.on_transition {
+        return;
+    }
+
+    // Session time counts from startup, not from the first transition
+    session();
+    module::on_module_enter(module_entered);
+    frame::on_every_frame(capture_when_loaded);
+    screenshot::on_screenshot_saved(screenshot_saved);
+}
@@ -0,0 +1,161 @@
+use super::common::{install_plt_hook, IatStore};
+
+use std::error::Error;
+use std::ffi::{c_void, CStr};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use crate::audio;
+
+/// Miles Sound System handles, opaque to us
+pub type HStream = *mut c_void;
+pub type HSample = *mut c_void;
+
+type OpenStreamFn =
+    unsafe extern "system" fn(driver: *mut c_void, filename: *const i8, memory: i32) -> HStream;
+type CloseStreamFn = unsafe extern "system" fn(stream: HStream);
+type SetStreamVolumeFn = unsafe extern "system" fn(stream: HStream, volume: i32);
+type SetSampleVolumeFn = unsafe extern "system" fn(sample: HSample, volume: i32);
+
+const MILES_MODULE: &str = "mss32.dll";
+
+// Miles exports are stdcall, the import table has them decorated
+const OPEN_STREAM: &str = "_AIL_open_stream@12";
+const CLOSE_STREAM: &str = "_AIL_close_stream@4";
+const SET_STREAM_VOLUME: &str = "_AIL_set_stream_volume@8";
+const SET_SAMPLE_VOLUME: &str = "_AIL_set_sample_volume@8";
+
+/// The IAT slots and the originals they pointed to, dropping a store restores its slot.
+static MILES_HOOKS: Mutex<Vec<IatStore<usize>>> = Mutex::new(Vec::new());
+
+// Addresses of the originals, zero until hooked. Left in place on uninstall like SwapBuffers.
+static REAL_OPEN_STREAM: AtomicUsize = AtomicUsize::new(0);
+static REAL_CLOSE_STREAM: AtomicUsize = AtomicUsize::new(0);
+static REAL_SET_STREAM_VOLUME: AtomicUsize = AtomicUsize::new(0);
+static REAL_SET_SAMPLE_VOLUME: AtomicUsize = AtomicUsize::new(0);
+
+fn real<T: Copy>(address: &AtomicUsize) -> Option<T> {
+    match address.load(Ordering::Acquire) {
+        0 => None,
+        address => Some(unsafe { std::mem::transmute_copy::<usize, T>(&address) }),
+    }
+}
+
+unsafe extern "system" fn my_open_stream(
+    driver: *mut c_void,
+    filename: *const i8,
+    memory: i32,
+) -> HStream {
+    let Some(real_fn) = real::<OpenStreamFn>(&REAL_OPEN_STREAM) else {
+        log::error!("Cannot run AIL_open_stream. Bug. No hook stored");
+        return std::ptr::null_mut();
+    };
+
+    let stream = real_fn(driver, filename, memory);
+    if !stream.is_null() && !filename.is_null() {
+        audio::stream_opened(stream, &CStr::from_ptr(filename).to_string_lossy());
+    }
+    stream
+}
+
+unsafe extern "system" fn my_close_stream(stream: HStream) {
+    let Some(real_fn) = real::<CloseStreamFn>(&REAL_CLOSE_STREAM) else {
+        log::error!("Cannot run AIL_close_stream. Bug. No hook stored");
+        return;
+    };
+
+    audio::stream_closed(stream);
+    real_fn(stream)
+}
+
+unsafe extern "system" fn my_set_stream_volume(stream: HStream, volume: i32) {
+    if let Some(real_fn) = real::<SetStreamVolumeFn>(&REAL_SET_STREAM_VOLUME) {
+        real_fn(stream, audio::stream_volume(stream, volume));
+    }
+}
+
+unsafe extern "system" fn my_set_sample_volume(sample: HSample, volume: i32) {
+    if let Some(real_fn) = real::<SetSampleVolumeFn>(&REAL_SET_SAMPLE_VOLUME) {
+        real_fn(sample, audio::sample_volume(sample, volume));
+    }
+}
+
+/// Sets the volume of the stream without scaling it.
+pub(crate) fn set_stream_volume(stream: HStream, volume: i32) {
+    if let Some(real_fn) = real::<SetStreamVolumeFn>(&REAL_SET_STREAM_VOLUME) {
+        unsafe { real_fn(stream, volume) };
+    }
+}
+
+/// Sets the volume of the sample without scaling it.
+pub(crate) fn set_sample_volume(sample: HSample, volume: i32) {
+    if let Some(real_fn) = real::<SetSampleVolumeFn>(&REAL_SET_SAMPLE_VOLUME) {
+        unsafe { real_fn(sample, volume) };
+    }
+}
+
+/// Installs the above hooks on the game's Miles imports. Installing again does nothing.
+///
+/// Must be called on the main thread, the game must not call a hook before its original is stored.
+pub fn install_miles_hooks() -> Result<(), Box<dyn Error>> {
+    let mut hooks = MILES_HOOKS.lock()?;
+    if !hooks.is_empty() {
+        return Ok(());
+    }
+
+    let wanted: [(&str, usize, &AtomicUsize); 4] = [
+        (
+            OPEN_STREAM,
+            my_open_stream as OpenStreamFn as usize,
+            &REAL_OPEN_STREAM,
+        ),
+        (
+            CLOSE_STREAM,
+            my_close_stream as CloseStreamFn as usize,
+            &REAL_CLOSE_STREAM,
+        ),
+        (
+            SET_STREAM_VOLUME,
+            my_set_stream_volume as SetStreamVolumeFn as usize,
+            &REAL_SET_STREAM_VOLUME,
+        ),
+        (
+            SET_SAMPLE_VOLUME,
+            my_set_sample_volume as SetSampleVolumeFn as usize,
+            &REAL_SET_SAMPLE_VOLUME,
+        ),
+    ];
+
+    for (symbol, hook, real) in wanted {
+        let store = install_plt_hook::<usize>("swkotor.exe", symbol, &hook)
+            .map_err(|e| format!("{MILES_MODULE} {symbol}: {e}"));
+        match store {
+            Ok(store) => {
+                real.store(store.get_fn(), Ordering::Release);
+                hooks.push(store);
+            }
+            Err(e) => {
+                // Half of the hooks would scale volumes the other half can't put back
+                hooks.clear();
+                return Err(e.into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub fn miles_hooked() -> bool {
+    !MILES_HOOKS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .is_empty()
+}
+
+/// Puts the original Miles functions back into the IAT. Safe to call when not hooked.
+pub fn uninstall_miles_hooks() {
+    let mut hooks = MILES_HOOKS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    hooks.clear();
+}
@@ -1,2 +1,4 @@
 mod common;
 pub mod createfile;
+pub mod miles;
+pub mod swapbuffers;
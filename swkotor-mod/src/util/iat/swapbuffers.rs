@@ -0,0 +1,80 @@
+use super::common::{install_plt_hook, IatStore};
+
+use std::error::Error;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use windows::Win32::Foundation::{BOOL, FALSE};
+use windows::Win32::Graphics::Gdi::HDC;
+
+use crate::engine::frame;
+use crate::{overlay, screenshot};
+
+type SwapBuffersFn = unsafe extern "system" fn(hdc: HDC) -> BOOL;
+
+/// The IAT slot and the original SwapBuffers it pointed to. Dropping the store writes the original
+/// back into the slot, nothing else holds on to it.
+static SWAPBUFFERS_HOOK: Mutex<Option<IatStore<SwapBuffersFn>>> = Mutex::new(None);
+
+/// Address of the original SwapBuffers, zero until hooked. Read on every frame without locking
+/// and left in place on uninstall, a frame already in the hook still has the original to call.
+static REAL_SWAPBUFFERS: AtomicUsize = AtomicUsize::new(0);
+
+fn real_swapbuffers() -> Option<SwapBuffersFn> {
+    match REAL_SWAPBUFFERS.load(Ordering::Acquire) {
+        0 => None,
+        address => Some(unsafe { std::mem::transmute::<usize, SwapBuffersFn>(address) }),
+    }
+}
+
+// Our hooked SwapBuffers implementation. Kotor calls this once per frame from the main thread,
+// right after everything has been rendered.
+unsafe extern "system" fn my_swapbuffers(hdc: HDC) -> BOOL {
+    let Some(real_fn) = real_swapbuffers() else {
+        log::error!("Cannot run SwapBuffers. Bug. No SwapBuffers hook stored");
+        return FALSE;
+    };
+
+    frame::on_frame();
+    screenshot::capture_if_requested(false);
+    overlay::render(hdc);
+    screenshot::capture_if_requested(true);
+
+    real_fn(hdc)
+}
+
+/// Installs the above hook to get a callback on every frame. Installing again does nothing, the
+/// original would otherwise be overwritten with our own hook.
+pub fn install_swapbuffers_hook() -> Result<(), Box<dyn Error>> {
+    let mut hook = SWAPBUFFERS_HOOK.lock()?;
+    if hook.is_some() {
+        return Ok(());
+    }
+
+    let store = install_plt_hook::<SwapBuffersFn>(
+        "swkotor.exe",
+        "SwapBuffers",
+        &(my_swapbuffers as SwapBuffersFn),
+    )?;
+
+    REAL_SWAPBUFFERS.store(store.get_fn() as usize, Ordering::Release);
+    *hook = Some(store);
+
+    Ok(())
+}
+
+pub fn swapbuffers_hooked() -> bool {
+    SWAPBUFFERS_HOOK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .is_some()
+}
+
+/// Puts the original SwapBuffers back into the IAT. Safe to call when not hooked and from
+/// DllMain, the game must not call into the mod once it's unloaded.
+pub fn uninstall_swapbuffers_hook() {
+    // A panic while holding the lock doesn't make the stored original any less valid
+    let mut hook = SWAPBUFFERS_HOOK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    *hook = None;
+}